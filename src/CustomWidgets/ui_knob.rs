@@ -25,6 +25,20 @@ lazy_static! {
     static ref DRAG_NORMALIZED_START_VALUE_MEMORY_ID: egui::Id = egui::Id::new((file!(), 0));
     static ref DRAG_AMOUNT_MEMORY_ID: egui::Id = egui::Id::new((file!(), 1));
     static ref VALUE_ENTRY_MEMORY_ID: egui::Id = egui::Id::new((file!(), 2));
+    static ref HIGHLIGHT_EDITS_MEMORY_ID: egui::Id = egui::Id::new((file!(), 3));
+}
+
+// Ring drawn around knobs that have been moved from their default when edits are highlighted
+const EDITED_RING_COLOR: Color32 = Color32::from_rgb(255, 140, 60);
+
+// Turn the "changed from default" rings on or off for every knob
+pub fn set_highlight_edits(ctx: &egui::Context, highlight: bool) {
+    ctx.data_mut(|data| data.insert_temp(*HIGHLIGHT_EDITS_MEMORY_ID, highlight));
+}
+
+pub fn highlight_edits(ctx: &egui::Context) -> bool {
+    ctx.data(|data| data.get_temp::<bool>(*HIGHLIGHT_EDITS_MEMORY_ID))
+        .unwrap_or(false)
 }
 
 struct SliderRegion<'a, P: Param> {
@@ -423,6 +437,20 @@ impl<'a, P: Param> Widget for ArcKnob<'a, P> {
                 self.fill_color.linear_multiply(0.4),
             );
 
+            // Mark knobs that differ from the init patch, DEFAULT_PRESET is built from these same param defaults
+            if highlight_edits(ui.ctx())
+                && (self.slider_region.param.unmodulated_normalized_value()
+                    - self.slider_region.param.default_normalized_value())
+                .abs()
+                    > 0.0001
+            {
+                painter.circle_stroke(
+                    center,
+                    self.center_size + self.center_to_line_space + self.line_width + 2.0,
+                    Stroke::new(1.5, EDITED_RING_COLOR),
+                );
+            }
+
             // Draw the outside ring around the control
             if self.outline {
                 let outline_stroke = Stroke::new(1.0, self.fill_color.linear_multiply(0.7));
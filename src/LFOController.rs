@@ -11,12 +11,19 @@ pub struct LFOController {
     phase: f32,
     amplitude: f32,
     waveform: Waveform,
+    // One shot runs a single cycle after a phase reset then holds the end value
+    one_shot: bool,
+    finished: bool,
+    // Gate mode stops the phase while no voices are playing
+    #[serde(default)]
+    frozen: bool,
 }
 
 #[derive(Enum, PartialEq, Clone, Copy, Serialize, Deserialize)]
 pub enum LFORetrigger {
     None,
     NoteOn,
+    OneShot,
 }
 
 #[derive(Enum, PartialEq, Clone, Copy, Serialize, Deserialize)]
@@ -58,6 +65,38 @@ pub enum Waveform {
     PulseEigth,
 }
 
+impl LFOSnapValues {
+    // Length of one cycle in quarter note beats
+    pub fn beats(&self) -> f32 {
+        match self {
+            LFOSnapValues::Quad => 16.0,
+            LFOSnapValues::QuadD => 16.0 * 1.5,
+            LFOSnapValues::QuadT => 16.0 / 3.0,
+            LFOSnapValues::Double => 8.0,
+            LFOSnapValues::DoubleD => 8.0 * 1.5,
+            LFOSnapValues::DoubleT => 8.0 / 3.0,
+            LFOSnapValues::Whole => 4.0,
+            LFOSnapValues::WholeD => 4.0 * 1.5,
+            LFOSnapValues::WholeT => 4.0 / 3.0,
+            LFOSnapValues::Half => 2.0,
+            LFOSnapValues::HalfD => 2.0 * 1.5,
+            LFOSnapValues::HalfT => 2.0 / 3.0,
+            LFOSnapValues::Quarter => 1.0,
+            LFOSnapValues::QuarterD => 1.0 * 1.5,
+            LFOSnapValues::QuarterT => 1.0 / 3.0,
+            LFOSnapValues::Eighth => 0.5,
+            LFOSnapValues::EighthD => 0.5 * 1.5,
+            LFOSnapValues::EighthT => 0.5 / 3.0,
+            LFOSnapValues::Sixteen => 0.25,
+            LFOSnapValues::SixteenD => 0.25 * 1.5,
+            LFOSnapValues::SixteenT => 0.25 / 3.0,
+            LFOSnapValues::ThirtySecond => 0.125,
+            LFOSnapValues::ThirtySecondD => 0.125 * 1.5,
+            LFOSnapValues::ThirtySecondT => 0.125 / 3.0,
+        }
+    }
+}
+
 impl LFOController {
     pub fn new(frequency: f32, amplitude: f32, waveform: Waveform, phase: f32) -> Self {
         LFOController {
@@ -65,6 +104,9 @@ impl LFOController {
             phase,
             amplitude,
             waveform,
+            one_shot: false,
+            finished: false,
+            frozen: false,
         }
     }
 
@@ -78,6 +120,18 @@ impl LFOController {
 
     pub fn set_phase(&mut self, phase: f32) {
         self.phase = phase;
+        self.finished = false;
+    }
+
+    pub fn set_one_shot(&mut self, one_shot: bool) {
+        if self.one_shot != one_shot {
+            self.one_shot = one_shot;
+            self.finished = false;
+        }
+    }
+
+    pub fn set_frozen(&mut self, frozen: bool) {
+        self.frozen = frozen;
     }
 
     pub fn get_frequency(&mut self) -> f32 {
@@ -90,42 +144,59 @@ impl LFOController {
 
     pub fn next_sample(&mut self, sample_rate: f32) -> f32 {
         let delta_time = 1.0 / sample_rate;
-        self.phase += self.frequency * delta_time;
+        if !self.finished && !self.frozen {
+            self.phase += self.frequency * delta_time;
+        }
 
         if self.phase >= 1.0 {
-            self.phase -= 1.0;
+            if self.one_shot {
+                // Clamp to the end of the cycle and hold there until the next reset
+                self.phase = 1.0;
+                self.finished = true;
+            } else {
+                self.phase -= 1.0;
+            }
         }
 
-        match self.waveform {
-            Waveform::Sine => self.amplitude * (2.0 * std::f32::consts::PI * self.phase).sin(),
+        self.amplitude * Self::shape_value(self.waveform, self.phase)
+    }
+
+    pub fn get_phase(&self) -> f32 {
+        self.phase
+    }
+
+    // Unit amplitude waveform at a phase, shared with the GUI position view
+    pub fn shape_value(waveform: Waveform, phase: f32) -> f32 {
+        match waveform {
+            Waveform::Sine => (2.0 * std::f32::consts::PI * phase).sin(),
             Waveform::Triangle => {
-                if self.phase < 0.5 {
-                    4.0 * self.amplitude * self.phase - self.amplitude
+                if phase < 0.5 {
+                    4.0 * phase - 1.0
                 } else {
-                    3.0 * self.amplitude - 4.0 * self.amplitude * self.phase
+                    3.0 - 4.0 * phase
                 }
             }
-            Waveform::Sawtooth => self.amplitude * (1.0 - 2.0 * self.phase),
-            Waveform::Ramp => self.amplitude * self.phase,
+            Waveform::Sawtooth => 1.0 - 2.0 * phase,
+            Waveform::Ramp => phase,
             Waveform::Square => {
-                if self.phase < 0.5 {
-                    self.amplitude
+                if phase < 0.5 {
+                    1.0
                 } else {
-                    -self.amplitude
+                    -1.0
                 }
             }
             Waveform::PulseQuarter => {
-                if self.phase < 0.25 {
-                    self.amplitude
+                if phase < 0.25 {
+                    1.0
                 } else {
-                    -self.amplitude
+                    -1.0
                 }
             }
             Waveform::PulseEigth => {
-                if self.phase < 0.125 {
-                    self.amplitude
+                if phase < 0.125 {
+                    1.0
                 } else {
-                    -self.amplitude
+                    -1.0
                 }
             }
         }
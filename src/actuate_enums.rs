@@ -5,6 +5,8 @@ use std::{fmt, path::PathBuf};
 use nih_plug::params::enums::Enum;
 use serde::{Deserialize, Serialize};
 
+use crate::actuate_structs::ActuatePresetV131;
+
 // For internal preset browser to sort through without manually reading each file
 pub struct PresetBrowserEntry {
     pub PresetCategory: PresetType,
@@ -30,6 +32,108 @@ pub struct PresetBrowserEntry {
     pub _file: PathBuf,
 }
 
+// Categories for the preset browser filter - Select shows everything
+pub const PRESET_CATEGORIES: [PresetType; 11] = [
+    PresetType::Select,
+    PresetType::Atmosphere,
+    PresetType::Bass,
+    PresetType::FX,
+    PresetType::Keys,
+    PresetType::Lead,
+    PresetType::Pad,
+    PresetType::Percussion,
+    PresetType::Pluck,
+    PresetType::Synth,
+    PresetType::Other,
+];
+
+// Tag names in the same order as PresetBrowserEntry::tags() and the browser checkboxes
+pub const PRESET_TAG_NAMES: [&str; 19] = [
+    "Acid", "Analog", "Bright", "Chord", "Crisp", "Deep", "Delicate", "Hard", "Harsh", "Lush",
+    "Mellow", "Resonant", "Rich", "Sharp", "Silky", "Smooth", "Soft", "Stab", "Warm",
+];
+
+impl PresetBrowserEntry {
+    pub fn from_preset(preset: &ActuatePresetV131, file: PathBuf) -> Self {
+        PresetBrowserEntry {
+            PresetCategory: preset.preset_category,
+            tag_acid: preset.tag_acid,
+            tag_analog: preset.tag_analog,
+            tag_bright: preset.tag_bright,
+            tag_chord: preset.tag_chord,
+            tag_crisp: preset.tag_crisp,
+            tag_deep: preset.tag_deep,
+            tag_delicate: preset.tag_delicate,
+            tag_hard: preset.tag_hard,
+            tag_harsh: preset.tag_harsh,
+            tag_lush: preset.tag_lush,
+            tag_mellow: preset.tag_mellow,
+            tag_resonant: preset.tag_resonant,
+            tag_rich: preset.tag_rich,
+            tag_sharp: preset.tag_sharp,
+            tag_silky: preset.tag_silky,
+            tag_smooth: preset.tag_smooth,
+            tag_soft: preset.tag_soft,
+            tag_stab: preset.tag_stab,
+            tag_warm: preset.tag_warm,
+            _file: file,
+        }
+    }
+
+    pub fn tags(&self) -> [bool; 19] {
+        [
+            self.tag_acid,
+            self.tag_analog,
+            self.tag_bright,
+            self.tag_chord,
+            self.tag_crisp,
+            self.tag_deep,
+            self.tag_delicate,
+            self.tag_hard,
+            self.tag_harsh,
+            self.tag_lush,
+            self.tag_mellow,
+            self.tag_resonant,
+            self.tag_rich,
+            self.tag_sharp,
+            self.tag_silky,
+            self.tag_smooth,
+            self.tag_soft,
+            self.tag_stab,
+            self.tag_warm,
+        ]
+    }
+
+    // Every active tag has to be on the preset (AND), category Select means any category
+    pub fn matches_filter(&self, active_tags: &[bool; 19], category: PresetType) -> bool {
+        if category != PresetType::Select && self.PresetCategory != category {
+            return false;
+        }
+        self.tags()
+            .iter()
+            .zip(active_tags.iter())
+            .all(|(has_tag, wanted)| *has_tag || !*wanted)
+    }
+}
+
+// Case-insensitive name search over a bank's preset files, returns the indices that match
+// An empty query matches everything
+pub fn search_presets(bank: &[PathBuf], query: &str) -> Vec<usize> {
+    let query = query.trim().to_lowercase();
+    bank.iter()
+        .enumerate()
+        .filter(|(_, file)| {
+            query.is_empty()
+                || file
+                    .file_stem()
+                    .and_then(|name| name.to_str())
+                    .map(|name| name.to_lowercase().contains(&query))
+                    .unwrap_or(false)
+        })
+        .map(|(index, _)| index)
+        .collect()
+}
+
 // Gui for which filter to display on bottom
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub enum UIBottomSelection {
@@ -85,6 +189,54 @@ pub enum ModulationDestination {
     Osc2UniDetune,
     Osc3UniDetune,
     UnsetModulation,
+    // Newer destinations go after UnsetModulation so the stored index of every older one stays put
+    Drive_1,
+    Drive_2,
+    Osc1_Morph,
+    Osc2_Morph,
+    Osc3_Morph,
+    Osc1_Shape,
+    Osc2_Shape,
+    Osc3_Shape,
+}
+
+impl ModulationDestination {
+    // Which destination a knob drives, used by the right-click LFO assign menu
+    pub fn from_param_id(id: &str) -> Option<Self> {
+        match id {
+            "filter_cutoff" => Some(ModulationDestination::Cutoff_1),
+            "filter_cutoff_2" => Some(ModulationDestination::Cutoff_2),
+            "filter_resonance" => Some(ModulationDestination::Resonance_1),
+            "filter_resonance_2" => Some(ModulationDestination::Resonance_2),
+            "audio_module_1_level" => Some(ModulationDestination::Osc1_Gain),
+            "audio_module_2_level" => Some(ModulationDestination::Osc2_Gain),
+            "audio_module_3_level" => Some(ModulationDestination::Osc3_Gain),
+            "osc_1_detune" => Some(ModulationDestination::Osc1Detune),
+            "osc_2_detune" => Some(ModulationDestination::Osc2Detune),
+            "osc_3_detune" => Some(ModulationDestination::Osc3Detune),
+            "osc_1_unison_detune" => Some(ModulationDestination::Osc1UniDetune),
+            "osc_2_unison_detune" => Some(ModulationDestination::Osc2UniDetune),
+            "osc_3_unison_detune" => Some(ModulationDestination::Osc3UniDetune),
+            "filter_drive" => Some(ModulationDestination::Drive_1),
+            "filter_drive_2" => Some(ModulationDestination::Drive_2),
+            "osc_1_morph" => Some(ModulationDestination::Osc1_Morph),
+            "osc_2_morph" => Some(ModulationDestination::Osc2_Morph),
+            "osc_3_morph" => Some(ModulationDestination::Osc3_Morph),
+            "osc_1_shape_amount" => Some(ModulationDestination::Osc1_Shape),
+            "osc_2_shape_amount" => Some(ModulationDestination::Osc2_Shape),
+            "osc_3_shape_amount" => Some(ModulationDestination::Osc3_Shape),
+            _ => None,
+        }
+    }
+}
+
+// Scales the detune modulation can snap to when quantized
+#[derive(Debug, Enum, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+pub enum PitchQuantizeScale {
+    Chromatic,
+    Major,
+    Minor,
+    Pentatonic,
 }
 
 // Values for Audio Module Routing to filters
@@ -153,6 +305,54 @@ pub enum PitchRouting {
     Osc2_Osc3,
 }
 
+// Voice modes - Mono always glides from the last pitch, Legato only glides when notes overlap
+#[derive(Enum, PartialEq, Clone, Copy, Serialize, Deserialize)]
+pub enum MonoMode {
+    Poly,
+    Mono,
+    Legato,
+}
+
+// Which voice gets cut when a new note arrives at the voice limit
+#[derive(Enum, PartialEq, Clone, Copy, Serialize, Deserialize)]
+pub enum VoiceStealMode {
+    Oldest,
+    Quietest,
+    Lowest,
+    Highest,
+}
+
+// Envelope time range, Extended stretches attack/decay/release out to around 30 seconds for slow pads
+#[derive(Enum, PartialEq, Clone, Copy, Serialize, Deserialize)]
+pub enum EnvTimeRange {
+    Normal,
+    Extended,
+}
+
+impl EnvTimeRange {
+    // Multiplier on the envelope knob times
+    pub fn time_scale(&self) -> f32 {
+        match self {
+            EnvTimeRange::Normal => 1.0,
+            EnvTimeRange::Extended => 15.0,
+        }
+    }
+}
+
+// Built-in 12 note tunings, the non-equal ones are laid out from the tuning root
+#[derive(Enum, PartialEq, Clone, Copy, Serialize, Deserialize)]
+pub enum TuningSystem {
+    #[name = "12-TET"]
+    TwelveTET,
+    #[name = "Just Major"]
+    JustMajor,
+    #[name = "Just Minor"]
+    JustMinor,
+    Pythagorean,
+    #[name = "1/4 Comma"]
+    QuarterComma,
+}
+
 #[derive(Enum, PartialEq, Clone, Copy, Serialize, Deserialize)]
 pub enum StereoAlgorithm {
     Original,
@@ -184,4 +384,43 @@ impl fmt::Display for AMFilterRouting {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{:?}", self)
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bank() -> Vec<PathBuf> {
+        vec![
+            PathBuf::from("presets/Acid Bass.actuate"),
+            PathBuf::from("presets/Warm Pad.actuate"),
+            PathBuf::from("presets/ACID lead.actuate"),
+            PathBuf::from("presets/Pluck.actuate"),
+        ]
+    }
+
+    #[test]
+    fn search_presets_ignores_case() {
+        assert_eq!(search_presets(&bank(), "acid"), vec![0, 2]);
+        assert_eq!(search_presets(&bank(), "WARM PAD"), vec![1]);
+    }
+
+    #[test]
+    fn search_presets_matches_part_of_the_name() {
+        assert_eq!(search_presets(&bank(), "luc"), vec![3]);
+        assert_eq!(search_presets(&bank(), "a"), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn search_presets_empty_query_returns_everything() {
+        assert_eq!(search_presets(&bank(), ""), vec![0, 1, 2, 3]);
+        assert_eq!(search_presets(&bank(), "   "), vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn search_presets_no_matches() {
+        assert!(search_presets(&bank(), "organ").is_empty());
+        // The extension isn't part of the name
+        assert!(search_presets(&bank(), "actuate").is_empty());
+    }
+}
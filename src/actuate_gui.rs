@@ -2,17 +2,17 @@
 // Builds the EGUI editor outside of the main file because it is huge
 // Ardura
 
-use std::{collections::HashMap, ffi::OsStr, ops::RangeInclusive, path::{Path, PathBuf}, sync::{atomic::{AtomicBool, Ordering}, Arc, Mutex, RwLock}};
+use std::{collections::HashMap, ffi::OsStr, ops::RangeInclusive, path::{Path, PathBuf}, sync::{atomic::{AtomicBool, AtomicI32, AtomicU32, Ordering}, Arc, Mutex, RwLock}};
 use egui_file::{FileDialog, State};
-use nih_plug::{context::gui::AsyncExecutor, editor::Editor, nih_log};
+use nih_plug::{context::gui::AsyncExecutor, editor::Editor, nih_log, prelude::{AtomicF32, FloatParam, Param, ParamSetter, Params}, util};
 use nih_plug_egui::{create_egui_editor, egui::{self, Color32, Pos2, Rect, RichText, Rounding, ScrollArea, Vec2}, widgets::ParamSlider};
 use walkdir::WalkDir;
 
-use crate::{actuate_enums::PresetBrowserEntry, CustomWidgets::ComboBoxParam};
+use crate::{actuate_enums::{search_presets, PresetBrowserEntry, PRESET_CATEGORIES, PRESET_TAG_NAMES}, actuate_keyboard::OnScreenKeyboard, actuate_midi_learn::{apply_midi_learn, param_ptr_map}, actuate_undo::UndoHistory, CustomWidgets::ComboBoxParam};
 #[allow(unused_imports)]
 use crate::{
     actuate_enums::{
-        AMFilterRouting, FilterAlgorithms, LFOSelect, ModulationDestination, ModulationSource, PresetType, UIBottomSelection}, actuate_structs::ActuatePresetV131, audio_module::{AudioModule, AudioModuleType}, Actuate, ActuateParams, CustomWidgets::{
+        AMFilterRouting, FilterAlgorithms, LFOSelect, ModulationDestination, ModulationSource, PresetType, UIBottomSelection}, actuate_structs::{ABCompare, ActuatePresetV131}, audio_module::{output_capture::OutputCapture, AudioModule, AudioModuleType}, Actuate, LFOController::{LFOController, Waveform}, ActuateParams, CustomWidgets::{
             slim_checkbox, toggle_switch, ui_knob::{self, KnobLayout}, BeizerButton::{self, ButtonLayout}, BoolButton, CustomParamSlider, CustomVerticalSlider::ParamSlider as VerticalParamSlider}, A_BACKGROUND_COLOR_TOP, DARKER_GREY_UI_COLOR, DARKEST_BOTTOM_UI_COLOR, DARK_GREY_UI_COLOR, FONT, FONT_COLOR, HEIGHT, LIGHTER_GREY_UI_COLOR, MEDIUM_GREY_UI_COLOR, SMALLER_FONT, TEAL_GREEN, WIDTH, YELLOW_MUSTARD};
 
 pub(crate) fn make_actuate_gui(instance: &mut Actuate, _async_executor: AsyncExecutor<Actuate>) -> Option<Box<dyn Editor>> {
@@ -29,15 +29,34 @@ pub(crate) fn make_actuate_gui(instance: &mut Actuate, _async_executor: AsyncExe
         //let import_bank_active: Arc<AtomicBool> = Arc::clone(&instance.importing_banks);
         //let export_bank_active: Arc<AtomicBool> = Arc::clone(&instance.exporting_banks);
         let safety_clip_output: Arc<Mutex<bool>> = Arc::clone(&instance.safety_clip_output);
+        let output_peak: Arc<AtomicF32> = Arc::clone(&instance.output_peak);
+        let output_clipped: Arc<AtomicBool> = Arc::clone(&instance.output_clipped);
+        let output_correlation: Arc<AtomicF32> = Arc::clone(&instance.output_correlation);
+        let module_peaks: [Arc<AtomicF32>; 3] = instance.module_peaks.clone();
+        let lfo_phases: [Arc<AtomicF32>; 3] = instance.lfo_phases.clone();
+        let lfo_values: [Arc<AtomicF32>; 3] = instance.lfo_values.clone();
+        let mod_values: [Arc<AtomicF32>; 8] = instance.mod_values.clone();
+        let current_bpm: Arc<AtomicF32> = Arc::clone(&instance.current_bpm);
         //let current_preset: Arc<AtomicU32> = Arc::clone(&instance.current_preset);
         let AM1: Arc<Mutex<AudioModule>> = Arc::clone(&instance.audio_module_1);
         let AM2: Arc<Mutex<AudioModule>> = Arc::clone(&instance.audio_module_2);
         let AM3: Arc<Mutex<AudioModule>> = Arc::clone(&instance.audio_module_3);
+        let output_capture: Arc<Mutex<OutputCapture>> = Arc::clone(&instance.output_capture);
 
         let update_current_preset: Arc<AtomicBool> = Arc::clone(&instance.update_current_preset);
+        let pending_program: Arc<AtomicI32> = Arc::clone(&instance.pending_program);
+        let pending_cc: Arc<Vec<AtomicF32>> = Arc::clone(&instance.pending_cc);
+        let param_ptrs = param_ptr_map(&instance.params);
+        let on_screen_keyboard: Arc<Mutex<OnScreenKeyboard>> = Arc::new(Mutex::new(OnScreenKeyboard::new(Arc::clone(&instance.gui_note_queue))));
+        let keyboard_active: Arc<AtomicBool> = Arc::new(AtomicBool::new(false));
+        let file_dialog: Arc<AtomicBool> = Arc::clone(&instance.file_dialog);
+        let file_open_buffer_timer: Arc<AtomicU32> = Arc::clone(&instance.file_open_buffer_timer);
+        let ab_compare: Arc<Mutex<ABCompare>> = Arc::new(Mutex::new(ABCompare { active: 0, slots: [None, None] }));
         let filter_select_outside: Arc<Mutex<UIBottomSelection>> =
             Arc::new(Mutex::new(UIBottomSelection::Filter1));
         let lfo_select_outside: Arc<Mutex<LFOSelect>> = Arc::new(Mutex::new(LFOSelect::INFO));
+        let browser_category: Arc<Mutex<PresetType>> = Arc::new(Mutex::new(PresetType::Select));
+        let browser_search: Arc<Mutex<String>> = Arc::new(Mutex::new(String::new()));
 
         let filter_acid = instance.filter_acid.clone();
         let filter_analog = instance.filter_analog.clone();
@@ -121,29 +140,7 @@ pub(crate) fn make_actuate_gui(instance: &mut Actuate, _async_executor: AsyncExe
                             lite_db_write.entry(parent_dir.file_name().unwrap().to_str().unwrap().to_string()).or_insert_with(HashMap::new)
                                 .insert(
                                     path.file_name().unwrap().to_str().unwrap().to_string().replace(".actuate", ""),
-                                    PresetBrowserEntry {
-                                        PresetCategory: current_import.preset_category,
-                                        tag_acid: current_import.tag_acid,
-                                        tag_analog: current_import.tag_analog,
-                                        tag_bright: current_import.tag_bright,
-                                        tag_chord: current_import.tag_chord,
-                                        tag_crisp: current_import.tag_crisp,
-                                        tag_deep: current_import.tag_deep,
-                                        tag_delicate: current_import.tag_delicate,
-                                        tag_hard: current_import.tag_hard,
-                                        tag_harsh: current_import.tag_harsh,
-                                        tag_lush: current_import.tag_lush,
-                                        tag_mellow: current_import.tag_mellow,
-                                        tag_resonant: current_import.tag_resonant,
-                                        tag_rich: current_import.tag_rich,
-                                        tag_sharp: current_import.tag_sharp,
-                                        tag_silky: current_import.tag_silky,
-                                        tag_smooth: current_import.tag_smooth,
-                                        tag_soft: current_import.tag_soft,
-                                        tag_stab: current_import.tag_stab,
-                                        tag_warm: current_import.tag_warm,
-                                        _file: path.to_path_buf(),
-                                    });
+                                    PresetBrowserEntry::from_preset(&current_import, path.to_path_buf()));
                         }
                     }
                 }
@@ -270,12 +267,57 @@ pub(crate) fn make_actuate_gui(instance: &mut Actuate, _async_executor: AsyncExe
                 )
         );
 
+        let render_filter = Box::new({
+            let ext = Some(OsStr::new("wav"));
+            move |path: &Path| -> bool { path.extension() == ext }
+        });
+        let render_dialog_main: Arc<Mutex<FileDialog>> = Arc::new(
+            Mutex::new(
+                FileDialog::save_file(Some(home_dir.clone()))
+                    .show_files_filter(render_filter)
+                    .keep_on_top(true)
+                    .show_new_folder(false)
+                    .show_rename(false)
+                )
+        );
+        let render_preview_active: Arc<AtomicBool> = Arc::new(AtomicBool::new(false));
+        let mod_curve_filter = Box::new({
+            let ext = Some(OsStr::new("csv"));
+            move |path: &Path| -> bool { path.extension() == ext }
+        });
+        let mod_curve_dialog_main: Arc<Mutex<FileDialog>> = Arc::new(
+            Mutex::new(
+                FileDialog::save_file(Some(home_dir.clone()))
+                    .show_files_filter(mod_curve_filter)
+                    .keep_on_top(true)
+                    .show_new_folder(false)
+                    .show_rename(false)
+                )
+        );
+        let mod_curve_export_active: Arc<AtomicBool> = Arc::new(AtomicBool::new(false));
+        let import_folder_dialog: Arc<Mutex<FileDialog>> = Arc::new(
+            Mutex::new(
+                FileDialog::select_folder(Some(home_dir.clone()))
+                    .keep_on_top(true)
+                    .show_new_folder(false)
+                    .show_rename(false)
+                )
+        );
+        let import_folder_active: Arc<AtomicBool> = Arc::new(AtomicBool::new(false));
+        // Result of the last folder import, shown beside the button
+        let import_folder_status: Arc<Mutex<String>> = Arc::new(Mutex::new(String::new()));
+
+        // Ctrl+Z / Ctrl+Y history for GUI param changes
+        let undo_history: Arc<Mutex<UndoHistory>> = Arc::new(Mutex::new(UndoHistory::new(params.as_ref())));
+
         // Do our GUI stuff. Store this to later get parent window handle from it
         create_egui_editor(
             instance.params.editor_state.clone(),
             (),
             |_, _| {},
             move |egui_ctx, setter, _state| {
+                undo_history.lock().unwrap().track(egui_ctx, setter.raw_context);
+
                 egui::CentralPanel::default()
                     .show(egui_ctx, |ui| {
                         //let current_preset_index = current_preset.load(Ordering::SeqCst);
@@ -390,9 +432,85 @@ pub(crate) fn make_actuate_gui(instance: &mut Actuate, _async_executor: AsyncExe
                             setter.set_parameter(&params.param_update_current_preset, false);
                             update_current_preset.store(false, Ordering::SeqCst);
                         }
+
+                        // MIDI program change - the number is the preset's position in the current bank
+                        let program = pending_program.swap(-1, Ordering::SeqCst);
+                        if program >= 0 {
+                            let presetfile = str_files_map.lock().unwrap()
+                                .get(&*bank_current_value.read().unwrap())
+                                .and_then(|row| row.get(program as usize).cloned());
+                            if let Some(presetfile) = presetfile {
+                                let unserialized: Option<ActuatePresetV131>;
+                                (_, unserialized) = Actuate::import_preset(Some(presetfile));
+
+                                if let Some(unserialized) = unserialized {
+                                    // Stop our current voices
+                                    clear_voices.store(true, Ordering::SeqCst);
+
+                                    let mut locked_lib = arc_preset.lock().unwrap();
+                                    *locked_lib = unserialized;
+                                    *params.preset_name_p.lock().unwrap() = locked_lib.preset_name.clone();
+                                    *params.preset_info_p.lock().unwrap() = locked_lib.preset_info.clone();
+                                    *params.preset_author_p.lock().unwrap() = locked_lib.preset_author.clone();
+                                    *params.preset_created_p.lock().unwrap() = locked_lib.preset_created.clone();
+                                    setter.set_parameter(&params.preset_category, locked_lib.preset_category);
+                                    drop(locked_lib);
+
+                                    Actuate::reload_entire_preset(
+                                        setter,
+                                        params.clone(),
+                                        arc_preset.lock().unwrap().clone(),
+                                        &mut AM1.lock().unwrap(),
+                                        &mut AM2.lock().unwrap(),
+                                        &mut AM3.lock().unwrap(),);
+                                    // This is set for the process thread
+                                    reload_entire_preset.store(true, Ordering::SeqCst);
+                                }
+                            }
+                        }
+                        // MIDI learn also runs from the task executor, this picks up learning while the editor is open
+                        apply_midi_learn(&params, &param_ptrs, &pending_cc, setter.raw_context);
+
                         if params.filter_cutoff_link.value() {
                             setter.set_parameter(&params.filter_cutoff_2, params.filter_cutoff.value());
                         }
+                        if params.filter_res_link.value() {
+                            if params.filter_resonance_2.value() != params.filter_resonance.value() {
+                                setter.set_parameter(&params.filter_resonance_2, params.filter_resonance.value());
+                            }
+                            if params.filter_res_type_2.value() != params.filter_res_type.value() {
+                                setter.set_parameter(&params.filter_res_type_2, params.filter_res_type.value());
+                            }
+                        }
+                        if params.filter_env_link.value() {
+                            if params.filter_env_peak_2.value() != params.filter_env_peak.value() {
+                                setter.set_parameter(&params.filter_env_peak_2, params.filter_env_peak.value());
+                            }
+                            if params.filter_env_invert_2.value() != params.filter_env_invert_1.value() {
+                                setter.set_parameter(&params.filter_env_invert_2, params.filter_env_invert_1.value());
+                            }
+                            if params.filter_env_attack_2.value() != params.filter_env_attack.value() {
+                                setter.set_parameter(&params.filter_env_attack_2, params.filter_env_attack.value());
+                            }
+                            if params.filter_env_decay_2.value() != params.filter_env_decay.value() {
+                                setter.set_parameter(&params.filter_env_decay_2, params.filter_env_decay.value());
+                            }
+                            if params.filter_env_sustain_2.value() != params.filter_env_sustain.value() {
+                                setter.set_parameter(&params.filter_env_sustain_2, params.filter_env_sustain.value());
+                            }
+                            if params.filter_env_release_2.value() != params.filter_env_release.value() {
+                                setter.set_parameter(&params.filter_env_release_2, params.filter_env_release.value());
+                            }
+                            if params.filter_env_atk_curve_2.value() != params.filter_env_atk_curve.value() {
+                                setter.set_parameter(&params.filter_env_atk_curve_2, params.filter_env_atk_curve.value());
+                            }
+                            if params.filter_env_dec_curve_2.value() != params.filter_env_dec_curve.value() {
+                                setter.set_parameter(&params.filter_env_dec_curve_2, params.filter_env_dec_curve.value());
+                            }
+                            if params.filter_env_rel_curve_2.value() != params.filter_env_rel_curve.value() {
+                                setter.set_parameter(&params.filter_env_rel_curve_2, params.filter_env_rel_curve.value());
+                            }
+                        }
 
                         // Assign default colors
                         ui.style_mut().visuals.widgets.inactive.bg_stroke.color = TEAL_GREEN;
@@ -521,17 +639,82 @@ pub(crate) fn make_actuate_gui(instance: &mut Actuate, _async_executor: AsyncExe
                                         .set_hover_text("Master volume level for Actuate".to_string());
                                     ui.add(master_knob);
 
+                                    // Output meter with a sticky clip light - click the meter to reset the clip
+                                    let (meter_rect, meter_response) = ui.allocate_exact_size(Vec2::new(54.0, 10.0), egui::Sense::click());
+                                    let peak_db = util::gain_to_db(output_peak.load(Ordering::Relaxed).max(0.000001));
+                                    let meter_fill = ((peak_db + 60.0) / 60.0).clamp(0.0, 1.0);
+                                    let clipped = output_clipped.load(Ordering::Relaxed);
+                                    let bar_rect = Rect::from_min_max(meter_rect.min, Pos2::new(meter_rect.max.x - 12.0, meter_rect.max.y));
+                                    ui.painter().rect_filled(bar_rect, Rounding::from(2.0), DARK_GREY_UI_COLOR);
+                                    ui.painter().rect_filled(
+                                        Rect::from_min_max(bar_rect.min, Pos2::new(bar_rect.min.x + bar_rect.width() * meter_fill, bar_rect.max.y)),
+                                        Rounding::from(2.0),
+                                        if peak_db > -3.0 { YELLOW_MUSTARD } else { TEAL_GREEN });
+                                    ui.painter().circle_filled(
+                                        Pos2::new(meter_rect.max.x - 5.0, meter_rect.center().y),
+                                        4.0,
+                                        if clipped { Color32::RED } else { DARK_GREY_UI_COLOR });
+                                    if meter_response.on_hover_text("Output peak level. The light turns red if the output went over 0dB, click to reset").clicked() {
+                                        output_clipped.store(false, Ordering::Relaxed);
+                                    }
+
+                                    // Correlation meter - the marker sits left of center when the output goes out of phase
+                                    let (corr_rect, corr_response) = ui.allocate_exact_size(Vec2::new(42.0, 10.0), egui::Sense::hover());
+                                    let correlation = output_correlation.load(Ordering::Relaxed);
+                                    ui.painter().rect_filled(corr_rect, Rounding::from(2.0), DARK_GREY_UI_COLOR);
+                                    ui.painter().vline(corr_rect.center().x, corr_rect.y_range(), egui::Stroke::new(1.0, LIGHTER_GREY_UI_COLOR));
+                                    ui.painter().circle_filled(
+                                        Pos2::new(corr_rect.min.x + 4.0 + (corr_rect.width() - 8.0) * (correlation + 1.0) * 0.5, corr_rect.center().y),
+                                        4.0,
+                                        if correlation < 0.0 { Color32::RED } else { TEAL_GREEN });
+                                    corr_response.on_hover_text(format!("Stereo correlation: {:.2}
+1 is mono, 0 is wide, below 0 the sides cancel when summed to mono", correlation));
+
                                     ui.separator();
                                     let browse = ui.button(RichText::new("Browse Presets")
                                         .font(FONT)
                                         .background_color(YELLOW_MUSTARD.linear_multiply(1.1))
                                         .color(DARKEST_BOTTOM_UI_COLOR)
                                     );
+                                    let keys = ui.button(RichText::new("Keys")
+                                        .font(FONT)
+                                        .background_color(A_BACKGROUND_COLOR_TOP)
+                                        .color(FONT_COLOR)
+                                    ).on_hover_text("Show a keyboard you can play with the mouse or the Z-M row of your computer keyboard");
+                                    let mut show_edits = ui_knob::highlight_edits(egui_ctx);
+                                    if ui.toggle_value(&mut show_edits, RichText::new("Edits").font(FONT))
+                                        .on_hover_text("Ring every knob that has been changed from the default patch")
+                                        .changed() {
+                                        ui_knob::set_highlight_edits(egui_ctx, show_edits);
+                                    }
                                     ui.separator();
                                     ui.selectable_value(&mut *lfo_select.lock().unwrap(), LFOSelect::INFO, RichText::new("Preset Info").background_color(DARKEST_BOTTOM_UI_COLOR).font(SMALLER_FONT));
                                     if browse.clicked() {
                                         browse_preset_active.store(true, Ordering::SeqCst);
                                     }
+                                    if keys.clicked() {
+                                        keyboard_active.fetch_xor(true, Ordering::SeqCst);
+                                        if !keyboard_active.load(Ordering::SeqCst) {
+                                            on_screen_keyboard.lock().unwrap().release_all();
+                                        }
+                                    }
+                                    if keyboard_active.load(Ordering::SeqCst) {
+                                        let mut keyboard = on_screen_keyboard.lock().unwrap();
+                                        keyboard.track_keys(egui_ctx);
+                                        egui::Window::new("Keyboard")
+                                            .id(egui::Id::new("on_screen_keyboard_window"))
+                                            .resizable(false)
+                                            .collapsible(false)
+                                            .title_bar(false)
+                                            .fixed_pos(Pos2::new(
+                                                (WIDTH as f32/ 2.0) - 150.0,
+                                                HEIGHT as f32 - 110.0))
+                                            .fade_in(false)
+                                            .fade_out(false)
+                                            .show(egui_ctx, |ui| {
+                                                keyboard.show(ui);
+                                            });
+                                    }
                                     if browse_preset_active.load(Ordering::SeqCst) {
                                         let window = egui::Window::new("Preset Browser")
                                             .id(egui::Id::new("browse_presets_window"))
@@ -607,6 +790,29 @@ pub(crate) fn make_actuate_gui(instance: &mut Actuate, _async_executor: AsyncExe
                                                     let warm = slim_checkbox::AtomicSlimCheckbox::new(&filter_warm, "Warm");
                                                     ui.add(warm);
                                                 });
+                                                ui.horizontal(|ui|{
+                                                    ui.label(RichText::new("Category:")
+                                                        .font(FONT)
+                                                        .background_color(A_BACKGROUND_COLOR_TOP)
+                                                        .color(FONT_COLOR));
+                                                    let mut category_filter = browser_category.lock().unwrap();
+                                                    egui::ComboBox::from_id_source("browser_category")
+                                                        .selected_text(format!("{:?}", *category_filter).replace("Select", "Any"))
+                                                        .show_ui(ui, |ui| {
+                                                            for category in PRESET_CATEGORIES {
+                                                                let category_name = format!("{:?}", category).replace("Select", "Any");
+                                                                ui.selectable_value(&mut *category_filter, category, category_name);
+                                                            }
+                                                        });
+                                                    ui.separator();
+                                                    ui.label(RichText::new("Search:")
+                                                        .font(FONT)
+                                                        .background_color(A_BACKGROUND_COLOR_TOP)
+                                                        .color(FONT_COLOR));
+                                                    ui.add(egui::TextEdit::singleline(&mut *browser_search.lock().unwrap())
+                                                        .desired_width(160.0)
+                                                        .hint_text("Preset name"));
+                                                });
                                             });
 
                                             ui.separator();
@@ -648,295 +854,105 @@ pub(crate) fn make_actuate_gui(instance: &mut Actuate, _async_executor: AsyncExe
                                                                 .background_color(A_BACKGROUND_COLOR_TOP)
                                                                 .color(FONT_COLOR));
                                                             ui.end_row();
-                                                            // No filters are checked
-                                                            if  !filter_acid.load(Ordering::SeqCst) &&
-                                                                !filter_analog.load(Ordering::SeqCst) &&
-                                                                !filter_bright.load(Ordering::SeqCst) &&
-                                                                !filter_chord.load(Ordering::SeqCst) &&
-                                                                !filter_crisp.load(Ordering::SeqCst) &&
-                                                                !filter_deep.load(Ordering::SeqCst) &&
-                                                                !filter_delicate.load(Ordering::SeqCst) &&
-                                                                !filter_hard.load(Ordering::SeqCst) &&
-                                                                !filter_harsh.load(Ordering::SeqCst) &&
-                                                                !filter_lush.load(Ordering::SeqCst) &&
-                                                                !filter_mellow.load(Ordering::SeqCst) &&
-                                                                !filter_resonant.load(Ordering::SeqCst) &&
-                                                                !filter_rich.load(Ordering::SeqCst) &&
-                                                                !filter_sharp.load(Ordering::SeqCst) &&
-                                                                !filter_silky.load(Ordering::SeqCst) &&
-                                                                !filter_smooth.load(Ordering::SeqCst) &&
-                                                                !filter_soft.load(Ordering::SeqCst) &&
-                                                                !filter_stab.load(Ordering::SeqCst) &&
-                                                                !filter_warm.load(Ordering::SeqCst)
-                                                                {
-                                                                    let tmp_val = bank_current_value.read().unwrap();
-                                                                    if let Some(row) = str_files_map.lock().unwrap().get(&*tmp_val) {
-                                                                        //ui.vertical(|ui|{
-                                                                            for (pno, presetfile) in row.iter().enumerate() {
-                                                                                //ui.horizontal(|ui|{
-                                                                                    let unserialized: Option<ActuatePresetV131>;
-                                                                                    let preset_name = presetfile.file_name().unwrap_or(OsStr::new("ERROR")).to_str().unwrap().replace(".actuate", "");
-                                                                                    if ui.button(format!("Load Preset {pno}")).clicked() {
-
-                                                                                        (_, unserialized) = Actuate::import_preset(Some(presetfile.to_path_buf()));
-                                                                                        
-                                                                                        // Stop our current voices
-                                                                                        clear_voices.store(true, Ordering::SeqCst);
-                                                                                    
-                                                                                        // Move to info tab on preset change
-                                                                                        *lfo_select.lock().unwrap() = LFOSelect::INFO;
-
-                                                                                        if unserialized.is_some() {
-                                                                                            let mut locked_lib = arc_preset.lock().unwrap();
-                                                                                            *locked_lib = unserialized.unwrap();
-                                                                                            //let temp_preset = &locked_lib;
-                                                                                            *params.preset_name_p.lock().unwrap() =  locked_lib.preset_name.clone();
-                                                                                            *params.preset_info_p.lock().unwrap() = locked_lib.preset_info.clone();
-                                                                                            setter.set_parameter(&params.preset_category, locked_lib.preset_category);
-                                                                                        
-                                                                                            import_preset_active.store(false, Ordering::SeqCst);
-                                                                                        
-                                                                                            drop(locked_lib);
-                                                                                        
-                                                                                            // GUI thread misses this without this call here for some reason
-                                                                                            Actuate::reload_entire_preset(
-                                                                                                setter,
-                                                                                                params.clone(),
-                                                                                                arc_preset.lock().unwrap().clone(),
-                                                                                                &mut AM1.lock().unwrap(),
-                                                                                                &mut AM2.lock().unwrap(),
-                                                                                                &mut AM3.lock().unwrap(),);
-                                                                                            // This is set for the process thread
-                                                                                            reload_entire_preset.store(true, Ordering::SeqCst);
-                                                                                        }
-                                                                                    }
-                                                                                    // Tags
-                                                                                    if !preset_name.contains("ERROR") {
-                                                                                        let bank_current = bank_current_value.read().unwrap(); // clone the value
-                                                                                        let preset_db_read = lite_db.read().unwrap();
-                                                                                        if let Some(inner_map) = preset_db_read.get(&*bank_current) {
-                                                                                            if let Some(tag_unwrap) = inner_map.get(&preset_name) {
-                                                                                                ui.label(preset_name.trim());
-                                                                                                ui.label(format!("{:?}",tag_unwrap.PresetCategory.clone()).trim());
-                                                                                                ui.horizontal(|ui|{
-                                                                                                    if tag_unwrap.tag_acid {
-                                                                                                        ui.label("Acid");
-                                                                                                    }
-                                                                                                    if tag_unwrap.tag_analog {
-                                                                                                        ui.label("Analog");
-                                                                                                    }
-                                                                                                    if tag_unwrap.tag_bright {
-                                                                                                        ui.label("Bright");
-                                                                                                    }
-                                                                                                    if tag_unwrap.tag_chord {
-                                                                                                        ui.label("Chord");
-                                                                                                    }
-                                                                                                    if tag_unwrap.tag_crisp {
-                                                                                                        ui.label("Crisp");
-                                                                                                    }
-                                                                                                    if tag_unwrap.tag_deep {
-                                                                                                        ui.label("Deep");
-                                                                                                    }
-                                                                                                    if tag_unwrap.tag_delicate {
-                                                                                                        ui.label("Delicate");
-                                                                                                    }
-                                                                                                    if tag_unwrap.tag_hard {
-                                                                                                        ui.label("Hard");
-                                                                                                    }
-                                                                                                    if tag_unwrap.tag_harsh {
-                                                                                                        ui.label("Harsh");
-                                                                                                    }
-                                                                                                    if tag_unwrap.tag_lush {
-                                                                                                        ui.label("Lush");
-                                                                                                    }
-                                                                                                    if tag_unwrap.tag_mellow {
-                                                                                                        ui.label("Mellow");
-                                                                                                    }
-                                                                                                    if tag_unwrap.tag_resonant {
-                                                                                                        ui.label("Resonant");
-                                                                                                    }
-                                                                                                    if tag_unwrap.tag_rich {
-                                                                                                        ui.label("Rich");
-                                                                                                    }
-                                                                                                    if tag_unwrap.tag_sharp {
-                                                                                                        ui.label("Sharp");
-                                                                                                    }
-                                                                                                    if tag_unwrap.tag_silky {
-                                                                                                        ui.label("Silky");
-                                                                                                    }
-                                                                                                    if tag_unwrap.tag_smooth {
-                                                                                                        ui.label("Smooth");
-                                                                                                    }
-                                                                                                    if tag_unwrap.tag_soft {
-                                                                                                        ui.label("Soft");
-                                                                                                    }
-                                                                                                    if tag_unwrap.tag_stab {
-                                                                                                        ui.label("Stab");
-                                                                                                    }
-                                                                                                    if tag_unwrap.tag_warm {
-                                                                                                        ui.label("Warm");
-                                                                                                    }
-                                                                                                });
-                                                                                            } else {
-                                                                                                ui.label(preset_name.trim());
-                                                                                                ui.label("Error Loading");
-                                                                                            }
-                                                                                        }
-                                                                                    }
-                                                                                ui.end_row();
-                                                                            }
+                                                            // Every checked tag has to be on the preset and the category has to match unless it's Select
+                                                            let active_tags = [
+                                                                filter_acid.load(Ordering::SeqCst),
+                                                                filter_analog.load(Ordering::SeqCst),
+                                                                filter_bright.load(Ordering::SeqCst),
+                                                                filter_chord.load(Ordering::SeqCst),
+                                                                filter_crisp.load(Ordering::SeqCst),
+                                                                filter_deep.load(Ordering::SeqCst),
+                                                                filter_delicate.load(Ordering::SeqCst),
+                                                                filter_hard.load(Ordering::SeqCst),
+                                                                filter_harsh.load(Ordering::SeqCst),
+                                                                filter_lush.load(Ordering::SeqCst),
+                                                                filter_mellow.load(Ordering::SeqCst),
+                                                                filter_resonant.load(Ordering::SeqCst),
+                                                                filter_rich.load(Ordering::SeqCst),
+                                                                filter_sharp.load(Ordering::SeqCst),
+                                                                filter_silky.load(Ordering::SeqCst),
+                                                                filter_smooth.load(Ordering::SeqCst),
+                                                                filter_soft.load(Ordering::SeqCst),
+                                                                filter_stab.load(Ordering::SeqCst),
+                                                                filter_warm.load(Ordering::SeqCst),
+                                                            ];
+                                                            let category_filter = *browser_category.lock().unwrap();
+                                                            let tmp_val = bank_current_value.read().unwrap();
+                                                            if let Some(row) = str_files_map.lock().unwrap().get(&*tmp_val) {
+                                                                let preset_db_read = lite_db.read().unwrap();
+                                                                let bank_db = preset_db_read.get(&*tmp_val);
+                                                                let name_matches = search_presets(row, &browser_search.lock().unwrap());
+                                                                for (pno, presetfile) in row.iter().enumerate() {
+                                                                    if !name_matches.contains(&pno) {
+                                                                        continue;
                                                                     }
-                                                                } else {
-                                                                    // Filter results
-                                                                    let tmp_val = bank_current_value.read().unwrap();
-                                                                    if let Some(row) = str_files_map.lock().unwrap().get(&*tmp_val) {
-                                                                        //ui.vertical(|ui|{
-                                                                            for (pno, presetfile) in row.iter().enumerate() {
-                                                                                //ui.horizontal(|ui|{
-                                                                                    let unserialized: Option<ActuatePresetV131>;
-                                                                                    let preset_name = presetfile.file_name().unwrap_or(OsStr::new("ERROR")).to_str().unwrap().replace(".actuate", "");
-
-                                                                                    if !preset_name.contains("ERROR") {
-                                                                                        let bank_current = bank_current_value.read().unwrap(); // clone the value
-                                                                                        let preset_db_read = lite_db.read().unwrap();
-                                                                                        if let Some(inner_map) = preset_db_read.get(&*bank_current) {
-                                                                                            if let Some(preset) = inner_map.get(&preset_name) {
-                                                                                                if (filter_acid.load(Ordering::SeqCst) && preset.tag_acid == true) ||
-                                                                                                    (filter_analog.load(Ordering::SeqCst) && preset.tag_analog == true) ||
-                                                                                                    (filter_bright.load(Ordering::SeqCst) && preset.tag_bright == true) ||
-                                                                                                    (filter_chord.load(Ordering::SeqCst) && preset.tag_chord == true) ||
-                                                                                                    (filter_crisp.load(Ordering::SeqCst) && preset.tag_crisp == true) ||
-                                                                                                    (filter_deep.load(Ordering::SeqCst) && preset.tag_deep == true) ||
-                                                                                                    (filter_delicate.load(Ordering::SeqCst) && preset.tag_delicate == true) ||
-                                                                                                    (filter_hard.load(Ordering::SeqCst) && preset.tag_hard == true) ||
-                                                                                                    (filter_harsh.load(Ordering::SeqCst) && preset.tag_harsh == true) ||
-                                                                                                    (filter_lush.load(Ordering::SeqCst) && preset.tag_lush == true) ||
-                                                                                                    (filter_mellow.load(Ordering::SeqCst) && preset.tag_mellow == true) ||
-                                                                                                    (filter_resonant.load(Ordering::SeqCst) && preset.tag_resonant == true) ||
-                                                                                                    (filter_rich.load(Ordering::SeqCst) && preset.tag_rich == true) ||
-                                                                                                    (filter_sharp.load(Ordering::SeqCst) && preset.tag_sharp == true) ||
-                                                                                                    (filter_silky.load(Ordering::SeqCst) && preset.tag_silky == true) ||
-                                                                                                    (filter_smooth.load(Ordering::SeqCst) && preset.tag_smooth == true) ||
-                                                                                                    (filter_soft.load(Ordering::SeqCst) && preset.tag_soft == true) ||
-                                                                                                    (filter_stab.load(Ordering::SeqCst) && preset.tag_stab == true) ||
-                                                                                                    (filter_warm.load(Ordering::SeqCst) && preset.tag_warm == true) {
-                                                                                                    
-                                                                                                        if ui.button(format!("Load Preset {pno}")).clicked() {
-
-                                                                                                            (_, unserialized) = Actuate::import_preset(Some(presetfile.to_path_buf()));
-                                                                                                            
-                                                                                                            // Stop our current voices
-                                                                                                            clear_voices.store(true, Ordering::SeqCst);
-                                                                                                        
-                                                                                                            // Move to info tab on preset change
-                                                                                                            *lfo_select.lock().unwrap() = LFOSelect::INFO;
-                    
-                                                                                                            if unserialized.is_some() {
-                                                                                                                let mut locked_lib = arc_preset.lock().unwrap();
-                                                                                                                *locked_lib = unserialized.unwrap();
-                                                                                                                //let temp_preset = &locked_lib;
-                                                                                                                *params.preset_name_p.lock().unwrap() = locked_lib.preset_name.clone();
-                                                                                                                *params.preset_info_p.lock().unwrap() = locked_lib.preset_info.clone();
-                                                                                                                setter.set_parameter(&params.preset_category, locked_lib.preset_category);
-                                                                                                            
-                                                                                                                import_preset_active.store(false, Ordering::SeqCst);
-                                                                                                            
-                                                                                                                drop(locked_lib);
-                                                                                                            
-                                                                                                                // GUI thread misses this without this call here for some reason
-                                                                                                                Actuate::reload_entire_preset(
-                                                                                                                    setter,
-                                                                                                                    params.clone(),
-                                                                                                                    arc_preset.lock().unwrap().clone(),
-                                                                                                                    &mut AM1.lock().unwrap(),
-                                                                                                                    &mut AM2.lock().unwrap(),
-                                                                                                                    &mut AM3.lock().unwrap(),);
-                                                                                                                // This is set for the process thread
-                                                                                                                reload_entire_preset.store(true, Ordering::SeqCst);
-                                                                                                            }
-                                                                                                        }
-                                                                                                        // Tags
-                                                                                                        if !preset_name.contains("ERROR") {
-                                                                                                            let bank_current = bank_current_value.read().unwrap(); // clone the value
-                                                                                                            let preset_db_read = lite_db.read().unwrap();
-                                                                                                            if let Some(inner_map) = preset_db_read.get(&*bank_current) {
-                                                                                                                if let Some(tag_unwrap) = inner_map.get(&preset_name) {
-                                                                                                                    ui.label(preset_name.trim());
-                                                                                                                    ui.label(format!("{:?}",tag_unwrap.PresetCategory.clone()).trim());
-                                                                                                                    ui.horizontal(|ui|{
-                                                                                                                        if tag_unwrap.tag_acid {
-                                                                                                                            ui.label("Acid");
-                                                                                                                        }
-                                                                                                                        if tag_unwrap.tag_analog {
-                                                                                                                            ui.label("Analog");
-                                                                                                                        }
-                                                                                                                        if tag_unwrap.tag_bright {
-                                                                                                                            ui.label("Bright");
-                                                                                                                        }
-                                                                                                                        if tag_unwrap.tag_chord {
-                                                                                                                            ui.label("Chord");
-                                                                                                                        }
-                                                                                                                        if tag_unwrap.tag_crisp {
-                                                                                                                            ui.label("Crisp");
-                                                                                                                        }
-                                                                                                                        if tag_unwrap.tag_deep {
-                                                                                                                            ui.label("Deep");
-                                                                                                                        }
-                                                                                                                        if tag_unwrap.tag_delicate {
-                                                                                                                            ui.label("Delicate");
-                                                                                                                        }
-                                                                                                                        if tag_unwrap.tag_hard {
-                                                                                                                            ui.label("Hard");
-                                                                                                                        }
-                                                                                                                        if tag_unwrap.tag_harsh {
-                                                                                                                            ui.label("Harsh");
-                                                                                                                        }
-                                                                                                                        if tag_unwrap.tag_lush {
-                                                                                                                            ui.label("Lush");
-                                                                                                                        }
-                                                                                                                        if tag_unwrap.tag_mellow {
-                                                                                                                            ui.label("Mellow");
-                                                                                                                        }
-                                                                                                                        if tag_unwrap.tag_resonant {
-                                                                                                                            ui.label("Resonant");
-                                                                                                                        }
-                                                                                                                        if tag_unwrap.tag_rich {
-                                                                                                                            ui.label("Rich");
-                                                                                                                        }
-                                                                                                                        if tag_unwrap.tag_sharp {
-                                                                                                                            ui.label("Sharp");
-                                                                                                                        }
-                                                                                                                        if tag_unwrap.tag_silky {
-                                                                                                                            ui.label("Silky");
-                                                                                                                        }
-                                                                                                                        if tag_unwrap.tag_smooth {
-                                                                                                                            ui.label("Smooth");
-                                                                                                                        }
-                                                                                                                        if tag_unwrap.tag_soft {
-                                                                                                                            ui.label("Soft");
-                                                                                                                        }
-                                                                                                                        if tag_unwrap.tag_stab {
-                                                                                                                            ui.label("Stab");
-                                                                                                                        }
-                                                                                                                        if tag_unwrap.tag_warm {
-                                                                                                                            ui.label("Warm");
-                                                                                                                        }
-                                                                                                                    });
-                                                                                                                } else {
-                                                                                                                    ui.label(preset_name.trim());
-                                                                                                                    ui.label("Error Loading");
-                                                                                                                }
-                                                                                                            }
-                                                                                                        }
-                                                                                                    ui.end_row();
-                                                                                                }
-                                                                                            }
-                                                                                        }
-                                                                                    }
-                                                                                    //ui.end_row();
-                                                                            }
+                                                                    let preset_name = presetfile.file_name().unwrap_or(OsStr::new("ERROR")).to_str().unwrap().replace(".actuate", "");
+                                                                    let entry = bank_db.and_then(|inner_map| inner_map.get(&preset_name));
+                                                                    let visible = match entry {
+                                                                        Some(entry) => entry.matches_filter(&active_tags, category_filter),
+                                                                        // Presets we couldn't read only show when nothing is filtered
+                                                                        None => !active_tags.contains(&true) && category_filter == PresetType::Select,
+                                                                    };
+                                                                    if !visible {
+                                                                        continue;
+                                                                    }
+
+                                                                    if ui.button(format!("Load Preset {pno}")).clicked() {
+                                                                        let unserialized: Option<ActuatePresetV131>;
+                                                                        (_, unserialized) = Actuate::import_preset(Some(presetfile.to_path_buf()));
+
+                                                                        // Stop our current voices
+                                                                        clear_voices.store(true, Ordering::SeqCst);
+
+                                                                        // Move to info tab on preset change
+                                                                        *lfo_select.lock().unwrap() = LFOSelect::INFO;
+
+                                                                        if unserialized.is_some() {
+                                                                            let mut locked_lib = arc_preset.lock().unwrap();
+                                                                            *locked_lib = unserialized.unwrap();
+                                                                            *params.preset_name_p.lock().unwrap() = locked_lib.preset_name.clone();
+                                                                            *params.preset_info_p.lock().unwrap() = locked_lib.preset_info.clone();
+                                                                            *params.preset_author_p.lock().unwrap() = locked_lib.preset_author.clone();
+                                                                            *params.preset_created_p.lock().unwrap() = locked_lib.preset_created.clone();
+                                                                            setter.set_parameter(&params.preset_category, locked_lib.preset_category);
+
+                                                                            import_preset_active.store(false, Ordering::SeqCst);
+
+                                                                            drop(locked_lib);
+
+                                                                            // GUI thread misses this without this call here for some reason
+                                                                            Actuate::reload_entire_preset(
+                                                                                setter,
+                                                                                params.clone(),
+                                                                                arc_preset.lock().unwrap().clone(),
+                                                                                &mut AM1.lock().unwrap(),
+                                                                                &mut AM2.lock().unwrap(),
+                                                                                &mut AM3.lock().unwrap(),);
+                                                                            // This is set for the process thread
+                                                                            reload_entire_preset.store(true, Ordering::SeqCst);
                                                                         }
                                                                     }
+                                                                    // Tags
+                                                                    match entry {
+                                                                        Some(tag_unwrap) => {
+                                                                            ui.label(preset_name.trim());
+                                                                            ui.label(format!("{:?}",tag_unwrap.PresetCategory.clone()).trim());
+                                                                            ui.horizontal(|ui|{
+                                                                                for (tag_name, tag_on) in PRESET_TAG_NAMES.iter().zip(tag_unwrap.tags()) {
+                                                                                    if tag_on {
+                                                                                        ui.label(*tag_name);
+                                                                                    }
+                                                                                }
+                                                                            });
+                                                                        },
+                                                                        None => {
+                                                                            ui.label(preset_name.trim());
+                                                                            ui.label("Error Loading");
+                                                                        },
+                                                                    }
+                                                                    ui.end_row();
+                                                                }
+                                                            }
                                                                 });
                                                     
                                                     ui.vertical_centered(|ui| {
@@ -1074,6 +1090,9 @@ pub(crate) fn make_actuate_gui(instance: &mut Actuate, _async_executor: AsyncExe
                                     */
                                     let use_fx_toggle = BoolButton::BoolButton::for_param(&params.use_fx, setter, 2.5, 1.0, SMALLER_FONT);
                                     ui.add(use_fx_toggle).on_hover_text("Enable or disable FX processing");
+                                    let dry_kill_toggle = BoolButton::BoolButton::for_param(&params.dry_kill, setter, 2.5, 1.0, SMALLER_FONT);
+                                    ui.add(dry_kill_toggle).on_hover_text("Remove the dry signal so only the delay and reverb tails come out, for return tracks.
+Needs FX on, the FX before the delays still shape what gets sent");
 
                                     // Studio One changes (compatible for all DAWs)
                                     let import_preset_button = ui.button(RichText::new("Import Preset")
@@ -1103,6 +1122,8 @@ pub(crate) fn make_actuate_gui(instance: &mut Actuate, _async_executor: AsyncExe
                                                     let temp_preset = &locked_lib;
                                                     *params.preset_name_p.lock().unwrap() =  temp_preset.preset_name.clone();
                                                     *params.preset_info_p.lock().unwrap() = temp_preset.preset_info.clone();
+                                                    *params.preset_author_p.lock().unwrap() = temp_preset.preset_author.clone();
+                                                    *params.preset_created_p.lock().unwrap() = temp_preset.preset_created.clone();
                                                     setter.set_parameter(&params.preset_category, temp_preset.preset_category);
 
                                                     import_preset_active.store(false, Ordering::SeqCst);
@@ -1131,6 +1152,54 @@ pub(crate) fn make_actuate_gui(instance: &mut Actuate, _async_executor: AsyncExe
                                         }
 
                                     }
+                                    let import_folder_button = ui.button(RichText::new("Import Folder")
+                                        .font(SMALLER_FONT)
+                                        .background_color(DARK_GREY_UI_COLOR)
+                                        .color(TEAL_GREEN)
+                                    ).on_hover_text(format!("Copy every preset in a folder into the current bank\n{}", import_folder_status.lock().unwrap()));
+                                    if import_folder_button.clicked() {
+                                        import_folder_active.store(true, Ordering::SeqCst);
+                                    }
+                                    if import_folder_active.load(Ordering::SeqCst) {
+                                        let dialock = import_folder_dialog.clone();
+                                        let mut dialog = dialock.lock().unwrap();
+                                        dialog.open();
+                                        if dialog.show(egui_ctx).selected() {
+                                            if let Some(folder) = dialog.path() {
+                                                let bank_name = bank_current_value.read().unwrap().clone();
+                                                let bank_dir = dir_files_map.lock().unwrap()
+                                                    .keys()
+                                                    .find(|dir| dir.file_name().and_then(|name| name.to_str()) == Some(bank_name.as_str()))
+                                                    .cloned();
+                                                if let Some(bank_dir) = bank_dir {
+                                                    let (imported, skipped) = Actuate::import_preset_folder(folder, &bank_dir);
+                                                    let mut lite_db_write = lite_db.write().unwrap();
+                                                    for (file, preset) in imported.iter() {
+                                                        if let Some(files) = dir_files_map.lock().unwrap().get_mut(&bank_dir) {
+                                                            files.push(file.clone());
+                                                        }
+                                                        if let Some(files) = str_files_map.lock().unwrap().get_mut(&bank_name) {
+                                                            files.push(file.clone());
+                                                        }
+                                                        lite_db_write.entry(bank_name.clone()).or_insert_with(HashMap::new)
+                                                            .insert(
+                                                                file.file_name().unwrap().to_str().unwrap().to_string().replace(".actuate", ""),
+                                                                PresetBrowserEntry::from_preset(preset, file.clone()));
+                                                    }
+                                                    *import_folder_status.lock().unwrap() = format!("Imported {} presets into {}, skipped {}", imported.len(), bank_name, skipped);
+                                                } else {
+                                                    *import_folder_status.lock().unwrap() = format!("Couldn't find the {} bank folder", bank_name);
+                                                }
+                                            }
+                                            import_folder_active.store(false, Ordering::SeqCst);
+                                        }
+                                        match dialog.state() {
+                                            State::Cancelled | State::Closed => {
+                                                import_folder_active.store(false, Ordering::SeqCst);
+                                            },
+                                            _ => {}
+                                        }
+                                    }
                                     // Studio One changes (compatible for all DAWs)
                                     let export_preset_button = ui.button(RichText::new("Export Preset")
                                         .font(SMALLER_FONT)
@@ -1164,6 +1233,124 @@ pub(crate) fn make_actuate_gui(instance: &mut Actuate, _async_executor: AsyncExe
                                             }
                                         }
                                     }
+                                    let init_exists = Actuate::user_init_path().map_or(false, |path| path.exists());
+                                    let set_init_button = ui.button(RichText::new("Set as Init")
+                                        .font(SMALLER_FONT)
+                                        .background_color(DARK_GREY_UI_COLOR)
+                                        .color(TEAL_GREEN)
+                                    ).on_hover_text("Save the current patch as the starting patch for new Actuate instances");
+                                    if set_init_button.clicked() {
+                                        let locked_lib = arc_preset.lock().unwrap();
+                                        Actuate::export_preset(Actuate::user_init_path(), locked_lib.clone());
+                                        drop(locked_lib);
+                                    }
+                                    if init_exists {
+                                        let clear_init_button = ui.button(RichText::new("Clear Init")
+                                            .font(SMALLER_FONT)
+                                            .background_color(DARK_GREY_UI_COLOR)
+                                            .color(TEAL_GREEN)
+                                        ).on_hover_text("Go back to the built in default patch for new instances");
+                                        if clear_init_button.clicked() {
+                                            if let Some(path) = Actuate::user_init_path() {
+                                                let _ = std::fs::remove_file(path);
+                                            }
+                                        }
+                                    }
+                                    let render_preview_button = ui.button(RichText::new("Render Preview")
+                                        .font(SMALLER_FONT)
+                                        .background_color(DARK_GREY_UI_COLOR)
+                                        .color(TEAL_GREEN)
+                                    ).on_hover_text("Render a C4 note of the current patch to a wav file for auditioning");
+                                    if render_preview_button.clicked() {
+                                        render_preview_active.store(true, Ordering::SeqCst);
+                                    }
+                                    if render_preview_active.load(Ordering::SeqCst) {
+                                        let render_dialock = render_dialog_main.clone();
+                                        let mut render_dialog = render_dialock.lock().unwrap();
+                                        render_dialog.open();
+                                        let mut dvar = Some(render_dialog);
+                                        if let Some(r_dialog) = &mut dvar {
+                                            if r_dialog.show(egui_ctx).selected() {
+                                              if let Some(file) = r_dialog.path() {
+                                                let saved_file = file.to_path_buf();
+                                                // Render from copies so the live voices keep playing
+                                                let modules = [
+                                                    AM1.lock().unwrap().render_copy(),
+                                                    AM2.lock().unwrap().render_copy(),
+                                                    AM3.lock().unwrap().render_copy(),
+                                                ];
+                                                let render_params = params.clone();
+                                                let bpm = current_bpm.load(Ordering::Relaxed);
+                                                std::thread::spawn(move || {
+                                                    Actuate::render_preview(saved_file, render_params, modules, bpm, 60, 2.0, 2.0);
+                                                });
+                                                render_preview_active.store(false, Ordering::SeqCst);
+                                              }
+                                            }
+
+                                            match r_dialog.state() {
+                                                State::Cancelled | State::Closed => {
+                                                    render_preview_active.store(false, Ordering::SeqCst);
+                                                },
+                                                _ => {}
+                                            }
+                                        }
+                                    }
+                                    // A/B Compare
+                                    let mut ab_lock = ab_compare.lock().unwrap();
+                                    let other_slot = 1 - ab_lock.active;
+                                    let copy_ab_button = ui.button(RichText::new(if other_slot == 1 { "Copy to B" } else { "Copy to A" })
+                                        .font(SMALLER_FONT)
+                                        .background_color(DARK_GREY_UI_COLOR)
+                                        .color(TEAL_GREEN)
+                                    ).on_hover_text("Copy the current patch into the other A/B slot");
+                                    if copy_ab_button.clicked() {
+                                        ab_lock.slots[other_slot] = Some(Actuate::snapshot_preset(
+                                            &params,
+                                            &AM1.lock().unwrap(),
+                                            &AM2.lock().unwrap(),
+                                            &AM3.lock().unwrap()));
+                                    }
+                                    let toggle_ab_button = ui.button(RichText::new(if ab_lock.active == 0 { "A/B: A" } else { "A/B: B" })
+                                        .font(SMALLER_FONT)
+                                        .background_color(DARK_GREY_UI_COLOR)
+                                        .color(YELLOW_MUSTARD)
+                                    ).on_hover_text("Swap between the A and B patch states");
+                                    if toggle_ab_button.clicked() {
+                                        let mut AM1L = AM1.lock().unwrap();
+                                        let mut AM2L = AM2.lock().unwrap();
+                                        let mut AM3L = AM3.lock().unwrap();
+                                        let live = Actuate::snapshot_preset(&params, &AM1L, &AM2L, &AM3L);
+                                        // An empty slot starts out as a copy of what we have now
+                                        let target = ab_lock.slots[other_slot].clone().unwrap_or(live.clone());
+                                        let active_slot = ab_lock.active;
+                                        ab_lock.slots[active_slot] = Some(live);
+                                        ab_lock.slots[other_slot] = Some(target.clone());
+                                        ab_lock.active = other_slot;
+
+                                        // Same buffer as file loading so the process thread skips while we swap
+                                        file_dialog.store(true, Ordering::SeqCst);
+                                        file_open_buffer_timer.store(0, Ordering::SeqCst);
+                                        clear_voices.store(true, Ordering::SeqCst);
+
+                                        *params.preset_name_p.lock().unwrap() = target.preset_name.clone();
+                                        *params.preset_info_p.lock().unwrap() = target.preset_info.clone();
+                                        *params.preset_author_p.lock().unwrap() = target.preset_author.clone();
+                                        *params.preset_created_p.lock().unwrap() = target.preset_created.clone();
+                                        *arc_preset.lock().unwrap() = target.clone();
+
+                                        // Loaded samples come along in the snapshot so they survive the swap
+                                        Actuate::reload_entire_preset(
+                                            setter,
+                                            params.clone(),
+                                            target,
+                                            &mut AM1L,
+                                            &mut AM2L,
+                                            &mut AM3L,);
+                                        // This is set for the process thread
+                                        reload_entire_preset.store(true, Ordering::SeqCst);
+                                    }
+                                    drop(ab_lock);
                                     ui.checkbox(&mut safety_clip_output.lock().unwrap(), "Safety Clip").on_hover_text("Clip the output at 0dB to save your ears/speakers");
                                 });
                                 const KNOB_SIZE: f32 = 28.0;
@@ -1208,6 +1395,14 @@ pub(crate) fn make_actuate_gui(instance: &mut Actuate, _async_executor: AsyncExe
                                                 ],
                                                 "fr1".to_string());
                                                 ui.add(fr1);
+                                                ui.horizontal(|ui|{
+                                                    ui.add(BoolButton::BoolButton::for_param(&params.audio_module_1_mute, setter, 2.0, 0.9, SMALLER_FONT))
+                                                        .on_hover_text("Silence this generator without touching its level");
+                                                    ui.add(BoolButton::BoolButton::for_param(&params.audio_module_1_solo, setter, 2.0, 0.9, SMALLER_FONT))
+                                                        .on_hover_text("Only hear soloed generators, multiple solos play together. Mute still wins over Solo");
+                                                    ui.add(BoolButton::BoolButton::for_param(&params.audio_module_1_phase_invert, setter, 2.0, 0.9, SMALLER_FONT))
+                                                        .on_hover_text("Flip the polarity of this generator's output to cancel or carve against the other layers. FM from it is unchanged");
+                                                });
                                             });
 
                                             let audio_module_1_level_knob = ui_knob::ArcKnob::for_param(
@@ -1220,7 +1415,8 @@ pub(crate) fn make_actuate_gui(instance: &mut Actuate, _async_executor: AsyncExe
                                                 .set_line_color(TEAL_GREEN)
                                                 .set_text_size(TEXT_SIZE).set_hover_text("The output gain of the generator".to_string())
                                                 .use_outline(true);
-                                            ui.add(audio_module_1_level_knob);
+                                            lfo_assign_menu(ui.add(audio_module_1_level_knob), &params.audio_module_1_level, setter, &params, &lfo_select);
+                                            module_meter(ui, module_peaks[0].load(Ordering::Relaxed));
                                         });
                                         ui.add_space(48.0);
 
@@ -1259,6 +1455,14 @@ pub(crate) fn make_actuate_gui(instance: &mut Actuate, _async_executor: AsyncExe
                                                 ],
                                                 "fr2".to_string());
                                                 ui.add(fr2);
+                                                ui.horizontal(|ui|{
+                                                    ui.add(BoolButton::BoolButton::for_param(&params.audio_module_2_mute, setter, 2.0, 0.9, SMALLER_FONT))
+                                                        .on_hover_text("Silence this generator without touching its level");
+                                                    ui.add(BoolButton::BoolButton::for_param(&params.audio_module_2_solo, setter, 2.0, 0.9, SMALLER_FONT))
+                                                        .on_hover_text("Only hear soloed generators, multiple solos play together. Mute still wins over Solo");
+                                                    ui.add(BoolButton::BoolButton::for_param(&params.audio_module_2_phase_invert, setter, 2.0, 0.9, SMALLER_FONT))
+                                                        .on_hover_text("Flip the polarity of this generator's output to cancel or carve against the other layers. FM from it is unchanged");
+                                                });
                                             });
 
                                             let audio_module_2_level_knob = ui_knob::ArcKnob::for_param(
@@ -1270,7 +1474,8 @@ pub(crate) fn make_actuate_gui(instance: &mut Actuate, _async_executor: AsyncExe
                                                 .set_fill_color(DARK_GREY_UI_COLOR)
                                                 .set_line_color(TEAL_GREEN)
                                                 .set_text_size(TEXT_SIZE).set_hover_text("The output gain of the generator".to_string());
-                                            ui.add(audio_module_2_level_knob);
+                                            lfo_assign_menu(ui.add(audio_module_2_level_knob), &params.audio_module_2_level, setter, &params, &lfo_select);
+                                            module_meter(ui, module_peaks[1].load(Ordering::Relaxed));
                                         });
                                         ui.add_space(46.0);
 
@@ -1309,6 +1514,14 @@ pub(crate) fn make_actuate_gui(instance: &mut Actuate, _async_executor: AsyncExe
                                                 ],
                                                 "fr3".to_string());
                                                 ui.add(fr3);
+                                                ui.horizontal(|ui|{
+                                                    ui.add(BoolButton::BoolButton::for_param(&params.audio_module_3_mute, setter, 2.0, 0.9, SMALLER_FONT))
+                                                        .on_hover_text("Silence this generator without touching its level");
+                                                    ui.add(BoolButton::BoolButton::for_param(&params.audio_module_3_solo, setter, 2.0, 0.9, SMALLER_FONT))
+                                                        .on_hover_text("Only hear soloed generators, multiple solos play together. Mute still wins over Solo");
+                                                    ui.add(BoolButton::BoolButton::for_param(&params.audio_module_3_phase_invert, setter, 2.0, 0.9, SMALLER_FONT))
+                                                        .on_hover_text("Flip the polarity of this generator's output to cancel or carve against the other layers. FM from it is unchanged");
+                                                });
                                             });
                                             let audio_module_3_level_knob = ui_knob::ArcKnob::for_param(
                                                 &params.audio_module_3_level,
@@ -1319,7 +1532,8 @@ pub(crate) fn make_actuate_gui(instance: &mut Actuate, _async_executor: AsyncExe
                                                 .set_fill_color(DARK_GREY_UI_COLOR)
                                                 .set_line_color(TEAL_GREEN)
                                                 .set_text_size(TEXT_SIZE).set_hover_text("The output gain of the generator".to_string());
-                                            ui.add(audio_module_3_level_knob);
+                                            lfo_assign_menu(ui.add(audio_module_3_level_knob), &params.audio_module_3_level, setter, &params, &lfo_select);
+                                            module_meter(ui, module_peaks[2].load(Ordering::Relaxed));
                                         });
                                         ui.add_space(32.0);
                                     });
@@ -1328,11 +1542,11 @@ pub(crate) fn make_actuate_gui(instance: &mut Actuate, _async_executor: AsyncExe
                                     ui.vertical(|ui|{
                                         let mut sample_dialog_lock = load_sample_dialog.lock().unwrap();
                                         ui.add_space(12.0);
-                                        AudioModule::draw_module(ui, egui_ctx, setter, params.clone(), &mut sample_dialog_lock, 1, &AM1, &AM2, &AM3);
+                                        AudioModule::draw_module(ui, egui_ctx, setter, params.clone(), &mut sample_dialog_lock, 1, &AM1, &AM2, &AM3, &output_capture);
                                         ui.add_space(10.0);
-                                        AudioModule::draw_module(ui, egui_ctx, setter, params.clone(), &mut sample_dialog_lock, 2, &AM1, &AM2, &AM3);
+                                        AudioModule::draw_module(ui, egui_ctx, setter, params.clone(), &mut sample_dialog_lock, 2, &AM1, &AM2, &AM3, &output_capture);
                                         ui.add_space(10.0);
-                                        AudioModule::draw_module(ui, egui_ctx, setter, params.clone(), &mut sample_dialog_lock, 3, &AM1, &AM2, &AM3);
+                                        AudioModule::draw_module(ui, egui_ctx, setter, params.clone(), &mut sample_dialog_lock, 3, &AM1, &AM2, &AM3, &output_capture);
                                         ui.add_space(4.0);
                                     });
                                 });
@@ -1575,6 +1789,14 @@ pub(crate) fn make_actuate_gui(instance: &mut Actuate, _async_executor: AsyncExe
                                                                 .with_background_color(MEDIUM_GREY_UI_COLOR)
                                                                 .with_line_color(YELLOW_MUSTARD),
                                                             ).on_hover_text_at_pointer("The behavior of Release movement in the envelope".to_string());
+                                                            ui.horizontal(|ui|{
+                                                                ui.label(RichText::new("Invert")
+                                                                    .font(SMALLER_FONT)
+                                                                    .color(FONT_COLOR)
+                                                                )
+                                                                    .on_hover_text("Flip the envelope so the filter closes on attack and opens again on release");
+                                                                ui.add(toggle_switch::ToggleSwitch::for_param(&params.filter_env_invert_1, setter));
+                                                            });
                                                         },
                                                         UIBottomSelection::Filter2 => {
                                                             ui.add(
@@ -1613,6 +1835,14 @@ pub(crate) fn make_actuate_gui(instance: &mut Actuate, _async_executor: AsyncExe
                                                                 .with_background_color(MEDIUM_GREY_UI_COLOR)
                                                                 .with_line_color(YELLOW_MUSTARD),
                                                             ).on_hover_text_at_pointer("The behavior of Release movement in the envelope".to_string());
+                                                            ui.horizontal(|ui|{
+                                                                ui.label(RichText::new("Invert")
+                                                                    .font(SMALLER_FONT)
+                                                                    .color(FONT_COLOR)
+                                                                )
+                                                                    .on_hover_text("Flip the envelope so the filter closes on attack and opens again on release");
+                                                                ui.add(toggle_switch::ToggleSwitch::for_param(&params.filter_env_invert_2, setter));
+                                                            });
                                                         },
                                                         UIBottomSelection::Pitch1 => {
                                                             ui.add(
@@ -1758,7 +1988,7 @@ A4II: Averaged 4 Pole Integrator II".to_string());
                                                                     .set_line_color(YELLOW_MUSTARD)
                                                                     .set_text_size(BTEXT_SIZE)
                                                                     .set_hover_text("Filter resonance/emphasis".to_string());
-                                                                ui.add(filter_resonance_knob);
+                                                                lfo_assign_menu(ui.add(filter_resonance_knob), &params.filter_resonance, setter, &params, &lfo_select);
                                                             });
                                                             ui.vertical(|ui|{
                                                                 let filter_wet_knob = ui_knob::ArcKnob::for_param(
@@ -1806,7 +2036,7 @@ A4II: Averaged 4 Pole Integrator II".to_string());
                                                                     .set_line_color(YELLOW_MUSTARD)
                                                                     .set_text_size(BTEXT_SIZE)
                                                                     .set_hover_text("Filter cutoff/center frequency".to_string());
-                                                                ui.add(filter_cutoff_knob);
+                                                                lfo_assign_menu(ui.add(filter_cutoff_knob), &params.filter_cutoff, setter, &params, &lfo_select);
                                                                 let filter_hp_knob = ui_knob::ArcKnob::for_param(
                                                                     &params.filter_hp_amount,
                                                                     setter,
@@ -1873,7 +2103,7 @@ A4II: Averaged 4 Pole Integrator II".to_string());
                                                                     .set_line_color(YELLOW_MUSTARD)
                                                                     .set_text_size(BTEXT_SIZE)
                                                                     .set_hover_text("Filter resonance/emphasis".to_string());
-                                                                ui.add(filter_resonance_knob);
+                                                                lfo_assign_menu(ui.add(filter_resonance_knob), &params.filter_resonance, setter, &params, &lfo_select);
                                                             });
                                                             ui.vertical(|ui|{
                                                                 let filter_cutoff_knob = ui_knob::ArcKnob::for_param(
@@ -1886,7 +2116,7 @@ A4II: Averaged 4 Pole Integrator II".to_string());
                                                                     .set_line_color(YELLOW_MUSTARD)
                                                                     .set_text_size(BTEXT_SIZE)
                                                                     .set_hover_text("Filter cutoff/center frequency".to_string());
-                                                                ui.add(filter_cutoff_knob);
+                                                                lfo_assign_menu(ui.add(filter_cutoff_knob), &params.filter_cutoff, setter, &params, &lfo_select);
                                                                 let filter_tilt_type_knob = ui_knob::ArcKnob::for_param(
                                                                     &params.tilt_filter_type,
                                                                     setter,
@@ -1955,7 +2185,7 @@ A4II: Averaged 4 Pole Integrator II".to_string());
                                                                     .set_line_color(YELLOW_MUSTARD)
                                                                     .set_text_size(BTEXT_SIZE)
                                                                     .set_hover_text("Filter resonance/emphasis".to_string());
-                                                                ui.add(filter_resonance_knob);
+                                                                lfo_assign_menu(ui.add(filter_resonance_knob), &params.filter_resonance, setter, &params, &lfo_select);
                                                             });
                                                             ui.vertical(|ui|{
                                                                 let filter_cutoff_knob = ui_knob::ArcKnob::for_param(
@@ -1968,7 +2198,7 @@ A4II: Averaged 4 Pole Integrator II".to_string());
                                                                     .set_line_color(YELLOW_MUSTARD)
                                                                     .set_text_size(BTEXT_SIZE)
                                                                     .set_hover_text("Filter cutoff/center frequency".to_string());
-                                                                ui.add(filter_cutoff_knob);
+                                                                lfo_assign_menu(ui.add(filter_cutoff_knob), &params.filter_cutoff, setter, &params, &lfo_select);
                                                                 let vcf_filter_type_knob = ui_knob::ArcKnob::for_param(
                                                                     &params.vcf_filter_type,
                                                                     setter,
@@ -2036,7 +2266,7 @@ A4II: Averaged 4 Pole Integrator II".to_string());
                                                                     .set_line_color(YELLOW_MUSTARD)
                                                                     .set_text_size(BTEXT_SIZE)
                                                                     .set_hover_text("Filter resonance/emphasis".to_string());
-                                                                ui.add(filter_resonance_knob);
+                                                                lfo_assign_menu(ui.add(filter_resonance_knob), &params.filter_resonance, setter, &params, &lfo_select);
                                                             });
                                                             ui.vertical(|ui|{
                                                                 let filter_cutoff_knob = ui_knob::ArcKnob::for_param(
@@ -2049,7 +2279,7 @@ A4II: Averaged 4 Pole Integrator II".to_string());
                                                                     .set_line_color(YELLOW_MUSTARD)
                                                                     .set_text_size(BTEXT_SIZE)
                                                                     .set_hover_text("Filter cutoff/center frequency".to_string());
-                                                                ui.add(filter_cutoff_knob);
+                                                                lfo_assign_menu(ui.add(filter_cutoff_knob), &params.filter_cutoff, setter, &params, &lfo_select);
                                                             });
                                                             ui.vertical(|ui|{
                                                                 let filter_env_peak = ui_knob::ArcKnob::for_param(
@@ -2107,7 +2337,7 @@ A4II: Averaged 4 Pole Integrator II".to_string());
                                                                     .set_line_color(YELLOW_MUSTARD)
                                                                     .set_text_size(BTEXT_SIZE)
                                                                     .set_hover_text("Filter resonance/emphasis".to_string());
-                                                                ui.add(filter_resonance_knob);
+                                                                lfo_assign_menu(ui.add(filter_resonance_knob), &params.filter_resonance, setter, &params, &lfo_select);
                                                             });
                                                             ui.vertical(|ui|{
                                                                 let filter_cutoff_knob = ui_knob::ArcKnob::for_param(
@@ -2120,7 +2350,7 @@ A4II: Averaged 4 Pole Integrator II".to_string());
                                                                     .set_line_color(YELLOW_MUSTARD)
                                                                     .set_text_size(BTEXT_SIZE)
                                                                     .set_hover_text("Filter cutoff/center frequency".to_string());
-                                                                ui.add(filter_cutoff_knob);
+                                                                lfo_assign_menu(ui.add(filter_cutoff_knob), &params.filter_cutoff, setter, &params, &lfo_select);
                                                             });
                                                             ui.vertical(|ui|{
                                                                 let filter_env_peak = ui_knob::ArcKnob::for_param(
@@ -2178,7 +2408,7 @@ A4II: Averaged 4 Pole Integrator II".to_string());
                                                                     .set_line_color(YELLOW_MUSTARD)
                                                                     .set_text_size(BTEXT_SIZE)
                                                                     .set_hover_text("Filter resonance/emphasis".to_string());
-                                                                ui.add(filter_resonance_knob);
+                                                                lfo_assign_menu(ui.add(filter_resonance_knob), &params.filter_resonance, setter, &params, &lfo_select);
                                                             });
                                                             ui.vertical(|ui|{
                                                                 let filter_cutoff_knob = ui_knob::ArcKnob::for_param(
@@ -2191,7 +2421,7 @@ A4II: Averaged 4 Pole Integrator II".to_string());
                                                                     .set_line_color(YELLOW_MUSTARD)
                                                                     .set_text_size(BTEXT_SIZE)
                                                                     .set_hover_text("Filter cutoff/center frequency".to_string());
-                                                                ui.add(filter_cutoff_knob);
+                                                                lfo_assign_menu(ui.add(filter_cutoff_knob), &params.filter_cutoff, setter, &params, &lfo_select);
                                                             });
                                                             ui.vertical(|ui|{
                                                                 let filter_env_peak = ui_knob::ArcKnob::for_param(
@@ -2252,7 +2482,7 @@ A4II: Averaged 4 Pole Integrator II".to_string());
                                                                     .set_line_color(YELLOW_MUSTARD)
                                                                     .set_text_size(BTEXT_SIZE)
                                                                     .set_hover_text("Filter resonance/emphasis".to_string());
-                                                                ui.add(filter_resonance_knob);
+                                                                lfo_assign_menu(ui.add(filter_resonance_knob), &params.filter_resonance_2, setter, &params, &lfo_select);
                                                             });
                                                             ui.vertical(|ui|{
                                                                 let filter_wet_knob = ui_knob::ArcKnob::for_param(
@@ -2298,7 +2528,7 @@ A4II: Averaged 4 Pole Integrator II".to_string());
                                                                     .set_line_color(YELLOW_MUSTARD)
                                                                     .set_text_size(BTEXT_SIZE)
                                                                     .set_hover_text("Filter cutoff/center frequency".to_string());
-                                                                ui.add(filter_cutoff_knob);
+                                                                lfo_assign_menu(ui.add(filter_cutoff_knob), &params.filter_cutoff_2, setter, &params, &lfo_select);
                                                                 let filter_hp_knob = ui_knob::ArcKnob::for_param(
                                                                     &params.filter_hp_amount_2,
                                                                     setter,
@@ -2364,7 +2594,7 @@ A4II: Averaged 4 Pole Integrator II".to_string());
                                                                     .set_line_color(YELLOW_MUSTARD)
                                                                     .set_text_size(BTEXT_SIZE)
                                                                     .set_hover_text("Filter resonance/emphasis".to_string());
-                                                                ui.add(filter_resonance_knob);
+                                                                lfo_assign_menu(ui.add(filter_resonance_knob), &params.filter_resonance_2, setter, &params, &lfo_select);
                                                             });
                                                             ui.vertical(|ui|{
                                                                 let filter_cutoff_knob = ui_knob::ArcKnob::for_param(
@@ -2377,7 +2607,7 @@ A4II: Averaged 4 Pole Integrator II".to_string());
                                                                     .set_line_color(YELLOW_MUSTARD)
                                                                     .set_text_size(BTEXT_SIZE)
                                                                     .set_hover_text("Filter cutoff/center frequency".to_string());
-                                                                ui.add(filter_cutoff_knob);
+                                                                lfo_assign_menu(ui.add(filter_cutoff_knob), &params.filter_cutoff_2, setter, &params, &lfo_select);
                                                                 let filter_tilt_type_knob = ui_knob::ArcKnob::for_param(
                                                                     &params.tilt_filter_type_2,
                                                                     setter,
@@ -2445,7 +2675,7 @@ A4II: Averaged 4 Pole Integrator II".to_string());
                                                                     .set_line_color(YELLOW_MUSTARD)
                                                                     .set_text_size(BTEXT_SIZE)
                                                                     .set_hover_text("Filter resonance/emphasis".to_string());
-                                                                ui.add(filter_resonance_knob);
+                                                                lfo_assign_menu(ui.add(filter_resonance_knob), &params.filter_resonance_2, setter, &params, &lfo_select);
                                                             });
                                                             ui.vertical(|ui|{
                                                                 let filter_cutoff_knob = ui_knob::ArcKnob::for_param(
@@ -2458,7 +2688,7 @@ A4II: Averaged 4 Pole Integrator II".to_string());
                                                                     .set_line_color(YELLOW_MUSTARD)
                                                                     .set_text_size(BTEXT_SIZE)
                                                                     .set_hover_text("Filter cutoff/center frequency".to_string());
-                                                                ui.add(filter_cutoff_knob);
+                                                                lfo_assign_menu(ui.add(filter_cutoff_knob), &params.filter_cutoff_2, setter, &params, &lfo_select);
                                                                 let vcf_filter_type_knob = ui_knob::ArcKnob::for_param(
                                                                     &params.vcf_filter_type_2,
                                                                     setter,
@@ -2527,7 +2757,7 @@ A4II: Averaged 4 Pole Integrator II".to_string());
                                                                     .set_line_color(YELLOW_MUSTARD)
                                                                     .set_text_size(BTEXT_SIZE)
                                                                     .set_hover_text("Filter resonance/emphasis".to_string());
-                                                                ui.add(filter_resonance_knob);
+                                                                lfo_assign_menu(ui.add(filter_resonance_knob), &params.filter_resonance_2, setter, &params, &lfo_select);
                                                             });
                                                             ui.vertical(|ui|{
                                                                 let filter_cutoff_knob = ui_knob::ArcKnob::for_param(
@@ -2540,7 +2770,7 @@ A4II: Averaged 4 Pole Integrator II".to_string());
                                                                     .set_line_color(YELLOW_MUSTARD)
                                                                     .set_text_size(BTEXT_SIZE)
                                                                     .set_hover_text("Filter cutoff/center frequency".to_string());
-                                                                ui.add(filter_cutoff_knob);
+                                                                lfo_assign_menu(ui.add(filter_cutoff_knob), &params.filter_cutoff_2, setter, &params, &lfo_select);
                                                                 let vcf_filter_type_knob = ui_knob::ArcKnob::for_param(
                                                                     &params.vcf_filter_type_2,
                                                                     setter,
@@ -2609,7 +2839,7 @@ A4II: Averaged 4 Pole Integrator II".to_string());
                                                                     .set_line_color(YELLOW_MUSTARD)
                                                                     .set_text_size(BTEXT_SIZE)
                                                                     .set_hover_text("Filter resonance/emphasis".to_string());
-                                                                ui.add(filter_resonance_knob);
+                                                                lfo_assign_menu(ui.add(filter_resonance_knob), &params.filter_resonance_2, setter, &params, &lfo_select);
                                                             });
                                                             ui.vertical(|ui|{
                                                                 let filter_cutoff_knob = ui_knob::ArcKnob::for_param(
@@ -2622,7 +2852,7 @@ A4II: Averaged 4 Pole Integrator II".to_string());
                                                                     .set_line_color(YELLOW_MUSTARD)
                                                                     .set_text_size(BTEXT_SIZE)
                                                                     .set_hover_text("Filter cutoff/center frequency".to_string());
-                                                                ui.add(filter_cutoff_knob);
+                                                                lfo_assign_menu(ui.add(filter_cutoff_knob), &params.filter_cutoff_2, setter, &params, &lfo_select);
                                                             });
                                                             ui.vertical(|ui|{
                                                                 let filter_env_peak = ui_knob::ArcKnob::for_param(
@@ -2680,7 +2910,7 @@ A4II: Averaged 4 Pole Integrator II".to_string());
                                                                     .set_line_color(YELLOW_MUSTARD)
                                                                     .set_text_size(BTEXT_SIZE)
                                                                     .set_hover_text("Filter resonance/emphasis".to_string());
-                                                                ui.add(filter_resonance_knob);
+                                                                lfo_assign_menu(ui.add(filter_resonance_knob), &params.filter_resonance_2, setter, &params, &lfo_select);
                                                             });
                                                             ui.vertical(|ui|{
                                                                 let filter_cutoff_knob = ui_knob::ArcKnob::for_param(
@@ -2693,7 +2923,7 @@ A4II: Averaged 4 Pole Integrator II".to_string());
                                                                     .set_line_color(YELLOW_MUSTARD)
                                                                     .set_text_size(BTEXT_SIZE)
                                                                     .set_hover_text("Filter cutoff/center frequency".to_string());
-                                                                ui.add(filter_cutoff_knob);
+                                                                lfo_assign_menu(ui.add(filter_cutoff_knob), &params.filter_cutoff_2, setter, &params, &lfo_select);
                                                             });
                                                             ui.vertical(|ui|{
                                                                 let filter_env_peak = ui_knob::ArcKnob::for_param(
@@ -2812,6 +3042,13 @@ A4II: Averaged 4 Pole Integrator II".to_string());
                                                         );
                                                         let lfo1_toggle = toggle_switch::ToggleSwitch::for_param(&params.lfo1_enable, setter);
                                                         ui.add(lfo1_toggle);
+                                                        ui.separator();
+                                                        ui.label(RichText::new("Gate")
+                                                            .font(FONT)
+                                                        )
+                                                            .on_hover_text("Only run the LFO while voices are sounding, it holds its value when everything has stopped.
+Sync and Retrig resets still happen on new notes before the LFO starts moving again");
+                                                        ui.add(toggle_switch::ToggleSwitch::for_param(&params.lfo1_gate, setter));
                                                     });
                                                     ui.horizontal(|ui|{
                                                         ui.label(RichText::new("Sync")
@@ -2824,7 +3061,7 @@ A4II: Averaged 4 Pole Integrator II".to_string());
                                                         ui.label(RichText::new("Retrig")
                                                             .font(FONT)
                                                         )
-                                                            .on_hover_text("When to reset the LFO".to_string());
+                                                            .on_hover_text("When to reset the LFO - OneShot runs a single cycle per note then holds".to_string());
                                                         ui.add(ParamSlider::for_param(&params.lfo1_retrigger, setter).with_width(80.0));
                                                     });
                                                     ui.separator();
@@ -2850,6 +3087,19 @@ A4II: Averaged 4 Pole Integrator II".to_string());
                                                         );
                                                         ui.add(ParamSlider::for_param(&params.lfo1_phase, setter).with_width(180.0));
                                                     });
+                                                    ui.horizontal(|ui|{
+                                                        ui.label(RichText::new("Depth")
+                                                            .font(FONT)
+                                                        )
+                                                            .on_hover_text("Base LFO strength - the mod matrix knobs trim this per destination");
+                                                        ui.add(ParamSlider::for_param(&params.lfo1_depth, setter).with_width(180.0));
+                                                    });
+                                                    lfo_position_view(
+                                                        ui,
+                                                        params.lfo1_waveform.value(),
+                                                        params.lfo1_enable.value(),
+                                                        lfo_phases[0].load(Ordering::Relaxed),
+                                                        lfo_values[0].load(Ordering::Relaxed));
                                                 });
                                             },
                                             LFOSelect::LFO2 => {
@@ -2860,6 +3110,13 @@ A4II: Averaged 4 Pole Integrator II".to_string());
                                                         );
                                                         let lfo2_toggle = toggle_switch::ToggleSwitch::for_param(&params.lfo2_enable, setter);
                                                         ui.add(lfo2_toggle);
+                                                        ui.separator();
+                                                        ui.label(RichText::new("Gate")
+                                                            .font(FONT)
+                                                        )
+                                                            .on_hover_text("Only run the LFO while voices are sounding, it holds its value when everything has stopped.
+Sync and Retrig resets still happen on new notes before the LFO starts moving again");
+                                                        ui.add(toggle_switch::ToggleSwitch::for_param(&params.lfo2_gate, setter));
                                                     });
                                                     ui.horizontal(|ui|{
                                                         ui.label(RichText::new("Sync")
@@ -2871,7 +3128,7 @@ A4II: Averaged 4 Pole Integrator II".to_string());
                                                         ui.separator();
                                                         ui.label(RichText::new("Retrig")
                                                             .font(FONT)
-                                                        ).on_hover_text("When to reset the LFO".to_string());
+                                                        ).on_hover_text("When to reset the LFO - OneShot runs a single cycle per note then holds".to_string());
                                                         ui.add(ParamSlider::for_param(&params.lfo2_retrigger, setter).with_width(80.0));
                                                     });
                                                     ui.separator();
@@ -2897,6 +3154,19 @@ A4II: Averaged 4 Pole Integrator II".to_string());
                                                         );
                                                         ui.add(ParamSlider::for_param(&params.lfo2_phase, setter).with_width(180.0));
                                                     });
+                                                    ui.horizontal(|ui|{
+                                                        ui.label(RichText::new("Depth")
+                                                            .font(FONT)
+                                                        )
+                                                            .on_hover_text("Base LFO strength - the mod matrix knobs trim this per destination");
+                                                        ui.add(ParamSlider::for_param(&params.lfo2_depth, setter).with_width(180.0));
+                                                    });
+                                                    lfo_position_view(
+                                                        ui,
+                                                        params.lfo2_waveform.value(),
+                                                        params.lfo2_enable.value(),
+                                                        lfo_phases[1].load(Ordering::Relaxed),
+                                                        lfo_values[1].load(Ordering::Relaxed));
                                                 });
                                             },
                                             LFOSelect::LFO3 => {
@@ -2907,6 +3177,13 @@ A4II: Averaged 4 Pole Integrator II".to_string());
                                                         );
                                                         let lfo3_toggle = toggle_switch::ToggleSwitch::for_param(&params.lfo3_enable, setter);
                                                         ui.add(lfo3_toggle);
+                                                        ui.separator();
+                                                        ui.label(RichText::new("Gate")
+                                                            .font(FONT)
+                                                        )
+                                                            .on_hover_text("Only run the LFO while voices are sounding, it holds its value when everything has stopped.
+Sync and Retrig resets still happen on new notes before the LFO starts moving again");
+                                                        ui.add(toggle_switch::ToggleSwitch::for_param(&params.lfo3_gate, setter));
                                                     });
                                                     ui.horizontal(|ui|{
                                                         ui.label(RichText::new("Sync")
@@ -2918,7 +3195,7 @@ A4II: Averaged 4 Pole Integrator II".to_string());
                                                         ui.separator();
                                                         ui.label(RichText::new("Retrig")
                                                             .font(FONT)
-                                                        ).on_hover_text("When to reset the LFO".to_string());
+                                                        ).on_hover_text("When to reset the LFO - OneShot runs a single cycle per note then holds".to_string());
                                                         ui.add(ParamSlider::for_param(&params.lfo3_retrigger, setter).with_width(80.0));
                                                     });
                                                     ui.separator();
@@ -2944,6 +3221,19 @@ A4II: Averaged 4 Pole Integrator II".to_string());
                                                         );
                                                         ui.add(ParamSlider::for_param(&params.lfo3_phase, setter).with_width(180.0));
                                                     });
+                                                    ui.horizontal(|ui|{
+                                                        ui.label(RichText::new("Depth")
+                                                            .font(FONT)
+                                                        )
+                                                            .on_hover_text("Base LFO strength - the mod matrix knobs trim this per destination");
+                                                        ui.add(ParamSlider::for_param(&params.lfo3_depth, setter).with_width(180.0));
+                                                    });
+                                                    lfo_position_view(
+                                                        ui,
+                                                        params.lfo3_waveform.value(),
+                                                        params.lfo3_enable.value(),
+                                                        lfo_phases[2].load(Ordering::Relaxed),
+                                                        lfo_values[2].load(Ordering::Relaxed));
                                                 });
                                             },
                                             LFOSelect::Misc => {
@@ -2959,6 +3249,28 @@ A4II: Averaged 4 Pole Integrator II".to_string());
                                                         .set_text_size(TEXT_SIZE)
                                                         .set_hover_text("The maximum number of voices that can be playing at once".to_string());
                                                     ui.add(max_voice_knob);
+                                                    let humanize_knob = ui_knob::ArcKnob::for_param(
+                                                        &params.humanize,
+                                                        setter,
+                                                        11.0,
+                                                        KnobLayout::HorizontalInline)
+                                                        .preset_style(ui_knob::KnobStyle::Preset1)
+                                                        .set_fill_color(DARK_GREY_UI_COLOR)
+                                                        .set_line_color(YELLOW_MUSTARD)
+                                                        .set_text_size(TEXT_SIZE)
+                                                        .set_hover_text("Small random timing, velocity and pitch changes on each new note".to_string());
+                                                    ui.add(humanize_knob);
+                                                    let release_vel_knob = ui_knob::ArcKnob::for_param(
+                                                        &params.release_vel_amount,
+                                                        setter,
+                                                        11.0,
+                                                        KnobLayout::HorizontalInline)
+                                                        .preset_style(ui_knob::KnobStyle::Preset1)
+                                                        .set_fill_color(DARK_GREY_UI_COLOR)
+                                                        .set_line_color(YELLOW_MUSTARD)
+                                                        .set_text_size(TEXT_SIZE)
+                                                        .set_hover_text("How much note-off velocity changes release times. Fast releases shorten the tail, slow ones lengthen it".to_string());
+                                                    ui.add(release_vel_knob);
                                                     ui.separator();
                                                     ui.horizontal(|ui|{
                                                         ui.label(RichText::new("Link Cutoff 2 to Cutoff 1")
@@ -2968,6 +3280,67 @@ A4II: Averaged 4 Pole Integrator II".to_string());
                                                         let filter_cutoff_link = toggle_switch::ToggleSwitch::for_param(&params.filter_cutoff_link, setter);
                                                         ui.add(filter_cutoff_link);
                                                     });
+                                                    ui.horizontal(|ui|{
+                                                        ui.label(RichText::new("Link Res 2 to Res 1")
+                                                            .font(FONT)
+                                                        )
+                                                            .on_hover_text("Filter 1 will control both resonance amounts and types");
+                                                        let filter_res_link = toggle_switch::ToggleSwitch::for_param(&params.filter_res_link, setter);
+                                                        ui.add(filter_res_link);
+                                                    });
+                                                    ui.horizontal(|ui|{
+                                                        ui.label(RichText::new("Link Env 2 to Env 1")
+                                                            .font(FONT)
+                                                        )
+                                                            .on_hover_text("Filter 1's envelope will control both filter envelopes");
+                                                        let filter_env_link = toggle_switch::ToggleSwitch::for_param(&params.filter_env_link, setter);
+                                                        ui.add(filter_env_link);
+                                                    });
+                                                    ui.horizontal(|ui|{
+                                                        ui.label(RichText::new("Paraphonic")
+                                                            .font(FONT)
+                                                        )
+                                                            .on_hover_text("Every new note restarts the filter envelopes of the notes you're holding, like a paraphonic synth with one shared envelope.
+Released notes keep their own tails. Off gives each note its own filter movement");
+                                                        let paraphonic = toggle_switch::ToggleSwitch::for_param(&params.paraphonic, setter);
+                                                        ui.add(paraphonic);
+                                                    });
+                                                    ui.horizontal(|ui|{
+                                                        ui.label(RichText::new("Filter 1 Drive")
+                                                            .font(FONT)
+                                                        )
+                                                            .on_hover_text("Saturate the signal going into Filter 1");
+                                                        lfo_assign_menu(ui.add(ParamSlider::for_param(&params.filter_drive, setter).with_width(180.0)), &params.filter_drive, setter, &params, &lfo_select);
+                                                    });
+                                                    ui.horizontal(|ui|{
+                                                        ui.label(RichText::new("Filter 2 Drive")
+                                                            .font(FONT)
+                                                        )
+                                                            .on_hover_text("Saturate the signal going into Filter 2");
+                                                        lfo_assign_menu(ui.add(ParamSlider::for_param(&params.filter_drive_2, setter).with_width(180.0)), &params.filter_drive_2, setter, &params, &lfo_select);
+                                                    });
+                                                    ui.horizontal(|ui|{
+                                                        ui.label(RichText::new("Filter 1 Stereo")
+                                                            .font(FONT)
+                                                        )
+                                                            .on_hover_text("Shift the right channel's Filter 1 cutoff by semitones for a wider, more analog stereo filter. Lovely with resonance up, 0 keeps both sides the same");
+                                                        ui.add(ParamSlider::for_param(&params.filter_stereo_offset_1, setter).with_width(180.0));
+                                                    });
+                                                    ui.horizontal(|ui|{
+                                                        ui.label(RichText::new("Filter 2 Stereo")
+                                                            .font(FONT)
+                                                        )
+                                                            .on_hover_text("Shift the right channel's Filter 2 cutoff by semitones for a wider, more analog stereo filter. Lovely with resonance up, 0 keeps both sides the same");
+                                                        ui.add(ParamSlider::for_param(&params.filter_stereo_offset_2, setter).with_width(180.0));
+                                                    });
+                                                    ui.horizontal(|ui|{
+                                                        ui.label(RichText::new("Filter Auto Gain")
+                                                            .font(FONT)
+                                                        )
+                                                            .on_hover_text("Keep the SVF output level roughly constant as the cutoff, resonance and LP/BP/HP mix change");
+                                                        let filter_auto_gain = toggle_switch::ToggleSwitch::for_param(&params.filter_auto_gain, setter);
+                                                        ui.add(filter_auto_gain);
+                                                    });
                                                     ui.separator();
                                                     ui.horizontal(|ui|{
                                                         ui.label(RichText::new("Stereo Behavior")
@@ -2976,49 +3349,230 @@ A4II: Averaged 4 Pole Integrator II".to_string());
                                                             .on_hover_text("The stereo algorithm to use for voice spreads");
                                                         ui.add(ParamSlider::for_param(&params.stereo_algorithm, setter).with_width(180.0));
                                                     }); 
-                                                });
-                                            },
-                                            LFOSelect::FM => {
-                                                ui.horizontal(|ui|{
-                                                    ui.vertical(|ui|{
-                                                        let fm_one_to_two = ui_knob::ArcKnob::for_param(
-                                                            &params.fm_one_to_two,
-                                                            setter,
-                                                            28.0,
-                                                            KnobLayout::Horizonal)
-                                                                .preset_style(ui_knob::KnobStyle::Preset1)
-                                                                .set_fill_color(DARK_GREY_UI_COLOR)
-                                                                .set_line_color(TEAL_GREEN)
-                                                                .set_show_label(true)
-                                                                .set_text_size(10.0)
-                                                                .set_hover_text("The amount Generator 1 modulates generator 2".to_string());
-                                                        ui.add(fm_one_to_two);
-                                                        let fm_one_to_three = ui_knob::ArcKnob::for_param(
-                                                            &params.fm_one_to_three,
-                                                            setter,
-                                                            28.0,
-                                                            KnobLayout::Horizonal)
-                                                                .preset_style(ui_knob::KnobStyle::Preset1)
-                                                                .set_fill_color(DARK_GREY_UI_COLOR)
-                                                                .set_line_color(TEAL_GREEN)
-                                                                .set_show_label(true)
-                                                                .set_text_size(10.0)
-                                                                .set_hover_text("The amount Generator 1 modulates generator 3".to_string());
-                                                        ui.add(fm_one_to_three);
-                                                        let fm_two_to_three = ui_knob::ArcKnob::for_param(
-                                                            &params.fm_two_to_three,
-                                                            setter,
-                                                            28.0,
-                                                            KnobLayout::Horizonal)
-                                                                .preset_style(ui_knob::KnobStyle::Preset1)
-                                                                .set_fill_color(DARK_GREY_UI_COLOR)
-                                                                .set_line_color(TEAL_GREEN)
-                                                                .set_show_label(true)
-                                                                .set_text_size(10.0)
-                                                                .set_hover_text("The amount Generator 2 modulates generator 3".to_string());
-                                                        ui.add(fm_two_to_three);
-                                                    });
-                                                    // ADSR for FM Signal
+                                                    ui.horizontal(|ui|{
+                                                        ui.label(RichText::new("Voice Mode")
+                                                            .font(FONT)
+                                                        )
+                                                            .on_hover_text("Poly: every note gets its own voice
+Mono: one note at a time, always glides from the last note
+Legato: one note at a time, only glides when notes overlap");
+                                                        ui.add(ParamSlider::for_param(&params.mono_mode, setter).with_width(180.0));
+                                                    });
+                                                    ui.horizontal(|ui|{
+                                                        ui.label(RichText::new("Voice Steal")
+                                                            .font(FONT)
+                                                        )
+                                                            .on_hover_text("Which voice is cut when a new note arrives at Max Voices:
+Oldest: the note held the longest
+Quietest: the voice with the lowest level, usually a fading tail
+Lowest/Highest: the lowest or highest pitched note");
+                                                        ui.add(ParamSlider::for_param(&params.voice_steal_mode, setter).with_width(180.0));
+                                                    });
+                                                    ui.horizontal(|ui|{
+                                                        ui.label(RichText::new("Live Wave Switch")
+                                                            .font(FONT)
+                                                        )
+                                                            .on_hover_text("On: changing an oscillator's waveform crossfades held notes to the new one over 5ms
+Off: held notes keep their waveform and the change starts with the next note");
+                                                        ui.add(ParamSlider::for_param(&params.live_waveform_switch, setter).with_width(180.0));
+                                                    });
+                                                    ui.horizontal(|ui|{
+                                                        ui.label(RichText::new("Env Times")
+                                                            .font(FONT)
+                                                        )
+                                                            .on_hover_text("Extended stretches the attack, decay and release of the generator and filter envelopes by 15x, up to about 30 seconds");
+                                                        ui.add(ParamSlider::for_param(&params.env_time_range, setter).with_width(180.0));
+                                                    });
+                                                    ui.horizontal(|ui|{
+                                                        ui.label(RichText::new("Cutoff Smooth")
+                                                            .font(FONT)
+                                                        )
+                                                            .on_hover_text("Glides cutoff knob and automation changes on both filters to stop zipper noise, 0 is instant");
+                                                        ui.add(ParamSlider::for_param(&params.filter_cutoff_smooth, setter).with_width(180.0));
+                                                    });
+                                                    ui.horizontal(|ui|{
+                                                        ui.label(RichText::new("Glide Time")
+                                                            .font(FONT)
+                                                        )
+                                                            .on_hover_text("How long Mono/Legato notes take to slide to the new pitch");
+                                                        ui.add(ParamSlider::for_param(&params.glide_time, setter).with_width(180.0));
+                                                    });
+                                                    ui.horizontal(|ui|{
+                                                        ui.label(RichText::new("Osc Quality")
+                                                            .font(FONT)
+                                                        )
+                                                            .on_hover_text("Oscillator anti-aliasing. Draft is lightest on CPU, High smooths the aliasing on saws and squares for bounces");
+                                                        ui.add(ParamSlider::for_param(&params.osc_quality, setter).with_width(180.0));
+                                                    });
+                                                    ui.separator();
+                                                    ui.horizontal(|ui|{
+                                                        ui.label(RichText::new(format!("Tempo: {:.1} BPM", current_bpm.load(Ordering::Relaxed)))
+                                                            .font(FONT)
+                                                        )
+                                                            .on_hover_text("The tempo LFO and delay sync are using right now");
+                                                        ui.label(RichText::new("Manual")
+                                                            .font(FONT)
+                                                        )
+                                                            .on_hover_text("Ignore the host tempo and use the BPM here. Useful standalone or without a host clock");
+                                                        let use_manual_bpm = toggle_switch::ToggleSwitch::for_param(&params.use_manual_bpm, setter);
+                                                        ui.add(use_manual_bpm);
+                                                        ui.add(ParamSlider::for_param(&params.manual_bpm, setter).with_width(80.0));
+                                                    });
+                                                    ui.horizontal(|ui|{
+                                                        ui.label(RichText::new("Eco Mode")
+                                                            .font(FONT)
+                                                        )
+                                                            .on_hover_text("Lower CPU for live sets: SVF filters stop oversampling and the reverb uses the Default model.
+High SVF cutoffs get capped while this is on");
+                                                        let eco_mode = toggle_switch::ToggleSwitch::for_param(&params.eco_mode, setter);
+                                                        ui.add(eco_mode);
+                                                    });
+                                                    ui.horizontal(|ui|{
+                                                        ui.label(RichText::new("Global Phase Reset")
+                                                            .font(FONT)
+                                                        )
+                                                            .on_hover_text("Start every oscillator and its unison voices at the same phase on each note.
+Keeps layered attacks lined up, overrides each oscillator's Retrig setting");
+                                                        let global_phase_reset = toggle_switch::ToggleSwitch::for_param(&params.global_phase_reset, setter);
+                                                        ui.add(global_phase_reset);
+                                                    });
+                                                    ui.horizontal(|ui|{
+                                                        ui.label(RichText::new("Voice Spread")
+                                                            .font(FONT)
+                                                        )
+                                                            .on_hover_text("Pans each played note by pitch: low notes lean left, high notes lean right.
+Chords and pads fan out across the stereo field, 0% keeps every note centered");
+                                                        ui.add(ParamSlider::for_param(&params.voice_spread, setter).with_width(180.0));
+                                                    });
+                                                    ui.horizontal(|ui|{
+                                                        ui.label(RichText::new("Tuning")
+                                                            .font(FONT)
+                                                        )
+                                                            .on_hover_text("Built-in 12 note tunings for the oscillators and additive modules.
+12-TET is standard tuning, the others are pure intervals laid out from the Tuning Root");
+                                                        ui.add(ParamSlider::for_param(&params.tuning_system, setter).with_width(88.0));
+                                                        ui.add(ParamSlider::for_param(&params.tuning_root, setter).with_width(88.0));
+                                                    });
+                                                    ui.horizontal(|ui|{
+                                                        ui.label(RichText::new("A4 Pitch")
+                                                            .font(FONT)
+                                                        )
+                                                            .on_hover_text("Reference pitch for the oscillators and additive modules, 432 for A=432Hz tuning.
+Samples keep their own pitch");
+                                                        ui.add(ParamSlider::for_param(&params.reference_pitch, setter).with_width(180.0));
+                                                    });
+                                                    ui.horizontal(|ui|{
+                                                        ui.label(RichText::new("Note Cents")
+                                                            .font(FONT)
+                                                        )
+                                                            .on_hover_text("Nudge each note from C to B by up to 50 cents on top of the Tuning.
+Flatten a third or sour a single key for lo-fi detuning, all centered is standard tuning");
+                                                        for param in params.note_offset_cents() {
+                                                            ui.add(
+                                                                VerticalParamSlider::for_param(param, setter)
+                                                                    .with_width(12.0)
+                                                                    .with_height(36.0)
+                                                                    .set_reversed(true)
+                                                                    .override_colors(LIGHTER_GREY_UI_COLOR, TEAL_GREEN)
+                                                                    .without_value(),
+                                                            ).on_hover_text(format!("{}: {}", param.name(), param));
+                                                        }
+                                                    });
+                                                    ui.horizontal(|ui|{
+                                                        ui.label(RichText::new("Filter Env Loop")
+                                                            .font(FONT)
+                                                        )
+                                                            .on_hover_text("While a key is held, the filter envelopes restart their attack and decay at this note length once they reach sustain");
+                                                        let filter_env_loop = toggle_switch::ToggleSwitch::for_param(&params.filter_env_loop, setter);
+                                                        ui.add(filter_env_loop);
+                                                        ui.add(ParamSlider::for_param(&params.filter_env_loop_rate, setter).with_width(180.0));
+                                                    });
+                                                    ui.horizontal(|ui|{
+                                                        ui.label(RichText::new("MIDI Program Change")
+                                                            .font(FONT)
+                                                        )
+                                                            .on_hover_text("Program change messages load that numbered preset from the current browser bank.
+This needs the Actuate window open to load");
+                                                        let midi_program_change = toggle_switch::ToggleSwitch::for_param(&params.midi_program_change, setter);
+                                                        ui.add(midi_program_change);
+                                                    });
+                                                    ui.horizontal(|ui|{
+                                                        ui.label(RichText::new("Process Audio Input")
+                                                            .font(FONT)
+                                                        )
+                                                            .on_hover_text("Run the host's audio input through the FX alongside the synth. Needs Actuate loaded with a stereo input");
+                                                        let process_input = toggle_switch::ToggleSwitch::for_param(&params.process_input, setter);
+                                                        ui.add(process_input);
+                                                    });
+                                                });
+                                            },
+                                            LFOSelect::FM => {
+                                                ui.horizontal(|ui|{
+                                                    ui.vertical(|ui|{
+                                                        let fm_one_to_two = ui_knob::ArcKnob::for_param(
+                                                            &params.fm_one_to_two,
+                                                            setter,
+                                                            28.0,
+                                                            KnobLayout::Horizonal)
+                                                                .preset_style(ui_knob::KnobStyle::Preset1)
+                                                                .set_fill_color(DARK_GREY_UI_COLOR)
+                                                                .set_line_color(TEAL_GREEN)
+                                                                .set_show_label(true)
+                                                                .set_text_size(10.0)
+                                                                .set_hover_text("The amount Generator 1 modulates generator 2".to_string());
+                                                        ui.add(fm_one_to_two);
+                                                        let fm_one_to_three = ui_knob::ArcKnob::for_param(
+                                                            &params.fm_one_to_three,
+                                                            setter,
+                                                            28.0,
+                                                            KnobLayout::Horizonal)
+                                                                .preset_style(ui_knob::KnobStyle::Preset1)
+                                                                .set_fill_color(DARK_GREY_UI_COLOR)
+                                                                .set_line_color(TEAL_GREEN)
+                                                                .set_show_label(true)
+                                                                .set_text_size(10.0)
+                                                                .set_hover_text("The amount Generator 1 modulates generator 3".to_string());
+                                                        ui.add(fm_one_to_three);
+                                                        let fm_two_to_three = ui_knob::ArcKnob::for_param(
+                                                            &params.fm_two_to_three,
+                                                            setter,
+                                                            28.0,
+                                                            KnobLayout::Horizonal)
+                                                                .preset_style(ui_knob::KnobStyle::Preset1)
+                                                                .set_fill_color(DARK_GREY_UI_COLOR)
+                                                                .set_line_color(TEAL_GREEN)
+                                                                .set_show_label(true)
+                                                                .set_text_size(10.0)
+                                                                .set_hover_text("The amount Generator 2 modulates generator 3".to_string());
+                                                        ui.add(fm_two_to_three);
+                                                    });
+                                                    ui.vertical(|ui|{
+                                                        let ring_mod_1x2 = ui_knob::ArcKnob::for_param(
+                                                            &params.ring_mod_1x2,
+                                                            setter,
+                                                            22.0,
+                                                            KnobLayout::Vertical)
+                                                                .preset_style(ui_knob::KnobStyle::Preset1)
+                                                                .set_fill_color(DARK_GREY_UI_COLOR)
+                                                                .set_line_color(YELLOW_MUSTARD)
+                                                                .set_show_label(true)
+                                                                .set_text_size(10.0)
+                                                                .set_hover_text("Ring modulate Generator 2 by Generator 1".to_string());
+                                                        ui.add(ring_mod_1x2);
+                                                        let ring_mod_2x3 = ui_knob::ArcKnob::for_param(
+                                                            &params.ring_mod_2x3,
+                                                            setter,
+                                                            22.0,
+                                                            KnobLayout::Vertical)
+                                                                .preset_style(ui_knob::KnobStyle::Preset1)
+                                                                .set_fill_color(DARK_GREY_UI_COLOR)
+                                                                .set_line_color(YELLOW_MUSTARD)
+                                                                .set_show_label(true)
+                                                                .set_text_size(10.0)
+                                                                .set_hover_text("Ring modulate Generator 3 by Generator 2".to_string());
+                                                        ui.add(ring_mod_2x3);
+                                                    });
+                                                    // ADSR for FM Signal
                                                     ui.add(
                                                         VerticalParamSlider::for_param(&params.fm_attack, setter)
                                                             .with_width(VERT_BAR_WIDTH)
@@ -3120,201 +3674,516 @@ For constant FM, turn Sustain to 100% and A,D,R to 0%".to_string());
                                             },
                                             LFOSelect::Modulation => {
                                                 ui.vertical(|ui|{
-                                                    // Modulator section 1
-                                                    //////////////////////////////////////////////////////////////////////////////////
-                                                    ui.horizontal(|ui|{
-                                                        let mod_1_knob = ui_knob::ArcKnob::for_param(
-                                                            &params.mod_amount_knob_1,
-                                                            setter,
-                                                            12.0,
-                                                            KnobLayout::SquareNoLabel)
+                                                    // 8 slots don't fit the box so they scroll
+                                                    ScrollArea::vertical()
+                                                        .id_source("mod_slots")
+                                                        .max_height(150.0)
+                                                        .scroll_bar_visibility(egui::scroll_area::ScrollBarVisibility::AlwaysVisible)
+                                                        .show(ui, |ui|{
+                                                        // Modulator section 1
+                                                        //////////////////////////////////////////////////////////////////////////////////
+                                                        ui.horizontal(|ui|{
+                                                            let mod_1_knob = ui_knob::ArcKnob::for_param(
+                                                                &params.mod_amount_knob_1,
+                                                                setter,
+                                                                12.0,
+                                                                KnobLayout::SquareNoLabel)
+                                                                    .preset_style(ui_knob::KnobStyle::Preset2)
+                                                                    .set_fill_color(DARK_GREY_UI_COLOR)
+                                                                    .set_line_color(TEAL_GREEN)
+                                                                    .set_show_label(false);
+                                                            ui.add(mod_1_knob);
+                                                            ui.separator();
+                                                            let ms1 = ComboBoxParam::ParamComboBox::for_param(&params.mod_source_1, setter, vec![
+                                                                String::from("None"),
+                                                                String::from("Velocity"),
+                                                                String::from("LFO1"),
+                                                                String::from("LFO2"),
+                                                                String::from("LFO3"),
+                                                            ],
+                                                            "ms1".to_string());
+                                                            ui.add(ms1);
+                                                            ui.label(RichText::new("Mods")
+                                                                .font(FONT));
+                                                            let md1 = ComboBoxParam::ParamComboBox::for_param(&params.mod_destination_1, setter, vec![
+                                                                String::from("None"),
+                                                                String::from("Cutoff_1"),
+                                                                String::from("Cutoff_2"),
+                                                                String::from("Resonance_1"),
+                                                                String::from("Resonance_2"),
+                                                                String::from("All_Gain"),
+                                                                String::from("Osc1_Gain"),
+                                                                String::from("Osc2_Gain"),
+                                                                String::from("Osc3_Gain"),
+                                                                String::from("All_Detune"),
+                                                                String::from("Osc1Detune"),
+                                                                String::from("Osc2Detune"),
+                                                                String::from("Osc3Detune"),
+                                                                String::from("All_UniDetune"),
+                                                                String::from("Osc1UniDetune"),
+                                                                String::from("Osc2UniDetune"),
+                                                                String::from("Osc3UniDetune"),
+                                                                String::from("Drive_1"),
+                                                                String::from("Drive_2"),
+                                                                String::from("Osc1_Morph"),
+                                                                String::from("Osc2_Morph"),
+                                                                String::from("Osc3_Morph"),
+                                                                String::from("Osc1_Shape"),
+                                                                String::from("Osc2_Shape"),
+                                                                String::from("Osc3_Shape"),
+                                                            ],
+                                                            "md1".to_string());
+                                                            ui.add(md1);
+                                                        });
+                                                        ui.separator();
+
+                                                        // Modulator section 2
+                                                        //////////////////////////////////////////////////////////////////////////////////
+                                                        ui.horizontal(|ui|{
+                                                            let mod_2_knob = ui_knob::ArcKnob::for_param(
+                                                                &params.mod_amount_knob_2,
+                                                                setter,
+                                                                12.0,
+                                                                KnobLayout::SquareNoLabel)
                                                                 .preset_style(ui_knob::KnobStyle::Preset2)
                                                                 .set_fill_color(DARK_GREY_UI_COLOR)
                                                                 .set_line_color(TEAL_GREEN)
                                                                 .set_show_label(false);
-                                                        ui.add(mod_1_knob);
+                                                            ui.add(mod_2_knob);
+                                                            ui.separator();
+                                                            let ms2 = ComboBoxParam::ParamComboBox::for_param(&params.mod_source_2, setter, vec![
+                                                                String::from("None"),
+                                                                String::from("Velocity"),
+                                                                String::from("LFO1"),
+                                                                String::from("LFO2"),
+                                                                String::from("LFO3"),
+                                                            ],
+                                                            "ms2".to_string());
+                                                            ui.add(ms2);
+                                                            ui.label(RichText::new("Mods")
+                                                                .font(FONT));
+                                                            let md2 = ComboBoxParam::ParamComboBox::for_param(&params.mod_destination_2, setter, vec![
+                                                                String::from("None"),
+                                                                String::from("Cutoff_1"),
+                                                                String::from("Cutoff_2"),
+                                                                String::from("Resonance_1"),
+                                                                String::from("Resonance_2"),
+                                                                String::from("All_Gain"),
+                                                                String::from("Osc1_Gain"),
+                                                                String::from("Osc2_Gain"),
+                                                                String::from("Osc3_Gain"),
+                                                                String::from("All_Detune"),
+                                                                String::from("Osc1Detune"),
+                                                                String::from("Osc2Detune"),
+                                                                String::from("Osc3Detune"),
+                                                                String::from("All_UniDetune"),
+                                                                String::from("Osc1UniDetune"),
+                                                                String::from("Osc2UniDetune"),
+                                                                String::from("Osc3UniDetune"),
+                                                                String::from("Drive_1"),
+                                                                String::from("Drive_2"),
+                                                                String::from("Osc1_Morph"),
+                                                                String::from("Osc2_Morph"),
+                                                                String::from("Osc3_Morph"),
+                                                                String::from("Osc1_Shape"),
+                                                                String::from("Osc2_Shape"),
+                                                                String::from("Osc3_Shape"),
+                                                            ],
+                                                            "md2".to_string());
+                                                            ui.add(md2);
+                                                        });
                                                         ui.separator();
-                                                        let ms1 = ComboBoxParam::ParamComboBox::for_param(&params.mod_source_1, setter, vec![
-                                                            String::from("None"),
-                                                            String::from("Velocity"),
-                                                            String::from("LFO1"),
-                                                            String::from("LFO2"),
-                                                            String::from("LFO3"),
-                                                        ],
-                                                        "ms1".to_string());
-                                                        ui.add(ms1);
-                                                        ui.label(RichText::new("Mods")
-                                                            .font(FONT));
-                                                        let md1 = ComboBoxParam::ParamComboBox::for_param(&params.mod_destination_1, setter, vec![
-                                                            String::from("None"),
-                                                            String::from("Cutoff_1"),
-                                                            String::from("Cutoff_2"),
-                                                            String::from("Resonance_1"),
-                                                            String::from("Resonance_2"),
-                                                            String::from("All_Gain"),
-                                                            String::from("Osc1_Gain"),
-                                                            String::from("Osc2_Gain"),
-                                                            String::from("Osc3_Gain"),
-                                                            String::from("All_Detune"),
-                                                            String::from("Osc1Detune"),
-                                                            String::from("Osc2Detune"),
-                                                            String::from("Osc3Detune"),
-                                                            String::from("All_UniDetune"),
-                                                            String::from("Osc1UniDetune"),
-                                                            String::from("Osc2UniDetune"),
-                                                            String::from("Osc3UniDetune"),
-                                                        ],
-                                                        "md1".to_string());
-                                                        ui.add(md1);
-                                                    });
-                                                    ui.separator();
 
-                                                    // Modulator section 2
-                                                    //////////////////////////////////////////////////////////////////////////////////
-                                                    ui.horizontal(|ui|{
-                                                        let mod_2_knob = ui_knob::ArcKnob::for_param(
-                                                            &params.mod_amount_knob_2,
-                                                            setter,
-                                                            12.0,
-                                                            KnobLayout::SquareNoLabel)
-                                                            .preset_style(ui_knob::KnobStyle::Preset2)
-                                                            .set_fill_color(DARK_GREY_UI_COLOR)
-                                                            .set_line_color(TEAL_GREEN)
-                                                            .set_show_label(false);
-                                                        ui.add(mod_2_knob);
+                                                        // Modulator section 3
+                                                        //////////////////////////////////////////////////////////////////////////////////
+                                                        ui.horizontal(|ui|{
+                                                            let mod_3_knob = ui_knob::ArcKnob::for_param(
+                                                                &params.mod_amount_knob_3,
+                                                                setter,
+                                                                12.0,
+                                                                KnobLayout::SquareNoLabel)
+                                                                .preset_style(ui_knob::KnobStyle::Preset2)
+                                                                .set_fill_color(DARK_GREY_UI_COLOR)
+                                                                .set_line_color(TEAL_GREEN)
+                                                                .set_show_label(false);
+                                                            ui.add(mod_3_knob);
+                                                            ui.separator();
+                                                            let ms3 = ComboBoxParam::ParamComboBox::for_param(&params.mod_source_3, setter, vec![
+                                                                String::from("None"),
+                                                                String::from("Velocity"),
+                                                                String::from("LFO1"),
+                                                                String::from("LFO2"),
+                                                                String::from("LFO3"),
+                                                            ],
+                                                            "ms3".to_string());
+                                                            ui.add(ms3);
+                                                            ui.label(RichText::new("Mods")
+                                                                .font(FONT));
+                                                            let md3 = ComboBoxParam::ParamComboBox::for_param(&params.mod_destination_3, setter, vec![
+                                                                String::from("None"),
+                                                                String::from("Cutoff_1"),
+                                                                String::from("Cutoff_2"),
+                                                                String::from("Resonance_1"),
+                                                                String::from("Resonance_2"),
+                                                                String::from("All_Gain"),
+                                                                String::from("Osc1_Gain"),
+                                                                String::from("Osc2_Gain"),
+                                                                String::from("Osc3_Gain"),
+                                                                String::from("All_Detune"),
+                                                                String::from("Osc1Detune"),
+                                                                String::from("Osc2Detune"),
+                                                                String::from("Osc3Detune"),
+                                                                String::from("All_UniDetune"),
+                                                                String::from("Osc1UniDetune"),
+                                                                String::from("Osc2UniDetune"),
+                                                                String::from("Osc3UniDetune"),
+                                                                String::from("Drive_1"),
+                                                                String::from("Drive_2"),
+                                                                String::from("Osc1_Morph"),
+                                                                String::from("Osc2_Morph"),
+                                                                String::from("Osc3_Morph"),
+                                                                String::from("Osc1_Shape"),
+                                                                String::from("Osc2_Shape"),
+                                                                String::from("Osc3_Shape"),
+                                                            ],
+                                                            "md3".to_string());
+                                                            ui.add(md3);
+                                                        });
                                                         ui.separator();
-                                                        let ms2 = ComboBoxParam::ParamComboBox::for_param(&params.mod_source_2, setter, vec![
-                                                            String::from("None"),
-                                                            String::from("Velocity"),
-                                                            String::from("LFO1"),
-                                                            String::from("LFO2"),
-                                                            String::from("LFO3"),
-                                                        ],
-                                                        "ms2".to_string());
-                                                        ui.add(ms2);
-                                                        ui.label(RichText::new("Mods")
-                                                            .font(FONT));
-                                                        let md2 = ComboBoxParam::ParamComboBox::for_param(&params.mod_destination_2, setter, vec![
-                                                            String::from("None"),
-                                                            String::from("Cutoff_1"),
-                                                            String::from("Cutoff_2"),
-                                                            String::from("Resonance_1"),
-                                                            String::from("Resonance_2"),
-                                                            String::from("All_Gain"),
-                                                            String::from("Osc1_Gain"),
-                                                            String::from("Osc2_Gain"),
-                                                            String::from("Osc3_Gain"),
-                                                            String::from("All_Detune"),
-                                                            String::from("Osc1Detune"),
-                                                            String::from("Osc2Detune"),
-                                                            String::from("Osc3Detune"),
-                                                            String::from("All_UniDetune"),
-                                                            String::from("Osc1UniDetune"),
-                                                            String::from("Osc2UniDetune"),
-                                                            String::from("Osc3UniDetune"),
-                                                        ],
-                                                        "md2".to_string());
-                                                        ui.add(md2);
+
+                                                        // Modulator section 4
+                                                        //////////////////////////////////////////////////////////////////////////////////
+                                                        ui.horizontal(|ui|{
+                                                            let mod_4_knob = ui_knob::ArcKnob::for_param(
+                                                                &params.mod_amount_knob_4,
+                                                                setter,
+                                                                12.0,
+                                                                KnobLayout::SquareNoLabel)
+                                                                .preset_style(ui_knob::KnobStyle::Preset2)
+                                                                .set_fill_color(DARK_GREY_UI_COLOR)
+                                                                .set_line_color(TEAL_GREEN)
+                                                                .set_show_label(false);
+                                                            ui.add(mod_4_knob);
+                                                            ui.separator();
+                                                            let ms4 = ComboBoxParam::ParamComboBox::for_param(&params.mod_source_4, setter, vec![
+                                                                String::from("None"),
+                                                                String::from("Velocity"),
+                                                                String::from("LFO1"),
+                                                                String::from("LFO2"),
+                                                                String::from("LFO3"),
+                                                            ],
+                                                            "ms4".to_string());
+                                                            ui.add(ms4);
+                                                            ui.label(RichText::new("Mods")
+                                                                .font(FONT));
+                                                            let md4 = ComboBoxParam::ParamComboBox::for_param(&params.mod_destination_4, setter, vec![
+                                                                String::from("None"),
+                                                                String::from("Cutoff_1"),
+                                                                String::from("Cutoff_2"),
+                                                                String::from("Resonance_1"),
+                                                                String::from("Resonance_2"),
+                                                                String::from("All_Gain"),
+                                                                String::from("Osc1_Gain"),
+                                                                String::from("Osc2_Gain"),
+                                                                String::from("Osc3_Gain"),
+                                                                String::from("All_Detune"),
+                                                                String::from("Osc1Detune"),
+                                                                String::from("Osc2Detune"),
+                                                                String::from("Osc3Detune"),
+                                                                String::from("All_UniDetune"),
+                                                                String::from("Osc1UniDetune"),
+                                                                String::from("Osc2UniDetune"),
+                                                                String::from("Osc3UniDetune"),
+                                                                String::from("Drive_1"),
+                                                                String::from("Drive_2"),
+                                                                String::from("Osc1_Morph"),
+                                                                String::from("Osc2_Morph"),
+                                                                String::from("Osc3_Morph"),
+                                                                String::from("Osc1_Shape"),
+                                                                String::from("Osc2_Shape"),
+                                                                String::from("Osc3_Shape"),
+                                                            ],
+                                                            "md4".to_string());
+                                                            ui.add(md4);
+                                                        });
+                                                        ui.separator();
+
+                                                        // Modulator section 5
+                                                        //////////////////////////////////////////////////////////////////////////////////
+                                                        ui.horizontal(|ui|{
+                                                            let mod_5_knob = ui_knob::ArcKnob::for_param(
+                                                                &params.mod_amount_knob_5,
+                                                                setter,
+                                                                12.0,
+                                                                KnobLayout::SquareNoLabel)
+                                                                .preset_style(ui_knob::KnobStyle::Preset2)
+                                                                .set_fill_color(DARK_GREY_UI_COLOR)
+                                                                .set_line_color(TEAL_GREEN)
+                                                                .set_show_label(false);
+                                                            ui.add(mod_5_knob);
+                                                            ui.separator();
+                                                            let ms5 = ComboBoxParam::ParamComboBox::for_param(&params.mod_source_5, setter, vec![
+                                                                String::from("None"),
+                                                                String::from("Velocity"),
+                                                                String::from("LFO1"),
+                                                                String::from("LFO2"),
+                                                                String::from("LFO3"),
+                                                            ],
+                                                            "ms5".to_string());
+                                                            ui.add(ms5);
+                                                            ui.label(RichText::new("Mods")
+                                                                .font(FONT));
+                                                            let md5 = ComboBoxParam::ParamComboBox::for_param(&params.mod_destination_5, setter, vec![
+                                                                String::from("None"),
+                                                                String::from("Cutoff_1"),
+                                                                String::from("Cutoff_2"),
+                                                                String::from("Resonance_1"),
+                                                                String::from("Resonance_2"),
+                                                                String::from("All_Gain"),
+                                                                String::from("Osc1_Gain"),
+                                                                String::from("Osc2_Gain"),
+                                                                String::from("Osc3_Gain"),
+                                                                String::from("All_Detune"),
+                                                                String::from("Osc1Detune"),
+                                                                String::from("Osc2Detune"),
+                                                                String::from("Osc3Detune"),
+                                                                String::from("All_UniDetune"),
+                                                                String::from("Osc1UniDetune"),
+                                                                String::from("Osc2UniDetune"),
+                                                                String::from("Osc3UniDetune"),
+                                                                String::from("Drive_1"),
+                                                                String::from("Drive_2"),
+                                                                String::from("Osc1_Morph"),
+                                                                String::from("Osc2_Morph"),
+                                                                String::from("Osc3_Morph"),
+                                                                String::from("Osc1_Shape"),
+                                                                String::from("Osc2_Shape"),
+                                                                String::from("Osc3_Shape"),
+                                                            ],
+                                                            "md5".to_string());
+                                                            ui.add(md5);
+                                                        });
+                                                        ui.separator();
+
+                                                        // Modulator section 6
+                                                        //////////////////////////////////////////////////////////////////////////////////
+                                                        ui.horizontal(|ui|{
+                                                            let mod_6_knob = ui_knob::ArcKnob::for_param(
+                                                                &params.mod_amount_knob_6,
+                                                                setter,
+                                                                12.0,
+                                                                KnobLayout::SquareNoLabel)
+                                                                .preset_style(ui_knob::KnobStyle::Preset2)
+                                                                .set_fill_color(DARK_GREY_UI_COLOR)
+                                                                .set_line_color(TEAL_GREEN)
+                                                                .set_show_label(false);
+                                                            ui.add(mod_6_knob);
+                                                            ui.separator();
+                                                            let ms6 = ComboBoxParam::ParamComboBox::for_param(&params.mod_source_6, setter, vec![
+                                                                String::from("None"),
+                                                                String::from("Velocity"),
+                                                                String::from("LFO1"),
+                                                                String::from("LFO2"),
+                                                                String::from("LFO3"),
+                                                            ],
+                                                            "ms6".to_string());
+                                                            ui.add(ms6);
+                                                            ui.label(RichText::new("Mods")
+                                                                .font(FONT));
+                                                            let md6 = ComboBoxParam::ParamComboBox::for_param(&params.mod_destination_6, setter, vec![
+                                                                String::from("None"),
+                                                                String::from("Cutoff_1"),
+                                                                String::from("Cutoff_2"),
+                                                                String::from("Resonance_1"),
+                                                                String::from("Resonance_2"),
+                                                                String::from("All_Gain"),
+                                                                String::from("Osc1_Gain"),
+                                                                String::from("Osc2_Gain"),
+                                                                String::from("Osc3_Gain"),
+                                                                String::from("All_Detune"),
+                                                                String::from("Osc1Detune"),
+                                                                String::from("Osc2Detune"),
+                                                                String::from("Osc3Detune"),
+                                                                String::from("All_UniDetune"),
+                                                                String::from("Osc1UniDetune"),
+                                                                String::from("Osc2UniDetune"),
+                                                                String::from("Osc3UniDetune"),
+                                                                String::from("Drive_1"),
+                                                                String::from("Drive_2"),
+                                                                String::from("Osc1_Morph"),
+                                                                String::from("Osc2_Morph"),
+                                                                String::from("Osc3_Morph"),
+                                                                String::from("Osc1_Shape"),
+                                                                String::from("Osc2_Shape"),
+                                                                String::from("Osc3_Shape"),
+                                                            ],
+                                                            "md6".to_string());
+                                                            ui.add(md6);
+                                                        });
+                                                        ui.separator();
+
+                                                        // Modulator section 7
+                                                        //////////////////////////////////////////////////////////////////////////////////
+                                                        ui.horizontal(|ui|{
+                                                            let mod_7_knob = ui_knob::ArcKnob::for_param(
+                                                                &params.mod_amount_knob_7,
+                                                                setter,
+                                                                12.0,
+                                                                KnobLayout::SquareNoLabel)
+                                                                .preset_style(ui_knob::KnobStyle::Preset2)
+                                                                .set_fill_color(DARK_GREY_UI_COLOR)
+                                                                .set_line_color(TEAL_GREEN)
+                                                                .set_show_label(false);
+                                                            ui.add(mod_7_knob);
+                                                            ui.separator();
+                                                            let ms7 = ComboBoxParam::ParamComboBox::for_param(&params.mod_source_7, setter, vec![
+                                                                String::from("None"),
+                                                                String::from("Velocity"),
+                                                                String::from("LFO1"),
+                                                                String::from("LFO2"),
+                                                                String::from("LFO3"),
+                                                            ],
+                                                            "ms7".to_string());
+                                                            ui.add(ms7);
+                                                            ui.label(RichText::new("Mods")
+                                                                .font(FONT));
+                                                            let md7 = ComboBoxParam::ParamComboBox::for_param(&params.mod_destination_7, setter, vec![
+                                                                String::from("None"),
+                                                                String::from("Cutoff_1"),
+                                                                String::from("Cutoff_2"),
+                                                                String::from("Resonance_1"),
+                                                                String::from("Resonance_2"),
+                                                                String::from("All_Gain"),
+                                                                String::from("Osc1_Gain"),
+                                                                String::from("Osc2_Gain"),
+                                                                String::from("Osc3_Gain"),
+                                                                String::from("All_Detune"),
+                                                                String::from("Osc1Detune"),
+                                                                String::from("Osc2Detune"),
+                                                                String::from("Osc3Detune"),
+                                                                String::from("All_UniDetune"),
+                                                                String::from("Osc1UniDetune"),
+                                                                String::from("Osc2UniDetune"),
+                                                                String::from("Osc3UniDetune"),
+                                                                String::from("Drive_1"),
+                                                                String::from("Drive_2"),
+                                                                String::from("Osc1_Morph"),
+                                                                String::from("Osc2_Morph"),
+                                                                String::from("Osc3_Morph"),
+                                                                String::from("Osc1_Shape"),
+                                                                String::from("Osc2_Shape"),
+                                                                String::from("Osc3_Shape"),
+                                                            ],
+                                                            "md7".to_string());
+                                                            ui.add(md7);
+                                                        });
+                                                        ui.separator();
+
+                                                        // Modulator section 8
+                                                        //////////////////////////////////////////////////////////////////////////////////
+                                                        ui.horizontal(|ui|{
+                                                            let mod_8_knob = ui_knob::ArcKnob::for_param(
+                                                                &params.mod_amount_knob_8,
+                                                                setter,
+                                                                12.0,
+                                                                KnobLayout::SquareNoLabel)
+                                                                .preset_style(ui_knob::KnobStyle::Preset2)
+                                                                .set_fill_color(DARK_GREY_UI_COLOR)
+                                                                .set_line_color(TEAL_GREEN)
+                                                                .set_show_label(false);
+                                                            ui.add(mod_8_knob);
+                                                            ui.separator();
+                                                            let ms8 = ComboBoxParam::ParamComboBox::for_param(&params.mod_source_8, setter, vec![
+                                                                String::from("None"),
+                                                                String::from("Velocity"),
+                                                                String::from("LFO1"),
+                                                                String::from("LFO2"),
+                                                                String::from("LFO3"),
+                                                            ],
+                                                            "ms8".to_string());
+                                                            ui.add(ms8);
+                                                            ui.label(RichText::new("Mods")
+                                                                .font(FONT));
+                                                            let md8 = ComboBoxParam::ParamComboBox::for_param(&params.mod_destination_8, setter, vec![
+                                                                String::from("None"),
+                                                                String::from("Cutoff_1"),
+                                                                String::from("Cutoff_2"),
+                                                                String::from("Resonance_1"),
+                                                                String::from("Resonance_2"),
+                                                                String::from("All_Gain"),
+                                                                String::from("Osc1_Gain"),
+                                                                String::from("Osc2_Gain"),
+                                                                String::from("Osc3_Gain"),
+                                                                String::from("All_Detune"),
+                                                                String::from("Osc1Detune"),
+                                                                String::from("Osc2Detune"),
+                                                                String::from("Osc3Detune"),
+                                                                String::from("All_UniDetune"),
+                                                                String::from("Osc1UniDetune"),
+                                                                String::from("Osc2UniDetune"),
+                                                                String::from("Osc3UniDetune"),
+                                                                String::from("Drive_1"),
+                                                                String::from("Drive_2"),
+                                                                String::from("Osc1_Morph"),
+                                                                String::from("Osc2_Morph"),
+                                                                String::from("Osc3_Morph"),
+                                                                String::from("Osc1_Shape"),
+                                                                String::from("Osc2_Shape"),
+                                                                String::from("Osc3_Shape"),
+                                                            ],
+                                                            "md8".to_string());
+                                                            ui.add(md8);
+                                                        });
                                                     });
                                                     ui.separator();
 
-                                                    // Modulator section 3
-                                                    //////////////////////////////////////////////////////////////////////////////////
                                                     ui.horizontal(|ui|{
-                                                        let mod_3_knob = ui_knob::ArcKnob::for_param(
-                                                            &params.mod_amount_knob_3,
-                                                            setter,
-                                                            12.0,
-                                                            KnobLayout::SquareNoLabel)
-                                                            .preset_style(ui_knob::KnobStyle::Preset2)
-                                                            .set_fill_color(DARK_GREY_UI_COLOR)
-                                                            .set_line_color(TEAL_GREEN)
-                                                            .set_show_label(false);
-                                                        ui.add(mod_3_knob);
-                                                        ui.separator();
-                                                        let ms3 = ComboBoxParam::ParamComboBox::for_param(&params.mod_source_3, setter, vec![
-                                                            String::from("None"),
-                                                            String::from("Velocity"),
-                                                            String::from("LFO1"),
-                                                            String::from("LFO2"),
-                                                            String::from("LFO3"),
-                                                        ],
-                                                        "ms3".to_string());
-                                                        ui.add(ms3);
-                                                        ui.label(RichText::new("Mods")
-                                                            .font(FONT));
-                                                        let md3 = ComboBoxParam::ParamComboBox::for_param(&params.mod_destination_3, setter, vec![
-                                                            String::from("None"),
-                                                            String::from("Cutoff_1"),
-                                                            String::from("Cutoff_2"),
-                                                            String::from("Resonance_1"),
-                                                            String::from("Resonance_2"),
-                                                            String::from("All_Gain"),
-                                                            String::from("Osc1_Gain"),
-                                                            String::from("Osc2_Gain"),
-                                                            String::from("Osc3_Gain"),
-                                                            String::from("All_Detune"),
-                                                            String::from("Osc1Detune"),
-                                                            String::from("Osc2Detune"),
-                                                            String::from("Osc3Detune"),
-                                                            String::from("All_UniDetune"),
-                                                            String::from("Osc1UniDetune"),
-                                                            String::from("Osc2UniDetune"),
-                                                            String::from("Osc3UniDetune"),
-                                                        ],
-                                                        "md3".to_string());
-                                                        ui.add(md3);
+                                                        if ui.button(RichText::new("Freeze Mod").font(FONT))
+                                                            .on_hover_text("Bake where the LFO slots are right now into the knobs they modulate and clear those slots.
+Cutoff, Resonance, Drive, Morph and Shape can be frozen, Gain and Detune slots keep running")
+                                                            .clicked() {
+                                                            freeze_modulation(&params, setter, &mod_values);
+                                                        }
+                                                        if ui.button(RichText::new("Export Mod CSV").font(FONT))
+                                                            .on_hover_text("Write one bar of what the LFO slots do to their knobs at the current tempo as a CSV curve.
+Use it to draw the movement in as host automation")
+                                                            .clicked() {
+                                                            mod_curve_export_active.store(true, Ordering::SeqCst);
+                                                        }
+                                                        if mod_curve_export_active.load(Ordering::SeqCst) {
+                                                            let mut curve_dialog = mod_curve_dialog_main.lock().unwrap();
+                                                            curve_dialog.open();
+                                                            if curve_dialog.show(egui_ctx).selected() {
+                                                                if let Some(file) = curve_dialog.path() {
+                                                                    export_mod_curve(file.to_path_buf(), &params, current_bpm.load(Ordering::Relaxed));
+                                                                    mod_curve_export_active.store(false, Ordering::SeqCst);
+                                                                }
+                                                            }
+                                                            match curve_dialog.state() {
+                                                                State::Cancelled | State::Closed => {
+                                                                    mod_curve_export_active.store(false, Ordering::SeqCst);
+                                                                },
+                                                                _ => {}
+                                                            }
+                                                        }
                                                     });
                                                     ui.separator();
 
-                                                    // Modulator section 4
-                                                    //////////////////////////////////////////////////////////////////////////////////
                                                     ui.horizontal(|ui|{
-                                                        let mod_4_knob = ui_knob::ArcKnob::for_param(
-                                                            &params.mod_amount_knob_4,
-                                                            setter,
-                                                            12.0,
-                                                            KnobLayout::SquareNoLabel)
-                                                            .preset_style(ui_knob::KnobStyle::Preset2)
-                                                            .set_fill_color(DARK_GREY_UI_COLOR)
-                                                            .set_line_color(TEAL_GREEN)
-                                                            .set_show_label(false);
-                                                        ui.add(mod_4_knob);
-                                                        ui.separator();
-                                                        let ms4 = ComboBoxParam::ParamComboBox::for_param(&params.mod_source_4, setter, vec![
-                                                            String::from("None"),
-                                                            String::from("Velocity"),
-                                                            String::from("LFO1"),
-                                                            String::from("LFO2"),
-                                                            String::from("LFO3"),
-                                                        ],
-                                                        "ms4".to_string());
-                                                        ui.add(ms4);
-                                                        ui.label(RichText::new("Mods")
-                                                            .font(FONT));
-                                                        let md4 = ComboBoxParam::ParamComboBox::for_param(&params.mod_destination_4, setter, vec![
-                                                            String::from("None"),
-                                                            String::from("Cutoff_1"),
-                                                            String::from("Cutoff_2"),
-                                                            String::from("Resonance_1"),
-                                                            String::from("Resonance_2"),
-                                                            String::from("All_Gain"),
-                                                            String::from("Osc1_Gain"),
-                                                            String::from("Osc2_Gain"),
-                                                            String::from("Osc3_Gain"),
-                                                            String::from("All_Detune"),
-                                                            String::from("Osc1Detune"),
-                                                            String::from("Osc2Detune"),
-                                                            String::from("Osc3Detune"),
-                                                            String::from("All_UniDetune"),
-                                                            String::from("Osc1UniDetune"),
-                                                            String::from("Osc2UniDetune"),
-                                                            String::from("Osc3UniDetune"),
+                                                        ui.label(RichText::new("Quantize Pitch Mod")
+                                                            .font(FONT))
+                                                            .on_hover_text("Snap detune modulation to whole semitones so LFOs step between notes");
+                                                        let pitch_quantize_toggle = toggle_switch::ToggleSwitch::for_param(&params.pitch_mod_quantize, setter);
+                                                        ui.add(pitch_quantize_toggle);
+                                                        let pitch_scale = ComboBoxParam::ParamComboBox::for_param(&params.pitch_mod_scale, setter, vec![
+                                                            String::from("Chromatic"),
+                                                            String::from("Major"),
+                                                            String::from("Minor"),
+                                                            String::from("Pentatonic"),
                                                         ],
-                                                        "md4".to_string());
-                                                        ui.add(md4);
+                                                        "pitch_scale".to_string());
+                                                        ui.add(pitch_scale);
                                                     });
-                                                    ui.separator();
                                                 });
                                             },
                                             LFOSelect::INFO => {
@@ -3345,6 +4214,19 @@ For constant FM, turn Sustain to 100% and A,D,R to 0%".to_string());
                                                         ui.add(preset_category_box);
                                                 });
 
+                                                ui.horizontal(|ui|{
+                                                    ui.add(
+                                                        nih_plug_egui::egui::TextEdit::singleline(&mut *params.preset_author_p.lock().unwrap())
+                                                            .interactive(true)
+                                                            .hint_text("Author")
+                                                            .desired_width(150.0));
+                                                    let preset_created = params.preset_created_p.lock().unwrap().clone();
+                                                    if !preset_created.is_empty() {
+                                                        ui.label(RichText::new(format!("Created: {}", preset_created))
+                                                            .font(SMALLER_FONT));
+                                                    }
+                                                });
+
                                                 ui.horizontal(|ui|{
                                                     ui.add(
                                                         egui::TextEdit::multiline(&mut *params.preset_info_p.lock().unwrap())
@@ -3506,6 +4388,100 @@ For constant FM, turn Sustain to 100% and A,D,R to 0%".to_string());
                                                                 ui.separator();
                                                             });
                                                             ui.separator();
+                                                            // Master Tilt
+                                                            ui.horizontal(|ui|{
+                                                                ui.label(RichText::new("Tilt")
+                                                                    .font(FONT)).on_hover_text("Tilts the whole spectrum around 1kHz - positive values brighten, negative values darken");
+                                                            });
+                                                            ui.vertical(|ui|{
+                                                                ui.add(CustomParamSlider::ParamSlider::for_param(&params.master_tilt, setter)
+                                                                    .set_left_sided_label(true)
+                                                                    .set_label_width(84.0)
+                                                                    .with_width(268.0));
+                                                            });
+                                                            ui.separator();
+                                                            // Master Rumble Filter
+                                                            ui.horizontal(|ui|{
+                                                                ui.label(RichText::new("Rumble Filter")
+                                                                    .font(FONT)).on_hover_text("Master high-pass to clear out sub rumble. Runs after the limiter even with FX off, Off leaves the low end alone");
+                                                            });
+                                                            ui.vertical(|ui|{
+                                                                ui.add(CustomParamSlider::ParamSlider::for_param(&params.master_hpf, setter)
+                                                                    .set_left_sided_label(true)
+                                                                    .set_label_width(84.0)
+                                                                    .with_width(268.0));
+                                                            });
+                                                            ui.separator();
+                                                            // Space
+                                                            ui.horizontal(|ui|{
+                                                                ui.label(RichText::new("Space")
+                                                                    .font(FONT)).on_hover_text("One knob stereo width. Left narrows toward mono, right widens. Lows under the Bass Mono crossover narrow either way and are fully mono at the ends. The mono sum never changes. Works with FX on or off");
+                                                            });
+                                                            ui.vertical(|ui|{
+                                                                ui.add(CustomParamSlider::ParamSlider::for_param(&params.space, setter)
+                                                                    .set_left_sided_label(true)
+                                                                    .set_label_width(84.0)
+                                                                    .with_width(268.0));
+                                                            });
+                                                            ui.separator();
+                                                            // Tape Stop
+                                                            ui.horizontal(|ui|{
+                                                                ui.label(RichText::new("Tape Stop")
+                                                                    .font(FONT)).on_hover_text("Switch on to slow the whole output down to a halt, switch off to spin it back up. Right click to MIDI learn it to a CC or pad. Works with FX on or off");
+                                                                let tape_stop_toggle = toggle_switch::ToggleSwitch::for_param(&params.tape_stop, setter);
+                                                                lfo_assign_menu(ui.add(tape_stop_toggle), &params.tape_stop, setter, &params, &lfo_select);
+                                                            });
+                                                            ui.vertical(|ui|{
+                                                                ui.add(CustomParamSlider::ParamSlider::for_param(&params.tape_stop_time, setter)
+                                                                    .set_left_sided_label(true)
+                                                                    .set_label_width(84.0)
+                                                                    .with_width(268.0));
+                                                            });
+                                                            ui.separator();
+                                                            // Dither
+                                                            ui.horizontal(|ui|{
+                                                                ui.label(RichText::new("Dither")
+                                                                    .font(FONT)).on_hover_text("TPDF dither and 16-bit quantize on the final output, after master level and safety clip. Only use it when Actuate is the last thing before a 16-bit render");
+                                                                let use_dither_toggle = toggle_switch::ToggleSwitch::for_param(&params.use_dither, setter);
+                                                                ui.add(use_dither_toggle);
+                                                                ui.label(RichText::new("Shaping")
+                                                                    .font(FONT)).on_hover_text("Moves the dither noise up toward the top of the spectrum where it's harder to hear");
+                                                                let dither_shaping_toggle = toggle_switch::ToggleSwitch::for_param(&params.dither_shaping, setter);
+                                                                ui.add(dither_shaping_toggle);
+                                                            });
+                                                            ui.separator();
+                                                            // Bass Mono
+                                                            ui.horizontal(|ui|{
+                                                                ui.label(RichText::new("Bass Mono")
+                                                                    .font(FONT)).on_hover_text("Sums everything below the crossover to mono so the sub stays centered and phase coherent. Runs right before the limiter");
+                                                                let use_bass_mono_toggle = toggle_switch::ToggleSwitch::for_param(&params.use_bass_mono, setter);
+                                                                ui.add(use_bass_mono_toggle);
+                                                            });
+                                                            ui.vertical(|ui|{
+                                                                ui.add(CustomParamSlider::ParamSlider::for_param(&params.elliptical_freq, setter)
+                                                                    .set_left_sided_label(true)
+                                                                    .set_label_width(84.0)
+                                                                    .with_width(268.0));
+                                                            });
+                                                            ui.separator();
+                                                            // Transient Shaper
+                                                            ui.horizontal(|ui|{
+                                                                ui.label(RichText::new("Transient Shaper")
+                                                                    .font(FONT)).on_hover_text("Boost or cut the attack and sustain of notes independent of their level");
+                                                                let use_transient_toggle = toggle_switch::ToggleSwitch::for_param(&params.use_transient, setter);
+                                                                ui.add(use_transient_toggle);
+                                                            });
+                                                            ui.vertical(|ui|{
+                                                                ui.add(CustomParamSlider::ParamSlider::for_param(&params.transient_attack, setter)
+                                                                    .set_left_sided_label(true)
+                                                                    .set_label_width(84.0)
+                                                                    .with_width(268.0));
+                                                                ui.add(CustomParamSlider::ParamSlider::for_param(&params.transient_sustain, setter)
+                                                                    .set_left_sided_label(true)
+                                                                    .set_label_width(84.0)
+                                                                    .with_width(268.0));
+                                                            });
+                                                            ui.separator();
                                                             // Compressor
                                                             ui.horizontal(|ui|{
                                                                 ui.label(RichText::new("Compressor")
@@ -3562,6 +4538,11 @@ For constant FM, turn Sustain to 100% and A,D,R to 0%".to_string());
                                                                     .set_left_sided_label(true)
                                                                     .set_label_width(84.0)
                                                                     .with_width(268.0));
+                                                                ui.add(CustomParamSlider::ParamSlider::for_param(&params.sat_stereo_mode, setter)
+                                                                    .set_left_sided_label(true)
+                                                                    .set_label_width(84.0)
+                                                                    .with_width(268.0))
+                                                                    .on_hover_text("Saturate L/R, only the Mid or only the Side - also applies to ABass");
                                                             });
                                                             ui.separator();
                                                             // Chorus
@@ -3608,6 +4589,10 @@ For constant FM, turn Sustain to 100% and A,D,R to 0%".to_string());
                                                                     .set_left_sided_label(true)
                                                                     .set_label_width(84.0)
                                                                     .with_width(268.0));
+                                                                ui.add(CustomParamSlider::ParamSlider::for_param(&params.phaser_stereo, setter)
+                                                                    .set_left_sided_label(true)
+                                                                    .set_label_width(84.0)
+                                                                    .with_width(268.0));
                                                             });
                                                             ui.separator();
                                                             // Flanger
@@ -3634,6 +4619,10 @@ For constant FM, turn Sustain to 100% and A,D,R to 0%".to_string());
                                                                     .set_left_sided_label(true)
                                                                     .set_label_width(84.0)
                                                                     .with_width(268.0));
+                                                                ui.add(CustomParamSlider::ParamSlider::for_param(&params.flanger_stereo, setter)
+                                                                    .set_left_sided_label(true)
+                                                                    .set_label_width(84.0)
+                                                                    .with_width(268.0));
                                                             });
                                                             ui.separator();
                                                             // Buffer Modulator
@@ -3664,6 +4653,10 @@ For constant FM, turn Sustain to 100% and A,D,R to 0%".to_string());
                                                                     .set_left_sided_label(true)
                                                                     .set_label_width(84.0)
                                                                     .with_width(268.0));
+                                                                ui.add(CustomParamSlider::ParamSlider::for_param(&params.buffermod_stereo, setter)
+                                                                    .set_left_sided_label(true)
+                                                                    .set_label_width(84.0)
+                                                                    .with_width(268.0));
                                                             });
                                                             ui.separator();
                                                             // Delay
@@ -3690,6 +4683,43 @@ For constant FM, turn Sustain to 100% and A,D,R to 0%".to_string());
                                                                     .set_left_sided_label(true)
                                                                     .set_label_width(84.0)
                                                                     .with_width(268.0));
+                                                                ui.add(CustomParamSlider::ParamSlider::for_param(&params.delay_pitch, setter)
+                                                                    .set_left_sided_label(true)
+                                                                    .set_label_width(84.0)
+                                                                    .with_width(268.0))
+                                                                    .on_hover_text("Pitch shift every repeat - with high Decay this climbs or falls forever");
+                                                            });
+                                                            ui.separator();
+                                                            // Delay 2
+                                                            ui.horizontal(|ui|{
+                                                                ui.label(RichText::new("Delay 2")
+                                                                    .font(FONT)).on_hover_text("A second delay, try a different note division from the first");
+                                                                let use_delay_2_toggle = toggle_switch::ToggleSwitch::for_param(&params.use_delay_2, setter);
+                                                                ui.add(use_delay_2_toggle);
+                                                            });
+                                                            ui.vertical(|ui|{
+                                                                ui.add(CustomParamSlider::ParamSlider::for_param(&params.delay_2_amount, setter)
+                                                                    .set_left_sided_label(true)
+                                                                    .set_label_width(84.0)
+                                                                    .with_width(268.0));
+                                                                ui.add(CustomParamSlider::ParamSlider::for_param(&params.delay_2_time, setter)
+                                                                    .set_left_sided_label(true)
+                                                                    .set_label_width(84.0)
+                                                                    .with_width(268.0));
+                                                                ui.add(CustomParamSlider::ParamSlider::for_param(&params.delay_2_decay, setter)
+                                                                    .set_left_sided_label(true)
+                                                                    .set_label_width(84.0)
+                                                                    .with_width(268.0));
+                                                                ui.add(CustomParamSlider::ParamSlider::for_param(&params.delay_2_type, setter)
+                                                                    .set_left_sided_label(true)
+                                                                    .set_label_width(84.0)
+                                                                    .with_width(268.0));
+                                                                ui.add(CustomParamSlider::ParamSlider::for_param(&params.delay_routing, setter)
+                                                                    .set_left_sided_label(true)
+                                                                    .set_label_width(84.0)
+                                                                    .with_width(268.0))
+                                                                    .on_hover_text("Series: Delay 2 repeats the output of Delay 1
+Parallel: Both delays work on the dry signal side by side");
                                                             });
                                                             ui.separator();
                                                             // Reverb
@@ -3716,12 +4746,101 @@ For constant FM, turn Sustain to 100% and A,D,R to 0%".to_string());
                                                                     .set_left_sided_label(true)
                                                                     .set_label_width(84.0)
                                                                     .with_width(268.0));
+                                                                ui.add(CustomParamSlider::ParamSlider::for_param(&params.reverb_predelay, setter)
+                                                                    .set_left_sided_label(true)
+                                                                    .set_label_width(84.0)
+                                                                    .with_width(268.0));
+                                                                ui.add(CustomParamSlider::ParamSlider::for_param(&params.reverb_diffusion, setter)
+                                                                    .set_left_sided_label(true)
+                                                                    .set_label_width(84.0)
+                                                                    .with_width(268.0))
+                                                                    .on_hover_text("Default model only: spread of the reflection sizes, low is sparse and gated, high is smeared");
+                                                                ui.add(CustomParamSlider::ParamSlider::for_param(&params.reverb_density, setter)
+                                                                    .set_left_sided_label(true)
+                                                                    .set_label_width(84.0)
+                                                                    .with_width(268.0))
+                                                                    .on_hover_text("Default model only: gain of each reflection tap, lower thins the tail out");
+                                                            });
+                                                            ui.separator();
+                                                            // Resonator
+                                                            ui.horizontal(|ui|{
+                                                                ui.label(RichText::new("Resonator")
+                                                                    .font(FONT)).on_hover_text("A bank of tuned combs fed like a reverb send that ring at the notes of the chord for a pitched shimmer or drone from anything. Decay is how long they ring");
+                                                                let use_resonator_toggle = toggle_switch::ToggleSwitch::for_param(&params.use_resonator, setter);
+                                                                ui.add(use_resonator_toggle);
+                                                            });
+                                                            ui.vertical(|ui|{
+                                                                ui.add(CustomParamSlider::ParamSlider::for_param(&params.resonator_chord, setter)
+                                                                    .set_left_sided_label(true)
+                                                                    .set_label_width(84.0)
+                                                                    .with_width(268.0));
+                                                                ui.add(CustomParamSlider::ParamSlider::for_param(&params.resonator_root, setter)
+                                                                    .set_left_sided_label(true)
+                                                                    .set_label_width(84.0)
+                                                                    .with_width(268.0));
+                                                                ui.add(CustomParamSlider::ParamSlider::for_param(&params.resonator_decay, setter)
+                                                                    .set_left_sided_label(true)
+                                                                    .set_label_width(84.0)
+                                                                    .with_width(268.0));
+                                                                ui.add(CustomParamSlider::ParamSlider::for_param(&params.resonator_mix, setter)
+                                                                    .set_left_sided_label(true)
+                                                                    .set_label_width(84.0)
+                                                                    .with_width(268.0));
+                                                            });
+                                                            ui.separator();
+                                                            // Looper
+                                                            ui.horizontal(|ui|{
+                                                                ui.label(RichText::new("Looper")
+                                                                    .font(FONT)).on_hover_text("Records the output into a loop that plays back into itself for evolving feedback textures. Feedback is how much of each pass survives the next");
+                                                                let use_looper_toggle = toggle_switch::ToggleSwitch::for_param(&params.use_looper, setter);
+                                                                ui.add(use_looper_toggle);
+                                                            });
+                                                            ui.vertical(|ui|{
+                                                                ui.add(CustomParamSlider::ParamSlider::for_param(&params.looper_length, setter)
+                                                                    .set_left_sided_label(true)
+                                                                    .set_label_width(84.0)
+                                                                    .with_width(268.0));
+                                                                ui.add(CustomParamSlider::ParamSlider::for_param(&params.looper_feedback, setter)
+                                                                    .set_left_sided_label(true)
+                                                                    .set_label_width(84.0)
+                                                                    .with_width(268.0));
+                                                                ui.add(CustomParamSlider::ParamSlider::for_param(&params.looper_mix, setter)
+                                                                    .set_left_sided_label(true)
+                                                                    .set_label_width(84.0)
+                                                                    .with_width(268.0));
+                                                                ui.add(CustomParamSlider::ParamSlider::for_param(&params.looper_pitch, setter)
+                                                                    .set_left_sided_label(true)
+                                                                    .set_label_width(84.0)
+                                                                    .with_width(268.0))
+                                                                    .on_hover_text("Playback speed of the loop, each pass is re-recorded at the new pitch so it keeps climbing or falling");
+                                                            });
+                                                            ui.separator();
+                                                            // Trance Gate
+                                                            ui.horizontal(|ui|{
+                                                                ui.label(RichText::new("Trance Gate")
+                                                                    .font(FONT)).on_hover_text("Tempo synced volume chopping on the output. Shape is how long each step stays open, Smooth softens the edges");
+                                                                let use_gate_toggle = toggle_switch::ToggleSwitch::for_param(&params.use_gate, setter);
+                                                                ui.add(use_gate_toggle);
+                                                            });
+                                                            ui.vertical(|ui|{
+                                                                ui.add(CustomParamSlider::ParamSlider::for_param(&params.gate_rate, setter)
+                                                                    .set_left_sided_label(true)
+                                                                    .set_label_width(84.0)
+                                                                    .with_width(268.0));
+                                                                ui.add(CustomParamSlider::ParamSlider::for_param(&params.gate_shape, setter)
+                                                                    .set_left_sided_label(true)
+                                                                    .set_label_width(84.0)
+                                                                    .with_width(268.0));
+                                                                ui.add(CustomParamSlider::ParamSlider::for_param(&params.gate_smooth, setter)
+                                                                    .set_left_sided_label(true)
+                                                                    .set_label_width(84.0)
+                                                                    .with_width(268.0));
                                                             });
                                                             ui.separator();
                                                             // Limiter
                                                             ui.horizontal(|ui|{
                                                                 ui.label(RichText::new("Limiter")
-                                                                    .font(FONT)).on_hover_text("A basic limiter with knee adjustment");
+                                                                    .font(FONT)).on_hover_text("A basic limiter with knee adjustment. Ceiling is a hard output cap in dB on top of the threshold and knee");
                                                                 let use_limiter_toggle = toggle_switch::ToggleSwitch::for_param(&params.use_limiter, setter);
                                                                 ui.add(use_limiter_toggle);
                                                             });
@@ -3734,6 +4853,22 @@ For constant FM, turn Sustain to 100% and A,D,R to 0%".to_string());
                                                                     .set_left_sided_label(true)
                                                                     .set_label_width(84.0)
                                                                     .with_width(268.0));
+                                                                ui.add(CustomParamSlider::ParamSlider::for_param(&params.limiter_ceiling, setter)
+                                                                    .set_left_sided_label(true)
+                                                                    .set_label_width(84.0)
+                                                                    .with_width(268.0));
+                                                            });
+                                                            ui.separator();
+                                                            // Oversample
+                                                            ui.horizontal(|ui|{
+                                                                ui.label(RichText::new("Oversample")
+                                                                    .font(FONT)).on_hover_text("Runs ABass, Saturation and the Limiter at 2x or 4x to cut down aliasing when they're driven hard. Adds a little latency the host compensates for, and more CPU");
+                                                            });
+                                                            ui.vertical(|ui|{
+                                                                ui.add(CustomParamSlider::ParamSlider::for_param(&params.output_oversample, setter)
+                                                                    .set_left_sided_label(true)
+                                                                    .set_label_width(84.0)
+                                                                    .with_width(268.0));
                                                             });
                                                         });
                                                     }).inner;
@@ -3757,3 +4892,269 @@ For constant FM, turn Sustain to 100% and A,D,R to 0%".to_string());
             // This is the end of create_egui_editor()
         )
 }
+
+// Right-click menu that routes an LFO to this knob through the first free mod slot
+fn lfo_assign_menu<P: Param>(response: egui::Response, param: &P, setter: &ParamSetter, params: &ActuateParams, lfo_select: &Arc<Mutex<LFOSelect>>) {
+    response.context_menu(|ui| {
+        let param_ptr = param.as_ptr();
+        let param_id = params
+            .param_map()
+            .into_iter()
+            .find(|(_, ptr, _)| *ptr == param_ptr)
+            .map(|(id, _, _)| id);
+        if let Some(param_id) = &param_id {
+            let bound_cc = params.midi_learn_map.lock().unwrap()
+                .iter()
+                .find(|(_, id)| *id == param_id)
+                .map(|(cc, _)| *cc);
+            let learning = params.midi_learn_target.lock().unwrap().as_ref() == Some(param_id);
+            let learn_label = if learning { "Waiting for CC..." } else { "MIDI Learn" };
+            if ui.button(learn_label).on_hover_text("Move a knob or fader on your controller to bind it here. Bindings only move knobs after the editor has been opened once since the plugin loaded").clicked() {
+                *params.midi_learn_target.lock().unwrap() = Some(param_id.clone());
+                ui.close_menu();
+            }
+            if let Some(cc) = bound_cc {
+                if ui.button(format!("Forget CC {}", cc)).clicked() {
+                    params.midi_learn_map.lock().unwrap().remove(&cc);
+                    ui.close_menu();
+                }
+            }
+            ui.separator();
+        }
+        let destination = param_id
+            .as_deref()
+            .and_then(ModulationDestination::from_param_id);
+        let Some(destination) = destination else {
+            ui.label("This knob can't be modulated");
+            return;
+        };
+        let slots = [
+            (&params.mod_source_1, &params.mod_destination_1, &params.mod_amount_knob_1),
+            (&params.mod_source_2, &params.mod_destination_2, &params.mod_amount_knob_2),
+            (&params.mod_source_3, &params.mod_destination_3, &params.mod_amount_knob_3),
+            (&params.mod_source_4, &params.mod_destination_4, &params.mod_amount_knob_4),
+            (&params.mod_source_5, &params.mod_destination_5, &params.mod_amount_knob_5),
+            (&params.mod_source_6, &params.mod_destination_6, &params.mod_amount_knob_6),
+            (&params.mod_source_7, &params.mod_destination_7, &params.mod_amount_knob_7),
+            (&params.mod_source_8, &params.mod_destination_8, &params.mod_amount_knob_8),
+        ];
+        // A slot missing either end isn't doing anything so it's free to take
+        let free_slot = slots.iter().find(|(source, dest, _)| {
+            source.value() == ModulationSource::None || dest.value() == ModulationDestination::None
+        });
+        for (label, source) in [
+            ("Assign LFO1", ModulationSource::LFO1),
+            ("Assign LFO2", ModulationSource::LFO2),
+            ("Assign LFO3", ModulationSource::LFO3),
+        ] {
+            if ui.add_enabled(free_slot.is_some(), egui::Button::new(label)).clicked() {
+                if let Some((slot_source, slot_destination, slot_amount)) = free_slot {
+                    setter.set_parameter(*slot_source, source);
+                    setter.set_parameter(*slot_destination, destination);
+                    // Start from half depth so the assignment is audible right away
+                    if slot_amount.value() == 0.0 {
+                        setter.set_parameter(*slot_amount, 0.5);
+                    }
+                }
+                // Jump to the matrix so the amount can be set
+                *lfo_select.lock().unwrap() = LFOSelect::Modulation;
+                ui.close_menu();
+            }
+        }
+        if free_slot.is_none() {
+            ui.label("All 8 mod slots are in use");
+        }
+    });
+}
+
+// Knob a mod destination moves and how many knob units one unit of mod value is
+// Only destinations that add straight onto their knob are listed
+fn freeze_target(params: &ActuateParams, destination: ModulationDestination) -> Option<(&FloatParam, f32)> {
+    match destination {
+        ModulationDestination::Cutoff_1 => Some((&params.filter_cutoff, 20000.0)),
+        ModulationDestination::Cutoff_2 => Some((&params.filter_cutoff_2, 20000.0)),
+        ModulationDestination::Resonance_1 => Some((&params.filter_resonance, 1.0)),
+        ModulationDestination::Resonance_2 => Some((&params.filter_resonance_2, 1.0)),
+        ModulationDestination::Drive_1 => Some((&params.filter_drive, 1.0)),
+        ModulationDestination::Drive_2 => Some((&params.filter_drive_2, 1.0)),
+        ModulationDestination::Osc1_Morph => Some((&params.osc_1_morph, 1.0)),
+        ModulationDestination::Osc2_Morph => Some((&params.osc_2_morph, 1.0)),
+        ModulationDestination::Osc3_Morph => Some((&params.osc_3_morph, 1.0)),
+        ModulationDestination::Osc1_Shape => Some((&params.osc_1_shape_amount, 1.0)),
+        ModulationDestination::Osc2_Shape => Some((&params.osc_2_shape_amount, 1.0)),
+        ModulationDestination::Osc3_Shape => Some((&params.osc_3_shape_amount, 1.0)),
+        _ => None,
+    }
+}
+
+// Bake the current output of every running LFO slot into its destination knob then turn the slot off
+fn freeze_modulation(params: &ActuateParams, setter: &ParamSetter, mod_values: &[Arc<AtomicF32>; 8]) {
+    let slots = [
+        (&params.mod_source_1, &params.mod_destination_1),
+        (&params.mod_source_2, &params.mod_destination_2),
+        (&params.mod_source_3, &params.mod_destination_3),
+        (&params.mod_source_4, &params.mod_destination_4),
+        (&params.mod_source_5, &params.mod_destination_5),
+        (&params.mod_source_6, &params.mod_destination_6),
+        (&params.mod_source_7, &params.mod_destination_7),
+        (&params.mod_source_8, &params.mod_destination_8),
+    ];
+    // Slots sharing a destination are summed first so the knob only moves once
+    let mut offsets: Vec<(ModulationDestination, f32)> = Vec::new();
+    let mut frozen_sources = Vec::new();
+    for ((source, destination), mod_value) in slots.iter().zip(mod_values.iter()) {
+        let lfo_running = match source.value() {
+            ModulationSource::LFO1 => params.lfo1_enable.value(),
+            ModulationSource::LFO2 => params.lfo2_enable.value(),
+            ModulationSource::LFO3 => params.lfo3_enable.value(),
+            _ => false,
+        };
+        if !lfo_running || freeze_target(params, destination.value()).is_none() {
+            continue;
+        }
+        let value = mod_value.load(Ordering::Relaxed);
+        match offsets.iter_mut().find(|(existing, _)| *existing == destination.value()) {
+            Some((_, offset)) => *offset += value,
+            None => offsets.push((destination.value(), value)),
+        }
+        frozen_sources.push(*source);
+    }
+    for (destination, offset) in offsets {
+        if let Some((param, scale)) = freeze_target(params, destination) {
+            setter.set_parameter(param, param.value() + offset * scale);
+        }
+    }
+    for source in frozen_sources {
+        setter.set_parameter(source, ModulationSource::None);
+    }
+}
+
+// Step the LFO slots through one 4/4 bar and write where each frozen-style destination knob ends up
+// LFOs start from their phase knob like a retriggered note so the curve lines up with the bar
+fn export_mod_curve(mut location: PathBuf, params: &ActuateParams, bpm: f32) {
+    const STEPS_PER_BEAT: usize = 96;
+    const BEATS: usize = 4;
+    location.set_extension("csv");
+    let bpm = if bpm > 0.0 { bpm } else { 120.0 };
+    let lfo_settings = [
+        (&params.lfo1_enable, &params.lfo1_sync, &params.lfo1_freq, &params.lfo1_snap, &params.lfo1_waveform, &params.lfo1_phase, &params.lfo1_depth),
+        (&params.lfo2_enable, &params.lfo2_sync, &params.lfo2_freq, &params.lfo2_snap, &params.lfo2_waveform, &params.lfo2_phase, &params.lfo2_depth),
+        (&params.lfo3_enable, &params.lfo3_sync, &params.lfo3_freq, &params.lfo3_snap, &params.lfo3_waveform, &params.lfo3_phase, &params.lfo3_depth),
+    ];
+    let mut lfos: Vec<LFOController> = lfo_settings
+        .iter()
+        .map(|(_, sync, freq, snap, waveform, phase, _)| {
+            let frequency = if sync.value() { (bpm / snap.value().beats()) / 60.0 } else { freq.value() };
+            LFOController::new(frequency, 1.0, waveform.value(), phase.value())
+        })
+        .collect();
+    let slots = [
+        (&params.mod_source_1, &params.mod_destination_1, &params.mod_amount_knob_1),
+        (&params.mod_source_2, &params.mod_destination_2, &params.mod_amount_knob_2),
+        (&params.mod_source_3, &params.mod_destination_3, &params.mod_amount_knob_3),
+        (&params.mod_source_4, &params.mod_destination_4, &params.mod_amount_knob_4),
+        (&params.mod_source_5, &params.mod_destination_5, &params.mod_amount_knob_5),
+        (&params.mod_source_6, &params.mod_destination_6, &params.mod_amount_knob_6),
+        (&params.mod_source_7, &params.mod_destination_7, &params.mod_amount_knob_7),
+        (&params.mod_source_8, &params.mod_destination_8, &params.mod_amount_knob_8),
+    ];
+    // Each destination gets one column, slots sharing it are summed like the process loop does
+    let mut columns: Vec<ModulationDestination> = Vec::new();
+    for (source, destination, _) in slots.iter() {
+        let lfo_running = match source.value() {
+            ModulationSource::LFO1 => params.lfo1_enable.value(),
+            ModulationSource::LFO2 => params.lfo2_enable.value(),
+            ModulationSource::LFO3 => params.lfo3_enable.value(),
+            _ => false,
+        };
+        if lfo_running && freeze_target(params, destination.value()).is_some() && !columns.contains(&destination.value()) {
+            columns.push(destination.value());
+        }
+    }
+    if columns.is_empty() {
+        nih_log!("Export Mod CSV: no LFO slots are modulating a knob");
+        return;
+    }
+
+    let mut csv = String::from("beat");
+    for destination in columns.iter() {
+        if let Some((param, _)) = freeze_target(params, *destination) {
+            csv.push_str(&format!(",{}", param.name()));
+        }
+    }
+    csv.push('\n');
+    let step_rate = STEPS_PER_BEAT as f32 * bpm / 60.0;
+    for step in 0..=(STEPS_PER_BEAT * BEATS) {
+        let lfo_values: Vec<f32> = lfos
+            .iter_mut()
+            .zip(lfo_settings.iter())
+            .map(|(lfo, (enable, _, _, _, _, _, depth))| {
+                if !enable.value() {
+                    return 0.0;
+                }
+                // Read before stepping so the first row is the LFO at its start phase
+                let value = LFOController::shape_value(lfo.get_waveform(), lfo.get_phase());
+                lfo.next_sample(step_rate);
+                value * depth.value()
+            })
+            .collect();
+        csv.push_str(&format!("{:.4}", step as f32 / STEPS_PER_BEAT as f32));
+        for destination in columns.iter() {
+            let mut offset = 0.0;
+            for (source, slot_destination, amount) in slots.iter() {
+                if slot_destination.value() != *destination {
+                    continue;
+                }
+                offset += match source.value() {
+                    ModulationSource::LFO1 => lfo_values[0] * amount.value(),
+                    ModulationSource::LFO2 => lfo_values[1] * amount.value(),
+                    ModulationSource::LFO3 => lfo_values[2] * amount.value(),
+                    _ => 0.0,
+                };
+            }
+            if let Some((param, scale)) = freeze_target(params, *destination) {
+                // Round trip through the normalized range so the curve stays inside what the knob can reach
+                let value = param.preview_plain(param.preview_normalized(param.value() + offset * scale));
+                csv.push_str(&format!(",{:.4}", value));
+            }
+        }
+        csv.push('\n');
+    }
+    if let Err(err) = std::fs::write(&location, csv) {
+        nih_log!("Error writing mod curve: {}", err);
+    }
+}
+
+// Small plot of the LFO shape with a dot riding along at the current position
+fn lfo_position_view(ui: &mut egui::Ui, waveform: Waveform, enabled: bool, phase: f32, value: f32) {
+    let (view_rect, view_response) = ui.allocate_exact_size(Vec2::new(220.0, 36.0), egui::Sense::hover());
+    ui.painter().rect_filled(view_rect, Rounding::from(2.0), DARK_GREY_UI_COLOR);
+    let to_screen = |x: f32, y: f32| Pos2::new(
+        view_rect.min.x + 4.0 + x * (view_rect.width() - 8.0),
+        view_rect.center().y - y * (view_rect.height() * 0.5 - 4.0));
+    let points: Vec<Pos2> = (0..=64)
+        .map(|step| {
+            let x = step as f32 / 64.0;
+            to_screen(x, LFOController::shape_value(waveform, x))
+        })
+        .collect();
+    let line_color = if enabled { LIGHTER_GREY_UI_COLOR } else { MEDIUM_GREY_UI_COLOR };
+    ui.painter().add(egui::Shape::line(points, egui::Stroke::new(1.0, line_color)));
+    if enabled {
+        ui.painter().circle_filled(to_screen(phase.clamp(0.0, 1.0), value.clamp(-1.0, 1.0)), 3.0, YELLOW_MUSTARD);
+        view_response.on_hover_text(format!("LFO output {:.2}", value));
+    }
+}
+
+// Thin vertical level meter shown beside each generator's level knob
+fn module_meter(ui: &mut egui::Ui, peak: f32) {
+    let (meter_rect, meter_response) = ui.allocate_exact_size(Vec2::new(6.0, 50.0), egui::Sense::hover());
+    let peak_db = util::gain_to_db(peak.max(0.000001));
+    let meter_fill = ((peak_db + 60.0) / 60.0).clamp(0.0, 1.0);
+    ui.painter().rect_filled(meter_rect, Rounding::from(2.0), DARK_GREY_UI_COLOR);
+    ui.painter().rect_filled(
+        Rect::from_min_max(Pos2::new(meter_rect.min.x, meter_rect.max.y - meter_rect.height() * meter_fill), meter_rect.max),
+        Rounding::from(2.0),
+        if peak_db > -3.0 { YELLOW_MUSTARD } else { TEAL_GREEN });
+    meter_response.on_hover_text(format!("Generator level {:.1} dB", peak_db));
+}
@@ -0,0 +1,185 @@
+// Actuate On-Screen Keyboard
+// Lets you play Actuate with the mouse or computer keyboard when there's no MIDI controller around
+// Notes go through a shared queue that process_midi drains alongside the host events
+// Ardura
+
+use std::{collections::VecDeque, sync::{Arc, Mutex}};
+use nih_plug::prelude::NoteEvent;
+use nih_plug_egui::egui::{Color32, Context, Key, Pos2, Rect, Rounding, Sense, Stroke, Ui, Vec2};
+
+// Two octaves starting from C3 by default
+const KEYBOARD_OCTAVES: u8 = 2;
+const DEFAULT_BASE_NOTE: u8 = 48;
+const MOUSE_VELOCITY: f32 = 0.8;
+const WHITE_KEY_WIDTH: f32 = 20.0;
+const BLACK_KEY_WIDTH: f32 = 13.0;
+const KEY_HEIGHT: f32 = 64.0;
+const BLACK_KEY_HEIGHT: f32 = 38.0;
+
+// Z-M row laid out like a piano: white keys on the bottom row, black keys on the row above
+const COMPUTER_KEYS: [Key; 12] = [
+    Key::Z, Key::S, Key::X, Key::D, Key::C, Key::V,
+    Key::G, Key::B, Key::H, Key::N, Key::J, Key::M,
+];
+
+// Semitone offsets within an octave
+const WHITE_KEYS: [u8; 7] = [0, 2, 4, 5, 7, 9, 11];
+const BLACK_KEYS: [(u8, f32); 5] = [(1, 1.0), (3, 2.0), (6, 4.0), (8, 5.0), (10, 6.0)];
+
+pub(crate) struct OnScreenKeyboard {
+    queue: Arc<Mutex<VecDeque<NoteEvent<()>>>>,
+    base_note: u8,
+    mouse_note: Option<u8>,
+    // The note each computer key started so an octave change mid-hold still releases the right one
+    computer_notes: [Option<u8>; 12],
+}
+
+impl OnScreenKeyboard {
+    pub fn new(queue: Arc<Mutex<VecDeque<NoteEvent<()>>>>) -> Self {
+        OnScreenKeyboard {
+            queue,
+            base_note: DEFAULT_BASE_NOTE,
+            mouse_note: None,
+            computer_notes: [None; 12],
+        }
+    }
+
+    fn send_on(&self, note: u8, velocity: f32) {
+        self.queue.lock().unwrap().push_back(NoteEvent::NoteOn {
+            timing: 0,
+            voice_id: None,
+            channel: 0,
+            note,
+            velocity,
+        });
+    }
+
+    fn send_off(&self, note: u8) {
+        self.queue.lock().unwrap().push_back(NoteEvent::NoteOff {
+            timing: 0,
+            voice_id: None,
+            channel: 0,
+            note,
+            velocity: 0.0,
+        });
+    }
+
+    fn is_held(&self, note: u8) -> bool {
+        self.mouse_note == Some(note) || self.computer_notes.contains(&Some(note))
+    }
+
+    // Let go of everything, used when the keyboard window closes
+    pub fn release_all(&mut self) {
+        if let Some(note) = self.mouse_note.take() {
+            self.send_off(note);
+        }
+        for slot in 0..self.computer_notes.len() {
+            if let Some(note) = self.computer_notes[slot].take() {
+                self.send_off(note);
+            }
+        }
+    }
+
+    // Call this once per frame while the keyboard is open
+    pub fn track_keys(&mut self, ctx: &Context) {
+        // Don't steal typing from text boxes or Ctrl+Z undo
+        if ctx.wants_keyboard_input() {
+            return;
+        }
+        let (down, command) = ctx.input(|i| {
+            let mut down = [false; 12];
+            for (slot, key) in COMPUTER_KEYS.iter().enumerate() {
+                down[slot] = i.key_down(*key);
+            }
+            (down, i.modifiers.command)
+        });
+        for slot in 0..COMPUTER_KEYS.len() {
+            match (down[slot] && !command, self.computer_notes[slot]) {
+                (true, None) => {
+                    let note = (self.base_note + slot as u8).min(127);
+                    self.send_on(note, MOUSE_VELOCITY);
+                    self.computer_notes[slot] = Some(note);
+                },
+                (false, Some(note)) => {
+                    self.send_off(note);
+                    self.computer_notes[slot] = None;
+                },
+                _ => {},
+            }
+        }
+    }
+
+    pub fn show(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            if ui.button("Oct -").clicked() && self.base_note >= 12 {
+                self.base_note -= 12;
+            }
+            ui.label(format!("C{}", self.base_note as i32 / 12 - 1));
+            if ui.button("Oct +").clicked() && self.base_note + 12 * (KEYBOARD_OCTAVES + 1) <= 128 {
+                self.base_note += 12;
+            }
+            ui.label("Click or use Z-M to play");
+        });
+
+        let white_count = WHITE_KEYS.len() * KEYBOARD_OCTAVES as usize;
+        let (rect, response) = ui.allocate_exact_size(
+            Vec2::new(WHITE_KEY_WIDTH * white_count as f32, KEY_HEIGHT),
+            Sense::click_and_drag(),
+        );
+
+        // Build the key rectangles, black keys last so they get hit tested first
+        let mut white_rects: Vec<(u8, Rect)> = Vec::with_capacity(white_count);
+        let mut black_rects: Vec<(u8, Rect)> = Vec::with_capacity(BLACK_KEYS.len() * KEYBOARD_OCTAVES as usize);
+        for octave in 0..KEYBOARD_OCTAVES {
+            let octave_x = rect.min.x + octave as f32 * 7.0 * WHITE_KEY_WIDTH;
+            let octave_note = self.base_note + octave * 12;
+            for (index, offset) in WHITE_KEYS.iter().enumerate() {
+                let x = octave_x + index as f32 * WHITE_KEY_WIDTH;
+                white_rects.push((
+                    octave_note + offset,
+                    Rect::from_min_size(Pos2::new(x, rect.min.y), Vec2::new(WHITE_KEY_WIDTH, KEY_HEIGHT)),
+                ));
+            }
+            for (offset, position) in BLACK_KEYS.iter() {
+                let x = octave_x + position * WHITE_KEY_WIDTH - BLACK_KEY_WIDTH / 2.0;
+                black_rects.push((
+                    octave_note + offset,
+                    Rect::from_min_size(Pos2::new(x, rect.min.y), Vec2::new(BLACK_KEY_WIDTH, BLACK_KEY_HEIGHT)),
+                ));
+            }
+        }
+
+        // Mouse: hold to play, dragging across keys glisses
+        let hovered_note = if response.is_pointer_button_down_on() {
+            response.interact_pointer_pos().and_then(|pos| {
+                black_rects
+                    .iter()
+                    .chain(white_rects.iter())
+                    .find(|(_, key_rect)| key_rect.contains(pos))
+                    .map(|(note, _)| *note)
+            })
+        } else {
+            None
+        };
+        if hovered_note != self.mouse_note {
+            if let Some(note) = self.mouse_note.take() {
+                self.send_off(note);
+            }
+            if let Some(note) = hovered_note {
+                self.send_on(note, MOUSE_VELOCITY);
+                self.mouse_note = Some(note);
+            }
+        }
+
+        let painter = ui.painter_at(rect);
+        for (note, key_rect) in white_rects.iter() {
+            let fill = if self.is_held(*note) { Color32::LIGHT_BLUE } else { Color32::WHITE };
+            painter.rect_filled(*key_rect, Rounding::from(2.0), fill);
+            painter.rect_stroke(*key_rect, Rounding::from(2.0), Stroke::new(1.0, Color32::DARK_GRAY));
+        }
+        for (note, key_rect) in black_rects.iter() {
+            let fill = if self.is_held(*note) { Color32::LIGHT_BLUE } else { Color32::BLACK };
+            painter.rect_filled(*key_rect, Rounding::from(2.0), fill);
+        }
+    }
+}
@@ -0,0 +1,84 @@
+// Actuate MIDI Learn
+// Bound CCs move their params on the main thread, process_midi stashes the latest value per CC and queues a task
+// Setting a param needs the host's GUI context, so bindings only start moving params once the editor has opened this session
+// Ardura
+
+use std::{any::Any, collections::HashMap, sync::{atomic::Ordering, Arc, Mutex}};
+use nih_plug::{editor::{Editor, ParentWindowHandle}, prelude::{AtomicF32, GuiContext, ParamPtr, Params}};
+
+use crate::ActuateParams;
+
+// Params can only be set through the host's GUI context, which we first get when the editor spawns
+// It stays valid after the window closes so it's kept for the MIDI learn task
+pub(crate) type SharedGuiContext = Arc<Mutex<Option<Arc<dyn GuiContext>>>>;
+
+// Wraps the egui editor just to hold on to its context
+pub(crate) struct MidiLearnEditor {
+    pub(crate) inner: Box<dyn Editor>,
+    pub(crate) context: SharedGuiContext,
+}
+
+impl Editor for MidiLearnEditor {
+    fn spawn(&self, parent: ParentWindowHandle, context: Arc<dyn GuiContext>) -> Box<dyn Any + Send> {
+        *self.context.lock().unwrap() = Some(context.clone());
+        self.inner.spawn(parent, context)
+    }
+
+    fn size(&self) -> (u32, u32) {
+        self.inner.size()
+    }
+
+    fn set_scale_factor(&self, factor: f32) -> bool {
+        self.inner.set_scale_factor(factor)
+    }
+
+    fn param_value_changed(&self, id: &str, normalized_value: f32) {
+        self.inner.param_value_changed(id, normalized_value)
+    }
+
+    fn param_modulation_changed(&self, id: &str, modulation_offset: f32) {
+        self.inner.param_modulation_changed(id, modulation_offset)
+    }
+
+    fn param_values_changed(&self) {
+        self.inner.param_values_changed()
+    }
+}
+
+// Param id to pointer lookup, built once since param_map walks every param
+pub(crate) fn param_ptr_map(params: &ActuateParams) -> HashMap<String, ParamPtr> {
+    params.param_map().into_iter().map(|(id, param_ptr, _)| (id, param_ptr)).collect()
+}
+
+// The first CC after "MIDI Learn" binds, bound CCs move their param
+// Called from the task executor and the editor so whichever runs first takes the pending value
+pub(crate) fn apply_midi_learn(
+    params: &ActuateParams,
+    param_ptrs: &HashMap<String, ParamPtr>,
+    pending_cc: &[AtomicF32],
+    context: &dyn GuiContext,
+) {
+    for (cc, pending) in pending_cc.iter().enumerate() {
+        let value = pending.swap(-1.0, Ordering::SeqCst);
+        if value < 0.0 {
+            continue;
+        }
+        if let Some(param_id) = params.midi_learn_target.lock().unwrap().take() {
+            let mut learn_map = params.midi_learn_map.lock().unwrap();
+            // One CC per param so relearning moves the binding
+            learn_map.retain(|_, id| *id != param_id);
+            learn_map.insert(cc as u8, param_id);
+        }
+        let bound_id = params.midi_learn_map.lock().unwrap().get(&(cc as u8)).cloned();
+        if let Some(bound_id) = bound_id {
+            if let Some(&param_ptr) = param_ptrs.get(&bound_id) {
+                // Safe since the pointer comes straight from our own param map
+                unsafe {
+                    context.raw_begin_set_parameter(param_ptr);
+                    context.raw_set_parameter_normalized(param_ptr, value);
+                    context.raw_end_set_parameter(param_ptr);
+                }
+            }
+        }
+    }
+}
@@ -2,7 +2,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::{actuate_enums::{AMFilterRouting, FilterAlgorithms, FilterRouting, ModulationDestination, ModulationSource, PitchRouting, PresetType, ReverbModel, StereoAlgorithm}, audio_module::{AudioModuleType, Oscillator::{self, RetriggerStyle, SmoothStyle}}, fx::{delay::{DelaySnapValues, DelayType}, saturation::SaturationType, TiltFilter, StateVariableFilter::ResonanceType}, LFOController};
+use crate::{actuate_enums::{AMFilterRouting, EnvTimeRange, FilterAlgorithms, FilterRouting, ModulationDestination, ModulationSource, MonoMode, PitchQuantizeScale, PitchRouting, PresetType, ReverbModel, StereoAlgorithm, TuningSystem, VoiceStealMode}, audio_module::{AudioModuleType, GrainWindow, Oscillator::{self, RetriggerStyle, SmoothStyle}}, fx::{delay::{DelayRouting, DelaySnapValues, DelayType}, resonator_bank::ResonatorChord, saturation::{SaturationStereoMode, SaturationType}, TiltFilter, StateVariableFilter::ResonanceType}, LFOController};
 
 /// Modulation struct for passing mods to audio modules
 #[derive(Serialize, Deserialize, Clone)]
@@ -20,12 +20,23 @@ pub struct ModulationStruct {
     pub temp_mod_vel_sum: f32,
 }
 
+/// Holds the two patch states for A/B comparison in the GUI
+pub struct ABCompare {
+    pub active: usize,
+    pub slots: [Option<ActuatePresetV131>; 2],
+}
+
 /// This is the structure that represents a storable preset value
 #[derive(Serialize, Deserialize, Clone)]
 pub struct ActuatePresetV131 {
     // Information
     pub preset_name: String,
     pub preset_info: String,
+    // Who made the patch and when it was first saved, empty for presets older than these fields
+    #[serde(default)]
+    pub preset_author: String,
+    #[serde(default)]
+    pub preset_created: String,
     pub preset_category: PresetType,
     // Preset tag information - made into bools to make my life easier
     pub tag_acid: bool,
@@ -383,4 +394,466 @@ pub struct ActuatePresetV131 {
     pub additive_amp_3_13: f32,
     pub additive_amp_3_14: f32,
     pub additive_amp_3_15: f32,
+
+    // 1.3.8 fields
+    #[serde(default)]
+    pub master_tilt: f32,
+    #[serde(default = "default_one")]
+    pub lfo1_depth: f32,
+    #[serde(default = "default_one")]
+    pub lfo2_depth: f32,
+    #[serde(default = "default_one")]
+    pub lfo3_depth: f32,
+    #[serde(default)]
+    pub ring_mod_1x2: f32,
+    #[serde(default)]
+    pub ring_mod_2x3: f32,
+    #[serde(default = "default_sat_stereo_mode")]
+    pub sat_stereo_mode: SaturationStereoMode,
+    #[serde(default)]
+    pub humanize: f32,
+    #[serde(default)]
+    pub mod1_osc_super: f32,
+    #[serde(default)]
+    pub mod2_osc_super: f32,
+    #[serde(default)]
+    pub mod3_osc_super: f32,
+    #[serde(default)]
+    pub filter_drive: f32,
+    #[serde(default)]
+    pub filter_drive_2: f32,
+    #[serde(default = "default_osc_quality")]
+    pub osc_quality: Oscillator::OscQuality,
+    #[serde(default)]
+    pub release_vel_amount: f32,
+    #[serde(default = "default_mono_mode")]
+    pub mono_mode: MonoMode,
+    #[serde(default)]
+    pub glide_time: f32,
+    // Waveform morph target and amount per oscillator
+    #[serde(default = "default_osc_type_b")]
+    pub mod1_osc_type_b: AudioModuleType,
+    #[serde(default = "default_osc_type_b")]
+    pub mod2_osc_type_b: AudioModuleType,
+    #[serde(default = "default_osc_type_b")]
+    pub mod3_osc_type_b: AudioModuleType,
+    #[serde(default)]
+    pub mod1_osc_morph: f32,
+    #[serde(default)]
+    pub mod2_osc_morph: f32,
+    #[serde(default)]
+    pub mod3_osc_morph: f32,
+    // Transient shaper
+    #[serde(default)]
+    pub use_transient: bool,
+    #[serde(default)]
+    pub transient_attack: f32,
+    #[serde(default)]
+    pub transient_sustain: f32,
+    #[serde(default)]
+    pub filter_auto_gain: bool,
+    #[serde(default)]
+    pub global_phase_reset: bool,
+    #[serde(default)]
+    pub delay_pitch: f32,
+    #[serde(default)]
+    pub pitch_mod_quantize: bool,
+    #[serde(default = "default_pitch_mod_scale")]
+    pub pitch_mod_scale: PitchQuantizeScale,
+    #[serde(default = "default_grain_window")]
+    pub mod1_grain_window: GrainWindow,
+    #[serde(default = "default_grain_window")]
+    pub mod2_grain_window: GrainWindow,
+    #[serde(default = "default_grain_window")]
+    pub mod3_grain_window: GrainWindow,
+    #[serde(default)]
+    pub use_delay_2: bool,
+    #[serde(default = "default_half")]
+    pub delay_2_amount: f32,
+    #[serde(default = "default_delay_2_time")]
+    pub delay_2_time: DelaySnapValues,
+    #[serde(default = "default_half")]
+    pub delay_2_decay: f32,
+    #[serde(default = "default_delay_type")]
+    pub delay_2_type: DelayType,
+    #[serde(default = "default_delay_routing")]
+    pub delay_routing: DelayRouting,
+    #[serde(default)]
+    pub filter_env_loop: bool,
+    #[serde(default = "default_filter_env_loop_rate")]
+    pub filter_env_loop_rate: LFOController::LFOSnapValues,
+    #[serde(default)]
+    pub voice_spread: f32,
+    #[serde(default)]
+    pub filter_env_invert_1: bool,
+    #[serde(default)]
+    pub filter_env_invert_2: bool,
+    #[serde(default = "default_sample_root")]
+    pub mod1_sample_root: i32,
+    #[serde(default = "default_sample_root")]
+    pub mod2_sample_root: i32,
+    #[serde(default = "default_sample_root")]
+    pub mod3_sample_root: i32,
+    #[serde(default = "default_one")]
+    pub mod1_stretch_factor: f32,
+    #[serde(default = "default_one")]
+    pub mod2_stretch_factor: f32,
+    #[serde(default = "default_one")]
+    pub mod3_stretch_factor: f32,
+    #[serde(default = "default_voice_steal_mode")]
+    pub voice_steal_mode: VoiceStealMode,
+    #[serde(default)]
+    pub lfo1_gate: bool,
+    #[serde(default)]
+    pub lfo2_gate: bool,
+    #[serde(default)]
+    pub lfo3_gate: bool,
+    #[serde(default = "default_osc_shape")]
+    pub mod1_osc_shape: Oscillator::OscShape,
+    #[serde(default = "default_half")]
+    pub mod1_osc_shape_amount: f32,
+    #[serde(default = "default_osc_shape")]
+    pub mod2_osc_shape: Oscillator::OscShape,
+    #[serde(default = "default_half")]
+    pub mod2_osc_shape_amount: f32,
+    #[serde(default = "default_osc_shape")]
+    pub mod3_osc_shape: Oscillator::OscShape,
+    #[serde(default = "default_half")]
+    pub mod3_osc_shape_amount: f32,
+    #[serde(default)]
+    pub reverb_predelay: f32,
+    #[serde(default)]
+    pub filter_res_link: bool,
+    #[serde(default)]
+    pub filter_env_link: bool,
+    #[serde(default)]
+    pub phaser_stereo: f32,
+    #[serde(default)]
+    pub flanger_stereo: f32,
+    #[serde(default)]
+    pub buffermod_stereo: f32,
+    #[serde(default = "default_tuning_system")]
+    pub tuning_system: TuningSystem,
+    #[serde(default)]
+    pub tuning_root: i32,
+    #[serde(default)]
+    pub mod1_osc_warmth: f32,
+    #[serde(default)]
+    pub mod2_osc_warmth: f32,
+    #[serde(default)]
+    pub mod3_osc_warmth: f32,
+    #[serde(default = "default_master_hpf")]
+    pub master_hpf: f32,
+    #[serde(default)]
+    pub limiter_ceiling: f32,
+    // Trance gate
+    #[serde(default)]
+    pub use_gate: bool,
+    #[serde(default = "default_gate_rate")]
+    pub gate_rate: LFOController::LFOSnapValues,
+    #[serde(default = "default_half")]
+    pub gate_shape: f32,
+    #[serde(default = "default_gate_smooth")]
+    pub gate_smooth: f32,
+    // Old presets were all made with the normal envelope times
+    #[serde(default = "default_env_time_range")]
+    pub env_time_range: EnvTimeRange,
+    #[serde(default)]
+    pub mod1_osc_drawbar_sub: f32,
+    #[serde(default = "default_one")]
+    pub mod1_osc_drawbar_root: f32,
+    #[serde(default)]
+    pub mod1_osc_drawbar_oct: f32,
+    #[serde(default)]
+    pub mod1_osc_drawbar_oct2: f32,
+    #[serde(default)]
+    pub mod2_osc_drawbar_sub: f32,
+    #[serde(default = "default_one")]
+    pub mod2_osc_drawbar_root: f32,
+    #[serde(default)]
+    pub mod2_osc_drawbar_oct: f32,
+    #[serde(default)]
+    pub mod2_osc_drawbar_oct2: f32,
+    #[serde(default)]
+    pub mod3_osc_drawbar_sub: f32,
+    #[serde(default = "default_one")]
+    pub mod3_osc_drawbar_root: f32,
+    #[serde(default)]
+    pub mod3_osc_drawbar_oct: f32,
+    #[serde(default)]
+    pub mod3_osc_drawbar_oct2: f32,
+    // Mod slots 5-8, off for presets saved with only 4 slots
+    #[serde(default = "default_mod_source")]
+    pub mod_source_5: ModulationSource,
+    #[serde(default = "default_mod_source")]
+    pub mod_source_6: ModulationSource,
+    #[serde(default = "default_mod_source")]
+    pub mod_source_7: ModulationSource,
+    #[serde(default = "default_mod_source")]
+    pub mod_source_8: ModulationSource,
+    #[serde(default = "default_mod_dest")]
+    pub mod_dest_5: ModulationDestination,
+    #[serde(default = "default_mod_dest")]
+    pub mod_dest_6: ModulationDestination,
+    #[serde(default = "default_mod_dest")]
+    pub mod_dest_7: ModulationDestination,
+    #[serde(default = "default_mod_dest")]
+    pub mod_dest_8: ModulationDestination,
+    #[serde(default)]
+    pub mod_amount_5: f32,
+    #[serde(default)]
+    pub mod_amount_6: f32,
+    #[serde(default)]
+    pub mod_amount_7: f32,
+    #[serde(default)]
+    pub mod_amount_8: f32,
+    #[serde(default = "default_loop_xfade")]
+    pub mod1_loop_xfade: f32,
+    #[serde(default = "default_loop_xfade")]
+    pub mod2_loop_xfade: f32,
+    #[serde(default = "default_loop_xfade")]
+    pub mod3_loop_xfade: f32,
+    #[serde(default)]
+    pub filter_cutoff_smooth: f32,
+    #[serde(default)]
+    pub note_offset_cents: [f32; 12],
+    #[serde(default)]
+    pub mod1_osc_env_keyscale: f32,
+    #[serde(default)]
+    pub mod2_osc_env_keyscale: f32,
+    #[serde(default)]
+    pub mod3_osc_env_keyscale: f32,
+    #[serde(default = "default_reference_pitch")]
+    pub reference_pitch: f32,
+    #[serde(default)]
+    pub filter_stereo_offset_1: f32,
+    #[serde(default)]
+    pub filter_stereo_offset_2: f32,
+    #[serde(default)]
+    pub paraphonic: bool,
+    #[serde(default)]
+    pub mod1_osc_detune_hz: f32,
+    #[serde(default)]
+    pub mod2_osc_detune_hz: f32,
+    #[serde(default)]
+    pub mod3_osc_detune_hz: f32,
+    #[serde(default)]
+    pub use_bass_mono: bool,
+    #[serde(default = "default_elliptical_freq")]
+    pub elliptical_freq: f32,
+    #[serde(default = "default_unison_blend")]
+    pub mod1_osc_unison_blend: f32,
+    #[serde(default = "default_unison_blend")]
+    pub mod2_osc_unison_blend: f32,
+    #[serde(default = "default_unison_blend")]
+    pub mod3_osc_unison_blend: f32,
+    #[serde(default = "default_reverb_diffusion")]
+    pub reverb_diffusion: f32,
+    #[serde(default = "default_reverb_density")]
+    pub reverb_density: f32,
+    #[serde(default = "default_slice_count")]
+    pub mod1_slice_count: i32,
+    #[serde(default = "default_slice_count")]
+    pub mod2_slice_count: i32,
+    #[serde(default = "default_slice_count")]
+    pub mod3_slice_count: i32,
+    #[serde(default)]
+    pub use_looper: bool,
+    #[serde(default = "default_looper_length")]
+    pub looper_length: f32,
+    #[serde(default = "default_looper_feedback")]
+    pub looper_feedback: f32,
+    #[serde(default = "default_looper_mix")]
+    pub looper_mix: f32,
+    #[serde(default)]
+    pub looper_pitch: f32,
+    #[serde(default = "default_tape_stop_time")]
+    pub tape_stop_time: f32,
+    #[serde(default)]
+    pub mod1_sample_normalize: bool,
+    #[serde(default)]
+    pub mod2_sample_normalize: bool,
+    #[serde(default)]
+    pub mod3_sample_normalize: bool,
+    #[serde(default)]
+    pub space: f32,
+    #[serde(default)]
+    pub mod1_osc_punch: f32,
+    #[serde(default)]
+    pub mod2_osc_punch: f32,
+    #[serde(default)]
+    pub mod3_osc_punch: f32,
+    #[serde(default)]
+    pub mod1_phase_invert: bool,
+    #[serde(default)]
+    pub mod2_phase_invert: bool,
+    #[serde(default)]
+    pub mod3_phase_invert: bool,
+    #[serde(default = "default_true")]
+    pub live_waveform_switch: bool,
+    #[serde(default)]
+    pub use_resonator: bool,
+    #[serde(default = "default_resonator_chord")]
+    pub resonator_chord: ResonatorChord,
+    #[serde(default = "default_resonator_root")]
+    pub resonator_root: i32,
+    #[serde(default = "default_resonator_decay")]
+    pub resonator_decay: f32,
+    #[serde(default = "default_half")]
+    pub resonator_mix: f32,
+    #[serde(default)]
+    pub mod1_grain_spray: f32,
+    #[serde(default)]
+    pub mod2_grain_spray: f32,
+    #[serde(default)]
+    pub mod3_grain_spray: f32,
+}
+
+// Serde defaults for fields added after 1.3.7 so older presets still load
+fn default_one() -> f32 {
+    1.0
+}
+
+fn default_half() -> f32 {
+    0.5
+}
+
+fn default_sat_stereo_mode() -> SaturationStereoMode {
+    SaturationStereoMode::LR
+}
+
+fn default_osc_quality() -> Oscillator::OscQuality {
+    Oscillator::OscQuality::Normal
+}
+
+fn default_mono_mode() -> MonoMode {
+    MonoMode::Poly
+}
+
+fn default_osc_type_b() -> AudioModuleType {
+    AudioModuleType::Saw
+}
+
+fn default_pitch_mod_scale() -> PitchQuantizeScale {
+    PitchQuantizeScale::Chromatic
+}
+
+fn default_grain_window() -> GrainWindow {
+    GrainWindow::Linear
+}
+
+fn default_delay_2_time() -> DelaySnapValues {
+    DelaySnapValues::EighthD
+}
+
+fn default_delay_type() -> DelayType {
+    DelayType::Stereo
+}
+
+fn default_delay_routing() -> DelayRouting {
+    DelayRouting::Series
+}
+
+fn default_filter_env_loop_rate() -> LFOController::LFOSnapValues {
+    LFOController::LFOSnapValues::Eighth
+}
+
+fn default_sample_root() -> i32 {
+    60
+}
+
+fn default_voice_steal_mode() -> VoiceStealMode {
+    VoiceStealMode::Oldest
+}
+
+fn default_osc_shape() -> Oscillator::OscShape {
+    Oscillator::OscShape::Off
+}
+
+fn default_tuning_system() -> TuningSystem {
+    TuningSystem::TwelveTET
+}
+
+fn default_master_hpf() -> f32 {
+    10.0
+}
+
+fn default_gate_rate() -> LFOController::LFOSnapValues {
+    LFOController::LFOSnapValues::Sixteen
+}
+
+fn default_gate_smooth() -> f32 {
+    0.2
+}
+
+fn default_env_time_range() -> EnvTimeRange {
+    EnvTimeRange::Normal
+}
+
+fn default_mod_source() -> ModulationSource {
+    ModulationSource::None
+}
+
+fn default_mod_dest() -> ModulationDestination {
+    ModulationDestination::None
+}
+
+fn default_loop_xfade() -> f32 {
+    5.0
+}
+
+fn default_reference_pitch() -> f32 {
+    440.0
+}
+
+fn default_elliptical_freq() -> f32 {
+    120.0
+}
+
+fn default_unison_blend() -> f32 {
+    0.5
+}
+
+fn default_reverb_diffusion() -> f32 {
+    0.5
+}
+
+fn default_reverb_density() -> f32 {
+    1.0
+}
+
+fn default_slice_count() -> i32 {
+    1
+}
+
+fn default_looper_length() -> f32 {
+    1000.0
+}
+
+fn default_looper_feedback() -> f32 {
+    0.5
+}
+
+fn default_looper_mix() -> f32 {
+    0.5
+}
+
+fn default_tape_stop_time() -> f32 {
+    1000.0
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_resonator_chord() -> ResonatorChord {
+    ResonatorChord::Major
+}
+
+fn default_resonator_root() -> i32 {
+    48
+}
+
+fn default_resonator_decay() -> f32 {
+    4.0
 }
\ No newline at end of file
@@ -0,0 +1,140 @@
+// Actuate Undo
+// Snapshot based undo/redo for parameter changes made in the GUI
+// Ardura
+
+use std::collections::VecDeque;
+use nih_plug::prelude::{GuiContext, ParamFlags, ParamPtr, Params};
+use nih_plug_egui::egui::{Context, Key};
+
+// How many steps back we keep before dropping the oldest
+const UNDO_DEPTH: usize = 64;
+
+// Anything closer than this is considered the same value
+const SNAPSHOT_EPSILON: f32 = 0.000001;
+
+/// A lightweight copy of every tracked param as normalized values
+#[derive(Clone, PartialEq)]
+pub(crate) struct ParamSnapshot {
+    values: Vec<f32>,
+}
+
+/// Undo/redo stacks for the GUI
+/// Changes are only recorded once the mouse is released so a whole knob drag becomes one entry
+pub(crate) struct UndoHistory {
+    params: Vec<ParamPtr>,
+    committed: ParamSnapshot,
+    undo_stack: VecDeque<ParamSnapshot>,
+    redo_stack: VecDeque<ParamSnapshot>,
+    gesture_active: bool,
+}
+
+impl UndoHistory {
+    pub fn new(params: &impl Params) -> Self {
+        // Skip hidden params and the "param_" dummy buttons - those are UI triggers, not sound
+        let params: Vec<ParamPtr> = params
+            .param_map()
+            .into_iter()
+            .filter(|(id, ptr, _)| {
+                !id.starts_with("param_") && unsafe { !ptr.flags().contains(ParamFlags::HIDDEN) }
+            })
+            .map(|(_, ptr, _)| ptr)
+            .collect();
+        let committed = Self::capture(&params);
+        UndoHistory {
+            params,
+            committed,
+            undo_stack: VecDeque::with_capacity(UNDO_DEPTH),
+            redo_stack: VecDeque::with_capacity(UNDO_DEPTH),
+            gesture_active: false,
+        }
+    }
+
+    fn capture(params: &[ParamPtr]) -> ParamSnapshot {
+        ParamSnapshot {
+            values: params
+                .iter()
+                .map(|ptr| unsafe { ptr.unmodulated_normalized_value() })
+                .collect(),
+        }
+    }
+
+    // Both stacks hold at most UNDO_DEPTH entries, the oldest falls off first
+    fn push_capped(stack: &mut VecDeque<ParamSnapshot>, snapshot: ParamSnapshot) {
+        if stack.len() >= UNDO_DEPTH {
+            stack.pop_front();
+        }
+        stack.push_back(snapshot);
+    }
+
+    fn differs(a: &ParamSnapshot, b: &ParamSnapshot) -> bool {
+        a.values
+            .iter()
+            .zip(b.values.iter())
+            .any(|(x, y)| (x - y).abs() > SNAPSHOT_EPSILON)
+    }
+
+    // Call this once per frame from the editor
+    pub fn track(&mut self, ctx: &Context, gui_context: &dyn GuiContext) {
+        let (pointer_down, undo_pressed, redo_pressed) = ctx.input(|i| {
+            (
+                i.pointer.any_down(),
+                i.modifiers.command && !i.modifiers.shift && i.key_pressed(Key::Z),
+                i.modifiers.command
+                    && (i.key_pressed(Key::Y) || (i.modifiers.shift && i.key_pressed(Key::Z))),
+            )
+        });
+
+        let current = Self::capture(&self.params);
+        if pointer_down {
+            // Hold off until the drag finishes so we coalesce it
+            self.gesture_active = true;
+        } else if self.gesture_active {
+            self.gesture_active = false;
+            if Self::differs(&current, &self.committed) {
+                Self::push_capped(
+                    &mut self.undo_stack,
+                    std::mem::replace(&mut self.committed, current),
+                );
+                self.redo_stack.clear();
+            }
+        } else if Self::differs(&current, &self.committed) {
+            // Host automation or a preset load - follow it without making an undo step
+            self.committed = current;
+        }
+
+        if self.gesture_active {
+            return;
+        }
+        if undo_pressed {
+            if let Some(previous) = self.undo_stack.pop_back() {
+                let now = std::mem::replace(&mut self.committed, previous.clone());
+                Self::push_capped(&mut self.redo_stack, now.clone());
+                self.restore(gui_context, &now, &previous);
+            }
+        } else if redo_pressed {
+            if let Some(next) = self.redo_stack.pop_back() {
+                let now = std::mem::replace(&mut self.committed, next.clone());
+                Self::push_capped(&mut self.undo_stack, now.clone());
+                self.restore(gui_context, &now, &next);
+            }
+        }
+    }
+
+    // Only touch params that actually changed so the host doesn't get flooded with automation
+    fn restore(&self, gui_context: &dyn GuiContext, from: &ParamSnapshot, to: &ParamSnapshot) {
+        for ((ptr, old), new) in self
+            .params
+            .iter()
+            .zip(from.values.iter())
+            .zip(to.values.iter())
+        {
+            if (old - new).abs() > SNAPSHOT_EPSILON {
+                unsafe {
+                    gui_context.raw_begin_set_parameter(*ptr);
+                    gui_context.raw_set_parameter_normalized(*ptr, *new);
+                    gui_context.raw_end_set_parameter(*ptr);
+                }
+            }
+        }
+    }
+}
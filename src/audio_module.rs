@@ -31,21 +31,44 @@ use rand::Rng;
 use rayon::iter::{IntoParallelIterator, IntoParallelRefMutIterator, ParallelIterator};
 use serde::{Deserialize, Serialize};
 use AdditiveModule::{AdditiveHarmonic, AdditiveOscillator};
-use std::{collections::VecDeque, f32::consts::SQRT_2, path::{Path, PathBuf}, sync::Arc};
+use std::{collections::VecDeque, f32::consts::{FRAC_PI_2, PI, SQRT_2}, path::{Path, PathBuf}, sync::Arc};
 
 // Audio module files
 pub(crate) mod Oscillator;
 pub(crate) mod frequency_modulation;
 pub(crate) mod AdditiveModule;
-use self::Oscillator::{DeterministicWhiteNoiseGenerator, OscState, RetriggerStyle, SmoothStyle};
+pub(crate) mod spectral_stretch;
+pub(crate) mod output_capture;
+use self::spectral_stretch::{SpectralFrames, StretchPlayhead};
+use self::output_capture::OutputCapture;
+use self::Oscillator::{DeterministicWhiteNoiseGenerator, OscQuality, OscState, RetriggerStyle, SmoothStyle};
 use crate::{
-    actuate_enums::{AMFilterRouting, FilterAlgorithms, FilterRouting, StereoAlgorithm}, adv_scale_value, 
-    fx::{A4I_Filter::A4iFilter, A4II_Filter::A4iiFilter, StateVariableFilter::{ResonanceType, StateVariableFilter}, TiltFilter::{self, ResponseType, TiltFilterStruct}, V4Filter::V4FilterStruct, VCFilter::{ResponseType as VCFResponseType, VCFilter}}, ActuateParams, CustomWidgets::{ui_knob::{self, KnobLayout}, CustomVerticalSlider}, 
+    actuate_enums::{AMFilterRouting, EnvTimeRange, FilterAlgorithms, FilterRouting, StereoAlgorithm, TuningSystem, VoiceStealMode}, adv_scale_value, 
+    fx::{remove_denormals, A4I_Filter::A4iFilter, A4II_Filter::A4iiFilter, StateVariableFilter::{ResonanceType, StateVariableFilter}, TiltFilter::{self, ResponseType, TiltFilterStruct}, V4Filter::V4FilterStruct, VCFilter::{ResponseType as VCFResponseType, VCFilter}}, ActuateParams, CustomWidgets::{ui_knob::{self, KnobLayout}, CustomVerticalSlider}, 
     PitchRouting, DARK_GREY_UI_COLOR, FONT_COLOR, LIGHTER_GREY_UI_COLOR, MEDIUM_GREY_UI_COLOR, SMALLER_FONT, WIDTH, YELLOW_MUSTARD
 };
 use crate::{CustomWidgets::{BeizerButton::{self, ButtonLayout}, BoolButton}, DARKER_GREY_UI_COLOR};
 use CustomVerticalSlider::ParamSlider as VerticalParamSlider;
 
+// Ramp time for the waveform morph so jumps in the knob or mod don't click
+const MORPH_SMOOTHING_MS: f32 = 20.0;
+// Crossfade length when a filter algorithm changes, in seconds
+const FILTER_ALG_FADE_TIME: f32 = 0.005;
+// Time constant of the oscillator punch transient in seconds
+const PUNCH_DECAY_TIME: f32 = 0.004;
+// Averaging time of the drive stage's RMS makeup in seconds
+const DRIVE_RMS_TIME: f32 = 0.05;
+// Base seed for grain spray, mixed with the note at each note on
+const SPRAY_SEED: u32 = 0x2545_F491;
+// Stretched notes that can sound at once, each playhead holds a few frames of buffers
+const STRETCH_POOL_SIZE: usize = 16;
+// Crossfade length when the waveform changes under held notes
+const WAVE_SWITCH_FADE_TIME: f32 = 0.005;
+// Peak level loaded samples are scaled to when Normalize is on
+const SAMPLE_NORMALIZE_DB: f32 = -1.0;
+// Release velocity that leaves the release time alone, also used for note offs that carry no velocity
+const RELEASE_VEL_NEUTRAL: f32 = 64.0 / 127.0;
+
 // When you create a new audio module, you should add it here
 #[derive(Debug, Enum, PartialEq, Clone, Copy, Serialize, Deserialize)]
 pub enum AudioModuleType {
@@ -68,6 +91,24 @@ pub enum AudioModuleType {
     UnsetAm,
 }
 
+// Amplitude window applied to each grain in the Granulizer
+#[derive(Debug, Enum, PartialEq, Clone, Copy, Serialize, Deserialize)]
+pub enum GrainWindow {
+    Linear,     // Straight crossfade ramps with a flat top, the original grain shape
+    Hann,
+    Triangle,
+    Tukey,
+    Gaussian,
+}
+
+// Note pitch offsets from the humanize and mono/glide handling in lib.rs, in semitones
+// Humanize is picked once when a note starts, glide follows the held note and stops moving once it releases
+#[derive(Clone, Copy, Default)]
+pub struct NotePitch {
+    pub humanize: f32,
+    pub glide: f32,
+}
+
 #[derive(Clone)]
 struct VoiceVec {
     /// The identifier for this voice
@@ -90,10 +131,10 @@ pub struct SingleVoice {
     _velocity: f32,
     /// Mod amount for velocity inputted to this AM
     vel_mod_amount: f32,
-    /// The voice's current phase.
-    phase: f32,
+    /// The voice's current phase. Kept in f64 so long held notes don't drift off pitch from rounding.
+    phase: f64,
     /// The phase increment. This is based on the voice's frequency, derived from the note index.
-    phase_delta: f32,
+    phase_delta: f64,
     /// Oscillator state for amplitude controlling
     state: Oscillator::OscState,
     // These are the attack and release smoothers
@@ -129,10 +170,24 @@ pub struct SingleVoice {
 
     // This is only used for unison detunes
     _angle: f32,
+    // Warmth lowpass memory
+    warmth_state: f32,
+    // Half speed phase for the -1 octave drawbar
+    sub_phase: f64,
+    // Transient level added over the envelope, decays away in the first few ms
+    punch: f32,
+    // Waveforms at note on, held voices keep these when live waveform switching is off
+    wave_a: AudioModuleType,
+    wave_b: AudioModuleType,
+    // Pitch offsets from NotePitch, kept per voice so a new note never moves the ones already sounding
+    humanize_detune: f32,
+    glide_offset: f32,
 
     // Sampler/Granulizer Pos
     sample_pos: usize,
     loop_it: bool,
+    // Slot in the module's stretch pool, handed out at note on
+    stretch: Option<usize>,
     grain_start_pos: usize,
     _granular_hold: i32,
     _granular_gap: i32,
@@ -176,6 +231,9 @@ pub struct SingleVoice {
     filter_atk_smoother_2: Smoother<f32>,
     filter_dec_smoother_2: Smoother<f32>,
     filter_rel_smoother_2: Smoother<f32>,
+    // Drive stage level followers for each filter
+    drive_level_1: DriveLevel,
+    drive_level_2: DriveLevel,
     // V4 Filter
     V4F_l_1: V4FilterStruct,
     V4F_l_2: V4FilterStruct,
@@ -210,10 +268,10 @@ pub struct SingleUnisonVoice {
     _velocity: f32,
     /// Mod amount for velocity inputted to this AM
     vel_mod_amount: f32,
-    /// The voice's current phase.
-    phase: f32,
+    /// The voice's current phase. Kept in f64 so long held notes don't drift off pitch from rounding.
+    phase: f64,
     /// The phase increment. This is based on the voice's frequency, derived from the note index.
-    phase_delta: f32,
+    phase_delta: f64,
     /// Oscillator state for amplitude controlling
     state: Oscillator::OscState,
     // These are the attack and release smoothers
@@ -246,10 +304,16 @@ pub struct SingleUnisonVoice {
 
     // This is only used for unison detunes
     _angle: f32,
+    // Warmth lowpass memory
+    warmth_state: f32,
+    // Half speed phase for the -1 octave drawbar
+    sub_phase: f64,
 
     // Sampler/Granulizer Pos
     sample_pos: usize,
     loop_it: bool,
+    // Slot in the module's stretch pool, handed out at note on
+    stretch: Option<usize>,
     grain_start_pos: usize,
     _granular_hold: i32,
     _granular_gap: i32,
@@ -303,13 +367,29 @@ pub struct AudioModule {
     // Restretch length with tracking bool
     pub restretch: bool,
     pub prev_restretch: bool,
+    // Root note of the loaded sample and the root the sample library was last built with
+    pub sample_root: i32,
+    prev_sample_root: i32,
 
     // Granulizer other options
     pub start_position: f32,
     pub _end_position: f32,
+    pub stretch_factor: f32,
+    // Sampler loop crossfade in ms
+    pub loop_xfade: f32,
+    // Drum slicer slice count, 1 plays the whole sample
+    pub slice_count: i32,
+    pub spectral_frames: SpectralFrames,
+    // Playheads for stretched notes, built with the analysis so note on only picks a free one
+    stretch_pool: Vec<StretchPlayhead>,
+    stretch_seed: u32,
     pub grain_hold: i32,
     pub grain_gap: i32,
+    pub grain_spray: f32,
+    // Reseeded every note so the same playing scatters grains the same way
+    spray_rng: u32,
     pub grain_crossfade: i32,
+    pub grain_window: GrainWindow,
 
     ///////////////////////////////////////////////////////////
 
@@ -319,6 +399,12 @@ pub struct AudioModule {
     pub osc_octave: i32,
     pub osc_semitones: i32,
     pub osc_detune: f32,
+    pub osc_detune_hz: f32,
+    pub osc_punch: f32,
+    live_waveform_switch: bool,
+    // Waveforms being faded out after a live switch, and how much of them is left
+    prev_waves: (AudioModuleType, AudioModuleType),
+    wave_fade: f32,
     pub osc_attack: f32,
     pub osc_decay: f32,
     pub osc_sustain: f32,
@@ -327,9 +413,41 @@ pub struct AudioModule {
     pub osc_atk_curve: SmoothStyle,
     pub osc_dec_curve: SmoothStyle,
     pub osc_rel_curve: SmoothStyle,
+    // How much higher notes shorten decay and release
+    pub osc_env_keyscale: f32,
     pub osc_unison: i32,
     pub osc_unison_detune: f32,
+    pub osc_unison_blend: f32,
+    // Center and detuned voice levels from the blend, worked out when params change instead of per sample
+    unison_center_gain: f32,
+    unison_side_gain: f32,
     pub osc_stereo: f32,
+    // What the voices actually play with the Super macro layered over the unison knobs
+    // The knob fields above stay as set so saving a patch never bakes Super into them
+    voice_unison: i32,
+    voice_unison_detune: f32,
+    voice_stereo: f32,
+    pub osc_quality: OscQuality,
+    pub release_vel_amount: f32,
+    // Second waveform the oscillator crossfades into
+    pub osc_type_b: AudioModuleType,
+    pub global_phase_reset: bool,
+    pub voice_spread: f32,
+    pub voice_steal_mode: VoiceStealMode,
+    pub tuning_system: TuningSystem,
+    pub tuning_root: i32,
+    // Cents added to each pitch class, C to B
+    pub note_offset_cents: [f32; 12],
+    // A4 in Hz
+    pub reference_pitch: f32,
+    pub osc_morph: f32,
+    pub osc_shape: Oscillator::OscShape,
+    pub osc_shape_amount: f32,
+    pub osc_warmth: f32,
+    // -1, root, +1 and +2 octave levels
+    pub osc_drawbars: [f32; 4],
+    morph_target: f32,
+    morph_smoother: Smoother<f32>,
 
     // Voice storage
     playing_voices: VoiceVec,
@@ -409,15 +527,40 @@ pub struct AudioModule {
 
     pub filter_env_peak: f32,
     pub filter_env_peak_2: f32,
+    pub filter_env_invert_1: bool,
+    pub filter_env_invert_2: bool,
     pub filter_alg_type: FilterAlgorithms,
     pub filter_alg_type_2: FilterAlgorithms,
+    // Algorithm being faded out after a switch and how much of it is left
+    filter_alg_prev: FilterAlgorithms,
+    filter_alg_fade: f32,
+    filter_alg_prev_2: FilterAlgorithms,
+    filter_alg_fade_2: f32,
     
     pub filter_cutoff: f32,
     pub filter_cutoff_2: f32,
+    // Cutoff knob values, filter_cutoff glides to these over filter_cutoff_smooth ms
+    cutoff_knob: f32,
+    cutoff_knob_2: f32,
+    cutoff_target: f32,
+    cutoff_target_2: f32,
+    cutoff_smoother: Smoother<f32>,
+    cutoff_smoother_2: Smoother<f32>,
+    filter_cutoff_smooth: f32,
 
     pub filter_wet: f32,
     pub filter_wet_2: f32,
 
+    pub filter_drive: f32,
+    pub filter_drive_2: f32,
+    // Right channel cutoff offsets in semitones
+    pub filter_stereo_offset: f32,
+    pub filter_stereo_offset_2: f32,
+    // New notes restart the filter envelopes of held notes
+    pub paraphonic: bool,
+    pub filter_auto_gain: bool,
+    pub eco_mode: bool,
+
     pub filter_env_attack: f32,
     pub filter_env_decay: f32,
     pub filter_env_sustain: f32,
@@ -475,16 +618,32 @@ impl Default for AudioModule {
             single_cycle: false,
             restretch: true,
             prev_restretch: false,
+            sample_root: 60,
+            prev_sample_root: 60,
             start_position: 0.0,
             _end_position: 1.0,
+            stretch_factor: 1.0,
+            loop_xfade: 5.0,
+            slice_count: 1,
+            spectral_frames: SpectralFrames::new(),
+            stretch_pool: Vec::new(),
+            stretch_seed: 1,
             grain_hold: 200,
             grain_gap: 200,
+            grain_spray: 0.0,
+            spray_rng: SPRAY_SEED,
             grain_crossfade: 50,
+            grain_window: GrainWindow::Linear,
 
             // Osc module knob storage
             osc_octave: 0,
             osc_semitones: 0,
             osc_detune: 0.0,
+            osc_detune_hz: 0.0,
+            osc_punch: 0.0,
+            live_waveform_switch: true,
+            prev_waves: (AudioModuleType::Sine, AudioModuleType::Saw),
+            wave_fade: 0.0,
             osc_attack: 0.0001,
             osc_decay: 0.0001,
             osc_sustain: 1999.9,
@@ -492,10 +651,34 @@ impl Default for AudioModule {
             osc_retrigger: RetriggerStyle::Free,
             osc_atk_curve: SmoothStyle::Linear,
             osc_rel_curve: SmoothStyle::Linear,
+            osc_env_keyscale: 0.0,
             osc_dec_curve: SmoothStyle::Linear,
             osc_unison: 1,
             osc_unison_detune: 0.0,
+            osc_unison_blend: 0.5,
+            unison_center_gain: 1.0,
+            unison_side_gain: 1.0,
             osc_stereo: 1.0,
+            voice_unison: 1,
+            voice_unison_detune: 0.0,
+            voice_stereo: 1.0,
+            osc_quality: OscQuality::Normal,
+            release_vel_amount: 0.0,
+            osc_type_b: AudioModuleType::Saw,
+            global_phase_reset: false,
+            voice_spread: 0.0,
+            voice_steal_mode: VoiceStealMode::Oldest,
+            tuning_system: TuningSystem::TwelveTET,
+            tuning_root: 0,
+            note_offset_cents: [0.0; 12],
+            reference_pitch: 440.0,
+            osc_morph: 0.0,
+            osc_shape: Oscillator::OscShape::Off,
+            osc_shape_amount: 0.5,
+            osc_warmth: 0.0,
+            osc_drawbars: [0.0, 1.0, 0.0, 0.0],
+            morph_target: 0.0,
+            morph_smoother: Smoother::new(SmoothingStyle::Linear(MORPH_SMOOTHING_MS)),
 
             // Voice storage
             playing_voices: VoiceVec {
@@ -574,15 +757,36 @@ impl Default for AudioModule {
             filter_routing: FilterRouting::Parallel,
             filter_cutoff: 20000.0,
             filter_cutoff_2: 20000.0,
+            cutoff_knob: 20000.0,
+            cutoff_knob_2: 20000.0,
+            cutoff_target: 20000.0,
+            cutoff_target_2: 20000.0,
+            cutoff_smoother: cutoff_smoother(0.0, 20000.0),
+            cutoff_smoother_2: cutoff_smoother(0.0, 20000.0),
+            filter_cutoff_smooth: 0.0,
 
             filter_env_peak: 0.0,
             filter_env_peak_2: 0.0,
+            filter_env_invert_1: false,
+            filter_env_invert_2: false,
             filter_alg_type: FilterAlgorithms::SVF,
+            filter_alg_prev: FilterAlgorithms::SVF,
+            filter_alg_fade: 0.0,
+            filter_alg_prev_2: FilterAlgorithms::SVF,
+            filter_alg_fade_2: 0.0,
             filter_alg_type_2: FilterAlgorithms::SVF,
 
             filter_wet: 1.0,
             filter_wet_2: 1.0,
 
+            filter_drive: 0.0,
+            filter_drive_2: 0.0,
+            filter_stereo_offset: 0.0,
+            filter_stereo_offset_2: 0.0,
+            paraphonic: false,
+            filter_auto_gain: false,
+            eco_mode: false,
+
             filter_env_attack: 30.0,
             filter_env_decay: 0.0,
             filter_env_sustain: 1999.9,
@@ -638,6 +842,7 @@ impl AudioModule {
         module1: &Arc<std::sync::Mutex<AudioModule>>,
         module2: &Arc<std::sync::Mutex<AudioModule>>,
         module3: &Arc<std::sync::Mutex<AudioModule>>,
+        capture: &Arc<std::sync::Mutex<OutputCapture>>,
     ) {
         let am_type;
         let osc_retrigger;
@@ -646,7 +851,17 @@ impl AudioModule {
         let osc_stereo;
         let osc_unison;
         let osc_detune;
+        let osc_detune_hz;
+        let osc_punch;
         let osc_unison_detune;
+        let osc_unison_blend;
+        let osc_super;
+        let osc_type_b;
+        let osc_morph;
+        let osc_shape;
+        let osc_shape_amount;
+        let osc_warmth;
+        let osc_drawbars;
         let osc_attack;
         let osc_decay;
         let osc_sustain;
@@ -654,15 +869,24 @@ impl AudioModule {
         let osc_atk_curve;
         let osc_dec_curve;
         let osc_rel_curve;
+        let osc_env_keyscale;
         let load_sample;
+        let freeze_sample;
         let restretch;
+        let sample_root;
         let loop_sample;
         let single_cycle;
+        let sample_normalize;
         let start_position;
         let end_position;
+        let stretch_factor;
+        let loop_xfade;
+        let slice_count;
         let grain_crossfade;
+        let grain_window;
         let grain_hold;
         let grain_gap;
+        let grain_spray;
         let additive_harmonic_0;
         let additive_harmonic_1;
         let additive_harmonic_2;
@@ -688,7 +912,17 @@ impl AudioModule {
                 osc_stereo = &params.osc_1_stereo;
                 osc_unison = &params.osc_1_unison;
                 osc_detune = &params.osc_1_detune;
+                osc_detune_hz = &params.osc_1_detune_hz;
+                osc_punch = &params.osc_1_punch;
                 osc_unison_detune = &params.osc_1_unison_detune;
+                osc_unison_blend = &params.osc_1_unison_blend;
+                osc_super = &params.osc_1_super;
+                osc_type_b = &params.osc_1_type_b;
+                osc_morph = &params.osc_1_morph;
+                osc_shape = &params.osc_1_shape;
+                osc_shape_amount = &params.osc_1_shape_amount;
+                osc_warmth = &params.osc_1_warmth;
+                osc_drawbars = [&params.osc_1_drawbar_sub, &params.osc_1_drawbar_root, &params.osc_1_drawbar_oct, &params.osc_1_drawbar_oct2];
                 osc_attack = &params.osc_1_attack;
                 osc_decay = &params.osc_1_decay;
                 osc_sustain = &params.osc_1_sustain;
@@ -696,15 +930,24 @@ impl AudioModule {
                 osc_atk_curve = &params.osc_1_atk_curve;
                 osc_dec_curve = &params.osc_1_dec_curve;
                 osc_rel_curve = &params.osc_1_rel_curve;
+                osc_env_keyscale = &params.osc_1_env_keyscale;
                 load_sample = &params.load_sample_1;
+                freeze_sample = &params.freeze_sample_1;
                 restretch = &params.restretch_1;
+                sample_root = &params.sample_root_1;
                 loop_sample = &params.loop_sample_1;
                 single_cycle = &params.single_cycle_1;
+                sample_normalize = &params.sample_normalize_1;
                 start_position = &params.start_position_1;
                 end_position = &params.end_position_1;
+                stretch_factor = &params.stretch_factor_1;
+                loop_xfade = &params.loop_xfade_1;
+                slice_count = &params.slice_count_1;
                 grain_crossfade = &params.grain_crossfade_1;
+                grain_window = &params.grain_window_1;
                 grain_hold = &params.grain_hold_1;
                 grain_gap = &params.grain_gap_1;
+                grain_spray = &params.grain_spray_1;
                 additive_harmonic_0 = &params.additive_amp_1_0;
                 additive_harmonic_1 = &params.additive_amp_1_1;
                 additive_harmonic_2 = &params.additive_amp_1_2;
@@ -730,7 +973,17 @@ impl AudioModule {
                 osc_stereo = &params.osc_2_stereo;
                 osc_unison = &params.osc_2_unison;
                 osc_detune = &params.osc_2_detune;
+                osc_detune_hz = &params.osc_2_detune_hz;
+                osc_punch = &params.osc_2_punch;
                 osc_unison_detune = &params.osc_2_unison_detune;
+                osc_unison_blend = &params.osc_2_unison_blend;
+                osc_super = &params.osc_2_super;
+                osc_type_b = &params.osc_2_type_b;
+                osc_morph = &params.osc_2_morph;
+                osc_shape = &params.osc_2_shape;
+                osc_shape_amount = &params.osc_2_shape_amount;
+                osc_warmth = &params.osc_2_warmth;
+                osc_drawbars = [&params.osc_2_drawbar_sub, &params.osc_2_drawbar_root, &params.osc_2_drawbar_oct, &params.osc_2_drawbar_oct2];
                 osc_attack = &params.osc_2_attack;
                 osc_decay = &params.osc_2_decay;
                 osc_sustain = &params.osc_2_sustain;
@@ -738,15 +991,24 @@ impl AudioModule {
                 osc_atk_curve = &params.osc_2_atk_curve;
                 osc_dec_curve = &params.osc_2_dec_curve;
                 osc_rel_curve = &params.osc_2_rel_curve;
+                osc_env_keyscale = &params.osc_2_env_keyscale;
                 load_sample = &params.load_sample_2;
+                freeze_sample = &params.freeze_sample_2;
                 restretch = &params.restretch_2;
+                sample_root = &params.sample_root_2;
                 loop_sample = &params.loop_sample_2;
                 single_cycle = &params.single_cycle_2;
+                sample_normalize = &params.sample_normalize_2;
                 start_position = &params.start_position_2;
                 end_position = &params.end_position_2;
+                stretch_factor = &params.stretch_factor_2;
+                loop_xfade = &params.loop_xfade_2;
+                slice_count = &params.slice_count_2;
                 grain_crossfade = &params.grain_crossfade_2;
+                grain_window = &params.grain_window_2;
                 grain_hold = &params.grain_hold_2;
                 grain_gap = &params.grain_gap_2;
+                grain_spray = &params.grain_spray_2;
                 additive_harmonic_0 = &params.additive_amp_2_0;
                 additive_harmonic_1 = &params.additive_amp_2_1;
                 additive_harmonic_2 = &params.additive_amp_2_2;
@@ -772,7 +1034,17 @@ impl AudioModule {
                 osc_stereo = &params.osc_3_stereo;
                 osc_unison = &params.osc_3_unison;
                 osc_detune = &params.osc_3_detune;
+                osc_detune_hz = &params.osc_3_detune_hz;
+                osc_punch = &params.osc_3_punch;
                 osc_unison_detune = &params.osc_3_unison_detune;
+                osc_unison_blend = &params.osc_3_unison_blend;
+                osc_super = &params.osc_3_super;
+                osc_type_b = &params.osc_3_type_b;
+                osc_morph = &params.osc_3_morph;
+                osc_shape = &params.osc_3_shape;
+                osc_shape_amount = &params.osc_3_shape_amount;
+                osc_warmth = &params.osc_3_warmth;
+                osc_drawbars = [&params.osc_3_drawbar_sub, &params.osc_3_drawbar_root, &params.osc_3_drawbar_oct, &params.osc_3_drawbar_oct2];
                 osc_attack = &params.osc_3_attack;
                 osc_decay = &params.osc_3_decay;
                 osc_sustain = &params.osc_3_sustain;
@@ -780,15 +1052,24 @@ impl AudioModule {
                 osc_atk_curve = &params.osc_3_atk_curve;
                 osc_dec_curve = &params.osc_3_dec_curve;
                 osc_rel_curve = &params.osc_3_rel_curve;
+                osc_env_keyscale = &params.osc_3_env_keyscale;
                 load_sample = &params.load_sample_3;
+                freeze_sample = &params.freeze_sample_3;
                 restretch = &params.restretch_3;
+                sample_root = &params.sample_root_3;
                 loop_sample = &params.loop_sample_3;
                 single_cycle = &params.single_cycle_3;
+                sample_normalize = &params.sample_normalize_3;
                 start_position = &params.start_position_3;
                 end_position = &params.end_position_3;
+                stretch_factor = &params.stretch_factor_3;
+                loop_xfade = &params.loop_xfade_3;
+                slice_count = &params.slice_count_3;
                 grain_crossfade = &params.grain_crossfade_3;
+                grain_window = &params.grain_window_3;
                 grain_hold = &params.grain_hold_3;
                 grain_gap = &params.grain_gap_3;
+                grain_spray = &params.grain_spray_3;
                 additive_harmonic_0 = &params.additive_amp_3_0;
                 additive_harmonic_1 = &params.additive_amp_3_1;
                 additive_harmonic_2 = &params.additive_amp_3_2;
@@ -874,6 +1155,21 @@ Retrigger: wave form restarts at every new note
 Random: Wave and all unisons use a new random phase every note
 MRandom: Every voice uses its own unique random phase every note".to_string());
                             ui.add(osc_1_retrigger_knob);
+
+                            let osc_1_super_knob = ui_knob::ArcKnob::for_param(
+                                osc_super,
+                                setter,
+                                KNOB_SIZE,
+                                KnobLayout::Horizonal,
+                            )
+                            .preset_style(ui_knob::KnobStyle::Preset1)
+                            .set_fill_color(DARK_GREY_UI_COLOR)
+                            .set_line_color(YELLOW_MUSTARD.gamma_multiply(2.0))
+                            .use_outline(true)
+                            .set_text_size(TEXT_SIZE)
+                            .set_hover_text("Supersaw macro: raises Multiply, MDetune and Stereo together.
+0 leaves those knobs alone, 1 is a full 7 voice super".to_string());
+                            ui.add(osc_1_super_knob);
                         });
 
                         ui.vertical(|ui| {
@@ -905,6 +1201,20 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                             .set_hover_text("How many voices should play per key/note.
 You may also know this as mixture, course, or unison".to_string());
                             ui.add(osc_1_unison_knob);
+
+                            let osc_1_punch_knob = ui_knob::ArcKnob::for_param(
+                                osc_punch,
+                                setter,
+                                KNOB_SIZE,
+                                KnobLayout::Horizonal,
+                            )
+                            .preset_style(ui_knob::KnobStyle::Preset1)
+                            .set_fill_color(DARK_GREY_UI_COLOR)
+                            .set_line_color(YELLOW_MUSTARD)
+                            .use_outline(true)
+                            .set_text_size(TEXT_SIZE)
+                            .set_hover_text("Adds a short level spike over the first few ms of each note for snappier kicks and plucks. Works on top of any attack".to_string());
+                            ui.add(osc_1_punch_knob);
                         });
 
                         ui.vertical(|ui| {
@@ -935,6 +1245,20 @@ You may also know this as mixture, course, or unison".to_string());
                             .set_text_size(TEXT_SIZE)
                             .set_hover_text("Spread the pitches of the multiplied voices apart".to_string());
                             ui.add(osc_1_unison_detune_knob);
+
+                            let osc_1_unison_blend_knob = ui_knob::ArcKnob::for_param(
+                                osc_unison_blend,
+                                setter,
+                                KNOB_SIZE,
+                                KnobLayout::Horizonal,
+                            )
+                            .preset_style(ui_knob::KnobStyle::Preset1)
+                            .set_fill_color(DARK_GREY_UI_COLOR)
+                            .set_line_color(YELLOW_MUSTARD.gamma_multiply(2.0))
+                            .use_outline(true)
+                            .set_text_size(TEXT_SIZE)
+                            .set_hover_text("Balance the in tune center voice against the detuned voices. Middle is both at full level, lower is hollow, higher is focused".to_string());
+                            ui.add(osc_1_unison_blend_knob);
                         });
 
                         ui.vertical(|ui| {
@@ -965,6 +1289,116 @@ You may also know this as mixture, course, or unison".to_string());
                             .set_text_size(TEXT_SIZE)
                             .set_hover_text("Oscillator voice stereo spread. 0 is Mono.".to_string());
                             ui.add(osc_1_stereo_knob);
+
+                            let osc_1_detune_hz_knob = ui_knob::ArcKnob::for_param(
+                                osc_detune_hz,
+                                setter,
+                                KNOB_SIZE,
+                                KnobLayout::Horizonal,
+                            )
+                            .preset_style(ui_knob::KnobStyle::Preset1)
+                            .set_fill_color(DARK_GREY_UI_COLOR)
+                            .set_line_color(YELLOW_MUSTARD.gamma_multiply(2.0))
+                            .use_outline(true)
+                            .set_text_size(TEXT_SIZE)
+                            .set_hover_text("Offset the pitch by a fixed amount of Hz. Beats at the same rate on every note unlike Fine".to_string());
+                            ui.add(osc_1_detune_hz_knob);
+                        });
+
+                        ui.vertical(|ui| {
+                            let osc_1_type_b_knob = ui_knob::ArcKnob::for_param(
+                                osc_type_b,
+                                setter,
+                                KNOB_SIZE,
+                                KnobLayout::Horizonal,
+                            )
+                            .preset_style(ui_knob::KnobStyle::Preset1)
+                            .set_fill_color(DARK_GREY_UI_COLOR)
+                            .set_line_color(YELLOW_MUSTARD)
+                            .use_outline(true)
+                            .set_text_size(TEXT_SIZE)
+                            .set_hover_text("Second wave form that Morph fades into.
+Non-oscillator types fade to silence".to_string());
+                            ui.add(osc_1_type_b_knob);
+
+                            let osc_1_morph_knob = ui_knob::ArcKnob::for_param(
+                                osc_morph,
+                                setter,
+                                KNOB_SIZE,
+                                KnobLayout::Horizonal,
+                            )
+                            .preset_style(ui_knob::KnobStyle::Preset1)
+                            .set_fill_color(DARK_GREY_UI_COLOR)
+                            .set_line_color(YELLOW_MUSTARD.gamma_multiply(2.0))
+                            .use_outline(true)
+                            .set_text_size(TEXT_SIZE)
+                            .set_hover_text("Crossfade from the main wave form to Wave B. Can be modulated".to_string());
+                            ui.add(osc_1_morph_knob);
+                        });
+
+                        ui.vertical(|ui| {
+                            let osc_1_shape_knob = ui_knob::ArcKnob::for_param(
+                                osc_shape,
+                                setter,
+                                KNOB_SIZE,
+                                KnobLayout::Horizonal,
+                            )
+                            .preset_style(ui_knob::KnobStyle::Preset1)
+                            .set_fill_color(DARK_GREY_UI_COLOR)
+                            .set_line_color(YELLOW_MUSTARD)
+                            .use_outline(true)
+                            .set_text_size(TEXT_SIZE)
+                            .set_hover_text("Waveshaper on each voice before the envelope:
+SoftClip: warm saturation
+Wavefold: folds the wave back on itself for West-Coast tones
+Rectify: flips the negative half up, adding octave content".to_string());
+                            ui.add(osc_1_shape_knob);
+
+                            let osc_1_shape_amount_knob = ui_knob::ArcKnob::for_param(
+                                osc_shape_amount,
+                                setter,
+                                KNOB_SIZE,
+                                KnobLayout::Horizonal,
+                            )
+                            .preset_style(ui_knob::KnobStyle::Preset1)
+                            .set_fill_color(DARK_GREY_UI_COLOR)
+                            .set_line_color(YELLOW_MUSTARD.gamma_multiply(2.0))
+                            .use_outline(true)
+                            .set_text_size(TEXT_SIZE)
+                            .set_hover_text("How hard the shaper works. Can be modulated".to_string());
+                            ui.add(osc_1_shape_amount_knob);
+                        });
+
+                        ui.vertical(|ui| {
+                            let osc_1_warmth_knob = ui_knob::ArcKnob::for_param(
+                                osc_warmth,
+                                setter,
+                                KNOB_SIZE,
+                                KnobLayout::Horizonal,
+                            )
+                            .preset_style(ui_knob::KnobStyle::Preset1)
+                            .set_fill_color(DARK_GREY_UI_COLOR)
+                            .set_line_color(YELLOW_MUSTARD)
+                            .use_outline(true)
+                            .set_text_size(TEXT_SIZE)
+                            .set_hover_text("Gentle analog style high end rolloff that follows each note's pitch. 0 is the raw digital wave".to_string());
+                            ui.add(osc_1_warmth_knob);
+                            ui.horizontal(|ui| {
+                                for (drawbar, octave) in osc_drawbars.iter().zip(["-1 octave", "Root", "+1 octave", "+2 octaves"]) {
+                                    let drawbar_knob = ui_knob::ArcKnob::for_param(
+                                        *drawbar,
+                                        setter,
+                                        9.0,
+                                        KnobLayout::SquareNoLabel,
+                                    )
+                                    .preset_style(ui_knob::KnobStyle::Preset2)
+                                    .set_fill_color(DARK_GREY_UI_COLOR)
+                                    .set_line_color(YELLOW_MUSTARD)
+                                    .set_show_label(false)
+                                    .set_hover_text(format!("Drawbar: {} level. Stack octaves of the wave like an organ", octave));
+                                    ui.add(drawbar_knob);
+                                }
+                            });
                         });
 
                         // Trying to draw background box as rect
@@ -975,7 +1409,7 @@ You may also know this as mixture, course, or unison".to_string());
                                     y: ui.cursor().left_top().y - 4.0,
                                 },
                                 Pos2 {
-                                    x: ui.cursor().left_top().x + VERT_BAR_WIDTH * 6.0 + 8.0,
+                                    x: ui.cursor().left_top().x + VERT_BAR_WIDTH * 7.0 + 8.0,
                                     y: ui.cursor().left_top().y + VERT_BAR_HEIGHT + 12.0 + 8.0,
                                 },
                             ),
@@ -1013,6 +1447,13 @@ You may also know this as mixture, course, or unison".to_string());
                                 .set_reversed(true)
                                 .override_colors(LIGHTER_GREY_UI_COLOR, YELLOW_MUSTARD),
                         );
+                        ui.add(
+                            VerticalParamSlider::for_param(osc_env_keyscale, setter)
+                                .with_width(VERT_BAR_WIDTH)
+                                .with_height(VERT_BAR_HEIGHT)
+                                .set_reversed(true)
+                                .override_colors(LIGHTER_GREY_UI_COLOR, YELLOW_MUSTARD.gamma_multiply(2.0)),
+                        ).on_hover_text("Key Scale: higher notes get shorter decay and release, lower notes longer, around C4");
 
                         // Curves
                         ui.vertical(|ui|{
@@ -1082,8 +1523,9 @@ You may also know this as mixture, course, or unison".to_string());
                                                     module1
                                                     .lock()
                                                     .unwrap()
-                                                    .load_new_sample(opened_file.unwrap());
+                                                    .load_new_sample(opened_file.unwrap(), params.sample_normalize_1.value());
                                                     *params.am1_sample.lock().unwrap() = module1.lock().unwrap().loaded_sample.clone();
+                                                    setter.set_parameter(&params.sample_root_1, module1.lock().unwrap().sample_root);
                                                     setter.set_parameter(&params.load_sample_1, false);
                                                     dialog.set_path(dialog.directory().to_path_buf());
                                                     //dialog.deselect();
@@ -1094,8 +1536,9 @@ You may also know this as mixture, course, or unison".to_string());
                                                     module2
                                                         .lock()
                                                         .unwrap()
-                                                        .load_new_sample(opened_file.unwrap());
+                                                        .load_new_sample(opened_file.unwrap(), params.sample_normalize_2.value());
                                                     *params.am2_sample.lock().unwrap() = module2.lock().unwrap().loaded_sample.clone();
+                                                    setter.set_parameter(&params.sample_root_2, module2.lock().unwrap().sample_root);
                                                     setter.set_parameter(&params.load_sample_2, false);
                                                     dialog.set_path(dialog.directory().to_path_buf());
                                                     //dialog.deselect();
@@ -1106,8 +1549,9 @@ You may also know this as mixture, course, or unison".to_string());
                                                     module3
                                                         .lock()
                                                         .unwrap()
-                                                        .load_new_sample(opened_file.unwrap());
+                                                        .load_new_sample(opened_file.unwrap(), params.sample_normalize_3.value());
                                                     *params.am3_sample.lock().unwrap() = module3.lock().unwrap().loaded_sample.clone();
+                                                    setter.set_parameter(&params.sample_root_3, module3.lock().unwrap().sample_root);
                                                     setter.set_parameter(&params.load_sample_3, false);
                                                     dialog.set_path(dialog.directory().to_path_buf());
                                                     //dialog.deselect();
@@ -1149,6 +1593,14 @@ You may also know this as mixture, course, or unison".to_string());
                         ui.add(loop_toggle);
                         let sc_toggle = BoolButton::BoolButton::for_param(single_cycle, setter, 3.5, 1.0, SMALLER_FONT);
                         ui.add(sc_toggle);
+                        let normalize_toggle = BoolButton::BoolButton::for_param(sample_normalize, setter, 3.5, 1.0, SMALLER_FONT);
+                        ui.add(normalize_toggle).on_hover_text("Scale the next loaded sample so its peak sits at -1 dB");
+                        let freeze_button = BoolButton::BoolButton::for_param(freeze_sample, setter, 3.5, 1.0, SMALLER_FONT);
+                        ui.add(freeze_button).on_hover_text("Resample the last Freeze Time of Actuate's output into this module to play or granulize it");
+                        if freeze_sample.value() {
+                            let module = match index { 1 => module1, 2 => module2, _ => module3 };
+                            AudioModule::freeze_output(setter, &params, index, module, capture);
+                        }
                     });
                     ui.vertical(|ui| {
                         let osc_1_octave_knob = ui_knob::ArcKnob::for_param(
@@ -1179,6 +1631,19 @@ You may also know this as mixture, course, or unison".to_string());
 Retrigger: Sample restarts at every new note
 Random: Sample uses a new random position every note".to_string());
                         ui.add(osc_1_retrigger_knob);
+                        let loop_xfade_knob = ui_knob::ArcKnob::for_param(
+                            loop_xfade,
+                            setter,
+                            KNOB_SIZE,
+                            KnobLayout::Horizonal,
+                        )
+                        .preset_style(ui_knob::KnobStyle::Preset1)
+                        .set_fill_color(DARK_GREY_UI_COLOR)
+                        .set_line_color(YELLOW_MUSTARD)
+                        .use_outline(true)
+                        .set_text_size(TEXT_SIZE)
+                        .set_hover_text("Crossfades the end of a looping sample into its start so the loop seam doesn't click".to_string());
+                        ui.add(loop_xfade_knob);
                     });
                     ui.vertical(|ui| {
                         let osc_1_semitones_knob = ui_knob::ArcKnob::for_param(
@@ -1207,6 +1672,20 @@ Random: Sample uses a new random position every note".to_string());
                         .set_text_size(TEXT_SIZE)
                         .set_hover_text("How many voices should play in unison".to_string());
                         ui.add(osc_1_unison_knob);
+                        let stretch_factor_knob = ui_knob::ArcKnob::for_param(
+                            stretch_factor,
+                            setter,
+                            KNOB_SIZE,
+                            KnobLayout::Horizonal,
+                        )
+                        .preset_style(ui_knob::KnobStyle::Preset1)
+                        .set_fill_color(DARK_GREY_UI_COLOR)
+                        .set_line_color(YELLOW_MUSTARD.gamma_multiply(2.0))
+                        .use_outline(true)
+                        .set_text_size(TEXT_SIZE)
+                        .set_hover_text("Extreme time-stretch for drones and ambient textures.
+Above 1x the sample is smeared spectrally and plays this many times slower, keeping the note pitch".to_string());
+                        ui.add(stretch_factor_knob);
                     });
                     ui.vertical(|ui| {
                         let start_position_1_knob = ui_knob::ArcKnob::for_param(
@@ -1233,6 +1712,44 @@ Random: Sample uses a new random position every note".to_string());
                         .set_text_size(TEXT_SIZE)
                         .set_hover_text("Where the sample should end".to_string());
                         ui.add(end_position_1_knob);
+                        let sample_root_knob = ui_knob::ArcKnob::for_param(
+                            sample_root,
+                            setter,
+                            KNOB_SIZE,
+                            KnobLayout::Horizonal,
+                        )
+                        .preset_style(ui_knob::KnobStyle::Preset1)
+                        .set_fill_color(DARK_GREY_UI_COLOR)
+                        .set_line_color(YELLOW_MUSTARD)
+                        .set_text_size(TEXT_SIZE)
+                        .set_hover_text("The note the sample plays at its original pitch.
+Detected when a sample is loaded, change it if the guess is off".to_string());
+                        ui.add(sample_root_knob);
+                        let slice_count_knob = ui_knob::ArcKnob::for_param(
+                            slice_count,
+                            setter,
+                            KNOB_SIZE,
+                            KnobLayout::Horizonal,
+                        )
+                        .preset_style(ui_knob::KnobStyle::Preset1)
+                        .set_fill_color(DARK_GREY_UI_COLOR)
+                        .set_line_color(YELLOW_MUSTARD)
+                        .set_text_size(TEXT_SIZE)
+                        .set_hover_text("Chop Start to End into this many slices for beat slicing.
+The Root note plays the first slice and each note above plays the next one at its original pitch".to_string());
+                        ui.add(slice_count_knob);
+                        let freeze_time_knob = ui_knob::ArcKnob::for_param(
+                            &params.freeze_time,
+                            setter,
+                            KNOB_SIZE,
+                            KnobLayout::Horizonal,
+                        )
+                        .preset_style(ui_knob::KnobStyle::Preset1)
+                        .set_fill_color(DARK_GREY_UI_COLOR)
+                        .set_line_color(YELLOW_MUSTARD)
+                        .set_text_size(TEXT_SIZE)
+                        .set_hover_text("How many seconds of output Freeze grabs, shared by all modules".to_string());
+                        ui.add(freeze_time_knob);
                     });
                     // Trying to draw background box as rect
                     ui.painter().rect_filled(
@@ -1242,7 +1759,7 @@ Random: Sample uses a new random position every note".to_string());
                                 y: ui.cursor().left_top().y - 4.0,
                             },
                             Pos2 {
-                                x: ui.cursor().left_top().x + VERT_BAR_WIDTH * 6.0 + 8.0,
+                                x: ui.cursor().left_top().x + VERT_BAR_WIDTH * 7.0 + 8.0,
                                 y: ui.cursor().left_top().y + VERT_BAR_HEIGHT + 12.0 + 8.0,
                             },
                         ),
@@ -1279,6 +1796,13 @@ Random: Sample uses a new random position every note".to_string());
                             .set_reversed(true)
                             .override_colors(LIGHTER_GREY_UI_COLOR, YELLOW_MUSTARD),
                     );
+                    ui.add(
+                        VerticalParamSlider::for_param(osc_env_keyscale, setter)
+                            .with_width(VERT_BAR_WIDTH)
+                            .with_height(VERT_BAR_HEIGHT)
+                            .set_reversed(true)
+                            .override_colors(LIGHTER_GREY_UI_COLOR, YELLOW_MUSTARD.gamma_multiply(2.0)),
+                    ).on_hover_text("Key Scale: higher notes get shorter decay and release, lower notes longer, around C4");
                     // Curves
                     ui.vertical(|ui|{
                         ui.add(
@@ -1346,7 +1870,7 @@ Random: Sample uses a new random position every note".to_string());
                                                     module1
                                                         .lock()
                                                         .unwrap()
-                                                        .load_new_sample(opened_file.unwrap());
+                                                        .load_new_sample(opened_file.unwrap(), params.sample_normalize_1.value());
                                                     *params.am1_sample.lock().unwrap() = module1.lock().unwrap().loaded_sample.clone();
                                                     setter.set_parameter(&params.load_sample_1, false);
                                                     dialog.set_path(dialog.directory().to_path_buf());
@@ -1358,7 +1882,7 @@ Random: Sample uses a new random position every note".to_string());
                                                     module2
                                                         .lock()
                                                         .unwrap()
-                                                        .load_new_sample(opened_file.unwrap());
+                                                        .load_new_sample(opened_file.unwrap(), params.sample_normalize_2.value());
                                                     *params.am2_sample.lock().unwrap() = module2.lock().unwrap().loaded_sample.clone();
                                                     setter.set_parameter(&params.load_sample_2, false);
                                                     dialog.set_path(dialog.directory().to_path_buf());
@@ -1370,7 +1894,7 @@ Random: Sample uses a new random position every note".to_string());
                                                     module3
                                                         .lock()
                                                         .unwrap()
-                                                        .load_new_sample(opened_file.unwrap());
+                                                        .load_new_sample(opened_file.unwrap(), params.sample_normalize_3.value());
                                                     *params.am3_sample.lock().unwrap() = module3.lock().unwrap().loaded_sample.clone();
                                                     setter.set_parameter(&params.load_sample_3, false);
                                                     dialog.set_path(dialog.directory().to_path_buf());
@@ -1409,6 +1933,14 @@ Random: Sample uses a new random position every note".to_string());
                         }
                         let loop_toggle = BoolButton::BoolButton::for_param(loop_sample, setter, 3.5, 0.8, SMALLER_FONT);
                         ui.add(loop_toggle);
+                        let normalize_toggle = BoolButton::BoolButton::for_param(sample_normalize, setter, 3.5, 0.8, SMALLER_FONT);
+                        ui.add(normalize_toggle).on_hover_text("Scale the next loaded sample so its peak sits at -1 dB");
+                        let freeze_button = BoolButton::BoolButton::for_param(freeze_sample, setter, 3.5, 0.8, SMALLER_FONT);
+                        ui.add(freeze_button).on_hover_text("Resample the last Freeze Time of Actuate's output into this module to play or granulize it");
+                        if freeze_sample.value() {
+                            let module = match index { 1 => module1, 2 => module2, _ => module3 };
+                            AudioModule::freeze_output(setter, &params, index, module, capture);
+                        }
 
                         ui.add_space(10.0);
                         ui.label(
@@ -1478,6 +2010,24 @@ Random: Sample uses a new random position every note".to_string());
                             .set_text_size(TEXT_SIZE)
                             .set_hover_text("How much crossfading between grains".to_string());
                             ui.add(grain_crossfade_1_knob);
+
+                            let grain_window_knob = ui_knob::ArcKnob::for_param(
+                                grain_window,
+                                setter,
+                                KNOB_SIZE,
+                                KnobLayout::Horizonal,
+                            )
+                            .preset_style(ui_knob::KnobStyle::Preset1)
+                            .set_fill_color(DARK_GREY_UI_COLOR)
+                            .set_line_color(YELLOW_MUSTARD)
+                            .set_text_size(TEXT_SIZE)
+                            .set_hover_text("The amplitude window of each grain:
+Linear: Straight crossfades with a flat top
+Hann: Smooth bell over the whole grain
+Triangle: Straight ramps up and down over the whole grain
+Tukey: Flat top with smooth crossfades
+Gaussian: Narrow bell, softest and most diffuse".to_string());
+                            ui.add(grain_window_knob);
                         });
 
                         ui.vertical(|ui| {
@@ -1506,6 +2056,19 @@ Random: Sample uses a new random position every note".to_string());
                             .set_text_size(TEXT_SIZE)
                             .set_hover_text("The space between grains".to_string());
                             ui.add(grain_gap_1_knob);
+
+                            let grain_spray_1_knob = ui_knob::ArcKnob::for_param(
+                                grain_spray,
+                                setter,
+                                KNOB_SIZE,
+                                KnobLayout::Horizonal,
+                            )
+                            .preset_style(ui_knob::KnobStyle::Preset1)
+                            .set_fill_color(DARK_GREY_UI_COLOR)
+                            .set_line_color(YELLOW_MUSTARD)
+                            .set_text_size(TEXT_SIZE)
+                            .set_hover_text("Scatter each grain's start around where it would play, as a share of Start to End. Grains never run past End".to_string());
+                            ui.add(grain_spray_1_knob);
                         });
 
                         ui.vertical(|ui| {
@@ -1534,6 +2097,33 @@ Random: Sample uses a new random position every note".to_string());
                             .set_text_size(TEXT_SIZE)
                             .set_hover_text("Where the sample should end".to_string());
                             ui.add(end_position_1_knob);
+
+                            let slice_count_knob = ui_knob::ArcKnob::for_param(
+                                slice_count,
+                                setter,
+                                KNOB_SIZE,
+                                KnobLayout::Horizonal,
+                            )
+                            .preset_style(ui_knob::KnobStyle::Preset1)
+                            .set_fill_color(DARK_GREY_UI_COLOR)
+                            .set_line_color(YELLOW_MUSTARD)
+                            .set_text_size(TEXT_SIZE)
+                            .set_hover_text("Chop Start to End into this many slices for beat slicing.
+The sample Root note plays the first slice and each note above starts grains from the next one".to_string());
+                            ui.add(slice_count_knob);
+
+                            let freeze_time_knob = ui_knob::ArcKnob::for_param(
+                                &params.freeze_time,
+                                setter,
+                                KNOB_SIZE,
+                                KnobLayout::Horizonal,
+                            )
+                            .preset_style(ui_knob::KnobStyle::Preset1)
+                            .set_fill_color(DARK_GREY_UI_COLOR)
+                            .set_line_color(YELLOW_MUSTARD)
+                            .set_text_size(TEXT_SIZE)
+                            .set_hover_text("How many seconds of output Freeze grabs, shared by all modules".to_string());
+                            ui.add(freeze_time_knob);
                         });
                         // Trying to draw background box as rect
                         ui.painter().rect_filled(
@@ -1543,7 +2133,7 @@ Random: Sample uses a new random position every note".to_string());
                                     y: ui.cursor().left_top().y - 4.0,
                                 },
                                 Pos2 {
-                                    x: ui.cursor().left_top().x + VERT_BAR_WIDTH * 6.0 + 8.0,
+                                    x: ui.cursor().left_top().x + VERT_BAR_WIDTH * 7.0 + 8.0,
                                     y: ui.cursor().left_top().y + VERT_BAR_HEIGHT + 12.0 + 8.0,
                                 },
                             ),
@@ -1580,6 +2170,13 @@ Random: Sample uses a new random position every note".to_string());
                                 .set_reversed(true)
                                 .override_colors(LIGHTER_GREY_UI_COLOR, YELLOW_MUSTARD),
                         );
+                        ui.add(
+                            VerticalParamSlider::for_param(osc_env_keyscale, setter)
+                                .with_width(VERT_BAR_WIDTH)
+                                .with_height(VERT_BAR_HEIGHT)
+                                .set_reversed(true)
+                                .override_colors(LIGHTER_GREY_UI_COLOR, YELLOW_MUSTARD.gamma_multiply(2.0)),
+                        ).on_hover_text("Key Scale: higher notes get shorter decay and release, lower notes longer, around C4");
                         // Curves
                         ui.vertical(|ui|{
                             ui.add(
@@ -1861,6 +2458,20 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                             .set_text_size(TEXT_SIZE)
                             .set_hover_text("How many voices should play in unison".to_string());
                             ui.add(osc_1_unison_knob);
+
+                            let osc_1_unison_blend_knob = ui_knob::ArcKnob::for_param(
+                                osc_unison_blend,
+                                setter,
+                                KNOB_SIZE,
+                                KnobLayout::Horizonal,
+                            )
+                            .preset_style(ui_knob::KnobStyle::Preset1)
+                            .set_fill_color(DARK_GREY_UI_COLOR)
+                            .set_line_color(YELLOW_MUSTARD.gamma_multiply(2.0))
+                            .use_outline(true)
+                            .set_text_size(TEXT_SIZE)
+                            .set_hover_text("Balance the in tune center voice against the detuned voices. Middle is both at full level, lower is hollow, higher is focused".to_string());
+                            ui.add(osc_1_unison_blend_knob);
                         });
 
                         ui.vertical(|ui| {
@@ -1891,6 +2502,20 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                             .set_text_size(TEXT_SIZE)
                             .set_hover_text("Spread the pitches of the unison voices apart".to_string());
                             ui.add(osc_1_unison_detune_knob);
+
+                            let osc_1_detune_hz_knob = ui_knob::ArcKnob::for_param(
+                                osc_detune_hz,
+                                setter,
+                                KNOB_SIZE,
+                                KnobLayout::Horizonal,
+                            )
+                            .preset_style(ui_knob::KnobStyle::Preset1)
+                            .set_fill_color(DARK_GREY_UI_COLOR)
+                            .set_line_color(YELLOW_MUSTARD.gamma_multiply(2.0))
+                            .use_outline(true)
+                            .set_text_size(TEXT_SIZE)
+                            .set_hover_text("Offset the pitch by a fixed amount of Hz. Beats at the same rate on every note unlike Fine".to_string());
+                            ui.add(osc_1_detune_hz_knob);
                         });
 
                         // Trying to draw background box as rect
@@ -1901,7 +2526,7 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                                     y: ui.cursor().left_top().y - 4.0,
                                 },
                                 Pos2 {
-                                    x: ui.cursor().left_top().x + VERT_BAR_WIDTH * 6.0 + 8.0,
+                                    x: ui.cursor().left_top().x + VERT_BAR_WIDTH * 7.0 + 8.0,
                                     y: ui.cursor().left_top().y + VERT_BAR_HEIGHT + 12.0 + 8.0,
                                 },
                             ),
@@ -1939,6 +2564,13 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                                 .set_reversed(true)
                                 .override_colors(LIGHTER_GREY_UI_COLOR, YELLOW_MUSTARD),
                         );
+                        ui.add(
+                            VerticalParamSlider::for_param(osc_env_keyscale, setter)
+                                .with_width(VERT_BAR_WIDTH)
+                                .with_height(VERT_BAR_HEIGHT)
+                                .set_reversed(true)
+                                .override_colors(LIGHTER_GREY_UI_COLOR, YELLOW_MUSTARD.gamma_multiply(2.0)),
+                        ).on_hover_text("Key Scale: higher notes get shorter decay and release, lower notes longer, around C4");
 
                         // Curves
                         ui.vertical(|ui|{
@@ -1990,6 +2622,7 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
     // Index proper params from knobs
     // This lets us have a copy for voices, and also track changes like restretch changing or ADR slopes
     pub fn consume_params(&mut self, params: Arc<ActuateParams>, voice_index: usize) -> AudioModuleType {
+        let prev_waves = (self.audio_module_type, self.osc_type_b);
         match voice_index {
             1 => {
                 self.audio_module_type = params.audio_module_1_type.value();
@@ -2056,25 +2689,57 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                 }
                 self.osc_semitones = params.osc_1_semitones.value();
                 self.osc_detune = params.osc_1_detune.value();
-                self.osc_attack = params.osc_1_attack.value();
-                self.osc_decay = params.osc_1_decay.value();
+                self.osc_detune_hz = params.osc_1_detune_hz.value();
+                self.osc_punch = params.osc_1_punch.value();
+                self.osc_attack = params.osc_1_attack.value() * params.env_time_range.value().time_scale();
+                self.osc_decay = params.osc_1_decay.value() * params.env_time_range.value().time_scale();
                 self.osc_sustain = params.osc_1_sustain.value();
-                self.osc_release = params.osc_1_release.value();
+                self.osc_release = params.osc_1_release.value() * params.env_time_range.value().time_scale();
                 self.osc_retrigger = params.osc_1_retrigger.value();
                 self.osc_atk_curve = params.osc_1_atk_curve.value();
                 self.osc_dec_curve = params.osc_1_dec_curve.value();
                 self.osc_rel_curve = params.osc_1_rel_curve.value();
+                self.osc_env_keyscale = params.osc_1_env_keyscale.value();
                 self.osc_unison = params.osc_1_unison.value();
                 self.osc_unison_detune = params.osc_1_unison_detune.value();
                 self.osc_stereo = params.osc_1_stereo.value();
+                self.apply_super(params.osc_1_super.value());
+                self.osc_unison_blend = params.osc_1_unison_blend.value();
+                (self.unison_center_gain, self.unison_side_gain) = unison_blend_gains(self.osc_unison_blend, self.voice_unison);
+                self.osc_type_b = params.osc_1_type_b.value();
+                self.osc_morph = params.osc_1_morph.value();
+                self.osc_shape = params.osc_1_shape.value();
+                self.osc_shape_amount = params.osc_1_shape_amount.value();
+                self.osc_warmth = params.osc_1_warmth.value();
+                self.osc_drawbars = [
+                    params.osc_1_drawbar_sub.value(),
+                    params.osc_1_drawbar_root.value(),
+                    params.osc_1_drawbar_oct.value(),
+                    params.osc_1_drawbar_oct2.value(),
+                ];
+                self.osc_quality = params.osc_quality.value();
+                self.global_phase_reset = params.global_phase_reset.value();
+                self.voice_spread = params.voice_spread.value();
+                self.voice_steal_mode = params.voice_steal_mode.value();
+                self.tuning_system = params.tuning_system.value();
+                self.tuning_root = params.tuning_root.value();
+                self.note_offset_cents = params.note_offset_cents().map(|param| param.value());
+                self.reference_pitch = params.reference_pitch.value();
+                self.release_vel_amount = params.release_vel_amount.value();
                 self.loop_wavetable = params.loop_sample_1.value();
                 self.single_cycle = params.single_cycle_1.value();
                 self.restretch = params.restretch_1.value();
+                self.sample_root = params.sample_root_1.value();
                 self.start_position = params.start_position_1.value();
                 self._end_position = params.end_position_1.value();
+                self.stretch_factor = params.stretch_factor_1.value();
+                self.loop_xfade = params.loop_xfade_1.value();
+                self.slice_count = params.slice_count_1.value();
                 self.grain_hold = params.grain_hold_1.value();
                 self.grain_gap = params.grain_gap_1.value();
+                self.grain_spray = params.grain_spray_1.value();
                 self.grain_crossfade = params.grain_crossfade_1.value();
+                self.grain_window = params.grain_window_1.value();
                 self.ah0 = params.additive_amp_1_0.value();
                 self.ah1 = params.additive_amp_1_1.value();
                 self.ah2 = params.additive_amp_1_2.value();
@@ -2093,14 +2758,29 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                 self.ah15 = params.additive_amp_1_15.value();
                 self.filter_routing = params.filter_routing.value();
                 self.audio_module_routing = params.audio_module_1_routing.value();
-                self.filter_cutoff = params.filter_cutoff.value();
-                self.filter_cutoff_2 = params.filter_cutoff_2.value();
+                self.cutoff_knob = params.filter_cutoff.value();
+                self.cutoff_knob_2 = params.filter_cutoff_2.value();
+                if self.filter_cutoff_smooth != params.filter_cutoff_smooth.value() {
+                    self.filter_cutoff_smooth = params.filter_cutoff_smooth.value();
+                    self.cutoff_smoother = cutoff_smoother(self.filter_cutoff_smooth, self.filter_cutoff);
+                    self.cutoff_smoother_2 = cutoff_smoother(self.filter_cutoff_smooth, self.filter_cutoff_2);
+                    // Force the new smoothers to pick up the knobs on the next sample
+                    self.cutoff_target = -1.0;
+                    self.cutoff_target_2 = -1.0;
+                }
                 self.filter_wet = params.filter_wet.value();
                 self.filter_wet_2 = params.filter_wet_2.value();
-                self.filter_env_attack = params.filter_env_attack.value();
-                self.filter_env_decay = params.filter_env_decay.value();
+                self.filter_drive = params.filter_drive.value();
+                self.filter_drive_2 = params.filter_drive_2.value();
+                self.filter_stereo_offset = params.filter_stereo_offset_1.value();
+                self.filter_stereo_offset_2 = params.filter_stereo_offset_2.value();
+                self.paraphonic = params.paraphonic.value();
+                self.filter_auto_gain = params.filter_auto_gain.value();
+                self.eco_mode = params.eco_mode.value();
+                self.filter_env_attack = params.filter_env_attack.value() * params.env_time_range.value().time_scale();
+                self.filter_env_decay = params.filter_env_decay.value() * params.env_time_range.value().time_scale();
                 self.filter_env_sustain = params.filter_env_sustain.value();
-                self.filter_env_release = params.filter_env_release.value();
+                self.filter_env_release = params.filter_env_release.value() * params.env_time_range.value().time_scale();
                 self.filter_env_atk_curve = params.filter_env_atk_curve.value();
                 self.filter_env_dec_curve = params.filter_env_dec_curve.value();
                 self.filter_env_rel_curve = params.filter_env_rel_curve.value();
@@ -2121,21 +2801,22 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                     };
                 self.filter_dec_smoother_1 = Smoother::new(SmoothingStyle::Linear(300.0));
                 self.filter_rel_smoother_1 = Smoother::new(SmoothingStyle::Linear(300.0));
-                self.filter_cutoff_2 = params.filter_cutoff_2.value();
-                self.filter_env_attack_2 = params.filter_env_attack_2.value();
-                self.filter_env_decay_2 = params.filter_env_decay_2.value();
+                self.cutoff_knob_2 = params.filter_cutoff_2.value();
+                self.filter_env_attack_2 = params.filter_env_attack_2.value() * params.env_time_range.value().time_scale();
+                self.filter_env_decay_2 = params.filter_env_decay_2.value() * params.env_time_range.value().time_scale();
                 self.filter_env_sustain_2 = params.filter_env_sustain_2.value();
-                self.filter_env_release_2 = params.filter_env_release_2.value();
+                self.filter_env_release_2 = params.filter_env_release_2.value() * params.env_time_range.value().time_scale();
                 self.filter_env_atk_curve_2 = params.filter_env_atk_curve_2.value();
                 self.filter_env_dec_curve_2 = params.filter_env_dec_curve_2.value();
                 self.filter_env_rel_curve_2 = params.filter_env_rel_curve_2.value();
                 self.filter_atk_smoother_2 = Smoother::new(SmoothingStyle::Linear(300.0));
                 self.filter_dec_smoother_2 = Smoother::new(SmoothingStyle::Linear(300.0));
                 self.filter_rel_smoother_2 = Smoother::new(SmoothingStyle::Linear(300.0));
-                self.filter_alg_type = params.filter_alg_type.value();
-                self.filter_alg_type_2 = params.filter_alg_type_2.value();
+                self.set_filter_alg_types(params.filter_alg_type.value(), params.filter_alg_type_2.value());
                 self.filter_env_peak = params.filter_env_peak.value();
                 self.filter_env_peak_2 = params.filter_env_peak_2.value();
+                self.filter_env_invert_1 = params.filter_env_invert_1.value();
+                self.filter_env_invert_2 = params.filter_env_invert_2.value();
                 self.filter_resonance = params.filter_resonance.value();
                 self.filter_resonance_2 = params.filter_resonance_2.value();
                 self.filter_res_type = params.filter_res_type.value();
@@ -2212,25 +2893,57 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                 }
                 self.osc_semitones = params.osc_2_semitones.value();
                 self.osc_detune = params.osc_2_detune.value();
-                self.osc_attack = params.osc_2_attack.value();
-                self.osc_decay = params.osc_2_decay.value();
+                self.osc_detune_hz = params.osc_2_detune_hz.value();
+                self.osc_punch = params.osc_2_punch.value();
+                self.osc_attack = params.osc_2_attack.value() * params.env_time_range.value().time_scale();
+                self.osc_decay = params.osc_2_decay.value() * params.env_time_range.value().time_scale();
                 self.osc_sustain = params.osc_2_sustain.value();
-                self.osc_release = params.osc_2_release.value();
+                self.osc_release = params.osc_2_release.value() * params.env_time_range.value().time_scale();
                 self.osc_retrigger = params.osc_2_retrigger.value();
                 self.osc_atk_curve = params.osc_2_atk_curve.value();
                 self.osc_dec_curve = params.osc_2_dec_curve.value();
                 self.osc_rel_curve = params.osc_2_rel_curve.value();
+                self.osc_env_keyscale = params.osc_2_env_keyscale.value();
                 self.osc_unison = params.osc_2_unison.value();
                 self.osc_unison_detune = params.osc_2_unison_detune.value();
                 self.osc_stereo = params.osc_2_stereo.value();
+                self.apply_super(params.osc_2_super.value());
+                self.osc_unison_blend = params.osc_2_unison_blend.value();
+                (self.unison_center_gain, self.unison_side_gain) = unison_blend_gains(self.osc_unison_blend, self.voice_unison);
+                self.osc_type_b = params.osc_2_type_b.value();
+                self.osc_morph = params.osc_2_morph.value();
+                self.osc_shape = params.osc_2_shape.value();
+                self.osc_shape_amount = params.osc_2_shape_amount.value();
+                self.osc_warmth = params.osc_2_warmth.value();
+                self.osc_drawbars = [
+                    params.osc_2_drawbar_sub.value(),
+                    params.osc_2_drawbar_root.value(),
+                    params.osc_2_drawbar_oct.value(),
+                    params.osc_2_drawbar_oct2.value(),
+                ];
+                self.osc_quality = params.osc_quality.value();
+                self.global_phase_reset = params.global_phase_reset.value();
+                self.voice_spread = params.voice_spread.value();
+                self.voice_steal_mode = params.voice_steal_mode.value();
+                self.tuning_system = params.tuning_system.value();
+                self.tuning_root = params.tuning_root.value();
+                self.note_offset_cents = params.note_offset_cents().map(|param| param.value());
+                self.reference_pitch = params.reference_pitch.value();
+                self.release_vel_amount = params.release_vel_amount.value();
                 self.loop_wavetable = params.loop_sample_2.value();
                 self.single_cycle = params.single_cycle_2.value();
                 self.restretch = params.restretch_2.value();
+                self.sample_root = params.sample_root_2.value();
                 self.start_position = params.start_position_2.value();
                 self._end_position = params.end_position_2.value();
+                self.stretch_factor = params.stretch_factor_2.value();
+                self.loop_xfade = params.loop_xfade_2.value();
+                self.slice_count = params.slice_count_2.value();
                 self.grain_hold = params.grain_hold_2.value();
                 self.grain_gap = params.grain_gap_2.value();
+                self.grain_spray = params.grain_spray_2.value();
                 self.grain_crossfade = params.grain_crossfade_2.value();
+                self.grain_window = params.grain_window_2.value();
                 self.ah0 = params.additive_amp_2_0.value();
                 self.ah1 = params.additive_amp_2_1.value();
                 self.ah2 = params.additive_amp_2_2.value();
@@ -2249,14 +2962,29 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                 self.ah15 = params.additive_amp_2_15.value();
                 self.filter_routing = params.filter_routing.value();
                 self.audio_module_routing = params.audio_module_2_routing.value();
-                self.filter_cutoff = params.filter_cutoff.value();
-                self.filter_cutoff_2 = params.filter_cutoff_2.value();
+                self.cutoff_knob = params.filter_cutoff.value();
+                self.cutoff_knob_2 = params.filter_cutoff_2.value();
+                if self.filter_cutoff_smooth != params.filter_cutoff_smooth.value() {
+                    self.filter_cutoff_smooth = params.filter_cutoff_smooth.value();
+                    self.cutoff_smoother = cutoff_smoother(self.filter_cutoff_smooth, self.filter_cutoff);
+                    self.cutoff_smoother_2 = cutoff_smoother(self.filter_cutoff_smooth, self.filter_cutoff_2);
+                    // Force the new smoothers to pick up the knobs on the next sample
+                    self.cutoff_target = -1.0;
+                    self.cutoff_target_2 = -1.0;
+                }
                 self.filter_wet = params.filter_wet.value();
                 self.filter_wet_2 = params.filter_wet_2.value();
-                self.filter_env_attack = params.filter_env_attack.value();
-                self.filter_env_decay = params.filter_env_decay.value();
+                self.filter_drive = params.filter_drive.value();
+                self.filter_drive_2 = params.filter_drive_2.value();
+                self.filter_stereo_offset = params.filter_stereo_offset_1.value();
+                self.filter_stereo_offset_2 = params.filter_stereo_offset_2.value();
+                self.paraphonic = params.paraphonic.value();
+                self.filter_auto_gain = params.filter_auto_gain.value();
+                self.eco_mode = params.eco_mode.value();
+                self.filter_env_attack = params.filter_env_attack.value() * params.env_time_range.value().time_scale();
+                self.filter_env_decay = params.filter_env_decay.value() * params.env_time_range.value().time_scale();
                 self.filter_env_sustain = params.filter_env_sustain.value();
-                self.filter_env_release = params.filter_env_release.value();
+                self.filter_env_release = params.filter_env_release.value() * params.env_time_range.value().time_scale();
                 self.filter_env_atk_curve = params.filter_env_atk_curve.value();
                 self.filter_env_dec_curve = params.filter_env_dec_curve.value();
                 self.filter_env_rel_curve = params.filter_env_rel_curve.value();
@@ -2277,21 +3005,22 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                     };
                 self.filter_dec_smoother_1 = Smoother::new(SmoothingStyle::Linear(300.0));
                 self.filter_rel_smoother_1 = Smoother::new(SmoothingStyle::Linear(300.0));
-                self.filter_cutoff_2 = params.filter_cutoff_2.value();
-                self.filter_env_attack_2 = params.filter_env_attack_2.value();
-                self.filter_env_decay_2 = params.filter_env_decay_2.value();
+                self.cutoff_knob_2 = params.filter_cutoff_2.value();
+                self.filter_env_attack_2 = params.filter_env_attack_2.value() * params.env_time_range.value().time_scale();
+                self.filter_env_decay_2 = params.filter_env_decay_2.value() * params.env_time_range.value().time_scale();
                 self.filter_env_sustain_2 = params.filter_env_sustain_2.value();
-                self.filter_env_release_2 = params.filter_env_release_2.value();
+                self.filter_env_release_2 = params.filter_env_release_2.value() * params.env_time_range.value().time_scale();
                 self.filter_env_atk_curve_2 = params.filter_env_atk_curve_2.value();
                 self.filter_env_dec_curve_2 = params.filter_env_dec_curve_2.value();
                 self.filter_env_rel_curve_2 = params.filter_env_rel_curve_2.value();
                 self.filter_atk_smoother_2 = Smoother::new(SmoothingStyle::Linear(300.0));
                 self.filter_dec_smoother_2 = Smoother::new(SmoothingStyle::Linear(300.0));
                 self.filter_rel_smoother_2 = Smoother::new(SmoothingStyle::Linear(300.0));
-                self.filter_alg_type = params.filter_alg_type.value();
-                self.filter_alg_type_2 = params.filter_alg_type_2.value();
+                self.set_filter_alg_types(params.filter_alg_type.value(), params.filter_alg_type_2.value());
                 self.filter_env_peak = params.filter_env_peak.value();
                 self.filter_env_peak_2 = params.filter_env_peak_2.value();
+                self.filter_env_invert_1 = params.filter_env_invert_1.value();
+                self.filter_env_invert_2 = params.filter_env_invert_2.value();
                 self.lp_amount = params.filter_lp_amount.value();
                 self.bp_amount = params.filter_bp_amount.value();
                 self.hp_amount = params.filter_hp_amount.value();
@@ -2364,25 +3093,57 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                 }
                 self.osc_semitones = params.osc_3_semitones.value();
                 self.osc_detune = params.osc_3_detune.value();
-                self.osc_attack = params.osc_3_attack.value();
-                self.osc_decay = params.osc_3_decay.value();
+                self.osc_detune_hz = params.osc_3_detune_hz.value();
+                self.osc_punch = params.osc_3_punch.value();
+                self.osc_attack = params.osc_3_attack.value() * params.env_time_range.value().time_scale();
+                self.osc_decay = params.osc_3_decay.value() * params.env_time_range.value().time_scale();
                 self.osc_sustain = params.osc_3_sustain.value();
-                self.osc_release = params.osc_3_release.value();
+                self.osc_release = params.osc_3_release.value() * params.env_time_range.value().time_scale();
                 self.osc_retrigger = params.osc_3_retrigger.value();
                 self.osc_atk_curve = params.osc_3_atk_curve.value();
                 self.osc_dec_curve = params.osc_3_dec_curve.value();
                 self.osc_rel_curve = params.osc_3_rel_curve.value();
+                self.osc_env_keyscale = params.osc_3_env_keyscale.value();
                 self.osc_unison = params.osc_3_unison.value();
                 self.osc_unison_detune = params.osc_3_unison_detune.value();
                 self.osc_stereo = params.osc_3_stereo.value();
+                self.apply_super(params.osc_3_super.value());
+                self.osc_unison_blend = params.osc_3_unison_blend.value();
+                (self.unison_center_gain, self.unison_side_gain) = unison_blend_gains(self.osc_unison_blend, self.voice_unison);
+                self.osc_type_b = params.osc_3_type_b.value();
+                self.osc_morph = params.osc_3_morph.value();
+                self.osc_shape = params.osc_3_shape.value();
+                self.osc_shape_amount = params.osc_3_shape_amount.value();
+                self.osc_warmth = params.osc_3_warmth.value();
+                self.osc_drawbars = [
+                    params.osc_3_drawbar_sub.value(),
+                    params.osc_3_drawbar_root.value(),
+                    params.osc_3_drawbar_oct.value(),
+                    params.osc_3_drawbar_oct2.value(),
+                ];
+                self.osc_quality = params.osc_quality.value();
+                self.global_phase_reset = params.global_phase_reset.value();
+                self.voice_spread = params.voice_spread.value();
+                self.voice_steal_mode = params.voice_steal_mode.value();
+                self.tuning_system = params.tuning_system.value();
+                self.tuning_root = params.tuning_root.value();
+                self.note_offset_cents = params.note_offset_cents().map(|param| param.value());
+                self.reference_pitch = params.reference_pitch.value();
+                self.release_vel_amount = params.release_vel_amount.value();
                 self.loop_wavetable = params.loop_sample_3.value();
                 self.single_cycle = params.single_cycle_3.value();
                 self.restretch = params.restretch_3.value();
+                self.sample_root = params.sample_root_3.value();
                 self.start_position = params.start_position_3.value();
                 self._end_position = params.end_position_3.value();
+                self.stretch_factor = params.stretch_factor_3.value();
+                self.loop_xfade = params.loop_xfade_3.value();
+                self.slice_count = params.slice_count_3.value();
                 self.grain_hold = params.grain_hold_3.value();
                 self.grain_gap = params.grain_gap_3.value();
+                self.grain_spray = params.grain_spray_3.value();
                 self.grain_crossfade = params.grain_crossfade_3.value();
+                self.grain_window = params.grain_window_3.value();
                 self.ah0 = params.additive_amp_3_0.value();
                 self.ah1 = params.additive_amp_3_1.value();
                 self.ah2 = params.additive_amp_3_2.value();
@@ -2401,14 +3162,29 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                 self.ah15 = params.additive_amp_3_15.value();
                 self.filter_routing = params.filter_routing.value();
                 self.audio_module_routing = params.audio_module_3_routing.value();
-                self.filter_cutoff = params.filter_cutoff.value();
-                self.filter_cutoff_2 = params.filter_cutoff_2.value();
+                self.cutoff_knob = params.filter_cutoff.value();
+                self.cutoff_knob_2 = params.filter_cutoff_2.value();
+                if self.filter_cutoff_smooth != params.filter_cutoff_smooth.value() {
+                    self.filter_cutoff_smooth = params.filter_cutoff_smooth.value();
+                    self.cutoff_smoother = cutoff_smoother(self.filter_cutoff_smooth, self.filter_cutoff);
+                    self.cutoff_smoother_2 = cutoff_smoother(self.filter_cutoff_smooth, self.filter_cutoff_2);
+                    // Force the new smoothers to pick up the knobs on the next sample
+                    self.cutoff_target = -1.0;
+                    self.cutoff_target_2 = -1.0;
+                }
                 self.filter_wet = params.filter_wet.value();
                 self.filter_wet_2 = params.filter_wet_2.value();
-                self.filter_env_attack = params.filter_env_attack.value();
-                self.filter_env_decay = params.filter_env_decay.value();
+                self.filter_drive = params.filter_drive.value();
+                self.filter_drive_2 = params.filter_drive_2.value();
+                self.filter_stereo_offset = params.filter_stereo_offset_1.value();
+                self.filter_stereo_offset_2 = params.filter_stereo_offset_2.value();
+                self.paraphonic = params.paraphonic.value();
+                self.filter_auto_gain = params.filter_auto_gain.value();
+                self.eco_mode = params.eco_mode.value();
+                self.filter_env_attack = params.filter_env_attack.value() * params.env_time_range.value().time_scale();
+                self.filter_env_decay = params.filter_env_decay.value() * params.env_time_range.value().time_scale();
                 self.filter_env_sustain = params.filter_env_sustain.value();
-                self.filter_env_release = params.filter_env_release.value();
+                self.filter_env_release = params.filter_env_release.value() * params.env_time_range.value().time_scale();
                 self.filter_env_atk_curve = params.filter_env_atk_curve.value();
                 self.filter_env_dec_curve = params.filter_env_dec_curve.value();
                 self.filter_env_rel_curve = params.filter_env_rel_curve.value();
@@ -2429,21 +3205,22 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                     };
                 self.filter_dec_smoother_1 = Smoother::new(SmoothingStyle::Linear(300.0));
                 self.filter_rel_smoother_1 = Smoother::new(SmoothingStyle::Linear(300.0));
-                self.filter_cutoff_2 = params.filter_cutoff_2.value();
-                self.filter_env_attack_2 = params.filter_env_attack_2.value();
-                self.filter_env_decay_2 = params.filter_env_decay_2.value();
+                self.cutoff_knob_2 = params.filter_cutoff_2.value();
+                self.filter_env_attack_2 = params.filter_env_attack_2.value() * params.env_time_range.value().time_scale();
+                self.filter_env_decay_2 = params.filter_env_decay_2.value() * params.env_time_range.value().time_scale();
                 self.filter_env_sustain_2 = params.filter_env_sustain_2.value();
-                self.filter_env_release_2 = params.filter_env_release_2.value();
+                self.filter_env_release_2 = params.filter_env_release_2.value() * params.env_time_range.value().time_scale();
                 self.filter_env_atk_curve_2 = params.filter_env_atk_curve_2.value();
                 self.filter_env_dec_curve_2 = params.filter_env_dec_curve_2.value();
                 self.filter_env_rel_curve_2 = params.filter_env_rel_curve_2.value();
                 self.filter_atk_smoother_2 = Smoother::new(SmoothingStyle::Linear(300.0));
                 self.filter_dec_smoother_2 = Smoother::new(SmoothingStyle::Linear(300.0));
                 self.filter_rel_smoother_2 = Smoother::new(SmoothingStyle::Linear(300.0));
-                self.filter_alg_type = params.filter_alg_type.value();
-                self.filter_alg_type_2 = params.filter_alg_type_2.value();
+                self.set_filter_alg_types(params.filter_alg_type.value(), params.filter_alg_type_2.value());
                 self.filter_env_peak = params.filter_env_peak.value();
                 self.filter_env_peak_2 = params.filter_env_peak_2.value();
+                self.filter_env_invert_1 = params.filter_env_invert_1.value();
+                self.filter_env_invert_2 = params.filter_env_invert_2.value();
                 self.lp_amount = params.filter_lp_amount.value();
                 self.bp_amount = params.filter_bp_amount.value();
                 self.hp_amount = params.filter_hp_amount.value();
@@ -2457,6 +3234,16 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
             }
             _ => {}
         }
+        // Held voices fade over from the old waveform instead of jumping mid cycle
+        self.live_waveform_switch = params.live_waveform_switch.value();
+        if self.live_waveform_switch
+            && (self.audio_module_type, self.osc_type_b) != prev_waves
+            && is_osc_wave(prev_waves.0)
+            && is_osc_wave(self.audio_module_type)
+        {
+            self.prev_waves = prev_waves;
+            self.wave_fade = 1.0;
+        }
         self.audio_module_type
     }
 
@@ -2464,12 +3251,25 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
     // Handle the audio module midi events and regular pricessing
     // This is an INDIVIDUAL instance process unlike the GUI function
     // This sends back the OSC output + note on for filter to reset
+    // Start a crossfade out of the old algorithm whenever a filter's algorithm changes
+    fn set_filter_alg_types(&mut self, filter_alg_type: FilterAlgorithms, filter_alg_type_2: FilterAlgorithms) {
+        if filter_alg_type != self.filter_alg_type {
+            self.filter_alg_prev = std::mem::replace(&mut self.filter_alg_type, filter_alg_type);
+            self.filter_alg_fade = 1.0;
+        }
+        if filter_alg_type_2 != self.filter_alg_type_2 {
+            self.filter_alg_prev_2 = std::mem::replace(&mut self.filter_alg_type_2, filter_alg_type_2);
+            self.filter_alg_fade_2 = 1.0;
+        }
+    }
+
     pub fn process(
         &mut self,
         _sample_id: usize,
         event_passed: Option<NoteEvent<()>>,
         voice_max: usize,
         detune_mod: f32,
+        note_pitch: NotePitch,
         uni_detune_mod: f32,
         velocity_mod: f32,
         uni_velocity_mod: f32,
@@ -2480,8 +3280,45 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
         cutoff_mod: f32,
         resonance_mod_2: f32,
         cutoff_mod_2: f32,
+        drive_mod: f32,
+        drive_mod_2: f32,
+        morph_mod: f32,
+        shape_mod: f32,
+        filter_loop_retrigger: bool,
     ) -> (f32, f32, bool, bool) {
         // If the process is in here the file dialog is not open per lib.rs
+        let drive = (self.filter_drive + drive_mod).clamp(0.0, 1.0);
+        let drive_2 = (self.filter_drive_2 + drive_mod_2).clamp(0.0, 1.0);
+
+        // Morph is smoothed so knob jumps and stepped mod sources don't click
+        let morph_target = (self.osc_morph + morph_mod).clamp(0.0, 1.0);
+        let shape_amount = (self.osc_shape_amount + shape_mod).clamp(0.0, 1.0);
+        if morph_target != self.morph_target {
+            self.morph_target = morph_target;
+            self.morph_smoother.set_target(self.sample_rate, morph_target);
+        }
+        let morph = self.morph_smoother.next();
+
+        // Cutoff glides so stepped automation doesn't zipper
+        if self.cutoff_knob != self.cutoff_target {
+            self.cutoff_target = self.cutoff_knob;
+            self.cutoff_smoother.set_target(self.sample_rate, self.cutoff_knob);
+        }
+        if self.cutoff_knob_2 != self.cutoff_target_2 {
+            self.cutoff_target_2 = self.cutoff_knob_2;
+            self.cutoff_smoother_2.set_target(self.sample_rate, self.cutoff_knob_2);
+        }
+        self.filter_cutoff = self.cutoff_smoother.next();
+        self.filter_cutoff_2 = self.cutoff_smoother_2.next();
+
+        // Filter algorithm switches fade over FILTER_ALG_FADE_TIME
+        let alg_fade_step = 1.0 / (FILTER_ALG_FADE_TIME * self.sample_rate);
+        self.filter_alg_fade = (self.filter_alg_fade - alg_fade_step).max(0.0);
+        self.filter_alg_fade_2 = (self.filter_alg_fade_2 - alg_fade_step).max(0.0);
+
+        // Where a looping filter envelope's attack heads when it restarts
+        let loop_peak_1 = filter_env_peak_target(self.filter_cutoff, self.filter_env_peak, &self.filter_alg_type);
+        let loop_peak_2 = filter_env_peak_target(self.filter_cutoff_2, self.filter_env_peak_2, &self.filter_alg_type_2);
 
         // Midi events are processed here
         let mut note_on: bool = false;
@@ -2499,7 +3336,15 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                     } => {
                         // Osc + generic stuff
                         note_on = true;
-                        let mut new_phase: f32 = 0.0;
+                        self.spray_rng = (SPRAY_SEED ^ (note as u32 + 1).wrapping_mul(0x9E37_79B9)).max(1);
+                        // Key scaling follows the key that was played, before octave and semitone shifts
+                        let env_keyscale = env_keyscale_factor(note, self.osc_env_keyscale);
+                        if self.paraphonic {
+                            for voice in self.playing_voices.voices.iter_mut().chain(self.unison_voices.voices.iter_mut()) {
+                                retrigger_filter_envs(voice, self.sample_rate, loop_peak_1, loop_peak_2);
+                            }
+                        }
+                        let mut new_phase: f64 = 0.0;
 
                         // Calculate our pitch mod stuff if applicable
                         let pitch_attack_smoother: Smoother<f32>;
@@ -2696,18 +3541,28 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                         }
                         // Shift our note per semitones
                         note += self.osc_semitones as u8;
+                        // Built-in tunings and the note cents ride along with detune, 12-TET adds nothing
+                        let tuned_detune = self.osc_detune
+                            + tuning_offset(note, self.tuning_system, self.tuning_root)
+                            + self.note_offset_cents[note as usize % 12] / 100.0
+                            // A4 = 440 adds exactly 0 so standard pitch is untouched
+                            + 12.0 * (self.reference_pitch / 440.0).log2();
                         // Shift our note per detune
                         // I'm so glad nih-plug has this helper for f32 conversions!
                         let base_note = if velocity_mod <= 0.0 {
                             note as f32
-                                + self.osc_detune
+                                + tuned_detune
                                 + detune_mod
+                                + note_pitch.humanize
+                                + note_pitch.glide
                                 + pitch_mod_current
                                 + pitch_mod_current_2
                         } else {
                             note as f32
-                                + self.osc_detune
+                                + tuned_detune
                                 + detune_mod
+                                + note_pitch.humanize
+                                + note_pitch.glide
                                 + velocity_mod.clamp(0.0, 1.0) * velocity
                                 + pitch_mod_current
                                 + pitch_mod_current_2
@@ -2727,7 +3582,7 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                                         if self.sample_lib.len() > 1 {
                                             if self.sample_lib[note as usize][0].len() > 0 {
                                                 new_phase = rng.gen_range(
-                                                    0.0..self.sample_lib[note as usize][0].len() as f32,
+                                                    0.0..self.sample_lib[note as usize][0].len() as f64,
                                                 );
                                             } else {
                                                 // There's probably no sample loaded
@@ -2741,7 +3596,7 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                                         if self.sample_lib.len() > 1 {
                                             if self.sample_lib[note as usize][0].len() > 0 {
                                                 new_phase = rng.gen_range(
-                                                    0.0..self.sample_lib[note as usize][0].len() as f32,
+                                                    0.0..self.sample_lib[note as usize][0].len() as f64,
                                                 );
                                             } else {
                                                 // There's probably no sample loaded
@@ -2769,15 +3624,25 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                             }
                         }
 
+                        // Global phase reset lines every oscillator module up on the same phase so layered attacks hit together
+                        let phase_locked = self.global_phase_reset
+                            && !matches!(
+                                self.audio_module_type,
+                                AudioModuleType::Sampler | AudioModuleType::Granulizer | AudioModuleType::Off | AudioModuleType::UnsetAm
+                            );
+                        if phase_locked {
+                            new_phase = 0.0;
+                        }
+
                         // Create an array of unison notes based off the param for how many unison voices we need
-                        let mut unison_notes: Vec<f32> = vec![0.0; self.osc_unison as usize];
+                        let mut unison_notes: Vec<f32> = vec![0.0; self.voice_unison as usize];
                         // If we have any unison voices
-                        if self.osc_unison > 1 {
+                        if self.voice_unison > 1 {
                             // Calculate the detune step amount per amount of voices
-                            let detune_step = self.osc_unison_detune / self.osc_unison as f32;
+                            let detune_step = self.voice_unison_detune / self.voice_unison as f32;
                             let base_pitch_mod = pitch_mod_current + pitch_mod_current_2;
                             let nvelocity_mod = uni_velocity_mod.clamp(0.0, 1.0) * velocity;
-                            let nunison_notes: Vec<f32> = (0..self.osc_unison as usize)
+                            let nunison_notes: Vec<f32> = (0..self.voice_unison as usize)
                                 .into_par_iter()
                                 .map(|unison_voice| {
                                     let detune_offset = detune_step * (unison_voice as f32 + 1.0);
@@ -2791,7 +3656,7 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                             unison_notes = nunison_notes;
 
                             /*
-                            for unison_voice in 0..(self.osc_unison as usize - 1) {
+                            for unison_voice in 0..(self.voice_unison as usize - 1) {
                                 // Create the detuned notes around the base note
                                 if unison_voice % 2 == 1 {
                                     unison_notes[unison_voice] = util::f32_midi_note_to_freq(
@@ -2835,34 +3700,34 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
 
                         let decay_smoother: Smoother<f32> = match self.osc_dec_curve {
                             SmoothStyle::Linear => {
-                                Smoother::new(SmoothingStyle::Linear(self.osc_decay))
+                                Smoother::new(SmoothingStyle::Linear(self.osc_decay * env_keyscale))
                             }
                             SmoothStyle::Logarithmic => Smoother::new(SmoothingStyle::Logarithmic(
-                                self.osc_decay.clamp(0.0001, 1999.9),
+                                (self.osc_decay * env_keyscale).clamp(0.0001, 1999.9),
                             )),
                             SmoothStyle::Exponential => {
-                                Smoother::new(SmoothingStyle::Exponential(self.osc_decay))
+                                Smoother::new(SmoothingStyle::Exponential(self.osc_decay * env_keyscale))
                             }
                             SmoothStyle::LogSteep => {
                                 Smoother::new(SmoothingStyle::LogSteep(
-                                    self.osc_decay.clamp(0.0001, 1999.9)
+                                    (self.osc_decay * env_keyscale).clamp(0.0001, 1999.9)
                                 ))
                             }
                         };
 
                         let release_smoother: Smoother<f32> = match self.osc_rel_curve {
                             SmoothStyle::Linear => {
-                                Smoother::new(SmoothingStyle::Linear(self.osc_release))
+                                Smoother::new(SmoothingStyle::Linear(self.osc_release * env_keyscale))
                             }
                             SmoothStyle::Logarithmic => Smoother::new(SmoothingStyle::Logarithmic(
-                                self.osc_release.clamp(0.0001, 1999.9),
+                                (self.osc_release * env_keyscale).clamp(0.0001, 1999.9),
                             )),
                             SmoothStyle::Exponential => {
-                                Smoother::new(SmoothingStyle::Exponential(self.osc_release))
+                                Smoother::new(SmoothingStyle::Exponential(self.osc_release * env_keyscale))
                             }
                             SmoothStyle::LogSteep => {
                                 Smoother::new(SmoothingStyle::LogSteep(
-                                    self.osc_release.clamp(0.0001, 1999.9)
+                                    (self.osc_release * env_keyscale).clamp(0.0001, 1999.9)
                                 ))
                             }
                         };
@@ -2890,37 +3755,47 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                                         && self.sample_lib[0][0].len() > 1
                                         && self.sample_lib.len() > 1
                                     {
-                                        // Create our granulizer/sampler starting position from our knob scale
-                                        scaled_sample_pos = if self.start_position > 0.0
-                                            && self.osc_retrigger != RetriggerStyle::Random
-                                            && self.osc_retrigger != RetriggerStyle::MRandom
-                                        {
-                                            (self.sample_lib[note as usize][0].len() as f32
-                                                * self.start_position)
-                                                .floor()
-                                                as usize
-                                        }
-                                        // Retrigger and use 0
-                                        else if self.osc_retrigger != RetriggerStyle::Random
-                                            && self.osc_retrigger != RetriggerStyle::MRandom
-                                        {
-                                            0_usize
-                                        }
-                                        // Retrigger with random
-                                        else {
-                                            new_phase.floor() as usize
-                                        };
-
-                                        scaled_end_pos = if self._end_position < 1.0 {
-                                            (self.sample_lib[note as usize][0].len() as f32
-                                                * self._end_position)
-                                                .ceil()
-                                                as usize
+                                        // Slices start and stop inside their own piece of the sample
+                                        if self.slice_count > 1 {
+                                            let (slice_start, slice_end) = self.slice_bounds(
+                                                note,
+                                                self.sample_lib[self.slice_lib_index().unwrap_or(note as usize)][0].len(),
+                                            );
+                                            scaled_sample_pos = slice_start;
+                                            scaled_end_pos = slice_end;
+                                        } else {
+                                            // Create our granulizer/sampler starting position from our knob scale
+                                            scaled_sample_pos = if self.start_position > 0.0
+                                                && self.osc_retrigger != RetriggerStyle::Random
+                                                && self.osc_retrigger != RetriggerStyle::MRandom
+                                            {
+                                                (self.sample_lib[note as usize][0].len() as f32
+                                                    * self.start_position)
+                                                    .floor()
+                                                    as usize
+                                            }
+                                            // Retrigger and use 0
+                                            else if self.osc_retrigger != RetriggerStyle::Random
+                                                && self.osc_retrigger != RetriggerStyle::MRandom
+                                            {
+                                                0_usize
+                                            }
+                                            // Retrigger with random
+                                            else {
+                                                new_phase.floor() as usize
+                                            };
+
+                                            scaled_end_pos = if self._end_position < 1.0 {
+                                                (self.sample_lib[note as usize][0].len() as f32
+                                                    * self._end_position)
+                                                    .ceil()
+                                                    as usize
+                                            }
+                                            // use end positions
+                                            else {
+                                                self.sample_lib[note as usize][0].len()
+                                            };
                                         }
-                                        // use end positions
-                                        else {
-                                            self.sample_lib[note as usize][0].len()
-                                        };
                                     } else {
                                         // Nothing is in our sample library, skip attempting audio output
                                         return (0.0, 0.0, false, false);
@@ -2953,6 +3828,11 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                             osc_release: release_smoother.clone(),
                             pitch_enabled: self.pitch_enable,
                             pitch_env_peak: self.pitch_env_peak,
+                            punch: self.osc_punch * velocity,
+                            wave_a: self.audio_module_type,
+                            wave_b: self.osc_type_b,
+                            humanize_detune: note_pitch.humanize,
+                            glide_offset: note_pitch.glide,
                             pitch_current: pitch_mod_current,
                             pitch_state: OscState::Attacking,
                             pitch_attack: pitch_attack_smoother.clone(),
@@ -2965,8 +3845,8 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                             pitch_attack_2: pitch_attack_smoother_2.clone(),
                             pitch_decay_2: pitch_decay_smoother_2.clone(),
                             pitch_release_2: pitch_release_smoother_2.clone(),
-                            _detune: self.osc_detune,
-                            _unison_detune_value: self.osc_unison_detune,
+                            _detune: tuned_detune,
+                            _unison_detune_value: self.voice_unison_detune,
                             //frequency: detuned_note,
                             frequency: 0.0,
                             _attack_time: self.osc_attack,
@@ -2977,6 +3857,9 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                             _angle: 0.0,
                             sample_pos: scaled_sample_pos,
                             loop_it: self.loop_wavetable,
+                            warmth_state: 0.0,
+                            sub_phase: 0.0,
+                            stretch: None,
                             grain_start_pos: scaled_sample_pos,
                             _granular_gap: self.grain_gap,
                             _granular_hold: self.grain_hold,
@@ -3137,6 +4020,8 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                                     self.filter_env_release_2.clamp(0.0001, 1999.9),
                                 )),
                             },
+                            drive_level_1: DriveLevel::default(),
+                            drive_level_2: DriveLevel::default(),
                             // V4 Filter
                             V4F_l_1: V4FilterStruct::default(),
                             V4F_l_2: V4FilterStruct::default(),
@@ -3207,30 +4092,31 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                         );
 
                         // Add unison voices to our voice tracking deque
-                        if self.osc_unison > 1 && ( 
+                        if self.voice_unison > 1 && ( 
                             self.audio_module_type != AudioModuleType::Granulizer &&
                             self.audio_module_type != AudioModuleType::UnsetAm) {
                             
-                            let unison_even_voices = if self.osc_unison % 2 == 0 {
-                                self.osc_unison
+                            let unison_even_voices = if self.voice_unison % 2 == 0 {
+                                self.voice_unison
                             } else {
-                                self.osc_unison - 1
+                                self.voice_unison - 1
                             };
                             
                             /*
                             let mut unison_angles = vec![0.0; unison_even_voices as usize];
                             
                             for i in 1..(unison_even_voices + 1) {
-                                let voice_angle = self.calculate_panning(i - 1, self.osc_unison, stereo_algorithm);
+                                let voice_angle = self.calculate_panning(i - 1, self.voice_unison, stereo_algorithm);
                                 unison_angles[(i - 1) as usize] = voice_angle;
                             }
                             */
                             let unison_angles: Vec<f32> = (0..unison_even_voices as usize)
-                                .map(|i| self.calculate_panning(i, self.osc_unison, stereo_algorithm))
+                                .map(|i| self.calculate_panning(i, self.voice_unison, stereo_algorithm))
                                 .collect();
 
-                            for unison_voice in 0..(self.osc_unison as usize - 1) {
+                            for unison_voice in 0..(self.voice_unison as usize - 1) {
                                 let uni_phase = match self.osc_retrigger {
+                                    _ if phase_locked => new_phase,
                                     RetriggerStyle::MRandom => {
                                         match self.audio_module_type {
                                             AudioModuleType::Additive |
@@ -3255,7 +4141,7 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                                                 if self.sample_lib.len() > 1 {
                                                     if self.sample_lib[note as usize][0].len() > 0 {
                                                         rng.gen_range(
-                                                            0.0..self.sample_lib[note as usize][0].len() as f32,
+                                                            0.0..self.sample_lib[note as usize][0].len() as f64,
                                                         ).floor()
                                                     } else {
                                                         // There's probably no sample loaded
@@ -3279,7 +4165,7 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                                     _velocity: velocity,
                                     vel_mod_amount: uni_velocity_mod,
                                     phase: uni_phase,
-                                    phase_delta: unison_notes[unison_voice] / self.sample_rate,
+                                    phase_delta: unison_notes[unison_voice] as f64 / self.sample_rate as f64,
                                     state: OscState::Attacking,
                                     // These get cloned since smoother cannot be copied
                                     amp_current: 0.0,
@@ -3298,8 +4184,8 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                                     pitch_attack_2: pitch_attack_smoother_2.clone(),
                                     pitch_decay_2: pitch_decay_smoother_2.clone(),
                                     pitch_release_2: pitch_release_smoother_2.clone(),
-                                    _detune: self.osc_detune,
-                                    _unison_detune_value: self.osc_unison_detune,
+                                    _detune: tuned_detune,
+                                    _unison_detune_value: self.voice_unison_detune,
                                     //frequency: unison_notes[unison_voice],
                                     //frequency: 0.0,
                                     //frequency: detuned_note,
@@ -3326,14 +4212,21 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                                             0
                                         },
                                         AudioModuleType::Granulizer | AudioModuleType::Sampler => {
-                                            uni_phase as usize
+                                            if self.slice_count > 1 {
+                                                scaled_sample_pos
+                                            } else {
+                                                uni_phase as usize
+                                            }
                                         },
                                         AudioModuleType::Off | AudioModuleType::UnsetAm => {
                                             0
                                         },
                                     },
-                                    grain_start_pos: 0,
+                                    grain_start_pos: if self.slice_count > 1 { scaled_sample_pos } else { 0 },
                                     loop_it: self.loop_wavetable,
+                                    warmth_state: 0.0,
+                                    sub_phase: 0.0,
+                                    stretch: None,
                                     _granular_gap: 200,
                                     _granular_hold: 200,
                                     granular_hold_end: 200,
@@ -3374,7 +4267,15 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                             }
                         }
 
+                        // Make room at the voice limit using the steal mode
+                        if self.playing_voices.voices.len() >= voice_max {
+                            if let Some(index) = steal_voice_index(&self.playing_voices.voices, self.voice_steal_mode) {
+                                self.playing_voices.voices.remove(index);
+                            }
+                        }
+
                         // Add our voice struct to our voice tracking deque
+                        new_voice.stretch = self.claim_stretch_playhead();
                         self.playing_voices.voices.push_back(new_voice);
 
                         // Remove the last voice when > voice_max
@@ -3395,6 +4296,11 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                                     osc_release: Smoother::new(SmoothingStyle::None),
                                     pitch_enabled: self.pitch_enable,
                                     pitch_env_peak: self.pitch_env_peak,
+                                    punch: 0.0,
+                                    wave_a: self.audio_module_type,
+                                    wave_b: self.osc_type_b,
+                                    humanize_detune: 0.0,
+                                    glide_offset: 0.0,
                                     pitch_current: 0.0,
                                     pitch_state: OscState::Attacking,
                                     pitch_attack: Smoother::new(SmoothingStyle::None),
@@ -3418,6 +4324,9 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                                     _angle: 0.0,
                                     sample_pos: 0,
                                     loop_it: self.loop_wavetable,
+                                    warmth_state: 0.0,
+                                    sub_phase: 0.0,
+                                    stretch: None,
                                     grain_start_pos: 0,
                                     _granular_gap: 200,
                                     _granular_hold: 200,
@@ -3494,6 +4403,8 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                                     filter_atk_smoother_2: Smoother::new(SmoothingStyle::Linear(300.0)),
                                     filter_dec_smoother_2: Smoother::new(SmoothingStyle::Linear(300.0)),
                                     filter_rel_smoother_2: Smoother::new(SmoothingStyle::Linear(300.0)),
+                                    drive_level_1: DriveLevel::default(),
+                                    drive_level_2: DriveLevel::default(),
                                     // V4 Filter
                                     V4F_l_1: V4FilterStruct::default(),
                                     V4F_l_2: V4FilterStruct::default(),
@@ -3527,10 +4438,15 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                     ////////////////////////////////////////////////////////////
                     // MIDI EVENT NOTE OFF
                     ////////////////////////////////////////////////////////////
-                    NoteEvent::NoteOff { note, .. } => {
+                    NoteEvent::NoteOff { note, velocity, .. } => {
                         // Set note off variable to pass back to filter
                         note_off = true;
 
+                        // Release velocity - 64 is neutral, harder releases shorten the tails and softer ones stretch them
+                        // Most keyboards send 0 when they don't do release velocity, so 0 counts as neutral instead of the longest release
+                        let velocity = if velocity > 0.0 { velocity } else { RELEASE_VEL_NEUTRAL };
+                        let release_scale = 2.0_f32.powf(self.release_vel_amount * (RELEASE_VEL_NEUTRAL - velocity) * 2.0);
+
                         // Get voices on our note and not already releasing
                         // When a voice reaches 0.0 target on releasing
 
@@ -3605,6 +4521,11 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                         
                                 // Update current voices to releasing state if they're valid
                             if voice.note == shifted_note && voice.state != OscState::Releasing {
+                                if release_scale != 1.0 {
+                                    voice.osc_release.style = scale_smoothing_time(voice.osc_release.style, release_scale);
+                                    voice.filter_rel_smoother_1.style = scale_smoothing_time(voice.filter_rel_smoother_1.style, release_scale);
+                                    voice.filter_rel_smoother_2.style = scale_smoothing_time(voice.filter_rel_smoother_2.style, release_scale);
+                                }
                                 // Start our release level from our current gain on the voice
                                 voice.osc_release.reset(voice.amp_current);
 
@@ -3625,6 +4546,9 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                                 voice.state = OscState::Releasing;
                                 //for internal_unison_voice in voice.internal_unison_voices.iter_mut() {
                                 voice.internal_unison_voices.par_iter_mut().for_each(|internal_unison_voice|{
+                                    if release_scale != 1.0 {
+                                        internal_unison_voice.osc_release.style = scale_smoothing_time(internal_unison_voice.osc_release.style, release_scale);
+                                    }
                                     internal_unison_voice.osc_release.reset(internal_unison_voice.amp_current);
                                     match internal_unison_voice.osc_release.style {
                                         SmoothingStyle::Logarithmic(_)
@@ -3673,6 +4597,11 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                 osc_release: Smoother::new(SmoothingStyle::None),
                 pitch_enabled: false,
                 pitch_env_peak: 0.0,
+                punch: 0.0,
+                wave_a: AudioModuleType::Sine,
+                wave_b: AudioModuleType::Saw,
+                humanize_detune: 0.0,
+                glide_offset: 0.0,
                 pitch_current: 0.0,
                 pitch_state: OscState::Off,
                 pitch_attack: Smoother::new(SmoothingStyle::None),
@@ -3696,6 +4625,9 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                 _angle: 0.0,
                 sample_pos: 0,
                 loop_it: self.loop_wavetable,
+                warmth_state: 0.0,
+                sub_phase: 0.0,
+                stretch: None,
                 grain_start_pos: 0,
                 _granular_gap: 200,
                 _granular_hold: 200,
@@ -3772,6 +4704,8 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                 filter_atk_smoother_2: Smoother::new(SmoothingStyle::Linear(300.0)),
                 filter_dec_smoother_2: Smoother::new(SmoothingStyle::Linear(300.0)),
                 filter_rel_smoother_2: Smoother::new(SmoothingStyle::Linear(300.0)),
+                drive_level_1: DriveLevel::default(),
+                drive_level_2: DriveLevel::default(),
                 // V4 Filter
                 V4F_l_1: V4FilterStruct::default(),
                 V4F_l_2: V4FilterStruct::default(),
@@ -3909,15 +4843,7 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
             {
                 // Move our phase outside of the midi events
                 // I couldn't find much on how to model this so I based it off previous note phase
-                voice.phase += voice.phase_delta;
-                if voice.phase > 1.0 {
-                    voice.phase -= 1.0;
-                }
-                // This happens on extreme pitch envelope values only and catches wild increments
-                // or pitches above nyquist that would alias into other pitches
-                if voice.phase > 1.0 {
-                    voice.phase = voice.phase % 1.0;
-                }
+                advance_phase(&mut voice.phase, voice.phase_delta);
 
                 // Move our pitch envelopes if this is an Osc
                 if voice.pitch_enabled {
@@ -4026,15 +4952,7 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
 
                 // NEW UNISON STUFF
                 for internal_unison_voice in voice.internal_unison_voices.iter_mut() {
-                    internal_unison_voice.phase += internal_unison_voice.phase_delta;
-                    if internal_unison_voice.phase > 1.0 {
-                        internal_unison_voice.phase -= 1.0;
-                    }
-                    // This happens on extreme pitch envelope values only and catches wild increments
-                    // or pitches above nyquist that would alias into other pitches
-                    if internal_unison_voice.phase > 1.0 {
-                        internal_unison_voice.phase = internal_unison_voice.phase % 1.0;
-                    }
+                    advance_phase(&mut internal_unison_voice.phase, internal_unison_voice.phase_delta);
 
                     // Move our pitch envelopes if this is an Osc
                     if internal_unison_voice.pitch_enabled {
@@ -4204,6 +5122,17 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                     // If we are at the end of our grain and need to create a new one
                     new_grain = true;
                     let new_end = voice.next_grain_pos + self.grain_hold as usize;
+                    // Spray moves where this grain reads from, the schedule underneath carries on unsprayed so it can't drift
+                    let mut grain_pos = voice.next_grain_pos;
+                    if self.grain_spray > 0.0 {
+                        let window = scaled_end_position.saturating_sub(scaled_start_position) as f32 * self.grain_spray;
+                        let offset = spray_random(&mut self.spray_rng) * window;
+                        // End is a hard stop so the whole grain has to fit before it
+                        let latest = scaled_end_position
+                            .saturating_sub(self.grain_hold as usize)
+                            .max(scaled_start_position);
+                        grain_pos = ((grain_pos as f32 + offset).max(scaled_start_position as f32) as usize).min(latest);
+                    }
                     next_grain = SingleVoice {
                         note: voice.note,
                         _velocity: voice._velocity,
@@ -4218,6 +5147,11 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                         osc_release: voice.osc_release.clone(),
                         pitch_enabled: voice.pitch_enabled,
                         pitch_env_peak: voice.pitch_env_peak,
+                        punch: voice.punch,
+                        wave_a: voice.wave_a,
+                        wave_b: voice.wave_b,
+                        humanize_detune: voice.humanize_detune,
+                        glide_offset: voice.glide_offset,
                         pitch_current: voice.pitch_current,
                         pitch_state: voice.pitch_state,
                         pitch_attack: voice.pitch_attack.clone(),
@@ -4239,12 +5173,15 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                         _retrigger: voice._retrigger,
                         _voice_type: voice._voice_type,
                         _angle: voice._angle,
-                        sample_pos: voice.next_grain_pos,
+                        sample_pos: grain_pos,
                         loop_it: voice.loop_it,
-                        grain_start_pos: voice.next_grain_pos,
+                        warmth_state: 0.0,
+                        sub_phase: 0.0,
+                        stretch: None,
+                        grain_start_pos: grain_pos,
                         _granular_gap: self.grain_gap,
                         _granular_hold: self.grain_hold,
-                        granular_hold_end: new_end,
+                        granular_hold_end: grain_pos + self.grain_hold as usize,
                         next_grain_pos: new_end + self.grain_gap as usize,
                         _end_position: voice._end_position,
                         _granular_crossfade: self.grain_crossfade,
@@ -4321,6 +5258,8 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                         filter_atk_smoother_2: Smoother::new(SmoothingStyle::Linear(300.0)),
                         filter_dec_smoother_2: Smoother::new(SmoothingStyle::Linear(300.0)),
                         filter_rel_smoother_2: Smoother::new(SmoothingStyle::Linear(300.0)),
+                        drive_level_1: DriveLevel::default(),
+                        drive_level_2: DriveLevel::default(),
                         // V4 Filter
                         V4F_l_1: V4FilterStruct::default(),
                         V4F_l_2: V4FilterStruct::default(),
@@ -4374,15 +5313,7 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                 for unison_voice in self.unison_voices.voices.iter_mut() {
                     // Move our phase outside of the midi events
                     // I couldn't find much on how to model this so I based it off previous note phase
-                    unison_voice.phase += unison_voice.phase_delta;
-                    if unison_voice.phase > 1.0 {
-                        unison_voice.phase -= 1.0;
-                    }
-                    // This happens on extreme pitch envelope values only and catches wild increments
-                    // or pitches above nyquist that would alias into other pitches
-                    if unison_voice.phase > 1.0 {
-                        unison_voice.phase = unison_voice.phase % 1.0;
-                    }
+                    advance_phase(&mut unison_voice.phase, unison_voice.phase_delta);
 
                     // Move our pitch envelopes if this is an Osc
                     if unison_voice.pitch_enabled
@@ -4632,11 +5563,19 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
             AudioModuleType::Noise => {
                 let mut stereo_voices_l: f32 = 0.0;
                 let mut stereo_voices_r: f32 = 0.0;
+                let punch_decay = (-1.0 / (PUNCH_DECAY_TIME * self.sample_rate)).exp();
+                let wave_fade = self.wave_fade;
+                self.wave_fade = (self.wave_fade - 1.0 / (WAVE_SWITCH_FADE_TIME * self.sample_rate)).max(0.0);
                 //////////////////////////////////////////////////////////////////////////
                 // POLYFILTER UPDATE
                 //////////////////////////////////////////////////////////////////////////
 
                 for voice in self.playing_voices.voices.iter_mut() {
+                    // Glide follows the held note, a released voice keeps the pitch it let go at
+                    if voice.state != OscState::Releasing {
+                        voice.glide_offset = note_pitch.glide;
+                    }
+                    let voice_detune = detune_mod + voice.humanize_detune + voice.glide_offset;
                     // Move the pitch envelope stuff independently of the MIDI info
                     if voice.pitch_enabled {
                         voice.pitch_current = 
@@ -4688,66 +5627,60 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                     }
 
                     voice.amp_current = temp_osc_gain_multiplier;
+                    // Punch is added over the envelope instead of scaling it so it still snaps under a slow attack
+                    let punch = voice.punch;
+                    voice.punch *= punch_decay;
+                    let temp_osc_gain_multiplier = temp_osc_gain_multiplier + punch;
 
+                    // Detune Hz lands after the pitch math so the beating rate stays the same up and down the keyboard
                     let nyquist = self.sample_rate / 2.0;
                     if voice.vel_mod_amount == 0.0 {
                         let base_note = voice.note as f32
                             + voice._detune
-                            + detune_mod
+                            + voice_detune
                             + voice.pitch_current
                             + voice.pitch_current_2;
                         voice.phase_delta =
-                            util::f32_midi_note_to_freq(base_note).min(nyquist) / self.sample_rate;
+                            (util::f32_midi_note_to_freq(base_note) + self.osc_detune_hz).clamp(0.0, nyquist) as f64 / self.sample_rate as f64;
                     } else {
                         let base_note = voice.note as f32
                             + voice._detune
-                            + detune_mod
+                            + voice_detune
                             + (voice.vel_mod_amount * voice._velocity)
                             + voice.pitch_current
                             + voice.pitch_current_2;
                         voice.phase_delta =
-                            util::f32_midi_note_to_freq(base_note).min(nyquist) / self.sample_rate;
+                            (util::f32_midi_note_to_freq(base_note) + self.osc_detune_hz).clamp(0.0, nyquist) as f64 / self.sample_rate as f64;
                     }
 
-                    let temp_center_voices = match self.audio_module_type {
-                        AudioModuleType::Sine => {
-                            Oscillator::get_sine(voice.phase) * temp_osc_gain_multiplier
-                        },
-                        AudioModuleType::Tri => {
-                            Oscillator::get_tri(voice.phase) * temp_osc_gain_multiplier
-                        },
-                        AudioModuleType::Saw => {
-                            Oscillator::get_saw(voice.phase) * temp_osc_gain_multiplier
-                        },
-                        AudioModuleType::RSaw => {
-                            Oscillator::get_rsaw(voice.phase) * temp_osc_gain_multiplier
-                        },
-                        AudioModuleType::WSaw => {
-                            Oscillator::get_wsaw(voice.phase) * temp_osc_gain_multiplier
-                        },
-                        AudioModuleType::RASaw => {
-                            Oscillator::get_rasaw(voice.phase) * temp_osc_gain_multiplier
-                        },
-                        AudioModuleType::SSaw => {
-                            Oscillator::get_ssaw(voice.phase) * temp_osc_gain_multiplier
-                        },
-                        AudioModuleType::Ramp => {
-                            Oscillator::get_ramp(voice.phase) * temp_osc_gain_multiplier
-                        },
-                        AudioModuleType::Square => {
-                            Oscillator::get_square(voice.phase) * temp_osc_gain_multiplier
-                        },
-                        AudioModuleType::RSquare => {
-                            Oscillator::get_rsquare(voice.phase) * temp_osc_gain_multiplier
-                        },
-                        AudioModuleType::Pulse => {
-                            Oscillator::get_pulse(voice.phase) * temp_osc_gain_multiplier
-                        },
-                        AudioModuleType::Noise => {
-                            self.noise_obj.generate_sample() * temp_osc_gain_multiplier
-                        },
-                        AudioModuleType::Additive | AudioModuleType::Granulizer | AudioModuleType::Off | AudioModuleType::UnsetAm | AudioModuleType::Sampler => 0.0,
+                    let waves = if self.live_waveform_switch {
+                        (self.audio_module_type, self.osc_type_b)
+                    } else {
+                        (voice.wave_a, voice.wave_b)
                     };
+                    let temp_center_voices = Oscillator::shape_sample(
+                        switched_wave(
+                            waves,
+                            self.prev_waves,
+                            wave_fade,
+                            morph,
+                            voice.phase as f32,
+                            &mut voice.sub_phase,
+                            voice.phase_delta as f32,
+                            self.osc_quality,
+                            &mut self.noise_obj,
+                            self.osc_drawbars,
+                        ),
+                        self.osc_shape,
+                        shape_amount,
+                    );
+                    let temp_center_voices = Oscillator::warmth_lowpass(
+                        &mut voice.warmth_state,
+                        temp_center_voices,
+                        self.osc_warmth,
+                        voice.phase_delta as f32,
+                    ) * temp_osc_gain_multiplier;
+                    let (spread_l, spread_r) = voice_spread_gains(voice.note, self.voice_spread);
                     for internal_unison_voice in voice.internal_unison_voices.iter_mut() {
                         // Move the pitch envelope stuff independently of the MIDI info
                         if internal_unison_voice.pitch_enabled {
@@ -4800,68 +5733,52 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                         }
 
                         internal_unison_voice.amp_current = temp_osc_gain_multiplier;
+                        let temp_osc_gain_multiplier = temp_osc_gain_multiplier + punch;
 
                         let nyquist = self.sample_rate / 2.0;
                         if internal_unison_voice.vel_mod_amount == 0.0 {
                             let base_note = internal_unison_voice.note as f32
                                 + internal_unison_voice._detune
                                 + internal_unison_voice._unison_detune_value
-                                + detune_mod
+                                + voice_detune
                                 + internal_unison_voice.pitch_current
                                 + internal_unison_voice.pitch_current_2;
                             internal_unison_voice.phase_delta =
-                                util::f32_midi_note_to_freq(base_note).min(nyquist) / self.sample_rate;
+                                (util::f32_midi_note_to_freq(base_note) + self.osc_detune_hz).clamp(0.0, nyquist) as f64 / self.sample_rate as f64;
                         } else {
                             let base_note = internal_unison_voice.note as f32
                                 + internal_unison_voice._detune
                                 + internal_unison_voice._unison_detune_value
-                                + detune_mod
+                                + voice_detune
                                 + (internal_unison_voice.vel_mod_amount * internal_unison_voice._velocity)
                                 + internal_unison_voice.pitch_current
                                 + internal_unison_voice.pitch_current_2;
                             internal_unison_voice.phase_delta =
-                                util::f32_midi_note_to_freq(base_note).min(nyquist) / self.sample_rate;
+                                (util::f32_midi_note_to_freq(base_note) + self.osc_detune_hz).clamp(0.0, nyquist) as f64 / self.sample_rate as f64;
                         }
 
-                        let temp_unison_voice_out = match self.audio_module_type {
-                            AudioModuleType::Sine => {
-                                Oscillator::get_sine(internal_unison_voice.phase) * temp_osc_gain_multiplier
-                            },
-                            AudioModuleType::Tri => {
-                                Oscillator::get_tri(internal_unison_voice.phase) * temp_osc_gain_multiplier
-                            },
-                            AudioModuleType::Saw => {
-                                Oscillator::get_saw(internal_unison_voice.phase) * temp_osc_gain_multiplier
-                            },
-                            AudioModuleType::RSaw => {
-                                Oscillator::get_rsaw(internal_unison_voice.phase) * temp_osc_gain_multiplier
-                            },
-                            AudioModuleType::WSaw => {
-                                Oscillator::get_wsaw(internal_unison_voice.phase) * temp_osc_gain_multiplier
-                            },
-                            AudioModuleType::RASaw => {
-                                Oscillator::get_rasaw(internal_unison_voice.phase) * temp_osc_gain_multiplier
-                            },
-                            AudioModuleType::SSaw => {
-                                Oscillator::get_ssaw(internal_unison_voice.phase) * temp_osc_gain_multiplier
-                            },
-                            AudioModuleType::Ramp => {
-                                Oscillator::get_ramp(internal_unison_voice.phase) * temp_osc_gain_multiplier
-                            },
-                            AudioModuleType::Square => {
-                                Oscillator::get_square(internal_unison_voice.phase) * temp_osc_gain_multiplier
-                            },
-                            AudioModuleType::RSquare => {
-                                Oscillator::get_rsquare(internal_unison_voice.phase) * temp_osc_gain_multiplier
-                            },
-                            AudioModuleType::Pulse => {
-                                Oscillator::get_pulse(internal_unison_voice.phase) * temp_osc_gain_multiplier
-                            },
-                            AudioModuleType::Noise => {
-                                self.noise_obj.generate_sample() * temp_osc_gain_multiplier
-                            },
-                            AudioModuleType::Additive | AudioModuleType::Granulizer | AudioModuleType::Off | AudioModuleType::UnsetAm | AudioModuleType::Sampler => 0.0,
-                        };
+                        let temp_unison_voice_out = Oscillator::shape_sample(
+                            switched_wave(
+                                waves,
+                                self.prev_waves,
+                                wave_fade,
+                                morph,
+                                internal_unison_voice.phase as f32,
+                                &mut internal_unison_voice.sub_phase,
+                                internal_unison_voice.phase_delta as f32,
+                                self.osc_quality,
+                                &mut self.noise_obj,
+                                self.osc_drawbars,
+                            ),
+                            self.osc_shape,
+                            shape_amount,
+                        );
+                        let temp_unison_voice_out = Oscillator::warmth_lowpass(
+                            &mut internal_unison_voice.warmth_state,
+                            temp_unison_voice_out,
+                            self.osc_warmth,
+                            internal_unison_voice.phase_delta as f32,
+                        ) * temp_osc_gain_multiplier;
                         // Create our stereo pan for unison
                         // Our angle comes back as radians
                         let pan = internal_unison_voice._angle;
@@ -4878,8 +5795,8 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                         let right_amp = temp_unison_voice_scaled * (cos_pan - sin_pan);
                         
                         // Add the voice to the sum of stereo voices
-                        stereo_voices_l += left_amp / (self.osc_unison - 1).clamp(1, 9) as f32;
-                        stereo_voices_r += right_amp / (self.osc_unison - 1).clamp(1, 9) as f32;
+                        stereo_voices_l += left_amp * self.unison_side_gain / (self.voice_unison - 1).clamp(1, 9) as f32;
+                        stereo_voices_r += right_amp * self.unison_side_gain / (self.voice_unison - 1).clamp(1, 9) as f32;
                     }
 
                     //////////////////////////////////////////////////////////////////////////
@@ -4933,23 +5850,31 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                         {
                             voice.filter_state_1 = OscState::Sustaining;
                         }
+                        // Looping envelopes go back to attack on the tempo grid while the key is held
+                        if filter_loop_retrigger && voice.filter_state_1 == OscState::Sustaining {
+                            voice.filter_state_1 = OscState::Attacking;
+                            // Start from where the envelope sits now so the restart doesn't jump
+                            let current_level = voice.filter_dec_smoother_1.next();
+                            voice.filter_atk_smoother_1.reset(current_level);
+                            voice.filter_atk_smoother_1.set_target(self.sample_rate, loop_peak_1);
+                        }
                         // use proper variable now that there are four filters and multiple states
                         // This double addition of voice.cutoff_modulation + cutoff_mod will stack the mod at the time of the voice movement with the current
                         next_filter_step = match voice.filter_state_1 {
                             OscState::Attacking => {
-                                (voice.filter_atk_smoother_1.next() + voice.cutoff_modulation + cutoff_mod).clamp(20.0, 20000.0)
+                                (filter_env_level(voice.filter_atk_smoother_1.next(), self.filter_cutoff, self.filter_env_invert_1) + voice.cutoff_modulation + cutoff_mod).clamp(20.0, 20000.0)
                             }
                             OscState::Decaying => {
-                                (voice.filter_dec_smoother_1.next() + voice.cutoff_modulation + cutoff_mod).clamp(20.0, 20000.0)
+                                (filter_env_level(voice.filter_dec_smoother_1.next(), self.filter_cutoff, self.filter_env_invert_1) + voice.cutoff_modulation + cutoff_mod).clamp(20.0, 20000.0)
                             }
                             OscState::Sustaining => {
-                                (voice.filter_dec_smoother_1.next() + voice.cutoff_modulation + cutoff_mod).clamp(20.0, 20000.0)
+                                (filter_env_level(voice.filter_dec_smoother_1.next(), self.filter_cutoff, self.filter_env_invert_1) + voice.cutoff_modulation + cutoff_mod).clamp(20.0, 20000.0)
                             }
                             OscState::Releasing => {
                                 if self.filter_env_release <= 0.0001 {
-                                    (voice.filter_dec_smoother_1.next() + voice.cutoff_modulation + cutoff_mod).clamp(20.0, 20000.0)    
+                                    (filter_env_level(voice.filter_dec_smoother_1.next(), self.filter_cutoff, self.filter_env_invert_1) + voice.cutoff_modulation + cutoff_mod).clamp(20.0, 20000.0)    
                                 } else {
-                                    (voice.filter_rel_smoother_1.next() + voice.cutoff_modulation + cutoff_mod).clamp(20.0, 20000.0)
+                                    (filter_env_level(voice.filter_rel_smoother_1.next(), self.filter_cutoff, self.filter_env_invert_1) + voice.cutoff_modulation + cutoff_mod).clamp(20.0, 20000.0)
                                 }
                             }
                             // I don't expect this to be used
@@ -5000,22 +5925,30 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                         {
                             voice.filter_state_2 = OscState::Sustaining;
                         }
+                        // Looping envelopes go back to attack on the tempo grid while the key is held
+                        if filter_loop_retrigger && voice.filter_state_2 == OscState::Sustaining {
+                            voice.filter_state_2 = OscState::Attacking;
+                            // Start from where the envelope sits now so the restart doesn't jump
+                            let current_level = voice.filter_dec_smoother_2.next();
+                            voice.filter_atk_smoother_2.reset(current_level);
+                            voice.filter_atk_smoother_2.set_target(self.sample_rate, loop_peak_2);
+                        }
                         // use proper variable now that there are four filters and multiple states
                         next_filter_step_2 = match voice.filter_state_2 {
                             OscState::Attacking => {
-                                (voice.filter_atk_smoother_2.next() + voice.cutoff_modulation_2 + cutoff_mod_2).clamp(20.0, 20000.0)
+                                (filter_env_level(voice.filter_atk_smoother_2.next(), self.filter_cutoff_2, self.filter_env_invert_2) + voice.cutoff_modulation_2 + cutoff_mod_2).clamp(20.0, 20000.0)
                             }
                             OscState::Decaying => {
-                                (voice.filter_dec_smoother_2.next() + voice.cutoff_modulation_2 + cutoff_mod_2).clamp(20.0, 20000.0)
+                                (filter_env_level(voice.filter_dec_smoother_2.next(), self.filter_cutoff_2, self.filter_env_invert_2) + voice.cutoff_modulation_2 + cutoff_mod_2).clamp(20.0, 20000.0)
                             }
                             OscState::Sustaining => {
-                                (voice.filter_dec_smoother_2.next() + voice.cutoff_modulation_2 + cutoff_mod_2).clamp(20.0, 20000.0)
+                                (filter_env_level(voice.filter_dec_smoother_2.next(), self.filter_cutoff_2, self.filter_env_invert_2) + voice.cutoff_modulation_2 + cutoff_mod_2).clamp(20.0, 20000.0)
                             }
                             OscState::Releasing => {
                                 if self.filter_env_release_2 <= 0.0001 {
-                                    (voice.filter_dec_smoother_2.next() + voice.cutoff_modulation_2 + cutoff_mod_2).clamp(20.0, 20000.0)    
+                                    (filter_env_level(voice.filter_dec_smoother_2.next(), self.filter_cutoff_2, self.filter_env_invert_2) + voice.cutoff_modulation_2 + cutoff_mod_2).clamp(20.0, 20000.0)    
                                 } else {
-                                    (voice.filter_rel_smoother_2.next() + voice.cutoff_modulation_2 + cutoff_mod_2).clamp(20.0, 20000.0)
+                                    (filter_env_level(voice.filter_rel_smoother_2.next(), self.filter_cutoff_2, self.filter_env_invert_2) + voice.cutoff_modulation_2 + cutoff_mod_2).clamp(20.0, 20000.0)
                                 }
                             }
                             // I don't expect this to be used
@@ -5023,27 +5956,30 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                         };
                     }
 
+                    // Unison blend trades the in tune center voice against the detuned ones
+                    let temp_center_voices = temp_center_voices * self.unison_center_gain;
+
                     //////////////////////////////////////////////////////////////////////////
                     // POLYFILTER UPDATE
                     //////////////////////////////////////////////////////////////////////////
                     match self.audio_module_routing {
                         AMFilterRouting::Bypass | AMFilterRouting::UNSETROUTING => {
-                            left_output += temp_center_voices + stereo_voices_l;
-                            right_output += temp_center_voices + stereo_voices_r;
+                            left_output += temp_center_voices * spread_l + stereo_voices_l;
+                            right_output += temp_center_voices * spread_r + stereo_voices_r;
                         },
                         AMFilterRouting::Filter1 => {
-                            left_output_filter1 = temp_center_voices + stereo_voices_l;
-                            right_output_filter1 = temp_center_voices + stereo_voices_r;
+                            left_output_filter1 = temp_center_voices * spread_l + stereo_voices_l;
+                            right_output_filter1 = temp_center_voices * spread_r + stereo_voices_r;
                         },
                         AMFilterRouting::Filter2 => {
-                            left_output_filter2 = temp_center_voices + stereo_voices_l;
-                            right_output_filter2 = temp_center_voices + stereo_voices_r;
+                            left_output_filter2 = temp_center_voices * spread_l + stereo_voices_l;
+                            right_output_filter2 = temp_center_voices * spread_r + stereo_voices_r;
                         },
                         AMFilterRouting::Both => {
-                            left_output_filter1 = temp_center_voices + stereo_voices_l;
-                            right_output_filter1 = temp_center_voices + stereo_voices_r;
-                            left_output_filter2 = temp_center_voices + stereo_voices_l;
-                            right_output_filter2 = temp_center_voices + stereo_voices_r;
+                            left_output_filter1 = temp_center_voices * spread_l + stereo_voices_l;
+                            right_output_filter1 = temp_center_voices * spread_r + stereo_voices_r;
+                            left_output_filter2 = temp_center_voices * spread_l + stereo_voices_l;
+                            right_output_filter2 = temp_center_voices * spread_r + stereo_voices_r;
                         },
                     }
 
@@ -5052,6 +5988,8 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                             FilterRouting::Parallel => {
                                 let (filter1_processed_l,filter1_processed_r) = filter_process_1(
                                     self.filter_alg_type.clone(),
+                                    self.filter_alg_prev.clone(),
+                                    self.filter_alg_fade,
                                     self.filter_resonance,
                                     self.sample_rate,
                                     self.filter_res_type.clone(),
@@ -5063,12 +6001,18 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                                     self.vcf_filter_type.clone(),
                                     voice,
                                     next_filter_step,
+                                    self.filter_stereo_offset,
                                     resonance_mod,
+                                    drive,
+                                    self.filter_auto_gain,
+                                    self.eco_mode,
                                     left_output_filter1,
                                     right_output_filter1,
                                 );
                                 let (filter2_processed_l,filter2_processed_r) = filter_process_2(
                                     self.filter_alg_type_2.clone(),
+                                    self.filter_alg_prev_2.clone(),
+                                    self.filter_alg_fade_2,
                                     self.filter_resonance_2,
                                     self.sample_rate,
                                     self.filter_res_type_2.clone(),
@@ -5080,7 +6024,11 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                                     self.vcf_filter_type_2.clone(),
                                     voice,
                                     next_filter_step_2,
+                                    self.filter_stereo_offset_2,
                                     resonance_mod_2,
+                                    drive_2,
+                                    self.filter_auto_gain,
+                                    self.eco_mode,
                                     left_output_filter2,
                                     right_output_filter2,
                                 );
@@ -5090,6 +6038,8 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                             FilterRouting::Series12 => {
                                 let (filter1_processed_l,filter1_processed_r) = filter_process_1(
                                     self.filter_alg_type.clone(),
+                                    self.filter_alg_prev.clone(),
+                                    self.filter_alg_fade,
                                     self.filter_resonance,
                                     self.sample_rate,
                                     self.filter_res_type.clone(),
@@ -5101,12 +6051,18 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                                     self.vcf_filter_type.clone(),
                                     voice,
                                     next_filter_step,
+                                    self.filter_stereo_offset,
                                     resonance_mod,
+                                    drive,
+                                    self.filter_auto_gain,
+                                    self.eco_mode,
                                     left_output_filter1,
                                     right_output_filter1,
                                 );
                                 let (filter2_processed_l,filter2_processed_r) = filter_process_2(
                                     self.filter_alg_type_2.clone(),
+                                    self.filter_alg_prev_2.clone(),
+                                    self.filter_alg_fade_2,
                                     self.filter_resonance_2,
                                     self.sample_rate,
                                     self.filter_res_type_2.clone(),
@@ -5118,7 +6074,11 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                                     self.vcf_filter_type_2.clone(),
                                     voice,
                                     next_filter_step_2,
+                                    self.filter_stereo_offset_2,
                                     resonance_mod_2,
+                                    drive_2,
+                                    self.filter_auto_gain,
+                                    self.eco_mode,
                                     left_output_filter2 + filter1_processed_l,
                                     right_output_filter2 + filter1_processed_r,
                                 );
@@ -5128,6 +6088,8 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                             FilterRouting::Series21 => {
                                 let (filter2_processed_l,filter2_processed_r) = filter_process_2(
                                     self.filter_alg_type_2.clone(),
+                                    self.filter_alg_prev_2.clone(),
+                                    self.filter_alg_fade_2,
                                     self.filter_resonance_2,
                                     self.sample_rate,
                                     self.filter_res_type_2.clone(),
@@ -5139,12 +6101,18 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                                     self.vcf_filter_type_2.clone(),
                                     voice,
                                     next_filter_step_2,
+                                    self.filter_stereo_offset_2,
                                     resonance_mod_2,
+                                    drive_2,
+                                    self.filter_auto_gain,
+                                    self.eco_mode,
                                     left_output_filter2,
                                     right_output_filter2,
                                 );
                                 let (filter1_processed_l,filter1_processed_r) = filter_process_1(
                                     self.filter_alg_type.clone(),
+                                    self.filter_alg_prev.clone(),
+                                    self.filter_alg_fade,
                                     self.filter_resonance,
                                     self.sample_rate,
                                     self.filter_res_type.clone(),
@@ -5156,7 +6124,11 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                                     self.vcf_filter_type.clone(),
                                     voice,
                                     next_filter_step,
+                                    self.filter_stereo_offset,
                                     resonance_mod,
+                                    drive,
+                                    self.filter_auto_gain,
+                                    self.eco_mode,
                                     left_output_filter1 + filter2_processed_l,
                                     right_output_filter1 + filter2_processed_r,
                                 );
@@ -5169,7 +6141,7 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
 
                 // Blending when multi-voiced
                 /*
-                if self.osc_unison > 1 {
+                if self.voice_unison > 1 {
                     let l = left_output;
                     let r = right_output;
                     left_output = (l + r * 0.8)/2.0;
@@ -5180,13 +6152,13 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                 // Stereo Spreading code
                 let width_coeff = match stereo_algorithm {
                     StereoAlgorithm::Original => {
-                        self.osc_stereo * 0.5
+                        self.voice_stereo * 0.5
                     }
                     StereoAlgorithm::CubeSpread => {
-                        self.osc_stereo
+                        self.voice_stereo
                     },
                     StereoAlgorithm::ExpSpread => {
-                        self.osc_stereo * 1.8
+                        self.voice_stereo * 1.8
                     },
                 };
                 let l = left_output;
@@ -5204,8 +6176,14 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                 let mut summed_voices_r: f32 = 0.0;
                 let mut stereo_voices_l: f32 = 0.0;
                 let mut stereo_voices_r: f32 = 0.0;
-                let mut center_voices: f32 = 0.0;
+                let mut center_voices_l: f32 = 0.0;
+                let mut center_voices_r: f32 = 0.0;
                 for voice in self.playing_voices.voices.iter_mut() {
+                    // Glide follows the held note, a released voice keeps the pitch it let go at
+                    if voice.state != OscState::Releasing {
+                        voice.glide_offset = note_pitch.glide;
+                    }
+                    let voice_detune = detune_mod + voice.humanize_detune + voice.glide_offset;
                     // Move the pitch envelope stuff independently of the MIDI info
                     if voice.pitch_enabled {
                         voice.pitch_current = 
@@ -5258,27 +6236,32 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
 
                     voice.amp_current = temp_osc_gain_multiplier;
 
+                    // Detune Hz lands after the pitch math so the beating rate stays the same up and down the keyboard
                     let nyquist = self.sample_rate / 2.0;
                     if voice.vel_mod_amount == 0.0 {
                         let base_note = voice.note as f32
                             + voice._detune
-                            + detune_mod
+                            + voice_detune
                             + voice.pitch_current
                             + voice.pitch_current_2;
                         voice.phase_delta =
-                            util::f32_midi_note_to_freq(base_note).min(nyquist) / self.sample_rate;
+                            (util::f32_midi_note_to_freq(base_note) + self.osc_detune_hz).clamp(0.0, nyquist) as f64 / self.sample_rate as f64;
                     } else {
                         let base_note = voice.note as f32
                             + voice._detune
-                            + detune_mod
+                            + voice_detune
                             + (voice.vel_mod_amount * voice._velocity)
                             + voice.pitch_current
                             + voice.pitch_current_2;
                         voice.phase_delta =
-                            util::f32_midi_note_to_freq(base_note).min(nyquist) / self.sample_rate;
+                            (util::f32_midi_note_to_freq(base_note) + self.osc_detune_hz).clamp(0.0, nyquist) as f64 / self.sample_rate as f64;
                     }
 
-                    center_voices += self.additive_module.next_sample(voice, self.sample_rate, detune_mod) * voice.amp_current;
+                    let (spread_l, spread_r) = voice_spread_gains(voice.note, self.voice_spread);
+                    let center_voice = Oscillator::shape_sample(self.additive_module.next_sample(voice, self.sample_rate, voice_detune, self.osc_detune_hz), self.osc_shape, shape_amount) * voice.amp_current;
+                    // Unison blend trades the in tune center voice against the detuned ones
+                    center_voices_l += center_voice * spread_l * self.unison_center_gain;
+                    center_voices_r += center_voice * spread_r * self.unison_center_gain;
                     for internal_unison_voice in voice.internal_unison_voices.iter_mut() {
                         // Move the pitch envelope stuff independently of the MIDI info
                         if internal_unison_voice.pitch_enabled {
@@ -5336,23 +6319,23 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                         if internal_unison_voice.vel_mod_amount == 0.0 {
                             let base_note = internal_unison_voice.note as f32
                                 + internal_unison_voice._detune
-                                + detune_mod
+                                + voice_detune
                                 + internal_unison_voice.pitch_current
                                 + internal_unison_voice.pitch_current_2;
                                 internal_unison_voice.phase_delta =
-                                util::f32_midi_note_to_freq(base_note).min(nyquist) / self.sample_rate;
+                                (util::f32_midi_note_to_freq(base_note) + self.osc_detune_hz).clamp(0.0, nyquist) as f64 / self.sample_rate as f64;
                         } else {
                             let base_note = internal_unison_voice.note as f32
                                 + internal_unison_voice._detune
-                                + detune_mod
+                                + voice_detune
                                 + (internal_unison_voice.vel_mod_amount * internal_unison_voice._velocity)
                                 + internal_unison_voice.pitch_current
                                 + internal_unison_voice.pitch_current_2;
                                 internal_unison_voice.phase_delta =
-                                util::f32_midi_note_to_freq(base_note).min(nyquist) / self.sample_rate;
+                                (util::f32_midi_note_to_freq(base_note) + self.osc_detune_hz).clamp(0.0, nyquist) as f64 / self.sample_rate as f64;
                         }
 
-                        let temp_unison_voice = self.additive_module.next_unison_sample(internal_unison_voice, self.sample_rate, uni_detune_mod) * internal_unison_voice.amp_current;
+                        let temp_unison_voice = Oscillator::shape_sample(self.additive_module.next_unison_sample(internal_unison_voice, self.sample_rate, uni_detune_mod, self.osc_detune_hz), self.osc_shape, shape_amount) * internal_unison_voice.amp_current;
 
                         // Create our stereo pan for unison
 
@@ -5371,8 +6354,8 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                         let right_amp = temp_unison_voice_scaled * (cos_pan - sin_pan);
 
                         // Add the voice to the sum of stereo voices
-                        stereo_voices_l += left_amp;
-                        stereo_voices_r += right_amp;
+                        stereo_voices_l += left_amp * self.unison_side_gain;
+                        stereo_voices_r += right_amp * self.unison_side_gain;
                     }
                 //}
 
@@ -5428,23 +6411,31 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                         {
                             voice.filter_state_1 = OscState::Sustaining;
                         }
+                        // Looping envelopes go back to attack on the tempo grid while the key is held
+                        if filter_loop_retrigger && voice.filter_state_1 == OscState::Sustaining {
+                            voice.filter_state_1 = OscState::Attacking;
+                            // Start from where the envelope sits now so the restart doesn't jump
+                            let current_level = voice.filter_dec_smoother_1.next();
+                            voice.filter_atk_smoother_1.reset(current_level);
+                            voice.filter_atk_smoother_1.set_target(self.sample_rate, loop_peak_1);
+                        }
                         // use proper variable now that there are four filters and multiple states
                         // This double addition of voice.cutoff_modulation + cutoff_mod will stack the mod at the time of the voice movement with the current
                         next_filter_step = match voice.filter_state_1 {
                             OscState::Attacking => {
-                                (voice.filter_atk_smoother_1.next() + voice.cutoff_modulation + cutoff_mod).clamp(20.0, 20000.0)
+                                (filter_env_level(voice.filter_atk_smoother_1.next(), self.filter_cutoff, self.filter_env_invert_1) + voice.cutoff_modulation + cutoff_mod).clamp(20.0, 20000.0)
                             }
                             OscState::Decaying => {
-                                (voice.filter_dec_smoother_1.next() + voice.cutoff_modulation + cutoff_mod).clamp(20.0, 20000.0)
+                                (filter_env_level(voice.filter_dec_smoother_1.next(), self.filter_cutoff, self.filter_env_invert_1) + voice.cutoff_modulation + cutoff_mod).clamp(20.0, 20000.0)
                             }
                             OscState::Sustaining => {
-                                (voice.filter_dec_smoother_1.next() + voice.cutoff_modulation + cutoff_mod).clamp(20.0, 20000.0)
+                                (filter_env_level(voice.filter_dec_smoother_1.next(), self.filter_cutoff, self.filter_env_invert_1) + voice.cutoff_modulation + cutoff_mod).clamp(20.0, 20000.0)
                             }
                             OscState::Releasing => {
                                 if self.filter_env_release <= 0.0001 {
-                                    (voice.filter_dec_smoother_1.next() + voice.cutoff_modulation + cutoff_mod).clamp(20.0, 20000.0)    
+                                    (filter_env_level(voice.filter_dec_smoother_1.next(), self.filter_cutoff, self.filter_env_invert_1) + voice.cutoff_modulation + cutoff_mod).clamp(20.0, 20000.0)    
                                 } else {
-                                    (voice.filter_rel_smoother_1.next() + voice.cutoff_modulation + cutoff_mod).clamp(20.0, 20000.0)
+                                    (filter_env_level(voice.filter_rel_smoother_1.next(), self.filter_cutoff, self.filter_env_invert_1) + voice.cutoff_modulation + cutoff_mod).clamp(20.0, 20000.0)
                                 }
                             }
                             // I don't expect this to be used
@@ -5495,22 +6486,30 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                         {
                             voice.filter_state_2 = OscState::Sustaining;
                         }
+                        // Looping envelopes go back to attack on the tempo grid while the key is held
+                        if filter_loop_retrigger && voice.filter_state_2 == OscState::Sustaining {
+                            voice.filter_state_2 = OscState::Attacking;
+                            // Start from where the envelope sits now so the restart doesn't jump
+                            let current_level = voice.filter_dec_smoother_2.next();
+                            voice.filter_atk_smoother_2.reset(current_level);
+                            voice.filter_atk_smoother_2.set_target(self.sample_rate, loop_peak_2);
+                        }
                         // use proper variable now that there are four filters and multiple states
                         next_filter_step_2 = match voice.filter_state_2 {
                             OscState::Attacking => {
-                                (voice.filter_atk_smoother_2.next() + voice.cutoff_modulation_2 + cutoff_mod_2).clamp(20.0, 20000.0)
+                                (filter_env_level(voice.filter_atk_smoother_2.next(), self.filter_cutoff_2, self.filter_env_invert_2) + voice.cutoff_modulation_2 + cutoff_mod_2).clamp(20.0, 20000.0)
                             }
                             OscState::Decaying => {
-                                (voice.filter_dec_smoother_2.next() + voice.cutoff_modulation_2 + cutoff_mod_2).clamp(20.0, 20000.0)
+                                (filter_env_level(voice.filter_dec_smoother_2.next(), self.filter_cutoff_2, self.filter_env_invert_2) + voice.cutoff_modulation_2 + cutoff_mod_2).clamp(20.0, 20000.0)
                             }
                             OscState::Sustaining => {
-                                (voice.filter_dec_smoother_2.next() + voice.cutoff_modulation_2 + cutoff_mod_2).clamp(20.0, 20000.0)
+                                (filter_env_level(voice.filter_dec_smoother_2.next(), self.filter_cutoff_2, self.filter_env_invert_2) + voice.cutoff_modulation_2 + cutoff_mod_2).clamp(20.0, 20000.0)
                             }
                             OscState::Releasing => {
                                 if self.filter_env_release_2 <= 0.0001 {
-                                    (voice.filter_dec_smoother_2.next() + voice.cutoff_modulation_2 + cutoff_mod_2).clamp(20.0, 20000.0)    
+                                    (filter_env_level(voice.filter_dec_smoother_2.next(), self.filter_cutoff_2, self.filter_env_invert_2) + voice.cutoff_modulation_2 + cutoff_mod_2).clamp(20.0, 20000.0)    
                                 } else {
-                                    (voice.filter_rel_smoother_2.next() + voice.cutoff_modulation_2 + cutoff_mod_2).clamp(20.0, 20000.0)
+                                    (filter_env_level(voice.filter_rel_smoother_2.next(), self.filter_cutoff_2, self.filter_env_invert_2) + voice.cutoff_modulation_2 + cutoff_mod_2).clamp(20.0, 20000.0)
                                 }
                             }
                             // I don't expect this to be used
@@ -5523,22 +6522,22 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                     //////////////////////////////////////////////////////////////////////////
                     match self.audio_module_routing {
                         AMFilterRouting::Bypass | AMFilterRouting::UNSETROUTING => {
-                            left_output += center_voices + stereo_voices_l;
-                            right_output += center_voices + stereo_voices_r;
+                            left_output += center_voices_l + stereo_voices_l;
+                            right_output += center_voices_r + stereo_voices_r;
                         },
                         AMFilterRouting::Filter1 => {
-                            left_output_filter1 = center_voices + stereo_voices_l;
-                            right_output_filter1 = center_voices + stereo_voices_r;
+                            left_output_filter1 = center_voices_l + stereo_voices_l;
+                            right_output_filter1 = center_voices_r + stereo_voices_r;
                         },
                         AMFilterRouting::Filter2 => {
-                            left_output_filter2 = center_voices + stereo_voices_l;
-                            right_output_filter2 = center_voices + stereo_voices_r;
+                            left_output_filter2 = center_voices_l + stereo_voices_l;
+                            right_output_filter2 = center_voices_r + stereo_voices_r;
                         },
                         AMFilterRouting::Both => {
-                            left_output_filter1 = center_voices + stereo_voices_l;
-                            right_output_filter1 = center_voices + stereo_voices_r;
-                            left_output_filter2 = center_voices + stereo_voices_l;
-                            right_output_filter2 = center_voices + stereo_voices_r;
+                            left_output_filter1 = center_voices_l + stereo_voices_l;
+                            right_output_filter1 = center_voices_r + stereo_voices_r;
+                            left_output_filter2 = center_voices_l + stereo_voices_l;
+                            right_output_filter2 = center_voices_r + stereo_voices_r;
                         },
                     }
 
@@ -5547,6 +6546,8 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                             FilterRouting::Parallel => {
                                 let (filter1_processed_l,filter1_processed_r) = filter_process_1(
                                     self.filter_alg_type.clone(),
+                                    self.filter_alg_prev.clone(),
+                                    self.filter_alg_fade,
                                     self.filter_resonance,
                                     self.sample_rate,
                                     self.filter_res_type.clone(),
@@ -5558,12 +6559,18 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                                     self.vcf_filter_type.clone(),
                                     voice,
                                     next_filter_step,
+                                    self.filter_stereo_offset,
                                     resonance_mod,
+                                    drive,
+                                    self.filter_auto_gain,
+                                    self.eco_mode,
                                     left_output_filter1,
                                     right_output_filter1,
                                 );
                                 let (filter2_processed_l,filter2_processed_r) = filter_process_2(
                                     self.filter_alg_type_2.clone(),
+                                    self.filter_alg_prev_2.clone(),
+                                    self.filter_alg_fade_2,
                                     self.filter_resonance_2,
                                     self.sample_rate,
                                     self.filter_res_type_2.clone(),
@@ -5575,7 +6582,11 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                                     self.vcf_filter_type_2.clone(),
                                     voice,
                                     next_filter_step_2,
+                                    self.filter_stereo_offset_2,
                                     resonance_mod_2,
+                                    drive_2,
+                                    self.filter_auto_gain,
+                                    self.eco_mode,
                                     left_output_filter2,
                                     right_output_filter2,
                                 );
@@ -5585,6 +6596,8 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                             FilterRouting::Series12 => {
                                 let (filter1_processed_l,filter1_processed_r) = filter_process_1(
                                     self.filter_alg_type.clone(),
+                                    self.filter_alg_prev.clone(),
+                                    self.filter_alg_fade,
                                     self.filter_resonance,
                                     self.sample_rate,
                                     self.filter_res_type.clone(),
@@ -5596,12 +6609,18 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                                     self.vcf_filter_type.clone(),
                                     voice,
                                     next_filter_step,
+                                    self.filter_stereo_offset,
                                     resonance_mod,
+                                    drive,
+                                    self.filter_auto_gain,
+                                    self.eco_mode,
                                     left_output_filter1,
                                     right_output_filter1,
                                 );
                                 let (filter2_processed_l,filter2_processed_r) = filter_process_2(
                                     self.filter_alg_type_2.clone(),
+                                    self.filter_alg_prev_2.clone(),
+                                    self.filter_alg_fade_2,
                                     self.filter_resonance_2,
                                     self.sample_rate,
                                     self.filter_res_type_2.clone(),
@@ -5613,7 +6632,11 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                                     self.vcf_filter_type_2.clone(),
                                     voice,
                                     next_filter_step_2,
+                                    self.filter_stereo_offset_2,
                                     resonance_mod_2,
+                                    drive_2,
+                                    self.filter_auto_gain,
+                                    self.eco_mode,
                                     left_output_filter2 + filter1_processed_l,
                                     right_output_filter2 + filter1_processed_r,
                                 );
@@ -5623,6 +6646,8 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                             FilterRouting::Series21 => {
                                 let (filter2_processed_l,filter2_processed_r) = filter_process_2(
                                     self.filter_alg_type_2.clone(),
+                                    self.filter_alg_prev_2.clone(),
+                                    self.filter_alg_fade_2,
                                     self.filter_resonance_2,
                                     self.sample_rate,
                                     self.filter_res_type_2.clone(),
@@ -5634,12 +6659,18 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                                     self.vcf_filter_type_2.clone(),
                                     voice,
                                     next_filter_step_2,
+                                    self.filter_stereo_offset_2,
                                     resonance_mod_2,
+                                    drive_2,
+                                    self.filter_auto_gain,
+                                    self.eco_mode,
                                     left_output_filter2,
                                     right_output_filter2,
                                 );
                                 let (filter1_processed_l,filter1_processed_r) = filter_process_1(
                                     self.filter_alg_type.clone(),
+                                    self.filter_alg_prev.clone(),
+                                    self.filter_alg_fade,
                                     self.filter_resonance,
                                     self.sample_rate,
                                     self.filter_res_type.clone(),
@@ -5651,7 +6682,11 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                                     self.vcf_filter_type.clone(),
                                     voice,
                                     next_filter_step,
+                                    self.filter_stereo_offset,
                                     resonance_mod,
+                                    drive,
+                                    self.filter_auto_gain,
+                                    self.eco_mode,
                                     left_output_filter1 + filter2_processed_l,
                                     right_output_filter1 + filter2_processed_r,
                                 );
@@ -5670,11 +6705,11 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                 summed_voices_l += left_output;
                 summed_voices_r += right_output;
                 // Scaling of output based on stereo voices and unison
-                //summed_voices_l += stereo_voices_l / (self.osc_unison - 1).clamp(1, 9) as f32;
-                //summed_voices_r += stereo_voices_r / (self.osc_unison - 1).clamp(1, 9) as f32;
+                //summed_voices_l += stereo_voices_l / (self.voice_unison - 1).clamp(1, 9) as f32;
+                //summed_voices_r += stereo_voices_r / (self.voice_unison - 1).clamp(1, 9) as f32;
 
                 // Blending
-                if self.osc_unison > 1 {
+                if self.voice_unison > 1 {
                     summed_voices_l = (summed_voices_l + summed_voices_r * 0.8)/2.0;
                     summed_voices_r = (summed_voices_r + summed_voices_l * 0.8)/2.0;
                 }
@@ -5682,13 +6717,13 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                 // Stereo Spreading code
                 let width_coeff = match stereo_algorithm {
                     StereoAlgorithm::Original => {
-                        self.osc_stereo * 0.5
+                        self.voice_stereo * 0.5
                     }
                     StereoAlgorithm::CubeSpread => {
-                        self.osc_stereo
+                        self.voice_stereo
                     },
                     StereoAlgorithm::ExpSpread => {
-                        self.osc_stereo * 1.8
+                        self.voice_stereo * 1.8
                     },
                 };
                 let mid = (summed_voices_l + summed_voices_r) * 0.5;
@@ -5700,6 +6735,7 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                 (summed_voices_l, summed_voices_r)
             },
             AudioModuleType::Sampler => {
+                let slice_lib_index = self.slice_lib_index();
                 let mut summed_voices_l: f32 = 0.0;
                 let mut summed_voices_r: f32 = 0.0;
                 let mut center_voices_l: f32 = 0.0;
@@ -5716,14 +6752,59 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                         OscState::Off => 0.0,
                     };
                     voice.amp_current = temp_osc_gain_multiplier;
-
-                    let usize_note = voice.note as usize;
-
+                    let (spread_l, spread_r) = voice_spread_gains(voice.note, self.voice_spread);
+
+                    let usize_note = slice_lib_index.unwrap_or(voice.note as usize);
+
+                    // Spectral stretch reads the analyzed sample instead of the note library
+                    if self.stretch_factor > 1.0 && !self.spectral_frames.is_empty() {
+                        let sample_len = self.spectral_frames.sample_len();
+                        let scaled_start_position = (sample_len as f32 * self.start_position).floor() as usize;
+                        let scaled_end_position = (sample_len as f32 * self._end_position).floor() as usize;
+                        let pitch_ratio = 2.0_f32.powf((voice.note as f32 - self.sample_root as f32) / 12.0);
+                        // Notes past the pool size stay silent rather than allocate a playhead here
+                        let stretched = match voice.stretch {
+                            Some(slot) => self.stretch_pool[slot].next(
+                                &self.spectral_frames,
+                                pitch_ratio,
+                                self.stretch_factor,
+                                scaled_start_position,
+                                scaled_end_position,
+                                voice.loop_it,
+                            ),
+                            None => None,
+                        };
+                        match stretched {
+                            Some((stretched_l, stretched_r)) => {
+                                center_voices_l += stretched_l * temp_osc_gain_multiplier * spread_l;
+                                center_voices_r += stretched_r * temp_osc_gain_multiplier * spread_r;
+                            }
+                            None => voice.state = OscState::Off,
+                        }
+                    }
                     // If we even have valid samples loaded
-                    if self.sample_lib[0][0].len() > 1
+                    else if self.sample_lib[0][0].len() > 1
                         && self.loaded_sample[0].len() > 1
                         && self.sample_lib.len() > 1
                     {
+                        // Slices keep the bounds they were given at note on
+                        let (scaled_start_position, scaled_end_position) = if slice_lib_index.is_some() {
+                            (voice.grain_start_pos, voice._end_position)
+                        } else {
+                            (
+                                (self.sample_lib[usize_note][0].len() as f32 * self.start_position).floor() as usize,
+                                (self.sample_lib[usize_note][0].len() as f32 * self._end_position).floor() as usize,
+                            )
+                        };
+                        let loop_end = (scaled_end_position + 1).min(self.sample_lib[usize_note][0].len());
+                        // Single cycle loops skip the crossfade so the waveform isn't smeared
+                        let xfade_len = if voice.loop_it && !self.single_cycle {
+                            ((self.loop_xfade * 0.001 * self.sample_rate) as usize)
+                                .min(loop_end.saturating_sub(scaled_start_position) / 2)
+                        } else {
+                            0
+                        };
+
                         // Use our Vec<midi note value<VectorOfChannels<VectorOfSamples>>>
                         // If our note is valid 0-127
                         if usize_note < self.sample_lib.len() {
@@ -5732,26 +6813,26 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                                 // Get our channels of sample vectors
                                 let NoteVector = &self.sample_lib[usize_note];
                                 // We don't need to worry about mono/stereo here because it's been setup in load_new_sample()
-                                center_voices_l +=
-                                    NoteVector[0][voice.sample_pos] * temp_osc_gain_multiplier;
-                                center_voices_r +=
-                                    NoteVector[1][voice.sample_pos] * temp_osc_gain_multiplier;
+                                let (sample_l, sample_r) = loop_xfade_read(
+                                    NoteVector,
+                                    voice.sample_pos,
+                                    scaled_start_position,
+                                    loop_end,
+                                    xfade_len,
+                                );
+                                center_voices_l += sample_l * temp_osc_gain_multiplier * spread_l;
+                                center_voices_r += sample_r * temp_osc_gain_multiplier * spread_r;
                             }
                         }
 
-                        let scaled_start_position = (self.sample_lib[usize_note][0].len() as f32
-                            * self.start_position)
-                            .floor() as usize;
-                        let scaled_end_position = (self.sample_lib[usize_note][0].len() as f32
-                            * self._end_position)
-                            .floor() as usize;
                         // Sampler moves position
                         voice.sample_pos += 1;
                         if voice.loop_it
                             && (voice.sample_pos > self.sample_lib[usize_note][0].len()
                                 || voice.sample_pos > scaled_end_position)
                         {
-                            voice.sample_pos = scaled_start_position;
+                            // The crossfade already played the head of the loop, so pick up after it
+                            voice.sample_pos = scaled_start_position + xfade_len;
                         } else if voice.sample_pos > scaled_end_position {
                             voice.sample_pos = self.sample_lib[usize_note][0].len();
                             voice.state = OscState::Off;
@@ -5773,13 +6854,57 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                     };
                     unison_voice.amp_current = temp_osc_gain_multiplier;
 
-                    let usize_note = unison_voice.note as usize;
-
+                    let usize_note = slice_lib_index.unwrap_or(unison_voice.note as usize);
+
+                    // Spectral stretch reads the analyzed sample instead of the note library
+                    if self.stretch_factor > 1.0 && !self.spectral_frames.is_empty() {
+                        let sample_len = self.spectral_frames.sample_len();
+                        let scaled_start_position = (sample_len as f32 * self.start_position).floor() as usize;
+                        let scaled_end_position = (sample_len as f32 * self._end_position).floor() as usize;
+                        let pitch_ratio = 2.0_f32.powf((unison_voice.note as f32 - self.sample_root as f32) / 12.0);
+                        // Notes past the pool size stay silent rather than allocate a playhead here
+                        let stretched = match unison_voice.stretch {
+                            Some(slot) => self.stretch_pool[slot].next(
+                                &self.spectral_frames,
+                                pitch_ratio,
+                                self.stretch_factor,
+                                scaled_start_position,
+                                scaled_end_position,
+                                unison_voice.loop_it,
+                            ),
+                            None => None,
+                        };
+                        match stretched {
+                            Some((stretched_l, stretched_r)) => {
+                                temp_unison_voice_l += stretched_l * temp_osc_gain_multiplier;
+                                temp_unison_voice_r += stretched_r * temp_osc_gain_multiplier;
+                            }
+                            None => unison_voice.state = OscState::Off,
+                        }
+                    }
                     // If we even have valid samples loaded
-                    if self.sample_lib[0][0].len() > 1
+                    else if self.sample_lib[0][0].len() > 1
                         && self.loaded_sample[0].len() > 1
                         && self.sample_lib.len() > 1
                     {
+                        // Slices keep the bounds they were given at note on
+                        let (scaled_start_position, scaled_end_position) = if slice_lib_index.is_some() {
+                            (unison_voice.grain_start_pos, unison_voice._end_position)
+                        } else {
+                            (
+                                (self.sample_lib[usize_note][0].len() as f32 * self.start_position).floor() as usize,
+                                (self.sample_lib[usize_note][0].len() as f32 * self._end_position).floor() as usize,
+                            )
+                        };
+                        let loop_end = (scaled_end_position + 1).min(self.sample_lib[usize_note][0].len());
+                        // Single cycle loops skip the crossfade so the waveform isn't smeared
+                        let xfade_len = if unison_voice.loop_it && !self.single_cycle {
+                            ((self.loop_xfade * 0.001 * self.sample_rate) as usize)
+                                .min(loop_end.saturating_sub(scaled_start_position) / 2)
+                        } else {
+                            0
+                        };
+
                         // Use our Vec<midi note value<VectorOfChannels<VectorOfSamples>>>
                         // If our note is valid 0-127
                         if usize_note < self.sample_lib.len() {
@@ -5788,26 +6913,26 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                                 // Get our channels of sample vectors
                                 let NoteVector = &self.sample_lib[usize_note];
                                 // We don't need to worry about mono/stereo here because it's been setup in load_new_sample()
-                                temp_unison_voice_l +=
-                                    NoteVector[0][unison_voice.sample_pos] * temp_osc_gain_multiplier;
-                                temp_unison_voice_r +=
-                                    NoteVector[1][unison_voice.sample_pos] * temp_osc_gain_multiplier;
+                                let (sample_l, sample_r) = loop_xfade_read(
+                                    NoteVector,
+                                    unison_voice.sample_pos,
+                                    scaled_start_position,
+                                    loop_end,
+                                    xfade_len,
+                                );
+                                temp_unison_voice_l += sample_l * temp_osc_gain_multiplier;
+                                temp_unison_voice_r += sample_r * temp_osc_gain_multiplier;
                             }
                         }
 
-                        let scaled_start_position = (self.sample_lib[usize_note][0].len() as f32
-                            * self.start_position)
-                            .floor() as usize;
-                        let scaled_end_position = (self.sample_lib[usize_note][0].len() as f32
-                            * self._end_position)
-                            .floor() as usize;
                         // Sampler moves position
                         unison_voice.sample_pos += 1;
                         if unison_voice.loop_it
                             && (unison_voice.sample_pos > self.sample_lib[usize_note][0].len()
                                 || unison_voice.sample_pos > scaled_end_position)
                         {
-                            unison_voice.sample_pos = scaled_start_position;
+                            // The crossfade already played the head of the loop, so pick up after it
+                            unison_voice.sample_pos = scaled_start_position + xfade_len;
                         } else if unison_voice.sample_pos > scaled_end_position {
                             unison_voice.sample_pos = self.sample_lib[usize_note][0].len();
                             unison_voice.state = OscState::Off;
@@ -5888,23 +7013,31 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                         {
                             voice.filter_state_1 = OscState::Sustaining;
                         }
+                        // Looping envelopes go back to attack on the tempo grid while the key is held
+                        if filter_loop_retrigger && voice.filter_state_1 == OscState::Sustaining {
+                            voice.filter_state_1 = OscState::Attacking;
+                            // Start from where the envelope sits now so the restart doesn't jump
+                            let current_level = voice.filter_dec_smoother_1.next();
+                            voice.filter_atk_smoother_1.reset(current_level);
+                            voice.filter_atk_smoother_1.set_target(self.sample_rate, loop_peak_1);
+                        }
                         // use proper variable now that there are four filters and multiple states
                         // This double addition of voice.cutoff_modulation + cutoff_mod will stack the mod at the time of the voice movement with the current
                         next_filter_step = match voice.filter_state_1 {
                             OscState::Attacking => {
-                                (voice.filter_atk_smoother_1.next() + voice.cutoff_modulation + cutoff_mod).clamp(20.0, 20000.0)
+                                (filter_env_level(voice.filter_atk_smoother_1.next(), self.filter_cutoff, self.filter_env_invert_1) + voice.cutoff_modulation + cutoff_mod).clamp(20.0, 20000.0)
                             }
                             OscState::Decaying => {
-                                (voice.filter_dec_smoother_1.next() + voice.cutoff_modulation + cutoff_mod).clamp(20.0, 20000.0)
+                                (filter_env_level(voice.filter_dec_smoother_1.next(), self.filter_cutoff, self.filter_env_invert_1) + voice.cutoff_modulation + cutoff_mod).clamp(20.0, 20000.0)
                             }
                             OscState::Sustaining => {
-                                (voice.filter_dec_smoother_1.next() + voice.cutoff_modulation + cutoff_mod).clamp(20.0, 20000.0)
+                                (filter_env_level(voice.filter_dec_smoother_1.next(), self.filter_cutoff, self.filter_env_invert_1) + voice.cutoff_modulation + cutoff_mod).clamp(20.0, 20000.0)
                             }
                             OscState::Releasing => {
                                 if self.filter_env_release <= 0.0001 {
-                                    (voice.filter_dec_smoother_1.next() + voice.cutoff_modulation + cutoff_mod).clamp(20.0, 20000.0)    
+                                    (filter_env_level(voice.filter_dec_smoother_1.next(), self.filter_cutoff, self.filter_env_invert_1) + voice.cutoff_modulation + cutoff_mod).clamp(20.0, 20000.0)    
                                 } else {
-                                    (voice.filter_rel_smoother_1.next() + voice.cutoff_modulation + cutoff_mod).clamp(20.0, 20000.0)
+                                    (filter_env_level(voice.filter_rel_smoother_1.next(), self.filter_cutoff, self.filter_env_invert_1) + voice.cutoff_modulation + cutoff_mod).clamp(20.0, 20000.0)
                                 }
                             }
                             // I don't expect this to be used
@@ -5955,22 +7088,30 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                         {
                             voice.filter_state_2 = OscState::Sustaining;
                         }
+                        // Looping envelopes go back to attack on the tempo grid while the key is held
+                        if filter_loop_retrigger && voice.filter_state_2 == OscState::Sustaining {
+                            voice.filter_state_2 = OscState::Attacking;
+                            // Start from where the envelope sits now so the restart doesn't jump
+                            let current_level = voice.filter_dec_smoother_2.next();
+                            voice.filter_atk_smoother_2.reset(current_level);
+                            voice.filter_atk_smoother_2.set_target(self.sample_rate, loop_peak_2);
+                        }
                         // use proper variable now that there are four filters and multiple states
                         next_filter_step_2 = match voice.filter_state_2 {
                             OscState::Attacking => {
-                                (voice.filter_atk_smoother_2.next() + voice.cutoff_modulation_2 + cutoff_mod_2).clamp(20.0, 20000.0)
+                                (filter_env_level(voice.filter_atk_smoother_2.next(), self.filter_cutoff_2, self.filter_env_invert_2) + voice.cutoff_modulation_2 + cutoff_mod_2).clamp(20.0, 20000.0)
                             }
                             OscState::Decaying => {
-                                (voice.filter_dec_smoother_2.next() + voice.cutoff_modulation_2 + cutoff_mod_2).clamp(20.0, 20000.0)
+                                (filter_env_level(voice.filter_dec_smoother_2.next(), self.filter_cutoff_2, self.filter_env_invert_2) + voice.cutoff_modulation_2 + cutoff_mod_2).clamp(20.0, 20000.0)
                             }
                             OscState::Sustaining => {
-                                (voice.filter_dec_smoother_2.next() + voice.cutoff_modulation_2 + cutoff_mod_2).clamp(20.0, 20000.0)
+                                (filter_env_level(voice.filter_dec_smoother_2.next(), self.filter_cutoff_2, self.filter_env_invert_2) + voice.cutoff_modulation_2 + cutoff_mod_2).clamp(20.0, 20000.0)
                             }
                             OscState::Releasing => {
                                 if self.filter_env_release_2 <= 0.0001 {
-                                    (voice.filter_dec_smoother_2.next() + voice.cutoff_modulation_2 + cutoff_mod_2).clamp(20.0, 20000.0)    
+                                    (filter_env_level(voice.filter_dec_smoother_2.next(), self.filter_cutoff_2, self.filter_env_invert_2) + voice.cutoff_modulation_2 + cutoff_mod_2).clamp(20.0, 20000.0)    
                                 } else {
-                                    (voice.filter_rel_smoother_2.next() + voice.cutoff_modulation_2 + cutoff_mod_2).clamp(20.0, 20000.0)
+                                    (filter_env_level(voice.filter_rel_smoother_2.next(), self.filter_cutoff_2, self.filter_env_invert_2) + voice.cutoff_modulation_2 + cutoff_mod_2).clamp(20.0, 20000.0)
                                 }
                             }
                             // I don't expect this to be used
@@ -6007,6 +7148,8 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                             FilterRouting::Parallel => {
                                 let (filter1_processed_l,filter1_processed_r) = filter_process_1(
                                     self.filter_alg_type.clone(),
+                                    self.filter_alg_prev.clone(),
+                                    self.filter_alg_fade,
                                     self.filter_resonance,
                                     self.sample_rate,
                                     self.filter_res_type.clone(),
@@ -6018,12 +7161,18 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                                     self.vcf_filter_type.clone(),
                                     voice,
                                     next_filter_step,
+                                    self.filter_stereo_offset,
                                     resonance_mod,
+                                    drive,
+                                    self.filter_auto_gain,
+                                    self.eco_mode,
                                     left_output_filter1,
                                     right_output_filter1,
                                 );
                                 let (filter2_processed_l,filter2_processed_r) = filter_process_2(
                                     self.filter_alg_type_2.clone(),
+                                    self.filter_alg_prev_2.clone(),
+                                    self.filter_alg_fade_2,
                                     self.filter_resonance_2,
                                     self.sample_rate,
                                     self.filter_res_type_2.clone(),
@@ -6035,7 +7184,11 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                                     self.vcf_filter_type_2.clone(),
                                     voice,
                                     next_filter_step_2,
+                                    self.filter_stereo_offset_2,
                                     resonance_mod_2,
+                                    drive_2,
+                                    self.filter_auto_gain,
+                                    self.eco_mode,
                                     left_output_filter2,
                                     right_output_filter2,
                                 );
@@ -6045,6 +7198,8 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                             FilterRouting::Series12 => {
                                 let (filter1_processed_l,filter1_processed_r) = filter_process_1(
                                     self.filter_alg_type.clone(),
+                                    self.filter_alg_prev.clone(),
+                                    self.filter_alg_fade,
                                     self.filter_resonance,
                                     self.sample_rate,
                                     self.filter_res_type.clone(),
@@ -6056,12 +7211,18 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                                     self.vcf_filter_type.clone(),
                                     voice,
                                     next_filter_step,
+                                    self.filter_stereo_offset,
                                     resonance_mod,
+                                    drive,
+                                    self.filter_auto_gain,
+                                    self.eco_mode,
                                     left_output_filter1,
                                     right_output_filter1,
                                 );
                                 let (filter2_processed_l,filter2_processed_r) = filter_process_2(
                                     self.filter_alg_type_2.clone(),
+                                    self.filter_alg_prev_2.clone(),
+                                    self.filter_alg_fade_2,
                                     self.filter_resonance_2,
                                     self.sample_rate,
                                     self.filter_res_type_2.clone(),
@@ -6073,7 +7234,11 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                                     self.vcf_filter_type_2.clone(),
                                     voice,
                                     next_filter_step_2,
+                                    self.filter_stereo_offset_2,
                                     resonance_mod_2,
+                                    drive_2,
+                                    self.filter_auto_gain,
+                                    self.eco_mode,
                                     left_output_filter2 + filter1_processed_l,
                                     right_output_filter2 + filter1_processed_r,
                                 );
@@ -6083,6 +7248,8 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                             FilterRouting::Series21 => {
                                 let (filter2_processed_l,filter2_processed_r) = filter_process_2(
                                     self.filter_alg_type_2.clone(),
+                                    self.filter_alg_prev_2.clone(),
+                                    self.filter_alg_fade_2,
                                     self.filter_resonance_2,
                                     self.sample_rate,
                                     self.filter_res_type_2.clone(),
@@ -6094,12 +7261,18 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                                     self.vcf_filter_type_2.clone(),
                                     voice,
                                     next_filter_step_2,
+                                    self.filter_stereo_offset_2,
                                     resonance_mod_2,
+                                    drive_2,
+                                    self.filter_auto_gain,
+                                    self.eco_mode,
                                     left_output_filter2,
                                     right_output_filter2,
                                 );
                                 let (filter1_processed_l,filter1_processed_r) = filter_process_1(
                                     self.filter_alg_type.clone(),
+                                    self.filter_alg_prev.clone(),
+                                    self.filter_alg_fade,
                                     self.filter_resonance,
                                     self.sample_rate,
                                     self.filter_res_type.clone(),
@@ -6111,7 +7284,11 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                                     self.vcf_filter_type.clone(),
                                     voice,
                                     next_filter_step,
+                                    self.filter_stereo_offset,
                                     resonance_mod,
+                                    drive,
+                                    self.filter_auto_gain,
+                                    self.eco_mode,
                                     left_output_filter1 + filter2_processed_l,
                                     right_output_filter1 + filter2_processed_r,
                                 );
@@ -6134,11 +7311,11 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                 summed_voices_l += left_output;
                 summed_voices_r += right_output;
                 // Scaling of output based on stereo voices and unison
-                //summed_voices_l += stereo_voices_l / (self.osc_unison - 1).clamp(1, 9) as f32;
-                //summed_voices_r += stereo_voices_r / (self.osc_unison - 1).clamp(1, 9) as f32;
+                //summed_voices_l += stereo_voices_l / (self.voice_unison - 1).clamp(1, 9) as f32;
+                //summed_voices_r += stereo_voices_r / (self.voice_unison - 1).clamp(1, 9) as f32;
 
                 // Stereo Spreading code
-                let width_coeff = self.osc_stereo * 0.5;
+                let width_coeff = self.voice_stereo * 0.5;
                 let mid = (summed_voices_l + summed_voices_r) * 0.5;
                 let stereo = (summed_voices_r - summed_voices_l) * width_coeff;
                 summed_voices_l = mid - stereo;
@@ -6151,6 +7328,7 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                 (0.0, 0.0)
             },
             AudioModuleType::Granulizer => {
+                let slice_lib_index = self.slice_lib_index();
                 let mut summed_voices_l: f32 = 0.0;
                 let mut summed_voices_r: f32 = 0.0;
                 for voice in self.playing_voices.voices.iter_mut() {
@@ -6163,8 +7341,9 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                         OscState::Off => 0.0,
                     };
                     voice.amp_current = temp_osc_gain_multiplier;
+                    let (spread_l, spread_r) = voice_spread_gains(voice.note, self.voice_spread);
 
-                    let usize_note = voice.note as usize;
+                    let usize_note = slice_lib_index.unwrap_or(voice.note as usize);
 
                     // If we even have valid samples loaded
                     if self.sample_lib[0][0].len() > 1
@@ -6179,32 +7358,38 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                                 // Get our channels of sample vectors
                                 let NoteVector = &self.sample_lib[usize_note];
                                 // If we are in crossfade or in middle of grain after atttack ends
+                                // How far through the grain body we are for the full length windows
+                                let grain_position = (voice.sample_pos.saturating_sub(voice.grain_start_pos) as f32
+                                    / self.grain_hold.max(1) as f32)
+                                    .clamp(0.0, 1.0);
                                 if voice.grain_state == GrainState::Attacking {
                                     // Add our current grain
                                     if voice.grain_attack.steps_left() != 0 {
                                         // This format is: Output = CurrentSample * Voice ADSR * GrainRelease
                                         summed_voices_l += NoteVector[0][voice.sample_pos]
-                                            * temp_osc_gain_multiplier
-                                            * voice.grain_attack.next();
+                                            * temp_osc_gain_multiplier * spread_l
+                                            * grain_window_gain(self.grain_window, voice.grain_attack.next(), grain_position);
                                         summed_voices_r += NoteVector[1][voice.sample_pos]
-                                            * temp_osc_gain_multiplier
-                                            * voice.grain_attack.next();
+                                            * temp_osc_gain_multiplier * spread_r
+                                            * grain_window_gain(self.grain_window, voice.grain_attack.next(), grain_position);
                                     } else {
                                         // This format is: Output = CurrentSample * Voice ADSR * GrainRelease
                                         summed_voices_l += NoteVector[0][voice.sample_pos]
-                                            * temp_osc_gain_multiplier;
+                                            * temp_osc_gain_multiplier * spread_l
+                                            * grain_window_gain(self.grain_window, 1.0, grain_position);
                                         summed_voices_r += NoteVector[1][voice.sample_pos]
-                                            * temp_osc_gain_multiplier;
+                                            * temp_osc_gain_multiplier * spread_r
+                                            * grain_window_gain(self.grain_window, 1.0, grain_position);
                                     }
                                 }
                                 // If we are in crossfade
                                 else if voice.grain_state == GrainState::Releasing {
                                     summed_voices_l += NoteVector[0][voice.sample_pos]
-                                        * temp_osc_gain_multiplier
-                                        * voice.grain_release.next();
+                                        * temp_osc_gain_multiplier * spread_l
+                                        * grain_window_gain(self.grain_window, voice.grain_release.next(), 1.0);
                                     summed_voices_r += NoteVector[1][voice.sample_pos]
-                                        * temp_osc_gain_multiplier
-                                        * voice.grain_release.next();
+                                        * temp_osc_gain_multiplier * spread_r
+                                        * grain_window_gain(self.grain_window, voice.grain_release.next(), 1.0);
                                 }
                             }
                         }
@@ -6281,23 +7466,31 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                         {
                             voice.filter_state_1 = OscState::Sustaining;
                         }
+                        // Looping envelopes go back to attack on the tempo grid while the key is held
+                        if filter_loop_retrigger && voice.filter_state_1 == OscState::Sustaining {
+                            voice.filter_state_1 = OscState::Attacking;
+                            // Start from where the envelope sits now so the restart doesn't jump
+                            let current_level = voice.filter_dec_smoother_1.next();
+                            voice.filter_atk_smoother_1.reset(current_level);
+                            voice.filter_atk_smoother_1.set_target(self.sample_rate, loop_peak_1);
+                        }
                         // use proper variable now that there are four filters and multiple states
                         // This double addition of voice.cutoff_modulation + cutoff_mod will stack the mod at the time of the voice movement with the current
                         next_filter_step = match voice.filter_state_1 {
                             OscState::Attacking => {
-                                (voice.filter_atk_smoother_1.next() + voice.cutoff_modulation + cutoff_mod).clamp(20.0, 20000.0)
+                                (filter_env_level(voice.filter_atk_smoother_1.next(), self.filter_cutoff, self.filter_env_invert_1) + voice.cutoff_modulation + cutoff_mod).clamp(20.0, 20000.0)
                             }
                             OscState::Decaying => {
-                                (voice.filter_dec_smoother_1.next() + voice.cutoff_modulation + cutoff_mod).clamp(20.0, 20000.0)
+                                (filter_env_level(voice.filter_dec_smoother_1.next(), self.filter_cutoff, self.filter_env_invert_1) + voice.cutoff_modulation + cutoff_mod).clamp(20.0, 20000.0)
                             }
                             OscState::Sustaining => {
-                                (voice.filter_dec_smoother_1.next() + voice.cutoff_modulation + cutoff_mod).clamp(20.0, 20000.0)
+                                (filter_env_level(voice.filter_dec_smoother_1.next(), self.filter_cutoff, self.filter_env_invert_1) + voice.cutoff_modulation + cutoff_mod).clamp(20.0, 20000.0)
                             }
                             OscState::Releasing => {
                                 if self.filter_env_release <= 0.0001 {
-                                    (voice.filter_dec_smoother_1.next() + voice.cutoff_modulation + cutoff_mod).clamp(20.0, 20000.0)    
+                                    (filter_env_level(voice.filter_dec_smoother_1.next(), self.filter_cutoff, self.filter_env_invert_1) + voice.cutoff_modulation + cutoff_mod).clamp(20.0, 20000.0)    
                                 } else {
-                                    (voice.filter_rel_smoother_1.next() + voice.cutoff_modulation + cutoff_mod).clamp(20.0, 20000.0)
+                                    (filter_env_level(voice.filter_rel_smoother_1.next(), self.filter_cutoff, self.filter_env_invert_1) + voice.cutoff_modulation + cutoff_mod).clamp(20.0, 20000.0)
                                 }
                             }
                             // I don't expect this to be used
@@ -6348,22 +7541,30 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                         {
                             voice.filter_state_2 = OscState::Sustaining;
                         }
+                        // Looping envelopes go back to attack on the tempo grid while the key is held
+                        if filter_loop_retrigger && voice.filter_state_2 == OscState::Sustaining {
+                            voice.filter_state_2 = OscState::Attacking;
+                            // Start from where the envelope sits now so the restart doesn't jump
+                            let current_level = voice.filter_dec_smoother_2.next();
+                            voice.filter_atk_smoother_2.reset(current_level);
+                            voice.filter_atk_smoother_2.set_target(self.sample_rate, loop_peak_2);
+                        }
                         // use proper variable now that there are four filters and multiple states
                         next_filter_step_2 = match voice.filter_state_2 {
                             OscState::Attacking => {
-                                (voice.filter_atk_smoother_2.next() + voice.cutoff_modulation_2 + cutoff_mod_2).clamp(20.0, 20000.0)
+                                (filter_env_level(voice.filter_atk_smoother_2.next(), self.filter_cutoff_2, self.filter_env_invert_2) + voice.cutoff_modulation_2 + cutoff_mod_2).clamp(20.0, 20000.0)
                             }
                             OscState::Decaying => {
-                                (voice.filter_dec_smoother_2.next() + voice.cutoff_modulation_2 + cutoff_mod_2).clamp(20.0, 20000.0)
+                                (filter_env_level(voice.filter_dec_smoother_2.next(), self.filter_cutoff_2, self.filter_env_invert_2) + voice.cutoff_modulation_2 + cutoff_mod_2).clamp(20.0, 20000.0)
                             }
                             OscState::Sustaining => {
-                                (voice.filter_dec_smoother_2.next() + voice.cutoff_modulation_2 + cutoff_mod_2).clamp(20.0, 20000.0)
+                                (filter_env_level(voice.filter_dec_smoother_2.next(), self.filter_cutoff_2, self.filter_env_invert_2) + voice.cutoff_modulation_2 + cutoff_mod_2).clamp(20.0, 20000.0)
                             }
                             OscState::Releasing => {
                                 if self.filter_env_release_2 <= 0.0001 {
-                                    (voice.filter_dec_smoother_2.next() + voice.cutoff_modulation_2 + cutoff_mod_2).clamp(20.0, 20000.0)    
+                                    (filter_env_level(voice.filter_dec_smoother_2.next(), self.filter_cutoff_2, self.filter_env_invert_2) + voice.cutoff_modulation_2 + cutoff_mod_2).clamp(20.0, 20000.0)    
                                 } else {
-                                    (voice.filter_rel_smoother_2.next() + voice.cutoff_modulation_2 + cutoff_mod_2).clamp(20.0, 20000.0)
+                                    (filter_env_level(voice.filter_rel_smoother_2.next(), self.filter_cutoff_2, self.filter_env_invert_2) + voice.cutoff_modulation_2 + cutoff_mod_2).clamp(20.0, 20000.0)
                                 }
                             }
                             // I don't expect this to be used
@@ -6400,6 +7601,8 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                             FilterRouting::Parallel => {
                                 let (filter1_processed_l,filter1_processed_r) = filter_process_1(
                                     self.filter_alg_type.clone(),
+                                    self.filter_alg_prev.clone(),
+                                    self.filter_alg_fade,
                                     self.filter_resonance,
                                     self.sample_rate,
                                     self.filter_res_type.clone(),
@@ -6411,12 +7614,18 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                                     self.vcf_filter_type.clone(),
                                     voice,
                                     next_filter_step,
+                                    self.filter_stereo_offset,
                                     resonance_mod,
+                                    drive,
+                                    self.filter_auto_gain,
+                                    self.eco_mode,
                                     left_output_filter1,
                                     right_output_filter1,
                                 );
                                 let (filter2_processed_l,filter2_processed_r) = filter_process_2(
                                     self.filter_alg_type_2.clone(),
+                                    self.filter_alg_prev_2.clone(),
+                                    self.filter_alg_fade_2,
                                     self.filter_resonance_2,
                                     self.sample_rate,
                                     self.filter_res_type_2.clone(),
@@ -6428,7 +7637,11 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                                     self.vcf_filter_type_2.clone(),
                                     voice,
                                     next_filter_step_2,
+                                    self.filter_stereo_offset_2,
                                     resonance_mod_2,
+                                    drive_2,
+                                    self.filter_auto_gain,
+                                    self.eco_mode,
                                     left_output_filter2,
                                     right_output_filter2,
                                 );
@@ -6438,6 +7651,8 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                             FilterRouting::Series12 => {
                                 let (filter1_processed_l,filter1_processed_r) = filter_process_1(
                                     self.filter_alg_type.clone(),
+                                    self.filter_alg_prev.clone(),
+                                    self.filter_alg_fade,
                                     self.filter_resonance,
                                     self.sample_rate,
                                     self.filter_res_type.clone(),
@@ -6449,12 +7664,18 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                                     self.vcf_filter_type.clone(),
                                     voice,
                                     next_filter_step,
+                                    self.filter_stereo_offset,
                                     resonance_mod,
+                                    drive,
+                                    self.filter_auto_gain,
+                                    self.eco_mode,
                                     left_output_filter1,
                                     right_output_filter1,
                                 );
                                 let (filter2_processed_l,filter2_processed_r) = filter_process_2(
                                     self.filter_alg_type_2.clone(),
+                                    self.filter_alg_prev_2.clone(),
+                                    self.filter_alg_fade_2,
                                     self.filter_resonance_2,
                                     self.sample_rate,
                                     self.filter_res_type_2.clone(),
@@ -6466,7 +7687,11 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                                     self.vcf_filter_type_2.clone(),
                                     voice,
                                     next_filter_step_2,
+                                    self.filter_stereo_offset_2,
                                     resonance_mod_2,
+                                    drive_2,
+                                    self.filter_auto_gain,
+                                    self.eco_mode,
                                     left_output_filter2 + filter1_processed_l,
                                     right_output_filter2 + filter1_processed_r,
                                 );
@@ -6476,6 +7701,8 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                             FilterRouting::Series21 => {
                                 let (filter2_processed_l,filter2_processed_r) = filter_process_2(
                                     self.filter_alg_type_2.clone(),
+                                    self.filter_alg_prev_2.clone(),
+                                    self.filter_alg_fade_2,
                                     self.filter_resonance_2,
                                     self.sample_rate,
                                     self.filter_res_type_2.clone(),
@@ -6487,12 +7714,18 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                                     self.vcf_filter_type_2.clone(),
                                     voice,
                                     next_filter_step_2,
+                                    self.filter_stereo_offset_2,
                                     resonance_mod_2,
+                                    drive_2,
+                                    self.filter_auto_gain,
+                                    self.eco_mode,
                                     left_output_filter2,
                                     right_output_filter2,
                                 );
                                 let (filter1_processed_l,filter1_processed_r) = filter_process_1(
                                     self.filter_alg_type.clone(),
+                                    self.filter_alg_prev.clone(),
+                                    self.filter_alg_fade,
                                     self.filter_resonance,
                                     self.sample_rate,
                                     self.filter_res_type.clone(),
@@ -6504,7 +7737,11 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                                     self.vcf_filter_type.clone(),
                                     voice,
                                     next_filter_step,
+                                    self.filter_stereo_offset,
                                     resonance_mod,
+                                    drive,
+                                    self.filter_auto_gain,
+                                    self.eco_mode,
                                     left_output_filter1 + filter2_processed_l,
                                     right_output_filter1 + filter2_processed_r,
                                 );
@@ -6532,12 +7769,53 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
         self.is_playing
     }
 
+    pub fn get_sample_rate(&self) -> f32 {
+        self.sample_rate
+    }
+
+    // Anything still sounding, including release tails
+    pub fn has_active_voices(&self) -> bool {
+        !self.playing_voices.voices.is_empty() || !self.unison_voices.voices.is_empty()
+    }
+
+    // Hands a new note the first playhead no playing voice holds, None once every one is taken
+    fn claim_stretch_playhead(&mut self) -> Option<usize> {
+        let slot = (0..self.stretch_pool.len()).find(|slot| {
+            !self
+                .playing_voices
+                .voices
+                .iter()
+                .any(|voice| voice.stretch == Some(*slot))
+        })?;
+        let start = (self.spectral_frames.sample_len() as f32 * self.start_position).floor() as usize;
+        self.stretch_seed = self.stretch_seed.wrapping_mul(1664525).wrapping_add(1013904223);
+        self.stretch_pool[slot].reset(start, self.stretch_seed);
+        Some(slot)
+    }
+
     pub fn clear_voices(&mut self) {
         self.playing_voices.voices.clear();
         self.unison_voices.voices.clear();
     }
 
-    pub fn load_new_sample(&mut self, path: PathBuf) {
+    // Settings and loaded sample for an offline render without the voices or the per note library
+    // Those are moved out while cloning so holding the lock stays cheap, regenerate_samples rebuilds the library
+    pub fn render_copy(&mut self) -> AudioModule {
+        let sample_lib = std::mem::replace(&mut self.sample_lib, vec![vec![vec![0.0, 0.0]]]);
+        let spectral_frames = std::mem::replace(&mut self.spectral_frames, SpectralFrames::new());
+        let stretch_pool = std::mem::take(&mut self.stretch_pool);
+        let playing_voices = std::mem::take(&mut self.playing_voices.voices);
+        let unison_voices = std::mem::take(&mut self.unison_voices.voices);
+        let copy = self.clone();
+        self.sample_lib = sample_lib;
+        self.spectral_frames = spectral_frames;
+        self.stretch_pool = stretch_pool;
+        self.playing_voices.voices = playing_voices;
+        self.unison_voices.voices = unison_voices;
+        copy
+    }
+
+    pub fn load_new_sample(&mut self, path: PathBuf, normalize: bool) {
         let reader = hound::WavReader::open(&path);
         if let Ok(mut reader) = reader {
             let spec = reader.spec();
@@ -6599,16 +7877,85 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                 }
             }
 
-            self.loaded_sample = new_samples;
+            self.set_loaded_sample(new_samples, normalize);
+        };
+    }
 
-            // Based off restretch vs non stretch use different algorithms
-            // To generate a sample library
-            self.regenerate_samples();
+    // Shared tail of loading a file and freezing the output, samples are uninterleaved per channel
+    pub fn set_loaded_sample(&mut self, mut new_samples: Vec<Vec<f32>>, normalize: bool) {
+        // Baked into the loaded sample so the preset saves the normalized data
+        if normalize {
+            normalize_sample(&mut new_samples);
+        }
+
+        self.loaded_sample = new_samples;
+
+        // Guess the pitch of the sample so it plays in tune across the keyboard
+        if self.audio_module_type == AudioModuleType::Sampler {
+            if let Some(root) = detect_root_note(&self.loaded_sample[0], self.sample_rate) {
+                self.sample_root = root;
+            }
+        }
+
+        // Based off restretch vs non stretch use different algorithms
+        // To generate a sample library
+        self.regenerate_samples();
+    }
+
+    // Freeze: resample the last Freeze Time of master output into this module as if it were a loaded file
+    fn freeze_output(
+        setter: &ParamSetter<'_>,
+        params: &ActuateParams,
+        index: u8,
+        module: &Arc<std::sync::Mutex<AudioModule>>,
+        capture: &Arc<std::sync::Mutex<OutputCapture>>,
+    ) {
+        let (freeze_sample, sample_normalize, stored_sample, sample_root) = match index {
+            1 => (&params.freeze_sample_1, &params.sample_normalize_1, &params.am1_sample, &params.sample_root_1),
+            2 => (&params.freeze_sample_2, &params.sample_normalize_2, &params.am2_sample, &params.sample_root_2),
+            _ => (&params.freeze_sample_3, &params.sample_normalize_3, &params.am3_sample, &params.sample_root_3),
         };
+        let captured = capture.lock().unwrap().snapshot(params.freeze_time.value());
+        // Nothing has played yet
+        if captured[0].len() > 1 {
+            let mut module_lock = module.lock().unwrap();
+            module_lock.set_loaded_sample(captured, sample_normalize.value());
+            *stored_sample.lock().unwrap() = module_lock.loaded_sample.clone();
+            setter.set_parameter(sample_root, module_lock.sample_root);
+        }
+        setter.set_parameter(freeze_sample, false);
+    }
+
+    // True when the root note moved since the sample library was built
+    pub fn sample_root_changed(&self) -> bool {
+        self.sample_root != self.prev_sample_root
+            && (self.audio_module_type == AudioModuleType::Sampler
+                || self.audio_module_type == AudioModuleType::Granulizer)
+    }
+
+    // Slices all read the unshifted root entry of the sample library so they keep their pitch
+    fn slice_lib_index(&self) -> Option<usize> {
+        if self.slice_count > 1 {
+            Some((self.sample_root.max(0) as usize).min(self.sample_lib.len() - 1))
+        } else {
+            None
+        }
+    }
+
+    // Drum slicer: Start..End is cut into equal slices and the root note plays the first one,
+    // notes above step through the slices and wrap around. Returns the slice start and end
+    fn slice_bounds(&self, note: u8, sample_len: usize) -> (usize, usize) {
+        let region_start = (sample_len as f32 * self.start_position).floor() as usize;
+        let region_end = ((sample_len as f32 * self._end_position).floor() as usize).min(sample_len);
+        let slice_len = region_end.saturating_sub(region_start) / self.slice_count.max(1) as usize;
+        let slice = (note as i32 - self.sample_root).rem_euclid(self.slice_count.max(1)) as usize;
+        let slice_start = region_start + slice * slice_len;
+        (slice_start, slice_start + slice_len.saturating_sub(1))
     }
 
     // This method performs the sample recalculations when restretch is toggled
     pub fn regenerate_samples(&mut self) {
+        self.prev_sample_root = self.sample_root;
         if !self.sample_lib.is_empty() {
             if self.audio_module_type == AudioModuleType::Sampler {
                 // Compare our restretch change
@@ -6625,13 +7972,25 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
             self.sample_lib.clear();
         }
 
+        // Spectral stretch works from the unshifted sample so it only needs one analysis
+        if self.audio_module_type == AudioModuleType::Sampler {
+            self.spectral_frames = SpectralFrames::analyze(&self.loaded_sample);
+            self.stretch_pool = if self.spectral_frames.is_empty() {
+                Vec::new()
+            } else {
+                vec![StretchPlayhead::new(0, 1); STRETCH_POOL_SIZE]
+            };
+        }
+
         if self.restretch {
             match self.audio_module_type {
                 AudioModuleType::Granulizer | AudioModuleType::Sampler => {
                     let middle_c: f32 = 256.0;
+                    // Move the reference pitch with the root note, 60 keeps the original tuning
+                    let root_pitch = middle_c * 2.0_f32.powf((self.sample_root - 60) as f32 / 12.0);
                     // Generate our sample library from our sample
                     for i in 0..127 {
-                        let target_pitch_factor = util::f32_midi_note_to_freq(i as f32) / middle_c;
+                        let target_pitch_factor = util::f32_midi_note_to_freq(i as f32) / root_pitch;
                     
                         // Calculate the number of samples in the shifted frame
                         let shifted_num_samples =
@@ -6692,7 +8051,7 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
                 AudioModuleType::Granulizer | AudioModuleType::Sampler => {
                     let mut shifter = PitchShifter::new(50, self.sample_rate as usize);
                     for i in 0..127 {
-                        let translated_i = (i as i32 - 60_i32) as f32;
+                        let translated_i = (i as i32 - self.sample_root) as f32;
                         let mut out_buffer_left = vec![0.0; self.loaded_sample[0].len()];
                         let mut out_buffer_right = vec![0.0; self.loaded_sample[0].len()];
                     
@@ -6718,6 +8077,20 @@ MRandom: Every voice uses its own unique random phase every note".to_string());
         }
     }
 
+    // Super macro - layers extra unison voices, detune and stereo width over the knobs without changing them
+    fn apply_super(&mut self, amount: f32) {
+        if amount <= 0.0 {
+            self.voice_unison = self.voice_unison;
+            self.voice_unison_detune = self.voice_unison_detune;
+            self.voice_stereo = self.voice_stereo;
+            return;
+        }
+        // 0 adds no voices so an untouched oscillator stays a single voice, 1 stacks the full 7 voice super on top
+        self.voice_unison = (self.voice_unison + Oscillator::super_voice_count(amount) - 1).min(9);
+        self.voice_unison_detune = self.voice_unison_detune.max(Oscillator::super_detune_curve(amount));
+        self.voice_stereo = self.voice_stereo.max(1.0 + amount);
+    }
+
     fn calculate_panning(&mut self, voice_index: usize, num_voices: i32, stereo_algorithm: StereoAlgorithm) -> f32 {
         // Ensure the voice index is within bounds.
         let voice_index = voice_index.min(num_voices as usize - 1);
@@ -6828,8 +8201,553 @@ fn check_inequality(
 }
 
 
+// Rough loudness compensation for the SVF so sweeping it doesn't change the perceived level
+// Treats the input as pink-ish, so each mode keeps a share of the 20Hz-20kHz octaves based on cutoff,
+// then takes back the energy the resonant peak adds. Resonance here is the param value: 1.0 is none, 0.1 is max
+fn filter_makeup_gain(cutoff: f32, resonance: f32, lp_amount: f32, bp_amount: f32, hp_amount: f32) -> f32 {
+    // 20Hz to 20kHz is just under 10 octaves
+    const AUDIBLE_OCTAVES: f32 = 9.966;
+    // About how many octaves the band pass keeps
+    const BAND_OCTAVES: f32 = 1.0;
+    // Never boost more than +12dB
+    const MAX_MAKEUP: f32 = 3.98;
+
+    let lp_share = ((cutoff.clamp(20.0, 20000.0) / 20.0).log2() / AUDIBLE_OCTAVES).max(0.001);
+    let hp_share = (1.0 - lp_share).max(0.001);
+    let bp_share = BAND_OCTAVES / AUDIBLE_OCTAVES;
+    let power = lp_amount * lp_amount * lp_share
+        + bp_amount * bp_amount * bp_share
+        + hp_amount * hp_amount * hp_share;
+    if power <= 0.0 {
+        return 1.0;
+    }
+
+    let res_amount = ((1.0 - resonance) / 0.9).clamp(0.0, 1.0);
+    (1.0 / power.sqrt()).min(MAX_MAKEUP) / (1.0 + res_amount)
+}
+
+// Moves a voice phase on by one sample, kept in f64 so long held notes don't drift off pitch
+// The second wrap happens on extreme pitch envelope values only and catches wild increments
+// or pitches above nyquist that would alias into other pitches
+fn advance_phase(phase: &mut f64, phase_delta: f64) {
+    *phase += phase_delta;
+    if *phase > 1.0 {
+        *phase -= 1.0;
+    }
+    if *phase > 1.0 {
+        *phase = *phase % 1.0;
+    }
+}
+
+// One sample of a basic oscillator waveform
+fn wave_sample(wave: AudioModuleType, phase: f32, quality: OscQuality, noise_obj: &mut DeterministicWhiteNoiseGenerator) -> f32 {
+    match wave {
+        AudioModuleType::Sine => {
+            if quality == OscQuality::Draft {
+                Oscillator::get_sine_draft(phase)
+            } else {
+                Oscillator::get_sine(phase)
+            }
+        },
+        AudioModuleType::Tri => Oscillator::get_tri(phase),
+        AudioModuleType::Saw => Oscillator::get_saw(phase),
+        AudioModuleType::RSaw => Oscillator::get_rsaw(phase),
+        AudioModuleType::WSaw => Oscillator::get_wsaw(phase),
+        AudioModuleType::RASaw => Oscillator::get_rasaw(phase),
+        AudioModuleType::SSaw => Oscillator::get_ssaw(phase),
+        AudioModuleType::Ramp => Oscillator::get_ramp(phase),
+        AudioModuleType::Square => Oscillator::get_square(phase),
+        AudioModuleType::RSquare => Oscillator::get_rsquare(phase),
+        AudioModuleType::Pulse => Oscillator::get_pulse(phase),
+        AudioModuleType::Noise => noise_obj.generate_sample(),
+        AudioModuleType::Additive | AudioModuleType::Granulizer | AudioModuleType::Off | AudioModuleType::UnsetAm | AudioModuleType::Sampler => 0.0,
+    }
+}
+
+// PolyBLEP correction for the waveforms with hard edges, used in High quality
+fn edge_correction(wave: AudioModuleType, phase: f32, phase_delta: f32) -> f32 {
+    match wave {
+        AudioModuleType::Saw | AudioModuleType::WSaw | AudioModuleType::SSaw | AudioModuleType::RASaw => {
+            Oscillator::saw_blep(phase, phase_delta)
+        },
+        AudioModuleType::Ramp => Oscillator::ramp_blep(phase, phase_delta),
+        AudioModuleType::Square => Oscillator::pulse_blep(phase, phase_delta, 0.5),
+        AudioModuleType::Pulse => Oscillator::pulse_blep(phase, phase_delta, 0.25),
+        _ => 0.0,
+    }
+}
+
+// Crossfade from wave A into wave B, both running off the same phase so there is no drift between them
+// Wave B is skipped entirely at morph 0 so existing patches sound and cost exactly the same
+fn morph_wave(
+    wave_a: AudioModuleType,
+    wave_b: AudioModuleType,
+    morph: f32,
+    phase: f32,
+    phase_delta: f32,
+    quality: OscQuality,
+    noise_obj: &mut DeterministicWhiteNoiseGenerator,
+) -> f32 {
+    let mut a = wave_sample(wave_a, phase, quality, noise_obj);
+    if quality == OscQuality::High {
+        a += edge_correction(wave_a, phase, phase_delta);
+    }
+    if morph <= 0.0 || wave_a == wave_b {
+        return a;
+    }
+    let mut b = wave_sample(wave_b, phase, quality, noise_obj);
+    if quality == OscQuality::High {
+        b += edge_correction(wave_b, phase, phase_delta);
+    }
+    a + (b - a) * morph
+}
+
+// Organ style octave stack on top of morph_wave, each drawbar adds the wave at -1, 0, +1 or +2 octaves
+// Root alone at 1.0 is exactly the plain oscillator, partials that would pass nyquist are skipped
+fn drawbar_wave(
+    wave_a: AudioModuleType,
+    wave_b: AudioModuleType,
+    morph: f32,
+    phase: f32,
+    sub_phase: &mut f64,
+    phase_delta: f32,
+    quality: OscQuality,
+    noise_obj: &mut DeterministicWhiteNoiseGenerator,
+    drawbars: [f32; 4],
+) -> f32 {
+    let mut output = 0.0;
+    if drawbars[0] > 0.0 {
+        *sub_phase = (*sub_phase + phase_delta as f64 * 0.5) % 1.0;
+        output += drawbars[0] * morph_wave(wave_a, wave_b, morph, *sub_phase as f32, phase_delta * 0.5, quality, noise_obj);
+    }
+    if drawbars[1] > 0.0 {
+        output += drawbars[1] * morph_wave(wave_a, wave_b, morph, phase, phase_delta, quality, noise_obj);
+    }
+    for (level, multiplier) in [(drawbars[2], 2.0), (drawbars[3], 4.0)] {
+        if level > 0.0 && phase_delta * multiplier < 0.5 {
+            output += level * morph_wave(wave_a, wave_b, morph, (phase * multiplier).fract(), phase_delta * multiplier, quality, noise_obj);
+        }
+    }
+    output
+}
+
+// drawbar_wave for a voice, crossfading from the previous waveforms while a live switch fade is running
+fn switched_wave(
+    waves: (AudioModuleType, AudioModuleType),
+    prev_waves: (AudioModuleType, AudioModuleType),
+    fade: f32,
+    morph: f32,
+    phase: f32,
+    sub_phase: &mut f64,
+    phase_delta: f32,
+    quality: OscQuality,
+    noise_obj: &mut DeterministicWhiteNoiseGenerator,
+    drawbars: [f32; 4],
+) -> f32 {
+    // The old wave gets its own copy of the sub phase so it isn't advanced twice
+    let mut prev_sub_phase = *sub_phase;
+    let output = drawbar_wave(waves.0, waves.1, morph, phase, sub_phase, phase_delta, quality, noise_obj, drawbars);
+    if fade <= 0.0 || waves == prev_waves {
+        return output;
+    }
+    let prev_output = drawbar_wave(prev_waves.0, prev_waves.1, morph, phase, &mut prev_sub_phase, phase_delta, quality, noise_obj, drawbars);
+    output + (prev_output - output) * fade
+}
+
+// Types that play through the oscillator path
+fn is_osc_wave(module_type: AudioModuleType) -> bool {
+    matches!(
+        module_type,
+        AudioModuleType::Sine
+            | AudioModuleType::Tri
+            | AudioModuleType::Saw
+            | AudioModuleType::RSaw
+            | AudioModuleType::WSaw
+            | AudioModuleType::SSaw
+            | AudioModuleType::RASaw
+            | AudioModuleType::Ramp
+            | AudioModuleType::Square
+            | AudioModuleType::RSquare
+            | AudioModuleType::Pulse
+            | AudioModuleType::Noise
+    )
+}
+
+// Xorshift for grain spray, -1 to 1
+fn spray_random(state: &mut u32) -> f32 {
+    *state ^= *state << 13;
+    *state ^= *state >> 17;
+    *state ^= *state << 5;
+    (*state as f32 / u32::MAX as f32) * 2.0 - 1.0
+}
+
+// Reads a looping sample, blending the last xfade_len samples before the loop end
+// with the head of the loop so the wrap back to the start doesn't click
+fn loop_xfade_read(
+    note_vector: &[Vec<f32>],
+    pos: usize,
+    loop_start: usize,
+    loop_end: usize,
+    xfade_len: usize,
+) -> (f32, f32) {
+    let fade_start = loop_end.saturating_sub(xfade_len);
+    if xfade_len == 0 || pos < fade_start || pos >= loop_end {
+        return (note_vector[0][pos], note_vector[1][pos]);
+    }
+    let offset = pos - fade_start;
+    // Equal power so uncorrelated loop points don't dip in the middle
+    let (fade_in, fade_out) = (offset as f32 / xfade_len as f32 * FRAC_PI_2).sin_cos();
+    let head = loop_start + offset;
+    (
+        note_vector[0][pos] * fade_out + note_vector[0][head] * fade_in,
+        note_vector[1][pos] * fade_out + note_vector[1][head] * fade_in,
+    )
+}
 
+// Cutoff smoothing is logarithmic so sweeps move evenly through the octaves, 0 ms jumps straight to the knob
+fn cutoff_smoother(smooth_ms: f32, current: f32) -> Smoother<f32> {
+    let smoother = Smoother::new(if smooth_ms > 0.0 {
+        SmoothingStyle::Logarithmic(smooth_ms)
+    } else {
+        SmoothingStyle::None
+    });
+    smoother.reset(current);
+    smoother
+}
+
+// Center and detuned voice levels for the unison blend
+// Half is both at full level like before, the ends fade one side out completely
+fn unison_blend_gains(blend: f32, unison: i32) -> (f32, f32) {
+    if unison <= 1 {
+        return (1.0, 1.0);
+    }
+    ((blend * 2.0).min(1.0), ((1.0 - blend) * 2.0).min(1.0))
+}
+
+// Decay and release time multiplier for a note, full amount halves the times every octave above C4
+fn env_keyscale_factor(note: u8, amount: f32) -> f32 {
+    2.0_f32.powf(-amount * (note as f32 - 60.0) / 12.0).clamp(0.125, 8.0)
+}
+
+// Right channel cutoff for the stereo filter offset, 0 semitones returns the left cutoff untouched
+fn stereo_filter_cutoff(cutoff: f32, offset_semitones: f32) -> f32 {
+    if offset_semitones == 0.0 {
+        return cutoff;
+    }
+    (cutoff * 2.0_f32.powf(offset_semitones / 12.0)).clamp(20.0, 20000.0)
+}
+
+// Paraphonic mode - held voices restart their filter attacks from wherever they sit so every
+// note moves with the newest one. Voices already releasing are left alone so their tails stay closed.
+// Each voice still runs its own filter, so this costs nothing extra over polyphonic filtering but
+// chords lose the independent per-note filter movement
+fn retrigger_filter_envs(voice: &mut SingleVoice, sample_rate: f32, peak_1: f32, peak_2: f32) {
+    match voice.filter_state_1 {
+        OscState::Attacking | OscState::Decaying | OscState::Sustaining => {
+            let current_level = if voice.filter_state_1 == OscState::Attacking {
+                voice.filter_atk_smoother_1.next()
+            } else {
+                voice.filter_dec_smoother_1.next()
+            };
+            voice.filter_state_1 = OscState::Attacking;
+            voice.filter_atk_smoother_1.reset(current_level);
+            voice.filter_atk_smoother_1.set_target(sample_rate, peak_1);
+        }
+        _ => {}
+    }
+    match voice.filter_state_2 {
+        OscState::Attacking | OscState::Decaying | OscState::Sustaining => {
+            let current_level = if voice.filter_state_2 == OscState::Attacking {
+                voice.filter_atk_smoother_2.next()
+            } else {
+                voice.filter_dec_smoother_2.next()
+            };
+            voice.filter_state_2 = OscState::Attacking;
+            voice.filter_atk_smoother_2.reset(current_level);
+            voice.filter_atk_smoother_2.set_target(sample_rate, peak_2);
+        }
+        _ => {}
+    }
+}
+
+// The peak a filter envelope attack moves to, TILT gets a gentler range like on note on
+fn filter_env_peak_target(cutoff: f32, env_peak: f32, filter_alg: &FilterAlgorithms) -> f32 {
+    let peak = match filter_alg {
+        FilterAlgorithms::TILT => adv_scale_value(env_peak, -19980.0, 19980.0, -5000.0, 5000.0),
+        _ => env_peak,
+    };
+    (cutoff + peak).clamp(20.0, 20000.0)
+}
+
+// Scales every channel by the same gain so the loudest peak lands on SAMPLE_NORMALIZE_DB, silence is left alone
+fn normalize_sample(sample: &mut [Vec<f32>]) {
+    let peak = sample
+        .iter()
+        .flat_map(|channel| channel.iter())
+        .fold(0.0_f32, |peak, value| peak.max(value.abs()));
+    if peak <= 0.0 {
+        return;
+    }
+    let gain = util::db_to_gain(SAMPLE_NORMALIZE_DB) / peak;
+    for value in sample.iter_mut().flat_map(|channel| channel.iter_mut()) {
+        *value *= gain;
+    }
+}
+
+// Autocorrelation pitch guess for a loaded sample, returns the nearest MIDI note
+// Looks at a short window after the attack and searches between roughly 40Hz and 2kHz
+fn detect_root_note(samples: &[f32], sample_rate: f32) -> Option<i32> {
+    const WINDOW: usize = 4096;
+    let min_lag = (sample_rate / 2000.0) as usize;
+    let max_lag = (sample_rate / 40.0) as usize;
+    if samples.len() < WINDOW + max_lag || min_lag < 2 {
+        return None;
+    }
+    // Skip past the transient where a sample is usually least periodic
+    let start = (samples.len() / 8).min(samples.len() - WINDOW - max_lag);
+    let window = &samples[start..start + WINDOW + max_lag];
+
+    let energy: f32 = window[..WINDOW].iter().map(|x| x * x).sum();
+    if energy < 0.000001 {
+        return None;
+    }
+    let correlation = |lag: usize| -> f32 {
+        let mut sum = 0.0;
+        let mut lag_energy = 0.0;
+        for i in 0..WINDOW {
+            sum += window[i] * window[i + lag];
+            lag_energy += window[i + lag] * window[i + lag];
+        }
+        sum / (energy * lag_energy).sqrt().max(0.000001)
+    };
+    let scores: Vec<f32> = (min_lag..=max_lag).map(correlation).collect();
+    let best = scores.iter().cloned().fold(0.0, f32::max);
+    if best < 0.5 {
+        return None;
+    }
+    // Take the first strong peak so we land on the fundamental rather than a multiple of it
+    let mut index = scores
+        .iter()
+        .position(|score| *score >= best * 0.9)
+        .unwrap_or(0);
+    while index + 1 < scores.len() && scores[index + 1] > scores[index] {
+        index += 1;
+    }
+    // Parabolic interpolation between neighbouring lags for a finer period
+    let mut lag = (index + min_lag) as f32;
+    if index > 0 && index + 1 < scores.len() {
+        let (a, b, c) = (scores[index - 1], scores[index], scores[index + 1]);
+        let denominator = a - 2.0 * b + c;
+        if denominator.abs() > 0.000001 {
+            lag += 0.5 * (a - c) / denominator;
+        }
+    }
+    let frequency = sample_rate / lag;
+    Some((69.0 + 12.0 * (frequency / 440.0).log2()).round().clamp(0.0, 127.0) as i32)
+}
+
+// Pick the voice to drop at the voice limit, voices are pushed in order so the front is the oldest
+fn steal_voice_index(voices: &VecDeque<SingleVoice>, mode: VoiceStealMode) -> Option<usize> {
+    if voices.is_empty() {
+        return None;
+    }
+    match mode {
+        VoiceStealMode::Oldest => Some(0),
+        VoiceStealMode::Quietest => voices
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| a.amp_current.total_cmp(&b.amp_current))
+            .map(|(index, _)| index),
+        VoiceStealMode::Lowest => voices
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, voice)| voice.note)
+            .map(|(index, _)| index),
+        VoiceStealMode::Highest => voices
+            .iter()
+            .enumerate()
+            .max_by_key(|(_, voice)| voice.note)
+            .map(|(index, _)| index),
+    }
+}
+
+// Cent offsets from 12-TET for each step above the tuning root
+// 5-limit just major: 1, 16/15, 9/8, 6/5, 5/4, 4/3, 45/32, 3/2, 8/5, 5/3, 9/5, 15/8
+const JUST_MAJOR_CENTS: [f32; 12] = [0.0, 11.73, 3.91, 15.64, -13.69, -1.96, -9.78, 1.96, 13.69, -15.64, 17.60, -11.73];
+// 5-limit just minor: 1, 16/15, 10/9, 6/5, 5/4, 4/3, 64/45, 3/2, 8/5, 5/3, 9/5, 15/8
+const JUST_MINOR_CENTS: [f32; 12] = [0.0, 11.73, -17.60, 15.64, -13.69, -1.96, 9.78, 1.96, 13.69, -15.64, 17.60, -11.73];
+// Stacked pure fifths: 1, 256/243, 9/8, 32/27, 81/64, 4/3, 729/512, 3/2, 128/81, 27/16, 16/9, 243/128
+const PYTHAGOREAN_CENTS: [f32; 12] = [0.0, -9.78, 3.91, -5.87, 7.82, -1.96, 11.73, 1.96, -7.82, 5.87, -3.91, 9.78];
+// Quarter-comma meantone with the wolf between the sharpened 5th and the flattened 3rd
+const QUARTER_COMMA_CENTS: [f32; 12] = [0.0, -23.95, -6.84, 10.26, -13.69, 3.42, -20.53, -3.42, -27.37, -10.26, 6.84, -17.11];
+
+// Semitone offset a tuning system puts on a note
+fn tuning_offset(note: u8, system: TuningSystem, root: i32) -> f32 {
+    let table = match system {
+        TuningSystem::TwelveTET => return 0.0,
+        TuningSystem::JustMajor => &JUST_MAJOR_CENTS,
+        TuningSystem::JustMinor => &JUST_MINOR_CENTS,
+        TuningSystem::Pythagorean => &PYTHAGOREAN_CENTS,
+        TuningSystem::QuarterComma => &QUARTER_COMMA_CENTS,
+    };
+    table[(note as i32 - root).rem_euclid(12) as usize] / 100.0
+}
+
+// Mirror the filter envelope around the cutoff when inverted so it dips down instead of sweeping up
+fn filter_env_level(level: f32, cutoff: f32, invert: bool) -> f32 {
+    if invert {
+        2.0 * cutoff - level
+    } else {
+        level
+    }
+}
+
+// Stereo gains that place a played note across the field, low notes left and high notes right
+// Equal power so a spread voice keeps its loudness, and the center stays at unity gain
+fn voice_spread_gains(note: u8, spread: f32) -> (f32, f32) {
+    if spread <= 0.0 {
+        return (1.0, 1.0);
+    }
+    let pan = ((note as f32 - 60.0) / 36.0).clamp(-1.0, 1.0) * spread;
+    let angle = (pan + 1.0) * PI / 4.0;
+    (angle.cos() * SQRT_2, angle.sin() * SQRT_2)
+}
+
+// Grain amplitude for a window shape
+// ramp is the grain's crossfade smoother (0-1, 1 while holding), position is 0-1 through the grain body
+// Linear and Tukey shape the crossfades, the rest span the whole hold and reach silence as the grain releases
+fn grain_window_gain(window: GrainWindow, ramp: f32, position: f32) -> f32 {
+    match window {
+        GrainWindow::Linear => ramp,
+        GrainWindow::Tukey => 0.5 - 0.5 * (PI * ramp).cos(),
+        GrainWindow::Hann => ramp * (0.5 - 0.5 * (2.0 * PI * position).cos()),
+        GrainWindow::Triangle => ramp * (1.0 - (2.0 * position - 1.0).abs()),
+        GrainWindow::Gaussian => {
+            // Sigma of 0.2 leaves the edges around -27dB before the crossfade finishes them off
+            let distance = (position - 0.5) / 0.2;
+            ramp * (-0.5 * distance * distance).exp()
+        },
+    }
+}
+
+// Stretch or shrink an envelope segment time, keeping the log curves inside their safe range
+fn scale_smoothing_time(style: SmoothingStyle, scale: f32) -> SmoothingStyle {
+    match style {
+        SmoothingStyle::Linear(time) => SmoothingStyle::Linear(time * scale),
+        SmoothingStyle::Exponential(time) => SmoothingStyle::Exponential(time * scale),
+        SmoothingStyle::Logarithmic(time) => SmoothingStyle::Logarithmic((time * scale).clamp(0.0001, 1999.9)),
+        SmoothingStyle::LogSteep(time) => SmoothingStyle::LogSteep((time * scale).clamp(0.0001, 1999.9)),
+        other => other,
+    }
+}
+
+// Mean square of the signal going into and coming out of a voice's drive stage
+#[derive(Clone, Copy, Default)]
+struct DriveLevel {
+    input: f32,
+    output: f32,
+}
+
+// Filter input saturation - tanh drive scaled by its measured RMS gain so the knob isn't a volume boost at any level
+fn drive_stage(input_l: f32, input_r: f32, drive: f32, level: &mut DriveLevel, sample_rate: f32) -> (f32, f32) {
+    if drive <= 0.0 {
+        return (input_l, input_r);
+    }
+    let gain = 1.0 + drive * 9.0;
+    let driven_l = (input_l * gain).tanh();
+    let driven_r = (input_r * gain).tanh();
+    let coeff = 1.0 - (-1.0 / (DRIVE_RMS_TIME * sample_rate)).exp();
+    level.input = remove_denormals(level.input + (input_l * input_l + input_r * input_r - level.input) * coeff);
+    level.output = remove_denormals(level.output + (driven_l * driven_l + driven_r * driven_r - level.output) * coeff);
+    // Before the followers have anything to go on the small signal gain is the safe guess
+    let makeup = if level.output > 1e-12 {
+        (level.input / level.output).sqrt().clamp(1.0 / gain, 1.0)
+    } else {
+        1.0 / gain
+    };
+    (
+        input_l + (driven_l * makeup - input_l) * drive,
+        input_r + (driven_r * makeup - input_r) * drive,
+    )
+}
+
+// Runs the selected algorithm, blending out of the previous one for a few ms after a switch so it doesn't click
 fn filter_process_1(
+    filter_alg_type: FilterAlgorithms,
+    filter_alg_prev: FilterAlgorithms,
+    alg_fade: f32,
+    filter_resonance: f32,
+    sample_rate: f32,
+    filter_res_type: ResonanceType,
+    lp_amount: f32,
+    bp_amount: f32,
+    hp_amount: f32,
+    filter_wet: f32,
+    tilt_filter_type: ResponseType,
+    vcf_filter_type: VCFResponseType,
+    voice: &mut SingleVoice,
+    next_filter_step: f32,
+    stereo_offset: f32,
+    filter_resonance_mod: f32,
+    filter_drive: f32,
+    auto_gain: bool,
+    eco_mode: bool,
+    left_input_filter1: f32,
+    right_input_filter1: f32,
+) -> (f32, f32) {
+    // Drive runs once ahead of both algorithms so a fade doesn't step its level follower twice
+    let (left_input_filter1, right_input_filter1) =
+        drive_stage(left_input_filter1, right_input_filter1, filter_drive, &mut voice.drive_level_1, sample_rate);
+    let (left_output, right_output) = filter_algorithm_1(
+        filter_alg_type,
+        filter_resonance,
+        sample_rate,
+        filter_res_type.clone(),
+        lp_amount,
+        bp_amount,
+        hp_amount,
+        filter_wet,
+        tilt_filter_type.clone(),
+        vcf_filter_type.clone(),
+        voice,
+        next_filter_step,
+        stereo_offset,
+        filter_resonance_mod,
+        auto_gain,
+        eco_mode,
+        left_input_filter1,
+        right_input_filter1,
+    );
+    if alg_fade <= 0.0 {
+        return (left_output, right_output);
+    }
+    let (prev_left, prev_right) = filter_algorithm_1(
+        filter_alg_prev,
+        filter_resonance,
+        sample_rate,
+        filter_res_type.clone(),
+        lp_amount,
+        bp_amount,
+        hp_amount,
+        filter_wet,
+        tilt_filter_type.clone(),
+        vcf_filter_type.clone(),
+        voice,
+        next_filter_step,
+        stereo_offset,
+        filter_resonance_mod,
+        auto_gain,
+        eco_mode,
+        left_input_filter1,
+        right_input_filter1,
+    );
+    (
+        left_output * (1.0 - alg_fade) + prev_left * alg_fade,
+        right_output * (1.0 - alg_fade) + prev_right * alg_fade,
+    )
+}
+
+fn filter_algorithm_1(
     filter_alg_type: FilterAlgorithms,
     filter_resonance: f32,
     sample_rate: f32,
@@ -6842,13 +8760,19 @@ fn filter_process_1(
     vcf_filter_type: VCFResponseType,
     voice: &mut SingleVoice,
     next_filter_step: f32,
+    stereo_offset: f32,
     filter_resonance_mod: f32,
+    auto_gain: bool,
+    eco_mode: bool,
     left_input_filter1: f32,
     right_input_filter1: f32,
 ) -> (f32, f32) {
+    let next_filter_step_r = stereo_filter_cutoff(next_filter_step, stereo_offset);
     match filter_alg_type {
         FilterAlgorithms::SVF => {
             // Filtering before output
+            voice.filter_l_1.set_eco(eco_mode);
+            voice.filter_r_1.set_eco(eco_mode);
             voice.filter_l_1.update(
                 next_filter_step,
                 filter_resonance - filter_resonance_mod,
@@ -6856,7 +8780,7 @@ fn filter_process_1(
                 filter_res_type.clone(),
             );
             voice.filter_r_1.update(
-                next_filter_step,
+                next_filter_step_r,
                 filter_resonance - filter_resonance_mod,
                 sample_rate,
                 filter_res_type.clone(),
@@ -6869,21 +8793,34 @@ fn filter_process_1(
             let high_r: f32;
             (low_l, band_l, high_l) = voice.filter_l_1.process(left_input_filter1);
             (low_r, band_r, high_r) = voice.filter_r_1.process(right_input_filter1);
+            let makeup = if auto_gain {
+                filter_makeup_gain(
+                    next_filter_step,
+                    filter_resonance - filter_resonance_mod,
+                    lp_amount,
+                    bp_amount,
+                    hp_amount,
+                )
+            } else {
+                1.0
+            };
             let left_output = (low_l * lp_amount
                 + band_l * bp_amount
                 + high_l * hp_amount)
+                * makeup
                 * filter_wet
                 + left_input_filter1 * (1.0 - filter_wet);
             let right_output = (low_r * lp_amount
                 + band_r * bp_amount
                 + high_r * hp_amount)
+                * makeup
                 * filter_wet
                 + right_input_filter1 * (1.0 - filter_wet);
             (left_output,right_output)
         }
         FilterAlgorithms::TILT => {
             voice.tilt_filter_l_1.set_cutoff(next_filter_step);
-            voice.tilt_filter_r_1.set_cutoff(next_filter_step);
+            voice.tilt_filter_r_1.set_cutoff(next_filter_step_r);
             voice.tilt_filter_l_1.set_tilt(tilt_filter_type.clone());
             voice.tilt_filter_r_1.set_tilt(tilt_filter_type.clone());
             let tilt_out_l = voice.tilt_filter_l_1.process(left_input_filter1 * db_to_gain(-12.0));
@@ -6902,7 +8839,7 @@ fn filter_process_1(
                 sample_rate,
             );
             voice.vcf_filter_r_1.update(
-                next_filter_step,
+                next_filter_step_r,
                 filter_resonance - filter_resonance_mod,
                 vcf_filter_type.clone(),
                 sample_rate,
@@ -6923,7 +8860,7 @@ fn filter_process_1(
             );
             voice.V4F_r_1.update(
                 filter_resonance,
-                next_filter_step,
+                next_filter_step_r,
                 sample_rate
             );
             let v4f_out_l = voice.V4F_l_1.process(left_input_filter1);
@@ -6940,7 +8877,7 @@ fn filter_process_1(
                 filter_resonance, 
                 sample_rate);
             voice.A4I_r_1.update(
-                next_filter_step, 
+                next_filter_step_r, 
                 filter_resonance, 
                 sample_rate);
             let a4i_out_l = voice.A4I_l_1.process(left_input_filter1);
@@ -6957,7 +8894,7 @@ fn filter_process_1(
                 filter_resonance,
                 sample_rate);
             voice.A4II_r_1.update(
-                next_filter_step,
+                next_filter_step_r,
                 filter_resonance,
                 sample_rate);
             let a4ii_out_l = voice.A4II_l_1.process(left_input_filter1);
@@ -6971,8 +8908,11 @@ fn filter_process_1(
     }
 }
 
+// Runs the selected algorithm, blending out of the previous one for a few ms after a switch so it doesn't click
 fn filter_process_2(
     filter_alg_type: FilterAlgorithms,
+    filter_alg_prev: FilterAlgorithms,
+    alg_fade: f32,
     filter_resonance: f32,
     sample_rate: f32,
     filter_res_type: ResonanceType,
@@ -6984,13 +8924,92 @@ fn filter_process_2(
     vcf_filter_type: VCFResponseType,
     voice: &mut SingleVoice,
     next_filter_step: f32,
+    stereo_offset: f32,
     filter_resonance_mod: f32,
+    filter_drive: f32,
+    auto_gain: bool,
+    eco_mode: bool,
     left_input_filter2: f32,
     right_input_filter2: f32,
 ) -> (f32, f32) {
+    // Drive runs once ahead of both algorithms so a fade doesn't step its level follower twice
+    let (left_input_filter2, right_input_filter2) =
+        drive_stage(left_input_filter2, right_input_filter2, filter_drive, &mut voice.drive_level_2, sample_rate);
+    let (left_output, right_output) = filter_algorithm_2(
+        filter_alg_type,
+        filter_resonance,
+        sample_rate,
+        filter_res_type.clone(),
+        lp_amount,
+        bp_amount,
+        hp_amount,
+        filter_wet,
+        tilt_filter_type.clone(),
+        vcf_filter_type.clone(),
+        voice,
+        next_filter_step,
+        stereo_offset,
+        filter_resonance_mod,
+        auto_gain,
+        eco_mode,
+        left_input_filter2,
+        right_input_filter2,
+    );
+    if alg_fade <= 0.0 {
+        return (left_output, right_output);
+    }
+    let (prev_left, prev_right) = filter_algorithm_2(
+        filter_alg_prev,
+        filter_resonance,
+        sample_rate,
+        filter_res_type.clone(),
+        lp_amount,
+        bp_amount,
+        hp_amount,
+        filter_wet,
+        tilt_filter_type.clone(),
+        vcf_filter_type.clone(),
+        voice,
+        next_filter_step,
+        stereo_offset,
+        filter_resonance_mod,
+        auto_gain,
+        eco_mode,
+        left_input_filter2,
+        right_input_filter2,
+    );
+    (
+        left_output * (1.0 - alg_fade) + prev_left * alg_fade,
+        right_output * (1.0 - alg_fade) + prev_right * alg_fade,
+    )
+}
+
+fn filter_algorithm_2(
+    filter_alg_type: FilterAlgorithms,
+    filter_resonance: f32,
+    sample_rate: f32,
+    filter_res_type: ResonanceType,
+    lp_amount: f32,
+    bp_amount: f32,
+    hp_amount: f32,
+    filter_wet: f32,
+    tilt_filter_type: ResponseType,
+    vcf_filter_type: VCFResponseType,
+    voice: &mut SingleVoice,
+    next_filter_step: f32,
+    stereo_offset: f32,
+    filter_resonance_mod: f32,
+    auto_gain: bool,
+    eco_mode: bool,
+    left_input_filter2: f32,
+    right_input_filter2: f32,
+) -> (f32, f32) {
+    let next_filter_step_r = stereo_filter_cutoff(next_filter_step, stereo_offset);
     match filter_alg_type {
         FilterAlgorithms::SVF => {
             // Filtering before output
+            voice.filter_l_2.set_eco(eco_mode);
+            voice.filter_r_2.set_eco(eco_mode);
             voice.filter_l_2.update(
                 next_filter_step,
                 filter_resonance - filter_resonance_mod,
@@ -6998,7 +9017,7 @@ fn filter_process_2(
                 filter_res_type.clone(),
             );
             voice.filter_r_2.update(
-                next_filter_step,
+                next_filter_step_r,
                 filter_resonance - filter_resonance_mod,
                 sample_rate,
                 filter_res_type.clone(),
@@ -7011,21 +9030,34 @@ fn filter_process_2(
             let high_r: f32;
             (low_l, band_l, high_l) = voice.filter_l_2.process(left_input_filter2);
             (low_r, band_r, high_r) = voice.filter_r_2.process(right_input_filter2);
+            let makeup = if auto_gain {
+                filter_makeup_gain(
+                    next_filter_step,
+                    filter_resonance - filter_resonance_mod,
+                    lp_amount,
+                    bp_amount,
+                    hp_amount,
+                )
+            } else {
+                1.0
+            };
             let left_output = (low_l * lp_amount
                 + band_l * bp_amount
                 + high_l * hp_amount)
+                * makeup
                 * filter_wet
                 + left_input_filter2 * (1.0 - filter_wet);
             let right_output = (low_r * lp_amount
                 + band_r * bp_amount
                 + high_r * hp_amount)
+                * makeup
                 * filter_wet
                 + right_input_filter2 * (1.0 - filter_wet);
             (left_output,right_output)
         }
         FilterAlgorithms::TILT => {
             voice.tilt_filter_l_2.set_cutoff(next_filter_step);
-            voice.tilt_filter_r_2.set_cutoff(next_filter_step);
+            voice.tilt_filter_r_2.set_cutoff(next_filter_step_r);
             voice.tilt_filter_l_2.set_tilt(tilt_filter_type.clone());
             voice.tilt_filter_r_2.set_tilt(tilt_filter_type.clone());
             let tilt_out_l = voice.tilt_filter_l_2.process(left_input_filter2 * db_to_gain(-12.0));
@@ -7044,7 +9076,7 @@ fn filter_process_2(
                 sample_rate,
             );
             voice.vcf_filter_r_2.update(
-                next_filter_step,
+                next_filter_step_r,
                 filter_resonance - filter_resonance_mod,
                 vcf_filter_type.clone(),
                 sample_rate,
@@ -7065,7 +9097,7 @@ fn filter_process_2(
             );
             voice.V4F_r_2.update(
                 filter_resonance,
-                next_filter_step,
+                next_filter_step_r,
                 sample_rate
             );
             let v4f_out_l = voice.V4F_l_2.process(left_input_filter2);
@@ -7082,7 +9114,7 @@ fn filter_process_2(
                 filter_resonance, 
                 sample_rate);
             voice.A4I_r_2.update(
-                next_filter_step, 
+                next_filter_step_r, 
                 filter_resonance, 
                 sample_rate);
             let a4i_out_l = voice.A4I_l_2.process(left_input_filter2);
@@ -7099,7 +9131,7 @@ fn filter_process_2(
                 filter_resonance,
                 sample_rate);
             voice.A4II_r_2.update(
-                next_filter_step,
+                next_filter_step_r,
                 filter_resonance,
                 sample_rate);
             let a4ii_out_l = voice.A4II_l_1.process(left_input_filter2);
@@ -7111,4 +9143,376 @@ fn filter_process_2(
             (left_output,right_output)
         }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn filter_makeup_gain_is_unity_at_neutral_settings() {
+        // Fully open low pass with no resonance passes everything
+        assert!((filter_makeup_gain(20000.0, 1.0, 1.0, 0.0, 0.0) - 1.0).abs() < 1e-4);
+        // Low and high pass together split the spectrum at any cutoff
+        for cutoff in [100.0, 1000.0, 5000.0] {
+            assert!((filter_makeup_gain(cutoff, 1.0, 1.0, 0.0, 1.0) - 1.0).abs() < 1e-4);
+        }
+        // Nothing in the mix leaves the level alone
+        assert_eq!(filter_makeup_gain(1000.0, 0.1, 0.0, 0.0, 0.0), 1.0);
+    }
+
+    #[test]
+    fn filter_makeup_gain_falls_with_resonance() {
+        let mut previous = filter_makeup_gain(1000.0, 1.0, 1.0, 0.0, 0.0);
+        // Resonance runs from 1.0 (none) down to 0.1 (max)
+        for step in 1..=9 {
+            let gain = filter_makeup_gain(1000.0, 1.0 - step as f32 * 0.1, 1.0, 0.0, 0.0);
+            assert!(gain < previous);
+            previous = gain;
+        }
+        // Max resonance takes back half
+        let open = filter_makeup_gain(1000.0, 1.0, 1.0, 0.0, 0.0);
+        assert!((filter_makeup_gain(1000.0, 0.1, 1.0, 0.0, 0.0) - open * 0.5).abs() < 1e-4);
+    }
+
+    #[test]
+    fn filter_makeup_gain_follows_the_mix() {
+        // More of each mode in the mix means more power passes, so less makeup
+        let mut previous = filter_makeup_gain(1000.0, 1.0, 1.0, 0.0, 0.0);
+        for step in 1..=10 {
+            let gain = filter_makeup_gain(1000.0, 1.0, 1.0, step as f32 * 0.1, 0.0);
+            assert!(gain < previous);
+            previous = gain;
+        }
+        // High pass alone starts past the cap at low amounts so sweep it from halfway
+        let mut previous = filter_makeup_gain(1000.0, 1.0, 0.0, 0.0, 0.5);
+        for step in 6..=10 {
+            let gain = filter_makeup_gain(1000.0, 1.0, 0.0, 0.0, step as f32 * 0.1);
+            assert!(gain < previous);
+            previous = gain;
+        }
+        // Closing the low pass needs more makeup, up to the +12dB cap
+        assert!(filter_makeup_gain(200.0, 1.0, 1.0, 0.0, 0.0) > filter_makeup_gain(2000.0, 1.0, 1.0, 0.0, 0.0));
+        assert!((filter_makeup_gain(20.0, 1.0, 1.0, 0.0, 0.0) - 3.98).abs() < 1e-4);
+    }
+
+    #[test]
+    fn phase_accumulator_holds_pitch_over_long_notes() {
+        // 5 minutes of a 20kHz note at 44.1kHz
+        let sample_rate = 44100.0_f64;
+        let frequency = 20000.0_f32;
+        let samples = 44100 * 60 * 5;
+        let phase_delta = frequency as f64 / sample_rate;
+        let mut phase = 0.0_f64;
+        let mut cycles = 0_u64;
+        for _ in 0..samples {
+            let previous = phase;
+            advance_phase(&mut phase, phase_delta);
+            if phase < previous {
+                cycles += 1;
+            }
+        }
+        // Average frequency over the whole note from the cycles it got through
+        let measured = (cycles as f64 + phase) * sample_rate / samples as f64;
+        let cents = 1200.0 * (measured / frequency as f64).log2();
+        assert!(cents.abs() < 1.0, "drifted {} cents", cents);
+    }
+
+    // A sustaining voice, just enough to pick a voice to steal
+    fn test_voice(note: u8, amp_current: f32) -> SingleVoice {
+        SingleVoice {
+            note,
+            _velocity: 0.0,
+            vel_mod_amount: 0.0,
+            phase: 0.0,
+            phase_delta: 0.0,
+            state: OscState::Sustaining,
+            amp_current,
+            osc_attack: Smoother::new(SmoothingStyle::None),
+            osc_decay: Smoother::new(SmoothingStyle::None),
+            osc_release: Smoother::new(SmoothingStyle::None),
+            pitch_enabled: false,
+            pitch_env_peak: 0.0,
+            punch: 0.0,
+            wave_a: AudioModuleType::Sine,
+            wave_b: AudioModuleType::Saw,
+            humanize_detune: 0.0,
+            glide_offset: 0.0,
+            pitch_current: 0.0,
+            pitch_state: OscState::Off,
+            pitch_attack: Smoother::new(SmoothingStyle::None),
+            pitch_decay: Smoother::new(SmoothingStyle::None),
+            pitch_release: Smoother::new(SmoothingStyle::None),
+            pitch_enabled_2: false,
+            pitch_env_peak_2: 0.0,
+            pitch_current_2: 0.0,
+            pitch_state_2: OscState::Off,
+            pitch_attack_2: Smoother::new(SmoothingStyle::None),
+            pitch_decay_2: Smoother::new(SmoothingStyle::None),
+            pitch_release_2: Smoother::new(SmoothingStyle::None),
+            _detune: 0.0,
+            _unison_detune_value: 0.0,
+            frequency: 0.0,
+            _attack_time: 0.0,
+            _decay_time: 0.0,
+            _release_time: 0.0,
+            _retrigger: RetriggerStyle::Free,
+            _voice_type: AudioModuleType::Sine,
+            _angle: 0.0,
+            sample_pos: 0,
+            loop_it: false,
+            warmth_state: 0.0,
+            sub_phase: 0.0,
+            stretch: None,
+            grain_start_pos: 0,
+            _granular_gap: 200,
+            _granular_hold: 200,
+            granular_hold_end: 200,
+            next_grain_pos: 400,
+            _end_position: 800,
+            _granular_crossfade: 50,
+            grain_attack: Smoother::new(SmoothingStyle::Linear(5.0)),
+            grain_release: Smoother::new(SmoothingStyle::Linear(5.0)),
+            grain_state: GrainState::Attacking,
+            // Additive
+            harmonic_phases: vec![0.0; 16],
+            //// Polyfilter update!!
+            ///////////////////////////////////////////////////////
+            filter_l_1: StateVariableFilter::default().set_oversample(4),
+            filter_r_1: StateVariableFilter::default().set_oversample(4),
+            // TILT Filters
+            tilt_filter_l_1: TiltFilterStruct::new(
+                44100.0,
+                20000.0,
+                TiltFilter::ResponseType::Lowpass
+            ),
+            tilt_filter_r_1: TiltFilterStruct::new(
+                44100.0,
+                20000.0,
+                TiltFilter::ResponseType::Lowpass
+            ),
+            // VCF Filters
+            vcf_filter_l_1: VCFilter::new(),
+            vcf_filter_r_1: VCFilter::new(),
+            // Filter state variables
+            filter_state_1: OscState::Attacking,
+            filter_atk_smoother_1: Smoother::new(SmoothingStyle::Linear(300.0)),
+            filter_dec_smoother_1: Smoother::new(SmoothingStyle::Linear(300.0)),
+            filter_rel_smoother_1: Smoother::new(SmoothingStyle::Linear(300.0)),
+            // SVF Filters
+            filter_l_2: StateVariableFilter::default().set_oversample(4),
+            filter_r_2: StateVariableFilter::default().set_oversample(4),
+            // TILT Filters
+            tilt_filter_l_2: TiltFilterStruct::new(
+                44100.0,
+                20000.0,
+                TiltFilter::ResponseType::Lowpass
+            ),
+            tilt_filter_r_2: TiltFilterStruct::new(
+                44100.0,
+                20000.0,
+                TiltFilter::ResponseType::Lowpass
+            ),
+            // VCF Filters
+            vcf_filter_l_2: VCFilter::new(),
+            vcf_filter_r_2: VCFilter::new(),
+            // Filter state variables
+            filter_state_2: OscState::Attacking,
+            filter_atk_smoother_2: Smoother::new(SmoothingStyle::Linear(300.0)),
+            filter_dec_smoother_2: Smoother::new(SmoothingStyle::Linear(300.0)),
+            filter_rel_smoother_2: Smoother::new(SmoothingStyle::Linear(300.0)),
+            drive_level_1: DriveLevel::default(),
+            drive_level_2: DriveLevel::default(),
+            // V4 Filter
+            V4F_l_1: V4FilterStruct::default(),
+            V4F_l_2: V4FilterStruct::default(),
+            V4F_r_1: V4FilterStruct::default(),
+            V4F_r_2: V4FilterStruct::default(),
+            // A4I Filter
+            A4I_l_1: A4iFilter::new(44100.0, 20000.0, 0.0),
+            A4I_l_2: A4iFilter::new(44100.0, 20000.0, 0.0),
+            A4I_r_1: A4iFilter::new(44100.0, 20000.0, 0.0),
+            A4I_r_2: A4iFilter::new(44100.0, 20000.0, 0.0),
+            // A4II Filter
+            A4II_l_1: A4iiFilter::new(20000.0, 44100.0, 0.0),
+            A4II_l_2: A4iiFilter::new(20000.0, 44100.0, 0.0),
+            A4II_r_1: A4iiFilter::new(20000.0, 44100.0, 0.0),
+            A4II_r_2: A4iiFilter::new(20000.0, 44100.0, 0.0),
+            cutoff_modulation: 0.0,
+            cutoff_modulation_2: 0.0,
+            resonance_modulation: 0.0,
+            resonance_modulation_2: 0.0,
+
+            internal_unison_voices: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn steal_voice_index_oldest() {
+        let voices = VecDeque::from(vec![test_voice(60, 0.5), test_voice(48, 0.2), test_voice(72, 0.9)]);
+        // Voices are pushed on the back so the front is the oldest
+        assert_eq!(steal_voice_index(&voices, VoiceStealMode::Oldest), Some(0));
+    }
+
+    #[test]
+    fn steal_voice_index_quietest() {
+        let voices = VecDeque::from(vec![test_voice(60, 0.5), test_voice(48, 0.9), test_voice(72, 0.2)]);
+        assert_eq!(steal_voice_index(&voices, VoiceStealMode::Quietest), Some(2));
+    }
+
+    #[test]
+    fn steal_voice_index_lowest() {
+        let voices = VecDeque::from(vec![test_voice(60, 0.5), test_voice(48, 0.2), test_voice(72, 0.9)]);
+        assert_eq!(steal_voice_index(&voices, VoiceStealMode::Lowest), Some(1));
+    }
+
+    #[test]
+    fn steal_voice_index_highest() {
+        let voices = VecDeque::from(vec![test_voice(60, 0.5), test_voice(72, 0.2), test_voice(48, 0.9)]);
+        assert_eq!(steal_voice_index(&voices, VoiceStealMode::Highest), Some(1));
+    }
+
+    #[test]
+    fn steal_voice_index_empty() {
+        let voices: VecDeque<SingleVoice> = VecDeque::new();
+        for mode in [VoiceStealMode::Oldest, VoiceStealMode::Quietest, VoiceStealMode::Lowest, VoiceStealMode::Highest] {
+            assert_eq!(steal_voice_index(&voices, mode), None);
+        }
+    }
+
+    // Cents from 12-TET of each step above the root, worked out from the ratios rather than copied from the tables
+    fn ratio_cents(ratios: [(f64, f64); 12]) -> [f64; 12] {
+        let mut cents = [0.0; 12];
+        for (step, (num, den)) in ratios.iter().enumerate() {
+            cents[step] = 1200.0 * (num / den).log2() - 100.0 * step as f64;
+        }
+        cents
+    }
+
+    // Same for tunings built from a chain of fifths, fifths is where each step sits in the chain
+    fn fifth_chain_cents(fifth: f64, fifths: [i32; 12]) -> [f64; 12] {
+        let mut cents = [0.0; 12];
+        for (step, count) in fifths.iter().enumerate() {
+            cents[step] = (fifth * *count as f64).rem_euclid(1200.0) - 100.0 * step as f64;
+        }
+        cents
+    }
+
+    fn reference_cents(system: TuningSystem) -> [f64; 12] {
+        match system {
+            TuningSystem::TwelveTET => [0.0; 12],
+            TuningSystem::JustMajor => ratio_cents([
+                (1.0, 1.0), (16.0, 15.0), (9.0, 8.0), (6.0, 5.0), (5.0, 4.0), (4.0, 3.0),
+                (45.0, 32.0), (3.0, 2.0), (8.0, 5.0), (5.0, 3.0), (9.0, 5.0), (15.0, 8.0),
+            ]),
+            TuningSystem::JustMinor => ratio_cents([
+                (1.0, 1.0), (16.0, 15.0), (10.0, 9.0), (6.0, 5.0), (5.0, 4.0), (4.0, 3.0),
+                (64.0, 45.0), (3.0, 2.0), (8.0, 5.0), (5.0, 3.0), (9.0, 5.0), (15.0, 8.0),
+            ]),
+            // Pure 3/2 fifths from Db to B
+            TuningSystem::Pythagorean => fifth_chain_cents(
+                1200.0 * 1.5_f64.log2(),
+                [0, -5, 2, -3, 4, -1, 6, 1, -4, 3, -2, 5],
+            ),
+            // Fifths narrowed by a quarter syntonic comma from Eb to G#
+            TuningSystem::QuarterComma => fifth_chain_cents(
+                1200.0 * 5.0_f64.powf(0.25).log2(),
+                [0, 7, 2, -3, 4, -1, 6, 1, 8, 3, -2, 5],
+            ),
+        }
+    }
+
+    const TUNING_SYSTEMS: [TuningSystem; 5] = [
+        TuningSystem::TwelveTET,
+        TuningSystem::JustMajor,
+        TuningSystem::JustMinor,
+        TuningSystem::Pythagorean,
+        TuningSystem::QuarterComma,
+    ];
+
+    #[test]
+    fn tuning_offset_matches_reference_tables() {
+        for system in TUNING_SYSTEMS {
+            let reference = reference_cents(system);
+            // Root on C
+            for note in 0..128_u8 {
+                let cents = tuning_offset(note, system, 0) as f64 * 100.0;
+                assert!((cents - reference[note as usize % 12]).abs() < 0.01, "note {} off by {} cents", note, cents - reference[note as usize % 12]);
+            }
+        }
+    }
+
+    #[test]
+    fn tuning_offset_follows_the_root() {
+        for system in TUNING_SYSTEMS {
+            let reference = reference_cents(system);
+            for root in 0..12 {
+                // The root itself is always in tune
+                assert_eq!(tuning_offset(60 + root as u8, system, root), 0.0);
+                for note in 0..128_u8 {
+                    let step = (note as i32 - root).rem_euclid(12) as usize;
+                    let cents = tuning_offset(note, system, root) as f64 * 100.0;
+                    assert!((cents - reference[step]).abs() < 0.01);
+                }
+            }
+        }
+        // Moving the root to D puts the just major third on F#
+        assert!((tuning_offset(66, TuningSystem::JustMajor, 2) * 100.0 + 13.69).abs() < 0.01);
+        assert_eq!(tuning_offset(64, TuningSystem::JustMajor, 2), tuning_offset(62, TuningSystem::JustMajor, 0));
+    }
+
+    #[test]
+    fn env_keyscale_factor_off_at_zero_amount() {
+        for note in 0..128_u8 {
+            assert_eq!(env_keyscale_factor(note, 0.0), 1.0);
+        }
+    }
+
+    #[test]
+    fn env_keyscale_factor_is_monotonic() {
+        // Higher notes never get longer times, the clamp just flattens the ends
+        for amount in [0.25, 0.5, 1.0] {
+            let mut previous = env_keyscale_factor(0, amount);
+            for note in 1..128_u8 {
+                let factor = env_keyscale_factor(note, amount);
+                assert!(factor <= previous, "factor rose at note {}", note);
+                previous = factor;
+            }
+        }
+    }
+
+    #[test]
+    fn env_keyscale_factor_reference_note() {
+        // C4 is the pivot so its times are untouched at any amount
+        for amount in [0.0, 0.5, 1.0] {
+            assert_eq!(env_keyscale_factor(60, amount), 1.0);
+        }
+        // Full amount halves an octave up and doubles an octave down
+        assert!((env_keyscale_factor(72, 1.0) - 0.5).abs() < 1e-6);
+        assert!((env_keyscale_factor(48, 1.0) - 2.0).abs() < 1e-6);
+        // Far ends stop at the clamp
+        assert_eq!(env_keyscale_factor(127, 1.0), 0.125);
+        assert_eq!(env_keyscale_factor(0, 1.0), 8.0);
+    }
+
+    #[test]
+    fn drive_stage_keeps_quiet_and_loud_levels() {
+        // Full drive only adds harmonics, the RMS makeup holds the level from a whisper to full scale
+        let sample_rate = 48000.0;
+        for amplitude in [0.01, 0.1, 0.5, 1.0] {
+            let mut level = DriveLevel::default();
+            let (mut input_power, mut output_power) = (0.0, 0.0);
+            for i in 0..sample_rate as usize {
+                let input = amplitude * (std::f32::consts::TAU * 220.0 * i as f32 / sample_rate).sin();
+                let (output, _) = drive_stage(input, input, 1.0, &mut level, sample_rate);
+                // Skip the first half second while the followers settle
+                if i > sample_rate as usize / 2 {
+                    input_power += input * input;
+                    output_power += output * output;
+                }
+            }
+            let gain_db = 10.0 * (output_power / input_power).log10();
+            assert!(gain_db.abs() < 0.5, "amplitude {} came out {} dB", amplitude, gain_db);
+        }
+    }
+}
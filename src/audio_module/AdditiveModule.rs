@@ -34,14 +34,14 @@ impl AdditiveOscillator {
         self.harmonics = harmonics;
     }
 
-    pub fn next_sample(&mut self, voice: &mut SingleVoice, sample_rate: f32, detune_mod: f32) -> f32 {
+    pub fn next_sample(&mut self, voice: &mut SingleVoice, sample_rate: f32, detune_mod: f32, detune_hz: f32) -> f32 {
         let mut sample = 0.0;
         let nyquist = sample_rate / 2.0;
         
         if voice.amp_current != 0.0 {
             let base_note = voice.note as f32 + voice._detune + detune_mod + voice.pitch_current + voice.pitch_current_2;
-            let instant_frequency = util::f32_midi_note_to_freq(base_note).min(nyquist);
-            voice.phase_delta = instant_frequency / sample_rate;
+            let instant_frequency = (util::f32_midi_note_to_freq(base_note) + detune_hz).clamp(0.0, nyquist);
+            voice.phase_delta = instant_frequency as f64 / sample_rate as f64;
 
             for (i, harmonic) in self.harmonics.iter_mut().enumerate() {
                 if harmonic.amplitude != 0.0 {
@@ -60,14 +60,14 @@ impl AdditiveOscillator {
         sample
     }
 
-    pub fn next_unison_sample(&mut self, voice: &mut SingleUnisonVoice, sample_rate: f32, detune_mod: f32) -> f32 {
+    pub fn next_unison_sample(&mut self, voice: &mut SingleUnisonVoice, sample_rate: f32, detune_mod: f32, detune_hz: f32) -> f32 {
         let mut sample = 0.0;
         let nyquist = sample_rate / 2.0;
         
         if voice.amp_current != 0.0 {
             let base_note = voice.note as f32 + voice._unison_detune_value + detune_mod + voice.pitch_current + voice.pitch_current_2;
-            let instant_frequency = util::f32_midi_note_to_freq(base_note).min(nyquist);
-            voice.phase_delta = instant_frequency / sample_rate;
+            let instant_frequency = (util::f32_midi_note_to_freq(base_note) + detune_hz).clamp(0.0, nyquist);
+            voice.phase_delta = instant_frequency as f64 / sample_rate as f64;
 
             for (i, harmonic) in self.harmonics.iter_mut().enumerate() {
                 if harmonic.amplitude != 0.0 {
@@ -108,7 +108,7 @@ impl AdditiveOscillator {
                 }
                 let instant_frequency = util::f32_midi_note_to_freq(base_note).min(nyquist);
 
-                voice.phase_delta = instant_frequency / sample_rate;
+                voice.phase_delta = instant_frequency as f64 / sample_rate as f64;
 
                 let harmonic_freq = if harmonic.index == 0 {
                     instant_frequency
@@ -518,6 +518,80 @@ pub enum RetriggerStyle {
     MRandom,
 }
 
+// Oscillator anti-aliasing quality
+// Draft: no sine interpolation, cheapest per voice for big live chords
+// Normal: the table lookups as they've always been
+// High: PolyBLEP on the hard edges of saw/ramp/square/pulse - roughly 2 extra branches per voice per sample
+#[derive(Enum, PartialEq, Eq, Debug, Copy, Clone, Deserialize, Serialize)]
+pub enum OscQuality {
+    Draft,
+    Normal,
+    High,
+}
+
+// Waveshaper on each oscillator voice before the envelope
+// SoftClip: tanh saturation, Wavefold: triangle folding for West-Coast tones, Rectify: blends toward full-wave rectified for octave-up content
+#[derive(Enum, PartialEq, Eq, Debug, Copy, Clone, Deserialize, Serialize)]
+pub enum OscShape {
+    Off,
+    SoftClip,
+    Wavefold,
+    Rectify,
+}
+
+// JP-8000 style supersaw detune curve sampled at 33 points across 0-1
+// Slow and even through most of the range then it jumps hard at the top like the hardware
+const SUPER_DETUNE_TABLE: [f32; 33] = [
+    0.0030, 0.0099, 0.0104, 0.0152, 0.0229, 0.0304, 0.0366, 0.0418,
+    0.0469, 0.0529, 0.0596, 0.0667, 0.0734, 0.0795, 0.0850, 0.0908,
+    0.0980, 0.1074, 0.1198, 0.1352, 0.1531, 0.1729, 0.1943, 0.2173,
+    0.2425, 0.2697, 0.2977, 0.3232, 0.3423, 0.3573, 0.3943, 0.5385,
+    1.0000,
+];
+
+// Lerped lookup of the supersaw detune curve, returns a 0-1 unison detune amount
+pub fn super_detune_curve(amount: f32) -> f32 {
+    let position = amount.clamp(0.0, 1.0) * (SUPER_DETUNE_TABLE.len() - 1) as f32;
+    let index = (position as usize).min(SUPER_DETUNE_TABLE.len() - 2);
+    let frac = position - index as f32;
+    SUPER_DETUNE_TABLE[index] + (SUPER_DETUNE_TABLE[index + 1] - SUPER_DETUNE_TABLE[index]) * frac
+}
+
+// Unison voice count for the super macro - 1 voice at 0 up to 7 at 1
+pub fn super_voice_count(amount: f32) -> i32 {
+    1 + (amount.clamp(0.0, 1.0) * 6.0).round() as i32
+}
+
+// Run one oscillator sample through the shaper, amount is 0-1
+pub fn shape_sample(sample: f32, shape: OscShape, amount: f32) -> f32 {
+    match shape {
+        OscShape::Off => sample,
+        OscShape::SoftClip => {
+            let drive = 1.0 + amount * 9.0;
+            (sample * drive).tanh() / drive.tanh()
+        }
+        OscShape::Wavefold => {
+            // Folds back every time the signal passes +/-1, more gain means more folds
+            let folded = sample * (1.0 + amount * 4.0);
+            1.0 - ((folded + 1.0).rem_euclid(4.0) - 2.0).abs()
+        }
+        OscShape::Rectify => sample + (sample.abs() - sample) * amount,
+    }
+}
+
+// One-pole lowpass that sits a few harmonics above the note so brighter notes keep their top end
+// phase_delta is the note frequency over the sample rate, amount 0 bypasses
+pub fn warmth_lowpass(state: &mut f32, input: f32, amount: f32, phase_delta: f32) -> f32 {
+    if amount <= 0.0 {
+        return input;
+    }
+    // 64 harmonics up at the lightest setting down to 2 at full warmth
+    let cutoff = (phase_delta * 2.0_f32.powf(6.0 - amount * 5.0)).min(0.45);
+    let coefficient = 1.0 - (-std::f32::consts::TAU * cutoff).exp();
+    *state += (input - *state) * coefficient;
+    *state
+}
+
 // Super useful function to scale an input 0-1 into other ranges
 /*
 pub(crate) fn scale_range(input: f32, min_output: f32, max_output: f32) -> f32 {
@@ -540,6 +614,42 @@ pub fn get_sine(phase: f32) -> f32 {
     sine
 }
 
+// Sine without the lerp for Draft quality
+pub fn get_sine_draft(phase: f32) -> f32 {
+    let index = (phase * (TABLE_SIZE - 1) as f32) as usize;
+    SIN_TABLE[index.min(TABLE_SIZE - 1)]
+}
+
+// PolyBLEP residual for a step at phase 0, dt is the phase increment per sample
+pub fn poly_blep(t: f32, dt: f32) -> f32 {
+    if dt <= 0.0 {
+        0.0
+    } else if t < dt {
+        let t = t / dt;
+        t + t - t * t - 1.0
+    } else if t > 1.0 - dt {
+        let t = (t - 1.0) / dt;
+        t * t + t + t + 1.0
+    } else {
+        0.0
+    }
+}
+
+// Saw drops by 2 when it wraps
+pub fn saw_blep(phase: f32, dt: f32) -> f32 {
+    -poly_blep(phase, dt)
+}
+
+// Ramp jumps up by 2 when it wraps
+pub fn ramp_blep(phase: f32, dt: f32) -> f32 {
+    poly_blep(phase, dt)
+}
+
+// Square/pulse jump up at the wrap and back down at the width point
+pub fn pulse_blep(phase: f32, dt: f32, width: f32) -> f32 {
+    poly_blep(phase, dt) - poly_blep((phase + 1.0 - width) % 1.0, dt)
+}
+
 // Rounded Saw Wave with rounding amount
 pub fn get_rsaw(phase: f32) -> f32 {
     let index = (phase * (TABLE_SIZE - 1) as f32) as usize;
@@ -656,3 +766,42 @@ impl DeterministicWhiteNoiseGenerator {
         x
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx(a: f32, b: f32) -> bool {
+        (a - b).abs() < 1e-6
+    }
+
+    #[test]
+    fn super_detune_curve_endpoints() {
+        assert!(approx(super_detune_curve(0.0), 0.0030));
+        assert!(approx(super_detune_curve(1.0), 1.0));
+        // Out of range amounts clamp to the ends
+        assert!(approx(super_detune_curve(-1.0), 0.0030));
+        assert!(approx(super_detune_curve(2.0), 1.0));
+    }
+
+    #[test]
+    fn super_detune_curve_is_monotonic() {
+        let mut previous = super_detune_curve(0.0);
+        for step in 1..=1000 {
+            let value = super_detune_curve(step as f32 / 1000.0);
+            assert!(value >= previous, "curve fell at {}", step as f32 / 1000.0);
+            previous = value;
+        }
+    }
+
+    #[test]
+    fn super_detune_curve_table_values() {
+        // Table points sit every 1/32
+        assert!(approx(super_detune_curve(8.0 / 32.0), 0.0469));
+        assert!(approx(super_detune_curve(16.0 / 32.0), 0.0980));
+        assert!(approx(super_detune_curve(24.0 / 32.0), 0.2425));
+        assert!(approx(super_detune_curve(31.0 / 32.0), 0.5385));
+        // Halfway between the first two points
+        assert!(approx(super_detune_curve(1.0 / 64.0), (0.0030 + 0.0099) / 2.0));
+    }
+}
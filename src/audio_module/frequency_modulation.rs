@@ -24,4 +24,12 @@ pub fn triple_modulation(modulating_sample: f32, carrier_sample: f32, modulation
     let second_fm_sample = frequency_modulation(first_fm_sample, carrier_sample, modulation_index);
     let third_fm_sample = frequency_modulation(second_fm_sample, carrier_sample, modulation_index);
     third_fm_sample
+}
+
+// Amplitude ring modulation - blend 0 returns the carrier, 1 is the pure product
+pub fn ring_modulation(modulating_sample: f32, carrier_sample: f32, blend: f32) -> f32 {
+    if blend == 0.0 {
+        return carrier_sample;
+    }
+    carrier_sample * (1.0 - blend) + carrier_sample * modulating_sample * blend
 }
\ No newline at end of file
@@ -0,0 +1,58 @@
+// Output Capture by Ardura
+// Keeps the last few seconds of the master output around so Freeze can resample it into a Sampler or Granulizer
+// - Written every sample on the audio thread, read from the GUI when Freeze is pressed
+// - The buffer is sized once for the longest Freeze Time so recording never allocates
+
+#[derive(Clone)]
+pub struct OutputCapture {
+    buffer_l: Vec<f32>,
+    buffer_r: Vec<f32>,
+    write_index: usize,
+    // How much of the buffer holds real output, so a fresh capture doesn't start with silence
+    filled: usize,
+    sample_rate: f32,
+}
+
+impl OutputCapture {
+    pub fn new() -> Self {
+        OutputCapture {
+            buffer_l: vec![0.0; 1],
+            buffer_r: vec![0.0; 1],
+            write_index: 0,
+            filled: 0,
+            sample_rate: 44100.0,
+        }
+    }
+
+    pub fn set_max_length(&mut self, max_seconds: f32, sample_rate: f32) {
+        let length = (max_seconds * sample_rate).ceil() as usize;
+        if self.buffer_l.len() != length {
+            self.buffer_l = vec![0.0; length];
+            self.buffer_r = vec![0.0; length];
+            self.write_index = 0;
+            self.filled = 0;
+        }
+        self.sample_rate = sample_rate;
+    }
+
+    pub fn push(&mut self, input_l: f32, input_r: f32) {
+        self.buffer_l[self.write_index] = input_l;
+        self.buffer_r[self.write_index] = input_r;
+        self.write_index = (self.write_index + 1) % self.buffer_l.len();
+        self.filled = (self.filled + 1).min(self.buffer_l.len());
+    }
+
+    // The last seconds of output oldest first, in the same channel layout a loaded file uses
+    pub fn snapshot(&self, seconds: f32) -> Vec<Vec<f32>> {
+        let length = self.buffer_l.len();
+        let count = ((seconds * self.sample_rate) as usize).min(self.filled);
+        let start = (self.write_index + length - count) % length;
+        let mut left = Vec::with_capacity(count);
+        let mut right = Vec::with_capacity(count);
+        for i in 0..count {
+            left.push(self.buffer_l[(start + i) % length]);
+            right.push(self.buffer_r[(start + i) % length]);
+        }
+        vec![left, right]
+    }
+}
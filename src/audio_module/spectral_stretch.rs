@@ -0,0 +1,227 @@
+// Spectral Stretch by Ardura
+// Paulstretch style extreme time-stretch for the Sampler
+// - Magnitude frames are analyzed once when a sample loads so playback only needs an inverse FFT per hop
+// - Every output frame gets random phases which smears the sound into a smooth drone
+// - Pitch follows the played note by moving magnitudes between bins instead of resampling
+
+use num_complex::Complex32;
+use std::f32::consts::PI;
+
+// Frame size for analysis and resynthesis, bigger frames give smoother drones
+const FRAME_SIZE: usize = 2048;
+const HALF_FRAME: usize = FRAME_SIZE / 2;
+// Distance between analyzed frames in source samples
+const ANALYSIS_HOP: usize = FRAME_SIZE / 4;
+// Output moves half a frame at a time with a sine window so random phase frames sum to a steady level
+const SYNTH_HOP: usize = FRAME_SIZE / 2;
+// Makes up for the level lost to the Hann analysis window: 1 / sqrt(3/8)
+const WINDOW_GAIN: f32 = 1.6329932;
+
+// Precomputed spectra of a loaded sample
+#[derive(Clone)]
+pub struct SpectralFrames {
+    frames_l: Vec<Vec<f32>>,
+    frames_r: Vec<Vec<f32>>,
+    twiddles: Vec<Complex32>,
+    synth_window: Vec<f32>,
+    sample_len: usize,
+}
+
+impl SpectralFrames {
+    pub fn new() -> Self {
+        SpectralFrames {
+            frames_l: Vec::new(),
+            frames_r: Vec::new(),
+            twiddles: Vec::new(),
+            synth_window: Vec::new(),
+            sample_len: 0,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.frames_l.is_empty()
+    }
+
+    pub fn sample_len(&self) -> usize {
+        self.sample_len
+    }
+
+    // Run the analysis over a loaded sample, mono samples get copied to both sides
+    pub fn analyze(sample: &[Vec<f32>]) -> Self {
+        let mut frames = SpectralFrames::new();
+        if sample.is_empty() || sample[0].len() <= 1 {
+            return frames;
+        }
+        frames.twiddles = (0..HALF_FRAME)
+            .map(|i| Complex32::from_polar(1.0, -2.0 * PI * i as f32 / FRAME_SIZE as f32))
+            .collect();
+        frames.synth_window = (0..FRAME_SIZE)
+            .map(|i| (PI * (i as f32 + 0.5) / FRAME_SIZE as f32).sin())
+            .collect();
+        let analysis_window: Vec<f32> = frames.synth_window.iter().map(|w| w * w).collect();
+
+        let left = &sample[0];
+        let right = if sample.len() > 1 { &sample[1] } else { &sample[0] };
+        frames.sample_len = left.len();
+        let mut buffer = vec![Complex32::new(0.0, 0.0); FRAME_SIZE];
+        let mut start = 0;
+        while start < left.len() {
+            for (channel, target) in [(left, &mut frames.frames_l), (right, &mut frames.frames_r)] {
+                for i in 0..FRAME_SIZE {
+                    let input = channel.get(start + i).copied().unwrap_or(0.0);
+                    buffer[i] = Complex32::new(input * analysis_window[i], 0.0);
+                }
+                fft_in_place(&mut buffer, &frames.twiddles, false);
+                target.push(buffer[..HALF_FRAME].iter().map(|bin| bin.norm()).collect());
+            }
+            start += ANALYSIS_HOP;
+        }
+        frames
+    }
+}
+
+// Per voice playback state
+#[derive(Clone)]
+pub struct StretchPlayhead {
+    // Read position in source samples
+    position: f32,
+    out_l: Vec<f32>,
+    out_r: Vec<f32>,
+    read: usize,
+    scratch_l: Vec<Complex32>,
+    scratch_r: Vec<Complex32>,
+    rng_state: u32,
+}
+
+impl StretchPlayhead {
+    pub fn new(start_position: usize, seed: u32) -> Self {
+        StretchPlayhead {
+            position: start_position as f32,
+            out_l: vec![0.0; FRAME_SIZE],
+            out_r: vec![0.0; FRAME_SIZE],
+            // Start at the end of a hop so the first sample synthesizes a frame
+            read: SYNTH_HOP,
+            scratch_l: vec![Complex32::new(0.0, 0.0); FRAME_SIZE],
+            scratch_r: vec![Complex32::new(0.0, 0.0); FRAME_SIZE],
+            rng_state: seed.max(1),
+        }
+    }
+
+    // Rewinds a pooled playhead for a new note, the buffers are reused so nothing allocates
+    pub fn reset(&mut self, start_position: usize, seed: u32) {
+        self.position = start_position as f32;
+        self.out_l.fill(0.0);
+        self.out_r.fill(0.0);
+        self.read = SYNTH_HOP;
+        self.rng_state = seed.max(1);
+    }
+
+    // Returns None once a non looping playhead passes the end position
+    pub fn next(
+        &mut self,
+        frames: &SpectralFrames,
+        pitch_ratio: f32,
+        stretch_factor: f32,
+        start_position: usize,
+        end_position: usize,
+        loop_it: bool,
+    ) -> Option<(f32, f32)> {
+        if self.read >= SYNTH_HOP {
+            if self.position >= end_position as f32 {
+                if loop_it {
+                    self.position = start_position as f32;
+                } else {
+                    return None;
+                }
+            }
+            // Slide the overlap buffer along and add in the next frame
+            self.out_l.copy_within(SYNTH_HOP.., 0);
+            self.out_r.copy_within(SYNTH_HOP.., 0);
+            self.out_l[FRAME_SIZE - SYNTH_HOP..].fill(0.0);
+            self.out_r[FRAME_SIZE - SYNTH_HOP..].fill(0.0);
+            self.synthesize(frames, pitch_ratio);
+            self.position += SYNTH_HOP as f32 / stretch_factor.max(1.0);
+            self.read = 0;
+        }
+        let output = (self.out_l[self.read], self.out_r[self.read]);
+        self.read += 1;
+        Some(output)
+    }
+
+    fn synthesize(&mut self, frames: &SpectralFrames, pitch_ratio: f32) {
+        let frame_position = self.position / ANALYSIS_HOP as f32;
+        let index = (frame_position.floor() as usize).min(frames.frames_l.len() - 1);
+        let next_index = (index + 1).min(frames.frames_l.len() - 1);
+        let fraction = (frame_position - index as f32).clamp(0.0, 1.0);
+
+        self.scratch_l.fill(Complex32::new(0.0, 0.0));
+        self.scratch_r.fill(Complex32::new(0.0, 0.0));
+        for bin in 1..HALF_FRAME {
+            let target = (bin as f32 * pitch_ratio).round() as usize;
+            if target == 0 || target >= HALF_FRAME {
+                continue;
+            }
+            let magnitude_l = frames.frames_l[index][bin] * (1.0 - fraction)
+                + frames.frames_l[next_index][bin] * fraction;
+            let magnitude_r = frames.frames_r[index][bin] * (1.0 - fraction)
+                + frames.frames_r[next_index][bin] * fraction;
+            // Same phase on both sides so a mono sample stays centered
+            let phase = Complex32::from_polar(1.0, self.random_phase());
+            self.scratch_l[target] += phase * magnitude_l;
+            self.scratch_r[target] += phase * magnitude_r;
+        }
+        // Mirror the spectrum so the inverse transform comes out real
+        for bin in 1..HALF_FRAME {
+            self.scratch_l[FRAME_SIZE - bin] = self.scratch_l[bin].conj();
+            self.scratch_r[FRAME_SIZE - bin] = self.scratch_r[bin].conj();
+        }
+        fft_in_place(&mut self.scratch_l, &frames.twiddles, true);
+        fft_in_place(&mut self.scratch_r, &frames.twiddles, true);
+
+        let scale = WINDOW_GAIN / FRAME_SIZE as f32;
+        for i in 0..FRAME_SIZE {
+            let window = frames.synth_window[i] * scale;
+            self.out_l[i] += self.scratch_l[i].re * window;
+            self.out_r[i] += self.scratch_r[i].re * window;
+        }
+    }
+
+    // Xorshift so the audio thread doesn't need a thread_rng
+    fn random_phase(&mut self) -> f32 {
+        self.rng_state ^= self.rng_state << 13;
+        self.rng_state ^= self.rng_state >> 17;
+        self.rng_state ^= self.rng_state << 5;
+        (self.rng_state as f32 / u32::MAX as f32) * 2.0 * PI
+    }
+}
+
+// Iterative radix-2 FFT, the inverse is left unscaled
+fn fft_in_place(buffer: &mut [Complex32], twiddles: &[Complex32], inverse: bool) {
+    let size = buffer.len();
+    let bits = size.trailing_zeros();
+    for i in 0..size {
+        let j = i.reverse_bits() >> (usize::BITS - bits);
+        if j > i {
+            buffer.swap(i, j);
+        }
+    }
+    let mut length = 2;
+    while length <= size {
+        let half = length / 2;
+        let step = size / length;
+        for start in (0..size).step_by(length) {
+            for k in 0..half {
+                let twiddle = if inverse {
+                    twiddles[k * step].conj()
+                } else {
+                    twiddles[k * step]
+                };
+                let odd = twiddle * buffer[start + k + half];
+                let even = buffer[start + k];
+                buffer[start + k] = even + odd;
+                buffer[start + k + half] = even - odd;
+            }
+        }
+        length *= 2;
+    }
+}
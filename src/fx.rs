@@ -6,8 +6,16 @@ pub(crate) mod StateVariableFilter;
 pub(crate) mod VCFilter;
 pub(crate) mod abass;
 pub(crate) mod biquad_filters;
+pub(crate) mod bypass_ramp;
 pub(crate) mod buffermodulator;
 pub(crate) mod compressor;
+pub(crate) mod transient_shaper;
+pub(crate) mod trance_gate;
+pub(crate) mod looper;
+pub(crate) mod resonator_bank;
+pub(crate) mod tape_stop;
+pub(crate) mod dither;
+pub(crate) mod oversampler;
 pub(crate) mod delay;
 pub(crate) mod flanger;
 pub(crate) mod limiter;
@@ -17,3 +25,71 @@ pub(crate) mod aw_galactic_reverb;
 pub(crate) mod simple_space_reverb;
 pub(crate) mod saturation;
 pub(crate) mod chorus;
+
+// Feedback state that decays below this is flushed to zero so long tails can't sink into denormals and spike the CPU
+pub(crate) fn remove_denormals(x: f32) -> f32 {
+    if x.abs() < 1e-30 {
+        0.0
+    } else {
+        x
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        aw_galactic_reverb::GalacticReverb,
+        biquad_filters::{Biquad, FilterType},
+        delay::{Delay, DelaySnapValues},
+        reverb::StereoReverb,
+        simple_space_reverb::SimpleSpaceReverb,
+        StateVariableFilter::{ResonanceType, StateVariableFilter},
+    };
+
+    const SAMPLE_RATE: f32 = 44100.0;
+
+    // Feeds an impulse then silence through an effect. The feedback state only shows through the output,
+    // so it must never come out subnormal and has to end up exactly silent instead of creeping toward zero
+    fn assert_tail_flushes(name: &str, seconds: f32, mut process: impl FnMut(f32) -> (f32, f32)) {
+        let samples = (seconds * SAMPLE_RATE) as usize;
+        let mut output = (0.0, 0.0);
+        for i in 0..samples {
+            output = process(if i == 0 { 1.0 } else { 0.0 });
+            assert!(
+                !output.0.is_subnormal() && !output.1.is_subnormal(),
+                "{} output went subnormal at sample {}",
+                name,
+                i
+            );
+        }
+        assert_eq!(output, (0.0, 0.0), "{} tail never flushed to zero", name);
+    }
+
+    #[test]
+    fn reverb_tails_flush_denormals() {
+        let mut tdl = StereoReverb::new(SAMPLE_RATE, 0.05, 0.8);
+        assert_tail_flushes("TDL reverb", 30.0, |x| tdl.process_tdl(x, x, 1.0, 1.0));
+        let mut space = SimpleSpaceReverb::new(SAMPLE_RATE, 0.5, 0.8, 1.0);
+        assert_tail_flushes("Space reverb", 60.0, |x| space.process(x, x));
+        let mut galactic = GalacticReverb::new(SAMPLE_RATE, 0.5, 0.8, 1.0);
+        assert_tail_flushes("Galactic reverb", 60.0, |x| galactic.process(x, x));
+    }
+
+    #[test]
+    fn delay_tail_flushes_denormals() {
+        let mut delay = Delay::new(SAMPLE_RATE, 120.0, DelaySnapValues::Sixteen, 0.8);
+        assert_tail_flushes("Delay", 60.0, |x| delay.process(x, x, 1.0));
+    }
+
+    #[test]
+    fn filter_state_flushes_denormals() {
+        let mut biquad = Biquad::new(SAMPLE_RATE, 1000.0, 0.0, 0.707, FilterType::LowPass);
+        assert_tail_flushes("Biquad", 10.0, |x| biquad.process_sample(x, x));
+        let mut svf = StateVariableFilter::default().set_oversample(4);
+        svf.update(1000.0, 0.5, SAMPLE_RATE, ResonanceType::Default);
+        assert_tail_flushes("SVF", 10.0, |x| {
+            let (low, _, _) = svf.process(x);
+            (low, low)
+        });
+    }
+}
@@ -2,6 +2,7 @@
 // Ardura
 
 use std::f32::consts::PI;
+use crate::fx::remove_denormals;
 
 #[derive(Clone)]
 pub struct A4iiFilter {
@@ -53,6 +54,9 @@ impl A4iiFilter {
         self.integrators[2] += self.alpha * (self.integrators[1] - self.integrators[2] + feedback_signal);
         feedback_signal = self.integrators[2] * self.feedback; //Feedback from third stage
         self.integrators[3] += self.alpha * (self.integrators[2] - self.integrators[3] + feedback_signal);
+        for integrator in self.integrators.iter_mut() {
+            *integrator = remove_denormals(*integrator);
+        }
 
 
         // Average the outputs of the four integrators
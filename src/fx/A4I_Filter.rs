@@ -2,6 +2,7 @@
 // Ardura
 
 use nih_plug::util;
+use crate::fx::remove_denormals;
 
 // Define the filter structure
 #[derive(Clone)]
@@ -120,12 +121,12 @@ impl OnePoleLowPassFilter {
     pub fn process_w_res(&mut self, input: f32) -> f32 {
         let feedback = self.prev_output * self.resonance;
         let filtered_input = input - feedback;
-        self.prev_output = self.alpha * filtered_input + (1.0 - self.alpha) * self.prev_output;
+        self.prev_output = remove_denormals(self.alpha * filtered_input + (1.0 - self.alpha) * self.prev_output);
         self.prev_output
     }
 
     pub fn process(&mut self, input: f32) -> f32 {
-        self.prev_output = self.alpha * input + (1.0 - self.alpha) * self.prev_output;
+        self.prev_output = remove_denormals(self.alpha * input + (1.0 - self.alpha) * self.prev_output);
         self.prev_output
     }
 }
\ No newline at end of file
@@ -2,9 +2,18 @@ use nih_plug::prelude::Enum;
 use serde::{Deserialize, Serialize};
 use std::f32::consts::PI;
 
+use crate::fx::remove_denormals;
+
 // Modified implementation from https://www.musicdsp.org/en/latest/Filters/23-state-variable.html and some tweaks
 // Adapted to rust by Ardura
 
+// Largest per sample frequency coefficient a single pass stays stable with
+const ECO_MAX_COEFFICIENT: f32 = 1.0;
+// How far below zero the Sine damping goes at max Res, small so the clipper barely bends the sine
+const SINE_OVERSHOOT: f32 = 0.02;
+// Level the Sine self oscillation settles at
+const SINE_LEVEL: f32 = 0.5;
+
 #[derive(Enum, PartialEq, Eq, Clone, Serialize, Deserialize)]
 pub enum ResonanceType {
     // Allegedly the "ideal" response when tying Q to angular sin response
@@ -21,6 +30,10 @@ pub enum ResonanceType {
     Bump,
     // I made this up - Curve based on powf behavior
     Powf,
+    // Screaming resonance, most of the knob is spent near the top and the peak is driven into a clipper
+    Acid,
+    // Gentle musical resonance that self oscillates into a clean sine at max for playing the filter
+    Sine,
 }
 
 #[derive(Clone)]
@@ -36,6 +49,7 @@ pub struct StateVariableFilter {
     high_output: f32,
     res_mode: ResonanceType,
     oversample: i32,
+    eco: bool,
 }
 
 impl Default for StateVariableFilter {
@@ -52,6 +66,7 @@ impl Default for StateVariableFilter {
             high_output: 0.0,
             res_mode: ResonanceType::Default,
             oversample: 4,
+            eco: false,
         }
     }
 }
@@ -62,6 +77,11 @@ impl StateVariableFilter {
         self
     }
 
+    // Eco mode runs a single pass instead of oversampling
+    pub fn set_eco(&mut self, eco: bool) {
+        self.eco = eco;
+    }
+
     pub fn update(
         &mut self,
         frequency: f32,
@@ -81,7 +101,7 @@ impl StateVariableFilter {
                 ResonanceType::Moog | ResonanceType::TB | ResonanceType::Arp => {
                     self.q = q.clamp(0.0, 1.0);
                 }
-                ResonanceType::Res | ResonanceType::Powf => {
+                ResonanceType::Res | ResonanceType::Powf | ResonanceType::Acid | ResonanceType::Sine => {
                     self.q = q.clamp(0.0, 1.0);
                 }
             }
@@ -97,6 +117,10 @@ impl StateVariableFilter {
     }
 
     pub fn process(&mut self, input: f32) -> (f32, f32, f32) {
+        if matches!(self.res_mode, ResonanceType::Acid | ResonanceType::Sine) {
+            return self.process_saturating(input);
+        }
+
         // Calculate our normalized freq for filtering
         let normalized_freq: f32 = match self.res_mode {
             ResonanceType::Default => self.double_pi_freq / self.sample_rate_quad,
@@ -107,6 +131,23 @@ impl StateVariableFilter {
             ResonanceType::Res => self.double_pi_freq / self.sample_rate_half,
             ResonanceType::Bump => self.double_pi_freq / self.sample_rate_quad,
             ResonanceType::Powf => self.double_pi_freq / self.sample_rate_quad,
+            // Handled in process_saturating
+            ResonanceType::Acid | ResonanceType::Sine => unreachable!(),
+        };
+
+        // In eco mode the quad rate modes take one big step instead of several small ones.
+        // The coefficient gets capped since a single pass goes unstable much sooner
+        let passes = if self.eco
+            && matches!(self.res_mode, ResonanceType::Default | ResonanceType::Bump | ResonanceType::Powf)
+        {
+            1
+        } else {
+            self.oversample
+        };
+        let normalized_freq = if passes != self.oversample {
+            (normalized_freq * self.oversample as f32).min(ECO_MAX_COEFFICIENT)
+        } else {
+            normalized_freq
         };
 
         // Calculate our resonance coefficient
@@ -142,12 +183,14 @@ impl StateVariableFilter {
                 let resonance_exp = (2.0 * PI * self.q).powf(0.4) + 0.001;
                 (resonance_exp * (2.0 * PI * normalized_freq / (2.0 * resonance_exp)).sin()).tanh()
             }
+            // Handled in process_saturating
+            ResonanceType::Acid | ResonanceType::Sine => unreachable!(),
         };
 
         let rd_input = remove_denormals(input);
 
         // Oversample by running multiple iterations
-        for _ in 0..self.oversample {
+        for _ in 0..passes {
             self.low_output += normalized_freq * self.band_output;
             self.high_output = rd_input - self.low_output - self.q * self.band_output;
             self.band_output += resonance * self.high_output;
@@ -158,12 +201,33 @@ impl StateVariableFilter {
         self.high_output = remove_denormals(self.high_output);
         (self.low_output, self.band_output, self.high_output)
     }
-}
 
-fn remove_denormals(x: f32) -> f32 {
-    if x.abs() < 1e-30 {
-        0.0
-    } else {
-        x
+    // Plain Chamberlin loop with a clipper on the band integrator so the level stays bounded when damping reaches zero
+    fn process_saturating(&mut self, input: f32) -> (f32, f32, f32) {
+        let passes = self.oversample.max(1);
+        // Knob position 0-1 back out of the reversed Res range
+        let res = ((1.0 - self.q) / 0.9).clamp(0.0, 1.0);
+        let coefficient = (2.0 * (PI * self.frequency / (self.sample_rate * passes as f32)).sin()).min(1.0);
+        let (damping, drive) = match self.res_mode {
+            // Stops just short of oscillating, the drive gives the peak its grit
+            ResonanceType::Acid => (2.0 * (1.0 - res).powi(2) + 0.03, 2.5),
+            // Damping dips below zero at the top so the filter rings on its own and the clipper holds the level.
+            // The drive follows the coefficient so the ring settles at the same level at any cutoff
+            _ => (
+                2.0 * (1.0 - res) - SINE_OVERSHOOT,
+                (3.0 * SINE_OVERSHOOT * coefficient).sqrt() / SINE_LEVEL,
+            ),
+        };
+
+        let rd_input = remove_denormals(input);
+        for _ in 0..passes {
+            self.low_output += coefficient * self.band_output;
+            self.high_output = rd_input - self.low_output - damping * self.band_output;
+            self.band_output = (drive * (self.band_output + coefficient * self.high_output)).tanh() / drive;
+        }
+        self.low_output = remove_denormals(self.low_output);
+        self.band_output = remove_denormals(self.band_output);
+        self.high_output = remove_denormals(self.high_output);
+        (self.low_output, self.band_output, self.high_output)
     }
 }
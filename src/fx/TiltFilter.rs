@@ -2,6 +2,7 @@
 
 use nih_plug::prelude::Enum;
 use serde::{Deserialize, Serialize};
+use crate::fx::remove_denormals;
 
 #[derive(Enum, PartialEq, Serialize, Deserialize, Clone)]
 pub enum ResponseType {
@@ -121,12 +122,12 @@ impl SimpleFilter {
     pub fn process(&mut self, input: f32, type_filter: ResponseType) -> f32 {
         match type_filter {
             ResponseType::Lowpass => {
-                let output = self.a * input + self.b * self.prev_output;
+                let output = remove_denormals(self.a * input + self.b * self.prev_output);
                 self.prev_output = output;
                 output
             },
             ResponseType::Highpass => {
-                let output = self.b * (self.prev_output + input - self.prev_input);
+                let output = remove_denormals(self.b * (self.prev_output + input - self.prev_input));
                 self.prev_input = input;
                 self.prev_output = output;
                 output
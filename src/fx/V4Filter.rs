@@ -2,6 +2,7 @@
 // Ardura
 
 use nih_plug::util;
+use crate::fx::remove_denormals;
 
 #[derive(Clone, Copy, Debug)]
 pub struct V4FilterStruct {
@@ -72,12 +73,12 @@ impl V4FilterStruct {
         }
         
         let mut intermediate_value2 = filter_result * self.feedback_factor + self.integrator;
-        self.integrator = intermediate_value2.clamp(-1.0, 1.0);
+        self.integrator = remove_denormals(intermediate_value2.clamp(-1.0, 1.0));
         
         intermediate_value2 = (intermediate_value1 - intermediate_value2) - stage_value * filter_result;
         let output = stage_value * self.feedback_factor + intermediate_value2;
         
-        output.clamp(-1.0, 1.0)
+        remove_denormals(output.clamp(-1.0, 1.0))
     }
 
     fn scale_gain_from_cutoff(&mut self) {
@@ -1,6 +1,7 @@
 
 use nih_plug::params::enums::Enum;
 use serde::{Deserialize, Serialize};
+use crate::fx::remove_denormals;
 
 // Rust port of https://www.musicdsp.org/en/latest/Filters/24-moog-vcf.html
 // Ardura
@@ -87,6 +88,9 @@ impl VCFilter {
         self.y[2] = self.y[1] * self.p + self.olds[2] * self.p - self.k * self.y[2];
         self.y[3] = self.y[2] * self.p + self.olds[3] * self.p - self.k * self.y[3];
         self.y[3] = (self.y[3] - (self.y[3].powf(3.0)) / 6.0).clamp(-1.0, 1.0);
+        for stage in self.y.iter_mut() {
+            *stage = remove_denormals(*stage);
+        }
         self.olds[0] = x;
         self.olds[1] = self.y[0];
         self.olds[2] = self.y[1];
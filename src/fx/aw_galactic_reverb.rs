@@ -3,6 +3,7 @@
 // Ardura + Chris @ Airwindows
 
 use std::f32::consts::{FRAC_PI_2, TAU};
+use crate::fx::remove_denormals;
 
 #[derive(Clone)]
 struct ArrayBank {
@@ -225,9 +226,9 @@ impl GalacticReverb {
         let mut output_r = interpolate_MR;
 
         // Lowpass filter
-        self.iir_a_l =(self.iir_a_l * (1.0 - self.lowpass)) + (output_l * self.lowpass);
+        self.iir_a_l = remove_denormals((self.iir_a_l * (1.0 - self.lowpass)) + (output_l * self.lowpass));
         output_l = self.iir_a_l;
-        self.iir_a_r =(self.iir_a_r * (1.0 - self.lowpass)) + (output_r * self.lowpass);
+        self.iir_a_r = remove_denormals((self.iir_a_r * (1.0 - self.lowpass)) + (output_r * self.lowpass));
         output_r = self.iir_a_r;
 
         // Cycle calculation - mainly for non 44100 sample rates
@@ -331,6 +332,10 @@ impl GalacticReverb {
             self.feedback_r[1] = outFR - (outER + outGR + outHR);
             self.feedback_r[2] = outGR - (outER + outFR + outHR);
             self.feedback_r[3] = outHR - (outER + outFR + outGR);
+            for i in 0..4 {
+                self.feedback_l[i] = remove_denormals(self.feedback_l[i]);
+                self.feedback_r[i] = remove_denormals(self.feedback_r[i]);
+            }
             
             output_l = (outEL + outFL + outGL + outHL) / 8.0;
             output_r = (outER + outFR + outGR + outHR) / 8.0;
@@ -381,10 +386,10 @@ impl GalacticReverb {
             output_r = self.last_ref_r[self.cycle as usize];
         }
 
-        self.iir_b_l = (self.iir_b_l * (1.0 - self.lowpass)) + (output_l * self.lowpass);
+        self.iir_b_l = remove_denormals((self.iir_b_l * (1.0 - self.lowpass)) + (output_l * self.lowpass));
         output_l = self.iir_b_l;
 
-        self.iir_b_r = (self.iir_b_r * (1.0 - self.lowpass)) + (output_r * self.lowpass);
+        self.iir_b_r = remove_denormals((self.iir_b_r * (1.0 - self.lowpass)) + (output_r * self.lowpass));
         output_r = self.iir_b_r;
         
         // Changed this wet summing to match my other reverb
@@ -6,6 +6,7 @@
 #![allow(dead_code)]
 
 use nih_plug::params::enums::Enum;
+use crate::fx::remove_denormals;
 
 // This is for my sanity
 const LEFT: usize = 0;
@@ -232,7 +233,7 @@ impl Biquad {
         self.input_history[1][LEFT] = self.input_history[0][LEFT];
         self.input_history[0][LEFT] = input_l;
         self.output_history[1][LEFT] = self.output_history[0][LEFT];
-        self.output_history[0][LEFT] = output_l;
+        self.output_history[0][LEFT] = remove_denormals(output_l);
 
         // Calculate our current output for the right side
         output_r = (self.coeffs.b0 / self.coeffs.a0) * input_r
@@ -244,7 +245,7 @@ impl Biquad {
         self.input_history[1][RIGHT] = self.input_history[0][RIGHT];
         self.input_history[0][RIGHT] = input_r;
         self.output_history[1][RIGHT] = self.output_history[0][RIGHT];
-        self.output_history[0][RIGHT] = output_r;
+        self.output_history[0][RIGHT] = remove_denormals(output_r);
 
         (output_l, output_r)
     }
@@ -10,6 +10,8 @@ pub struct BufferModulator {
     depth: f32,
     rate: f32,
     spread: f32,
+    // 0-1 turns into a 0-180 degree offset between the L and R modulation
+    stereo: f32,
     buffer_tracker: f32,
     delay_left: usize,
     delay_right: usize,
@@ -29,6 +31,7 @@ impl BufferModulator {
             depth,
             rate,
             spread: 0.0,
+            stereo: 0.0,
             buffer_tracker: 0.0,
             delay_left,
             delay_right,
@@ -54,6 +57,10 @@ impl BufferModulator {
         }
     }
 
+    pub fn set_stereo(&mut self, stereo: f32) {
+        self.stereo = stereo.clamp(0.0, 1.0);
+    }
+
     pub fn process(&mut self, input_left: f32, input_right: f32, amount: f32) -> (f32, f32) {
         // Update time variables
         self.time_left += 1.0 / self.sample_rate;
@@ -62,7 +69,7 @@ impl BufferModulator {
 
         // Calculate modulation signals
         let modulation_left = (self.time_left * TAU * self.rate).sin();
-        let modulation_right = (self.time_right * TAU * self.rate).sin();
+        let modulation_right = (self.time_right * TAU * self.rate + self.stereo * TAU / 2.0).sin();
 
         // Apply effect to the left channel
         let delayed_sample_left = self.delay_line_left.remove(0);
@@ -0,0 +1,91 @@
+// Click-free FX bypass by Ardura
+// Flipping an effect's toggle fades its output against its input instead of hard switching
+// - The effect keeps running until the fade out finishes so its buffers and filter state don't cut off mid tail
+// - Once fully off the effect is skipped like before so bypassed FX still cost nothing
+
+// Length of the crossfade in milliseconds
+const RAMP_MS: f32 = 10.0;
+
+#[derive(Clone, Copy)]
+pub(crate) struct BypassRamp {
+    // 0 is fully bypassed, 1 is fully in
+    mix: f32,
+}
+
+impl BypassRamp {
+    pub fn new() -> Self {
+        BypassRamp { mix: 0.0 }
+    }
+
+    // Move toward the toggle state, returns if the effect still needs to run this sample
+    pub fn advance(&mut self, enabled: bool, sample_rate: f32) -> bool {
+        let step = 1.0 / (RAMP_MS * 0.001 * sample_rate);
+        self.mix = if enabled {
+            (self.mix + step).min(1.0)
+        } else {
+            (self.mix - step).max(0.0)
+        };
+        self.mix > 0.0
+    }
+
+    pub fn amount(&self) -> f32 {
+        self.mix
+    }
+
+    pub fn mix(&self, dry: (f32, f32), wet: (f32, f32)) -> (f32, f32) {
+        if self.mix >= 1.0 {
+            wet
+        } else {
+            (
+                dry.0 + (wet.0 - dry.0) * self.mix,
+                dry.1 + (wet.1 - dry.1) * self.mix,
+            )
+        }
+    }
+}
+
+// One ramp per toggleable effect on the master chain
+#[derive(Clone, Copy)]
+pub(crate) struct FxBypass {
+    pub eq: BypassRamp,
+    pub transient: BypassRamp,
+    pub compressor: BypassRamp,
+    pub abass: BypassRamp,
+    pub saturation: BypassRamp,
+    pub buffermod: BypassRamp,
+    pub chorus: BypassRamp,
+    pub phaser: BypassRamp,
+    pub flanger: BypassRamp,
+    pub delay: BypassRamp,
+    pub delay_2: BypassRamp,
+    pub reverb: BypassRamp,
+    pub resonator: BypassRamp,
+    pub looper: BypassRamp,
+    pub gate: BypassRamp,
+    pub bass_mono: BypassRamp,
+    pub limiter: BypassRamp,
+}
+
+impl FxBypass {
+    pub fn new() -> Self {
+        FxBypass {
+            eq: BypassRamp::new(),
+            transient: BypassRamp::new(),
+            compressor: BypassRamp::new(),
+            abass: BypassRamp::new(),
+            saturation: BypassRamp::new(),
+            buffermod: BypassRamp::new(),
+            chorus: BypassRamp::new(),
+            phaser: BypassRamp::new(),
+            flanger: BypassRamp::new(),
+            delay: BypassRamp::new(),
+            delay_2: BypassRamp::new(),
+            reverb: BypassRamp::new(),
+            resonator: BypassRamp::new(),
+            looper: BypassRamp::new(),
+            gate: BypassRamp::new(),
+            bass_mono: BypassRamp::new(),
+            limiter: BypassRamp::new(),
+        }
+    }
+}
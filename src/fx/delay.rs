@@ -2,8 +2,14 @@
 // Stock synth delays are pretty ok :)
 // Ardura 2023
 
+use std::f32::consts::PI;
+
 use nih_plug::params::enums::Enum;
 use serde::{Deserialize, Serialize};
+use crate::fx::remove_denormals;
+
+// Window of the feedback pitch shifter in samples - long enough to keep the grain flutter low
+const SHIFT_WINDOW: usize = 2048;
 
 #[derive(Clone, Enum, PartialEq, Serialize, Deserialize)]
 pub enum DelaySnapValues {
@@ -34,6 +40,58 @@ pub enum DelayType {
     PingPongR,
 }
 
+// How the second delay is chained with the first
+#[derive(Clone, Enum, PartialEq, Serialize, Deserialize)]
+pub enum DelayRouting {
+    Series,
+    Parallel,
+}
+
+// Two crossfaded taps sliding through a short buffer, the cheap classic delay-line pitch shifter
+// Tap gains always sum to 1 so it can't add energy to the feedback loop
+#[derive(Clone)]
+struct FeedbackShifter {
+    buffer: Vec<f32>,
+    write_index: usize,
+    tap_delay: f32,
+}
+
+impl FeedbackShifter {
+    fn new() -> Self {
+        FeedbackShifter {
+            buffer: vec![0.0; SHIFT_WINDOW],
+            write_index: 0,
+            tap_delay: 0.0,
+        }
+    }
+
+    fn read(&self, delay: f32) -> f32 {
+        let len = SHIFT_WINDOW as f32;
+        let position = (self.write_index as f32 - delay).rem_euclid(len);
+        let index = position.floor() as usize % SHIFT_WINDOW;
+        let next = (index + 1) % SHIFT_WINDOW;
+        let frac = position - position.floor();
+        self.buffer[index] + (self.buffer[next] - self.buffer[index]) * frac
+    }
+
+    fn process(&mut self, input: f32, ratio: f32) -> f32 {
+        let len = SHIFT_WINDOW as f32;
+        self.buffer[self.write_index] = input;
+
+        // Pitching up means reading faster than we write, so the tap delay shrinks
+        self.tap_delay = (self.tap_delay + 1.0 - ratio).rem_euclid(len);
+        let tap_delay_2 = (self.tap_delay + len * 0.5) % len;
+
+        // Each tap fades out right where it wraps around
+        let gain_1 = (PI * self.tap_delay / len).sin().powi(2);
+        let gain_2 = 1.0 - gain_1;
+        let output = self.read(self.tap_delay) * gain_1 + self.read(tap_delay_2) * gain_2;
+
+        self.write_index = (self.write_index + 1) % SHIFT_WINDOW;
+        output
+    }
+}
+
 #[derive(Clone)]
 pub(crate) struct Delay {
     sample_rate: f32,
@@ -45,6 +103,10 @@ pub(crate) struct Delay {
     delay_type: DelayType,
     feedback: f32,
     current_index: usize,
+    // Semitones each repeat gets shifted by
+    pitch: f32,
+    shifter_l: FeedbackShifter,
+    shifter_r: FeedbackShifter,
 }
 
 impl Delay {
@@ -95,6 +157,9 @@ impl Delay {
             delay_type: DelayType::Stereo,
             feedback,
             current_index: 0,
+            pitch: 0.0,
+            shifter_l: FeedbackShifter::new(),
+            shifter_r: FeedbackShifter::new(),
         }
     }
 
@@ -182,17 +247,28 @@ impl Delay {
         self.feedback = feedback;
     }
 
+    pub fn set_pitch(&mut self, pitch: f32) {
+        self.pitch = pitch;
+    }
+
     pub fn process(&mut self, input_l: f32, input_r: f32, amount: f32) -> (f32, f32) {
         // Get the current values from the delay lines
-        let delayed_sample_l: f32 = self.delay_buffer_l[self.current_index];
-        let delayed_sample_r: f32 = self.delay_buffer_r[self.current_index];
+        let mut delayed_sample_l: f32 = self.delay_buffer_l[self.current_index];
+        let mut delayed_sample_r: f32 = self.delay_buffer_r[self.current_index];
+
+        // Shift each repeat on its way back into the line, soft clipped so rising repeats at high feedback can't run away
+        if self.pitch != 0.0 {
+            let ratio = 2.0_f32.powf(self.pitch / 12.0);
+            delayed_sample_l = self.shifter_l.process(delayed_sample_l, ratio).tanh();
+            delayed_sample_r = self.shifter_r.process(delayed_sample_r, ratio).tanh();
+        }
 
         // Calculate the left and right outputs
         let mut output_l: f32;
         let mut output_r: f32;
 
-        output_l = input_l + self.feedback * delayed_sample_l;
-        output_r = input_r + self.feedback * delayed_sample_r;
+        output_l = remove_denormals(input_l + self.feedback * delayed_sample_l);
+        output_r = remove_denormals(input_r + self.feedback * delayed_sample_r);
 
         let delay_shift_l: usize;
         let delay_shift_r: usize;
@@ -0,0 +1,120 @@
+// Output dither by Ardura
+// Readies the master output for a 16-bit render:
+// - TPDF dither, two uniform randoms summed so the noise doesn't move with the signal level
+// - The output is quantized to 16-bit here so the host's truncation afterwards changes nothing
+// - Shaping feeds the quantization error back through a first order highpass, pushing the noise up out of the midrange
+
+// Size of one 16-bit step at full scale
+const LSB: f32 = 1.0 / 32768.0;
+
+#[derive(Clone, Copy)]
+pub(crate) struct Dither {
+    rng_state: u32,
+    error_l: f32,
+    error_r: f32,
+}
+
+impl Dither {
+    pub fn new() -> Self {
+        Dither {
+            rng_state: 0x9E37_79B9,
+            error_l: 0.0,
+            error_r: 0.0,
+        }
+    }
+
+    pub fn process(&mut self, input_l: f32, input_r: f32, shaping: bool) -> (f32, f32) {
+        if !shaping {
+            self.error_l = 0.0;
+            self.error_r = 0.0;
+        }
+        let noise_l = self.tpdf();
+        let noise_r = self.tpdf();
+        let (output_l, error_l) = quantize(input_l - self.error_l, noise_l);
+        let (output_r, error_r) = quantize(input_r - self.error_r, noise_r);
+        if shaping {
+            self.error_l = error_l;
+            self.error_r = error_r;
+        }
+        (output_l, output_r)
+    }
+
+    // Triangular noise spanning +/- 1 LSB
+    fn tpdf(&mut self) -> f32 {
+        (self.random() - self.random()) * LSB
+    }
+
+    // Xorshift so the audio thread doesn't need a thread_rng
+    fn random(&mut self) -> f32 {
+        self.rng_state ^= self.rng_state << 13;
+        self.rng_state ^= self.rng_state >> 17;
+        self.rng_state ^= self.rng_state << 5;
+        self.rng_state as f32 / u32::MAX as f32
+    }
+}
+
+// Round to the nearest 16-bit step, returns the output and the error it added
+fn quantize(input: f32, noise: f32) -> (f32, f32) {
+    let output = (((input + noise) / LSB).round() * LSB).clamp(-1.0, 1.0 - LSB);
+    // Clipping at full scale isn't quantization error, keep it out of the shaping loop
+    (output, (output - input).clamp(-2.0 * LSB, 2.0 * LSB))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLES: usize = 1_000_000;
+
+    // On the 16-bit grid when the value scaled up to steps is a whole number
+    fn on_grid(value: f32) -> bool {
+        let steps = value / LSB;
+        steps == steps.round()
+    }
+
+    #[test]
+    fn tpdf_stays_within_one_lsb_and_centered() {
+        let mut dither = Dither::new();
+        let mut sum = 0.0_f64;
+        let mut sum_squares = 0.0_f64;
+        for _ in 0..SAMPLES {
+            let noise = dither.tpdf();
+            assert!(noise.abs() <= LSB);
+            sum += noise as f64;
+            sum_squares += (noise as f64) * (noise as f64);
+        }
+        let mean = sum / SAMPLES as f64;
+        assert!(mean.abs() < 0.01 * LSB as f64, "mean was {} LSB", mean / LSB as f64);
+        // Triangular over +/- 1 LSB has a variance of LSB^2 / 6
+        let variance = sum_squares / SAMPLES as f64 - mean * mean;
+        let expected = (LSB as f64).powi(2) / 6.0;
+        assert!((variance - expected).abs() < 0.02 * expected);
+    }
+
+    #[test]
+    fn quantize_lands_on_the_bit_grid() {
+        let mut dither = Dither::new();
+        for i in 0..SAMPLES {
+            let input = ((i as f32) * 0.001).sin() * 0.9;
+            let (output, error) = quantize(input, dither.tpdf());
+            assert!(on_grid(output), "{} is off the grid", output);
+            // Rounding plus the noise never moves a sample more than 1.5 steps
+            assert!(error.abs() <= 1.5 * LSB);
+        }
+        // Full scale clips to the largest 16-bit values
+        assert_eq!(quantize(1.5, 0.0).0, 1.0 - LSB);
+        assert_eq!(quantize(-1.5, 0.0).0, -1.0);
+    }
+
+    #[test]
+    fn process_outputs_land_on_the_bit_grid() {
+        for shaping in [false, true] {
+            let mut dither = Dither::new();
+            for i in 0..SAMPLES / 10 {
+                let input = ((i as f32) * 0.01).sin() * 0.5;
+                let (output_l, output_r) = dither.process(input, -input, shaping);
+                assert!(on_grid(output_l) && on_grid(output_r));
+            }
+        }
+    }
+}
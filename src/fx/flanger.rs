@@ -10,6 +10,8 @@ pub struct StereoFlanger {
     delay_line: Vec<(f32, f32)>,
     index: usize,
     lfo_phase: f32,
+    // 0-1 turns into a 0-180 degree offset between the L and R sweeps
+    stereo: f32,
 }
 
 impl StereoFlanger {
@@ -30,6 +32,7 @@ impl StereoFlanger {
             delay_line: vec![(0.0, 0.0); max_delay_samples],
             index: 0,
             lfo_phase: 0.0,
+            stereo: 0.0,
         }
     }
 
@@ -40,6 +43,10 @@ impl StereoFlanger {
         self.feedback = feedback;
     }
 
+    pub fn set_stereo(&mut self, stereo: f32) {
+        self.stereo = stereo.clamp(0.0, 1.0);
+    }
+
     pub fn process(&mut self, left_in: f32, right_in: f32, amount: f32) -> (f32, f32) {
         // Update LFO phase
         self.lfo_phase += 2.0 * PI * self.lfo_rate / self.sample_rate;
@@ -47,15 +54,17 @@ impl StereoFlanger {
             self.lfo_phase -= 2.0 * PI;
         }
 
-        // Calculate modulation depth
-        let modulator = self.depth * (0.5 * self.lfo_phase.sin() + 0.5);
+        // Calculate modulation depth, the right side sweeps behind the left by the stereo offset
+        let modulator_left = self.depth * (0.5 * self.lfo_phase.sin() + 0.5);
+        let modulator_right = self.depth * (0.5 * (self.lfo_phase + self.stereo * PI).sin() + 0.5);
 
         // Calculate delay in samples
-        let delay_samples = (self.delay_range * modulator) as usize;
+        let delay_samples_left = (self.delay_range * modulator_left) as usize;
+        let delay_samples_right = (self.delay_range * modulator_right) as usize;
 
         // Retrieve delayed samples from the delay line
-        let delayed_left = self.delay_line[(self.index + delay_samples) % self.delay_line.len()].0;
-        let delayed_right = self.delay_line[(self.index + delay_samples) % self.delay_line.len()].1;
+        let delayed_left = self.delay_line[(self.index + delay_samples_left) % self.delay_line.len()].0;
+        let delayed_right = self.delay_line[(self.index + delay_samples_right) % self.delay_line.len()].1;
 
         // Apply flanger effect
         let mut left_out = left_in + self.feedback * delayed_left;
@@ -2,6 +2,8 @@
 pub struct StereoLimiter {
     threshold: f32,
     knee_width: f32,
+    // Linear gain of the output ceiling, nothing leaves process above this
+    ceiling: f32,
 }
 
 impl StereoLimiter {
@@ -9,6 +11,7 @@ impl StereoLimiter {
         StereoLimiter {
             threshold,
             knee_width,
+            ceiling: 1.0,
         }
     }
 
@@ -17,9 +20,13 @@ impl StereoLimiter {
         self.knee_width = knee_width;
     }
 
+    pub fn set_ceiling(&mut self, ceiling_db: f32) {
+        self.ceiling = 10.0_f32.powf(ceiling_db / 20.0);
+    }
+
     pub fn process(&self, left_in: f32, right_in: f32) -> (f32, f32) {
-        let left_gain = self.limit(left_in);
-        let right_gain = self.limit(right_in);
+        let left_gain = self.limit(left_in).clamp(-self.ceiling, self.ceiling);
+        let right_gain = self.limit(right_in).clamp(-self.ceiling, self.ceiling);
         (left_gain, right_gain)
     }
 
@@ -0,0 +1,93 @@
+// Looping recorder by Ardura
+// The output is recorded into a loop buffer that plays back into itself:
+// - Length is the loop time, the buffer holds the longest loop so changing it never reallocates
+// - Feedback is how much of the old loop survives each pass
+// - Mix is the level of the loop playback over the dry output
+// - Pitch is the playback rate of the loop, each pass is read faster or slower and goes back in that way
+//   Two read heads half a loop apart sweep around the loop length, each fading out right where it wraps
+
+use std::f32::consts::PI;
+
+use crate::fx::remove_denormals;
+
+// Passes always lose a little so the loop fades out instead of building forever
+const MAX_FEEDBACK: f32 = 0.95;
+
+// The Hermite read needs two samples ahead of the read position that are already written
+const READ_GUARD: f32 = 3.0;
+
+#[derive(Clone)]
+pub(crate) struct Looper {
+    buffer_l: Vec<f32>,
+    buffer_r: Vec<f32>,
+    write_index: usize,
+    // Where the first read head sits in its sweep, 0.5 is exactly one loop back and only moves when pitched
+    phase: f32,
+}
+
+impl Looper {
+    pub fn new() -> Self {
+        Looper {
+            buffer_l: vec![0.0; 1],
+            buffer_r: vec![0.0; 1],
+            write_index: 0,
+            phase: 0.5,
+        }
+    }
+
+    pub fn set_max_length(&mut self, max_ms: f32, sample_rate: f32) {
+        // The heads reach half a loop past the longest loop, plus room for the interpolation points
+        let length = ((max_ms / 1000.0) * sample_rate * 1.5).ceil() as usize + 4;
+        if self.buffer_l.len() != length {
+            self.buffer_l = vec![0.0; length];
+            self.buffer_r = vec![0.0; length];
+            self.write_index = 0;
+            self.phase = 0.5;
+        }
+    }
+
+    pub fn process(
+        &mut self,
+        input_l: f32,
+        input_r: f32,
+        loop_samples: usize,
+        rate: f32,
+        feedback: f32,
+        mix: f32,
+    ) -> (f32, f32) {
+        let length = self.buffer_l.len();
+        let loop_samples = loop_samples.clamp(2 * READ_GUARD as usize, (length - 4) * 2 / 3) as f32;
+        // Faster playback walks a head towards the write head, slower walks it away
+        // Each head covers half a loop either side of one loop back, the other takes over while it wraps
+        self.phase = (self.phase + (rate - 1.0) / loop_samples).rem_euclid(1.0);
+        let phase_2 = (self.phase + 0.5) % 1.0;
+        let delay_1 = loop_samples * (1.5 - self.phase);
+        let delay_2 = loop_samples * (1.5 - phase_2);
+        let gain_1 = (PI * self.phase).sin().powi(2);
+        let gain_2 = 1.0 - gain_1;
+        let loop_l = self.read(&self.buffer_l, delay_1) * gain_1 + self.read(&self.buffer_l, delay_2) * gain_2;
+        let loop_r = self.read(&self.buffer_r, delay_1) * gain_1 + self.read(&self.buffer_r, delay_2) * gain_2;
+        let feedback = feedback.clamp(0.0, MAX_FEEDBACK);
+        // Soft clip what goes back in so loud input stacked over many passes stays bounded
+        self.buffer_l[self.write_index] = remove_denormals((input_l + loop_l * feedback).tanh());
+        self.buffer_r[self.write_index] = remove_denormals((input_r + loop_r * feedback).tanh());
+        self.write_index = (self.write_index + 1) % length;
+        (input_l + loop_l * mix, input_r + loop_r * mix)
+    }
+
+    // 4 point Hermite read at a fractional distance behind the write head
+    fn read(&self, buffer: &[f32], delay: f32) -> f32 {
+        let length = buffer.len();
+        let position = (self.write_index as f32 - delay).rem_euclid(length as f32);
+        let index = position.floor() as usize % length;
+        let frac = position - position.floor();
+        let y0 = buffer[(index + length - 1) % length];
+        let y1 = buffer[index];
+        let y2 = buffer[(index + 1) % length];
+        let y3 = buffer[(index + 2) % length];
+        let c1 = 0.5 * (y2 - y0);
+        let c2 = y0 - 2.5 * y1 + 2.0 * y2 - 0.5 * y3;
+        let c3 = 0.5 * (y3 - y0) + 1.5 * (y1 - y2);
+        ((c3 * frac + c2) * frac + c1) * frac + y1
+    }
+}
@@ -0,0 +1,179 @@
+// Oversampler by Ardura
+// Runs the nonlinear FX at 2x or 4x so the harmonics they make above Nyquist get filtered off instead of folding back down:
+// - Each 2x stage is a pair of linear phase halfband FIRs split into polyphase branches, so zero stuffed samples are never multiplied
+// - 4x nests a shorter second stage inside the first since the first already cleared everything near its Nyquist
+// - The delay through the filters is fixed per factor so it can be reported to the host as latency
+
+use nih_plug::params::enums::Enum;
+use serde::{Deserialize, Serialize};
+use std::f32::consts::PI;
+
+// Tap counts are 4n + 3 so the center lands on an odd index and the round trip delay is a whole number of samples
+const STAGE_1_TAPS: usize = 47;
+const STAGE_2_TAPS: usize = 15;
+// Kaiser window shape, around 80dB of stopband rejection
+const KAISER_BETA: f32 = 8.0;
+
+#[derive(Clone, Copy, Enum, PartialEq, Serialize, Deserialize)]
+pub enum OversampleFactor {
+    #[name = "1x"]
+    X1,
+    #[name = "2x"]
+    X2,
+    #[name = "4x"]
+    X4,
+}
+
+#[derive(Clone)]
+pub(crate) struct Oversampler {
+    stage_1: [Halfband; 2],
+    stage_2: [Halfband; 2],
+    // One sample of delay at 2x that rounds the 4x latency up to a whole base rate sample
+    pad: [f32; 2],
+}
+
+impl Oversampler {
+    pub fn new() -> Self {
+        Oversampler {
+            stage_1: [Halfband::new(STAGE_1_TAPS), Halfband::new(STAGE_1_TAPS)],
+            stage_2: [Halfband::new(STAGE_2_TAPS), Halfband::new(STAGE_2_TAPS)],
+            pad: [0.0; 2],
+        }
+    }
+
+    // Delay through an up/down round trip in base rate samples
+    pub fn latency(factor: OversampleFactor) -> u32 {
+        let stage_1 = (STAGE_1_TAPS - 1) / 2;
+        let stage_2 = (STAGE_2_TAPS - 1) / 2;
+        match factor {
+            OversampleFactor::X1 => 0,
+            OversampleFactor::X2 => stage_1 as u32,
+            // Stage 2 adds half its delay at 2x, plus the pad sample
+            OversampleFactor::X4 => (stage_1 + (stage_2 + 1) / 2) as u32,
+        }
+    }
+
+    // Runs effect at the oversampled rate between the up and down filters
+    pub fn process<F: FnMut(f32, f32) -> (f32, f32)>(
+        &mut self,
+        input_l: f32,
+        input_r: f32,
+        factor: OversampleFactor,
+        mut effect: F,
+    ) -> (f32, f32) {
+        match factor {
+            OversampleFactor::X1 => effect(input_l, input_r),
+            OversampleFactor::X2 => {
+                let up_l = self.stage_1[0].upsample(input_l);
+                let up_r = self.stage_1[1].upsample(input_r);
+                let mut out_l = [0.0; 2];
+                let mut out_r = [0.0; 2];
+                for i in 0..2 {
+                    (out_l[i], out_r[i]) = effect(up_l[i], up_r[i]);
+                }
+                (self.stage_1[0].downsample(out_l), self.stage_1[1].downsample(out_r))
+            }
+            OversampleFactor::X4 => {
+                let up_l = self.stage_1[0].upsample(input_l);
+                let up_r = self.stage_1[1].upsample(input_r);
+                let mut out_l = [0.0; 2];
+                let mut out_r = [0.0; 2];
+                for i in 0..2 {
+                    let inner_l = self.stage_2[0].upsample(up_l[i]);
+                    let inner_r = self.stage_2[1].upsample(up_r[i]);
+                    let mut inner_out_l = [0.0; 2];
+                    let mut inner_out_r = [0.0; 2];
+                    for j in 0..2 {
+                        (inner_out_l[j], inner_out_r[j]) = effect(inner_l[j], inner_r[j]);
+                    }
+                    let down_l = self.stage_2[0].downsample(inner_out_l);
+                    let down_r = self.stage_2[1].downsample(inner_out_r);
+                    out_l[i] = self.pad[0];
+                    out_r[i] = self.pad[1];
+                    self.pad = [down_l, down_r];
+                }
+                (self.stage_1[0].downsample(out_l), self.stage_1[1].downsample(out_r))
+            }
+        }
+    }
+}
+
+// One mono 2x stage, the same taps are used going up and coming back down
+#[derive(Clone)]
+struct Halfband {
+    taps: Vec<f32>,
+    up_history: Vec<f32>,
+    up_index: usize,
+    down_history: Vec<f32>,
+    down_index: usize,
+}
+
+impl Halfband {
+    fn new(length: usize) -> Self {
+        let center = (length - 1) as f32 / 2.0;
+        let taps = (0..length)
+            .map(|n| {
+                let x = n as f32 - center;
+                // Windowed sinc with the cutoff at half the base rate Nyquist
+                let sinc = if x == 0.0 { 0.5 } else { (0.5 * PI * x).sin() / (PI * x) };
+                let ratio = x / center;
+                sinc * bessel_i0(KAISER_BETA * (1.0 - ratio * ratio).sqrt()) / bessel_i0(KAISER_BETA)
+            })
+            .collect();
+        Halfband {
+            taps,
+            up_history: vec![0.0; (length + 1) / 2],
+            up_index: 0,
+            down_history: vec![0.0; length],
+            down_index: 0,
+        }
+    }
+
+    // One sample in, two out at the doubled rate
+    fn upsample(&mut self, input: f32) -> [f32; 2] {
+        let size = self.up_history.len();
+        self.up_index = (self.up_index + 1) % size;
+        self.up_history[self.up_index] = input;
+        let mut output = [0.0; 2];
+        for (phase, out) in output.iter_mut().enumerate() {
+            let mut sum = 0.0;
+            // Each output phase only lines up with every other tap
+            for (k, tap) in self.taps.iter().skip(phase).step_by(2).enumerate() {
+                sum += tap * self.up_history[(self.up_index + size - k) % size];
+            }
+            // Zero stuffing halves the level so the filter makes it back up
+            *out = 2.0 * sum;
+        }
+        output
+    }
+
+    // Two samples at the doubled rate in, one out
+    fn downsample(&mut self, input: [f32; 2]) -> f32 {
+        let size = self.down_history.len();
+        self.down_index = (self.down_index + 1) % size;
+        self.down_history[self.down_index] = input[0];
+        // Only the kept phase gets filtered, the other sample just goes into the history
+        let mut output = 0.0;
+        for (k, tap) in self.taps.iter().enumerate() {
+            output += tap * self.down_history[(self.down_index + size - k) % size];
+        }
+        self.down_index = (self.down_index + 1) % size;
+        self.down_history[self.down_index] = input[1];
+        output
+    }
+}
+
+// Zeroth order modified Bessel function for the Kaiser window
+fn bessel_i0(x: f32) -> f32 {
+    let mut sum = 1.0;
+    let mut term = 1.0;
+    let half = x / 2.0;
+    for k in 1..32 {
+        term *= (half / k as f32) * (half / k as f32);
+        sum += term;
+        if term < sum * 1e-9 {
+            break;
+        }
+    }
+    sum
+}
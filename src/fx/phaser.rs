@@ -24,28 +24,35 @@ impl AllpassDelay {
 
 #[derive(Clone, Copy)]
 pub struct StereoPhaser {
-    alps: [AllpassDelay; 6],
+    alps_l: [AllpassDelay; 6],
+    alps_r: [AllpassDelay; 6],
     dmin: f32,
     dmax: f32,
     fb: f32,
     lfo_phase: f32,
     lfo_inc: f32,
     depth: f32,
-    zm1: f32,
+    zm1_l: f32,
+    zm1_r: f32,
+    // 0-1 turns into a 0-180 degree offset between the L and R sweeps
+    stereo: f32,
     sample_rate: f32,
 }
 
 impl StereoPhaser {
     pub fn new() -> Self {
         let mut phaser = StereoPhaser {
-            alps: [AllpassDelay::new(); 6],
+            alps_l: [AllpassDelay::new(); 6],
+            alps_r: [AllpassDelay::new(); 6],
             dmin: 0.0,
             dmax: 0.0,
             fb: 0.7,
             lfo_phase: 0.0,
             lfo_inc: 0.0,
             depth: 1.0,
-            zm1: 0.0,
+            zm1_l: 0.0,
+            zm1_r: 0.0,
+            stereo: 0.0,
             sample_rate: 44100.0,
         };
         phaser.range(440.0, 1600.0);
@@ -74,26 +81,36 @@ impl StereoPhaser {
         self.depth = depth;
     }
 
+    pub fn set_stereo(&mut self, stereo: f32) {
+        self.stereo = stereo.clamp(0.0, 1.0);
+    }
+
     pub fn process(&mut self, left_in: f32, right_in: f32, amount: f32) -> (f32, f32) {
-        let d = self.dmin + (self.dmax - self.dmin) * ((self.lfo_phase.sin() + 1.0) / 2.0);
+        // Each side gets its own allpass chain so the right sweep can trail the left
+        let d_l = self.dmin + (self.dmax - self.dmin) * ((self.lfo_phase.sin() + 1.0) / 2.0);
+        let d_r = self.dmin + (self.dmax - self.dmin) * (((self.lfo_phase + self.stereo * PI).sin() + 1.0) / 2.0);
         self.lfo_phase += self.lfo_inc;
         self.lfo_phase %= PI * 2.0;
 
-        for alp in &mut self.alps {
-            alp.delay(d);
+        for alp in &mut self.alps_l {
+            alp.delay(d_l);
+        }
+        for alp in &mut self.alps_r {
+            alp.delay(d_r);
         }
 
         let left_out = self
-            .alps
+            .alps_l
             .iter_mut()
-            .fold(left_in + self.zm1 * self.fb, |acc, alp| alp.update(acc));
+            .fold(left_in + self.zm1_l * self.fb, |acc, alp| alp.update(acc));
 
         let right_out = self
-            .alps
+            .alps_r
             .iter_mut()
-            .fold(right_in + self.zm1 * self.fb, |acc, alp| alp.update(acc));
+            .fold(right_in + self.zm1_r * self.fb, |acc, alp| alp.update(acc));
 
-        self.zm1 = left_out;
+        self.zm1_l = left_out;
+        self.zm1_r = right_out;
 
         let output_l = left_out + left_in * self.depth;
         let output_r = right_out + right_in * self.depth;
@@ -0,0 +1,226 @@
+// Resonator bank by Ardura
+// Tuned feedback combs that ring at the notes of a chord, turning any input into a pitched drone or shimmer:
+// - Every chord note gets a comb per side, the right one a few cents sharp so the tail has width
+// - Decay is the time for a comb to ring down 60dB, feedback is worked out per comb from its own period
+// - A one pole lowpass in each loop darkens the tail as it rings like a real string or plate
+
+use nih_plug::params::enums::Enum;
+use serde::{Deserialize, Serialize};
+
+use crate::fx::remove_denormals;
+
+const VOICES: usize = 4;
+// Right side detune for width
+const STEREO_CENTS: f32 = 4.0;
+// Loop lowpass coefficient, higher keeps more top end in the tail
+const DAMPING: f32 = 0.35;
+// Stops the loop gain from reaching 1 even at the longest decay
+const MAX_FEEDBACK: f32 = 0.9995;
+
+#[derive(Clone, Copy, Enum, PartialEq, Serialize, Deserialize)]
+pub enum ResonatorChord {
+    Major,
+    Minor,
+    Sus4,
+    #[name = "Maj7"]
+    Major7,
+    #[name = "Min7"]
+    Minor7,
+    Fifths,
+    Octaves,
+}
+
+impl ResonatorChord {
+    // Semitones above the root for each comb
+    fn intervals(&self) -> [f32; VOICES] {
+        match self {
+            ResonatorChord::Major => [0.0, 4.0, 7.0, 12.0],
+            ResonatorChord::Minor => [0.0, 3.0, 7.0, 12.0],
+            ResonatorChord::Sus4 => [0.0, 5.0, 7.0, 12.0],
+            ResonatorChord::Major7 => [0.0, 4.0, 7.0, 11.0],
+            ResonatorChord::Minor7 => [0.0, 3.0, 7.0, 10.0],
+            ResonatorChord::Fifths => [0.0, 7.0, 12.0, 19.0],
+            ResonatorChord::Octaves => [0.0, 12.0, 24.0, 36.0],
+        }
+    }
+}
+
+#[derive(Clone)]
+struct Comb {
+    buffer: Vec<f32>,
+    write_index: usize,
+    // Fractional delay in samples
+    delay: f32,
+    feedback: f32,
+    // Keeps white noise at the same level going in and coming out
+    input_gain: f32,
+    lowpass: f32,
+}
+
+impl Comb {
+    fn new() -> Self {
+        Comb {
+            buffer: vec![0.0; 4],
+            write_index: 0,
+            delay: 1.0,
+            feedback: 0.0,
+            input_gain: 1.0,
+            lowpass: 0.0,
+        }
+    }
+
+    // Allocates, so only called outside of process
+    fn set_max_delay(&mut self, max_delay: f32) {
+        let length = max_delay.ceil() as usize + 2;
+        if self.buffer.len() != length {
+            self.buffer = vec![0.0; length];
+            self.write_index = 0;
+        }
+    }
+
+    // Never touches the buffer, a note below the sized range just clamps to the longest delay
+    fn tune(&mut self, freq: f32, decay: f32, sample_rate: f32) {
+        self.delay = (sample_rate / freq).clamp(2.0, (self.buffer.len() - 2) as f32);
+        // -60dB over the decay time, spread over however many trips around the loop that is
+        self.feedback = 10.0_f32.powf(-3.0 * (self.delay / sample_rate) / decay).min(MAX_FEEDBACK);
+        self.input_gain = (1.0 - self.feedback * self.feedback).sqrt();
+    }
+
+    fn process(&mut self, input: f32) -> f32 {
+        let length = self.buffer.len();
+        let position = (self.write_index as f32 - self.delay).rem_euclid(length as f32);
+        let index = position.floor() as usize % length;
+        let frac = position - position.floor();
+        let delayed = self.buffer[index] + (self.buffer[(index + 1) % length] - self.buffer[index]) * frac;
+        self.lowpass = remove_denormals(self.lowpass + (delayed - self.lowpass) * (1.0 - DAMPING));
+        // tanh only ever shrinks the loop so a tone landing right on a comb can't run away
+        self.buffer[self.write_index] = (input * self.input_gain + self.lowpass * self.feedback).tanh();
+        self.write_index = (self.write_index + 1) % length;
+        delayed
+    }
+}
+
+#[derive(Clone)]
+pub(crate) struct ResonatorBank {
+    combs_l: Vec<Comb>,
+    combs_r: Vec<Comb>,
+    // Last settings so the combs only retune on change
+    tuning: (f32, i32, Option<ResonatorChord>, f32),
+}
+
+impl ResonatorBank {
+    pub fn new() -> Self {
+        ResonatorBank {
+            combs_l: vec![Comb::new(); VOICES],
+            combs_r: vec![Comb::new(); VOICES],
+            tuning: (0.0, 0, None, 0.0),
+        }
+    }
+
+    // Sizes every comb for the lowest root at this sample rate so retuning never allocates
+    pub fn set_max_length(&mut self, lowest_root: i32, sample_rate: f32) {
+        let max_delay = sample_rate / midi_to_freq(lowest_root as f32);
+        for comb in self.combs_l.iter_mut().chain(self.combs_r.iter_mut()) {
+            comb.set_max_delay(max_delay);
+        }
+        // Delays were clamped to the old buffers so make the next update retune
+        self.tuning = (0.0, 0, None, 0.0);
+    }
+
+    // root is a midi note, decay in seconds
+    pub fn update(&mut self, sample_rate: f32, root: i32, chord: ResonatorChord, decay: f32) {
+        if self.tuning == (sample_rate, root, Some(chord), decay) {
+            return;
+        }
+        self.tuning = (sample_rate, root, Some(chord), decay);
+        for (i, interval) in chord.intervals().iter().enumerate() {
+            let freq = midi_to_freq(root as f32 + interval);
+            self.combs_l[i].tune(freq, decay, sample_rate);
+            self.combs_r[i].tune(freq * 2.0_f32.powf(STEREO_CENTS / 1200.0), decay, sample_rate);
+        }
+    }
+
+    // Returns only the ringing, the caller mixes it over the dry
+    pub fn process(&mut self, input_l: f32, input_r: f32) -> (f32, f32) {
+        let mut output_l = 0.0;
+        let mut output_r = 0.0;
+        for (comb_l, comb_r) in self.combs_l.iter_mut().zip(self.combs_r.iter_mut()) {
+            output_l += comb_l.process(input_l);
+            output_r += comb_r.process(input_r);
+        }
+        (output_l / VOICES as f32, output_r / VOICES as f32)
+    }
+}
+
+fn midi_to_freq(note: f32) -> f32 {
+    440.0 * 2.0_f32.powf((note - 69.0) / 12.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_RATE: f32 = 44100.0;
+    // Bottom of the Root param
+    const LOWEST_ROOT: i32 = 24;
+    // Top of the Decay param
+    const MAX_DECAY: f32 = 30.0;
+    const CHORDS: [ResonatorChord; 7] = [
+        ResonatorChord::Major,
+        ResonatorChord::Minor,
+        ResonatorChord::Sus4,
+        ResonatorChord::Major7,
+        ResonatorChord::Minor7,
+        ResonatorChord::Fifths,
+        ResonatorChord::Octaves,
+    ];
+
+    // Every comb output is bounded by the tanh in its loop so the averaged bank can't pass 1
+    fn assert_bounded(bank: &mut ResonatorBank, seconds: f32, input: impl Fn(usize) -> f32) {
+        for i in 0..(seconds * SAMPLE_RATE) as usize {
+            let (output_l, output_r) = bank.process(input(i), input(i));
+            assert!(output_l.is_finite() && output_r.is_finite(), "output blew up at sample {}", i);
+            assert!(output_l.abs() <= 1.0 && output_r.abs() <= 1.0, "output ran away at sample {}", i);
+        }
+    }
+
+    #[test]
+    fn impulse_at_max_decay_stays_bounded() {
+        for chord in CHORDS {
+            // Both ends of the Root range
+            for root in [24, 72] {
+                let mut bank = ResonatorBank::new();
+                bank.set_max_length(LOWEST_ROOT, SAMPLE_RATE);
+                bank.update(SAMPLE_RATE, root, chord, MAX_DECAY);
+                assert_bounded(&mut bank, 10.0, |i| if i == 0 { 1.0 } else { 0.0 });
+            }
+        }
+    }
+
+    #[test]
+    fn tone_on_the_root_at_max_decay_stays_bounded() {
+        // A full scale sine right on the lowest comb feeds it every pass
+        let freq = 440.0 * 2.0_f32.powf((48.0 - 69.0) / 12.0);
+        let mut bank = ResonatorBank::new();
+        bank.set_max_length(LOWEST_ROOT, SAMPLE_RATE);
+        bank.update(SAMPLE_RATE, 48, ResonatorChord::Octaves, MAX_DECAY);
+        assert_bounded(&mut bank, 20.0, |i| (std::f32::consts::TAU * freq * i as f32 / SAMPLE_RATE).sin());
+    }
+
+    #[test]
+    fn retuning_keeps_the_presized_buffers() {
+        let mut bank = ResonatorBank::new();
+        bank.set_max_length(LOWEST_ROOT, SAMPLE_RATE);
+        let lengths: Vec<usize> = bank.combs_l.iter().chain(bank.combs_r.iter()).map(|comb| comb.buffer.len()).collect();
+        for chord in CHORDS {
+            for root in LOWEST_ROOT..=72 {
+                bank.update(SAMPLE_RATE, root, chord, MAX_DECAY);
+                let retuned: Vec<usize> = bank.combs_l.iter().chain(bank.combs_r.iter()).map(|comb| comb.buffer.len()).collect();
+                assert_eq!(retuned, lengths);
+                // The lowest root fits without clamping so every comb lands on its note
+                let expected = SAMPLE_RATE / midi_to_freq(root as f32);
+                assert!((bank.combs_l[0].delay - expected).abs() < 1e-3, "root {} was clamped", root);
+            }
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use crate::fx::remove_denormals;
+
 #[derive(Clone)]
 pub struct StereoReverb {
     left_delay: Vec<f32>,
@@ -39,18 +41,20 @@ impl StereoReverb {
         }
     }
 
-    pub fn process_tdl(&mut self, input_l: f32, input_r: f32, amount: f32) -> (f32, f32) {
+    // Density scales the tap gain, 1 is the full tap and lower values thin the tail out
+    pub fn process_tdl(&mut self, input_l: f32, input_r: f32, amount: f32, density: f32) -> (f32, f32) {
         // Get the current values from the delay lines
         let delayed_sample_l = self.left_delay[self.current_index];
         let delayed_sample_r = self.right_delay[self.current_index];
 
         // Calculate the left and right outputs
-        let mut output_l = input_l + self.feedback * delayed_sample_l;
-        let mut output_r = input_r + self.feedback * delayed_sample_r;
+        let tap_gain = self.feedback * density;
+        let mut output_l = input_l + tap_gain * delayed_sample_l;
+        let mut output_r = input_r + tap_gain * delayed_sample_r;
 
         // Store the outputs in the delay lines
-        self.left_delay[self.current_index] = output_l;
-        self.right_delay[self.current_index] = output_r;
+        self.left_delay[self.current_index] = remove_denormals(output_l);
+        self.right_delay[self.current_index] = remove_denormals(output_r);
 
         // Move the index to the next position in the delay lines
         self.current_index = (self.current_index + 1) % self.delay_length;
@@ -60,3 +64,40 @@ impl StereoReverb {
         (output_l, output_r)
     }
 }
+
+// Delay ahead of the reverb models so the dry transient lands before the tail starts
+#[derive(Clone)]
+pub struct PreDelay {
+    left_buffer: Vec<f32>,
+    right_buffer: Vec<f32>,
+    write_index: usize,
+}
+
+impl PreDelay {
+    pub fn new() -> Self {
+        PreDelay {
+            left_buffer: vec![0.0; 1],
+            right_buffer: vec![0.0; 1],
+            write_index: 0,
+        }
+    }
+
+    // Buffer holds the longest delay at this sample rate so changing the time never reallocates
+    pub fn set_max_length(&mut self, max_ms: f32, sample_rate: f32) {
+        let length = ((max_ms / 1000.0) * sample_rate).ceil() as usize + 1;
+        if self.left_buffer.len() != length {
+            self.left_buffer = vec![0.0; length];
+            self.right_buffer = vec![0.0; length];
+            self.write_index = 0;
+        }
+    }
+
+    pub fn process(&mut self, input_l: f32, input_r: f32, delay_samples: usize) -> (f32, f32) {
+        let length = self.left_buffer.len();
+        self.left_buffer[self.write_index] = input_l;
+        self.right_buffer[self.write_index] = input_r;
+        let read_index = (self.write_index + length - delay_samples.min(length - 1)) % length;
+        self.write_index = (self.write_index + 1) % length;
+        (self.left_buffer[read_index], self.right_buffer[read_index])
+    }
+}
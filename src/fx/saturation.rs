@@ -15,6 +15,24 @@ pub enum SaturationType {
     Sine,
 }
 
+// Which part of the stereo image the saturation stages work on
+#[derive(Clone, Copy, Enum, PartialEq, Serialize, Deserialize)]
+pub enum SaturationStereoMode {
+    #[name = "L/R"]
+    LR,
+    Mid,
+    Side,
+}
+
+// Standard M/S encode and decode
+pub fn to_mid_side(input_l: f32, input_r: f32) -> (f32, f32) {
+    ((input_l + input_r) * 0.5, (input_l - input_r) * 0.5)
+}
+
+pub fn from_mid_side(mid: f32, side: f32) -> (f32, f32) {
+    (mid + side, mid - side)
+}
+
 #[derive(Clone, PartialEq)]
 pub(crate) struct Saturation {
     sat_type: SaturationType,
@@ -3,6 +3,7 @@
 // Ardura
 
 use std::f32::consts::{TAU};
+use crate::fx::remove_denormals;
 
 #[derive(Clone)]
 struct ArrayBank {
@@ -176,9 +177,9 @@ impl SimpleSpaceReverb {
         let mut output_r = interpolate_MR;
 
         // Lowpass filter
-        self.iir_a_l =(self.iir_a_l * (1.0 - self.lowpass)) + (output_l * self.lowpass);
+        self.iir_a_l = remove_denormals((self.iir_a_l * (1.0 - self.lowpass)) + (output_l * self.lowpass));
         output_l = self.iir_a_l;
-        self.iir_a_r =(self.iir_a_r * (1.0 - self.lowpass)) + (output_r * self.lowpass);
+        self.iir_a_r = remove_denormals((self.iir_a_r * (1.0 - self.lowpass)) + (output_r * self.lowpass));
         output_r = self.iir_a_r;
 
         ///////////////////////////////////////////////////////////////////////////////////
@@ -217,6 +218,10 @@ impl SimpleSpaceReverb {
         self.feedback_r[1] = outJR - (outIR + outKR + outLR);
         self.feedback_r[2] = outKR - (outIR + outJR + outLR);
         self.feedback_r[3] = outLR - (outIR + outJR + outKR);
+        for i in 0..4 {
+            self.feedback_l[i] = remove_denormals(self.feedback_l[i]);
+            self.feedback_r[i] = remove_denormals(self.feedback_r[i]);
+        }
         
         output_l = (outIL + outJL + outKL + outLL)/2.0;
         output_r = (outIR + outJR + outKR + outLR)/2.0;
@@ -235,10 +240,10 @@ impl SimpleSpaceReverb {
         output_l = self.last_ref_l[4];
         output_r = self.last_ref_r[4];
 
-        self.iir_b_l = (self.iir_b_l * (1.0 - self.lowpass)) + (output_l * self.lowpass);
+        self.iir_b_l = remove_denormals((self.iir_b_l * (1.0 - self.lowpass)) + (output_l * self.lowpass));
         output_l = self.iir_b_l;
 
-        self.iir_b_r = (self.iir_b_r * (1.0 - self.lowpass)) + (output_r * self.lowpass);
+        self.iir_b_r = remove_denormals((self.iir_b_r * (1.0 - self.lowpass)) + (output_r * self.lowpass));
         output_r = self.iir_b_r;
         
         output_l = input_l * (1.0 - self.wet) + output_l * self.wet;
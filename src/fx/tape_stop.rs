@@ -0,0 +1,108 @@
+// Tape stop by Ardura
+// A read head that slows down behind the write head like a reel losing power:
+// - Holding stop ramps the speed down to zero over the stop time, pitch and level fall with it
+// - Releasing spins the tape back up over the same time, then fades over to the live signal to drop the lag
+// - Reads are cubic interpolated so the slow parts don't turn grainy
+
+use crate::fx::remove_denormals;
+
+// Tape reads always sit this far behind so the interpolation has samples on both sides
+const READ_OFFSET: f32 = 2.0;
+// Fade from the lagging tape back to live once it's up to speed
+const CATCH_UP_TIME: f32 = 0.02;
+
+#[derive(Clone)]
+pub(crate) struct TapeStop {
+    buffer_l: Vec<f32>,
+    buffer_r: Vec<f32>,
+    write_index: usize,
+    speed: f32,
+    // How far the read head has fallen behind in samples
+    lag: f32,
+    catch_up: f32,
+}
+
+impl TapeStop {
+    pub fn new() -> Self {
+        TapeStop {
+            buffer_l: vec![0.0; 8],
+            buffer_r: vec![0.0; 8],
+            write_index: 0,
+            speed: 1.0,
+            lag: 0.0,
+            catch_up: 0.0,
+        }
+    }
+
+    // The head falls at most half the ramp time behind while slowing down or spinning up
+    pub fn set_max_time(&mut self, max_ms: f32, sample_rate: f32) {
+        let length = ((max_ms / 2000.0) * sample_rate).ceil() as usize + 8;
+        if self.buffer_l.len() != length {
+            self.buffer_l = vec![0.0; length];
+            self.buffer_r = vec![0.0; length];
+            self.write_index = 0;
+            self.lag = 0.0;
+        }
+    }
+
+    pub fn process(&mut self, input_l: f32, input_r: f32, stopping: bool, ramp_ms: f32, sample_rate: f32) -> (f32, f32) {
+        let length = self.buffer_l.len();
+        self.buffer_l[self.write_index] = input_l;
+        self.buffer_r[self.write_index] = input_r;
+
+        let step = 1.0 / (ramp_ms * 0.001 * sample_rate).max(1.0);
+        if stopping {
+            self.speed = (self.speed - step).max(0.0);
+            self.catch_up = 0.0;
+        } else {
+            self.speed = (self.speed + step).min(1.0);
+        }
+        self.lag = (self.lag + 1.0 - self.speed).min(length as f32 - READ_OFFSET - 3.0);
+        // Fully stopped is silent, so the head can jump back and spin up from the live signal
+        if self.speed == 0.0 {
+            self.lag = 0.0;
+        }
+
+        // Live is the input itself so an idle tape stop adds no delay to the output
+        let live = (input_l, input_r);
+        let output = if self.lag > 0.0 || self.speed < 1.0 {
+            // Level follows the speed so a stopped tape fades out instead of holding a DC value
+            let gain = self.speed.sqrt();
+            let tape = (
+                self.read(&self.buffer_l, READ_OFFSET + self.lag) * gain,
+                self.read(&self.buffer_r, READ_OFFSET + self.lag) * gain,
+            );
+            if !stopping && self.speed == 1.0 {
+                self.catch_up = (self.catch_up + 1.0 / (CATCH_UP_TIME * sample_rate)).min(1.0);
+                if self.catch_up == 1.0 {
+                    self.lag = 0.0;
+                    self.catch_up = 0.0;
+                }
+            }
+            (
+                tape.0 + (live.0 - tape.0) * self.catch_up,
+                tape.1 + (live.1 - tape.1) * self.catch_up,
+            )
+        } else {
+            live
+        };
+        self.write_index = (self.write_index + 1) % length;
+        (remove_denormals(output.0), remove_denormals(output.1))
+    }
+
+    // 4 point Hermite read at a fractional distance behind the write head
+    fn read(&self, buffer: &[f32], delay: f32) -> f32 {
+        let length = buffer.len();
+        let position = (self.write_index as f32 - delay).rem_euclid(length as f32);
+        let index = position.floor() as usize % length;
+        let frac = position - position.floor();
+        let y0 = buffer[(index + length - 1) % length];
+        let y1 = buffer[index];
+        let y2 = buffer[(index + 1) % length];
+        let y3 = buffer[(index + 2) % length];
+        let c1 = 0.5 * (y2 - y0);
+        let c2 = y0 - 2.5 * y1 + 2.0 * y2 - 0.5 * y3;
+        let c3 = 0.5 * (y3 - y0) + 1.5 * (y1 - y2);
+        ((c3 * frac + c2) * frac + c1) * frac + y1
+    }
+}
@@ -0,0 +1,53 @@
+// Tempo synced trance gate by Ardura
+// Chops the master output on a note grid:
+// - Shape is the duty cycle, how much of each step the gate stays open
+// - Smooth slews the gain so the gate edges don't click, higher values give a softer pump
+
+// Gain slew time range in milliseconds for smooth 0-1
+const MIN_SMOOTH_MS: f32 = 0.5;
+const MAX_SMOOTH_MS: f32 = 40.0;
+
+#[derive(Clone, Copy)]
+pub(crate) struct TranceGate {
+    // 0-1 position inside the current gate step
+    phase: f32,
+    phase_step: f32,
+    duty: f32,
+    gain: f32,
+    slew_coeff: f32,
+}
+
+impl TranceGate {
+    pub fn new() -> Self {
+        TranceGate {
+            phase: 0.0,
+            phase_step: 0.0,
+            duty: 0.5,
+            gain: 1.0,
+            slew_coeff: 0.0,
+        }
+    }
+
+    // beats is the step length in quarter notes, shape and smooth are 0-1
+    pub fn update(&mut self, sample_rate: f32, bpm: f32, beats: f32, shape: f32, smooth: f32) {
+        self.phase_step = (bpm / 60.0) / (beats * sample_rate);
+        self.duty = shape;
+        let smooth_ms = MIN_SMOOTH_MS + (MAX_SMOOTH_MS - MIN_SMOOTH_MS) * smooth * smooth;
+        self.slew_coeff = (-1.0 / (smooth_ms * 0.001 * sample_rate)).exp();
+    }
+
+    // Line the gate up with the host grid, pos_beats is the transport position in quarter notes
+    pub fn sync(&mut self, pos_beats: f64, beats: f32) {
+        self.phase = (pos_beats / beats as f64).rem_euclid(1.0) as f32;
+    }
+
+    pub fn process(&mut self, input_l: f32, input_r: f32) -> (f32, f32) {
+        let target = if self.phase < self.duty { 1.0 } else { 0.0 };
+        self.gain = target + self.slew_coeff * (self.gain - target);
+        self.phase += self.phase_step;
+        if self.phase >= 1.0 {
+            self.phase -= 1.0;
+        }
+        (input_l * self.gain, input_r * self.gain)
+    }
+}
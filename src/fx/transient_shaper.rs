@@ -0,0 +1,109 @@
+// Transient shaper in the style of the SPL Transient Designer by Ardura
+// Two pairs of envelope followers are compared so the result doesn't depend on input level:
+// - Fast attack vs slow attack follower: the difference is the transient part of the note
+// - Long release vs short release follower: the difference is the sustain/tail part of the note
+
+use nih_plug::util;
+
+// Envelope follower times in milliseconds
+const FAST_ATTACK_MS: f32 = 0.5;
+const SLOW_ATTACK_MS: f32 = 25.0;
+const SHORT_RELEASE_MS: f32 = 40.0;
+const LONG_RELEASE_MS: f32 = 400.0;
+// How many dB of boost/cut a full knob turn gives per dB of envelope difference
+const SHAPE_SCALE: f32 = 2.0;
+// Keep extreme settings from blowing up
+const MAX_GAIN_DB: f32 = 18.0;
+// Stop the followers from going to zero so the ratios stay defined
+const ENV_FLOOR: f32 = 0.00001;
+
+#[derive(Clone, Copy)]
+struct EnvelopeFollower {
+    attack_coeff: f32,
+    release_coeff: f32,
+    env: f32,
+}
+
+impl EnvelopeFollower {
+    fn new() -> Self {
+        EnvelopeFollower {
+            attack_coeff: 0.0,
+            release_coeff: 0.0,
+            env: ENV_FLOOR,
+        }
+    }
+
+    fn set_times(&mut self, sample_rate: f32, attack_ms: f32, release_ms: f32) {
+        self.attack_coeff = (-1.0 / (attack_ms * 0.001 * sample_rate)).exp();
+        self.release_coeff = (-1.0 / (release_ms * 0.001 * sample_rate)).exp();
+    }
+
+    fn next(&mut self, input: f32) -> f32 {
+        let coeff = if input > self.env {
+            self.attack_coeff
+        } else {
+            self.release_coeff
+        };
+        self.env = (input + coeff * (self.env - input)).max(ENV_FLOOR);
+        self.env
+    }
+}
+
+#[derive(Clone, Copy)]
+pub(crate) struct TransientShaper {
+    sample_rate: f32,
+    // -1 to 1 for each, 0 leaves the signal alone
+    attack: f32,
+    sustain: f32,
+    fast_attack: EnvelopeFollower,
+    slow_attack: EnvelopeFollower,
+    short_release: EnvelopeFollower,
+    long_release: EnvelopeFollower,
+}
+
+impl TransientShaper {
+    pub fn new(sample_rate: f32) -> Self {
+        let mut shaper = TransientShaper {
+            sample_rate: 0.0,
+            attack: 0.0,
+            sustain: 0.0,
+            fast_attack: EnvelopeFollower::new(),
+            slow_attack: EnvelopeFollower::new(),
+            short_release: EnvelopeFollower::new(),
+            long_release: EnvelopeFollower::new(),
+        };
+        shaper.update(sample_rate, 0.0, 0.0);
+        shaper
+    }
+
+    pub fn update(&mut self, sample_rate: f32, attack: f32, sustain: f32) {
+        self.attack = attack;
+        self.sustain = sustain;
+        if sample_rate != self.sample_rate {
+            self.sample_rate = sample_rate;
+            self.fast_attack.set_times(sample_rate, FAST_ATTACK_MS, SHORT_RELEASE_MS);
+            self.slow_attack.set_times(sample_rate, SLOW_ATTACK_MS, SHORT_RELEASE_MS);
+            self.short_release.set_times(sample_rate, FAST_ATTACK_MS, SHORT_RELEASE_MS);
+            self.long_release.set_times(sample_rate, FAST_ATTACK_MS, LONG_RELEASE_MS);
+        }
+    }
+
+    pub fn process(&mut self, input_l: f32, input_r: f32) -> (f32, f32) {
+        // Stereo linked detection so the image doesn't shift
+        let level = input_l.abs().max(input_r.abs());
+
+        let fast = self.fast_attack.next(level);
+        let slow = self.slow_attack.next(level);
+        let short = self.short_release.next(level);
+        let long = self.long_release.next(level);
+
+        // Both of these are 0 dB or above, only rising when there is a transient or a tail
+        let transient_db = util::gain_to_db(fast / slow).max(0.0);
+        let sustain_db = util::gain_to_db(long / short).max(0.0);
+
+        let gain_db = (self.attack * transient_db + self.sustain * sustain_db) * SHAPE_SCALE;
+        let gain = util::db_to_gain_fast(gain_db.clamp(-MAX_GAIN_DB, MAX_GAIN_DB));
+
+        (input_l * gain, input_r * gain)
+    }
+}
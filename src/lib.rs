@@ -25,27 +25,32 @@ This is the first synth I've ever written and first large Rust project. Thanks f
 */
 
 #![allow(non_snake_case)]
-use actuate_enums::{AMFilterRouting, FilterAlgorithms, FilterRouting, ModulationDestination, ModulationSource, PitchRouting, PresetBrowserEntry, PresetType, ReverbModel, StereoAlgorithm};
+use actuate_enums::{AMFilterRouting, EnvTimeRange, FilterAlgorithms, FilterRouting, ModulationDestination, ModulationSource, MonoMode, PitchQuantizeScale, PitchRouting, PresetBrowserEntry, PresetType, ReverbModel, StereoAlgorithm, TuningSystem, VoiceStealMode};
 use actuate_structs::{ActuatePresetV131, ModulationStruct};
+use actuate_midi_learn::{apply_midi_learn, param_ptr_map, MidiLearnEditor, SharedGuiContext};
 use nih_plug::{prelude::*};
 use nih_plug_egui::{
     egui::{Color32, FontId}, EguiState
 };
+use rand::Rng;
+use rand_pcg::Pcg32;
+use flate2::{read::GzDecoder, write::GzEncoder, Compression};
 use std::{
-    collections::HashMap, fs::File, io::Read, path::PathBuf, sync::{
-        atomic::{AtomicBool, AtomicU32, Ordering},
+    collections::{HashMap, VecDeque}, fs::File, io::{Read, Write}, path::PathBuf, sync::{
+        atomic::{AtomicBool, AtomicI32, AtomicU32, Ordering},
         Arc, Mutex, RwLock,
     }
 };
 
 // My Files/crates
 use audio_module::{
-    AudioModule, AudioModuleType,
+    AudioModule, AudioModuleType, GrainWindow, NotePitch,
+    output_capture::OutputCapture,
     Oscillator::{self, OscState, RetriggerStyle, SmoothStyle},
     frequency_modulation,
 };
 use fx::{
-    abass::a_bass_saturation, aw_galactic_reverb::GalacticReverb, biquad_filters::{self, FilterType}, buffermodulator::BufferModulator, chorus::ChorusEnsemble, compressor::Compressor, delay::{Delay, DelaySnapValues, DelayType}, flanger::StereoFlanger, limiter::StereoLimiter, phaser::StereoPhaser, reverb::StereoReverb, saturation::{Saturation, SaturationType}, simple_space_reverb::SimpleSpaceReverb, StateVariableFilter::{ResonanceType,StateVariableFilter}, TiltFilter::{self, ResponseType}, VCFilter::ResponseType as VCResponseType
+    abass::a_bass_saturation, aw_galactic_reverb::GalacticReverb, biquad_filters::{self, FilterType}, bypass_ramp::FxBypass, buffermodulator::BufferModulator, chorus::ChorusEnsemble, compressor::Compressor, transient_shaper::TransientShaper, trance_gate::TranceGate, looper::Looper, resonator_bank::{ResonatorBank, ResonatorChord}, tape_stop::TapeStop, dither::Dither, oversampler::{OversampleFactor, Oversampler}, delay::{Delay, DelayRouting, DelaySnapValues, DelayType}, flanger::StereoFlanger, limiter::StereoLimiter, phaser::StereoPhaser, reverb::{PreDelay, StereoReverb}, saturation::{self, Saturation, SaturationStereoMode, SaturationType}, simple_space_reverb::SimpleSpaceReverb, StateVariableFilter::{ResonanceType,StateVariableFilter}, TiltFilter::{self, ResponseType}, VCFilter::ResponseType as VCResponseType
 };
 
 // This is here in meantime until new Actuate versions past this one!
@@ -57,6 +62,9 @@ use old_preset_structs::{
 mod actuate_gui;
 mod actuate_enums;
 mod actuate_structs;
+mod actuate_undo;
+mod actuate_keyboard;
+mod actuate_midi_learn;
 mod CustomWidgets;
 mod LFOController;
 mod audio_module;
@@ -70,6 +78,44 @@ const HEIGHT: u32 = 656;
 // File Open Buffer Timer - fixes sync issues from load/save to the gui
 const FILE_OPEN_BUFFER_MAX: u32 = 1;
 
+// Soft start fade length in seconds - short enough not to smear transients
+const SOFT_START_TIME: f32 = 0.005;
+
+// Lowest master HPF setting, which bypasses the filter
+const MASTER_HPF_OFF: f32 = 10.0;
+
+// Longest ramp of the tape stop in ms
+const TAPE_STOP_MAX_MS: f32 = 4000.0;
+
+// Longest reverb pre-delay in ms
+const REVERB_PREDELAY_MAX_MS: f32 = 200.0;
+
+// Lowest resonator root note, the combs are sized for it up front
+const RESONATOR_LOWEST_ROOT: i32 = 24;
+
+// Longest stretch of output Freeze can grab in seconds
+const CAPTURE_MAX_SECONDS: f32 = 10.0;
+
+// Longest loop the looper can record in ms
+const LOOPER_MAX_MS: f32 = 4000.0;
+
+// Size of each of the 8 stacked TDL reverbs relative to the Size knob
+const TDL_TAP_SCALES: [f32; 8] = [1.0, 0.546, 0.251, 0.735, 0.669, 0.374, 0.8, 0.4];
+
+// Output meter falloff in seconds, short enough to follow notes but slow enough to read
+const METER_DECAY_TIME: f32 = 0.3;
+
+// Humanize limits at full amount: timing in seconds, velocity, and pitch in semitones
+const HUMANIZE_SEED: u64 = 0xcafef00dd15ea5e5;
+const HUMANIZE_MAX_TIME: f32 = 0.02;
+const HUMANIZE_MAX_VELOCITY: f32 = 0.15;
+const HUMANIZE_MAX_PITCH: f32 = 0.1;
+// Delayed events waiting to play, the queue is allocated once at this size
+const HUMANIZE_QUEUE_SIZE: usize = 128;
+
+// Pitch class names for the tuning root
+const NOTE_NAMES: [&str; 12] = ["C", "C#", "D", "D#", "E", "F", "F#", "G", "G#", "A", "A#", "B"];
+
 // GUI values to refer to
 pub const TEAL_GREEN: Color32 = Color32::from_rgb(61, 178, 166);
 pub const DARKEST_BOTTOM_UI_COLOR: Color32 = Color32::from_rgb(27, 27, 27);
@@ -85,6 +131,12 @@ pub const FONT_COLOR: Color32 = Color32::from_rgb(248, 248, 248);
 const FONT: nih_plug_egui::egui::FontId = FontId::proportional(12.0);
 const SMALLER_FONT: nih_plug_egui::egui::FontId = FontId::proportional(11.0);
 
+// Work the audio thread hands off to the main thread through task_executor
+pub enum ActuateTask {
+    // Move MIDI learn bound params to their latest CC values
+    ApplyMidiLearn,
+}
+
 // This is the struct of the actual plugin object that tracks everything
 //#[derive(Clone)]
 pub struct Actuate {
@@ -104,11 +156,50 @@ pub struct Actuate {
     //exporting_banks: Arc<AtomicBool>,
     //current_preset: Arc<AtomicU32>,
     update_current_preset: Arc<AtomicBool>,
+    // Program change waiting for the GUI to load it, -1 when there is none
+    pending_program: Arc<AtomicI32>,
+    // Notes played on the GUI keyboard waiting for process_midi
+    gui_note_queue: Arc<Mutex<VecDeque<NoteEvent<()>>>>,
+    // Latest value of each CC waiting for MIDI learn to apply it, -1 when nothing new arrived
+    pending_cc: Arc<Vec<AtomicF32>>,
+    // Set while an ApplyMidiLearn task is queued so a CC sweep doesn't flood the task queue
+    midi_learn_queued: Arc<AtomicBool>,
+    // Host GUI context from the editor, MIDI learn needs it to set params
+    gui_context: SharedGuiContext,
 
     safety_clip_output: Arc<Mutex<bool>>,
 
     current_note_on_velocity: Arc<AtomicF32>,
 
+    // Output meter - peak is read by the GUI, clip stays lit until the GUI clears it
+    output_peak: Arc<AtomicF32>,
+    output_clipped: Arc<AtomicBool>,
+    meter_hold: f32,
+    // Stereo correlation of the output, -1 is out of phase, 0 is wide/unrelated, 1 is mono
+    output_correlation: Arc<AtomicF32>,
+    // Running averages of L*R, L*L and R*R the correlation is computed from
+    correlation_sums: [f32; 3],
+    // Tempo clock for looping filter envelopes, 0-1 through the current loop
+    filter_loop_phase: f32,
+    // Per generator levels so you can balance the three layers
+    module_peaks: [Arc<AtomicF32>; 3],
+    // Where each LFO sits in its cycle and what it is outputting, read by the LFO tab position view
+    lfo_phases: [Arc<AtomicF32>; 3],
+    lfo_values: [Arc<AtomicF32>; 3],
+    // Latest output of each mod matrix slot so the GUI can freeze it into the knobs
+    mod_values: [Arc<AtomicF32>; 8],
+    module_hold: [f32; 3],
+
+    // Set when the host gave us the stereo input layout
+    has_main_input: bool,
+    // Set when the host picked the Multi-Out layout
+    multi_out: bool,
+    // The generator buses skip the FX so they get held back by the latency reported for the main out
+    aux_delays: [PreDelay; 3],
+
+    // Tempo used for syncing this block, shown in the GUI
+    current_bpm: Arc<AtomicF32>,
+
     // Managing resample logic
     prev_restretch_1: Arc<AtomicBool>,
     prev_restretch_2: Arc<AtomicBool>,
@@ -118,6 +209,8 @@ pub struct Actuate {
     audio_module_1: Arc<Mutex<AudioModule>>,
     audio_module_2: Arc<Mutex<AudioModule>>,
     audio_module_3: Arc<Mutex<AudioModule>>,
+    // Recent master output for Freeze
+    output_capture: Arc<Mutex<OutputCapture>>,
 
 
     // LFOs!
@@ -132,6 +225,26 @@ pub struct Actuate {
     dc_filter_l: StateVariableFilter,
     dc_filter_r: StateVariableFilter,
 
+    // Soft start ramp after loads/voice clears - 1.0 means no fade is running
+    soft_start_gain: f32,
+
+    // Humanize - seeded so renders are repeatable
+    humanize_rng: Pcg32,
+    humanize_queue: VecDeque<(usize, NoteEvent<()>)>,
+    // Pitch offset picked for the latest note on, the module keeps it on the voice that note starts
+    humanize_detune: f32,
+
+    // Mono/legato note tracking and glide in semitones relative to the sounding voice
+    held_notes: Vec<u8>,
+    mono_voice_note: Option<u8>,
+    mono_velocity: f32,
+    // Mono retrigger waiting for the sample after its release, with the offset it glides in from
+    mono_pending: Option<(NoteEvent<()>, f32)>,
+    mono_last_pitch: Option<f32>,
+    glide_current: f32,
+    glide_target: f32,
+    glide_step: f32,
+
     fm_state: OscState,
     fm_atk_smoother_1: Smoother<f32>,
     fm_dec_smoother_1: Smoother<f32>,
@@ -148,19 +261,39 @@ pub struct Actuate {
     // People thought the quirks of interleaving were bugs
     bands: Arc<Mutex<[biquad_filters::Biquad; 3]>>,
 
+    // Master tilt shelves - low shelf then high shelf around a shared pivot
+    tilt_bands: [biquad_filters::Biquad; 2],
+    // User rumble filter on the master bus
+    master_hpf: biquad_filters::Biquad,
+    // Linkwitz-Riley crossover for bass mono, two Butterworth stages per side so the bands sum flat
+    bass_mono_lows: [biquad_filters::Biquad; 2],
+    bass_mono_highs: [biquad_filters::Biquad; 2],
+    // Lows of the side channel for the Space macro
+    space_side_low: biquad_filters::Biquad,
+    // Our own smoother so an offline render never steps the live Space param
+    space_smoother: Smoother<f32>,
+
     // Compressor
     compressor: Compressor,
+    transient_shaper: TransientShaper,
+    trance_gate: TranceGate,
+    looper: Looper,
+    resonator: ResonatorBank,
+    tape_stop: TapeStop,
+    dither: Dither,
 
     // Saturation
     saturator: Saturation,
 
     // Delay
     delay: Delay,
+    delay_2: Delay,
 
     // Reverb
     reverb: [StereoReverb; 8],
     galactic_reverb: GalacticReverb,
     simple_space: [SimpleSpaceReverb;4],
+    reverb_predelay: PreDelay,
 
     // Phaser
     phaser: StereoPhaser,
@@ -177,6 +310,15 @@ pub struct Actuate {
     // Limiter
     limiter: StereoLimiter,
 
+    // Oversampling around the saturation stages and the limiter
+    sat_oversampler: Oversampler,
+    limiter_oversampler: Oversampler,
+    // What we last told the host, only resent on change
+    reported_latency: u32,
+
+    // Crossfades for the FX toggles
+    fx_bypass: FxBypass,
+
     // Preset browser stuff
     filter_acid: Arc<AtomicBool>,
     filter_analog: Arc<AtomicBool>,
@@ -240,12 +382,21 @@ impl Default for Actuate {
         let str_files_map: Arc<Mutex<HashMap<String, Vec<PathBuf>>>> =  Arc::new(Mutex::new(HashMap::new()));
         //let mut preset_browser_lite_db:  Arc<Mutex<HashMap<String, HashMap<String, PresetBrowserEntry>>> =  ;
 
+        // New instances start from the user's Init patch if one was saved, a host restoring a project overwrites it
+        let user_init = Actuate::user_init_preset();
+        let init_preset = user_init.clone().unwrap_or_else(|| DEFAULT_PRESET.clone());
+        let params = Arc::new(ActuateParams::new(
+            update_something.clone(),
+            file_dialog.clone(),
+            update_current_preset.clone(),
+            &init_preset,
+        ));
+        if let Some(user_init) = &user_init {
+            params.load_init_extras(user_init);
+        }
+
         Self {
-            params: Arc::new(ActuateParams::new(
-                update_something.clone(),
-                file_dialog.clone(),
-                update_current_preset.clone(),
-            )),
+            params: params,
             sample_rate: 44100.0,
 
             // Plugin control ARCs
@@ -262,9 +413,32 @@ impl Default for Actuate {
             exporting_presets: exporting_presets,
             //current_preset: current_preset,
             update_current_preset: update_current_preset,
+            pending_program: Arc::new(AtomicI32::new(-1)),
+            gui_note_queue: Arc::new(Mutex::new(VecDeque::with_capacity(64))),
+            pending_cc: Arc::new((0..128).map(|_| AtomicF32::new(-1.0)).collect()),
+            midi_learn_queued: Arc::new(AtomicBool::new(false)),
+            gui_context: Arc::new(Mutex::new(None)),
 
             current_note_on_velocity: Arc::new(AtomicF32::new(0.0)),
 
+            output_peak: Arc::new(AtomicF32::new(0.0)),
+            output_clipped: Arc::new(AtomicBool::new(false)),
+            meter_hold: 0.0,
+            output_correlation: Arc::new(AtomicF32::new(0.0)),
+            correlation_sums: [0.0; 3],
+            filter_loop_phase: 0.0,
+            module_peaks: [Arc::new(AtomicF32::new(0.0)), Arc::new(AtomicF32::new(0.0)), Arc::new(AtomicF32::new(0.0))],
+            module_hold: [0.0; 3],
+            lfo_phases: [Arc::new(AtomicF32::new(0.0)), Arc::new(AtomicF32::new(0.0)), Arc::new(AtomicF32::new(0.0))],
+            lfo_values: [Arc::new(AtomicF32::new(0.0)), Arc::new(AtomicF32::new(0.0)), Arc::new(AtomicF32::new(0.0))],
+            mod_values: std::array::from_fn(|_| Arc::new(AtomicF32::new(-2.0))),
+
+            has_main_input: false,
+            multi_out: false,
+            aux_delays: [PreDelay::new(), PreDelay::new(), PreDelay::new()],
+
+            current_bpm: Arc::new(AtomicF32::new(0.0)),
+
             prev_restretch_1: Arc::new(AtomicBool::new(false)),
             prev_restretch_2: Arc::new(AtomicBool::new(false)),
             prev_restretch_3: Arc::new(AtomicBool::new(false)),
@@ -273,6 +447,7 @@ impl Default for Actuate {
             audio_module_1: Arc::new(Mutex::new(AudioModule::default())),
             audio_module_2: Arc::new(Mutex::new(AudioModule::default())),
             audio_module_3: Arc::new(Mutex::new(AudioModule::default())),
+            output_capture: Arc::new(Mutex::new(OutputCapture::new())),
 
 
             //LFOs
@@ -284,7 +459,7 @@ impl Default for Actuate {
             //preset_name: Arc::new(Mutex::new(String::new())),
             //preset_info: Arc::new(Mutex::new(String::new())),
             //preset_category: Arc::new(Mutex::new(PresetType::Select)),
-            current_loaded_params: Arc::new(Mutex::new(DEFAULT_PRESET.clone())),
+            current_loaded_params: Arc::new(Mutex::new(init_preset)),
 
             fm_state: OscState::Off,
             fm_atk_smoother_1: Smoother::new(SmoothingStyle::Linear(300.0)),
@@ -300,21 +475,52 @@ impl Default for Actuate {
             dc_filter_l: StateVariableFilter::default().set_oversample(2),
             dc_filter_r: StateVariableFilter::default().set_oversample(2),
 
+            soft_start_gain: 0.0,
+
+            humanize_rng: Pcg32::new(HUMANIZE_SEED, 0xa02bdbf7bb3c0a7),
+            humanize_queue: VecDeque::with_capacity(HUMANIZE_QUEUE_SIZE),
+            humanize_detune: 0.0,
+
+            held_notes: Vec::with_capacity(128),
+            mono_voice_note: None,
+            mono_velocity: 0.0,
+            mono_pending: None,
+            mono_last_pitch: None,
+            glide_current: 0.0,
+            glide_target: 0.0,
+            glide_step: 0.0,
+
             // EQ Structs
             bands: Arc::new(Mutex::new([
                 biquad_filters::Biquad::new(44100.0, 800.0, 0.0, 0.93, FilterType::LowShelf),
                 biquad_filters::Biquad::new(44100.0, 3000.0, 0.0, 0.93, FilterType::Peak),
                 biquad_filters::Biquad::new(44100.0, 10000.0, 0.0, 0.93, FilterType::HighShelf),
             ])),
+            tilt_bands: [
+                biquad_filters::Biquad::new(44100.0, 1000.0, 0.0, 0.5, FilterType::LowShelf),
+                biquad_filters::Biquad::new(44100.0, 1000.0, 0.0, 0.5, FilterType::HighShelf),
+            ],
+            master_hpf: biquad_filters::Biquad::new(44100.0, MASTER_HPF_OFF, 0.0, 0.707, FilterType::HighPass),
+            bass_mono_lows: [biquad_filters::Biquad::new(44100.0, 120.0, 0.0, 0.707, FilterType::LowPass); 2],
+            space_side_low: biquad_filters::Biquad::new(44100.0, 120.0, 0.0, 0.707, FilterType::LowPass),
+            space_smoother: Smoother::new(SmoothingStyle::Linear(5.0)),
+            bass_mono_highs: [biquad_filters::Biquad::new(44100.0, 120.0, 0.0, 0.707, FilterType::HighPass); 2],
 
             // Compressor
             compressor: Compressor::new(44100.0, 0.5, 0.5, 0.5, 0.5),
+            transient_shaper: TransientShaper::new(44100.0),
+            trance_gate: TranceGate::new(),
+            looper: Looper::new(),
+            resonator: ResonatorBank::new(),
+            tape_stop: TapeStop::new(),
+            dither: Dither::new(),
 
             // Saturation
             saturator: Saturation::new(),
 
             // Delay
             delay: Delay::new(44100.0, 138.0, DelaySnapValues::Quarter, 0.5),
+            delay_2: Delay::new(44100.0, 138.0, DelaySnapValues::EighthD, 0.5),
 
             // Reverb
             reverb: [
@@ -334,6 +540,7 @@ impl Default for Actuate {
                 SimpleSpaceReverb::new(44100.0, 1.0, 0.76, 0.5),
                 SimpleSpaceReverb::new(44100.0, 1.0, 0.76, 0.5),
             ],
+            reverb_predelay: PreDelay::new(),
 
             // Buffer Modulator
             buffermod: BufferModulator::new(44100.0, 0.5, 10.0),
@@ -350,6 +557,13 @@ impl Default for Actuate {
             // Limiter
             limiter: StereoLimiter::new(0.5, 0.5),
 
+            sat_oversampler: Oversampler::new(),
+            limiter_oversampler: Oversampler::new(),
+            reported_latency: 0,
+
+            // Crossfades for the FX toggles
+            fx_bypass: FxBypass::new(),
+
             // Preset browser stuff
             filter_acid: Arc::new(AtomicBool::new(false)),
             filter_analog: Arc::new(AtomicBool::new(false)),
@@ -396,6 +610,66 @@ pub struct ActuateParams {
     pub master_level: FloatParam,
     #[id = "Max Voices"]
     pub voice_limit: IntParam,
+    #[id = "humanize"]
+    pub humanize: FloatParam,
+    #[id = "osc_quality"]
+    pub osc_quality: EnumParam<Oscillator::OscQuality>,
+    #[id = "process_input"]
+    pub process_input: BoolParam,
+    #[id = "release_vel_amount"]
+    pub release_vel_amount: FloatParam,
+    #[id = "mono_mode"]
+    pub mono_mode: EnumParam<MonoMode>,
+    #[id = "voice_steal_mode"]
+    pub voice_steal_mode: EnumParam<VoiceStealMode>,
+    #[id = "live_waveform_switch"]
+    pub live_waveform_switch: BoolParam,
+    #[id = "env_time_range"]
+    pub env_time_range: EnumParam<EnvTimeRange>,
+    #[id = "glide_time"]
+    pub glide_time: FloatParam,
+    #[id = "global_phase_reset"]
+    pub global_phase_reset: BoolParam,
+    #[id = "voice_spread"]
+    pub voice_spread: FloatParam,
+    #[id = "tuning_system"]
+    pub tuning_system: EnumParam<TuningSystem>,
+    #[id = "tuning_root"]
+    pub tuning_root: IntParam,
+    #[id = "reference_pitch"]
+    pub reference_pitch: FloatParam,
+    #[id = "note_offset_cents_0"]
+    pub note_offset_cents_0: FloatParam,
+    #[id = "note_offset_cents_1"]
+    pub note_offset_cents_1: FloatParam,
+    #[id = "note_offset_cents_2"]
+    pub note_offset_cents_2: FloatParam,
+    #[id = "note_offset_cents_3"]
+    pub note_offset_cents_3: FloatParam,
+    #[id = "note_offset_cents_4"]
+    pub note_offset_cents_4: FloatParam,
+    #[id = "note_offset_cents_5"]
+    pub note_offset_cents_5: FloatParam,
+    #[id = "note_offset_cents_6"]
+    pub note_offset_cents_6: FloatParam,
+    #[id = "note_offset_cents_7"]
+    pub note_offset_cents_7: FloatParam,
+    #[id = "note_offset_cents_8"]
+    pub note_offset_cents_8: FloatParam,
+    #[id = "note_offset_cents_9"]
+    pub note_offset_cents_9: FloatParam,
+    #[id = "note_offset_cents_10"]
+    pub note_offset_cents_10: FloatParam,
+    #[id = "note_offset_cents_11"]
+    pub note_offset_cents_11: FloatParam,
+    #[id = "use_manual_bpm"]
+    pub use_manual_bpm: BoolParam,
+    #[id = "manual_bpm"]
+    pub manual_bpm: FloatParam,
+    #[id = "midi_program_change"]
+    pub midi_program_change: BoolParam,
+    #[id = "eco_mode"]
+    pub eco_mode: BoolParam,
 
     // This audio module is what switches between functions for generators in the synth
     #[id = "audio_module_1_type"]
@@ -408,10 +682,29 @@ pub struct ActuateParams {
     // Audio Module Gains
     #[id = "audio_module_1_level"]
     pub audio_module_1_level: FloatParam,
+    // Mixing helpers, not saved in presets. Any soloed modules play together and mute beats solo
+    #[id = "audio_module_1_mute"]
+    pub audio_module_1_mute: BoolParam,
+    #[id = "audio_module_1_solo"]
+    pub audio_module_1_solo: BoolParam,
+    #[id = "audio_module_1_phase_invert"]
+    pub audio_module_1_phase_invert: BoolParam,
     #[id = "audio_module_2_level"]
     pub audio_module_2_level: FloatParam,
+    #[id = "audio_module_2_mute"]
+    pub audio_module_2_mute: BoolParam,
+    #[id = "audio_module_2_solo"]
+    pub audio_module_2_solo: BoolParam,
+    #[id = "audio_module_2_phase_invert"]
+    pub audio_module_2_phase_invert: BoolParam,
     #[id = "audio_module_3_level"]
     pub audio_module_3_level: FloatParam,
+    #[id = "audio_module_3_mute"]
+    pub audio_module_3_mute: BoolParam,
+    #[id = "audio_module_3_solo"]
+    pub audio_module_3_solo: BoolParam,
+    #[id = "audio_module_3_phase_invert"]
+    pub audio_module_3_phase_invert: BoolParam,
 
     // Audio Module Filter Routing
     #[id = "audio_module_1_routing"]
@@ -426,6 +719,14 @@ pub struct ActuateParams {
     pub filter_routing: EnumParam<FilterRouting>,
     #[id = "filter_cutoff_link"]
     pub filter_cutoff_link: BoolParam,
+    #[id = "filter_res_link"]
+    pub filter_res_link: BoolParam,
+    #[id = "filter_env_link"]
+    pub filter_env_link: BoolParam,
+    #[id = "paraphonic"]
+    pub paraphonic: BoolParam,
+    #[id = "filter_auto_gain"]
+    pub filter_auto_gain: BoolParam,
 
     // Controls for when audio_module_1_type is Osc
     #[id = "osc_1_octave"]
@@ -434,6 +735,10 @@ pub struct ActuateParams {
     pub osc_1_semitones: IntParam,
     #[id = "osc_1_detune"]
     pub osc_1_detune: FloatParam,
+    #[id = "osc_1_detune_hz"]
+    pub osc_1_detune_hz: FloatParam,
+    #[id = "osc_1_punch"]
+    pub osc_1_punch: FloatParam,
     #[id = "osc_1_attack"]
     pub osc_1_attack: FloatParam,
     #[id = "osc_1_decay"]
@@ -450,12 +755,37 @@ pub struct ActuateParams {
     pub osc_1_dec_curve: EnumParam<Oscillator::SmoothStyle>,
     #[id = "osc_1_rel_curve"]
     pub osc_1_rel_curve: EnumParam<Oscillator::SmoothStyle>,
+    #[id = "osc_1_env_keyscale"]
+    pub osc_1_env_keyscale: FloatParam,
     #[id = "osc_1_unison"]
     pub osc_1_unison: IntParam,
     #[id = "osc_1_unison_detune"]
     pub osc_1_unison_detune: FloatParam,
+    #[id = "osc_1_unison_blend"]
+    pub osc_1_unison_blend: FloatParam,
     #[id = "osc_1_stereo"]
     pub osc_1_stereo: FloatParam,
+    #[id = "osc_1_super"]
+    pub osc_1_super: FloatParam,
+    #[id = "osc_1_type_b"]
+    pub osc_1_type_b: EnumParam<AudioModuleType>,
+    #[id = "osc_1_morph"]
+    pub osc_1_morph: FloatParam,
+    #[id = "osc_1_shape"]
+    pub osc_1_shape: EnumParam<Oscillator::OscShape>,
+    #[id = "osc_1_shape_amount"]
+    pub osc_1_shape_amount: FloatParam,
+    #[id = "osc_1_warmth"]
+    pub osc_1_warmth: FloatParam,
+    // Organ style octave drawbars, Root at full with the rest off is a plain oscillator
+    #[id = "osc_1_drawbar_sub"]
+    pub osc_1_drawbar_sub: FloatParam,
+    #[id = "osc_1_drawbar_root"]
+    pub osc_1_drawbar_root: FloatParam,
+    #[id = "osc_1_drawbar_oct"]
+    pub osc_1_drawbar_oct: FloatParam,
+    #[id = "osc_1_drawbar_oct2"]
+    pub osc_1_drawbar_oct2: FloatParam,
 
     // Controls for when audio_module_2_type is Osc
     #[id = "osc_2_octave"]
@@ -464,6 +794,10 @@ pub struct ActuateParams {
     pub osc_2_semitones: IntParam,
     #[id = "osc_2_detune"]
     pub osc_2_detune: FloatParam,
+    #[id = "osc_2_detune_hz"]
+    pub osc_2_detune_hz: FloatParam,
+    #[id = "osc_2_punch"]
+    pub osc_2_punch: FloatParam,
     #[id = "osc_2_attack"]
     pub osc_2_attack: FloatParam,
     #[id = "osc_2_decay"]
@@ -480,12 +814,36 @@ pub struct ActuateParams {
     pub osc_2_dec_curve: EnumParam<Oscillator::SmoothStyle>,
     #[id = "osc_2_rel_curve"]
     pub osc_2_rel_curve: EnumParam<Oscillator::SmoothStyle>,
+    #[id = "osc_2_env_keyscale"]
+    pub osc_2_env_keyscale: FloatParam,
     #[id = "osc_2_unison"]
     pub osc_2_unison: IntParam,
     #[id = "osc_2_unison_detune"]
     pub osc_2_unison_detune: FloatParam,
+    #[id = "osc_2_unison_blend"]
+    pub osc_2_unison_blend: FloatParam,
     #[id = "osc_2_stereo"]
     pub osc_2_stereo: FloatParam,
+    #[id = "osc_2_super"]
+    pub osc_2_super: FloatParam,
+    #[id = "osc_2_type_b"]
+    pub osc_2_type_b: EnumParam<AudioModuleType>,
+    #[id = "osc_2_morph"]
+    pub osc_2_morph: FloatParam,
+    #[id = "osc_2_shape"]
+    pub osc_2_shape: EnumParam<Oscillator::OscShape>,
+    #[id = "osc_2_shape_amount"]
+    pub osc_2_shape_amount: FloatParam,
+    #[id = "osc_2_warmth"]
+    pub osc_2_warmth: FloatParam,
+    #[id = "osc_2_drawbar_sub"]
+    pub osc_2_drawbar_sub: FloatParam,
+    #[id = "osc_2_drawbar_root"]
+    pub osc_2_drawbar_root: FloatParam,
+    #[id = "osc_2_drawbar_oct"]
+    pub osc_2_drawbar_oct: FloatParam,
+    #[id = "osc_2_drawbar_oct2"]
+    pub osc_2_drawbar_oct2: FloatParam,
 
     // Controls for when audio_module_3_type is Osc
     #[id = "osc_3_octave"]
@@ -494,6 +852,10 @@ pub struct ActuateParams {
     pub osc_3_semitones: IntParam,
     #[id = "osc_3_detune"]
     pub osc_3_detune: FloatParam,
+    #[id = "osc_3_detune_hz"]
+    pub osc_3_detune_hz: FloatParam,
+    #[id = "osc_3_punch"]
+    pub osc_3_punch: FloatParam,
     #[id = "osc_3_attack"]
     pub osc_3_attack: FloatParam,
     #[id = "osc_3_decay"]
@@ -510,13 +872,37 @@ pub struct ActuateParams {
     pub osc_3_dec_curve: EnumParam<Oscillator::SmoothStyle>,
     #[id = "osc_3_rel_curve"]
     pub osc_3_rel_curve: EnumParam<Oscillator::SmoothStyle>,
+    #[id = "osc_3_env_keyscale"]
+    pub osc_3_env_keyscale: FloatParam,
 
     #[id = "osc_3_unison"]
     pub osc_3_unison: IntParam,
     #[id = "osc_3_unison_detune"]
     pub osc_3_unison_detune: FloatParam,
+    #[id = "osc_3_unison_blend"]
+    pub osc_3_unison_blend: FloatParam,
     #[id = "osc_3_stereo"]
     pub osc_3_stereo: FloatParam,
+    #[id = "osc_3_super"]
+    pub osc_3_super: FloatParam,
+    #[id = "osc_3_type_b"]
+    pub osc_3_type_b: EnumParam<AudioModuleType>,
+    #[id = "osc_3_morph"]
+    pub osc_3_morph: FloatParam,
+    #[id = "osc_3_shape"]
+    pub osc_3_shape: EnumParam<Oscillator::OscShape>,
+    #[id = "osc_3_shape_amount"]
+    pub osc_3_shape_amount: FloatParam,
+    #[id = "osc_3_warmth"]
+    pub osc_3_warmth: FloatParam,
+    #[id = "osc_3_drawbar_sub"]
+    pub osc_3_drawbar_sub: FloatParam,
+    #[id = "osc_3_drawbar_root"]
+    pub osc_3_drawbar_root: FloatParam,
+    #[id = "osc_3_drawbar_oct"]
+    pub osc_3_drawbar_oct: FloatParam,
+    #[id = "osc_3_drawbar_oct2"]
+    pub osc_3_drawbar_oct2: FloatParam,
 
     // Controls for when audio_module_1_type is Sampler/Granulizer
     #[id = "load_sample_1"]
@@ -525,18 +911,34 @@ pub struct ActuateParams {
     pub loop_sample_1: BoolParam,
     #[id = "single_cycle_1"]
     pub single_cycle_1: BoolParam,
+    #[id = "sample_normalize_1"]
+    pub sample_normalize_1: BoolParam,
+    #[id = "freeze_sample_1"]
+    pub freeze_sample_1: BoolParam,
     #[id = "restretch_1"]
     pub restretch_1: BoolParam,
+    #[id = "sample_root_1"]
+    pub sample_root_1: IntParam,
     #[id = "grain_hold_1"]
     grain_hold_1: IntParam,
     #[id = "grain_gap_1"]
     grain_gap_1: IntParam,
+    #[id = "grain_spray_1"]
+    pub grain_spray_1: FloatParam,
     #[id = "start_position_1"]
     start_position_1: FloatParam,
     #[id = "end_position_1"]
     end_position_1: FloatParam,
+    #[id = "stretch_factor_1"]
+    pub stretch_factor_1: FloatParam,
+    #[id = "loop_xfade_1"]
+    pub loop_xfade_1: FloatParam,
+    #[id = "slice_count_1"]
+    pub slice_count_1: IntParam,
     #[id = "grain_crossfade_1"]
     grain_crossfade_1: IntParam,
+    #[id = "grain_window_1"]
+    grain_window_1: EnumParam<GrainWindow>,
 
     // Controls for when audio_module_2_type is Sampler/Granulizer
     #[id = "load_sample_2"]
@@ -545,18 +947,34 @@ pub struct ActuateParams {
     pub loop_sample_2: BoolParam,
     #[id = "single_cycle_2"]
     pub single_cycle_2: BoolParam,
+    #[id = "sample_normalize_2"]
+    pub sample_normalize_2: BoolParam,
+    #[id = "freeze_sample_2"]
+    pub freeze_sample_2: BoolParam,
     #[id = "restretch_2"]
     pub restretch_2: BoolParam,
+    #[id = "sample_root_2"]
+    pub sample_root_2: IntParam,
     #[id = "grain_hold_2"]
     grain_hold_2: IntParam,
     #[id = "grain_gap_2"]
     grain_gap_2: IntParam,
+    #[id = "grain_spray_2"]
+    pub grain_spray_2: FloatParam,
     #[id = "start_position_2"]
     start_position_2: FloatParam,
     #[id = "end_position_2"]
     end_position_2: FloatParam,
+    #[id = "stretch_factor_2"]
+    pub stretch_factor_2: FloatParam,
+    #[id = "loop_xfade_2"]
+    pub loop_xfade_2: FloatParam,
+    #[id = "slice_count_2"]
+    pub slice_count_2: IntParam,
     #[id = "grain_crossfade_2"]
     grain_crossfade_2: IntParam,
+    #[id = "grain_window_2"]
+    grain_window_2: EnumParam<GrainWindow>,
 
     // Controls for when audio_module_3_type is Sampler/Granulizer
     #[id = "load_sample_3"]
@@ -565,18 +983,37 @@ pub struct ActuateParams {
     pub loop_sample_3: BoolParam,
     #[id = "single_cycle_3"]
     pub single_cycle_3: BoolParam,
+    #[id = "sample_normalize_3"]
+    pub sample_normalize_3: BoolParam,
+    #[id = "freeze_sample_3"]
+    pub freeze_sample_3: BoolParam,
+    // Shared by all modules and left out of presets, it only matters at the moment Freeze is pressed
+    #[id = "freeze_time"]
+    pub freeze_time: FloatParam,
     #[id = "restretch_3"]
     pub restretch_3: BoolParam,
+    #[id = "sample_root_3"]
+    pub sample_root_3: IntParam,
     #[id = "grain_hold_3"]
     grain_hold_3: IntParam,
     #[id = "grain_gap_3"]
     grain_gap_3: IntParam,
+    #[id = "grain_spray_3"]
+    pub grain_spray_3: FloatParam,
     #[id = "start_position_3"]
     start_position_3: FloatParam,
     #[id = "end_position_3"]
     end_position_3: FloatParam,
+    #[id = "stretch_factor_3"]
+    pub stretch_factor_3: FloatParam,
+    #[id = "loop_xfade_3"]
+    pub loop_xfade_3: FloatParam,
+    #[id = "slice_count_3"]
+    pub slice_count_3: IntParam,
     #[id = "grain_crossfade_3"]
     grain_crossfade_3: IntParam,
+    #[id = "grain_window_3"]
+    grain_window_3: EnumParam<GrainWindow>,
 
     // Additive Data
     #[id = "additive_amp_1_0"]
@@ -682,6 +1119,8 @@ pub struct ActuateParams {
     // Filters
     #[id = "filter_wet"]
     pub filter_wet: FloatParam,
+    #[id = "filter_drive"]
+    pub filter_drive: FloatParam,
     #[id = "filter_cutoff"]
     pub filter_cutoff: FloatParam,
     #[id = "filter_resonance"]
@@ -696,6 +1135,8 @@ pub struct ActuateParams {
     pub filter_bp_amount: FloatParam,
     #[id = "filter_env_peak"]
     pub filter_env_peak: FloatParam,
+    #[id = "filter_env_invert_1"]
+    pub filter_env_invert_1: BoolParam,
     #[id = "filter_env_attack"]
     pub filter_env_attack: FloatParam,
     #[id = "filter_env_decay"]
@@ -710,6 +1151,10 @@ pub struct ActuateParams {
     pub filter_env_dec_curve: EnumParam<Oscillator::SmoothStyle>,
     #[id = "filter_env_rel_curve"]
     pub filter_env_rel_curve: EnumParam<Oscillator::SmoothStyle>,
+    #[id = "filter_env_loop"]
+    pub filter_env_loop: BoolParam,
+    #[id = "filter_env_loop_rate"]
+    pub filter_env_loop_rate: EnumParam<LFOController::LFOSnapValues>,
     #[id = "filter_alg_type"]
     pub filter_alg_type: EnumParam<FilterAlgorithms>,
     #[id = "tilt_filter_type"]
@@ -719,8 +1164,16 @@ pub struct ActuateParams {
 
     #[id = "filter_wet_2"]
     pub filter_wet_2: FloatParam,
+    #[id = "filter_drive_2"]
+    pub filter_drive_2: FloatParam,
+    #[id = "filter_stereo_offset_1"]
+    pub filter_stereo_offset_1: FloatParam,
+    #[id = "filter_stereo_offset_2"]
+    pub filter_stereo_offset_2: FloatParam,
     #[id = "filter_cutoff_2"]
     pub filter_cutoff_2: FloatParam,
+    #[id = "filter_cutoff_smooth"]
+    pub filter_cutoff_smooth: FloatParam,
     #[id = "filter_resonance_2"]
     pub filter_resonance_2: FloatParam,
     #[id = "filter_res_type_2"]
@@ -733,6 +1186,8 @@ pub struct ActuateParams {
     pub filter_bp_amount_2: FloatParam,
     #[id = "filter_env_peak_2"]
     pub filter_env_peak_2: FloatParam,
+    #[id = "filter_env_invert_2"]
+    pub filter_env_invert_2: BoolParam,
     #[id = "filter_env_attack_2"]
     pub filter_env_attack_2: FloatParam,
     #[id = "filter_env_decay_2"]
@@ -816,6 +1271,12 @@ pub struct ActuateParams {
     pub lfo2_sync: BoolParam,
     #[id = "lfo3_sync"]
     pub lfo3_sync: BoolParam,
+    #[id = "lfo1_gate"]
+    pub lfo1_gate: BoolParam,
+    #[id = "lfo2_gate"]
+    pub lfo2_gate: BoolParam,
+    #[id = "lfo3_gate"]
+    pub lfo3_gate: BoolParam,
     #[id = "lfo1_freq"]
     pub lfo1_freq: FloatParam,
     #[id = "lfo2_freq"]
@@ -840,6 +1301,12 @@ pub struct ActuateParams {
     pub lfo2_phase: FloatParam,
     #[id = "lfo3_phase"]
     pub lfo3_phase: FloatParam,
+    #[id = "lfo1_depth"]
+    pub lfo1_depth: FloatParam,
+    #[id = "lfo2_depth"]
+    pub lfo2_depth: FloatParam,
+    #[id = "lfo3_depth"]
+    pub lfo3_depth: FloatParam,
 
     // Mod knobs
     #[id = "mod_amount_knob_1"]
@@ -850,6 +1317,14 @@ pub struct ActuateParams {
     pub mod_amount_knob_3: FloatParam,
     #[id = "mod_amount_knob_4"]
     pub mod_amount_knob_4: FloatParam,
+    #[id = "mod_amount_knob_5"]
+    pub mod_amount_knob_5: FloatParam,
+    #[id = "mod_amount_knob_6"]
+    pub mod_amount_knob_6: FloatParam,
+    #[id = "mod_amount_knob_7"]
+    pub mod_amount_knob_7: FloatParam,
+    #[id = "mod_amount_knob_8"]
+    pub mod_amount_knob_8: FloatParam,
     #[id = "mod_source_1"]
     pub mod_source_1: EnumParam<ModulationSource>,
     #[id = "mod_source_2"]
@@ -858,6 +1333,14 @@ pub struct ActuateParams {
     pub mod_source_3: EnumParam<ModulationSource>,
     #[id = "mod_source_4"]
     pub mod_source_4: EnumParam<ModulationSource>,
+    #[id = "mod_source_5"]
+    pub mod_source_5: EnumParam<ModulationSource>,
+    #[id = "mod_source_6"]
+    pub mod_source_6: EnumParam<ModulationSource>,
+    #[id = "mod_source_7"]
+    pub mod_source_7: EnumParam<ModulationSource>,
+    #[id = "mod_source_8"]
+    pub mod_source_8: EnumParam<ModulationSource>,
     #[id = "mod_destination_1"]
     pub mod_destination_1: EnumParam<ModulationDestination>,
     #[id = "mod_destination_2"]
@@ -866,6 +1349,18 @@ pub struct ActuateParams {
     pub mod_destination_3: EnumParam<ModulationDestination>,
     #[id = "mod_destination_4"]
     pub mod_destination_4: EnumParam<ModulationDestination>,
+    #[id = "mod_destination_5"]
+    pub mod_destination_5: EnumParam<ModulationDestination>,
+    #[id = "mod_destination_6"]
+    pub mod_destination_6: EnumParam<ModulationDestination>,
+    #[id = "mod_destination_7"]
+    pub mod_destination_7: EnumParam<ModulationDestination>,
+    #[id = "mod_destination_8"]
+    pub mod_destination_8: EnumParam<ModulationDestination>,
+    #[id = "pitch_mod_quantize"]
+    pub pitch_mod_quantize: BoolParam,
+    #[id = "pitch_mod_scale"]
+    pub pitch_mod_scale: EnumParam<PitchQuantizeScale>,
 
     // EQ Params
     #[id = "pre_use_eq"]
@@ -885,9 +1380,40 @@ pub struct ActuateParams {
     #[id = "pre_high_gain"]
     pub pre_high_gain: FloatParam,
 
+    #[id = "master_tilt"]
+    pub master_tilt: FloatParam,
+    #[id = "master_hpf"]
+    pub master_hpf: FloatParam,
+    #[id = "use_bass_mono"]
+    pub use_bass_mono: BoolParam,
+    #[id = "elliptical_freq"]
+    pub elliptical_freq: FloatParam,
+    #[id = "space"]
+    pub space: FloatParam,
+    // Held like a button, not saved with presets
+    #[id = "tape_stop"]
+    pub tape_stop: BoolParam,
+    #[id = "tape_stop_time"]
+    pub tape_stop_time: FloatParam,
+    // Render settings, not saved with presets
+    #[id = "use_dither"]
+    pub use_dither: BoolParam,
+    #[id = "dither_shaping"]
+    pub dither_shaping: BoolParam,
+
     // FX
     #[id = "use_fx"]
     pub use_fx: BoolParam,
+    // Send/return use, left out of presets so loading a sound on a return track doesn't bring the dry back
+    #[id = "dry_kill"]
+    pub dry_kill: BoolParam,
+
+    #[id = "use_transient"]
+    pub use_transient: BoolParam,
+    #[id = "transient_attack"]
+    pub transient_attack: FloatParam,
+    #[id = "transient_sustain"]
+    pub transient_sustain: FloatParam,
 
     #[id = "use_compressor"]
     pub use_compressor: BoolParam,
@@ -911,6 +1437,8 @@ pub struct ActuateParams {
     pub sat_amt: FloatParam,
     #[id = "sat_type"]
     pub sat_type: EnumParam<SaturationType>,
+    #[id = "sat_stereo_mode"]
+    pub sat_stereo_mode: EnumParam<SaturationStereoMode>,
 
     #[id = "use_delay"]
     pub use_delay: BoolParam,
@@ -922,6 +1450,20 @@ pub struct ActuateParams {
     pub delay_decay: FloatParam,
     #[id = "delay_type"]
     pub delay_type: EnumParam<DelayType>,
+    #[id = "delay_pitch"]
+    pub delay_pitch: FloatParam,
+    #[id = "use_delay_2"]
+    pub use_delay_2: BoolParam,
+    #[id = "delay_2_amount"]
+    pub delay_2_amount: FloatParam,
+    #[id = "delay_2_time"]
+    pub delay_2_time: EnumParam<DelaySnapValues>,
+    #[id = "delay_2_decay"]
+    pub delay_2_decay: FloatParam,
+    #[id = "delay_2_type"]
+    pub delay_2_type: EnumParam<DelayType>,
+    #[id = "delay_routing"]
+    pub delay_routing: EnumParam<DelayRouting>,
 
     #[id = "use_reverb"]
     pub use_reverb: BoolParam,
@@ -933,6 +1475,12 @@ pub struct ActuateParams {
     pub reverb_size: FloatParam,
     #[id = "reverb_feedback"]
     pub reverb_feedback: FloatParam,
+    #[id = "reverb_predelay"]
+    pub reverb_predelay: FloatParam,
+    #[id = "reverb_diffusion"]
+    pub reverb_diffusion: FloatParam,
+    #[id = "reverb_density"]
+    pub reverb_density: FloatParam,
 
     #[id = "use_phaser"]
     pub use_phaser: BoolParam,
@@ -944,6 +1492,8 @@ pub struct ActuateParams {
     pub phaser_rate: FloatParam,
     #[id = "phaser_feedback"]
     pub phaser_feedback: FloatParam,
+    #[id = "phaser_stereo"]
+    pub phaser_stereo: FloatParam,
 
     #[id = "use_buffermod"]
     pub use_buffermod: BoolParam,
@@ -957,6 +1507,8 @@ pub struct ActuateParams {
     pub buffermod_spread: FloatParam,
     #[id = "buffermod_timing"]
     pub buffermod_timing: FloatParam,
+    #[id = "buffermod_stereo"]
+    pub buffermod_stereo: FloatParam,
 
     #[id = "use_flanger"]
     pub use_flanger: BoolParam,
@@ -968,6 +1520,8 @@ pub struct ActuateParams {
     pub flanger_rate: FloatParam,
     #[id = "flanger_feedback"]
     pub flanger_feedback: FloatParam,
+    #[id = "flanger_stereo"]
+    pub flanger_stereo: FloatParam,
 
     #[id = "use_chorus"]
     pub use_chorus: BoolParam,
@@ -984,6 +1538,42 @@ pub struct ActuateParams {
     pub limiter_threshold: FloatParam,
     #[id = "limiter_knee"]
     pub limiter_knee: FloatParam,
+    #[id = "limiter_ceiling"]
+    pub limiter_ceiling: FloatParam,
+    // Quality setting like dither, not saved with presets
+    #[id = "output_oversample"]
+    pub output_oversample: EnumParam<OversampleFactor>,
+
+    #[id = "use_gate"]
+    pub use_gate: BoolParam,
+    #[id = "gate_rate"]
+    pub gate_rate: EnumParam<LFOController::LFOSnapValues>,
+    #[id = "gate_shape"]
+    pub gate_shape: FloatParam,
+    #[id = "gate_smooth"]
+    pub gate_smooth: FloatParam,
+
+    #[id = "use_resonator"]
+    pub use_resonator: BoolParam,
+    #[id = "resonator_chord"]
+    pub resonator_chord: EnumParam<ResonatorChord>,
+    #[id = "resonator_root"]
+    pub resonator_root: IntParam,
+    #[id = "resonator_decay"]
+    pub resonator_decay: FloatParam,
+    #[id = "resonator_mix"]
+    pub resonator_mix: FloatParam,
+
+    #[id = "use_looper"]
+    pub use_looper: BoolParam,
+    #[id = "looper_length"]
+    pub looper_length: FloatParam,
+    #[id = "looper_feedback"]
+    pub looper_feedback: FloatParam,
+    #[id = "looper_mix"]
+    pub looper_mix: FloatParam,
+    #[id = "looper_pitch"]
+    pub looper_pitch: FloatParam,
 
     // FM
     #[id = "fm_one_to_two"]
@@ -1009,6 +1599,12 @@ pub struct ActuateParams {
     #[id = "fm_release_curve"]
     pub fm_release_curve: EnumParam<Oscillator::SmoothStyle>,
 
+    // Ring Mod
+    #[id = "ring_mod_1x2"]
+    pub ring_mod_1x2: FloatParam,
+    #[id = "ring_mod_2x3"]
+    pub ring_mod_2x3: FloatParam,
+
     // Stereo Algorithm
     #[id = "Stereo Algorithm"]
     pub stereo_algorithm: EnumParam<StereoAlgorithm>,
@@ -1082,14 +1678,25 @@ pub struct ActuateParams {
     pub preset_name_p: Arc<Mutex<String>>,
     #[persist = "preset_info_p"]
     pub preset_info_p: Arc<Mutex<String>>,
+    #[persist = "preset_author_p"]
+    pub preset_author_p: Arc<Mutex<String>>,
+    #[persist = "preset_created_p"]
+    pub preset_created_p: Arc<Mutex<String>>,
+    // MIDI learn bindings from CC number to param id, saved with the plugin state
+    #[persist = "midi_learn_map"]
+    pub midi_learn_map: Arc<Mutex<HashMap<u8, String>>>,
+    // Param id waiting for the next CC, set from the right click menu
+    pub midi_learn_target: Arc<Mutex<Option<String>>>,
 }
 
 // This is where parameters are established and defined as well as the callbacks to share gui/audio process info
 impl ActuateParams {
+    // Params start on the values of init, which is the built in default patch unless the user saved their own
     fn new(
         update_something: Arc<AtomicBool>,
         file_dialog: Arc<AtomicBool>,
         update_current_preset: Arc<AtomicBool>,
+        init: &ActuatePresetV131,
     ) -> Self {
         Self {
             editor_state: EguiState::from_size(WIDTH, HEIGHT),
@@ -1103,18 +1710,190 @@ impl ActuateParams {
                 .with_value_to_string(formatters::v2s_f32_percentage(0))
                 .with_unit("%"),
             voice_limit: IntParam::new("Max Voices", 64, IntRange::Linear { min: 1, max: 512 }),
+            humanize: FloatParam::new("Humanize", init.humanize, FloatRange::Linear { min: 0.0, max: 1.0 })
+                .with_value_to_string(formatters::v2s_f32_percentage(0))
+                .with_unit("%"),
+            osc_quality: EnumParam::new("Osc Quality", init.osc_quality)
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
+            process_input: BoolParam::new("Process Input", false),
+            release_vel_amount: FloatParam::new("Release Vel", init.release_vel_amount, FloatRange::Linear { min: 0.0, max: 1.0 })
+                .with_value_to_string(formatters::v2s_f32_percentage(0))
+                .with_unit("%")
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
+            mono_mode: EnumParam::new("Voice Mode", init.mono_mode),
+            voice_steal_mode: EnumParam::new("Voice Steal", init.voice_steal_mode).with_callback({
+                let update_something = update_something.clone();
+                Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+            }),
+            live_waveform_switch: BoolParam::new("Live Wave Switch", init.live_waveform_switch).with_callback({
+                let update_something = update_something.clone();
+                Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+            }),
+            env_time_range: EnumParam::new("Env Times", init.env_time_range).with_callback({
+                let update_something = update_something.clone();
+                Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+            }),
+            glide_time: FloatParam::new(
+                "Glide",
+                init.glide_time,
+                FloatRange::Skewed { min: 0.0, max: 2000.0, factor: 0.5 },
+            )
+            .with_value_to_string(formatters::v2s_f32_rounded(0))
+            .with_unit(" ms"),
+            global_phase_reset: BoolParam::new("Global Phase Reset", init.global_phase_reset).with_callback({
+                let update_something = update_something.clone();
+                Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+            }),
+            voice_spread: FloatParam::new("Voice Spread", init.voice_spread, FloatRange::Linear { min: 0.0, max: 1.0 })
+                .with_value_to_string(formatters::v2s_f32_percentage(0))
+                .with_unit("%")
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
+            tuning_system: EnumParam::new("Tuning", init.tuning_system).with_callback({
+                let update_something = update_something.clone();
+                Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+            }),
+            tuning_root: IntParam::new("Tuning Root", init.tuning_root, IntRange::Linear { min: 0, max: 11 })
+                .with_value_to_string(Arc::new(|value| NOTE_NAMES[value as usize].to_string()))
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
+            // Where A4 sits, 440 is standard concert pitch
+            reference_pitch: FloatParam::new("A4 Pitch", init.reference_pitch, FloatRange::Linear { min: 430.0, max: 445.0 })
+                .with_step_size(0.1)
+                .with_value_to_string(formatters::v2s_f32_rounded(1))
+                .with_unit(" Hz")
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
+            // Per pitch class detune on top of the Tuning system, all 0 is plain 12-TET
+            note_offset_cents_0: FloatParam::new(format!("{} Cents", NOTE_NAMES[0]), init.note_offset_cents[0], FloatRange::Linear { min: -50.0, max: 50.0 })
+                .with_step_size(0.1)
+                .with_value_to_string(formatters::v2s_f32_rounded(1))
+                .with_unit(" ct")
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
+            note_offset_cents_1: FloatParam::new(format!("{} Cents", NOTE_NAMES[1]), init.note_offset_cents[1], FloatRange::Linear { min: -50.0, max: 50.0 })
+                .with_step_size(0.1)
+                .with_value_to_string(formatters::v2s_f32_rounded(1))
+                .with_unit(" ct")
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
+            note_offset_cents_2: FloatParam::new(format!("{} Cents", NOTE_NAMES[2]), init.note_offset_cents[2], FloatRange::Linear { min: -50.0, max: 50.0 })
+                .with_step_size(0.1)
+                .with_value_to_string(formatters::v2s_f32_rounded(1))
+                .with_unit(" ct")
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
+            note_offset_cents_3: FloatParam::new(format!("{} Cents", NOTE_NAMES[3]), init.note_offset_cents[3], FloatRange::Linear { min: -50.0, max: 50.0 })
+                .with_step_size(0.1)
+                .with_value_to_string(formatters::v2s_f32_rounded(1))
+                .with_unit(" ct")
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
+            note_offset_cents_4: FloatParam::new(format!("{} Cents", NOTE_NAMES[4]), init.note_offset_cents[4], FloatRange::Linear { min: -50.0, max: 50.0 })
+                .with_step_size(0.1)
+                .with_value_to_string(formatters::v2s_f32_rounded(1))
+                .with_unit(" ct")
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
+            note_offset_cents_5: FloatParam::new(format!("{} Cents", NOTE_NAMES[5]), init.note_offset_cents[5], FloatRange::Linear { min: -50.0, max: 50.0 })
+                .with_step_size(0.1)
+                .with_value_to_string(formatters::v2s_f32_rounded(1))
+                .with_unit(" ct")
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
+            note_offset_cents_6: FloatParam::new(format!("{} Cents", NOTE_NAMES[6]), init.note_offset_cents[6], FloatRange::Linear { min: -50.0, max: 50.0 })
+                .with_step_size(0.1)
+                .with_value_to_string(formatters::v2s_f32_rounded(1))
+                .with_unit(" ct")
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
+            note_offset_cents_7: FloatParam::new(format!("{} Cents", NOTE_NAMES[7]), init.note_offset_cents[7], FloatRange::Linear { min: -50.0, max: 50.0 })
+                .with_step_size(0.1)
+                .with_value_to_string(formatters::v2s_f32_rounded(1))
+                .with_unit(" ct")
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
+            note_offset_cents_8: FloatParam::new(format!("{} Cents", NOTE_NAMES[8]), init.note_offset_cents[8], FloatRange::Linear { min: -50.0, max: 50.0 })
+                .with_step_size(0.1)
+                .with_value_to_string(formatters::v2s_f32_rounded(1))
+                .with_unit(" ct")
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
+            note_offset_cents_9: FloatParam::new(format!("{} Cents", NOTE_NAMES[9]), init.note_offset_cents[9], FloatRange::Linear { min: -50.0, max: 50.0 })
+                .with_step_size(0.1)
+                .with_value_to_string(formatters::v2s_f32_rounded(1))
+                .with_unit(" ct")
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
+            note_offset_cents_10: FloatParam::new(format!("{} Cents", NOTE_NAMES[10]), init.note_offset_cents[10], FloatRange::Linear { min: -50.0, max: 50.0 })
+                .with_step_size(0.1)
+                .with_value_to_string(formatters::v2s_f32_rounded(1))
+                .with_unit(" ct")
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
+            note_offset_cents_11: FloatParam::new(format!("{} Cents", NOTE_NAMES[11]), init.note_offset_cents[11], FloatRange::Linear { min: -50.0, max: 50.0 })
+                .with_step_size(0.1)
+                .with_value_to_string(formatters::v2s_f32_rounded(1))
+                .with_unit(" ct")
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
+            use_manual_bpm: BoolParam::new("Manual BPM", false),
+            manual_bpm: FloatParam::new("BPM", 120.0, FloatRange::Linear { min: 20.0, max: 300.0 })
+                .with_step_size(0.1)
+                .with_value_to_string(formatters::v2s_f32_rounded(1)),
+            midi_program_change: BoolParam::new("Program Change", false),
+            eco_mode: BoolParam::new("Eco Mode", false).with_callback({
+                let update_something = update_something.clone();
+                Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+            }),
 
-            audio_module_1_type: EnumParam::new("Type", AudioModuleType::Sine)
+            audio_module_1_type: EnumParam::new("Type", init.mod1_audio_module_type)
                 .with_callback({
                 let update_something = update_something.clone();
                 Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
                 }),
-            audio_module_2_type: EnumParam::new("Type", AudioModuleType::Sine)
+            audio_module_2_type: EnumParam::new("Type", init.mod2_audio_module_type)
                 .with_callback({
                     let update_something = update_something.clone();
                     Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
                 }),
-            audio_module_3_type: EnumParam::new("Type", AudioModuleType::Sine)
+            audio_module_3_type: EnumParam::new("Type", init.mod3_audio_module_type)
                 .with_callback({
                     let update_something = update_something.clone();
                     Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
@@ -1122,59 +1901,68 @@ impl ActuateParams {
 
             audio_module_1_level: FloatParam::new(
                 "Level",
-                0.1,
+                init.mod1_audio_module_level,
                 FloatRange::Linear { min: 0.0, max: 1.0 },
             )
             .with_value_to_string(formatters::v2s_f32_percentage(0))
             .with_unit("%"),
+            audio_module_1_mute: BoolParam::new("Mute", false).hide(),
+            audio_module_1_solo: BoolParam::new("Solo", false).hide(),
+            audio_module_1_phase_invert: BoolParam::new("Inv", init.mod1_phase_invert),
             audio_module_2_level: FloatParam::new(
                 "Level",
-                0.1,
+                init.mod2_audio_module_level,
                 FloatRange::Linear { min: 0.0, max: 1.0 },
             )
             .with_value_to_string(formatters::v2s_f32_percentage(0))
             .with_unit("%"),
+            audio_module_2_mute: BoolParam::new("Mute", false).hide(),
+            audio_module_2_solo: BoolParam::new("Solo", false).hide(),
+            audio_module_2_phase_invert: BoolParam::new("Inv", init.mod2_phase_invert),
             audio_module_3_level: FloatParam::new(
                 "Level",
-                0.1,
+                init.mod3_audio_module_level,
                 FloatRange::Linear { min: 0.0, max: 1.0 },
             )
             .with_value_to_string(formatters::v2s_f32_percentage(0))
             .with_unit("%"),
+            audio_module_3_mute: BoolParam::new("Mute", false).hide(),
+            audio_module_3_solo: BoolParam::new("Solo", false).hide(),
+            audio_module_3_phase_invert: BoolParam::new("Inv", init.mod3_phase_invert),
 
-            audio_module_1_routing: EnumParam::new("Routing", AMFilterRouting::Filter1).with_callback({
+            audio_module_1_routing: EnumParam::new("Routing", init.mod1_audio_module_routing.clone()).with_callback({
                     let update_something = update_something.clone();
                     Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
                 }),
-            audio_module_2_routing: EnumParam::new("Routing", AMFilterRouting::Filter1).with_callback({
+            audio_module_2_routing: EnumParam::new("Routing", init.mod2_audio_module_routing.clone()).with_callback({
                     let update_something = update_something.clone();
                     Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
                 }),
-            audio_module_3_routing: EnumParam::new("Routing", AMFilterRouting::Filter1).with_callback({
+            audio_module_3_routing: EnumParam::new("Routing", init.mod3_audio_module_routing.clone()).with_callback({
                     let update_something = update_something.clone();
                     Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
                 }),
 
-            filter_routing: EnumParam::new("Filter Routing", FilterRouting::Parallel).with_callback({
+            filter_routing: EnumParam::new("Filter Routing", init.filter_routing.clone()).with_callback({
                 let update_something = update_something.clone();
                 Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
             }),
 
             // Oscillators
             ////////////////////////////////////////////////////////////////////////////////////
-            osc_1_octave: IntParam::new("Octave", 0, IntRange::Linear { min: -2, max: 2 })
+            osc_1_octave: IntParam::new("Octave", init.mod1_osc_octave, IntRange::Linear { min: -2, max: 2 })
                 .with_callback({
                     let update_something = update_something.clone();
                     Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
                 }),
-            osc_1_semitones: IntParam::new("Semi", 0, IntRange::Linear { min: -11, max: 11 })
+            osc_1_semitones: IntParam::new("Semi", init.mod1_osc_semitones, IntRange::Linear { min: -11, max: 11 })
                 .with_callback({
                     let update_something = update_something.clone();
                     Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
                 }),
             osc_1_detune: FloatParam::new(
                 "Fine",
-                0.0,
+                init.mod1_osc_detune,
                 FloatRange::Linear {
                     min: -0.999,
                     max: 0.999,
@@ -1186,9 +1974,24 @@ impl ActuateParams {
                 let update_something = update_something.clone();
                 Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
             }),
+            osc_1_detune_hz: FloatParam::new("Detune Hz", init.mod1_osc_detune_hz, FloatRange::Linear { min: -20.0, max: 20.0 })
+                .with_step_size(0.01)
+                .with_value_to_string(formatters::v2s_f32_rounded(2))
+                .with_unit(" Hz")
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
+            osc_1_punch: FloatParam::new("Punch", init.mod1_osc_punch, FloatRange::Linear { min: 0.0, max: 1.0 })
+                .with_unit("%")
+                .with_value_to_string(formatters::v2s_f32_percentage(0))
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
             osc_1_attack: FloatParam::new(
                 "Attack",
-                0.0001,
+                init.mod1_osc_attack,
                 FloatRange::Skewed {
                     min: 0.0001,
                     max: 1999.9,
@@ -1204,7 +2007,7 @@ impl ActuateParams {
             }),
             osc_1_decay: FloatParam::new(
                 "Decay",
-                0.0001,
+                init.mod1_osc_decay,
                 FloatRange::Skewed {
                     min: 0.0001,
                     max: 1999.9,
@@ -1220,7 +2023,7 @@ impl ActuateParams {
             }),
             osc_1_sustain: FloatParam::new(
                 "Sustain",
-                1999.9,
+                init.mod1_osc_sustain,
                 FloatRange::Linear {
                     min: 0.0001,
                     max: 1999.9,
@@ -1235,7 +2038,7 @@ impl ActuateParams {
             }),
             osc_1_release: FloatParam::new(
                 "Release",
-                5.0,
+                init.mod1_osc_release,
                 FloatRange::Skewed {
                     min: 0.0001,
                     max: 1999.9,
@@ -1249,33 +2052,40 @@ impl ActuateParams {
                 let update_something = update_something.clone();
                 Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
             }),
-            osc_1_retrigger: EnumParam::new("Retrig", RetriggerStyle::Retrigger).with_callback({
+            osc_1_retrigger: EnumParam::new("Retrig", init.mod1_osc_retrigger).with_callback({
                 let update_something = update_something.clone();
                 Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
             }),
-            osc_1_atk_curve: EnumParam::new("Atk Curve", Oscillator::SmoothStyle::Linear)
+            osc_1_atk_curve: EnumParam::new("Atk Curve", init.mod1_osc_atk_curve)
                 .with_callback({
                     let update_something = update_something.clone();
                     Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
                 }),
-            osc_1_dec_curve: EnumParam::new("Dec Curve", Oscillator::SmoothStyle::Linear)
+            osc_1_dec_curve: EnumParam::new("Dec Curve", init.mod1_osc_dec_curve)
                 .with_callback({
                     let update_something = update_something.clone();
                     Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
                 }),
-            osc_1_rel_curve: EnumParam::new("Rel Curve", Oscillator::SmoothStyle::Linear)
+            osc_1_rel_curve: EnumParam::new("Rel Curve", init.mod1_osc_rel_curve)
                 .with_callback({
                     let update_something = update_something.clone();
                     Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
                 }),
-            osc_1_unison: IntParam::new("Multiply", 1, IntRange::Linear { min: 1, max: 9 })
+            osc_1_env_keyscale: FloatParam::new("Key Scale", init.mod1_osc_env_keyscale, FloatRange::Linear { min: 0.0, max: 1.0 })
+                .with_value_to_string(formatters::v2s_f32_percentage(0))
+                .with_unit("%")
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
+            osc_1_unison: IntParam::new("Multiply", init.mod1_osc_unison, IntRange::Linear { min: 1, max: 9 })
                 .with_callback({
                     let update_something = update_something.clone();
                     Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
                 }),
             osc_1_unison_detune: FloatParam::new(
                 "MDetune",
-                0.0,
+                init.mod1_osc_unison_detune,
                 FloatRange::Linear { min: 0.0, max: 1.0 },
             )
             .with_step_size(0.0001)
@@ -1284,26 +2094,91 @@ impl ActuateParams {
                 let update_something = update_something.clone();
                 Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
             }),
-            osc_1_stereo: FloatParam::new("Stereo", 1.0, FloatRange::Linear { min: 0.0, max: 2.0 })
+            osc_1_unison_blend: FloatParam::new("Blend", init.mod1_osc_unison_blend, FloatRange::Linear { min: 0.0, max: 1.0 })
+                .with_value_to_string(formatters::v2s_f32_percentage(0))
+                .with_unit("%")
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
+            osc_1_stereo: FloatParam::new("Stereo", init.mod1_osc_stereo, FloatRange::Linear { min: 0.0, max: 2.0 })
+                .with_value_to_string(formatters::v2s_f32_rounded(2))
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
+            osc_1_super: FloatParam::new("Super", init.mod1_osc_super, FloatRange::Linear { min: 0.0, max: 1.0 })
+                .with_value_to_string(formatters::v2s_f32_rounded(2))
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
+            osc_1_type_b: EnumParam::new("Wave B", init.mod1_osc_type_b)
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
+            osc_1_morph: FloatParam::new("Morph", init.mod1_osc_morph, FloatRange::Linear { min: 0.0, max: 1.0 })
+                .with_value_to_string(formatters::v2s_f32_rounded(2))
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
+            osc_1_shape: EnumParam::new("Shaper", init.mod1_osc_shape)
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
+            osc_1_shape_amount: FloatParam::new("Shape Amt", init.mod1_osc_shape_amount, FloatRange::Linear { min: 0.0, max: 1.0 })
+                .with_value_to_string(formatters::v2s_f32_rounded(2))
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
+            osc_1_warmth: FloatParam::new("Warmth", init.mod1_osc_warmth, FloatRange::Linear { min: 0.0, max: 1.0 })
+                .with_value_to_string(formatters::v2s_f32_rounded(2))
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
+            osc_1_drawbar_sub: FloatParam::new("-1 Oct", init.mod1_osc_drawbar_sub, FloatRange::Linear { min: 0.0, max: 1.0 })
+                .with_value_to_string(formatters::v2s_f32_rounded(2))
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
+            osc_1_drawbar_root: FloatParam::new("Root", init.mod1_osc_drawbar_root, FloatRange::Linear { min: 0.0, max: 1.0 })
+                .with_value_to_string(formatters::v2s_f32_rounded(2))
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
+            osc_1_drawbar_oct: FloatParam::new("+1 Oct", init.mod1_osc_drawbar_oct, FloatRange::Linear { min: 0.0, max: 1.0 })
+                .with_value_to_string(formatters::v2s_f32_rounded(2))
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
+            osc_1_drawbar_oct2: FloatParam::new("+2 Oct", init.mod1_osc_drawbar_oct2, FloatRange::Linear { min: 0.0, max: 1.0 })
                 .with_value_to_string(formatters::v2s_f32_rounded(2))
                 .with_callback({
                     let update_something = update_something.clone();
                     Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
                 }),
 
-            osc_2_octave: IntParam::new("Octave", 0, IntRange::Linear { min: -2, max: 2 })
+            osc_2_octave: IntParam::new("Octave", init.mod2_osc_octave, IntRange::Linear { min: -2, max: 2 })
                 .with_callback({
                     let update_something = update_something.clone();
                     Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
                 }),
-            osc_2_semitones: IntParam::new("Semi", 0, IntRange::Linear { min: -11, max: 11 })
+            osc_2_semitones: IntParam::new("Semi", init.mod2_osc_semitones, IntRange::Linear { min: -11, max: 11 })
                 .with_callback({
                     let update_something = update_something.clone();
                     Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
                 }),
             osc_2_detune: FloatParam::new(
                 "Fine",
-                0.0,
+                init.mod2_osc_detune,
                 FloatRange::Linear {
                     min: -0.999,
                     max: 0.999,
@@ -1315,9 +2190,24 @@ impl ActuateParams {
                 let update_something = update_something.clone();
                 Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
             }),
+            osc_2_detune_hz: FloatParam::new("Detune Hz", init.mod2_osc_detune_hz, FloatRange::Linear { min: -20.0, max: 20.0 })
+                .with_step_size(0.01)
+                .with_value_to_string(formatters::v2s_f32_rounded(2))
+                .with_unit(" Hz")
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
+            osc_2_punch: FloatParam::new("Punch", init.mod2_osc_punch, FloatRange::Linear { min: 0.0, max: 1.0 })
+                .with_unit("%")
+                .with_value_to_string(formatters::v2s_f32_percentage(0))
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
             osc_2_attack: FloatParam::new(
                 "Attack",
-                0.0001,
+                init.mod2_osc_attack,
                 FloatRange::Skewed {
                     min: 0.0001,
                     max: 1999.9,
@@ -1333,7 +2223,7 @@ impl ActuateParams {
             }),
             osc_2_decay: FloatParam::new(
                 "Decay",
-                0.0001,
+                init.mod2_osc_decay,
                 FloatRange::Skewed {
                     min: 0.0001,
                     max: 1999.9,
@@ -1349,7 +2239,7 @@ impl ActuateParams {
             }),
             osc_2_sustain: FloatParam::new(
                 "Sustain",
-                1999.9,
+                init.mod2_osc_sustain,
                 FloatRange::Linear {
                     min: 0.0001,
                     max: 1999.9,
@@ -1364,7 +2254,7 @@ impl ActuateParams {
             }),
             osc_2_release: FloatParam::new(
                 "Release",
-                5.0,
+                init.mod2_osc_release,
                 FloatRange::Skewed {
                     min: 0.0001,
                     max: 1999.9,
@@ -1378,33 +2268,40 @@ impl ActuateParams {
                 let update_something = update_something.clone();
                 Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
             }),
-            osc_2_retrigger: EnumParam::new("Retrig", RetriggerStyle::Retrigger).with_callback({
+            osc_2_retrigger: EnumParam::new("Retrig", init.mod2_osc_retrigger).with_callback({
                 let update_something = update_something.clone();
                 Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
             }),
-            osc_2_atk_curve: EnumParam::new("Atk Curve", Oscillator::SmoothStyle::Linear)
+            osc_2_atk_curve: EnumParam::new("Atk Curve", init.mod2_osc_atk_curve)
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
+            osc_2_dec_curve: EnumParam::new("Dec Curve", init.mod2_osc_dec_curve)
                 .with_callback({
                     let update_something = update_something.clone();
                     Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
                 }),
-            osc_2_dec_curve: EnumParam::new("Dec Curve", Oscillator::SmoothStyle::Linear)
+            osc_2_rel_curve: EnumParam::new("Rel Curve", init.mod2_osc_rel_curve)
                 .with_callback({
                     let update_something = update_something.clone();
                     Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
                 }),
-            osc_2_rel_curve: EnumParam::new("Rel Curve", Oscillator::SmoothStyle::Linear)
+            osc_2_env_keyscale: FloatParam::new("Key Scale", init.mod2_osc_env_keyscale, FloatRange::Linear { min: 0.0, max: 1.0 })
+                .with_value_to_string(formatters::v2s_f32_percentage(0))
+                .with_unit("%")
                 .with_callback({
                     let update_something = update_something.clone();
                     Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
                 }),
-            osc_2_unison: IntParam::new("Multiply", 1, IntRange::Linear { min: 1, max: 9 })
+            osc_2_unison: IntParam::new("Multiply", init.mod2_osc_unison, IntRange::Linear { min: 1, max: 9 })
                 .with_callback({
                     let update_something = update_something.clone();
                     Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
                 }),
             osc_2_unison_detune: FloatParam::new(
                 "MDetune",
-                0.0,
+                init.mod2_osc_unison_detune,
                 FloatRange::Linear { min: 0.0, max: 1.0 },
             )
             .with_step_size(0.0001)
@@ -1413,61 +2310,141 @@ impl ActuateParams {
                 let update_something = update_something.clone();
                 Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
             }),
-            osc_2_stereo: FloatParam::new("Stereo", 1.0, FloatRange::Linear { min: 0.0, max: 2.0 })
+            osc_2_unison_blend: FloatParam::new("Blend", init.mod2_osc_unison_blend, FloatRange::Linear { min: 0.0, max: 1.0 })
+                .with_value_to_string(formatters::v2s_f32_percentage(0))
+                .with_unit("%")
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
+            osc_2_stereo: FloatParam::new("Stereo", init.mod2_osc_stereo, FloatRange::Linear { min: 0.0, max: 2.0 })
                 .with_value_to_string(formatters::v2s_f32_rounded(2))
                 .with_callback({
                     let update_something = update_something.clone();
                     Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
                 }),
-
-            osc_3_octave: IntParam::new("Octave", 0, IntRange::Linear { min: -2, max: 2 })
+            osc_2_super: FloatParam::new("Super", init.mod2_osc_super, FloatRange::Linear { min: 0.0, max: 1.0 })
+                .with_value_to_string(formatters::v2s_f32_rounded(2))
                 .with_callback({
                     let update_something = update_something.clone();
                     Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
                 }),
-            osc_3_semitones: IntParam::new("Semi", 0, IntRange::Linear { min: -11, max: 11 })
+            osc_2_type_b: EnumParam::new("Wave B", init.mod2_osc_type_b)
                 .with_callback({
                     let update_something = update_something.clone();
                     Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
                 }),
-            osc_3_detune: FloatParam::new(
-                "Fine",
-                0.0,
-                FloatRange::Linear {
-                    min: -0.999,
-                    max: 0.999,
-                },
-            )
-            .with_step_size(0.0001)
-            .with_value_to_string(formatters::v2s_f32_rounded(4))
-            .with_callback({
-                let update_something = update_something.clone();
-                Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
-            }),
-            osc_3_attack: FloatParam::new(
-                "Attack",
-                0.0001,
-                FloatRange::Skewed {
-                    min: 0.0001,
-                    max: 1999.9,
-                    factor: 0.5,
-                },
-            )
-            .with_step_size(0.0001)
-            .with_value_to_string(format_nothing())
-            .with_unit("A")
-            .with_callback({
-                let update_something = update_something.clone();
-                Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
-            }),
-            osc_3_decay: FloatParam::new(
-                "Decay",
-                0.0001,
-                FloatRange::Skewed {
-                    min: 0.0001,
-                    max: 1999.9,
-                    factor: 0.5,
-                },
+            osc_2_morph: FloatParam::new("Morph", init.mod2_osc_morph, FloatRange::Linear { min: 0.0, max: 1.0 })
+                .with_value_to_string(formatters::v2s_f32_rounded(2))
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
+            osc_2_shape: EnumParam::new("Shaper", init.mod2_osc_shape)
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
+            osc_2_shape_amount: FloatParam::new("Shape Amt", init.mod2_osc_shape_amount, FloatRange::Linear { min: 0.0, max: 1.0 })
+                .with_value_to_string(formatters::v2s_f32_rounded(2))
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
+            osc_2_warmth: FloatParam::new("Warmth", init.mod2_osc_warmth, FloatRange::Linear { min: 0.0, max: 1.0 })
+                .with_value_to_string(formatters::v2s_f32_rounded(2))
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
+            osc_2_drawbar_sub: FloatParam::new("-1 Oct", init.mod2_osc_drawbar_sub, FloatRange::Linear { min: 0.0, max: 1.0 })
+                .with_value_to_string(formatters::v2s_f32_rounded(2))
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
+            osc_2_drawbar_root: FloatParam::new("Root", init.mod2_osc_drawbar_root, FloatRange::Linear { min: 0.0, max: 1.0 })
+                .with_value_to_string(formatters::v2s_f32_rounded(2))
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
+            osc_2_drawbar_oct: FloatParam::new("+1 Oct", init.mod2_osc_drawbar_oct, FloatRange::Linear { min: 0.0, max: 1.0 })
+                .with_value_to_string(formatters::v2s_f32_rounded(2))
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
+            osc_2_drawbar_oct2: FloatParam::new("+2 Oct", init.mod2_osc_drawbar_oct2, FloatRange::Linear { min: 0.0, max: 1.0 })
+                .with_value_to_string(formatters::v2s_f32_rounded(2))
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
+
+            osc_3_octave: IntParam::new("Octave", init.mod3_osc_octave, IntRange::Linear { min: -2, max: 2 })
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
+            osc_3_semitones: IntParam::new("Semi", init.mod3_osc_semitones, IntRange::Linear { min: -11, max: 11 })
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
+            osc_3_detune: FloatParam::new(
+                "Fine",
+                init.mod3_osc_detune,
+                FloatRange::Linear {
+                    min: -0.999,
+                    max: 0.999,
+                },
+            )
+            .with_step_size(0.0001)
+            .with_value_to_string(formatters::v2s_f32_rounded(4))
+            .with_callback({
+                let update_something = update_something.clone();
+                Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+            }),
+            osc_3_detune_hz: FloatParam::new("Detune Hz", init.mod3_osc_detune_hz, FloatRange::Linear { min: -20.0, max: 20.0 })
+                .with_step_size(0.01)
+                .with_value_to_string(formatters::v2s_f32_rounded(2))
+                .with_unit(" Hz")
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
+            osc_3_punch: FloatParam::new("Punch", init.mod3_osc_punch, FloatRange::Linear { min: 0.0, max: 1.0 })
+                .with_unit("%")
+                .with_value_to_string(formatters::v2s_f32_percentage(0))
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
+            osc_3_attack: FloatParam::new(
+                "Attack",
+                init.mod3_osc_attack,
+                FloatRange::Skewed {
+                    min: 0.0001,
+                    max: 1999.9,
+                    factor: 0.5,
+                },
+            )
+            .with_step_size(0.0001)
+            .with_value_to_string(format_nothing())
+            .with_unit("A")
+            .with_callback({
+                let update_something = update_something.clone();
+                Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+            }),
+            osc_3_decay: FloatParam::new(
+                "Decay",
+                init.mod3_osc_decay,
+                FloatRange::Skewed {
+                    min: 0.0001,
+                    max: 1999.9,
+                    factor: 0.5,
+                },
             )
             .with_step_size(0.0001)
             .with_value_to_string(format_nothing())
@@ -1478,7 +2455,7 @@ impl ActuateParams {
             }),
             osc_3_sustain: FloatParam::new(
                 "Sustain",
-                1999.9,
+                init.mod3_osc_sustain,
                 FloatRange::Linear {
                     min: 0.0001,
                     max: 1999.9,
@@ -1493,7 +2470,7 @@ impl ActuateParams {
             }),
             osc_3_release: FloatParam::new(
                 "Release",
-                5.0,
+                init.mod3_osc_release,
                 FloatRange::Skewed {
                     min: 0.0001,
                     max: 1999.9,
@@ -1507,33 +2484,40 @@ impl ActuateParams {
                 let update_something = update_something.clone();
                 Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
             }),
-            osc_3_retrigger: EnumParam::new("Retrig", RetriggerStyle::Retrigger).with_callback({
+            osc_3_retrigger: EnumParam::new("Retrig", init.mod3_osc_retrigger).with_callback({
                 let update_something = update_something.clone();
                 Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
             }),
-            osc_3_atk_curve: EnumParam::new("Atk Curve", Oscillator::SmoothStyle::Linear)
+            osc_3_atk_curve: EnumParam::new("Atk Curve", init.mod3_osc_atk_curve)
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
+            osc_3_dec_curve: EnumParam::new("Dec Curve", init.mod3_osc_dec_curve)
                 .with_callback({
                     let update_something = update_something.clone();
                     Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
                 }),
-            osc_3_dec_curve: EnumParam::new("Dec Curve", Oscillator::SmoothStyle::Linear)
+            osc_3_rel_curve: EnumParam::new("Rel Curve", init.mod3_osc_rel_curve)
                 .with_callback({
                     let update_something = update_something.clone();
                     Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
                 }),
-            osc_3_rel_curve: EnumParam::new("Rel Curve", Oscillator::SmoothStyle::Linear)
+            osc_3_env_keyscale: FloatParam::new("Key Scale", init.mod3_osc_env_keyscale, FloatRange::Linear { min: 0.0, max: 1.0 })
+                .with_value_to_string(formatters::v2s_f32_percentage(0))
+                .with_unit("%")
                 .with_callback({
                     let update_something = update_something.clone();
                     Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
                 }),
-            osc_3_unison: IntParam::new("Multiply", 1, IntRange::Linear { min: 1, max: 9 })
+            osc_3_unison: IntParam::new("Multiply", init.mod3_osc_unison, IntRange::Linear { min: 1, max: 9 })
                 .with_callback({
                     let update_something = update_something.clone();
                     Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
                 }),
             osc_3_unison_detune: FloatParam::new(
                 "MDetune",
-                0.0,
+                init.mod3_osc_unison_detune,
                 FloatRange::Linear { min: 0.0, max: 1.0 },
             )
             .with_step_size(0.0001)
@@ -1542,7 +2526,72 @@ impl ActuateParams {
                 let update_something = update_something.clone();
                 Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
             }),
-            osc_3_stereo: FloatParam::new("Stereo", 1.0, FloatRange::Linear { min: 0.0, max: 2.0 })
+            osc_3_unison_blend: FloatParam::new("Blend", init.mod3_osc_unison_blend, FloatRange::Linear { min: 0.0, max: 1.0 })
+                .with_value_to_string(formatters::v2s_f32_percentage(0))
+                .with_unit("%")
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
+            osc_3_stereo: FloatParam::new("Stereo", init.mod3_osc_stereo, FloatRange::Linear { min: 0.0, max: 2.0 })
+                .with_value_to_string(formatters::v2s_f32_rounded(2))
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
+            osc_3_super: FloatParam::new("Super", init.mod3_osc_super, FloatRange::Linear { min: 0.0, max: 1.0 })
+                .with_value_to_string(formatters::v2s_f32_rounded(2))
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
+            osc_3_type_b: EnumParam::new("Wave B", init.mod3_osc_type_b)
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
+            osc_3_morph: FloatParam::new("Morph", init.mod3_osc_morph, FloatRange::Linear { min: 0.0, max: 1.0 })
+                .with_value_to_string(formatters::v2s_f32_rounded(2))
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
+            osc_3_shape: EnumParam::new("Shaper", init.mod3_osc_shape)
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
+            osc_3_shape_amount: FloatParam::new("Shape Amt", init.mod3_osc_shape_amount, FloatRange::Linear { min: 0.0, max: 1.0 })
+                .with_value_to_string(formatters::v2s_f32_rounded(2))
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
+            osc_3_warmth: FloatParam::new("Warmth", init.mod3_osc_warmth, FloatRange::Linear { min: 0.0, max: 1.0 })
+                .with_value_to_string(formatters::v2s_f32_rounded(2))
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
+            osc_3_drawbar_sub: FloatParam::new("-1 Oct", init.mod3_osc_drawbar_sub, FloatRange::Linear { min: 0.0, max: 1.0 })
+                .with_value_to_string(formatters::v2s_f32_rounded(2))
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
+            osc_3_drawbar_root: FloatParam::new("Root", init.mod3_osc_drawbar_root, FloatRange::Linear { min: 0.0, max: 1.0 })
+                .with_value_to_string(formatters::v2s_f32_rounded(2))
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
+            osc_3_drawbar_oct: FloatParam::new("+1 Oct", init.mod3_osc_drawbar_oct, FloatRange::Linear { min: 0.0, max: 1.0 })
+                .with_value_to_string(formatters::v2s_f32_rounded(2))
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
+            osc_3_drawbar_oct2: FloatParam::new("+2 Oct", init.mod3_osc_drawbar_oct2, FloatRange::Linear { min: 0.0, max: 1.0 })
                 .with_value_to_string(formatters::v2s_f32_rounded(2))
                 .with_callback({
                     let update_something = update_something.clone();
@@ -1570,71 +2619,133 @@ impl ActuateParams {
                 })
                 .hide(),
             // To loop the sampler/granulizer
-            loop_sample_1: BoolParam::new("Loop Sample", false).with_callback({
+            loop_sample_1: BoolParam::new("Loop Sample", init.mod1_loop_wavetable).with_callback({
                 let update_something = update_something.clone();
                 Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
             }),
-            loop_sample_2: BoolParam::new("Loop Sample", false).with_callback({
+            loop_sample_2: BoolParam::new("Loop Sample", init.mod2_loop_wavetable).with_callback({
                 let update_something = update_something.clone();
                 Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
             }),
-            loop_sample_3: BoolParam::new("Loop Sample", false).with_callback({
+            loop_sample_3: BoolParam::new("Loop Sample", init.mod3_loop_wavetable).with_callback({
                 let update_something = update_something.clone();
                 Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
             }),
             // Sampler only - toggle single cycle mode
-            single_cycle_1: BoolParam::new("Single Cycle", false).with_callback({
+            single_cycle_1: BoolParam::new("Single Cycle", init.mod1_single_cycle).with_callback({
                 let update_something = update_something.clone();
                 Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
             }),
-            single_cycle_2: BoolParam::new("Single Cycle", false).with_callback({
+            // Scales a sample to -1 dB peak as it loads
+            sample_normalize_1: BoolParam::new("Normalize", init.mod1_sample_normalize),
+            freeze_sample_1: BoolParam::new("Freeze", false).hide(),
+            single_cycle_2: BoolParam::new("Single Cycle", init.mod2_single_cycle).with_callback({
                 let update_something = update_something.clone();
                 Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
             }),
-            single_cycle_3: BoolParam::new("Single Cycle", false).with_callback({
+            sample_normalize_2: BoolParam::new("Normalize", init.mod2_sample_normalize),
+            freeze_sample_2: BoolParam::new("Freeze", false).hide(),
+            single_cycle_3: BoolParam::new("Single Cycle", init.mod3_single_cycle).with_callback({
                 let update_something = update_something.clone();
                 Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
             }),
+            sample_normalize_3: BoolParam::new("Normalize", init.mod3_sample_normalize),
+            freeze_sample_3: BoolParam::new("Freeze", false).hide(),
+            freeze_time: FloatParam::new(
+                "Freeze Time",
+                2.0,
+                FloatRange::Skewed {
+                    min: 0.25,
+                    max: CAPTURE_MAX_SECONDS,
+                    factor: 0.5,
+                },
+            )
+            .with_step_size(0.01)
+            .with_unit(" s")
+            .with_value_to_string(formatters::v2s_f32_rounded(2)),
             // Always true for granulizer/ can be off for sampler
-            restretch_1: BoolParam::new("Resample", true).with_callback({
+            restretch_1: BoolParam::new("Resample", init.mod1_restretch).with_callback({
                 let update_something = update_something.clone();
                 Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
             }),
-            restretch_2: BoolParam::new("Resample", true).with_callback({
+            restretch_2: BoolParam::new("Resample", init.mod2_restretch).with_callback({
                 let update_something = update_something.clone();
                 Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
             }),
-            restretch_3: BoolParam::new("Resample", true).with_callback({
+            restretch_3: BoolParam::new("Resample", init.mod3_restretch).with_callback({
                 let update_something = update_something.clone();
                 Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
             }),
+            // The note a loaded sample plays at unshifted, found on load for the Sampler and overridable after
+            sample_root_1: IntParam::new("Root", init.mod1_sample_root, IntRange::Linear { min: 0, max: 127 })
+                .with_value_to_string(formatters::v2s_i32_note_formatter())
+                .with_string_to_value(formatters::s2v_i32_note_formatter())
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
+            sample_root_2: IntParam::new("Root", init.mod2_sample_root, IntRange::Linear { min: 0, max: 127 })
+                .with_value_to_string(formatters::v2s_i32_note_formatter())
+                .with_string_to_value(formatters::s2v_i32_note_formatter())
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
+            sample_root_3: IntParam::new("Root", init.mod3_sample_root, IntRange::Linear { min: 0, max: 127 })
+                .with_value_to_string(formatters::v2s_i32_note_formatter())
+                .with_string_to_value(formatters::s2v_i32_note_formatter())
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
             // This is from 0 to 2000 samples
-            grain_hold_1: IntParam::new("Hold", 200, IntRange::Linear { min: 5, max: 22050 })
+            grain_hold_1: IntParam::new("Hold", init.mod1_grain_hold, IntRange::Linear { min: 5, max: 22050 })
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
+            grain_hold_2: IntParam::new("Hold", init.mod2_grain_hold, IntRange::Linear { min: 5, max: 22050 })
                 .with_callback({
                     let update_something = update_something.clone();
                     Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
                 }),
-            grain_hold_2: IntParam::new("Hold", 200, IntRange::Linear { min: 5, max: 22050 })
+            grain_hold_3: IntParam::new("Hold", init.mod3_grain_hold, IntRange::Linear { min: 5, max: 22050 })
                 .with_callback({
                     let update_something = update_something.clone();
                     Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
                 }),
-            grain_hold_3: IntParam::new("Hold", 200, IntRange::Linear { min: 5, max: 22050 })
+            grain_gap_1: IntParam::new("Gap", init.mod1_grain_gap, IntRange::Linear { min: 0, max: 22050 })
                 .with_callback({
                     let update_something = update_something.clone();
                     Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
                 }),
-            grain_gap_1: IntParam::new("Gap", 200, IntRange::Linear { min: 0, max: 22050 })
+            grain_spray_1: FloatParam::new("Spray", init.mod1_grain_spray, FloatRange::Linear { min: 0.0, max: 1.0 })
+                .with_unit("%")
+                .with_value_to_string(formatters::v2s_f32_percentage(0))
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
+            grain_gap_2: IntParam::new("Gap", init.mod2_grain_gap, IntRange::Linear { min: 0, max: 22050 })
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
+            grain_spray_2: FloatParam::new("Spray", init.mod2_grain_spray, FloatRange::Linear { min: 0.0, max: 1.0 })
+                .with_unit("%")
+                .with_value_to_string(formatters::v2s_f32_percentage(0))
                 .with_callback({
                     let update_something = update_something.clone();
                     Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
                 }),
-            grain_gap_2: IntParam::new("Gap", 200, IntRange::Linear { min: 0, max: 22050 })
+            grain_gap_3: IntParam::new("Gap", init.mod3_grain_gap, IntRange::Linear { min: 0, max: 22050 })
                 .with_callback({
                     let update_something = update_something.clone();
                     Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
                 }),
-            grain_gap_3: IntParam::new("Gap", 200, IntRange::Linear { min: 0, max: 22050 })
+            grain_spray_3: FloatParam::new("Spray", init.mod3_grain_spray, FloatRange::Linear { min: 0.0, max: 1.0 })
+                .with_unit("%")
+                .with_value_to_string(formatters::v2s_f32_percentage(0))
                 .with_callback({
                     let update_something = update_something.clone();
                     Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
@@ -1642,7 +2753,7 @@ impl ActuateParams {
             // This is going to be in % since sample can be any size
             start_position_1: FloatParam::new(
                 "Start",
-                0.0,
+                init.mod1_start_position,
                 FloatRange::Linear { min: 0.0, max: 1.0 },
             )
             .with_unit("%")
@@ -1653,7 +2764,7 @@ impl ActuateParams {
             }),
             start_position_2: FloatParam::new(
                 "Start",
-                0.0,
+                init.mod2_start_position,
                 FloatRange::Linear { min: 0.0, max: 1.0 },
             )
             .with_unit("%")
@@ -1664,7 +2775,7 @@ impl ActuateParams {
             }),
             start_position_3: FloatParam::new(
                 "Start",
-                0.0,
+                init.mod3_start_position,
                 FloatRange::Linear { min: 0.0, max: 1.0 },
             )
             .with_unit("%")
@@ -1673,39 +2784,120 @@ impl ActuateParams {
                 let update_something = update_something.clone();
                 Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
             }),
-            end_position_1: FloatParam::new("End", 1.0, FloatRange::Linear { min: 0.0, max: 1.0 })
+            end_position_1: FloatParam::new("End", init.mod1_end_position, FloatRange::Linear { min: 0.0, max: 1.0 })
                 .with_unit("%")
                 .with_value_to_string(formatters::v2s_f32_percentage(0))
                 .with_callback({
                     let update_something = update_something.clone();
                     Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
                 }),
-            end_position_2: FloatParam::new("End", 1.0, FloatRange::Linear { min: 0.0, max: 1.0 })
+            end_position_2: FloatParam::new("End", init.mod2_end_position, FloatRange::Linear { min: 0.0, max: 1.0 })
                 .with_unit("%")
                 .with_value_to_string(formatters::v2s_f32_percentage(0))
                 .with_callback({
                     let update_something = update_something.clone();
                     Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
                 }),
-            end_position_3: FloatParam::new("End", 1.0, FloatRange::Linear { min: 0.0, max: 1.0 })
+            end_position_3: FloatParam::new("End", init.mod3_end_position, FloatRange::Linear { min: 0.0, max: 1.0 })
                 .with_unit("%")
                 .with_value_to_string(formatters::v2s_f32_percentage(0))
                 .with_callback({
                     let update_something = update_something.clone();
                     Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
                 }),
+            // Sampler extreme time-stretch, 1x plays the sample normally
+            stretch_factor_1: FloatParam::new("Stretch", init.mod1_stretch_factor, FloatRange::Skewed { min: 1.0, max: 100.0, factor: 0.3 })
+                .with_step_size(0.1)
+                .with_value_to_string(formatters::v2s_f32_rounded(1))
+                .with_unit("x")
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
+            // Sampler loop crossfade to hide the seam where the loop end meets the loop start
+            loop_xfade_1: FloatParam::new("Loop XFade", init.mod1_loop_xfade, FloatRange::Skewed { min: 0.0, max: 500.0, factor: 0.4 })
+                .with_step_size(0.1)
+                .with_value_to_string(formatters::v2s_f32_rounded(1))
+                .with_unit(" ms")
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
+            // Drum slicer, splits Start..End into slices played by consecutive notes up from the root, 1 is off
+            slice_count_1: IntParam::new("Slices", init.mod1_slice_count, IntRange::Linear { min: 1, max: 64 })
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
+            stretch_factor_2: FloatParam::new("Stretch", init.mod2_stretch_factor, FloatRange::Skewed { min: 1.0, max: 100.0, factor: 0.3 })
+                .with_step_size(0.1)
+                .with_value_to_string(formatters::v2s_f32_rounded(1))
+                .with_unit("x")
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
+            loop_xfade_2: FloatParam::new("Loop XFade", init.mod2_loop_xfade, FloatRange::Skewed { min: 0.0, max: 500.0, factor: 0.4 })
+                .with_step_size(0.1)
+                .with_value_to_string(formatters::v2s_f32_rounded(1))
+                .with_unit(" ms")
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
+            slice_count_2: IntParam::new("Slices", init.mod2_slice_count, IntRange::Linear { min: 1, max: 64 })
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
+            stretch_factor_3: FloatParam::new("Stretch", init.mod3_stretch_factor, FloatRange::Skewed { min: 1.0, max: 100.0, factor: 0.3 })
+                .with_step_size(0.1)
+                .with_value_to_string(formatters::v2s_f32_rounded(1))
+                .with_unit("x")
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
+            loop_xfade_3: FloatParam::new("Loop XFade", init.mod3_loop_xfade, FloatRange::Skewed { min: 0.0, max: 500.0, factor: 0.4 })
+                .with_step_size(0.1)
+                .with_value_to_string(formatters::v2s_f32_rounded(1))
+                .with_unit(" ms")
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
+            slice_count_3: IntParam::new("Slices", init.mod3_slice_count, IntRange::Linear { min: 1, max: 64 })
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
             // Grain Crossfade
-            grain_crossfade_1: IntParam::new("Shape", 50, IntRange::Linear { min: 2, max: 2000 })
+            grain_crossfade_1: IntParam::new("Shape", init.mod1_grain_crossfade, IntRange::Linear { min: 2, max: 2000 })
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
+            grain_window_1: EnumParam::new("Window", init.mod1_grain_window)
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
+            grain_crossfade_2: IntParam::new("Shape", init.mod2_grain_crossfade, IntRange::Linear { min: 2, max: 2000 })
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
+            grain_window_2: EnumParam::new("Window", init.mod2_grain_window)
                 .with_callback({
                     let update_something = update_something.clone();
                     Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
                 }),
-            grain_crossfade_2: IntParam::new("Shape", 50, IntRange::Linear { min: 2, max: 2000 })
+            grain_crossfade_3: IntParam::new("Shape", init.mod3_grain_crossfade, IntRange::Linear { min: 2, max: 2000 })
                 .with_callback({
                     let update_something = update_something.clone();
                     Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
                 }),
-            grain_crossfade_3: IntParam::new("Shape", 50, IntRange::Linear { min: 2, max: 2000 })
+            grain_window_3: EnumParam::new("Window", init.mod3_grain_window)
                 .with_callback({
                     let update_something = update_something.clone();
                     Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
@@ -1715,7 +2907,7 @@ impl ActuateParams {
             ////////////////////////////////////////////////////////////////////////////////////
             filter_lp_amount: FloatParam::new(
                 "LPF",
-                1.0,
+                init.filter_lp_amount,
                 FloatRange::Linear { min: 0.0, max: 1.0 },
             )
             .with_value_to_string(formatters::v2s_f32_percentage(0))
@@ -1725,7 +2917,7 @@ impl ActuateParams {
             }),
             filter_hp_amount: FloatParam::new(
                 "HPF",
-                0.0,
+                init.filter_hp_amount,
                 FloatRange::Linear { min: 0.0, max: 1.0 },
             )
             .with_value_to_string(formatters::v2s_f32_percentage(0))
@@ -1735,7 +2927,7 @@ impl ActuateParams {
             }),
             filter_bp_amount: FloatParam::new(
                 "BPF",
-                0.0,
+                init.filter_bp_amount,
                 FloatRange::Linear { min: 0.0, max: 1.0 },
             )
             .with_value_to_string(formatters::v2s_f32_percentage(0))
@@ -1744,7 +2936,14 @@ impl ActuateParams {
                 Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
             }),
 
-            filter_wet: FloatParam::new("Filter", 1.0, FloatRange::Linear { min: 0.0, max: 1.0 })
+            filter_wet: FloatParam::new("Filter", init.filter_wet, FloatRange::Linear { min: 0.0, max: 1.0 })
+                .with_unit("%")
+                .with_value_to_string(formatters::v2s_f32_percentage(0))
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
+            filter_drive: FloatParam::new("Drive", init.filter_drive, FloatRange::Linear { min: 0.0, max: 1.0 })
                 .with_unit("%")
                 .with_value_to_string(formatters::v2s_f32_percentage(0))
                 .with_callback({
@@ -1753,7 +2952,7 @@ impl ActuateParams {
                 }),
             filter_resonance: FloatParam::new(
                 "Res",
-                1.0,
+                init.filter_resonance,
                 FloatRange::Reversed(&FloatRange::Linear { min: 0.1, max: 1.0 }),
             )
             .with_unit("%")
@@ -1762,13 +2961,13 @@ impl ActuateParams {
                 let update_something = update_something.clone();
                 Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
             }),
-            filter_res_type: EnumParam::new("Res Type", ResonanceType::Default).with_callback({
+            filter_res_type: EnumParam::new("Res Type", init.filter_res_type.clone()).with_callback({
                 let update_something = update_something.clone();
                 Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
             }),
             filter_cutoff: FloatParam::new(
                 "Cutoff",
-                20000.0,
+                init.filter_cutoff,
                 FloatRange::Skewed {
                     min: 20.0,
                     max: 20000.0,
@@ -1780,11 +2979,11 @@ impl ActuateParams {
                 let update_something = update_something.clone();
                 Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
             }),
-            filter_alg_type: EnumParam::new("Filter Alg", FilterAlgorithms::SVF).with_callback({
+            filter_alg_type: EnumParam::new("Filter Alg", init.filter_alg_type.clone()).with_callback({
                 let update_something = update_something.clone();
                 Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
             }),
-            tilt_filter_type: EnumParam::new("Filter Type", ResponseType::Lowpass).with_callback({
+            tilt_filter_type: EnumParam::new("Filter Type", init.tilt_filter_type.clone()).with_callback({
                 let update_something = update_something.clone();
                 Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
             }),
@@ -1795,7 +2994,7 @@ impl ActuateParams {
 
             filter_env_peak: FloatParam::new(
                 "Env Mod",
-                0.0,
+                init.filter_env_peak,
                 FloatRange::Linear {
                     min: -14980.0,
                     max: 14980.0,
@@ -1807,9 +3006,13 @@ impl ActuateParams {
                 let update_something = update_something.clone();
                 Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
             }),
+            filter_env_invert_1: BoolParam::new("Env Invert", init.filter_env_invert_1).with_callback({
+                let update_something = update_something.clone();
+                Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+            }),
             filter_env_attack: FloatParam::new(
                 "Env Attack",
-                0.0001,
+                init.filter_env_attack,
                 FloatRange::Skewed {
                     min: 0.0001,
                     max: 1999.9,
@@ -1824,7 +3027,7 @@ impl ActuateParams {
             }),
             filter_env_decay: FloatParam::new(
                 "Env Decay",
-                0.0001,
+                init.filter_env_decay,
                 FloatRange::Skewed {
                     min: 0.0001,
                     max: 1999.9,
@@ -1839,7 +3042,7 @@ impl ActuateParams {
             }),
             filter_env_sustain: FloatParam::new(
                 "Env Sustain",
-                1999.9,
+                init.filter_env_sustain,
                 FloatRange::Skewed {
                     min: 0.0001,
                     max: 1999.9,
@@ -1854,7 +3057,7 @@ impl ActuateParams {
             }),
             filter_env_release: FloatParam::new(
                 "Env Release",
-                0.0001,
+                init.filter_env_release,
                 FloatRange::Skewed {
                     min: 0.0001,
                     max: 1999.9,
@@ -1867,25 +3070,27 @@ impl ActuateParams {
                 let update_something = update_something.clone();
                 Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
             }),
-            filter_env_atk_curve: EnumParam::new("Atk Curve", Oscillator::SmoothStyle::Linear)
+            filter_env_atk_curve: EnumParam::new("Atk Curve", init.filter_env_atk_curve)
                 .with_callback({
                     let update_something = update_something.clone();
                     Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
                 }),
-            filter_env_dec_curve: EnumParam::new("Dec Curve", Oscillator::SmoothStyle::Linear)
+            filter_env_dec_curve: EnumParam::new("Dec Curve", init.filter_env_dec_curve)
                 .with_callback({
                     let update_something = update_something.clone();
                     Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
                 }),
-            filter_env_rel_curve: EnumParam::new("Rel Curve", Oscillator::SmoothStyle::Linear)
+            filter_env_rel_curve: EnumParam::new("Rel Curve", init.filter_env_rel_curve)
                 .with_callback({
                     let update_something = update_something.clone();
                     Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
                 }),
+            filter_env_loop: BoolParam::new("Filter Env Loop", init.filter_env_loop),
+            filter_env_loop_rate: EnumParam::new("Loop Rate", init.filter_env_loop_rate),
 
             filter_lp_amount_2: FloatParam::new(
                 "LPF",
-                1.0,
+                init.filter_lp_amount_2,
                 FloatRange::Linear { min: 0.0, max: 1.0 },
             )
             .with_value_to_string(formatters::v2s_f32_percentage(0))
@@ -1895,7 +3100,7 @@ impl ActuateParams {
             }),
             filter_hp_amount_2: FloatParam::new(
                 "HPF",
-                0.0,
+                init.filter_hp_amount_2,
                 FloatRange::Linear { min: 0.0, max: 1.0 },
             )
             .with_value_to_string(formatters::v2s_f32_percentage(0))
@@ -1905,7 +3110,7 @@ impl ActuateParams {
             }),
             filter_bp_amount_2: FloatParam::new(
                 "BPF",
-                0.0,
+                init.filter_bp_amount_2,
                 FloatRange::Linear { min: 0.0, max: 1.0 },
             )
             .with_value_to_string(formatters::v2s_f32_percentage(0))
@@ -1914,31 +3119,55 @@ impl ActuateParams {
                 Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
             }),
 
-            filter_wet_2: FloatParam::new("Filter", 1.0, FloatRange::Linear { min: 0.0, max: 1.0 })
+            filter_wet_2: FloatParam::new("Filter", init.filter_wet_2, FloatRange::Linear { min: 0.0, max: 1.0 })
                 .with_unit("%")
                 .with_value_to_string(formatters::v2s_f32_percentage(0))
                 .with_callback({
                     let update_something = update_something.clone();
                     Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
                 }),
-            filter_resonance_2: FloatParam::new(
-                "Res",
-                1.0,
-                FloatRange::Reversed(&FloatRange::Linear { min: 0.1, max: 1.0 }),
-            )
-            .with_unit("%")
-            .with_value_to_string(formatters::v2s_f32_percentage(0))
-            .with_callback({
-                let update_something = update_something.clone();
-                Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+            filter_drive_2: FloatParam::new("Drive", init.filter_drive_2, FloatRange::Linear { min: 0.0, max: 1.0 })
+                .with_unit("%")
+                .with_value_to_string(formatters::v2s_f32_percentage(0))
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
+            // Right channel cutoff offset in semitones for a wider stereo filter, 0 keeps L and R identical
+            filter_stereo_offset_1: FloatParam::new("Stereo Offset", init.filter_stereo_offset_1, FloatRange::Linear { min: -12.0, max: 12.0 })
+                .with_step_size(0.01)
+                .with_value_to_string(formatters::v2s_f32_rounded(2))
+                .with_unit(" st")
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
+            filter_stereo_offset_2: FloatParam::new("Stereo Offset", init.filter_stereo_offset_2, FloatRange::Linear { min: -12.0, max: 12.0 })
+                .with_step_size(0.01)
+                .with_value_to_string(formatters::v2s_f32_rounded(2))
+                .with_unit(" st")
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
+            filter_resonance_2: FloatParam::new(
+                "Res",
+                init.filter_resonance_2,
+                FloatRange::Reversed(&FloatRange::Linear { min: 0.1, max: 1.0 }),
+            )
+            .with_unit("%")
+            .with_value_to_string(formatters::v2s_f32_percentage(0))
+            .with_callback({
+                let update_something = update_something.clone();
+                Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
             }),
-            filter_res_type_2: EnumParam::new("Res Type", ResonanceType::Default).with_callback({
+            filter_res_type_2: EnumParam::new("Res Type", init.filter_res_type_2.clone()).with_callback({
                 let update_something = update_something.clone();
                 Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
             }),
             filter_cutoff_2: FloatParam::new(
                 "Cutoff",
-                20000.0,
+                init.filter_cutoff_2,
                 FloatRange::Skewed {
                     min: 20.0,
                     max: 20000.0,
@@ -1950,11 +3179,20 @@ impl ActuateParams {
                 let update_something = update_something.clone();
                 Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
             }),
-            filter_alg_type_2: EnumParam::new("Filter Alg", FilterAlgorithms::SVF).with_callback({
+            // Glide time for cutoff knob/automation changes on both filters, 0 is instant
+            filter_cutoff_smooth: FloatParam::new("Cutoff Smooth", init.filter_cutoff_smooth, FloatRange::Skewed { min: 0.0, max: 200.0, factor: 0.5 })
+                .with_step_size(0.1)
+                .with_value_to_string(formatters::v2s_f32_rounded(1))
+                .with_unit(" ms")
+                .with_callback({
+                    let update_something = update_something.clone();
+                    Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+                }),
+            filter_alg_type_2: EnumParam::new("Filter Alg", init.filter_alg_type_2.clone()).with_callback({
                 let update_something = update_something.clone();
                 Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
             }),
-            tilt_filter_type_2: EnumParam::new("Filter Type", ResponseType::Lowpass).with_callback({
+            tilt_filter_type_2: EnumParam::new("Filter Type", init.tilt_filter_type_2.clone()).with_callback({
                 let update_something = update_something.clone();
                 Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
             }),
@@ -1965,7 +3203,7 @@ impl ActuateParams {
 
             filter_env_peak_2: FloatParam::new(
                 "Env Mod",
-                0.0,
+                init.filter_env_peak_2,
                 FloatRange::Linear {
                     min: -14980.0,
                     max: 14980.0,
@@ -1977,9 +3215,13 @@ impl ActuateParams {
                 let update_something = update_something.clone();
                 Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
             }),
+            filter_env_invert_2: BoolParam::new("Env Invert", init.filter_env_invert_2).with_callback({
+                let update_something = update_something.clone();
+                Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+            }),
             filter_env_attack_2: FloatParam::new(
                 "Env Attack",
-                0.0001,
+                init.filter_env_attack_2,
                 FloatRange::Skewed {
                     min: 0.0001,
                     max: 1999.9,
@@ -1994,7 +3236,7 @@ impl ActuateParams {
             }),
             filter_env_decay_2: FloatParam::new(
                 "Env Decay",
-                0.0001,
+                init.filter_env_decay_2,
                 FloatRange::Skewed {
                     min: 0.0001,
                     max: 1999.9,
@@ -2009,7 +3251,7 @@ impl ActuateParams {
             }),
             filter_env_sustain_2: FloatParam::new(
                 "Env Sustain",
-                1999.9,
+                init.filter_env_sustain_2,
                 FloatRange::Skewed {
                     min: 0.0001,
                     max: 1999.9,
@@ -2024,7 +3266,7 @@ impl ActuateParams {
             }),
             filter_env_release_2: FloatParam::new(
                 "Env Release",
-                0.0001,
+                init.filter_env_release_2,
                 FloatRange::Skewed {
                     min: 0.0001,
                     max: 1999.9,
@@ -2037,29 +3279,40 @@ impl ActuateParams {
                 let update_something = update_something.clone();
                 Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
             }),
-            filter_env_atk_curve_2: EnumParam::new("Atk Curve", Oscillator::SmoothStyle::Linear)
+            filter_env_atk_curve_2: EnumParam::new("Atk Curve", init.filter_env_atk_curve_2)
                 .with_callback({
                     let update_something = update_something.clone();
                     Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
                 }),
-            filter_env_dec_curve_2: EnumParam::new("Dec Curve", Oscillator::SmoothStyle::Linear)
+            filter_env_dec_curve_2: EnumParam::new("Dec Curve", init.filter_env_dec_curve_2)
                 .with_callback({
                     let update_something = update_something.clone();
                     Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
                 }),
-            filter_env_rel_curve_2: EnumParam::new("Rel Curve", Oscillator::SmoothStyle::Linear)
+            filter_env_rel_curve_2: EnumParam::new("Rel Curve", init.filter_env_rel_curve_2)
                 .with_callback({
                     let update_something = update_something.clone();
                     Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
                 }),
 
-            filter_cutoff_link: BoolParam::new("Filter Cutoffs Linked", false),
+            filter_cutoff_link: BoolParam::new("Filter Cutoffs Linked", init.filter_cutoff_link),
+            filter_res_link: BoolParam::new("Filter Resonances Linked", init.filter_res_link),
+            filter_env_link: BoolParam::new("Filter Envelopes Linked", init.filter_env_link),
+            // Every note on restarts the filter envelopes of all held notes, like one shared envelope
+            paraphonic: BoolParam::new("Paraphonic", init.paraphonic).with_callback({
+                let update_something = update_something.clone();
+                Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+            }),
+            filter_auto_gain: BoolParam::new("Filter Auto Gain", init.filter_auto_gain).with_callback({
+                let update_something = update_something.clone();
+                Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
+            }),
 
             // Pitch Envelope
             ////////////////////////////////////////////////////////////////////////////////////
             pitch_env_peak: FloatParam::new(
                 "Pitch Env",
-                0.0,
+                init.pitch_env_peak,
                 FloatRange::Linear {
                     min: -144.0,
                     max: 144.0,
@@ -2073,7 +3326,7 @@ impl ActuateParams {
             }),
             pitch_env_attack: FloatParam::new(
                 "Env Attack",
-                0.0001,
+                init.pitch_env_attack,
                 FloatRange::Skewed {
                     min: 0.0001,
                     max: 1999.9,
@@ -2088,7 +3341,7 @@ impl ActuateParams {
             }),
             pitch_env_decay: FloatParam::new(
                 "Env Decay",
-                300.0,
+                init.pitch_env_decay,
                 FloatRange::Skewed {
                     min: 0.0001,
                     max: 1999.9,
@@ -2103,7 +3356,7 @@ impl ActuateParams {
             }),
             pitch_env_sustain: FloatParam::new(
                 "Env Sustain",
-                0.0001,
+                init.pitch_env_sustain,
                 FloatRange::Skewed {
                     min: 0.0001,
                     max: 1999.9,
@@ -2118,7 +3371,7 @@ impl ActuateParams {
             }),
             pitch_env_release: FloatParam::new(
                 "Env Release",
-                0.0001,
+                init.pitch_env_release,
                 FloatRange::Skewed {
                     min: 0.0001,
                     max: 1999.9,
@@ -2131,33 +3384,33 @@ impl ActuateParams {
                 let update_something = update_something.clone();
                 Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
             }),
-            pitch_env_atk_curve: EnumParam::new("Atk Curve", Oscillator::SmoothStyle::Linear)
+            pitch_env_atk_curve: EnumParam::new("Atk Curve", init.pitch_env_atk_curve)
                 .with_callback({
                     let update_something = update_something.clone();
                     Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
                 }),
-            pitch_env_dec_curve: EnumParam::new("Dec Curve", Oscillator::SmoothStyle::Linear)
+            pitch_env_dec_curve: EnumParam::new("Dec Curve", init.pitch_env_dec_curve)
                 .with_callback({
                     let update_something = update_something.clone();
                     Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
                 }),
-            pitch_env_rel_curve: EnumParam::new("Rel Curve", Oscillator::SmoothStyle::Linear)
+            pitch_env_rel_curve: EnumParam::new("Rel Curve", init.pitch_env_rel_curve)
                 .with_callback({
                     let update_something = update_something.clone();
                     Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
                 }),
-            pitch_enable: BoolParam::new("Pitch Enable", false).with_callback({
+            pitch_enable: BoolParam::new("Pitch Enable", init.pitch_enable).with_callback({
                 let update_something = update_something.clone();
                 Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
             }),
-            pitch_routing: EnumParam::new("Routing", PitchRouting::Osc1).with_callback({
+            pitch_routing: EnumParam::new("Routing", init.pitch_routing.clone()).with_callback({
                 let update_something = update_something.clone();
                 Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
             }),
 
             pitch_env_peak_2: FloatParam::new(
                 "Pitch Env",
-                0.0,
+                init.pitch_env_peak_2,
                 FloatRange::Linear {
                     min: -144.0,
                     max: 144.0,
@@ -2171,7 +3424,7 @@ impl ActuateParams {
             }),
             pitch_env_attack_2: FloatParam::new(
                 "Env Attack",
-                0.0001,
+                init.pitch_env_attack_2,
                 FloatRange::Skewed {
                     min: 0.0001,
                     max: 1999.9,
@@ -2186,7 +3439,7 @@ impl ActuateParams {
             }),
             pitch_env_decay_2: FloatParam::new(
                 "Env Decay",
-                300.0,
+                init.pitch_env_decay_2,
                 FloatRange::Skewed {
                     min: 0.0001,
                     max: 1999.9,
@@ -2201,7 +3454,7 @@ impl ActuateParams {
             }),
             pitch_env_sustain_2: FloatParam::new(
                 "Env Sustain",
-                0.0001,
+                init.pitch_env_sustain_2,
                 FloatRange::Skewed {
                     min: 0.0001,
                     max: 1999.9,
@@ -2216,7 +3469,7 @@ impl ActuateParams {
             }),
             pitch_env_release_2: FloatParam::new(
                 "Env Release",
-                0.0001,
+                init.pitch_env_release_2,
                 FloatRange::Skewed {
                     min: 0.0001,
                     max: 1999.9,
@@ -2229,26 +3482,26 @@ impl ActuateParams {
                 let update_something = update_something.clone();
                 Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
             }),
-            pitch_env_atk_curve_2: EnumParam::new("Atk Curve", Oscillator::SmoothStyle::Linear)
+            pitch_env_atk_curve_2: EnumParam::new("Atk Curve", init.pitch_env_atk_curve_2)
                 .with_callback({
                     let update_something = update_something.clone();
                     Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
                 }),
-            pitch_env_dec_curve_2: EnumParam::new("Dec Curve", Oscillator::SmoothStyle::Linear)
+            pitch_env_dec_curve_2: EnumParam::new("Dec Curve", init.pitch_env_dec_curve_2)
                 .with_callback({
                     let update_something = update_something.clone();
                     Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
                 }),
-            pitch_env_rel_curve_2: EnumParam::new("Rel Curve", Oscillator::SmoothStyle::Linear)
+            pitch_env_rel_curve_2: EnumParam::new("Rel Curve", init.pitch_env_rel_curve_2)
                 .with_callback({
                     let update_something = update_something.clone();
                     Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
                 }),
-            pitch_enable_2: BoolParam::new("Pitch Enable", false).with_callback({
+            pitch_enable_2: BoolParam::new("Pitch Enable", init.pitch_enable_2).with_callback({
                 let update_something = update_something.clone();
                 Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
             }),
-            pitch_routing_2: EnumParam::new("Routing", PitchRouting::Osc1).with_callback({
+            pitch_routing_2: EnumParam::new("Routing", init.pitch_routing_2.clone()).with_callback({
                 let update_something = update_something.clone();
                 Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
             }),
@@ -2257,7 +3510,7 @@ impl ActuateParams {
             ////////////////////////////////////////////////////////////////////////////////////
             additive_amp_1_0: FloatParam::new(
                 "Harmonic 0",
-                1.0,
+                init.additive_amp_1_0,
                 FloatRange::Skewed {
                     min: 0.0,
                     max: 1.0,
@@ -2272,7 +3525,7 @@ impl ActuateParams {
             }),
             additive_amp_1_1: FloatParam::new(
                 "Harmonic 1",
-                0.0,
+                init.additive_amp_1_1,
                 FloatRange::Skewed {
                     min: 0.0,
                     max: 1.0,
@@ -2287,7 +3540,7 @@ impl ActuateParams {
             }),
             additive_amp_1_2: FloatParam::new(
                 "Harmonic 2",
-                0.0,
+                init.additive_amp_1_2,
                 FloatRange::Skewed {
                     min: 0.0,
                     max: 1.0,
@@ -2302,7 +3555,7 @@ impl ActuateParams {
             }),
             additive_amp_1_3: FloatParam::new(
                 "Harmonic 3",
-                0.0,
+                init.additive_amp_1_3,
                 FloatRange::Skewed {
                     min: 0.0,
                     max: 1.0,
@@ -2317,7 +3570,7 @@ impl ActuateParams {
             }),
             additive_amp_1_4: FloatParam::new(
                 "Harmonic 4",
-                0.0,
+                init.additive_amp_1_4,
                 FloatRange::Skewed {
                     min: 0.0,
                     max: 1.0,
@@ -2332,7 +3585,7 @@ impl ActuateParams {
             }),
             additive_amp_1_5: FloatParam::new(
                 "Harmonic 5",
-                0.0,
+                init.additive_amp_1_5,
                 FloatRange::Skewed {
                     min: 0.0,
                     max: 1.0,
@@ -2347,7 +3600,7 @@ impl ActuateParams {
             }),
             additive_amp_1_6: FloatParam::new(
                 "Harmonic 6",
-                0.0,
+                init.additive_amp_1_6,
                 FloatRange::Skewed {
                     min: 0.0,
                     max: 1.0,
@@ -2362,7 +3615,7 @@ impl ActuateParams {
             }),
             additive_amp_1_7: FloatParam::new(
                 "Harmonic 7",
-                0.0,
+                init.additive_amp_1_7,
                 FloatRange::Skewed {
                     min: 0.0,
                     max: 1.0,
@@ -2377,7 +3630,7 @@ impl ActuateParams {
             }),
             additive_amp_1_8: FloatParam::new(
                 "Harmonic 8",
-                0.0,
+                init.additive_amp_1_8,
                 FloatRange::Skewed {
                     min: 0.0,
                     max: 1.0,
@@ -2392,7 +3645,7 @@ impl ActuateParams {
             }),
             additive_amp_1_9: FloatParam::new(
                 "Harmonic 9",
-                0.0,
+                init.additive_amp_1_9,
                 FloatRange::Skewed {
                     min: 0.0,
                     max: 1.0,
@@ -2407,7 +3660,7 @@ impl ActuateParams {
             }),
             additive_amp_1_10: FloatParam::new(
                 "Harmonic 10",
-                0.0,
+                init.additive_amp_1_10,
                 FloatRange::Skewed {
                     min: 0.0,
                     max: 1.0,
@@ -2422,7 +3675,7 @@ impl ActuateParams {
             }),
             additive_amp_1_11: FloatParam::new(
                 "Harmonic 11",
-                0.0,
+                init.additive_amp_1_11,
                 FloatRange::Skewed {
                     min: 0.0,
                     max: 1.0,
@@ -2437,7 +3690,7 @@ impl ActuateParams {
             }),
             additive_amp_1_12: FloatParam::new(
                 "Harmonic 12",
-                0.0,
+                init.additive_amp_1_12,
                 FloatRange::Skewed {
                     min: 0.0,
                     max: 1.0,
@@ -2452,7 +3705,7 @@ impl ActuateParams {
             }),
             additive_amp_1_13: FloatParam::new(
                 "Harmonic 13",
-                0.0,
+                init.additive_amp_1_13,
                 FloatRange::Skewed {
                     min: 0.0,
                     max: 1.0,
@@ -2467,7 +3720,7 @@ impl ActuateParams {
             }),
             additive_amp_1_14: FloatParam::new(
                 "Harmonic 14",
-                0.0,
+                init.additive_amp_1_14,
                 FloatRange::Skewed {
                     min: 0.0,
                     max: 1.0,
@@ -2482,7 +3735,7 @@ impl ActuateParams {
             }),
             additive_amp_1_15: FloatParam::new(
                 "Harmonic 15",
-                0.0,
+                init.additive_amp_1_15,
                 FloatRange::Skewed {
                     min: 0.0,
                     max: 1.0,
@@ -2498,7 +3751,7 @@ impl ActuateParams {
 
             additive_amp_2_0: FloatParam::new(
                 "Harmonic 0",
-                1.0,
+                init.additive_amp_2_0,
                 FloatRange::Skewed {
                     min: 0.0,
                     max: 1.0,
@@ -2513,7 +3766,7 @@ impl ActuateParams {
             }),
             additive_amp_2_1: FloatParam::new(
                 "Harmonic 1",
-                0.0,
+                init.additive_amp_2_1,
                 FloatRange::Skewed {
                     min: 0.0,
                     max: 1.0,
@@ -2528,7 +3781,7 @@ impl ActuateParams {
             }),
             additive_amp_2_2: FloatParam::new(
                 "Harmonic 2",
-                0.0,
+                init.additive_amp_2_2,
                 FloatRange::Skewed {
                     min: 0.0,
                     max: 1.0,
@@ -2543,7 +3796,7 @@ impl ActuateParams {
             }),
             additive_amp_2_3: FloatParam::new(
                 "Harmonic 3",
-                0.0,
+                init.additive_amp_2_3,
                 FloatRange::Skewed {
                     min: 0.0,
                     max: 1.0,
@@ -2558,7 +3811,7 @@ impl ActuateParams {
             }),
             additive_amp_2_4: FloatParam::new(
                 "Harmonic 4",
-                0.0,
+                init.additive_amp_2_4,
                 FloatRange::Skewed {
                     min: 0.0,
                     max: 1.0,
@@ -2573,7 +3826,7 @@ impl ActuateParams {
             }),
             additive_amp_2_5: FloatParam::new(
                 "Harmonic 5",
-                0.0,
+                init.additive_amp_2_5,
                 FloatRange::Skewed {
                     min: 0.0,
                     max: 1.0,
@@ -2588,7 +3841,7 @@ impl ActuateParams {
             }),
             additive_amp_2_6: FloatParam::new(
                 "Harmonic 6",
-                0.0,
+                init.additive_amp_2_6,
                 FloatRange::Skewed {
                     min: 0.0,
                     max: 1.0,
@@ -2603,7 +3856,7 @@ impl ActuateParams {
             }),
             additive_amp_2_7: FloatParam::new(
                 "Harmonic 7",
-                0.0,
+                init.additive_amp_2_7,
                 FloatRange::Skewed {
                     min: 0.0,
                     max: 1.0,
@@ -2618,7 +3871,7 @@ impl ActuateParams {
             }),
             additive_amp_2_8: FloatParam::new(
                 "Harmonic 8",
-                0.0,
+                init.additive_amp_2_8,
                 FloatRange::Skewed {
                     min: 0.0,
                     max: 1.0,
@@ -2633,7 +3886,7 @@ impl ActuateParams {
             }),
             additive_amp_2_9: FloatParam::new(
                 "Harmonic 9",
-                0.0,
+                init.additive_amp_2_9,
                 FloatRange::Skewed {
                     min: 0.0,
                     max: 1.0,
@@ -2648,7 +3901,7 @@ impl ActuateParams {
             }),
             additive_amp_2_10: FloatParam::new(
                 "Harmonic 10",
-                0.0,
+                init.additive_amp_2_10,
                 FloatRange::Skewed {
                     min: 0.0,
                     max: 1.0,
@@ -2663,7 +3916,7 @@ impl ActuateParams {
             }),
             additive_amp_2_11: FloatParam::new(
                 "Harmonic 11",
-                0.0,
+                init.additive_amp_2_11,
                 FloatRange::Skewed {
                     min: 0.0,
                     max: 1.0,
@@ -2678,7 +3931,7 @@ impl ActuateParams {
             }),
             additive_amp_2_12: FloatParam::new(
                 "Harmonic 12",
-                0.0,
+                init.additive_amp_2_12,
                 FloatRange::Skewed {
                     min: 0.0,
                     max: 1.0,
@@ -2693,7 +3946,7 @@ impl ActuateParams {
             }),
             additive_amp_2_13: FloatParam::new(
                 "Harmonic 13",
-                0.0,
+                init.additive_amp_2_13,
                 FloatRange::Skewed {
                     min: 0.0,
                     max: 1.0,
@@ -2708,7 +3961,7 @@ impl ActuateParams {
             }),
             additive_amp_2_14: FloatParam::new(
                 "Harmonic 14",
-                0.0,
+                init.additive_amp_2_14,
                 FloatRange::Skewed {
                     min: 0.0,
                     max: 1.0,
@@ -2723,7 +3976,7 @@ impl ActuateParams {
             }),
             additive_amp_2_15: FloatParam::new(
                 "Harmonic 15",
-                0.0,
+                init.additive_amp_2_15,
                 FloatRange::Skewed {
                     min: 0.0,
                     max: 1.0,
@@ -2739,7 +3992,7 @@ impl ActuateParams {
 
             additive_amp_3_0: FloatParam::new(
                 "Harmonic 0",
-                1.0,
+                init.additive_amp_3_0,
                 FloatRange::Skewed {
                     min: 0.0,
                     max: 1.0,
@@ -2754,7 +4007,7 @@ impl ActuateParams {
             }),
             additive_amp_3_1: FloatParam::new(
                 "Harmonic 1",
-                0.0,
+                init.additive_amp_3_1,
                 FloatRange::Skewed {
                     min: 0.0,
                     max: 1.0,
@@ -2769,7 +4022,7 @@ impl ActuateParams {
             }),
             additive_amp_3_2: FloatParam::new(
                 "Harmonic 2",
-                0.0,
+                init.additive_amp_3_2,
                 FloatRange::Skewed {
                     min: 0.0,
                     max: 1.0,
@@ -2784,7 +4037,7 @@ impl ActuateParams {
             }),
             additive_amp_3_3: FloatParam::new(
                 "Harmonic 3",
-                0.0,
+                init.additive_amp_3_3,
                 FloatRange::Skewed {
                     min: 0.0,
                     max: 1.0,
@@ -2799,7 +4052,7 @@ impl ActuateParams {
             }),
             additive_amp_3_4: FloatParam::new(
                 "Harmonic 4",
-                0.0,
+                init.additive_amp_3_4,
                 FloatRange::Skewed {
                     min: 0.0,
                     max: 1.0,
@@ -2814,7 +4067,7 @@ impl ActuateParams {
             }),
             additive_amp_3_5: FloatParam::new(
                 "Harmonic 5",
-                0.0,
+                init.additive_amp_3_5,
                 FloatRange::Skewed {
                     min: 0.0,
                     max: 1.0,
@@ -2829,7 +4082,7 @@ impl ActuateParams {
             }),
             additive_amp_3_6: FloatParam::new(
                 "Harmonic 6",
-                0.0,
+                init.additive_amp_3_6,
                 FloatRange::Skewed {
                     min: 0.0,
                     max: 1.0,
@@ -2844,7 +4097,7 @@ impl ActuateParams {
             }),
             additive_amp_3_7: FloatParam::new(
                 "Harmonic 7",
-                0.0,
+                init.additive_amp_3_7,
                 FloatRange::Skewed {
                     min: 0.0,
                     max: 1.0,
@@ -2859,7 +4112,7 @@ impl ActuateParams {
             }),
             additive_amp_3_8: FloatParam::new(
                 "Harmonic 8",
-                0.0,
+                init.additive_amp_3_8,
                 FloatRange::Skewed {
                     min: 0.0,
                     max: 1.0,
@@ -2874,7 +4127,7 @@ impl ActuateParams {
             }),
             additive_amp_3_9: FloatParam::new(
                 "Harmonic 9",
-                0.0,
+                init.additive_amp_3_9,
                 FloatRange::Skewed {
                     min: 0.0,
                     max: 1.0,
@@ -2889,7 +4142,7 @@ impl ActuateParams {
             }),
             additive_amp_3_10: FloatParam::new(
                 "Harmonic 10",
-                0.0,
+                init.additive_amp_3_10,
                 FloatRange::Skewed {
                     min: 0.0,
                     max: 1.0,
@@ -2904,7 +4157,7 @@ impl ActuateParams {
             }),
             additive_amp_3_11: FloatParam::new(
                 "Harmonic 11",
-                0.0,
+                init.additive_amp_3_11,
                 FloatRange::Skewed {
                     min: 0.0,
                     max: 1.0,
@@ -2919,7 +4172,7 @@ impl ActuateParams {
             }),
             additive_amp_3_12: FloatParam::new(
                 "Harmonic 12",
-                0.0,
+                init.additive_amp_3_12,
                 FloatRange::Skewed {
                     min: 0.0,
                     max: 1.0,
@@ -2934,7 +4187,7 @@ impl ActuateParams {
             }),
             additive_amp_3_13: FloatParam::new(
                 "Harmonic 13",
-                0.0,
+                init.additive_amp_3_13,
                 FloatRange::Skewed {
                     min: 0.0,
                     max: 1.0,
@@ -2949,7 +4202,7 @@ impl ActuateParams {
             }),
             additive_amp_3_14: FloatParam::new(
                 "Harmonic 14",
-                0.0,
+                init.additive_amp_3_14,
                 FloatRange::Skewed {
                     min: 0.0,
                     max: 1.0,
@@ -2964,7 +4217,7 @@ impl ActuateParams {
             }),
             additive_amp_3_15: FloatParam::new(
                 "Harmonic 15",
-                0.0,
+                init.additive_amp_3_15,
                 FloatRange::Skewed {
                     min: 0.0,
                     max: 1.0,
@@ -2980,27 +4233,27 @@ impl ActuateParams {
 
             // LFOs
             ////////////////////////////////////////////////////////////////////////////////////
-            lfo1_enable: BoolParam::new("LFO 1 Enable", false),
-            lfo2_enable: BoolParam::new("LFO 2 Enable", false),
-            lfo3_enable: BoolParam::new("LFO 3 Enable", false),
-            lfo1_retrigger: EnumParam::new("LFO Retrigger", LFOController::LFORetrigger::None)
+            lfo1_enable: BoolParam::new("LFO 1 Enable", init.lfo1_enable),
+            lfo2_enable: BoolParam::new("LFO 2 Enable", init.lfo2_enable),
+            lfo3_enable: BoolParam::new("LFO 3 Enable", init.lfo3_enable),
+            lfo1_retrigger: EnumParam::new("LFO Retrigger", init.lfo1_retrigger)
                 .with_callback({
                     let update_something = update_something.clone();
                     Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
                 }),
-            lfo2_retrigger: EnumParam::new("LFO Retrigger", LFOController::LFORetrigger::None)
+            lfo2_retrigger: EnumParam::new("LFO Retrigger", init.lfo2_retrigger)
                 .with_callback({
                     let update_something = update_something.clone();
                     Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
                 }),
-            lfo3_retrigger: EnumParam::new("LFO Retrigger", LFOController::LFORetrigger::None)
+            lfo3_retrigger: EnumParam::new("LFO Retrigger", init.lfo3_retrigger)
                 .with_callback({
                     let update_something = update_something.clone();
                     Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
                 }),
             lfo1_freq: FloatParam::new(
                 "LFO1 Freq",
-                4.62, // Defualt is half note at 138 bpm
+                init.lfo1_freq, // Defualt is half note at 138 bpm
                 FloatRange::Skewed {
                     min: 1.0,
                     max: 9600.0,
@@ -3014,7 +4267,7 @@ impl ActuateParams {
             }),
             lfo2_freq: FloatParam::new(
                 "LFO2 Freq",
-                4.62, // Defualt is half note at 138 bpm
+                init.lfo2_freq, // Defualt is half note at 138 bpm
                 FloatRange::Skewed {
                     min: 1.0,
                     max: 9600.0,
@@ -3028,7 +4281,7 @@ impl ActuateParams {
             }),
             lfo3_freq: FloatParam::new(
                 "LFO3 Freq",
-                4.62, // Defualt is half note at 138 bpm
+                init.lfo3_freq, // Defualt is half note at 138 bpm
                 FloatRange::Skewed {
                     min: 1.0,
                     max: 9600.0,
@@ -3040,36 +4293,60 @@ impl ActuateParams {
                 let update_something = update_something.clone();
                 Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
             }),
-            lfo1_snap: EnumParam::new("LFO1 Snap", LFOController::LFOSnapValues::Half),
-            lfo2_snap: EnumParam::new("LFO2 Snap", LFOController::LFOSnapValues::Half),
-            lfo3_snap: EnumParam::new("LFO3 Snap", LFOController::LFOSnapValues::Half),
-            lfo1_sync: BoolParam::new("LFO1 Sync", true),
-            lfo2_sync: BoolParam::new("LFO2 Sync", true),
-            lfo3_sync: BoolParam::new("LFO3 Sync", true),
-            lfo1_waveform: EnumParam::new("LFO1 Waveform", LFOController::Waveform::Sine),
-            lfo2_waveform: EnumParam::new("LFO2 Waveform", LFOController::Waveform::Sine),
-            lfo3_waveform: EnumParam::new("LFO3 Waveform", LFOController::Waveform::Sine),
+            lfo1_snap: EnumParam::new("LFO1 Snap", init.lfo1_snap),
+            lfo2_snap: EnumParam::new("LFO2 Snap", init.lfo2_snap),
+            lfo3_snap: EnumParam::new("LFO3 Snap", init.lfo3_snap),
+            lfo1_sync: BoolParam::new("LFO1 Sync", init.lfo1_sync),
+            lfo2_sync: BoolParam::new("LFO2 Sync", init.lfo2_sync),
+            lfo3_sync: BoolParam::new("LFO3 Sync", init.lfo3_sync),
+            lfo1_gate: BoolParam::new("LFO1 Gate", init.lfo1_gate),
+            lfo2_gate: BoolParam::new("LFO2 Gate", init.lfo2_gate),
+            lfo3_gate: BoolParam::new("LFO3 Gate", init.lfo3_gate),
+            lfo1_waveform: EnumParam::new("LFO1 Waveform", init.lfo1_waveform),
+            lfo2_waveform: EnumParam::new("LFO2 Waveform", init.lfo2_waveform),
+            lfo3_waveform: EnumParam::new("LFO3 Waveform", init.lfo3_waveform),
             lfo1_phase: FloatParam::new(
                 "LFO1 Phase",
-                0.0,
+                init.lfo1_phase,
                 FloatRange::Linear { min: 0.0, max: 1.0 },
             ),
             lfo2_phase: FloatParam::new(
                 "LFO2 Phase",
-                0.0,
+                init.lfo2_phase,
                 FloatRange::Linear { min: 0.0, max: 1.0 },
             ),
             lfo3_phase: FloatParam::new(
                 "LFO3 Phase",
-                0.0,
+                init.lfo3_phase,
                 FloatRange::Linear { min: 0.0, max: 1.0 },
             ),
+            lfo1_depth: FloatParam::new(
+                "LFO1 Depth",
+                init.lfo1_depth,
+                FloatRange::Linear { min: 0.0, max: 1.0 },
+            )
+            .with_value_to_string(formatters::v2s_f32_percentage(0))
+            .with_unit("%"),
+            lfo2_depth: FloatParam::new(
+                "LFO2 Depth",
+                init.lfo2_depth,
+                FloatRange::Linear { min: 0.0, max: 1.0 },
+            )
+            .with_value_to_string(formatters::v2s_f32_percentage(0))
+            .with_unit("%"),
+            lfo3_depth: FloatParam::new(
+                "LFO3 Depth",
+                init.lfo3_depth,
+                FloatRange::Linear { min: 0.0, max: 1.0 },
+            )
+            .with_value_to_string(formatters::v2s_f32_percentage(0))
+            .with_unit("%"),
 
             // Modulators
             ////////////////////////////////////////////////////////////////////////////////////
             mod_amount_knob_1: FloatParam::new(
                 "Mod Amt 1",
-                0.0,
+                init.mod_amount_1,
                 FloatRange::Linear {
                     min: -1.0,
                     max: 1.0,
@@ -3078,7 +4355,7 @@ impl ActuateParams {
             .with_value_to_string(format_nothing()),
             mod_amount_knob_2: FloatParam::new(
                 "Mod Amt 2",
-                0.0,
+                init.mod_amount_2,
                 FloatRange::Linear {
                     min: -1.0,
                     max: 1.0,
@@ -3087,7 +4364,7 @@ impl ActuateParams {
             .with_value_to_string(format_nothing()),
             mod_amount_knob_3: FloatParam::new(
                 "Mod Amt 3",
-                0.0,
+                init.mod_amount_3,
                 FloatRange::Linear {
                     min: -1.0,
                     max: 1.0,
@@ -3096,27 +4373,73 @@ impl ActuateParams {
             .with_value_to_string(format_nothing()),
             mod_amount_knob_4: FloatParam::new(
                 "Mod Amt 4",
-                0.0,
+                init.mod_amount_4,
+                FloatRange::Linear {
+                    min: -1.0,
+                    max: 1.0,
+                },
+            )
+            .with_value_to_string(format_nothing()),
+            mod_amount_knob_5: FloatParam::new(
+                "Mod Amt 5",
+                init.mod_amount_5,
+                FloatRange::Linear {
+                    min: -1.0,
+                    max: 1.0,
+                },
+            )
+            .with_value_to_string(format_nothing()),
+            mod_amount_knob_6: FloatParam::new(
+                "Mod Amt 6",
+                init.mod_amount_6,
+                FloatRange::Linear {
+                    min: -1.0,
+                    max: 1.0,
+                },
+            )
+            .with_value_to_string(format_nothing()),
+            mod_amount_knob_7: FloatParam::new(
+                "Mod Amt 7",
+                init.mod_amount_7,
+                FloatRange::Linear {
+                    min: -1.0,
+                    max: 1.0,
+                },
+            )
+            .with_value_to_string(format_nothing()),
+            mod_amount_knob_8: FloatParam::new(
+                "Mod Amt 8",
+                init.mod_amount_8,
                 FloatRange::Linear {
                     min: -1.0,
                     max: 1.0,
                 },
             )
             .with_value_to_string(format_nothing()),
-            mod_source_1: EnumParam::new("Source 1", ModulationSource::None),
-            mod_source_2: EnumParam::new("Source 2", ModulationSource::None),
-            mod_source_3: EnumParam::new("Source 3", ModulationSource::None),
-            mod_source_4: EnumParam::new("Source 4", ModulationSource::None),
-            mod_destination_1: EnumParam::new("Dest 1", ModulationDestination::None),
-            mod_destination_2: EnumParam::new("Dest 2", ModulationDestination::None),
-            mod_destination_3: EnumParam::new("Dest 3", ModulationDestination::None),
-            mod_destination_4: EnumParam::new("Dest 4", ModulationDestination::None),
+            mod_source_1: EnumParam::new("Source 1", init.mod_source_1.clone()),
+            mod_source_2: EnumParam::new("Source 2", init.mod_source_2.clone()),
+            mod_source_3: EnumParam::new("Source 3", init.mod_source_3.clone()),
+            mod_source_4: EnumParam::new("Source 4", init.mod_source_4.clone()),
+            mod_source_5: EnumParam::new("Source 5", init.mod_source_5),
+            mod_source_6: EnumParam::new("Source 6", init.mod_source_6),
+            mod_source_7: EnumParam::new("Source 7", init.mod_source_7),
+            mod_source_8: EnumParam::new("Source 8", init.mod_source_8),
+            mod_destination_1: EnumParam::new("Dest 1", init.mod_dest_1.clone()),
+            mod_destination_2: EnumParam::new("Dest 2", init.mod_dest_2.clone()),
+            mod_destination_3: EnumParam::new("Dest 3", init.mod_dest_3.clone()),
+            mod_destination_4: EnumParam::new("Dest 4", init.mod_dest_4.clone()),
+            mod_destination_5: EnumParam::new("Dest 5", init.mod_dest_5),
+            mod_destination_6: EnumParam::new("Dest 6", init.mod_dest_6),
+            mod_destination_7: EnumParam::new("Dest 7", init.mod_dest_7),
+            mod_destination_8: EnumParam::new("Dest 8", init.mod_dest_8),
+            pitch_mod_quantize: BoolParam::new("Quantize Pitch Mod", init.pitch_mod_quantize),
+            pitch_mod_scale: EnumParam::new("Pitch Mod Scale", init.pitch_mod_scale),
 
             // EQ
-            pre_use_eq: BoolParam::new("EQ", false),
+            pre_use_eq: BoolParam::new("EQ", init.pre_use_eq),
             pre_low_freq: FloatParam::new(
                 "Low",
-                800.0,
+                init.pre_low_freq,
                 FloatRange::Linear {
                     min: 100.0,
                     max: 2000.0,
@@ -3127,7 +4450,7 @@ impl ActuateParams {
             .with_value_to_string(formatters::v2s_f32_hz_then_khz(0)),
             pre_mid_freq: FloatParam::new(
                 "Mid",
-                3000.0,
+                init.pre_mid_freq,
                 FloatRange::Linear {
                     min: 1000.0,
                     max: 8000.0,
@@ -3138,7 +4461,7 @@ impl ActuateParams {
             .with_value_to_string(formatters::v2s_f32_hz_then_khz(0)),
             pre_high_freq: FloatParam::new(
                 "High",
-                10000.0,
+                init.pre_high_freq,
                 FloatRange::Linear {
                     min: 3000.0,
                     max: 20000.0,
@@ -3149,7 +4472,7 @@ impl ActuateParams {
             .with_value_to_string(formatters::v2s_f32_hz_then_khz(0)),
             pre_low_gain: FloatParam::new(
                 "Low Gain",
-                0.0,
+                init.pre_low_gain,
                 FloatRange::Linear {
                     min: -12.0,
                     max: 12.0,
@@ -3158,7 +4481,7 @@ impl ActuateParams {
             .with_value_to_string(formatters::v2s_f32_rounded(1)),
             pre_mid_gain: FloatParam::new(
                 "Mid Gain",
-                0.0,
+                init.pre_mid_gain,
                 FloatRange::Linear {
                     min: -12.0,
                     max: 12.0,
@@ -3167,31 +4490,100 @@ impl ActuateParams {
             .with_value_to_string(formatters::v2s_f32_rounded(1)),
             pre_high_gain: FloatParam::new(
                 "High Gain",
-                0.0,
+                init.pre_high_gain,
                 FloatRange::Linear {
                     min: -12.0,
                     max: 12.0,
                 },
             )
             .with_value_to_string(formatters::v2s_f32_rounded(1)),
+            master_tilt: FloatParam::new(
+                "Tilt",
+                init.master_tilt,
+                FloatRange::Linear {
+                    min: -6.0,
+                    max: 6.0,
+                },
+            )
+            .with_step_size(0.1)
+            .with_unit(" dB")
+            .with_value_to_string(formatters::v2s_f32_rounded(1)),
+            // The bottom of the range is Off
+            master_hpf: FloatParam::new(
+                "Rumble HPF",
+                init.master_hpf,
+                FloatRange::Skewed {
+                    min: MASTER_HPF_OFF,
+                    max: 200.0,
+                    factor: 0.5,
+                },
+            )
+            .with_step_size(1.0)
+            .with_value_to_string(Arc::new(|value| {
+                if value <= MASTER_HPF_OFF {
+                    String::from("Off")
+                } else {
+                    format!("{:.0} Hz", value)
+                }
+            })),
+            use_bass_mono: BoolParam::new("Bass Mono", init.use_bass_mono),
+            elliptical_freq: FloatParam::new(
+                "Mono Below",
+                init.elliptical_freq,
+                FloatRange::Skewed {
+                    min: 40.0,
+                    max: 400.0,
+                    factor: 0.5,
+                },
+            )
+            .with_step_size(1.0)
+            .with_unit(" Hz")
+            .with_value_to_string(formatters::v2s_f32_rounded(0)),
+            // Master width macro, negative narrows to mono and positive widens while pulling the lows to mono
+            space: FloatParam::new("Space", init.space, FloatRange::Linear { min: -1.0, max: 1.0 })
+                .with_smoother(SmoothingStyle::Linear(5.0))
+                .with_unit("%")
+                .with_value_to_string(formatters::v2s_f32_percentage(0)),
+            tape_stop: BoolParam::new("Tape Stop", false),
+            tape_stop_time: FloatParam::new(
+                "Stop Time",
+                init.tape_stop_time,
+                FloatRange::Skewed {
+                    min: 50.0,
+                    max: TAPE_STOP_MAX_MS,
+                    factor: 0.5,
+                },
+            )
+            .with_step_size(1.0)
+            .with_unit(" ms")
+            .with_value_to_string(formatters::v2s_f32_rounded(0)),
+            use_dither: BoolParam::new("Dither", false),
+            dither_shaping: BoolParam::new("Noise Shaping", false),
 
             // fx
-            use_fx: BoolParam::new("Use FX", true),
+            use_fx: BoolParam::new("Use FX", init.use_fx),
+            dry_kill: BoolParam::new("Dry Kill", false),
+
+            use_transient: BoolParam::new("Transient Shaper", init.use_transient),
+            transient_attack: FloatParam::new("Attack", init.transient_attack, FloatRange::Linear { min: -1.0, max: 1.0 })
+                .with_value_to_string(formatters::v2s_f32_rounded(2)),
+            transient_sustain: FloatParam::new("Sustain", init.transient_sustain, FloatRange::Linear { min: -1.0, max: 1.0 })
+                .with_value_to_string(formatters::v2s_f32_rounded(2)),
 
-            use_compressor: BoolParam::new("Compressor", false),
-            comp_amt: FloatParam::new("Amount", 0.3, FloatRange::Linear { min: 0.0, max: 1.0 })
+            use_compressor: BoolParam::new("Compressor", init.use_compressor),
+            comp_amt: FloatParam::new("Amount", init.comp_amt, FloatRange::Linear { min: 0.0, max: 1.0 })
                 .with_value_to_string(formatters::v2s_f32_rounded(2)),
-            comp_atk: FloatParam::new("Attack", 0.8, FloatRange::Linear { min: 0.0, max: 1.0 })
+            comp_atk: FloatParam::new("Attack", init.comp_atk, FloatRange::Linear { min: 0.0, max: 1.0 })
                 .with_value_to_string(formatters::v2s_f32_rounded(2)),
-            comp_rel: FloatParam::new("Release", 0.3, FloatRange::Linear { min: 0.0, max: 1.0 })
+            comp_rel: FloatParam::new("Release", init.comp_rel, FloatRange::Linear { min: 0.0, max: 1.0 })
                 .with_value_to_string(formatters::v2s_f32_rounded(2)),
-            comp_drive: FloatParam::new("Drive", 0.3, FloatRange::Linear { min: 0.0, max: 1.0 })
+            comp_drive: FloatParam::new("Drive", init.comp_drive, FloatRange::Linear { min: 0.0, max: 1.0 })
                 .with_value_to_string(formatters::v2s_f32_rounded(2)),
 
-            use_abass: BoolParam::new("ABass", false),
+            use_abass: BoolParam::new("ABass", init.use_abass),
             abass_amount: FloatParam::new(
                 "Amount",
-                0.000668,
+                init.abass_amount,
                 FloatRange::Skewed {
                     min: 0.0,
                     max: 1.0,
@@ -3200,37 +4592,57 @@ impl ActuateParams {
             )
             .with_value_to_string(formatters::v2s_f32_rounded(5)),
 
-            use_saturation: BoolParam::new("Saturation", false),
-            sat_amt: FloatParam::new("Amount", 0.0, FloatRange::Linear { min: 0.0, max: 1.0 })
+            use_saturation: BoolParam::new("Saturation", init.use_saturation),
+            sat_amt: FloatParam::new("Amount", init.sat_amount, FloatRange::Linear { min: 0.0, max: 1.0 })
                 .with_value_to_string(formatters::v2s_f32_rounded(2)),
-            sat_type: EnumParam::new("Type", SaturationType::Tape),
+            sat_type: EnumParam::new("Type", init.sat_type.clone()),
+            sat_stereo_mode: EnumParam::new("Sat Stereo", init.sat_stereo_mode),
 
-            use_delay: BoolParam::new("Delay", false),
-            delay_amount: FloatParam::new("Amount", 0.5, FloatRange::Linear { min: 0.0, max: 1.0 })
+            use_delay: BoolParam::new("Delay", init.use_delay),
+            delay_amount: FloatParam::new("Amount", init.delay_amount, FloatRange::Linear { min: 0.0, max: 1.0 })
                 .with_value_to_string(formatters::v2s_f32_rounded(2)),
-            delay_time: EnumParam::new("Time", DelaySnapValues::Quarter),
+            delay_time: EnumParam::new("Time", init.delay_time.clone()),
             delay_decay: FloatParam::new(
                 "Decay",
-                0.5,
+                init.delay_decay,
+                FloatRange::Linear {
+                    min: 0.001,
+                    max: 1.0,
+                },
+            )
+            .with_value_to_string(formatters::v2s_f32_rounded(2)),
+            delay_type: EnumParam::new("Type", init.delay_type.clone()),
+            delay_pitch: FloatParam::new("Pitch", init.delay_pitch, FloatRange::Linear { min: -12.0, max: 12.0 })
+                .with_step_size(0.1)
+                .with_unit(" st")
+                .with_value_to_string(formatters::v2s_f32_rounded(1)),
+            use_delay_2: BoolParam::new("Delay 2", init.use_delay_2),
+            delay_2_amount: FloatParam::new("Amount 2", init.delay_2_amount, FloatRange::Linear { min: 0.0, max: 1.0 })
+                .with_value_to_string(formatters::v2s_f32_rounded(2)),
+            delay_2_time: EnumParam::new("Time 2", init.delay_2_time.clone()),
+            delay_2_decay: FloatParam::new(
+                "Decay 2",
+                init.delay_2_decay,
                 FloatRange::Linear {
                     min: 0.001,
                     max: 1.0,
                 },
             )
             .with_value_to_string(formatters::v2s_f32_rounded(2)),
-            delay_type: EnumParam::new("Type", DelayType::Stereo),
+            delay_2_type: EnumParam::new("Type 2", init.delay_2_type.clone()),
+            delay_routing: EnumParam::new("Routing", init.delay_routing.clone()),
 
-            use_reverb: BoolParam::new("Reverb", false),
-            reverb_model: EnumParam::new("Model", ReverbModel::Default),
+            use_reverb: BoolParam::new("Reverb", init.use_reverb),
+            reverb_model: EnumParam::new("Model", init.reverb_model.clone()),
             reverb_amount: FloatParam::new(
                 "Amount",
-                0.85,
+                init.reverb_amount,
                 FloatRange::Linear { min: 0.0, max: 1.0 },
             )
             .with_value_to_string(formatters::v2s_f32_rounded(2)),
             reverb_size: FloatParam::new(
                 "Size",
-                1.0,
+                init.reverb_size,
                 FloatRange::Linear {
                     min: 0.001,
                     max: 2.0,
@@ -3239,23 +4651,43 @@ impl ActuateParams {
             .with_value_to_string(formatters::v2s_f32_rounded(2)),
             reverb_feedback: FloatParam::new(
                 "Feedback",
-                0.28,
+                init.reverb_feedback,
+                FloatRange::Linear { min: 0.0, max: 1.0 },
+            )
+            .with_value_to_string(formatters::v2s_f32_rounded(2)),
+            reverb_predelay: FloatParam::new(
+                "Pre-Delay",
+                init.reverb_predelay,
+                FloatRange::Linear { min: 0.0, max: REVERB_PREDELAY_MAX_MS },
+            )
+            .with_unit(" ms")
+            .with_value_to_string(formatters::v2s_f32_rounded(1)),
+            // Middle is the original tap spread
+            reverb_diffusion: FloatParam::new(
+                "Diffusion",
+                init.reverb_diffusion,
+                FloatRange::Linear { min: 0.0, max: 1.0 },
+            )
+            .with_value_to_string(formatters::v2s_f32_rounded(2)),
+            reverb_density: FloatParam::new(
+                "Density",
+                init.reverb_density,
                 FloatRange::Linear { min: 0.0, max: 1.0 },
             )
             .with_value_to_string(formatters::v2s_f32_rounded(2)),
 
-            use_phaser: BoolParam::new("Phaser", false),
+            use_phaser: BoolParam::new("Phaser", init.use_phaser),
             phaser_amount: FloatParam::new(
                 "Amount",
-                0.5,
+                init.phaser_amount,
                 FloatRange::Linear { min: 0.0, max: 1.0 },
             )
             .with_value_to_string(formatters::v2s_f32_rounded(2)),
-            phaser_depth: FloatParam::new("Depth", 1.0, FloatRange::Linear { min: 0.0, max: 1.0 })
+            phaser_depth: FloatParam::new("Depth", init.phaser_depth, FloatRange::Linear { min: 0.0, max: 1.0 })
                 .with_value_to_string(formatters::v2s_f32_rounded(2)),
             phaser_rate: FloatParam::new(
                 "Rate",
-                1.0,
+                init.phaser_rate,
                 FloatRange::Linear {
                     min: 0.001,
                     max: 16.0,
@@ -3265,27 +4697,31 @@ impl ActuateParams {
             .with_value_to_string(formatters::v2s_f32_rounded(2)),
             phaser_feedback: FloatParam::new(
                 "Feedback",
-                0.5,
+                init.phaser_feedback,
                 FloatRange::Linear { min: 0.0, max: 1.0 },
             )
             .with_value_to_string(formatters::v2s_f32_rounded(2)),
+            phaser_stereo: FloatParam::new("Stereo", init.phaser_stereo, FloatRange::Linear { min: 0.0, max: 1.0 })
+                .with_unit("%")
+                .with_value_to_string(formatters::v2s_f32_percentage(0))
+                .with_string_to_value(formatters::s2v_f32_percentage()),
 
-            use_buffermod: BoolParam::new("Buffer Modulator", false),
+            use_buffermod: BoolParam::new("Buffer Modulator", init.use_buffermod),
             buffermod_amount: FloatParam::new(
                 "Amount",
-                0.5,
+                init.buffermod_amount,
                 FloatRange::Linear { min: 0.0, max: 1.0 },
             )
             .with_value_to_string(formatters::v2s_f32_rounded(2)),
             buffermod_depth: FloatParam::new(
                 "Depth",
-                1.0,
+                init.buffermod_depth,
                 FloatRange::Linear { min: 0.0, max: 1.0 },
             )
             .with_value_to_string(formatters::v2s_f32_rounded(2)),
             buffermod_spread: FloatParam::new(
                 "Spread",
-                0.0,
+                init.buffermod_spread,
                 FloatRange::Skewed {
                     min: 0.0,
                     max: 1.0,
@@ -3296,7 +4732,7 @@ impl ActuateParams {
             .with_value_to_string(formatters::v2s_f32_rounded(3)),
             buffermod_rate: FloatParam::new(
                 "Rate",
-                0.01,
+                init.buffermod_rate,
                 FloatRange::Skewed {
                     min: 0.01,
                     max: 3.0,
@@ -3307,7 +4743,7 @@ impl ActuateParams {
             .with_value_to_string(formatters::v2s_f32_rounded(3)),
             buffermod_timing: FloatParam::new(
                 "Buffer",
-                620.0,
+                init.buffermod_timing,
                 FloatRange::Skewed {
                     min: 1.0,
                     max: 2700.0,
@@ -3316,19 +4752,23 @@ impl ActuateParams {
             )
             .with_step_size(1.0)
             .with_value_to_string(formatters::v2s_f32_rounded(0)),
+            buffermod_stereo: FloatParam::new("Stereo", init.buffermod_stereo, FloatRange::Linear { min: 0.0, max: 1.0 })
+                .with_unit("%")
+                .with_value_to_string(formatters::v2s_f32_percentage(0))
+                .with_string_to_value(formatters::s2v_f32_percentage()),
 
-            use_flanger: BoolParam::new("Flanger", false),
+            use_flanger: BoolParam::new("Flanger", init.use_flanger),
             flanger_amount: FloatParam::new(
                 "Amount",
-                0.5,
+                init.flanger_amount,
                 FloatRange::Linear { min: 0.0, max: 1.0 },
             )
             .with_value_to_string(formatters::v2s_f32_rounded(2)),
-            flanger_depth: FloatParam::new("Depth", 0.5, FloatRange::Linear { min: 0.0, max: 1.0 })
+            flanger_depth: FloatParam::new("Depth", init.flanger_depth, FloatRange::Linear { min: 0.0, max: 1.0 })
                 .with_value_to_string(formatters::v2s_f32_rounded(2)),
             flanger_rate: FloatParam::new(
                 "Rate",
-                5.0,
+                init.flanger_rate,
                 FloatRange::Linear {
                     min: 0.001,
                     max: 24.0,
@@ -3338,21 +4778,25 @@ impl ActuateParams {
             .with_value_to_string(formatters::v2s_f32_rounded(2)),
             flanger_feedback: FloatParam::new(
                 "Feedback",
-                0.5,
+                init.flanger_feedback,
                 FloatRange::Linear { min: 0.0, max: 1.0 },
             )
             .with_value_to_string(formatters::v2s_f32_rounded(2)),
+            flanger_stereo: FloatParam::new("Stereo", init.flanger_stereo, FloatRange::Linear { min: 0.0, max: 1.0 })
+                .with_unit("%")
+                .with_value_to_string(formatters::v2s_f32_percentage(0))
+                .with_string_to_value(formatters::s2v_f32_percentage()),
 
-            use_chorus: BoolParam::new("Chorus", false),
+            use_chorus: BoolParam::new("Chorus", init.use_chorus),
             chorus_amount: FloatParam::new(
                 "Amount",
-                0.8,
+                init.chorus_amount,
                 FloatRange::Linear { min: 0.0, max: 1.0 },
             )
             .with_value_to_string(formatters::v2s_f32_rounded(3)),
             chorus_range: FloatParam::new(
                 "Range", 
-                0.5, 
+                init.chorus_range, 
                 FloatRange::Linear { 
                     min: 0.0, 
                     max: 1.0 
@@ -3361,7 +4805,7 @@ impl ActuateParams {
             .with_value_to_string(formatters::v2s_f32_rounded(3)),
             chorus_speed: FloatParam::new(
                 "Speed",
-                0.5,
+                init.chorus_speed,
                 FloatRange::Linear {
                     min: 0.0,
                     max: 1.0,
@@ -3369,29 +4813,74 @@ impl ActuateParams {
             )
             .with_value_to_string(formatters::v2s_f32_rounded(3)),
 
-            use_limiter: BoolParam::new("Limiter", false),
+            use_limiter: BoolParam::new("Limiter", init.use_limiter),
             limiter_threshold: FloatParam::new(
                 "Threshold",
-                0.5,
+                init.limiter_threshold,
                 FloatRange::Linear { min: 0.0, max: 1.0 },
             )
             .with_value_to_string(formatters::v2s_f32_rounded(2)),
-            limiter_knee: FloatParam::new("Knee", 0.5, FloatRange::Linear { min: 0.0, max: 1.0 })
+            limiter_knee: FloatParam::new("Knee", init.limiter_knee, FloatRange::Linear { min: 0.0, max: 1.0 })
+                .with_value_to_string(formatters::v2s_f32_rounded(2)),
+            limiter_ceiling: FloatParam::new("Ceiling", init.limiter_ceiling, FloatRange::Linear { min: -12.0, max: 0.0 })
+                .with_step_size(0.1)
+                .with_unit(" dB")
+                .with_value_to_string(formatters::v2s_f32_rounded(1)),
+            output_oversample: EnumParam::new("Oversample", OversampleFactor::X1),
+
+            use_gate: BoolParam::new("Gate", init.use_gate),
+            gate_rate: EnumParam::new("Gate Rate", init.gate_rate),
+            gate_shape: FloatParam::new("Gate Shape", init.gate_shape, FloatRange::Linear { min: 0.05, max: 1.0 })
+                .with_value_to_string(formatters::v2s_f32_percentage(0))
+                .with_unit("%"),
+            gate_smooth: FloatParam::new("Gate Smooth", init.gate_smooth, FloatRange::Linear { min: 0.0, max: 1.0 })
+                .with_value_to_string(formatters::v2s_f32_rounded(2)),
+
+            use_resonator: BoolParam::new("Resonator", init.use_resonator),
+            resonator_chord: EnumParam::new("Chord", init.resonator_chord),
+            resonator_root: IntParam::new("Root", init.resonator_root, IntRange::Linear { min: RESONATOR_LOWEST_ROOT, max: 72 })
+                .with_value_to_string(formatters::v2s_i32_note_formatter())
+                .with_string_to_value(formatters::s2v_i32_note_formatter()),
+            resonator_decay: FloatParam::new("Decay", init.resonator_decay, FloatRange::Skewed { min: 0.1, max: 30.0, factor: 0.4 })
+                .with_step_size(0.01)
+                .with_unit(" s")
                 .with_value_to_string(formatters::v2s_f32_rounded(2)),
+            resonator_mix: FloatParam::new("Mix", init.resonator_mix, FloatRange::Linear { min: 0.0, max: 1.0 })
+                .with_value_to_string(formatters::v2s_f32_percentage(0))
+                .with_unit("%"),
+            use_looper: BoolParam::new("Looper", init.use_looper),
+            looper_length: FloatParam::new("Loop Length", init.looper_length, FloatRange::Skewed { min: 10.0, max: LOOPER_MAX_MS, factor: 0.5 })
+                .with_step_size(1.0)
+                .with_value_to_string(formatters::v2s_f32_rounded(0))
+                .with_unit(" ms"),
+            looper_feedback: FloatParam::new("Loop Feedback", init.looper_feedback, FloatRange::Linear { min: 0.0, max: 0.95 })
+                .with_value_to_string(formatters::v2s_f32_percentage(0))
+                .with_unit("%"),
+            looper_mix: FloatParam::new("Loop Mix", init.looper_mix, FloatRange::Linear { min: 0.0, max: 1.0 })
+                .with_value_to_string(formatters::v2s_f32_percentage(0))
+                .with_unit("%"),
+            looper_pitch: FloatParam::new("Loop Pitch", init.looper_pitch, FloatRange::Linear { min: -12.0, max: 12.0 })
+                .with_step_size(0.1)
+                .with_value_to_string(formatters::v2s_f32_rounded(1))
+                .with_unit(" st"),
             
             // FM
-            fm_one_to_two: FloatParam::new("FM 1 to 2", 0.0, FloatRange::Skewed { min: 0.0, max: 20.0, factor: 0.3 })
+            fm_one_to_two: FloatParam::new("FM 1 to 2", init.fm_one_to_two, FloatRange::Skewed { min: 0.0, max: 20.0, factor: 0.3 })
                 .with_value_to_string(formatters::v2s_f32_rounded(5)),
             
-            fm_one_to_three: FloatParam::new("FM 1 to 3", 0.0, FloatRange::Skewed { min: 0.0, max: 20.0, factor: 0.3 })
+            fm_one_to_three: FloatParam::new("FM 1 to 3", init.fm_one_to_three, FloatRange::Skewed { min: 0.0, max: 20.0, factor: 0.3 })
                 .with_value_to_string(formatters::v2s_f32_rounded(5)),
             
-            fm_two_to_three: FloatParam::new("FM 2 to 3", 0.0, FloatRange::Skewed { min: 0.0, max: 20.0, factor: 0.3 })
+            fm_two_to_three: FloatParam::new("FM 2 to 3", init.fm_two_to_three, FloatRange::Skewed { min: 0.0, max: 20.0, factor: 0.3 })
                 .with_value_to_string(formatters::v2s_f32_rounded(5)),
-            fm_cycles: IntParam::new("Cycles", 1, IntRange::Linear { min: 1, max: 3 }),
+            ring_mod_1x2: FloatParam::new("Ring 1x2", init.ring_mod_1x2, FloatRange::Linear { min: 0.0, max: 1.0 })
+                .with_value_to_string(formatters::v2s_f32_rounded(2)),
+            ring_mod_2x3: FloatParam::new("Ring 2x3", init.ring_mod_2x3, FloatRange::Linear { min: 0.0, max: 1.0 })
+                .with_value_to_string(formatters::v2s_f32_rounded(2)),
+            fm_cycles: IntParam::new("Cycles", init.fm_cycles, IntRange::Linear { min: 1, max: 3 }),
             fm_attack: FloatParam::new(
                     "FM Attack",
-                    0.0001,
+                    init.fm_attack,
                     FloatRange::Skewed {
                         min: 0.0001,
                         max: 1999.9,
@@ -3406,7 +4895,7 @@ impl ActuateParams {
                 }),
             fm_decay: FloatParam::new(
                     "FM Decay",
-                    0.0001,
+                    init.fm_decay,
                     FloatRange::Skewed {
                         min: 0.0001,
                         max: 1999.9,
@@ -3421,7 +4910,7 @@ impl ActuateParams {
                 }),
             fm_sustain: FloatParam::new(
                     "FM Sustain",
-                    1999.9,
+                    init.fm_sustain,
                     FloatRange::Skewed {
                         min: 0.0001,
                         max: 1999.9,
@@ -3436,7 +4925,7 @@ impl ActuateParams {
                 }),
             fm_release: FloatParam::new(
                     "FM Release",
-                    0.0001,
+                    init.fm_release,
                     FloatRange::Skewed {
                         min: 0.0001,
                         max: 1999.9,
@@ -3449,23 +4938,23 @@ impl ActuateParams {
                     let update_something = update_something.clone();
                     Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
                 }),
-            fm_attack_curve: EnumParam::new("Atk Curve", Oscillator::SmoothStyle::Linear)
+            fm_attack_curve: EnumParam::new("Atk Curve", init.fm_attack_curve)
                 .with_callback({
                     let update_something = update_something.clone();
                     Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
                 }),
-            fm_decay_curve: EnumParam::new("Dec Curve", Oscillator::SmoothStyle::Linear)
+            fm_decay_curve: EnumParam::new("Dec Curve", init.fm_decay_curve)
                 .with_callback({
                     let update_something = update_something.clone();
                     Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
                 }),
-            fm_release_curve: EnumParam::new("Rel Curve", Oscillator::SmoothStyle::Linear)
+            fm_release_curve: EnumParam::new("Rel Curve", init.fm_release_curve)
                 .with_callback({
                     let update_something = update_something.clone();
                     Arc::new(move |_| update_something.store(true, Ordering::SeqCst))
                 }),
             
-            stereo_algorithm: EnumParam::new("Stereo Behavior", StereoAlgorithm::Original),
+            stereo_algorithm: EnumParam::new("Stereo Behavior", init.stereo_algorithm),
 
             // UI Non-Param Params are dummy params for my buttons
             ////////////////////////////////////////////////////////////////////////////////////
@@ -3473,29 +4962,33 @@ impl ActuateParams {
             param_save_bank: BoolParam::new("Save Bank", false).hide(),
             param_import_preset: BoolParam::new("Import Preset", false).hide(),
             param_export_preset: BoolParam::new("Export Preset", false).hide(),
-            preset_category: EnumParam::new("Type", PresetType::Select).hide(),
-            tag_acid: BoolParam::new("Acid", false).hide(),
-            tag_analog: BoolParam::new("Analog", false).hide(),
-            tag_bright: BoolParam::new("Bright", false).hide(),
-            tag_chord: BoolParam::new("Chord", false).hide(),
-            tag_crisp: BoolParam::new("Crisp", false).hide(),
-            tag_deep: BoolParam::new("Deep", false).hide(),
-            tag_delicate: BoolParam::new("Delicate", false).hide(),
-            tag_hard: BoolParam::new("Hard", false).hide(),
-            tag_harsh: BoolParam::new("Harsh", false).hide(),
-            tag_lush: BoolParam::new("Lush", false).hide(),
-            tag_mellow: BoolParam::new("Mellow", false).hide(),
-            tag_resonant: BoolParam::new("Resonant", false).hide(),
-            tag_rich: BoolParam::new("Rich", false).hide(),
-            tag_sharp: BoolParam::new("Sharp", false).hide(),
-            tag_silky: BoolParam::new("Silky", false).hide(),
-            tag_smooth: BoolParam::new("Smooth", false).hide(),
-            tag_soft: BoolParam::new("Soft", false).hide(),
-            tag_stab: BoolParam::new("Stab", false).hide(),
-            tag_warm: BoolParam::new("Warm", false).hide(),
+            preset_category: EnumParam::new("Type", init.preset_category).hide(),
+            tag_acid: BoolParam::new("Acid", init.tag_acid).hide(),
+            tag_analog: BoolParam::new("Analog", init.tag_analog).hide(),
+            tag_bright: BoolParam::new("Bright", init.tag_bright).hide(),
+            tag_chord: BoolParam::new("Chord", init.tag_chord).hide(),
+            tag_crisp: BoolParam::new("Crisp", init.tag_crisp).hide(),
+            tag_deep: BoolParam::new("Deep", init.tag_deep).hide(),
+            tag_delicate: BoolParam::new("Delicate", init.tag_delicate).hide(),
+            tag_hard: BoolParam::new("Hard", init.tag_hard).hide(),
+            tag_harsh: BoolParam::new("Harsh", init.tag_harsh).hide(),
+            tag_lush: BoolParam::new("Lush", init.tag_lush).hide(),
+            tag_mellow: BoolParam::new("Mellow", init.tag_mellow).hide(),
+            tag_resonant: BoolParam::new("Resonant", init.tag_resonant).hide(),
+            tag_rich: BoolParam::new("Rich", init.tag_rich).hide(),
+            tag_sharp: BoolParam::new("Sharp", init.tag_sharp).hide(),
+            tag_silky: BoolParam::new("Silky", init.tag_silky).hide(),
+            tag_smooth: BoolParam::new("Smooth", init.tag_smooth).hide(),
+            tag_soft: BoolParam::new("Soft", init.tag_soft).hide(),
+            tag_stab: BoolParam::new("Stab", init.tag_stab).hide(),
+            tag_warm: BoolParam::new("Warm", init.tag_warm).hide(),
 
             preset_name_p: Arc::new(Mutex::new(String::from("Welcome to Actuate!"))),
             preset_info_p: Arc::new(Mutex::new(String::from("by Ardura"))),
+            preset_author_p: Arc::new(Mutex::new(String::new())),
+            preset_created_p: Arc::new(Mutex::new(String::new())),
+            midi_learn_map: Arc::new(Mutex::new(HashMap::new())),
+            midi_learn_target: Arc::new(Mutex::new(None)),
 
             // These are now unused in 1.3.5+
             param_next_preset: BoolParam::new("->", false).hide(),
@@ -3512,6 +5005,41 @@ impl ActuateParams {
             loading: BoolParam::new("loading_mod", false).hide(),
         }
     }
+
+    // The parts of the Init patch that aren't params, the editor loads the samples into the modules like a restored project
+    fn load_init_extras(&self, init: &ActuatePresetV131) {
+        *self.preset_name_p.lock().unwrap() = init.preset_name.clone();
+        *self.preset_info_p.lock().unwrap() = init.preset_info.clone();
+        *self.preset_author_p.lock().unwrap() = init.preset_author.clone();
+        *self.preset_created_p.lock().unwrap() = init.preset_created.clone();
+        for (sample, module_type, loaded_sample) in [
+            (&self.am1_sample, init.mod1_audio_module_type, &init.mod1_loaded_sample),
+            (&self.am2_sample, init.mod2_audio_module_type, &init.mod2_loaded_sample),
+            (&self.am3_sample, init.mod3_audio_module_type, &init.mod3_loaded_sample),
+        ] {
+            if module_type == AudioModuleType::Sampler || module_type == AudioModuleType::Granulizer {
+                *sample.lock().unwrap() = loaded_sample.clone();
+            }
+        }
+    }
+
+    // The note cents params from C to B
+    pub fn note_offset_cents(&self) -> [&FloatParam; 12] {
+        [
+            &self.note_offset_cents_0,
+            &self.note_offset_cents_1,
+            &self.note_offset_cents_2,
+            &self.note_offset_cents_3,
+            &self.note_offset_cents_4,
+            &self.note_offset_cents_5,
+            &self.note_offset_cents_6,
+            &self.note_offset_cents_7,
+            &self.note_offset_cents_8,
+            &self.note_offset_cents_9,
+            &self.note_offset_cents_10,
+            &self.note_offset_cents_11,
+        ]
+    }
 }
 
 impl Plugin for Actuate {
@@ -3521,17 +5049,39 @@ impl Plugin for Actuate {
     const EMAIL: &'static str = "azviscarra@gmail.com";
     const VERSION: &'static str = env!("CARGO_PKG_VERSION");
     const SAMPLE_ACCURATE_AUTOMATION: bool = true;
-    const MIDI_INPUT: MidiConfig = MidiConfig::Basic;
+    // MidiCCs is needed for program changes to come through
+    const MIDI_INPUT: MidiConfig = MidiConfig::MidiCCs;
     const MIDI_OUTPUT: MidiConfig = MidiConfig::Basic;
 
     type SysExMessage = ();
-    type BackgroundTask = ();
-
-    const AUDIO_IO_LAYOUTS: &'static [AudioIOLayout] = &[AudioIOLayout {
-        main_input_channels: None,
-        main_output_channels: NonZeroU32::new(2),
-        ..AudioIOLayout::const_default()
-    }];
+    type BackgroundTask = ActuateTask;
+
+    const AUDIO_IO_LAYOUTS: &'static [AudioIOLayout] = &[
+        AudioIOLayout {
+            main_input_channels: None,
+            main_output_channels: NonZeroU32::new(2),
+            ..AudioIOLayout::const_default()
+        },
+        // Stereo in for using Actuate's FX on incoming audio
+        AudioIOLayout {
+            main_input_channels: NonZeroU32::new(2),
+            main_output_channels: NonZeroU32::new(2),
+            ..AudioIOLayout::const_default()
+        },
+        // Multi-out: each generator also gets its own stereo bus for processing in the DAW
+        AudioIOLayout {
+            main_input_channels: None,
+            main_output_channels: NonZeroU32::new(2),
+            aux_output_ports: &[new_nonzero_u32(2), new_nonzero_u32(2), new_nonzero_u32(2)],
+            names: PortNames {
+                layout: Some("Multi-Out"),
+                main_output: Some("Main"),
+                aux_outputs: &["Generator 1", "Generator 2", "Generator 3"],
+                ..PortNames::const_default()
+            },
+            ..AudioIOLayout::const_default()
+        },
+    ];
 
     fn params(&self) -> Arc<dyn Params> {
         self.params.clone()
@@ -3539,16 +5089,33 @@ impl Plugin for Actuate {
 
     // This draws our GUI with egui library
     fn editor(&mut self, _async_executor: AsyncExecutor<Self>) -> Option<Box<dyn Editor>> {
+        let context = self.gui_context.clone();
         actuate_gui::make_actuate_gui(self, _async_executor)
+            .map(|inner| Box::new(MidiLearnEditor { inner, context }) as Box<dyn Editor>)
     }
 
     fn initialize(
         &mut self,
-        _audio_io_layout: &AudioIOLayout,
+        audio_io_layout: &AudioIOLayout,
         buffer_config: &BufferConfig,
-        _context: &mut impl InitContext<Self>,
+        context: &mut impl InitContext<Self>,
     ) -> bool {
         self.sample_rate = buffer_config.sample_rate;
+        self.has_main_input = audio_io_layout.main_input_channels.is_some();
+        self.multi_out = audio_io_layout.aux_output_ports.len() == 3;
+        self.soft_start_gain = 0.0;
+        self.reported_latency = self.fx_latency();
+        context.set_latency_samples(self.reported_latency);
+        self.output_capture.lock().unwrap().set_max_length(CAPTURE_MAX_SECONDS, self.sample_rate);
+        self.looper.set_max_length(LOOPER_MAX_MS, self.sample_rate);
+        self.tape_stop.set_max_time(TAPE_STOP_MAX_MS, self.sample_rate);
+        self.reverb_predelay.set_max_length(REVERB_PREDELAY_MAX_MS, self.sample_rate);
+        self.resonator.set_max_length(RESONATOR_LOWEST_ROOT, self.sample_rate);
+        // Room for the worst case FX latency so switching oversampling never reallocates
+        let aux_delay_ms = 2.0 * Oversampler::latency(OversampleFactor::X4) as f32 * 1000.0 / self.sample_rate;
+        for aux_delay in self.aux_delays.iter_mut() {
+            aux_delay.set_max_length(aux_delay_ms, self.sample_rate);
+        }
 
         return true;
     }
@@ -3557,7 +5124,7 @@ impl Plugin for Actuate {
     fn process(
         &mut self,
         buffer: &mut Buffer,
-        _aux: &mut AuxiliaryBuffers,
+        aux: &mut AuxiliaryBuffers,
         context: &mut impl ProcessContext<Self>,
     ) -> ProcessStatus {
         // Clear any voices on change of module type (especially during play)
@@ -3569,182 +5136,132 @@ impl Plugin for Actuate {
 
             self.clear_voices.store(false, Ordering::SeqCst);
             self.update_something.store(true, Ordering::SeqCst);
+            self.soft_start_gain = 0.0;
+        }
+        // Preset reloads can leave filter state mid-swing so fade in after them too
+        if self.reload_entire_preset.swap(false, Ordering::SeqCst) {
+            self.soft_start_gain = 0.0;
+        }
+        let latency = self.fx_latency();
+        if latency != self.reported_latency {
+            self.reported_latency = latency;
+            context.set_latency_samples(latency);
         }
-        self.process_midi(context, buffer);
+        self.process_midi(context, buffer, aux);
         ProcessStatus::Normal
     }
 
     const HARD_REALTIME_ONLY: bool = false;
 
     fn task_executor(&mut self) -> TaskExecutor<Self> {
-        // In the default implementation we can simply ignore the value
-        Box::new(|_| ())
+        let params = self.params.clone();
+        let pending_cc = self.pending_cc.clone();
+        let midi_learn_queued = self.midi_learn_queued.clone();
+        let gui_context = self.gui_context.clone();
+        let param_ptrs = param_ptr_map(&params);
+        Box::new(move |task| match task {
+            ActuateTask::ApplyMidiLearn => {
+                midi_learn_queued.store(false, Ordering::SeqCst);
+                // Until the editor has opened once there's no context, the values stay pending until then
+                if let Some(context) = gui_context.lock().unwrap().as_ref() {
+                    apply_midi_learn(&params, &param_ptrs, &pending_cc, context.as_ref());
+                }
+            }
+        })
     }
 
     fn filter_state(_state: &mut PluginState) {}
 
-    fn reset(&mut self) {}
+    fn reset(&mut self) {
+        self.soft_start_gain = 0.0;
+        self.humanize_rng = Pcg32::new(HUMANIZE_SEED, 0xa02bdbf7bb3c0a7);
+        self.humanize_queue.clear();
+        self.humanize_detune = 0.0;
+        self.held_notes.clear();
+        self.mono_voice_note = None;
+        self.mono_pending = None;
+        self.mono_last_pitch = None;
+        self.glide_current = 0.0;
+        self.glide_target = 0.0;
+    }
 
     fn deactivate(&mut self) {}
 }
 
 impl Actuate {
+    // The saturation and limiter oversamplers each add a round trip of filter delay while FX are on
+    fn fx_latency(&self) -> u32 {
+        if self.params.use_fx.value() {
+            2 * Oversampler::latency(self.params.output_oversample.value())
+        } else {
+            0
+        }
+    }
+
     // Send midi events to the audio modules and let them process them - also send params so they can access
-    fn process_midi(&mut self, context: &mut impl ProcessContext<Self>, buffer: &mut Buffer) {
-        let mut lfo_1_current: f32 = -2.0;
-        let mut lfo_2_current: f32 = -2.0;
-        let mut lfo_3_current: f32 = -2.0;
+    fn process_midi(&mut self, context: &mut impl ProcessContext<Self>, buffer: &mut Buffer, aux: &mut AuxiliaryBuffers) {
+        let mut lfo_current: [f32; 3] = [-2.0; 3];
 
         // Update our LFOs per each sample
         /////////////////////////////////////////////////////////////////////////////////////////////
-        let bpm = context.transport().tempo.unwrap_or(1.0) as f32;
+        // Standalone or clockless hosts can use the manual tempo instead
+        let bpm = if self.params.use_manual_bpm.value() {
+            self.params.manual_bpm.value()
+        } else {
+            context.transport().tempo.unwrap_or(1.0) as f32
+        };
+        self.current_bpm.store(bpm, Ordering::Relaxed);
         if bpm == 1.0 {
             // This means we are not getting proper tempo so LFO can't sync
             return;
         }
-        if self.params.lfo1_enable.value() {
-            // Update LFO Frequency
-            if self.params.lfo1_sync.value() {
-                let divisor = match self.params.lfo1_snap.value() {
-                    LFOController::LFOSnapValues::Quad => 16.0,
-                    LFOController::LFOSnapValues::QuadD => 16.0 * 1.5,
-                    LFOController::LFOSnapValues::QuadT => 16.0 / 3.0,
-                    LFOController::LFOSnapValues::Double => 8.0,
-                    LFOController::LFOSnapValues::DoubleD => 8.0 * 1.5,
-                    LFOController::LFOSnapValues::DoubleT => 8.0 / 3.0,
-                    LFOController::LFOSnapValues::Whole => 4.0,
-                    LFOController::LFOSnapValues::WholeD => 4.0 * 1.5,
-                    LFOController::LFOSnapValues::WholeT => 4.0 / 3.0,
-                    LFOController::LFOSnapValues::Half => 2.0,
-                    LFOController::LFOSnapValues::HalfD => 2.0 * 1.5,
-                    LFOController::LFOSnapValues::HalfT => 2.0 / 3.0,
-                    LFOController::LFOSnapValues::Quarter => 1.0,
-                    LFOController::LFOSnapValues::QuarterD => 1.0 * 1.5,
-                    LFOController::LFOSnapValues::QuarterT => 1.0 / 3.0,
-                    LFOController::LFOSnapValues::Eighth => 0.5,
-                    LFOController::LFOSnapValues::EighthD => 0.5 * 1.5,
-                    LFOController::LFOSnapValues::EighthT => 0.5 / 3.0,
-                    LFOController::LFOSnapValues::Sixteen => 0.25,
-                    LFOController::LFOSnapValues::SixteenD => 0.25 * 1.5,
-                    LFOController::LFOSnapValues::SixteenT => 0.25 / 3.0,
-                    LFOController::LFOSnapValues::ThirtySecond => 0.125,
-                    LFOController::LFOSnapValues::ThirtySecondD => 0.125 * 1.5,
-                    LFOController::LFOSnapValues::ThirtySecondT => 0.125 / 3.0,
-                };
-                let freq_snap = (bpm / divisor) / 60.0;
-                if self.params.lfo1_freq.value() != freq_snap {
-                    self.lfo_1.set_frequency(freq_snap);
-                }
-            } else {
-                if self.params.lfo1_freq.value() != self.lfo_1.get_frequency() {
-                    self.lfo_1.set_frequency(self.params.lfo1_freq.value());
+        self.update_lfo_rates(bpm);
+
+        let meter_decay = (-1.0 / (METER_DECAY_TIME * self.sample_rate)).exp();
+        let process_input = self.has_main_input && self.params.process_input.value();
+
+        // Anything humanize held over from the last block is due right away
+        let block_len = buffer.samples();
+        // Generator buses only get written while voices run so start them silent
+        if self.multi_out {
+            for output in aux.outputs.iter_mut() {
+                for channel in output.as_slice() {
+                    channel.fill(0.0);
                 }
             }
-
-            // Update LFO Waveform
-            if self.params.lfo1_waveform.value() != self.lfo_1.get_waveform() {
-                self.lfo_1.set_waveform(self.params.lfo1_waveform.value());
+        }
+        for (due, _) in self.humanize_queue.iter_mut() {
+            *due = 0;
+        }
+        // The gate follows the host grid while playing and free runs from where it was when stopped
+        if self.params.use_gate.value() && context.transport().playing {
+            if let Some(pos_beats) = context.transport().pos_beats() {
+                self.trance_gate.sync(pos_beats, self.params.gate_rate.value().beats());
             }
         }
-        if self.params.lfo2_enable.value() {
-            // Update LFO Frequency
-            if self.params.lfo2_sync.value() {
-                let divisor = match self.params.lfo2_snap.value() {
-                    LFOController::LFOSnapValues::Quad => 16.0,
-                    LFOController::LFOSnapValues::QuadD => 16.0 * 1.5,
-                    LFOController::LFOSnapValues::QuadT => 16.0 / 3.0,
-                    LFOController::LFOSnapValues::Double => 8.0,
-                    LFOController::LFOSnapValues::DoubleD => 8.0 * 1.5,
-                    LFOController::LFOSnapValues::DoubleT => 8.0 / 3.0,
-                    LFOController::LFOSnapValues::Whole => 4.0,
-                    LFOController::LFOSnapValues::WholeD => 4.0 * 1.5,
-                    LFOController::LFOSnapValues::WholeT => 4.0 / 3.0,
-                    LFOController::LFOSnapValues::Half => 2.0,
-                    LFOController::LFOSnapValues::HalfD => 2.0 * 1.5,
-                    LFOController::LFOSnapValues::HalfT => 2.0 / 3.0,
-                    LFOController::LFOSnapValues::Quarter => 1.0,
-                    LFOController::LFOSnapValues::QuarterD => 1.0 * 1.5,
-                    LFOController::LFOSnapValues::QuarterT => 1.0 / 3.0,
-                    LFOController::LFOSnapValues::Eighth => 0.5,
-                    LFOController::LFOSnapValues::EighthD => 0.5 * 1.5,
-                    LFOController::LFOSnapValues::EighthT => 0.5 / 3.0,
-                    LFOController::LFOSnapValues::Sixteen => 0.25,
-                    LFOController::LFOSnapValues::SixteenD => 0.25 * 1.5,
-                    LFOController::LFOSnapValues::SixteenT => 0.25 / 3.0,
-                    LFOController::LFOSnapValues::ThirtySecond => 0.125,
-                    LFOController::LFOSnapValues::ThirtySecondD => 0.125 * 1.5,
-                    LFOController::LFOSnapValues::ThirtySecondT => 0.125 / 3.0,
-                };
-                let freq_snap = (bpm / divisor) / 60.0;
-                if self.params.lfo2_freq.value() != freq_snap {
-                    self.lfo_2.set_frequency(freq_snap);
-                }
+
+        // Held through their own handles so render_sample can borrow self while the modules are locked
+        let audio_module_1 = self.audio_module_1.clone();
+        let audio_module_2 = self.audio_module_2.clone();
+        let audio_module_3 = self.audio_module_3.clone();
+
+        for (sample_id, mut channel_samples) in buffer.iter_samples().enumerate() {
+            // The buffer starts out holding the host's input when we're running as an effect
+            let (input_l, input_r) = if process_input {
+                (*channel_samples.get_mut(0).unwrap(), *channel_samples.get_mut(1).unwrap())
             } else {
-                if self.params.lfo2_freq.value() != self.lfo_2.get_frequency() {
-                    self.lfo_2.set_frequency(self.params.lfo2_freq.value());
-                }
-            }
-
-            // Update LFO Waveform
-            if self.params.lfo2_waveform.value() != self.lfo_2.get_waveform() {
-                self.lfo_2.set_waveform(self.params.lfo2_waveform.value());
-            }
-        }
-        if self.params.lfo3_enable.value() {
-            // Update LFO Frequency
-            if self.params.lfo3_sync.value() {
-                let divisor = match self.params.lfo3_snap.value() {
-                    LFOController::LFOSnapValues::Quad => 16.0,
-                    LFOController::LFOSnapValues::QuadD => 16.0 * 1.5,
-                    LFOController::LFOSnapValues::QuadT => 16.0 / 3.0,
-                    LFOController::LFOSnapValues::Double => 8.0,
-                    LFOController::LFOSnapValues::DoubleD => 8.0 * 1.5,
-                    LFOController::LFOSnapValues::DoubleT => 8.0 / 3.0,
-                    LFOController::LFOSnapValues::Whole => 4.0,
-                    LFOController::LFOSnapValues::WholeD => 4.0 * 1.5,
-                    LFOController::LFOSnapValues::WholeT => 4.0 / 3.0,
-                    LFOController::LFOSnapValues::Half => 2.0,
-                    LFOController::LFOSnapValues::HalfD => 2.0 * 1.5,
-                    LFOController::LFOSnapValues::HalfT => 2.0 / 3.0,
-                    LFOController::LFOSnapValues::Quarter => 1.0,
-                    LFOController::LFOSnapValues::QuarterD => 1.0 * 1.5,
-                    LFOController::LFOSnapValues::QuarterT => 1.0 / 3.0,
-                    LFOController::LFOSnapValues::Eighth => 0.5,
-                    LFOController::LFOSnapValues::EighthD => 0.5 * 1.5,
-                    LFOController::LFOSnapValues::EighthT => 0.5 / 3.0,
-                    LFOController::LFOSnapValues::Sixteen => 0.25,
-                    LFOController::LFOSnapValues::SixteenD => 0.25 * 1.5,
-                    LFOController::LFOSnapValues::SixteenT => 0.25 / 3.0,
-                    LFOController::LFOSnapValues::ThirtySecond => 0.125,
-                    LFOController::LFOSnapValues::ThirtySecondD => 0.125 * 1.5,
-                    LFOController::LFOSnapValues::ThirtySecondT => 0.125 / 3.0,
-                };
-                let freq_snap = (bpm / divisor) / 60.0;
-                if self.params.lfo3_freq.value() != freq_snap {
-                    self.lfo_3.set_frequency(freq_snap);
-                }
-            } else {
-                if self.params.lfo3_freq.value() != self.lfo_3.get_frequency() {
-                    self.lfo_3.set_frequency(self.params.lfo3_freq.value());
-                }
-            }
-
-            // Update LFO Waveform
-            if self.params.lfo3_waveform.value() != self.lfo_3.get_waveform() {
-                self.lfo_3.set_waveform(self.params.lfo3_waveform.value());
-            }
-        }
-
-        for (sample_id, mut channel_samples) in buffer.iter_samples().enumerate() {
-            // Get around post file loading breaking things with an arbitrary buffer
-            if self.file_dialog.load(Ordering::Acquire) {
-                self.file_open_buffer_timer.store(
-                    self.file_open_buffer_timer.load(Ordering::SeqCst) + 1,
-                    Ordering::SeqCst,
-                );
-                if self.file_open_buffer_timer.load(Ordering::SeqCst) > FILE_OPEN_BUFFER_MAX {
-                    self.file_open_buffer_timer.store(0, Ordering::SeqCst);
-                    self.file_dialog.store(false, Ordering::SeqCst); //Changed from Release
+                (0.0, 0.0)
+            };
+
+            // Get around post file loading breaking things with an arbitrary buffer
+            if self.file_dialog.load(Ordering::Acquire) {
+                self.file_open_buffer_timer.store(
+                    self.file_open_buffer_timer.load(Ordering::SeqCst) + 1,
+                    Ordering::SeqCst,
+                );
+                if self.file_open_buffer_timer.load(Ordering::SeqCst) > FILE_OPEN_BUFFER_MAX {
+                    self.file_open_buffer_timer.store(0, Ordering::SeqCst);
+                    self.file_dialog.store(false, Ordering::SeqCst); //Changed from Release
                 }
             }
 
@@ -3780,9 +5297,9 @@ impl Actuate {
                     _ => {},
                 }
             }
-            let mut am1_lock = self.audio_module_1.lock().unwrap();
-            let mut am2_lock = self.audio_module_2.lock().unwrap();
-            let mut am3_lock = self.audio_module_3.lock().unwrap();
+            let mut am1_lock = audio_module_1.lock().unwrap();
+            let mut am2_lock = audio_module_2.lock().unwrap();
+            let mut am3_lock = audio_module_3.lock().unwrap();
 
             // Prevent processing if our file dialog is open!!!
             if self.file_dialog.load(Ordering::SeqCst) {
@@ -3821,1616 +5338,3879 @@ impl Actuate {
                 am3_lock.set_playing(true);
             }
 
-            let midi_event: Option<NoteEvent<()>> = context.next_event();
-            let sent_voice_max: usize = self.params.voice_limit.value() as usize;
-            let mut wave1_l: f32 = 0.0;
-            let mut wave2_l: f32 = 0.0;
-            let mut wave3_l: f32 = 0.0;
-            let mut wave1_r: f32 = 0.0;
-            let mut wave2_r: f32 = 0.0;
-            let mut wave3_r: f32 = 0.0;
-            // These track if a new note happens to re-open the filter
-            let mut reset_filter_controller1: bool = false;
-            let mut reset_filter_controller2: bool = false;
-            let mut reset_filter_controller3: bool = false;
-            // These track if a note just finished to allow filter closing
-            let mut note_off_filter_controller1: bool = false;
-            let mut note_off_filter_controller2: bool = false;
-            let mut note_off_filter_controller3: bool = false;
-
-            // Trigger passing variables to the audio modules when the GUI input changes
-            if self.update_something.load(Ordering::SeqCst) {
-                am1_lock.consume_params(self.params.clone(), 1);
-                am2_lock.consume_params(self.params.clone(), 2);
-                am3_lock.consume_params(self.params.clone(), 3);
-                // Fix Auto restretch/repitch behavior
-                if self.prev_restretch_1.load(Ordering::SeqCst) != self.params.restretch_1.value() {
-                    self.prev_restretch_1.store(self.params.restretch_1.value(), Ordering::SeqCst);
-                    am1_lock.regenerate_samples();
-                }
-                if self.prev_restretch_2.load(Ordering::SeqCst) != self.params.restretch_2.value() {
-                    self.prev_restretch_2.store(self.params.restretch_2.value(), Ordering::SeqCst);
-                    am2_lock.regenerate_samples();
-                }
-                if self.prev_restretch_3.load(Ordering::SeqCst) != self.params.restretch_3.value() {
-                    self.prev_restretch_3.store(self.params.restretch_3.value(), Ordering::SeqCst);
-                    am3_lock.regenerate_samples();
-                }
-
-                self.update_something.store(false, Ordering::SeqCst);
+            // A pending mono retrigger takes this sample, anything new waits for the next one
+            let mut midi_event: Option<NoteEvent<()>> = if self.mono_pending.is_none() {
+                context.next_event()
+            } else {
+                None
+            };
+
+            // Notes from the on-screen keyboard fill in whenever the host has nothing for this sample
+            if midi_event.is_none() && self.mono_pending.is_none() {
+                if let Ok(mut gui_notes) = self.gui_note_queue.try_lock() {
+                    midi_event = gui_notes.pop_front();
+                }
             }
 
-            // Modulations
-            /////////////////////////////////////////////////////////////////////////////////////////////////
-            let mod_value_1: f32;
-            let mod_value_2: f32;
-            let mod_value_3: f32;
-            let mod_value_4: f32;
-
-            // If no modulations this = -2.0
-            mod_value_1 = match self.params.mod_source_1.value() {
-                ModulationSource::None | ModulationSource::UnsetModulation => -2.0,
-                ModulationSource::LFO1 => lfo_1_current * self.params.mod_amount_knob_1.value(),
-                ModulationSource::LFO2 => lfo_2_current * self.params.mod_amount_knob_1.value(),
-                ModulationSource::LFO3 => lfo_3_current * self.params.mod_amount_knob_1.value(),
-                ModulationSource::Velocity => {
-                    // This is to allow invalid midi events to not break this logic since we only want NoteOn
-                    match midi_event.clone().unwrap_or(NoteEvent::Choke {
-                        timing: 0_u32,
-                        voice_id: Some(0_i32),
-                        channel: 0_u8,
-                        note: 0_u8,
-                    }) {
-                        NoteEvent::NoteOn {
-                            velocity,
-                            timing: _,
-                            voice_id: _,
-                            channel: _,
-                            note: _,
-                        } => {
-                            // Store velocity on new note happening
-                            let vel = (velocity * self.params.mod_amount_knob_1.value().abs())
-                                .clamp(0.0, 1.0);
-                            if velocity != -1.0 {
-                                self.current_note_on_velocity.store(vel, Ordering::SeqCst);
-                            }
-                            vel
-                        }
-                        _ => -2.0,
-                    }
+            // Program changes pick a preset from the current browser bank, the GUI thread does the actual load
+            if let Some(NoteEvent::MidiProgramChange { program, .. }) = midi_event {
+                if self.params.midi_program_change.value() {
+                    self.pending_program.store(program as i32, Ordering::SeqCst);
                 }
-            };
+                midi_event = None;
+            }
 
-            mod_value_2 = match self.params.mod_source_2.value() {
-                ModulationSource::None | ModulationSource::UnsetModulation => -2.0,
-                ModulationSource::LFO1 => lfo_1_current * self.params.mod_amount_knob_2.value(),
-                ModulationSource::LFO2 => lfo_2_current * self.params.mod_amount_knob_2.value(),
-                ModulationSource::LFO3 => lfo_3_current * self.params.mod_amount_knob_2.value(),
-                ModulationSource::Velocity => {
-                    match midi_event.clone().unwrap_or(NoteEvent::Choke {
-                        timing: 0_u32,
-                        voice_id: Some(0_i32),
-                        channel: 0_u8,
-                        note: 0_u8,
-                    }) {
-                        NoteEvent::NoteOn {
-                            velocity,
-                            timing: _,
-                            voice_id: _,
-                            channel: _,
-                            note: _,
-                        } => {
-                            if velocity != -1.0 {
-                                self.current_note_on_velocity
-                                    .store(velocity, Ordering::SeqCst);
-                            }
-                            (velocity * self.params.mod_amount_knob_2.value().abs()).clamp(0.0, 1.0)
-                        }
-                        _ => -2.0,
-                    }
+            // CCs are stashed for MIDI learn and applied on the main thread, see actuate_midi_learn
+            if let Some(NoteEvent::MidiCC { cc, value, .. }) = midi_event {
+                self.pending_cc[cc as usize].store(value, Ordering::SeqCst);
+                if !self.midi_learn_queued.swap(true, Ordering::SeqCst) {
+                    context.execute_gui(ActuateTask::ApplyMidiLearn);
                 }
-            };
+            }
 
-            mod_value_3 = match self.params.mod_source_3.value() {
-                ModulationSource::None | ModulationSource::UnsetModulation => -2.0,
-                ModulationSource::LFO1 => lfo_1_current * self.params.mod_amount_knob_3.value(),
-                ModulationSource::LFO2 => lfo_2_current * self.params.mod_amount_knob_3.value(),
-                ModulationSource::LFO3 => lfo_3_current * self.params.mod_amount_knob_3.value(),
-                ModulationSource::Velocity => {
-                    match midi_event.clone().unwrap_or(NoteEvent::Choke {
-                        timing: 0_u32,
-                        voice_id: Some(0_i32),
-                        channel: 0_u8,
-                        note: 0_u8,
-                    }) {
-                        NoteEvent::NoteOn {
-                            velocity,
-                            timing: _,
-                            voice_id: _,
-                            channel: _,
-                            note: _,
-                        } => {
-                            if velocity != -1.0 {
-                                self.current_note_on_velocity
-                                    .store(velocity, Ordering::SeqCst);
-                            }
-                            (velocity * self.params.mod_amount_knob_3.value().abs()).clamp(0.0, 1.0)
-                        }
-                        _ => -2.0,
+            let ((final_l, final_r), generators) = self.render_sample(
+                &mut am1_lock,
+                &mut am2_lock,
+                &mut am3_lock,
+                midi_event,
+                sample_id,
+                block_len,
+                (input_l, input_r),
+                bpm,
+                meter_decay,
+                &mut lfo_current,
+            );
+
+            // Each generator's share of the mix goes out on its own bus before FX
+            // Delayed by the reported latency so the buses stay lined up with the main out after compensation
+            if self.multi_out {
+                let master_level = self.params.master_level.value();
+                let aux_latency = self.reported_latency as usize;
+                for ((output, (wave_l, wave_r)), aux_delay) in aux.outputs.iter_mut().zip(generators).zip(self.aux_delays.iter_mut()) {
+                    let (delayed_l, delayed_r) = aux_delay.process(wave_l * 0.33 * master_level, wave_r * 0.33 * master_level, aux_latency);
+                    let output_channels = output.as_slice();
+                    if output_channels.len() >= 2 && sample_id < output_channels[0].len() {
+                        output_channels[0][sample_id] = delayed_l;
+                        output_channels[1][sample_id] = delayed_r;
                     }
                 }
-            };
+            }
 
-            mod_value_4 = match self.params.mod_source_4.value() {
-                ModulationSource::None | ModulationSource::UnsetModulation => -2.0,
-                ModulationSource::LFO1 => lfo_1_current * self.params.mod_amount_knob_4.value(),
-                ModulationSource::LFO2 => lfo_2_current * self.params.mod_amount_knob_4.value(),
-                ModulationSource::LFO3 => lfo_3_current * self.params.mod_amount_knob_4.value(),
-                ModulationSource::Velocity => {
-                    match midi_event.clone().unwrap_or(NoteEvent::Choke {
-                        timing: 0_u32,
-                        voice_id: Some(0_i32),
-                        channel: 0_u8,
-                        note: 0_u8,
-                    }) {
-                        NoteEvent::NoteOn {
-                            velocity,
-                            timing: _,
-                            voice_id: _,
-                            channel: _,
-                            note: _,
-                        } => {
-                            if velocity != -1.0 {
-                                self.current_note_on_velocity
-                                    .store(velocity, Ordering::SeqCst);
-                            }
-                            (velocity * self.params.mod_amount_knob_4.value().abs()).clamp(0.0, 1.0)
-                        }
-                        _ => -2.0,
-                    }
+            // Reassign our output signal
+            *channel_samples.get_mut(0).unwrap() = final_l;
+            *channel_samples.get_mut(1).unwrap() = final_r;
+        }
+        self.output_peak.store(self.meter_hold, Ordering::Relaxed);
+        self.output_correlation.store(stereo_correlation(self.correlation_sums), Ordering::Relaxed);
+        for (meter, hold) in self.module_peaks.iter().zip(self.module_hold.iter()) {
+            meter.store(*hold, Ordering::Relaxed);
+        }
+        for (index, lfo) in [&self.lfo_1, &self.lfo_2, &self.lfo_3].into_iter().enumerate() {
+            self.lfo_phases[index].store(lfo.get_phase(), Ordering::Relaxed);
+        }
+    }
+
+
+    // Tempo synced rates, waveforms and one shot mode for the LFOs, once per block
+    fn update_lfo_rates(&mut self, bpm: f32) {
+        if self.params.lfo1_enable.value() {
+            // Update LFO Frequency
+            if self.params.lfo1_sync.value() {
+                let divisor = match self.params.lfo1_snap.value() {
+                    LFOController::LFOSnapValues::Quad => 16.0,
+                    LFOController::LFOSnapValues::QuadD => 16.0 * 1.5,
+                    LFOController::LFOSnapValues::QuadT => 16.0 / 3.0,
+                    LFOController::LFOSnapValues::Double => 8.0,
+                    LFOController::LFOSnapValues::DoubleD => 8.0 * 1.5,
+                    LFOController::LFOSnapValues::DoubleT => 8.0 / 3.0,
+                    LFOController::LFOSnapValues::Whole => 4.0,
+                    LFOController::LFOSnapValues::WholeD => 4.0 * 1.5,
+                    LFOController::LFOSnapValues::WholeT => 4.0 / 3.0,
+                    LFOController::LFOSnapValues::Half => 2.0,
+                    LFOController::LFOSnapValues::HalfD => 2.0 * 1.5,
+                    LFOController::LFOSnapValues::HalfT => 2.0 / 3.0,
+                    LFOController::LFOSnapValues::Quarter => 1.0,
+                    LFOController::LFOSnapValues::QuarterD => 1.0 * 1.5,
+                    LFOController::LFOSnapValues::QuarterT => 1.0 / 3.0,
+                    LFOController::LFOSnapValues::Eighth => 0.5,
+                    LFOController::LFOSnapValues::EighthD => 0.5 * 1.5,
+                    LFOController::LFOSnapValues::EighthT => 0.5 / 3.0,
+                    LFOController::LFOSnapValues::Sixteen => 0.25,
+                    LFOController::LFOSnapValues::SixteenD => 0.25 * 1.5,
+                    LFOController::LFOSnapValues::SixteenT => 0.25 / 3.0,
+                    LFOController::LFOSnapValues::ThirtySecond => 0.125,
+                    LFOController::LFOSnapValues::ThirtySecondD => 0.125 * 1.5,
+                    LFOController::LFOSnapValues::ThirtySecondT => 0.125 / 3.0,
+                };
+                let freq_snap = (bpm / divisor) / 60.0;
+                if self.params.lfo1_freq.value() != freq_snap {
+                    self.lfo_1.set_frequency(freq_snap);
                 }
-            };
+            } else {
+                if self.params.lfo1_freq.value() != self.lfo_1.get_frequency() {
+                    self.lfo_1.set_frequency(self.params.lfo1_freq.value());
+                }
+            }
 
-            let mut temp_mod_cutoff_1_source_1: f32 = 0.0;
-            let mut temp_mod_cutoff_1_source_2: f32 = 0.0;
-            let mut temp_mod_cutoff_1_source_3: f32 = 0.0;
-            let mut temp_mod_cutoff_1_source_4: f32 = 0.0;
-            let mut temp_mod_cutoff_2_source_1: f32 = 0.0;
-            let mut temp_mod_cutoff_2_source_2: f32 = 0.0;
-            let mut temp_mod_cutoff_2_source_3: f32 = 0.0;
-            let mut temp_mod_cutoff_2_source_4: f32 = 0.0;
-            let mut temp_mod_resonance_1_source_1: f32 = 0.0;
-            let mut temp_mod_resonance_1_source_2: f32 = 0.0;
-            let mut temp_mod_resonance_1_source_3: f32 = 0.0;
-            let mut temp_mod_resonance_1_source_4: f32 = 0.0;
-            let mut temp_mod_resonance_2_source_1: f32 = 0.0;
-            let mut temp_mod_resonance_2_source_2: f32 = 0.0;
-            let mut temp_mod_resonance_2_source_3: f32 = 0.0;
-            let mut temp_mod_resonance_2_source_4: f32 = 0.0;
-            let mut temp_mod_detune_1: f32 = 0.0;
-            let mut temp_mod_detune_2: f32 = 0.0;
-            let mut temp_mod_detune_3: f32 = 0.0;
-            let mut temp_mod_uni_detune_1: f32 = 0.0;
-            let mut temp_mod_uni_detune_2: f32 = 0.0;
-            let mut temp_mod_uni_detune_3: f32 = 0.0;
-            // These are used for velocity to detune linkages
-            let mut temp_mod_vel_sum: f32 = 0.0;
-            let mut temp_mod_uni_vel_sum: f32 = 0.0;
-            let mut temp_mod_gain_1: f32 = -2.0;
-            let mut temp_mod_gain_2: f32 = -2.0;
-            let mut temp_mod_gain_3: f32 = -2.0;
-            let mut temp_mod_lfo_gain_1: f32 = 1.0;
-            let mut temp_mod_lfo_gain_2: f32 = 1.0;
-            let mut temp_mod_lfo_gain_3: f32 = 1.0;
-            // Modulation structs to pass things
-            let modulations_1: ModulationStruct;
-            let modulations_2: ModulationStruct;
-            let modulations_3: ModulationStruct;
-            let modulations_4: ModulationStruct;
-
-            // In this modulation section the velocity stuff is all weird since we need to pass velocity mod
-            // But this happens before we process the note values hence storing/passing it
-
-            // This is outside for held notes on specific source -> destinations
-            // This would happen when mod_value_X == 2.0 as a result - hence using the Atomic for velocity
-
-            if self.params.mod_source_1.value() == ModulationSource::Velocity {
-                match self.params.mod_destination_1.value() {
-                    ModulationDestination::Cutoff_1 => {
-                        temp_mod_cutoff_1_source_1 +=
-                            8000.0 * self.current_note_on_velocity.load(Ordering::SeqCst);
-                    }
-                    ModulationDestination::Cutoff_2 => {
-                        temp_mod_cutoff_2_source_1 +=
-                            8000.0 * self.current_note_on_velocity.load(Ordering::SeqCst);
-                    }
-                    ModulationDestination::All_Gain => {
-                        if self.params.mod_source_1.value() == ModulationSource::Velocity {
-                            let vel = self.current_note_on_velocity.load(Ordering::SeqCst);
-                            temp_mod_gain_1 = vel;
-                            temp_mod_gain_2 = vel;
-                            temp_mod_gain_3 = vel;
-                        }
+            // Update LFO Waveform
+            if self.params.lfo1_waveform.value() != self.lfo_1.get_waveform() {
+                self.lfo_1.set_waveform(self.params.lfo1_waveform.value());
+            }
+            self.lfo_1.set_one_shot(self.params.lfo1_retrigger.value() == LFOController::LFORetrigger::OneShot);
+        }
+        if self.params.lfo2_enable.value() {
+            // Update LFO Frequency
+            if self.params.lfo2_sync.value() {
+                let divisor = match self.params.lfo2_snap.value() {
+                    LFOController::LFOSnapValues::Quad => 16.0,
+                    LFOController::LFOSnapValues::QuadD => 16.0 * 1.5,
+                    LFOController::LFOSnapValues::QuadT => 16.0 / 3.0,
+                    LFOController::LFOSnapValues::Double => 8.0,
+                    LFOController::LFOSnapValues::DoubleD => 8.0 * 1.5,
+                    LFOController::LFOSnapValues::DoubleT => 8.0 / 3.0,
+                    LFOController::LFOSnapValues::Whole => 4.0,
+                    LFOController::LFOSnapValues::WholeD => 4.0 * 1.5,
+                    LFOController::LFOSnapValues::WholeT => 4.0 / 3.0,
+                    LFOController::LFOSnapValues::Half => 2.0,
+                    LFOController::LFOSnapValues::HalfD => 2.0 * 1.5,
+                    LFOController::LFOSnapValues::HalfT => 2.0 / 3.0,
+                    LFOController::LFOSnapValues::Quarter => 1.0,
+                    LFOController::LFOSnapValues::QuarterD => 1.0 * 1.5,
+                    LFOController::LFOSnapValues::QuarterT => 1.0 / 3.0,
+                    LFOController::LFOSnapValues::Eighth => 0.5,
+                    LFOController::LFOSnapValues::EighthD => 0.5 * 1.5,
+                    LFOController::LFOSnapValues::EighthT => 0.5 / 3.0,
+                    LFOController::LFOSnapValues::Sixteen => 0.25,
+                    LFOController::LFOSnapValues::SixteenD => 0.25 * 1.5,
+                    LFOController::LFOSnapValues::SixteenT => 0.25 / 3.0,
+                    LFOController::LFOSnapValues::ThirtySecond => 0.125,
+                    LFOController::LFOSnapValues::ThirtySecondD => 0.125 * 1.5,
+                    LFOController::LFOSnapValues::ThirtySecondT => 0.125 / 3.0,
+                };
+                let freq_snap = (bpm / divisor) / 60.0;
+                if self.params.lfo2_freq.value() != freq_snap {
+                    self.lfo_2.set_frequency(freq_snap);
+                }
+            } else {
+                if self.params.lfo2_freq.value() != self.lfo_2.get_frequency() {
+                    self.lfo_2.set_frequency(self.params.lfo2_freq.value());
+                }
+            }
+
+            // Update LFO Waveform
+            if self.params.lfo2_waveform.value() != self.lfo_2.get_waveform() {
+                self.lfo_2.set_waveform(self.params.lfo2_waveform.value());
+            }
+            self.lfo_2.set_one_shot(self.params.lfo2_retrigger.value() == LFOController::LFORetrigger::OneShot);
+        }
+        if self.params.lfo3_enable.value() {
+            // Update LFO Frequency
+            if self.params.lfo3_sync.value() {
+                let divisor = match self.params.lfo3_snap.value() {
+                    LFOController::LFOSnapValues::Quad => 16.0,
+                    LFOController::LFOSnapValues::QuadD => 16.0 * 1.5,
+                    LFOController::LFOSnapValues::QuadT => 16.0 / 3.0,
+                    LFOController::LFOSnapValues::Double => 8.0,
+                    LFOController::LFOSnapValues::DoubleD => 8.0 * 1.5,
+                    LFOController::LFOSnapValues::DoubleT => 8.0 / 3.0,
+                    LFOController::LFOSnapValues::Whole => 4.0,
+                    LFOController::LFOSnapValues::WholeD => 4.0 * 1.5,
+                    LFOController::LFOSnapValues::WholeT => 4.0 / 3.0,
+                    LFOController::LFOSnapValues::Half => 2.0,
+                    LFOController::LFOSnapValues::HalfD => 2.0 * 1.5,
+                    LFOController::LFOSnapValues::HalfT => 2.0 / 3.0,
+                    LFOController::LFOSnapValues::Quarter => 1.0,
+                    LFOController::LFOSnapValues::QuarterD => 1.0 * 1.5,
+                    LFOController::LFOSnapValues::QuarterT => 1.0 / 3.0,
+                    LFOController::LFOSnapValues::Eighth => 0.5,
+                    LFOController::LFOSnapValues::EighthD => 0.5 * 1.5,
+                    LFOController::LFOSnapValues::EighthT => 0.5 / 3.0,
+                    LFOController::LFOSnapValues::Sixteen => 0.25,
+                    LFOController::LFOSnapValues::SixteenD => 0.25 * 1.5,
+                    LFOController::LFOSnapValues::SixteenT => 0.25 / 3.0,
+                    LFOController::LFOSnapValues::ThirtySecond => 0.125,
+                    LFOController::LFOSnapValues::ThirtySecondD => 0.125 * 1.5,
+                    LFOController::LFOSnapValues::ThirtySecondT => 0.125 / 3.0,
+                };
+                let freq_snap = (bpm / divisor) / 60.0;
+                if self.params.lfo3_freq.value() != freq_snap {
+                    self.lfo_3.set_frequency(freq_snap);
+                }
+            } else {
+                if self.params.lfo3_freq.value() != self.lfo_3.get_frequency() {
+                    self.lfo_3.set_frequency(self.params.lfo3_freq.value());
+                }
+            }
+
+            // Update LFO Waveform
+            if self.params.lfo3_waveform.value() != self.lfo_3.get_waveform() {
+                self.lfo_3.set_waveform(self.params.lfo3_waveform.value());
+            }
+            self.lfo_3.set_one_shot(self.params.lfo3_retrigger.value() == LFOController::LFORetrigger::OneShot);
+        }
+    }
+
+    // One sample of the whole synth: humanize, mono/glide, the audio modules, mod matrix, FX and the master section
+    // process_midi and the offline preview render both run through here so the preview sounds like the patch
+    // Returns the final output and each generator's unmixed output for the Multi-Out buses
+    fn render_sample(
+        &mut self,
+        am1_lock: &mut AudioModule,
+        am2_lock: &mut AudioModule,
+        am3_lock: &mut AudioModule,
+        mut midi_event: Option<NoteEvent<()>>,
+        sample_id: usize,
+        block_len: usize,
+        input: (f32, f32),
+        bpm: f32,
+        meter_decay: f32,
+        lfo_current: &mut [f32; 3],
+    ) -> ((f32, f32), [(f32, f32); 3]) {
+        let (input_l, input_r) = input;
+        let [mut lfo_1_current, mut lfo_2_current, mut lfo_3_current] = *lfo_current;
+        // A mono retrigger plays on the sample after its release and has already been through humanize and
+        // mono handling, process_midi holds back new events until it has gone out
+        if let Some((event, glide_from)) = self.mono_pending.take() {
+            midi_event = Some(event);
+            self.glide_current = glide_from;
+            self.set_glide_target(0.0);
+        } else {
+            // Humanize
+            // Note ons get a small delay and velocity change, everything queues behind them to keep event order
+            let humanize = self.params.humanize.value();
+            if let Some(event) = midi_event.take() {
+                if humanize > 0.0 || !self.humanize_queue.is_empty() {
+                    let (due, event) = match event {
+                        NoteEvent::NoteOn { timing, voice_id, channel, note, velocity } if humanize > 0.0 => {
+                            // Never push a note past the end of this buffer
+                            let max_offset = (humanize * HUMANIZE_MAX_TIME * self.sample_rate) as usize;
+                            let offset = self.humanize_rng.gen_range(0..=max_offset).min(block_len - 1 - sample_id);
+                            let velocity = (velocity + self.humanize_rng.gen_range(-1.0..=1.0) * humanize * HUMANIZE_MAX_VELOCITY).clamp(0.0, 1.0);
+                            (sample_id + offset, NoteEvent::NoteOn { timing, voice_id, channel, note, velocity })
+                        },
+                        other => (sample_id, other),
+                    };
+                    let due = due.max(self.humanize_queue.back().map(|(d, _)| *d).unwrap_or(0));
+                    // A full queue drops the new event rather than growing on the audio thread
+                    if self.humanize_queue.len() < HUMANIZE_QUEUE_SIZE {
+                        self.humanize_queue.push_back((due, event));
                     }
-                    ModulationDestination::Osc1_Gain => {
-                        if self.params.mod_source_1.value() == ModulationSource::Velocity {
-                            temp_mod_gain_1 = self.current_note_on_velocity.load(Ordering::SeqCst);
-                        }
+                } else {
+                    midi_event = Some(event);
+                }
+            }
+            if midi_event.is_none() {
+                if let Some((due, _)) = self.humanize_queue.front() {
+                    if *due <= sample_id {
+                        midi_event = self.humanize_queue.pop_front().map(|(_, event)| event);
                     }
-                    ModulationDestination::Osc2_Gain => {
-                        if self.params.mod_source_1.value() == ModulationSource::Velocity {
-                            temp_mod_gain_2 = self.current_note_on_velocity.load(Ordering::SeqCst);
+                }
+            }
+            // Each note gets its own pitch offset, voices already sounding keep theirs
+            if let Some(NoteEvent::NoteOn { .. }) = midi_event {
+                self.humanize_detune = if humanize > 0.0 {
+                    self.humanize_rng.gen_range(-1.0..=1.0) * humanize * HUMANIZE_MAX_PITCH
+                } else {
+                    0.0
+                };
+            }
+
+            // Mono/Legato note handling happens after humanize so it sees the final note order
+            if let Some(event) = midi_event.take() {
+                midi_event = self.handle_mono(event);
+            }
+        }
+        if self.glide_current != self.glide_target {
+            self.glide_current += self.glide_step;
+            if (self.glide_step > 0.0 && self.glide_current > self.glide_target)
+                || (self.glide_step < 0.0 && self.glide_current < self.glide_target)
+                || self.glide_step == 0.0
+            {
+                self.glide_current = self.glide_target;
+            }
+        }
+
+        let sent_voice_max: usize = self.params.voice_limit.value() as usize;
+        let mut wave1_l: f32 = 0.0;
+        let mut wave2_l: f32 = 0.0;
+        let mut wave3_l: f32 = 0.0;
+        let mut wave1_r: f32 = 0.0;
+        let mut wave2_r: f32 = 0.0;
+        let mut wave3_r: f32 = 0.0;
+        // These track if a new note happens to re-open the filter
+        let mut reset_filter_controller1: bool = false;
+        let mut reset_filter_controller2: bool = false;
+        let mut reset_filter_controller3: bool = false;
+        // These track if a note just finished to allow filter closing
+        let mut note_off_filter_controller1: bool = false;
+        let mut note_off_filter_controller2: bool = false;
+        let mut note_off_filter_controller3: bool = false;
+
+        // Trigger passing variables to the audio modules when the GUI input changes
+        if self.update_something.load(Ordering::SeqCst) {
+            am1_lock.consume_params(self.params.clone(), 1);
+            am2_lock.consume_params(self.params.clone(), 2);
+            am3_lock.consume_params(self.params.clone(), 3);
+            // Fix Auto restretch/repitch behavior
+            if self.prev_restretch_1.load(Ordering::SeqCst) != self.params.restretch_1.value() {
+                self.prev_restretch_1.store(self.params.restretch_1.value(), Ordering::SeqCst);
+                am1_lock.regenerate_samples();
+            } else if am1_lock.sample_root_changed() {
+                am1_lock.regenerate_samples();
+            }
+            if self.prev_restretch_2.load(Ordering::SeqCst) != self.params.restretch_2.value() {
+                self.prev_restretch_2.store(self.params.restretch_2.value(), Ordering::SeqCst);
+                am2_lock.regenerate_samples();
+            } else if am2_lock.sample_root_changed() {
+                am2_lock.regenerate_samples();
+            }
+            if self.prev_restretch_3.load(Ordering::SeqCst) != self.params.restretch_3.value() {
+                self.prev_restretch_3.store(self.params.restretch_3.value(), Ordering::SeqCst);
+                am3_lock.regenerate_samples();
+            } else if am3_lock.sample_root_changed() {
+                am3_lock.regenerate_samples();
+            }
+
+            self.update_something.store(false, Ordering::SeqCst);
+        }
+
+        // Modulations
+        /////////////////////////////////////////////////////////////////////////////////////////////////
+        let mod_value_1: f32;
+        let mod_value_2: f32;
+        let mod_value_3: f32;
+        let mod_value_4: f32;
+        let mod_value_5: f32;
+        let mod_value_6: f32;
+        let mod_value_7: f32;
+        let mod_value_8: f32;
+
+        // If no modulations this = -2.0
+        // LFO depth sets the base strength and the matrix knob trims it per slot
+        mod_value_1 = match self.params.mod_source_1.value() {
+            ModulationSource::None | ModulationSource::UnsetModulation => -2.0,
+            ModulationSource::LFO1 => lfo_1_current * self.params.lfo1_depth.value() * self.params.mod_amount_knob_1.value(),
+            ModulationSource::LFO2 => lfo_2_current * self.params.lfo2_depth.value() * self.params.mod_amount_knob_1.value(),
+            ModulationSource::LFO3 => lfo_3_current * self.params.lfo3_depth.value() * self.params.mod_amount_knob_1.value(),
+            ModulationSource::Velocity => {
+                // This is to allow invalid midi events to not break this logic since we only want NoteOn
+                match midi_event.clone().unwrap_or(NoteEvent::Choke {
+                    timing: 0_u32,
+                    voice_id: Some(0_i32),
+                    channel: 0_u8,
+                    note: 0_u8,
+                }) {
+                    NoteEvent::NoteOn {
+                        velocity,
+                        timing: _,
+                        voice_id: _,
+                        channel: _,
+                        note: _,
+                    } => {
+                        // Store velocity on new note happening
+                        let vel = (velocity * self.params.mod_amount_knob_1.value().abs())
+                            .clamp(0.0, 1.0);
+                        if velocity != -1.0 {
+                            self.current_note_on_velocity.store(vel, Ordering::SeqCst);
                         }
+                        vel
                     }
-                    ModulationDestination::Osc3_Gain => {
-                        if self.params.mod_source_1.value() == ModulationSource::Velocity {
-                            temp_mod_gain_3 = self.current_note_on_velocity.load(Ordering::SeqCst);
+                    _ => -2.0,
+                }
+            }
+        };
+
+        mod_value_2 = match self.params.mod_source_2.value() {
+            ModulationSource::None | ModulationSource::UnsetModulation => -2.0,
+            ModulationSource::LFO1 => lfo_1_current * self.params.lfo1_depth.value() * self.params.mod_amount_knob_2.value(),
+            ModulationSource::LFO2 => lfo_2_current * self.params.lfo2_depth.value() * self.params.mod_amount_knob_2.value(),
+            ModulationSource::LFO3 => lfo_3_current * self.params.lfo3_depth.value() * self.params.mod_amount_knob_2.value(),
+            ModulationSource::Velocity => {
+                match midi_event.clone().unwrap_or(NoteEvent::Choke {
+                    timing: 0_u32,
+                    voice_id: Some(0_i32),
+                    channel: 0_u8,
+                    note: 0_u8,
+                }) {
+                    NoteEvent::NoteOn {
+                        velocity,
+                        timing: _,
+                        voice_id: _,
+                        channel: _,
+                        note: _,
+                    } => {
+                        if velocity != -1.0 {
+                            self.current_note_on_velocity
+                                .store(velocity, Ordering::SeqCst);
                         }
+                        (velocity * self.params.mod_amount_knob_2.value().abs()).clamp(0.0, 1.0)
                     }
-                    ModulationDestination::Resonance_1 => {
-                        if self.params.mod_source_1.value() == ModulationSource::Velocity {
-                            temp_mod_resonance_1_source_1 +=
-                                self.current_note_on_velocity.load(Ordering::SeqCst);
+                    _ => -2.0,
+                }
+            }
+        };
+
+        mod_value_3 = match self.params.mod_source_3.value() {
+            ModulationSource::None | ModulationSource::UnsetModulation => -2.0,
+            ModulationSource::LFO1 => lfo_1_current * self.params.lfo1_depth.value() * self.params.mod_amount_knob_3.value(),
+            ModulationSource::LFO2 => lfo_2_current * self.params.lfo2_depth.value() * self.params.mod_amount_knob_3.value(),
+            ModulationSource::LFO3 => lfo_3_current * self.params.lfo3_depth.value() * self.params.mod_amount_knob_3.value(),
+            ModulationSource::Velocity => {
+                match midi_event.clone().unwrap_or(NoteEvent::Choke {
+                    timing: 0_u32,
+                    voice_id: Some(0_i32),
+                    channel: 0_u8,
+                    note: 0_u8,
+                }) {
+                    NoteEvent::NoteOn {
+                        velocity,
+                        timing: _,
+                        voice_id: _,
+                        channel: _,
+                        note: _,
+                    } => {
+                        if velocity != -1.0 {
+                            self.current_note_on_velocity
+                                .store(velocity, Ordering::SeqCst);
                         }
+                        (velocity * self.params.mod_amount_knob_3.value().abs()).clamp(0.0, 1.0)
                     }
-                    ModulationDestination::Resonance_2 => {
-                        if self.params.mod_source_1.value() == ModulationSource::Velocity {
-                            temp_mod_resonance_2_source_1 +=
-                                self.current_note_on_velocity.load(Ordering::SeqCst);
+                    _ => -2.0,
+                }
+            }
+        };
+
+        mod_value_4 = match self.params.mod_source_4.value() {
+            ModulationSource::None | ModulationSource::UnsetModulation => -2.0,
+            ModulationSource::LFO1 => lfo_1_current * self.params.lfo1_depth.value() * self.params.mod_amount_knob_4.value(),
+            ModulationSource::LFO2 => lfo_2_current * self.params.lfo2_depth.value() * self.params.mod_amount_knob_4.value(),
+            ModulationSource::LFO3 => lfo_3_current * self.params.lfo3_depth.value() * self.params.mod_amount_knob_4.value(),
+            ModulationSource::Velocity => {
+                match midi_event.clone().unwrap_or(NoteEvent::Choke {
+                    timing: 0_u32,
+                    voice_id: Some(0_i32),
+                    channel: 0_u8,
+                    note: 0_u8,
+                }) {
+                    NoteEvent::NoteOn {
+                        velocity,
+                        timing: _,
+                        voice_id: _,
+                        channel: _,
+                        note: _,
+                    } => {
+                        if velocity != -1.0 {
+                            self.current_note_on_velocity
+                                .store(velocity, Ordering::SeqCst);
                         }
+                        (velocity * self.params.mod_amount_knob_4.value().abs()).clamp(0.0, 1.0)
                     }
-                    _ => {}
+                    _ => -2.0,
                 }
             }
-            if self.params.mod_source_2.value() == ModulationSource::Velocity {
-                match self.params.mod_destination_2.value() {
-                    ModulationDestination::Cutoff_1 => {
-                        temp_mod_cutoff_1_source_2 +=
-                            8000.0 * self.current_note_on_velocity.load(Ordering::SeqCst);
-                    }
-                    ModulationDestination::Cutoff_2 => {
-                        temp_mod_cutoff_2_source_2 +=
-                            8000.0 * self.current_note_on_velocity.load(Ordering::SeqCst);
-                    }
-                    ModulationDestination::All_Gain => {
-                        if self.params.mod_source_2.value() == ModulationSource::Velocity {
-                            let vel = self.current_note_on_velocity.load(Ordering::SeqCst);
-                            temp_mod_gain_1 = vel;
-                            temp_mod_gain_2 = vel;
-                            temp_mod_gain_3 = vel;
+        };
+
+        mod_value_5 = match self.params.mod_source_5.value() {
+            ModulationSource::None | ModulationSource::UnsetModulation => -2.0,
+            ModulationSource::LFO1 => lfo_1_current * self.params.lfo1_depth.value() * self.params.mod_amount_knob_5.value(),
+            ModulationSource::LFO2 => lfo_2_current * self.params.lfo2_depth.value() * self.params.mod_amount_knob_5.value(),
+            ModulationSource::LFO3 => lfo_3_current * self.params.lfo3_depth.value() * self.params.mod_amount_knob_5.value(),
+            ModulationSource::Velocity => {
+                match midi_event.clone().unwrap_or(NoteEvent::Choke {
+                    timing: 0_u32,
+                    voice_id: Some(0_i32),
+                    channel: 0_u8,
+                    note: 0_u8,
+                }) {
+                    NoteEvent::NoteOn {
+                        velocity,
+                        timing: _,
+                        voice_id: _,
+                        channel: _,
+                        note: _,
+                    } => {
+                        if velocity != -1.0 {
+                            self.current_note_on_velocity
+                                .store(velocity, Ordering::SeqCst);
                         }
+                        (velocity * self.params.mod_amount_knob_5.value().abs()).clamp(0.0, 1.0)
                     }
-                    ModulationDestination::Osc1_Gain => {
-                        if self.params.mod_source_2.value() == ModulationSource::Velocity {
-                            temp_mod_gain_1 = self.current_note_on_velocity.load(Ordering::SeqCst);
+                    _ => -2.0,
+                }
+            }
+        };
+
+        mod_value_6 = match self.params.mod_source_6.value() {
+            ModulationSource::None | ModulationSource::UnsetModulation => -2.0,
+            ModulationSource::LFO1 => lfo_1_current * self.params.lfo1_depth.value() * self.params.mod_amount_knob_6.value(),
+            ModulationSource::LFO2 => lfo_2_current * self.params.lfo2_depth.value() * self.params.mod_amount_knob_6.value(),
+            ModulationSource::LFO3 => lfo_3_current * self.params.lfo3_depth.value() * self.params.mod_amount_knob_6.value(),
+            ModulationSource::Velocity => {
+                match midi_event.clone().unwrap_or(NoteEvent::Choke {
+                    timing: 0_u32,
+                    voice_id: Some(0_i32),
+                    channel: 0_u8,
+                    note: 0_u8,
+                }) {
+                    NoteEvent::NoteOn {
+                        velocity,
+                        timing: _,
+                        voice_id: _,
+                        channel: _,
+                        note: _,
+                    } => {
+                        if velocity != -1.0 {
+                            self.current_note_on_velocity
+                                .store(velocity, Ordering::SeqCst);
                         }
+                        (velocity * self.params.mod_amount_knob_6.value().abs()).clamp(0.0, 1.0)
                     }
-                    ModulationDestination::Osc2_Gain => {
-                        if self.params.mod_source_2.value() == ModulationSource::Velocity {
-                            temp_mod_gain_2 = self.current_note_on_velocity.load(Ordering::SeqCst);
+                    _ => -2.0,
+                }
+            }
+        };
+
+        mod_value_7 = match self.params.mod_source_7.value() {
+            ModulationSource::None | ModulationSource::UnsetModulation => -2.0,
+            ModulationSource::LFO1 => lfo_1_current * self.params.lfo1_depth.value() * self.params.mod_amount_knob_7.value(),
+            ModulationSource::LFO2 => lfo_2_current * self.params.lfo2_depth.value() * self.params.mod_amount_knob_7.value(),
+            ModulationSource::LFO3 => lfo_3_current * self.params.lfo3_depth.value() * self.params.mod_amount_knob_7.value(),
+            ModulationSource::Velocity => {
+                match midi_event.clone().unwrap_or(NoteEvent::Choke {
+                    timing: 0_u32,
+                    voice_id: Some(0_i32),
+                    channel: 0_u8,
+                    note: 0_u8,
+                }) {
+                    NoteEvent::NoteOn {
+                        velocity,
+                        timing: _,
+                        voice_id: _,
+                        channel: _,
+                        note: _,
+                    } => {
+                        if velocity != -1.0 {
+                            self.current_note_on_velocity
+                                .store(velocity, Ordering::SeqCst);
                         }
+                        (velocity * self.params.mod_amount_knob_7.value().abs()).clamp(0.0, 1.0)
                     }
-                    ModulationDestination::Osc3_Gain => {
-                        if self.params.mod_source_2.value() == ModulationSource::Velocity {
-                            temp_mod_gain_3 = self.current_note_on_velocity.load(Ordering::SeqCst);
+                    _ => -2.0,
+                }
+            }
+        };
+
+        mod_value_8 = match self.params.mod_source_8.value() {
+            ModulationSource::None | ModulationSource::UnsetModulation => -2.0,
+            ModulationSource::LFO1 => lfo_1_current * self.params.lfo1_depth.value() * self.params.mod_amount_knob_8.value(),
+            ModulationSource::LFO2 => lfo_2_current * self.params.lfo2_depth.value() * self.params.mod_amount_knob_8.value(),
+            ModulationSource::LFO3 => lfo_3_current * self.params.lfo3_depth.value() * self.params.mod_amount_knob_8.value(),
+            ModulationSource::Velocity => {
+                match midi_event.clone().unwrap_or(NoteEvent::Choke {
+                    timing: 0_u32,
+                    voice_id: Some(0_i32),
+                    channel: 0_u8,
+                    note: 0_u8,
+                }) {
+                    NoteEvent::NoteOn {
+                        velocity,
+                        timing: _,
+                        voice_id: _,
+                        channel: _,
+                        note: _,
+                    } => {
+                        if velocity != -1.0 {
+                            self.current_note_on_velocity
+                                .store(velocity, Ordering::SeqCst);
                         }
+                        (velocity * self.params.mod_amount_knob_8.value().abs()).clamp(0.0, 1.0)
                     }
-                    ModulationDestination::Resonance_1 => {
-                        if self.params.mod_source_2.value() == ModulationSource::Velocity {
-                            temp_mod_resonance_1_source_2 -=
-                                self.current_note_on_velocity.load(Ordering::SeqCst);
-                        }
+                    _ => -2.0,
+                }
+            }
+        };
+        for (slot, value) in self.mod_values.iter().zip([mod_value_1, mod_value_2, mod_value_3, mod_value_4, mod_value_5, mod_value_6, mod_value_7, mod_value_8]) {
+            slot.store(value, Ordering::Relaxed);
+        }
+
+        let mut temp_mod_cutoff_1_source_1: f32 = 0.0;
+        let mut temp_mod_cutoff_1_source_2: f32 = 0.0;
+        let mut temp_mod_cutoff_1_source_3: f32 = 0.0;
+        let mut temp_mod_cutoff_1_source_4: f32 = 0.0;
+        let mut temp_mod_cutoff_1_source_5: f32 = 0.0;
+        let mut temp_mod_cutoff_1_source_6: f32 = 0.0;
+        let mut temp_mod_cutoff_1_source_7: f32 = 0.0;
+        let mut temp_mod_cutoff_1_source_8: f32 = 0.0;
+        let mut temp_mod_cutoff_2_source_1: f32 = 0.0;
+        let mut temp_mod_cutoff_2_source_2: f32 = 0.0;
+        let mut temp_mod_cutoff_2_source_3: f32 = 0.0;
+        let mut temp_mod_cutoff_2_source_4: f32 = 0.0;
+        let mut temp_mod_cutoff_2_source_5: f32 = 0.0;
+        let mut temp_mod_cutoff_2_source_6: f32 = 0.0;
+        let mut temp_mod_cutoff_2_source_7: f32 = 0.0;
+        let mut temp_mod_cutoff_2_source_8: f32 = 0.0;
+        let mut temp_mod_resonance_1_source_1: f32 = 0.0;
+        let mut temp_mod_resonance_1_source_2: f32 = 0.0;
+        let mut temp_mod_resonance_1_source_3: f32 = 0.0;
+        let mut temp_mod_resonance_1_source_4: f32 = 0.0;
+        let mut temp_mod_resonance_1_source_5: f32 = 0.0;
+        let mut temp_mod_resonance_1_source_6: f32 = 0.0;
+        let mut temp_mod_resonance_1_source_7: f32 = 0.0;
+        let mut temp_mod_resonance_1_source_8: f32 = 0.0;
+        let mut temp_mod_resonance_2_source_1: f32 = 0.0;
+        let mut temp_mod_resonance_2_source_2: f32 = 0.0;
+        let mut temp_mod_resonance_2_source_3: f32 = 0.0;
+        let mut temp_mod_resonance_2_source_4: f32 = 0.0;
+        let mut temp_mod_resonance_2_source_5: f32 = 0.0;
+        let mut temp_mod_resonance_2_source_6: f32 = 0.0;
+        let mut temp_mod_resonance_2_source_7: f32 = 0.0;
+        let mut temp_mod_resonance_2_source_8: f32 = 0.0;
+        let mut temp_mod_detune_1: f32 = 0.0;
+        let mut temp_mod_detune_2: f32 = 0.0;
+        let mut temp_mod_detune_3: f32 = 0.0;
+        let mut temp_mod_uni_detune_1: f32 = 0.0;
+        let mut temp_mod_uni_detune_2: f32 = 0.0;
+        let mut temp_mod_uni_detune_3: f32 = 0.0;
+        // These are used for velocity to detune linkages
+        let mut temp_mod_vel_sum: f32 = 0.0;
+        let mut temp_mod_uni_vel_sum: f32 = 0.0;
+        let mut temp_mod_drive_1: f32 = 0.0;
+        let mut temp_mod_drive_2: f32 = 0.0;
+        let mut temp_mod_morph_1: f32 = 0.0;
+        let mut temp_mod_morph_2: f32 = 0.0;
+        let mut temp_mod_morph_3: f32 = 0.0;
+        let mut temp_mod_shape_1: f32 = 0.0;
+        let mut temp_mod_shape_2: f32 = 0.0;
+        let mut temp_mod_shape_3: f32 = 0.0;
+        let mut temp_mod_gain_1: f32 = -2.0;
+        let mut temp_mod_gain_2: f32 = -2.0;
+        let mut temp_mod_gain_3: f32 = -2.0;
+        let mut temp_mod_lfo_gain_1: f32 = 1.0;
+        let mut temp_mod_lfo_gain_2: f32 = 1.0;
+        let mut temp_mod_lfo_gain_3: f32 = 1.0;
+        // Modulation structs to pass things
+        let modulations_1: ModulationStruct;
+        let modulations_2: ModulationStruct;
+        let modulations_3: ModulationStruct;
+        let modulations_4: ModulationStruct;
+        let modulations_5: ModulationStruct;
+        let modulations_6: ModulationStruct;
+        let modulations_7: ModulationStruct;
+        let modulations_8: ModulationStruct;
+
+        // In this modulation section the velocity stuff is all weird since we need to pass velocity mod
+        // But this happens before we process the note values hence storing/passing it
+
+        // This is outside for held notes on specific source -> destinations
+        // This would happen when mod_value_X == 2.0 as a result - hence using the Atomic for velocity
+
+        if self.params.mod_source_1.value() == ModulationSource::Velocity {
+            match self.params.mod_destination_1.value() {
+                ModulationDestination::Cutoff_1 => {
+                    temp_mod_cutoff_1_source_1 +=
+                        8000.0 * self.current_note_on_velocity.load(Ordering::SeqCst);
+                }
+                ModulationDestination::Cutoff_2 => {
+                    temp_mod_cutoff_2_source_1 +=
+                        8000.0 * self.current_note_on_velocity.load(Ordering::SeqCst);
+                }
+                ModulationDestination::All_Gain => {
+                    if self.params.mod_source_1.value() == ModulationSource::Velocity {
+                        let vel = self.current_note_on_velocity.load(Ordering::SeqCst);
+                        temp_mod_gain_1 = vel;
+                        temp_mod_gain_2 = vel;
+                        temp_mod_gain_3 = vel;
                     }
-                    ModulationDestination::Resonance_2 => {
-                        if self.params.mod_source_2.value() == ModulationSource::Velocity {
-                            temp_mod_resonance_2_source_2 -=
-                                self.current_note_on_velocity.load(Ordering::SeqCst);
-                        }
+                }
+                ModulationDestination::Osc1_Gain => {
+                    if self.params.mod_source_1.value() == ModulationSource::Velocity {
+                        temp_mod_gain_1 = self.current_note_on_velocity.load(Ordering::SeqCst);
                     }
-                    _ => {}
                 }
-            }
-            if self.params.mod_source_3.value() == ModulationSource::Velocity {
-                match self.params.mod_destination_3.value() {
-                    ModulationDestination::Cutoff_1 => {
-                        temp_mod_cutoff_1_source_3 +=
-                            8000.0 * self.current_note_on_velocity.load(Ordering::SeqCst);
+                ModulationDestination::Osc2_Gain => {
+                    if self.params.mod_source_1.value() == ModulationSource::Velocity {
+                        temp_mod_gain_2 = self.current_note_on_velocity.load(Ordering::SeqCst);
                     }
-                    ModulationDestination::Cutoff_2 => {
-                        temp_mod_cutoff_2_source_3 +=
-                            8000.0 * self.current_note_on_velocity.load(Ordering::SeqCst);
-                    }
-                    ModulationDestination::All_Gain => {
-                        if self.params.mod_source_3.value() == ModulationSource::Velocity {
-                            let vel = self.current_note_on_velocity.load(Ordering::SeqCst);
-                            temp_mod_gain_1 = vel;
-                            temp_mod_gain_2 = vel;
-                            temp_mod_gain_3 = vel;
-                        }
+                }
+                ModulationDestination::Osc3_Gain => {
+                    if self.params.mod_source_1.value() == ModulationSource::Velocity {
+                        temp_mod_gain_3 = self.current_note_on_velocity.load(Ordering::SeqCst);
                     }
-                    ModulationDestination::Osc1_Gain => {
-                        if self.params.mod_source_3.value() == ModulationSource::Velocity {
-                            temp_mod_gain_1 = self.current_note_on_velocity.load(Ordering::SeqCst);
-                        }
+                }
+                ModulationDestination::Resonance_1 => {
+                    if self.params.mod_source_1.value() == ModulationSource::Velocity {
+                        temp_mod_resonance_1_source_1 +=
+                            self.current_note_on_velocity.load(Ordering::SeqCst);
                     }
-                    ModulationDestination::Osc2_Gain => {
-                        if self.params.mod_source_3.value() == ModulationSource::Velocity {
-                            temp_mod_gain_2 = self.current_note_on_velocity.load(Ordering::SeqCst);
-                        }
+                }
+                ModulationDestination::Resonance_2 => {
+                    if self.params.mod_source_1.value() == ModulationSource::Velocity {
+                        temp_mod_resonance_2_source_1 +=
+                            self.current_note_on_velocity.load(Ordering::SeqCst);
                     }
-                    ModulationDestination::Osc3_Gain => {
-                        if self.params.mod_source_3.value() == ModulationSource::Velocity {
-                            temp_mod_gain_3 = self.current_note_on_velocity.load(Ordering::SeqCst);
-                        }
+                }
+                _ => {}
+            }
+        }
+        if self.params.mod_source_2.value() == ModulationSource::Velocity {
+            match self.params.mod_destination_2.value() {
+                ModulationDestination::Cutoff_1 => {
+                    temp_mod_cutoff_1_source_2 +=
+                        8000.0 * self.current_note_on_velocity.load(Ordering::SeqCst);
+                }
+                ModulationDestination::Cutoff_2 => {
+                    temp_mod_cutoff_2_source_2 +=
+                        8000.0 * self.current_note_on_velocity.load(Ordering::SeqCst);
+                }
+                ModulationDestination::All_Gain => {
+                    if self.params.mod_source_2.value() == ModulationSource::Velocity {
+                        let vel = self.current_note_on_velocity.load(Ordering::SeqCst);
+                        temp_mod_gain_1 = vel;
+                        temp_mod_gain_2 = vel;
+                        temp_mod_gain_3 = vel;
                     }
-                    ModulationDestination::Resonance_1 => {
-                        if self.params.mod_source_3.value() == ModulationSource::Velocity {
-                            temp_mod_resonance_1_source_3 +=
-                                self.current_note_on_velocity.load(Ordering::SeqCst);
-                        }
+                }
+                ModulationDestination::Osc1_Gain => {
+                    if self.params.mod_source_2.value() == ModulationSource::Velocity {
+                        temp_mod_gain_1 = self.current_note_on_velocity.load(Ordering::SeqCst);
                     }
-                    ModulationDestination::Resonance_2 => {
-                        if self.params.mod_source_3.value() == ModulationSource::Velocity {
-                            temp_mod_resonance_2_source_3 +=
-                                self.current_note_on_velocity.load(Ordering::SeqCst);
-                        }
+                }
+                ModulationDestination::Osc2_Gain => {
+                    if self.params.mod_source_2.value() == ModulationSource::Velocity {
+                        temp_mod_gain_2 = self.current_note_on_velocity.load(Ordering::SeqCst);
                     }
-                    _ => {}
                 }
-            }
-            if self.params.mod_source_4.value() == ModulationSource::Velocity {
-                match self.params.mod_destination_4.value() {
-                    ModulationDestination::Cutoff_1 => {
-                        temp_mod_cutoff_1_source_4 +=
-                            8000.0 * self.current_note_on_velocity.load(Ordering::SeqCst);
+                ModulationDestination::Osc3_Gain => {
+                    if self.params.mod_source_2.value() == ModulationSource::Velocity {
+                        temp_mod_gain_3 = self.current_note_on_velocity.load(Ordering::SeqCst);
                     }
-                    ModulationDestination::Cutoff_2 => {
-                        temp_mod_cutoff_2_source_4 +=
-                            8000.0 * self.current_note_on_velocity.load(Ordering::SeqCst);
-                    }
-                    ModulationDestination::All_Gain => {
-                        if self.params.mod_source_4.value() == ModulationSource::Velocity {
-                            let vel = self.current_note_on_velocity.load(Ordering::SeqCst);
-                            temp_mod_gain_1 = vel;
-                            temp_mod_gain_2 = vel;
-                            temp_mod_gain_3 = vel;
-                        }
+                }
+                ModulationDestination::Resonance_1 => {
+                    if self.params.mod_source_2.value() == ModulationSource::Velocity {
+                        temp_mod_resonance_1_source_2 -=
+                            self.current_note_on_velocity.load(Ordering::SeqCst);
                     }
-                    ModulationDestination::Osc1_Gain => {
-                        if self.params.mod_source_4.value() == ModulationSource::Velocity {
-                            temp_mod_gain_1 = self.current_note_on_velocity.load(Ordering::SeqCst);
-                        }
+                }
+                ModulationDestination::Resonance_2 => {
+                    if self.params.mod_source_2.value() == ModulationSource::Velocity {
+                        temp_mod_resonance_2_source_2 -=
+                            self.current_note_on_velocity.load(Ordering::SeqCst);
                     }
-                    ModulationDestination::Osc2_Gain => {
-                        if self.params.mod_source_4.value() == ModulationSource::Velocity {
-                            temp_mod_gain_2 = self.current_note_on_velocity.load(Ordering::SeqCst);
-                        }
+                }
+                _ => {}
+            }
+        }
+        if self.params.mod_source_3.value() == ModulationSource::Velocity {
+            match self.params.mod_destination_3.value() {
+                ModulationDestination::Cutoff_1 => {
+                    temp_mod_cutoff_1_source_3 +=
+                        8000.0 * self.current_note_on_velocity.load(Ordering::SeqCst);
+                }
+                ModulationDestination::Cutoff_2 => {
+                    temp_mod_cutoff_2_source_3 +=
+                        8000.0 * self.current_note_on_velocity.load(Ordering::SeqCst);
+                }
+                ModulationDestination::All_Gain => {
+                    if self.params.mod_source_3.value() == ModulationSource::Velocity {
+                        let vel = self.current_note_on_velocity.load(Ordering::SeqCst);
+                        temp_mod_gain_1 = vel;
+                        temp_mod_gain_2 = vel;
+                        temp_mod_gain_3 = vel;
                     }
-                    ModulationDestination::Osc3_Gain => {
-                        if self.params.mod_source_4.value() == ModulationSource::Velocity {
-                            temp_mod_gain_3 = self.current_note_on_velocity.load(Ordering::SeqCst);
-                        }
+                }
+                ModulationDestination::Osc1_Gain => {
+                    if self.params.mod_source_3.value() == ModulationSource::Velocity {
+                        temp_mod_gain_1 = self.current_note_on_velocity.load(Ordering::SeqCst);
                     }
-                    ModulationDestination::Resonance_1 => {
-                        if self.params.mod_source_4.value() == ModulationSource::Velocity {
-                            temp_mod_resonance_1_source_4 +=
-                                self.current_note_on_velocity.load(Ordering::SeqCst);
-                        }
+                }
+                ModulationDestination::Osc2_Gain => {
+                    if self.params.mod_source_3.value() == ModulationSource::Velocity {
+                        temp_mod_gain_2 = self.current_note_on_velocity.load(Ordering::SeqCst);
                     }
-                    ModulationDestination::Resonance_2 => {
-                        if self.params.mod_source_4.value() == ModulationSource::Velocity {
-                            temp_mod_resonance_2_source_4 +=
-                                self.current_note_on_velocity.load(Ordering::SeqCst);
-                        }
+                }
+                ModulationDestination::Osc3_Gain => {
+                    if self.params.mod_source_3.value() == ModulationSource::Velocity {
+                        temp_mod_gain_3 = self.current_note_on_velocity.load(Ordering::SeqCst);
+                    }
+                }
+                ModulationDestination::Resonance_1 => {
+                    if self.params.mod_source_3.value() == ModulationSource::Velocity {
+                        temp_mod_resonance_1_source_3 +=
+                            self.current_note_on_velocity.load(Ordering::SeqCst);
+                    }
+                }
+                ModulationDestination::Resonance_2 => {
+                    if self.params.mod_source_3.value() == ModulationSource::Velocity {
+                        temp_mod_resonance_2_source_3 +=
+                            self.current_note_on_velocity.load(Ordering::SeqCst);
                     }
-                    _ => {}
                 }
+                _ => {}
             }
-
-            ///////////////////////////////////////////////////////////////
-            // If mod_value is not -2.0 we are in Note ON event or an LFO
-            if mod_value_1 != -2.0 {
-                match self.params.mod_destination_1.value() {
-                    ModulationDestination::None | ModulationDestination::UnsetModulation => {}
-                    ModulationDestination::Cutoff_1 => {
-                        if self.params.mod_source_1.value() == ModulationSource::Velocity {
-                            // I don't think this gets reached in Velocity case because of mod_value_X
-                            temp_mod_cutoff_1_source_1 +=
-                                20000.0 * self.current_note_on_velocity.load(Ordering::SeqCst);
-                        } else {
-                            temp_mod_cutoff_1_source_1 += 20000.0 * mod_value_1;
-                        }
+        }
+        if self.params.mod_source_4.value() == ModulationSource::Velocity {
+            match self.params.mod_destination_4.value() {
+                ModulationDestination::Cutoff_1 => {
+                    temp_mod_cutoff_1_source_4 +=
+                        8000.0 * self.current_note_on_velocity.load(Ordering::SeqCst);
+                }
+                ModulationDestination::Cutoff_2 => {
+                    temp_mod_cutoff_2_source_4 +=
+                        8000.0 * self.current_note_on_velocity.load(Ordering::SeqCst);
+                }
+                ModulationDestination::All_Gain => {
+                    if self.params.mod_source_4.value() == ModulationSource::Velocity {
+                        let vel = self.current_note_on_velocity.load(Ordering::SeqCst);
+                        temp_mod_gain_1 = vel;
+                        temp_mod_gain_2 = vel;
+                        temp_mod_gain_3 = vel;
                     }
-                    ModulationDestination::Cutoff_2 => {
-                        if self.params.mod_source_1.value() == ModulationSource::Velocity {
-                            temp_mod_cutoff_2_source_1 +=
-                                20000.0 * self.current_note_on_velocity.load(Ordering::SeqCst);
-                        } else {
-                            temp_mod_cutoff_2_source_1 += 20000.0 * mod_value_1;
-                        }
+                }
+                ModulationDestination::Osc1_Gain => {
+                    if self.params.mod_source_4.value() == ModulationSource::Velocity {
+                        temp_mod_gain_1 = self.current_note_on_velocity.load(Ordering::SeqCst);
                     }
-                    ModulationDestination::Resonance_1 => {
-                        if self.params.mod_source_1.value() == ModulationSource::Velocity {
-                            temp_mod_resonance_1_source_1 -=
-                                self.current_note_on_velocity.load(Ordering::SeqCst);
-                        } else {
-                            temp_mod_resonance_1_source_1 -= mod_value_1;
-                        }
+                }
+                ModulationDestination::Osc2_Gain => {
+                    if self.params.mod_source_4.value() == ModulationSource::Velocity {
+                        temp_mod_gain_2 = self.current_note_on_velocity.load(Ordering::SeqCst);
                     }
-                    ModulationDestination::Resonance_2 => {
-                        if self.params.mod_source_1.value() == ModulationSource::Velocity {
-                            temp_mod_resonance_2_source_1 -=
-                                self.current_note_on_velocity.load(Ordering::SeqCst);
-                        } else {
-                            temp_mod_resonance_2_source_1 -= mod_value_1;
-                        }
+                }
+                ModulationDestination::Osc3_Gain => {
+                    if self.params.mod_source_4.value() == ModulationSource::Velocity {
+                        temp_mod_gain_3 = self.current_note_on_velocity.load(Ordering::SeqCst);
                     }
-                    ModulationDestination::All_Detune => {
-                        if self.params.mod_source_1.value() == ModulationSource::Velocity {
-                            temp_mod_vel_sum += mod_value_1;
-                        }
-                        temp_mod_detune_1 += mod_value_1;
-                        temp_mod_detune_2 += mod_value_1;
-                        temp_mod_detune_3 += mod_value_1;
+                }
+                ModulationDestination::Resonance_1 => {
+                    if self.params.mod_source_4.value() == ModulationSource::Velocity {
+                        temp_mod_resonance_1_source_4 +=
+                            self.current_note_on_velocity.load(Ordering::SeqCst);
                     }
-                    ModulationDestination::Osc1Detune => {
-                        if self.params.mod_source_1.value() == ModulationSource::Velocity {
-                            temp_mod_vel_sum += mod_value_1;
-                        }
-                        temp_mod_detune_1 += mod_value_1;
+                }
+                ModulationDestination::Resonance_2 => {
+                    if self.params.mod_source_4.value() == ModulationSource::Velocity {
+                        temp_mod_resonance_2_source_4 +=
+                            self.current_note_on_velocity.load(Ordering::SeqCst);
                     }
-                    ModulationDestination::Osc2Detune => {
-                        if self.params.mod_source_1.value() == ModulationSource::Velocity {
-                            temp_mod_vel_sum += mod_value_1;
-                        }
-                        temp_mod_detune_2 += mod_value_1;
+                }
+                _ => {}
+            }
+        }
+        if self.params.mod_source_5.value() == ModulationSource::Velocity {
+            match self.params.mod_destination_5.value() {
+                ModulationDestination::Cutoff_1 => {
+                    temp_mod_cutoff_1_source_5 +=
+                        8000.0 * self.current_note_on_velocity.load(Ordering::SeqCst);
+                }
+                ModulationDestination::Cutoff_2 => {
+                    temp_mod_cutoff_2_source_5 +=
+                        8000.0 * self.current_note_on_velocity.load(Ordering::SeqCst);
+                }
+                ModulationDestination::All_Gain => {
+                    if self.params.mod_source_5.value() == ModulationSource::Velocity {
+                        let vel = self.current_note_on_velocity.load(Ordering::SeqCst);
+                        temp_mod_gain_1 = vel;
+                        temp_mod_gain_2 = vel;
+                        temp_mod_gain_3 = vel;
                     }
-                    ModulationDestination::Osc3Detune => {
-                        if self.params.mod_source_1.value() == ModulationSource::Velocity {
-                            temp_mod_vel_sum += mod_value_1;
-                        }
-                        temp_mod_detune_3 += mod_value_1;
+                }
+                ModulationDestination::Osc1_Gain => {
+                    if self.params.mod_source_5.value() == ModulationSource::Velocity {
+                        temp_mod_gain_1 = self.current_note_on_velocity.load(Ordering::SeqCst);
                     }
-                    ModulationDestination::All_UniDetune => {
-                        if self.params.mod_source_1.value() == ModulationSource::Velocity {
-                            temp_mod_vel_sum += mod_value_1;
-                        }
-                        temp_mod_uni_detune_1 += mod_value_1;
-                        temp_mod_uni_detune_2 += mod_value_1;
-                        temp_mod_uni_detune_3 += mod_value_1;
+                }
+                ModulationDestination::Osc2_Gain => {
+                    if self.params.mod_source_5.value() == ModulationSource::Velocity {
+                        temp_mod_gain_2 = self.current_note_on_velocity.load(Ordering::SeqCst);
                     }
-                    ModulationDestination::Osc1UniDetune => {
-                        if self.params.mod_source_1.value() == ModulationSource::Velocity {
-                            temp_mod_uni_vel_sum += mod_value_1;
-                        }
-                        temp_mod_uni_detune_1 += mod_value_1;
+                }
+                ModulationDestination::Osc3_Gain => {
+                    if self.params.mod_source_5.value() == ModulationSource::Velocity {
+                        temp_mod_gain_3 = self.current_note_on_velocity.load(Ordering::SeqCst);
                     }
-                    ModulationDestination::Osc2UniDetune => {
-                        if self.params.mod_source_1.value() == ModulationSource::Velocity {
-                            temp_mod_uni_vel_sum += mod_value_1;
-                        }
-                        temp_mod_uni_detune_2 += mod_value_1;
+                }
+                ModulationDestination::Resonance_1 => {
+                    if self.params.mod_source_5.value() == ModulationSource::Velocity {
+                        temp_mod_resonance_1_source_5 +=
+                            self.current_note_on_velocity.load(Ordering::SeqCst);
                     }
-                    ModulationDestination::Osc3UniDetune => {
-                        if self.params.mod_source_1.value() == ModulationSource::Velocity {
-                            temp_mod_uni_vel_sum += mod_value_1;
-                        }
-                        temp_mod_uni_detune_3 += mod_value_1;
-                    }
-                    ModulationDestination::All_Gain => {
-                        if self.params.mod_source_1.value() == ModulationSource::Velocity {
-                            let vel = self.current_note_on_velocity.load(Ordering::SeqCst);
-                            temp_mod_gain_1 = vel;
-                            temp_mod_gain_2 = vel;
-                            temp_mod_gain_3 = vel;
-                        } else {
-                            temp_mod_lfo_gain_1 = mod_value_1;
-                            temp_mod_lfo_gain_2 = mod_value_1;
-                            temp_mod_lfo_gain_3 = mod_value_1;
-                        }
+                }
+                ModulationDestination::Resonance_2 => {
+                    if self.params.mod_source_5.value() == ModulationSource::Velocity {
+                        temp_mod_resonance_2_source_5 +=
+                            self.current_note_on_velocity.load(Ordering::SeqCst);
                     }
-                    ModulationDestination::Osc1_Gain => {
-                        if self.params.mod_source_1.value() == ModulationSource::Velocity {
-                            temp_mod_gain_1 = self.current_note_on_velocity.load(Ordering::SeqCst);
-                        } else {
-                            temp_mod_lfo_gain_1 = mod_value_1;
-                        }
+                }
+                _ => {}
+            }
+        }
+        if self.params.mod_source_6.value() == ModulationSource::Velocity {
+            match self.params.mod_destination_6.value() {
+                ModulationDestination::Cutoff_1 => {
+                    temp_mod_cutoff_1_source_6 +=
+                        8000.0 * self.current_note_on_velocity.load(Ordering::SeqCst);
+                }
+                ModulationDestination::Cutoff_2 => {
+                    temp_mod_cutoff_2_source_6 +=
+                        8000.0 * self.current_note_on_velocity.load(Ordering::SeqCst);
+                }
+                ModulationDestination::All_Gain => {
+                    if self.params.mod_source_6.value() == ModulationSource::Velocity {
+                        let vel = self.current_note_on_velocity.load(Ordering::SeqCst);
+                        temp_mod_gain_1 = vel;
+                        temp_mod_gain_2 = vel;
+                        temp_mod_gain_3 = vel;
                     }
-                    ModulationDestination::Osc2_Gain => {
-                        if self.params.mod_source_1.value() == ModulationSource::Velocity {
-                            temp_mod_gain_2 = self.current_note_on_velocity.load(Ordering::SeqCst);
-                        } else {
-                            temp_mod_lfo_gain_2 = mod_value_1;
-                        }
+                }
+                ModulationDestination::Osc1_Gain => {
+                    if self.params.mod_source_6.value() == ModulationSource::Velocity {
+                        temp_mod_gain_1 = self.current_note_on_velocity.load(Ordering::SeqCst);
                     }
-                    ModulationDestination::Osc3_Gain => {
-                        if self.params.mod_source_1.value() == ModulationSource::Velocity {
-                            temp_mod_gain_3 = self.current_note_on_velocity.load(Ordering::SeqCst);
-                        } else {
-                            temp_mod_lfo_gain_3 = mod_value_1;
-                        }
+                }
+                ModulationDestination::Osc2_Gain => {
+                    if self.params.mod_source_6.value() == ModulationSource::Velocity {
+                        temp_mod_gain_2 = self.current_note_on_velocity.load(Ordering::SeqCst);
                     }
                 }
-            }
-            if mod_value_2 != -2.0 {
-                match self.params.mod_destination_2.value() {
-                    ModulationDestination::None | ModulationDestination::UnsetModulation => {}
-                    ModulationDestination::Cutoff_1 => {
-                        if self.params.mod_source_2.value() == ModulationSource::Velocity {
-                            temp_mod_cutoff_1_source_2 +=
-                                20000.0 * self.current_note_on_velocity.load(Ordering::SeqCst);
-                        } else {
-                            temp_mod_cutoff_1_source_2 += 20000.0 * mod_value_2;
-                        }
+                ModulationDestination::Osc3_Gain => {
+                    if self.params.mod_source_6.value() == ModulationSource::Velocity {
+                        temp_mod_gain_3 = self.current_note_on_velocity.load(Ordering::SeqCst);
                     }
-                    ModulationDestination::Cutoff_2 => {
-                        if self.params.mod_source_2.value() == ModulationSource::Velocity {
-                            temp_mod_cutoff_2_source_2 +=
-                                20000.0 * self.current_note_on_velocity.load(Ordering::SeqCst);
-                        } else {
-                            temp_mod_cutoff_2_source_2 += 20000.0 * mod_value_2;
-                        }
+                }
+                ModulationDestination::Resonance_1 => {
+                    if self.params.mod_source_6.value() == ModulationSource::Velocity {
+                        temp_mod_resonance_1_source_6 +=
+                            self.current_note_on_velocity.load(Ordering::SeqCst);
                     }
-                    ModulationDestination::Resonance_1 => {
-                        if self.params.mod_source_2.value() == ModulationSource::Velocity {
-                            temp_mod_resonance_1_source_2 -=
-                                self.current_note_on_velocity.load(Ordering::SeqCst);
-                        } else {
-                            temp_mod_resonance_1_source_2 -= mod_value_2;
-                        }
+                }
+                ModulationDestination::Resonance_2 => {
+                    if self.params.mod_source_6.value() == ModulationSource::Velocity {
+                        temp_mod_resonance_2_source_6 +=
+                            self.current_note_on_velocity.load(Ordering::SeqCst);
                     }
-                    ModulationDestination::Resonance_2 => {
-                        if self.params.mod_source_2.value() == ModulationSource::Velocity {
-                            temp_mod_resonance_2_source_2 -=
-                                self.current_note_on_velocity.load(Ordering::SeqCst);
-                        } else {
-                            temp_mod_resonance_2_source_2 -= mod_value_2;
-                        }
+                }
+                _ => {}
+            }
+        }
+        if self.params.mod_source_7.value() == ModulationSource::Velocity {
+            match self.params.mod_destination_7.value() {
+                ModulationDestination::Cutoff_1 => {
+                    temp_mod_cutoff_1_source_7 +=
+                        8000.0 * self.current_note_on_velocity.load(Ordering::SeqCst);
+                }
+                ModulationDestination::Cutoff_2 => {
+                    temp_mod_cutoff_2_source_7 +=
+                        8000.0 * self.current_note_on_velocity.load(Ordering::SeqCst);
+                }
+                ModulationDestination::All_Gain => {
+                    if self.params.mod_source_7.value() == ModulationSource::Velocity {
+                        let vel = self.current_note_on_velocity.load(Ordering::SeqCst);
+                        temp_mod_gain_1 = vel;
+                        temp_mod_gain_2 = vel;
+                        temp_mod_gain_3 = vel;
                     }
-                    ModulationDestination::All_Detune => {
-                        if self.params.mod_source_2.value() == ModulationSource::Velocity {
-                            temp_mod_vel_sum += mod_value_2;
-                        }
-                        temp_mod_detune_1 += mod_value_2;
-                        temp_mod_detune_2 += mod_value_2;
-                        temp_mod_detune_3 += mod_value_2;
+                }
+                ModulationDestination::Osc1_Gain => {
+                    if self.params.mod_source_7.value() == ModulationSource::Velocity {
+                        temp_mod_gain_1 = self.current_note_on_velocity.load(Ordering::SeqCst);
                     }
-                    ModulationDestination::Osc1Detune => {
-                        if self.params.mod_source_2.value() == ModulationSource::Velocity {
-                            temp_mod_vel_sum += mod_value_2;
-                        }
-                        temp_mod_detune_1 += mod_value_2;
+                }
+                ModulationDestination::Osc2_Gain => {
+                    if self.params.mod_source_7.value() == ModulationSource::Velocity {
+                        temp_mod_gain_2 = self.current_note_on_velocity.load(Ordering::SeqCst);
                     }
-                    ModulationDestination::Osc2Detune => {
-                        if self.params.mod_source_2.value() == ModulationSource::Velocity {
-                            temp_mod_vel_sum += mod_value_2;
-                        }
-                        temp_mod_detune_2 += mod_value_2;
+                }
+                ModulationDestination::Osc3_Gain => {
+                    if self.params.mod_source_7.value() == ModulationSource::Velocity {
+                        temp_mod_gain_3 = self.current_note_on_velocity.load(Ordering::SeqCst);
                     }
-                    ModulationDestination::Osc3Detune => {
-                        if self.params.mod_source_2.value() == ModulationSource::Velocity {
-                            temp_mod_vel_sum += mod_value_2;
-                        }
-                        temp_mod_detune_3 += mod_value_2;
+                }
+                ModulationDestination::Resonance_1 => {
+                    if self.params.mod_source_7.value() == ModulationSource::Velocity {
+                        temp_mod_resonance_1_source_7 +=
+                            self.current_note_on_velocity.load(Ordering::SeqCst);
                     }
-                    ModulationDestination::All_UniDetune => {
-                        if self.params.mod_source_2.value() == ModulationSource::Velocity {
-                            temp_mod_vel_sum += mod_value_2;
-                        }
-                        temp_mod_uni_detune_1 += mod_value_2;
-                        temp_mod_uni_detune_2 += mod_value_2;
-                        temp_mod_uni_detune_3 += mod_value_2;
+                }
+                ModulationDestination::Resonance_2 => {
+                    if self.params.mod_source_7.value() == ModulationSource::Velocity {
+                        temp_mod_resonance_2_source_7 +=
+                            self.current_note_on_velocity.load(Ordering::SeqCst);
                     }
-                    ModulationDestination::Osc1UniDetune => {
-                        if self.params.mod_source_2.value() == ModulationSource::Velocity {
-                            temp_mod_uni_vel_sum += mod_value_2;
-                        }
-                        temp_mod_uni_detune_1 += mod_value_2;
+                }
+                _ => {}
+            }
+        }
+        if self.params.mod_source_8.value() == ModulationSource::Velocity {
+            match self.params.mod_destination_8.value() {
+                ModulationDestination::Cutoff_1 => {
+                    temp_mod_cutoff_1_source_8 +=
+                        8000.0 * self.current_note_on_velocity.load(Ordering::SeqCst);
+                }
+                ModulationDestination::Cutoff_2 => {
+                    temp_mod_cutoff_2_source_8 +=
+                        8000.0 * self.current_note_on_velocity.load(Ordering::SeqCst);
+                }
+                ModulationDestination::All_Gain => {
+                    if self.params.mod_source_8.value() == ModulationSource::Velocity {
+                        let vel = self.current_note_on_velocity.load(Ordering::SeqCst);
+                        temp_mod_gain_1 = vel;
+                        temp_mod_gain_2 = vel;
+                        temp_mod_gain_3 = vel;
                     }
-                    ModulationDestination::Osc2UniDetune => {
-                        if self.params.mod_source_2.value() == ModulationSource::Velocity {
-                            temp_mod_uni_vel_sum += mod_value_2;
-                        }
-                        temp_mod_uni_detune_2 += mod_value_2;
+                }
+                ModulationDestination::Osc1_Gain => {
+                    if self.params.mod_source_8.value() == ModulationSource::Velocity {
+                        temp_mod_gain_1 = self.current_note_on_velocity.load(Ordering::SeqCst);
                     }
-                    ModulationDestination::Osc3UniDetune => {
-                        if self.params.mod_source_2.value() == ModulationSource::Velocity {
-                            temp_mod_uni_vel_sum += mod_value_2;
-                        }
-                        temp_mod_uni_detune_3 += mod_value_2;
-                    }
-                    ModulationDestination::All_Gain => {
-                        if self.params.mod_source_2.value() == ModulationSource::Velocity {
-                            let vel = self.current_note_on_velocity.load(Ordering::SeqCst);
-                            temp_mod_gain_1 = vel;
-                            temp_mod_gain_2 = vel;
-                            temp_mod_gain_3 = vel;
-                        } else {
-                            temp_mod_lfo_gain_1 = mod_value_2;
-                            temp_mod_lfo_gain_2 = mod_value_2;
-                            temp_mod_lfo_gain_3 = mod_value_2;
-                        }
+                }
+                ModulationDestination::Osc2_Gain => {
+                    if self.params.mod_source_8.value() == ModulationSource::Velocity {
+                        temp_mod_gain_2 = self.current_note_on_velocity.load(Ordering::SeqCst);
                     }
-                    ModulationDestination::Osc1_Gain => {
-                        if self.params.mod_source_2.value() == ModulationSource::Velocity {
-                            temp_mod_gain_1 = self.current_note_on_velocity.load(Ordering::SeqCst);
-                        } else {
-                            temp_mod_lfo_gain_1 = mod_value_2;
-                        }
+                }
+                ModulationDestination::Osc3_Gain => {
+                    if self.params.mod_source_8.value() == ModulationSource::Velocity {
+                        temp_mod_gain_3 = self.current_note_on_velocity.load(Ordering::SeqCst);
                     }
-                    ModulationDestination::Osc2_Gain => {
-                        if self.params.mod_source_2.value() == ModulationSource::Velocity {
-                            temp_mod_gain_2 = self.current_note_on_velocity.load(Ordering::SeqCst);
-                        } else {
-                            temp_mod_lfo_gain_2 = mod_value_2;
-                        }
+                }
+                ModulationDestination::Resonance_1 => {
+                    if self.params.mod_source_8.value() == ModulationSource::Velocity {
+                        temp_mod_resonance_1_source_8 +=
+                            self.current_note_on_velocity.load(Ordering::SeqCst);
                     }
-                    ModulationDestination::Osc3_Gain => {
-                        if self.params.mod_source_2.value() == ModulationSource::Velocity {
-                            temp_mod_gain_3 = self.current_note_on_velocity.load(Ordering::SeqCst);
-                        } else {
-                            temp_mod_lfo_gain_3 = mod_value_2;
-                        }
+                }
+                ModulationDestination::Resonance_2 => {
+                    if self.params.mod_source_8.value() == ModulationSource::Velocity {
+                        temp_mod_resonance_2_source_8 +=
+                            self.current_note_on_velocity.load(Ordering::SeqCst);
                     }
                 }
+                _ => {}
             }
-            if mod_value_3 != -2.0 {
-                match self.params.mod_destination_3.value() {
-                    ModulationDestination::None | ModulationDestination::UnsetModulation => {}
-                    ModulationDestination::Cutoff_1 => {
-                        if self.params.mod_source_3.value() == ModulationSource::Velocity {
-                            temp_mod_cutoff_1_source_3 +=
-                                20000.0 * self.current_note_on_velocity.load(Ordering::SeqCst);
-                        } else {
-                            temp_mod_cutoff_1_source_3 += 20000.0 * mod_value_3;
-                        }
+        }
+
+        ///////////////////////////////////////////////////////////////
+        // If mod_value is not -2.0 we are in Note ON event or an LFO
+        if mod_value_1 != -2.0 {
+            match self.params.mod_destination_1.value() {
+                ModulationDestination::None | ModulationDestination::UnsetModulation => {}
+                ModulationDestination::Cutoff_1 => {
+                    if self.params.mod_source_1.value() == ModulationSource::Velocity {
+                        // I don't think this gets reached in Velocity case because of mod_value_X
+                        temp_mod_cutoff_1_source_1 +=
+                            20000.0 * self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_cutoff_1_source_1 += 20000.0 * mod_value_1;
                     }
-                    ModulationDestination::Cutoff_2 => {
-                        if self.params.mod_source_3.value() == ModulationSource::Velocity {
-                            temp_mod_cutoff_2_source_3 +=
-                                20000.0 * self.current_note_on_velocity.load(Ordering::SeqCst);
-                        } else {
-                            temp_mod_cutoff_2_source_3 += 20000.0 * mod_value_3;
-                        }
+                }
+                ModulationDestination::Cutoff_2 => {
+                    if self.params.mod_source_1.value() == ModulationSource::Velocity {
+                        temp_mod_cutoff_2_source_1 +=
+                            20000.0 * self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_cutoff_2_source_1 += 20000.0 * mod_value_1;
                     }
-                    ModulationDestination::Resonance_1 => {
-                        if self.params.mod_source_3.value() == ModulationSource::Velocity {
-                            temp_mod_resonance_1_source_3 -=
-                                self.current_note_on_velocity.load(Ordering::SeqCst);
-                        } else {
-                            temp_mod_resonance_1_source_3 -= mod_value_3;
-                        }
+                }
+                ModulationDestination::Resonance_1 => {
+                    if self.params.mod_source_1.value() == ModulationSource::Velocity {
+                        temp_mod_resonance_1_source_1 -=
+                            self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_resonance_1_source_1 -= mod_value_1;
                     }
-                    ModulationDestination::Resonance_2 => {
-                        if self.params.mod_source_3.value() == ModulationSource::Velocity {
-                            temp_mod_resonance_2_source_3 -=
-                                self.current_note_on_velocity.load(Ordering::SeqCst);
-                        } else {
-                            temp_mod_resonance_2_source_3 -= mod_value_3;
-                        }
+                }
+                ModulationDestination::Resonance_2 => {
+                    if self.params.mod_source_1.value() == ModulationSource::Velocity {
+                        temp_mod_resonance_2_source_1 -=
+                            self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_resonance_2_source_1 -= mod_value_1;
                     }
-                    ModulationDestination::All_Detune => {
-                        if self.params.mod_source_3.value() == ModulationSource::Velocity {
-                            temp_mod_vel_sum += mod_value_3;
-                        }
-                        temp_mod_detune_1 += mod_value_3;
-                        temp_mod_detune_2 += mod_value_3;
-                        temp_mod_detune_3 += mod_value_3;
+                }
+                ModulationDestination::All_Detune => {
+                    if self.params.mod_source_1.value() == ModulationSource::Velocity {
+                        temp_mod_vel_sum += mod_value_1;
                     }
-                    ModulationDestination::Osc1Detune => {
-                        if self.params.mod_source_3.value() == ModulationSource::Velocity {
-                            temp_mod_vel_sum += mod_value_3;
-                        }
-                        temp_mod_detune_1 += mod_value_3;
+                    temp_mod_detune_1 += mod_value_1;
+                    temp_mod_detune_2 += mod_value_1;
+                    temp_mod_detune_3 += mod_value_1;
+                }
+                ModulationDestination::Osc1Detune => {
+                    if self.params.mod_source_1.value() == ModulationSource::Velocity {
+                        temp_mod_vel_sum += mod_value_1;
                     }
-                    ModulationDestination::Osc2Detune => {
-                        if self.params.mod_source_3.value() == ModulationSource::Velocity {
-                            temp_mod_vel_sum += mod_value_3;
-                        }
-                        temp_mod_detune_2 += mod_value_3;
+                    temp_mod_detune_1 += mod_value_1;
+                }
+                ModulationDestination::Osc2Detune => {
+                    if self.params.mod_source_1.value() == ModulationSource::Velocity {
+                        temp_mod_vel_sum += mod_value_1;
                     }
-                    ModulationDestination::Osc3Detune => {
-                        if self.params.mod_source_3.value() == ModulationSource::Velocity {
-                            temp_mod_vel_sum += mod_value_3;
-                        }
-                        temp_mod_detune_3 += mod_value_3;
+                    temp_mod_detune_2 += mod_value_1;
+                }
+                ModulationDestination::Osc3Detune => {
+                    if self.params.mod_source_1.value() == ModulationSource::Velocity {
+                        temp_mod_vel_sum += mod_value_1;
                     }
-                    ModulationDestination::All_UniDetune => {
-                        if self.params.mod_source_3.value() == ModulationSource::Velocity {
-                            temp_mod_vel_sum += mod_value_3;
-                        }
-                        temp_mod_uni_detune_1 += mod_value_3;
-                        temp_mod_uni_detune_2 += mod_value_3;
-                        temp_mod_uni_detune_3 += mod_value_3;
+                    temp_mod_detune_3 += mod_value_1;
+                }
+                ModulationDestination::All_UniDetune => {
+                    if self.params.mod_source_1.value() == ModulationSource::Velocity {
+                        temp_mod_vel_sum += mod_value_1;
                     }
-                    ModulationDestination::Osc1UniDetune => {
-                        if self.params.mod_source_3.value() == ModulationSource::Velocity {
-                            temp_mod_uni_vel_sum += mod_value_3;
-                        }
-                        temp_mod_uni_detune_1 += mod_value_3;
+                    temp_mod_uni_detune_1 += mod_value_1;
+                    temp_mod_uni_detune_2 += mod_value_1;
+                    temp_mod_uni_detune_3 += mod_value_1;
+                }
+                ModulationDestination::Osc1UniDetune => {
+                    if self.params.mod_source_1.value() == ModulationSource::Velocity {
+                        temp_mod_uni_vel_sum += mod_value_1;
                     }
-                    ModulationDestination::Osc2UniDetune => {
-                        if self.params.mod_source_3.value() == ModulationSource::Velocity {
-                            temp_mod_uni_vel_sum += mod_value_3;
-                        }
-                        temp_mod_uni_detune_2 += mod_value_3;
+                    temp_mod_uni_detune_1 += mod_value_1;
+                }
+                ModulationDestination::Osc2UniDetune => {
+                    if self.params.mod_source_1.value() == ModulationSource::Velocity {
+                        temp_mod_uni_vel_sum += mod_value_1;
                     }
-                    ModulationDestination::Osc3UniDetune => {
-                        if self.params.mod_source_3.value() == ModulationSource::Velocity {
-                            temp_mod_uni_vel_sum += mod_value_3;
-                        }
-                        temp_mod_uni_detune_3 += mod_value_3;
-                    }
-                    ModulationDestination::All_Gain => {
-                        if self.params.mod_source_3.value() == ModulationSource::Velocity {
-                            let vel = self.current_note_on_velocity.load(Ordering::SeqCst);
-                            temp_mod_gain_1 = vel;
-                            temp_mod_gain_2 = vel;
-                            temp_mod_gain_3 = vel;
-                        } else {
-                            temp_mod_lfo_gain_1 = mod_value_3;
-                            temp_mod_lfo_gain_2 = mod_value_3;
-                            temp_mod_lfo_gain_3 = mod_value_3;
-                        }
+                    temp_mod_uni_detune_2 += mod_value_1;
+                }
+                ModulationDestination::Osc3UniDetune => {
+                    if self.params.mod_source_1.value() == ModulationSource::Velocity {
+                        temp_mod_uni_vel_sum += mod_value_1;
                     }
-                    ModulationDestination::Osc1_Gain => {
-                        if self.params.mod_source_3.value() == ModulationSource::Velocity {
-                            temp_mod_gain_1 = self.current_note_on_velocity.load(Ordering::SeqCst);
-                        } else {
-                            temp_mod_lfo_gain_1 = mod_value_3;
-                        }
+                    temp_mod_uni_detune_3 += mod_value_1;
+                }
+                ModulationDestination::Drive_1 => {
+                    if self.params.mod_source_1.value() == ModulationSource::Velocity {
+                        temp_mod_drive_1 += self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_drive_1 += mod_value_1;
                     }
-                    ModulationDestination::Osc2_Gain => {
-                        if self.params.mod_source_3.value() == ModulationSource::Velocity {
-                            temp_mod_gain_2 = self.current_note_on_velocity.load(Ordering::SeqCst);
-                        } else {
-                            temp_mod_lfo_gain_2 = mod_value_3;
-                        }
+                }
+                ModulationDestination::Drive_2 => {
+                    if self.params.mod_source_1.value() == ModulationSource::Velocity {
+                        temp_mod_drive_2 += self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_drive_2 += mod_value_1;
                     }
-                    ModulationDestination::Osc3_Gain => {
-                        if self.params.mod_source_3.value() == ModulationSource::Velocity {
-                            temp_mod_gain_3 = self.current_note_on_velocity.load(Ordering::SeqCst);
-                        } else {
-                            temp_mod_lfo_gain_3 = mod_value_3;
-                        }
+                }
+                ModulationDestination::Osc1_Morph => {
+                    if self.params.mod_source_1.value() == ModulationSource::Velocity {
+                        temp_mod_morph_1 += self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_morph_1 += mod_value_1;
                     }
                 }
-            }
-            if mod_value_4 != -2.0 {
-                match self.params.mod_destination_4.value() {
-                    ModulationDestination::None | ModulationDestination::UnsetModulation => {}
-                    ModulationDestination::Cutoff_1 => {
-                        if self.params.mod_source_4.value() == ModulationSource::Velocity {
-                            temp_mod_cutoff_1_source_4 +=
-                                20000.0 * self.current_note_on_velocity.load(Ordering::SeqCst);
-                        } else {
-                            temp_mod_cutoff_1_source_4 += 20000.0 * mod_value_4;
-                        }
+                ModulationDestination::Osc2_Morph => {
+                    if self.params.mod_source_1.value() == ModulationSource::Velocity {
+                        temp_mod_morph_2 += self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_morph_2 += mod_value_1;
                     }
-                    ModulationDestination::Cutoff_2 => {
-                        if self.params.mod_source_4.value() == ModulationSource::Velocity {
-                            temp_mod_cutoff_2_source_4 +=
-                                20000.0 * self.current_note_on_velocity.load(Ordering::SeqCst);
-                        } else {
-                            temp_mod_cutoff_2_source_4 += 20000.0 * mod_value_4;
-                        }
+                }
+                ModulationDestination::Osc3_Morph => {
+                    if self.params.mod_source_1.value() == ModulationSource::Velocity {
+                        temp_mod_morph_3 += self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_morph_3 += mod_value_1;
                     }
-                    ModulationDestination::Resonance_1 => {
-                        if self.params.mod_source_4.value() == ModulationSource::Velocity {
-                            temp_mod_resonance_1_source_4 -=
-                                self.current_note_on_velocity.load(Ordering::SeqCst);
-                        } else {
-                            temp_mod_resonance_1_source_4 -= mod_value_4;
-                        }
+                }
+                ModulationDestination::Osc1_Shape => {
+                    if self.params.mod_source_1.value() == ModulationSource::Velocity {
+                        temp_mod_shape_1 += self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_shape_1 += mod_value_1;
                     }
-                    ModulationDestination::Resonance_2 => {
-                        if self.params.mod_source_4.value() == ModulationSource::Velocity {
-                            temp_mod_resonance_2_source_4 -=
-                                self.current_note_on_velocity.load(Ordering::SeqCst);
-                        } else {
-                            temp_mod_resonance_2_source_4 -= mod_value_4;
-                        }
+                }
+                ModulationDestination::Osc2_Shape => {
+                    if self.params.mod_source_1.value() == ModulationSource::Velocity {
+                        temp_mod_shape_2 += self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_shape_2 += mod_value_1;
                     }
-                    ModulationDestination::All_Detune => {
-                        if self.params.mod_source_4.value() == ModulationSource::Velocity {
-                            temp_mod_vel_sum += mod_value_4;
-                        }
-                        temp_mod_detune_1 += mod_value_4;
-                        temp_mod_detune_2 += mod_value_4;
-                        temp_mod_detune_3 += mod_value_4;
+                }
+                ModulationDestination::Osc3_Shape => {
+                    if self.params.mod_source_1.value() == ModulationSource::Velocity {
+                        temp_mod_shape_3 += self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_shape_3 += mod_value_1;
                     }
-                    ModulationDestination::Osc1Detune => {
-                        if self.params.mod_source_4.value() == ModulationSource::Velocity {
-                            temp_mod_vel_sum += mod_value_4;
-                        }
-                        temp_mod_detune_1 += mod_value_4;
+                }
+                ModulationDestination::All_Gain => {
+                    if self.params.mod_source_1.value() == ModulationSource::Velocity {
+                        let vel = self.current_note_on_velocity.load(Ordering::SeqCst);
+                        temp_mod_gain_1 = vel;
+                        temp_mod_gain_2 = vel;
+                        temp_mod_gain_3 = vel;
+                    } else {
+                        temp_mod_lfo_gain_1 = mod_value_1;
+                        temp_mod_lfo_gain_2 = mod_value_1;
+                        temp_mod_lfo_gain_3 = mod_value_1;
                     }
-                    ModulationDestination::Osc2Detune => {
-                        if self.params.mod_source_4.value() == ModulationSource::Velocity {
-                            temp_mod_vel_sum += mod_value_4;
-                        }
-                        temp_mod_detune_2 += mod_value_4;
+                }
+                ModulationDestination::Osc1_Gain => {
+                    if self.params.mod_source_1.value() == ModulationSource::Velocity {
+                        temp_mod_gain_1 = self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_lfo_gain_1 = mod_value_1;
                     }
-                    ModulationDestination::Osc3Detune => {
-                        if self.params.mod_source_4.value() == ModulationSource::Velocity {
-                            temp_mod_vel_sum += mod_value_4;
-                        }
-                        temp_mod_detune_3 += mod_value_4;
+                }
+                ModulationDestination::Osc2_Gain => {
+                    if self.params.mod_source_1.value() == ModulationSource::Velocity {
+                        temp_mod_gain_2 = self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_lfo_gain_2 = mod_value_1;
                     }
-                    ModulationDestination::All_UniDetune => {
-                        if self.params.mod_source_4.value() == ModulationSource::Velocity {
-                            temp_mod_vel_sum += mod_value_4;
-                        }
-                        temp_mod_uni_detune_1 += mod_value_4;
-                        temp_mod_uni_detune_2 += mod_value_4;
-                        temp_mod_uni_detune_3 += mod_value_4;
+                }
+                ModulationDestination::Osc3_Gain => {
+                    if self.params.mod_source_1.value() == ModulationSource::Velocity {
+                        temp_mod_gain_3 = self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_lfo_gain_3 = mod_value_1;
                     }
-                    ModulationDestination::Osc1UniDetune => {
-                        if self.params.mod_source_4.value() == ModulationSource::Velocity {
-                            temp_mod_uni_vel_sum += mod_value_4;
-                        }
-                        temp_mod_uni_detune_1 += mod_value_4;
+                }
+            }
+        }
+        if mod_value_2 != -2.0 {
+            match self.params.mod_destination_2.value() {
+                ModulationDestination::None | ModulationDestination::UnsetModulation => {}
+                ModulationDestination::Cutoff_1 => {
+                    if self.params.mod_source_2.value() == ModulationSource::Velocity {
+                        temp_mod_cutoff_1_source_2 +=
+                            20000.0 * self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_cutoff_1_source_2 += 20000.0 * mod_value_2;
                     }
-                    ModulationDestination::Osc2UniDetune => {
-                        if self.params.mod_source_4.value() == ModulationSource::Velocity {
-                            temp_mod_uni_vel_sum += mod_value_4;
-                        }
-                        temp_mod_uni_detune_2 += mod_value_4;
+                }
+                ModulationDestination::Cutoff_2 => {
+                    if self.params.mod_source_2.value() == ModulationSource::Velocity {
+                        temp_mod_cutoff_2_source_2 +=
+                            20000.0 * self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_cutoff_2_source_2 += 20000.0 * mod_value_2;
                     }
-                    ModulationDestination::Osc3UniDetune => {
-                        if self.params.mod_source_4.value() == ModulationSource::Velocity {
-                            temp_mod_uni_vel_sum += mod_value_4;
-                        }
-                        temp_mod_uni_detune_3 += mod_value_4;
-                    }
-                    ModulationDestination::All_Gain => {
-                        if self.params.mod_source_4.value() == ModulationSource::Velocity {
-                            let vel = self.current_note_on_velocity.load(Ordering::SeqCst);
-                            temp_mod_gain_1 = vel;
-                            temp_mod_gain_2 = vel;
-                            temp_mod_gain_3 = vel;
-                        } else {
-                            temp_mod_lfo_gain_1 = mod_value_4;
-                            temp_mod_lfo_gain_2 = mod_value_4;
-                            temp_mod_lfo_gain_3 = mod_value_4;
-                        }
+                }
+                ModulationDestination::Resonance_1 => {
+                    if self.params.mod_source_2.value() == ModulationSource::Velocity {
+                        temp_mod_resonance_1_source_2 -=
+                            self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_resonance_1_source_2 -= mod_value_2;
                     }
-                    ModulationDestination::Osc1_Gain => {
-                        if self.params.mod_source_4.value() == ModulationSource::Velocity {
-                            temp_mod_gain_1 = self.current_note_on_velocity.load(Ordering::SeqCst);
-                        } else {
-                            temp_mod_lfo_gain_1 = mod_value_4;
-                        }
+                }
+                ModulationDestination::Resonance_2 => {
+                    if self.params.mod_source_2.value() == ModulationSource::Velocity {
+                        temp_mod_resonance_2_source_2 -=
+                            self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_resonance_2_source_2 -= mod_value_2;
                     }
-                    ModulationDestination::Osc2_Gain => {
-                        if self.params.mod_source_4.value() == ModulationSource::Velocity {
-                            temp_mod_gain_2 = self.current_note_on_velocity.load(Ordering::SeqCst);
-                        } else {
-                            temp_mod_lfo_gain_2 = mod_value_4;
-                        }
+                }
+                ModulationDestination::All_Detune => {
+                    if self.params.mod_source_2.value() == ModulationSource::Velocity {
+                        temp_mod_vel_sum += mod_value_2;
                     }
-                    ModulationDestination::Osc3_Gain => {
-                        if self.params.mod_source_4.value() == ModulationSource::Velocity {
-                            temp_mod_gain_3 = self.current_note_on_velocity.load(Ordering::SeqCst);
-                        } else {
-                            temp_mod_lfo_gain_3 = mod_value_4;
-                        }
+                    temp_mod_detune_1 += mod_value_2;
+                    temp_mod_detune_2 += mod_value_2;
+                    temp_mod_detune_3 += mod_value_2;
+                }
+                ModulationDestination::Osc1Detune => {
+                    if self.params.mod_source_2.value() == ModulationSource::Velocity {
+                        temp_mod_vel_sum += mod_value_2;
+                    }
+                    temp_mod_detune_1 += mod_value_2;
+                }
+                ModulationDestination::Osc2Detune => {
+                    if self.params.mod_source_2.value() == ModulationSource::Velocity {
+                        temp_mod_vel_sum += mod_value_2;
+                    }
+                    temp_mod_detune_2 += mod_value_2;
+                }
+                ModulationDestination::Osc3Detune => {
+                    if self.params.mod_source_2.value() == ModulationSource::Velocity {
+                        temp_mod_vel_sum += mod_value_2;
+                    }
+                    temp_mod_detune_3 += mod_value_2;
+                }
+                ModulationDestination::All_UniDetune => {
+                    if self.params.mod_source_2.value() == ModulationSource::Velocity {
+                        temp_mod_vel_sum += mod_value_2;
+                    }
+                    temp_mod_uni_detune_1 += mod_value_2;
+                    temp_mod_uni_detune_2 += mod_value_2;
+                    temp_mod_uni_detune_3 += mod_value_2;
+                }
+                ModulationDestination::Osc1UniDetune => {
+                    if self.params.mod_source_2.value() == ModulationSource::Velocity {
+                        temp_mod_uni_vel_sum += mod_value_2;
+                    }
+                    temp_mod_uni_detune_1 += mod_value_2;
+                }
+                ModulationDestination::Osc2UniDetune => {
+                    if self.params.mod_source_2.value() == ModulationSource::Velocity {
+                        temp_mod_uni_vel_sum += mod_value_2;
+                    }
+                    temp_mod_uni_detune_2 += mod_value_2;
+                }
+                ModulationDestination::Osc3UniDetune => {
+                    if self.params.mod_source_2.value() == ModulationSource::Velocity {
+                        temp_mod_uni_vel_sum += mod_value_2;
+                    }
+                    temp_mod_uni_detune_3 += mod_value_2;
+                }
+                ModulationDestination::Drive_1 => {
+                    if self.params.mod_source_2.value() == ModulationSource::Velocity {
+                        temp_mod_drive_1 += self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_drive_1 += mod_value_2;
+                    }
+                }
+                ModulationDestination::Drive_2 => {
+                    if self.params.mod_source_2.value() == ModulationSource::Velocity {
+                        temp_mod_drive_2 += self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_drive_2 += mod_value_2;
+                    }
+                }
+                ModulationDestination::Osc1_Morph => {
+                    if self.params.mod_source_2.value() == ModulationSource::Velocity {
+                        temp_mod_morph_1 += self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_morph_1 += mod_value_2;
+                    }
+                }
+                ModulationDestination::Osc2_Morph => {
+                    if self.params.mod_source_2.value() == ModulationSource::Velocity {
+                        temp_mod_morph_2 += self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_morph_2 += mod_value_2;
+                    }
+                }
+                ModulationDestination::Osc3_Morph => {
+                    if self.params.mod_source_2.value() == ModulationSource::Velocity {
+                        temp_mod_morph_3 += self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_morph_3 += mod_value_2;
+                    }
+                }
+                ModulationDestination::Osc1_Shape => {
+                    if self.params.mod_source_2.value() == ModulationSource::Velocity {
+                        temp_mod_shape_1 += self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_shape_1 += mod_value_2;
+                    }
+                }
+                ModulationDestination::Osc2_Shape => {
+                    if self.params.mod_source_2.value() == ModulationSource::Velocity {
+                        temp_mod_shape_2 += self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_shape_2 += mod_value_2;
+                    }
+                }
+                ModulationDestination::Osc3_Shape => {
+                    if self.params.mod_source_2.value() == ModulationSource::Velocity {
+                        temp_mod_shape_3 += self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_shape_3 += mod_value_2;
+                    }
+                }
+                ModulationDestination::All_Gain => {
+                    if self.params.mod_source_2.value() == ModulationSource::Velocity {
+                        let vel = self.current_note_on_velocity.load(Ordering::SeqCst);
+                        temp_mod_gain_1 = vel;
+                        temp_mod_gain_2 = vel;
+                        temp_mod_gain_3 = vel;
+                    } else {
+                        temp_mod_lfo_gain_1 = mod_value_2;
+                        temp_mod_lfo_gain_2 = mod_value_2;
+                        temp_mod_lfo_gain_3 = mod_value_2;
+                    }
+                }
+                ModulationDestination::Osc1_Gain => {
+                    if self.params.mod_source_2.value() == ModulationSource::Velocity {
+                        temp_mod_gain_1 = self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_lfo_gain_1 = mod_value_2;
+                    }
+                }
+                ModulationDestination::Osc2_Gain => {
+                    if self.params.mod_source_2.value() == ModulationSource::Velocity {
+                        temp_mod_gain_2 = self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_lfo_gain_2 = mod_value_2;
+                    }
+                }
+                ModulationDestination::Osc3_Gain => {
+                    if self.params.mod_source_2.value() == ModulationSource::Velocity {
+                        temp_mod_gain_3 = self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_lfo_gain_3 = mod_value_2;
+                    }
+                }
+            }
+        }
+        if mod_value_3 != -2.0 {
+            match self.params.mod_destination_3.value() {
+                ModulationDestination::None | ModulationDestination::UnsetModulation => {}
+                ModulationDestination::Cutoff_1 => {
+                    if self.params.mod_source_3.value() == ModulationSource::Velocity {
+                        temp_mod_cutoff_1_source_3 +=
+                            20000.0 * self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_cutoff_1_source_3 += 20000.0 * mod_value_3;
+                    }
+                }
+                ModulationDestination::Cutoff_2 => {
+                    if self.params.mod_source_3.value() == ModulationSource::Velocity {
+                        temp_mod_cutoff_2_source_3 +=
+                            20000.0 * self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_cutoff_2_source_3 += 20000.0 * mod_value_3;
+                    }
+                }
+                ModulationDestination::Resonance_1 => {
+                    if self.params.mod_source_3.value() == ModulationSource::Velocity {
+                        temp_mod_resonance_1_source_3 -=
+                            self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_resonance_1_source_3 -= mod_value_3;
+                    }
+                }
+                ModulationDestination::Resonance_2 => {
+                    if self.params.mod_source_3.value() == ModulationSource::Velocity {
+                        temp_mod_resonance_2_source_3 -=
+                            self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_resonance_2_source_3 -= mod_value_3;
+                    }
+                }
+                ModulationDestination::All_Detune => {
+                    if self.params.mod_source_3.value() == ModulationSource::Velocity {
+                        temp_mod_vel_sum += mod_value_3;
+                    }
+                    temp_mod_detune_1 += mod_value_3;
+                    temp_mod_detune_2 += mod_value_3;
+                    temp_mod_detune_3 += mod_value_3;
+                }
+                ModulationDestination::Osc1Detune => {
+                    if self.params.mod_source_3.value() == ModulationSource::Velocity {
+                        temp_mod_vel_sum += mod_value_3;
+                    }
+                    temp_mod_detune_1 += mod_value_3;
+                }
+                ModulationDestination::Osc2Detune => {
+                    if self.params.mod_source_3.value() == ModulationSource::Velocity {
+                        temp_mod_vel_sum += mod_value_3;
+                    }
+                    temp_mod_detune_2 += mod_value_3;
+                }
+                ModulationDestination::Osc3Detune => {
+                    if self.params.mod_source_3.value() == ModulationSource::Velocity {
+                        temp_mod_vel_sum += mod_value_3;
+                    }
+                    temp_mod_detune_3 += mod_value_3;
+                }
+                ModulationDestination::All_UniDetune => {
+                    if self.params.mod_source_3.value() == ModulationSource::Velocity {
+                        temp_mod_vel_sum += mod_value_3;
+                    }
+                    temp_mod_uni_detune_1 += mod_value_3;
+                    temp_mod_uni_detune_2 += mod_value_3;
+                    temp_mod_uni_detune_3 += mod_value_3;
+                }
+                ModulationDestination::Osc1UniDetune => {
+                    if self.params.mod_source_3.value() == ModulationSource::Velocity {
+                        temp_mod_uni_vel_sum += mod_value_3;
+                    }
+                    temp_mod_uni_detune_1 += mod_value_3;
+                }
+                ModulationDestination::Osc2UniDetune => {
+                    if self.params.mod_source_3.value() == ModulationSource::Velocity {
+                        temp_mod_uni_vel_sum += mod_value_3;
+                    }
+                    temp_mod_uni_detune_2 += mod_value_3;
+                }
+                ModulationDestination::Osc3UniDetune => {
+                    if self.params.mod_source_3.value() == ModulationSource::Velocity {
+                        temp_mod_uni_vel_sum += mod_value_3;
+                    }
+                    temp_mod_uni_detune_3 += mod_value_3;
+                }
+                ModulationDestination::Drive_1 => {
+                    if self.params.mod_source_3.value() == ModulationSource::Velocity {
+                        temp_mod_drive_1 += self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_drive_1 += mod_value_3;
+                    }
+                }
+                ModulationDestination::Drive_2 => {
+                    if self.params.mod_source_3.value() == ModulationSource::Velocity {
+                        temp_mod_drive_2 += self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_drive_2 += mod_value_3;
+                    }
+                }
+                ModulationDestination::Osc1_Morph => {
+                    if self.params.mod_source_3.value() == ModulationSource::Velocity {
+                        temp_mod_morph_1 += self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_morph_1 += mod_value_3;
+                    }
+                }
+                ModulationDestination::Osc2_Morph => {
+                    if self.params.mod_source_3.value() == ModulationSource::Velocity {
+                        temp_mod_morph_2 += self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_morph_2 += mod_value_3;
+                    }
+                }
+                ModulationDestination::Osc3_Morph => {
+                    if self.params.mod_source_3.value() == ModulationSource::Velocity {
+                        temp_mod_morph_3 += self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_morph_3 += mod_value_3;
+                    }
+                }
+                ModulationDestination::Osc1_Shape => {
+                    if self.params.mod_source_3.value() == ModulationSource::Velocity {
+                        temp_mod_shape_1 += self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_shape_1 += mod_value_3;
+                    }
+                }
+                ModulationDestination::Osc2_Shape => {
+                    if self.params.mod_source_3.value() == ModulationSource::Velocity {
+                        temp_mod_shape_2 += self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_shape_2 += mod_value_3;
+                    }
+                }
+                ModulationDestination::Osc3_Shape => {
+                    if self.params.mod_source_3.value() == ModulationSource::Velocity {
+                        temp_mod_shape_3 += self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_shape_3 += mod_value_3;
+                    }
+                }
+                ModulationDestination::All_Gain => {
+                    if self.params.mod_source_3.value() == ModulationSource::Velocity {
+                        let vel = self.current_note_on_velocity.load(Ordering::SeqCst);
+                        temp_mod_gain_1 = vel;
+                        temp_mod_gain_2 = vel;
+                        temp_mod_gain_3 = vel;
+                    } else {
+                        temp_mod_lfo_gain_1 = mod_value_3;
+                        temp_mod_lfo_gain_2 = mod_value_3;
+                        temp_mod_lfo_gain_3 = mod_value_3;
+                    }
+                }
+                ModulationDestination::Osc1_Gain => {
+                    if self.params.mod_source_3.value() == ModulationSource::Velocity {
+                        temp_mod_gain_1 = self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_lfo_gain_1 = mod_value_3;
+                    }
+                }
+                ModulationDestination::Osc2_Gain => {
+                    if self.params.mod_source_3.value() == ModulationSource::Velocity {
+                        temp_mod_gain_2 = self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_lfo_gain_2 = mod_value_3;
+                    }
+                }
+                ModulationDestination::Osc3_Gain => {
+                    if self.params.mod_source_3.value() == ModulationSource::Velocity {
+                        temp_mod_gain_3 = self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_lfo_gain_3 = mod_value_3;
+                    }
+                }
+            }
+        }
+        if mod_value_4 != -2.0 {
+            match self.params.mod_destination_4.value() {
+                ModulationDestination::None | ModulationDestination::UnsetModulation => {}
+                ModulationDestination::Cutoff_1 => {
+                    if self.params.mod_source_4.value() == ModulationSource::Velocity {
+                        temp_mod_cutoff_1_source_4 +=
+                            20000.0 * self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_cutoff_1_source_4 += 20000.0 * mod_value_4;
+                    }
+                }
+                ModulationDestination::Cutoff_2 => {
+                    if self.params.mod_source_4.value() == ModulationSource::Velocity {
+                        temp_mod_cutoff_2_source_4 +=
+                            20000.0 * self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_cutoff_2_source_4 += 20000.0 * mod_value_4;
+                    }
+                }
+                ModulationDestination::Resonance_1 => {
+                    if self.params.mod_source_4.value() == ModulationSource::Velocity {
+                        temp_mod_resonance_1_source_4 -=
+                            self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_resonance_1_source_4 -= mod_value_4;
+                    }
+                }
+                ModulationDestination::Resonance_2 => {
+                    if self.params.mod_source_4.value() == ModulationSource::Velocity {
+                        temp_mod_resonance_2_source_4 -=
+                            self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_resonance_2_source_4 -= mod_value_4;
+                    }
+                }
+                ModulationDestination::All_Detune => {
+                    if self.params.mod_source_4.value() == ModulationSource::Velocity {
+                        temp_mod_vel_sum += mod_value_4;
+                    }
+                    temp_mod_detune_1 += mod_value_4;
+                    temp_mod_detune_2 += mod_value_4;
+                    temp_mod_detune_3 += mod_value_4;
+                }
+                ModulationDestination::Osc1Detune => {
+                    if self.params.mod_source_4.value() == ModulationSource::Velocity {
+                        temp_mod_vel_sum += mod_value_4;
+                    }
+                    temp_mod_detune_1 += mod_value_4;
+                }
+                ModulationDestination::Osc2Detune => {
+                    if self.params.mod_source_4.value() == ModulationSource::Velocity {
+                        temp_mod_vel_sum += mod_value_4;
+                    }
+                    temp_mod_detune_2 += mod_value_4;
+                }
+                ModulationDestination::Osc3Detune => {
+                    if self.params.mod_source_4.value() == ModulationSource::Velocity {
+                        temp_mod_vel_sum += mod_value_4;
+                    }
+                    temp_mod_detune_3 += mod_value_4;
+                }
+                ModulationDestination::All_UniDetune => {
+                    if self.params.mod_source_4.value() == ModulationSource::Velocity {
+                        temp_mod_vel_sum += mod_value_4;
+                    }
+                    temp_mod_uni_detune_1 += mod_value_4;
+                    temp_mod_uni_detune_2 += mod_value_4;
+                    temp_mod_uni_detune_3 += mod_value_4;
+                }
+                ModulationDestination::Osc1UniDetune => {
+                    if self.params.mod_source_4.value() == ModulationSource::Velocity {
+                        temp_mod_uni_vel_sum += mod_value_4;
+                    }
+                    temp_mod_uni_detune_1 += mod_value_4;
+                }
+                ModulationDestination::Osc2UniDetune => {
+                    if self.params.mod_source_4.value() == ModulationSource::Velocity {
+                        temp_mod_uni_vel_sum += mod_value_4;
+                    }
+                    temp_mod_uni_detune_2 += mod_value_4;
+                }
+                ModulationDestination::Osc3UniDetune => {
+                    if self.params.mod_source_4.value() == ModulationSource::Velocity {
+                        temp_mod_uni_vel_sum += mod_value_4;
+                    }
+                    temp_mod_uni_detune_3 += mod_value_4;
+                }
+                ModulationDestination::Drive_1 => {
+                    if self.params.mod_source_4.value() == ModulationSource::Velocity {
+                        temp_mod_drive_1 += self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_drive_1 += mod_value_4;
+                    }
+                }
+                ModulationDestination::Drive_2 => {
+                    if self.params.mod_source_4.value() == ModulationSource::Velocity {
+                        temp_mod_drive_2 += self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_drive_2 += mod_value_4;
+                    }
+                }
+                ModulationDestination::Osc1_Morph => {
+                    if self.params.mod_source_4.value() == ModulationSource::Velocity {
+                        temp_mod_morph_1 += self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_morph_1 += mod_value_4;
+                    }
+                }
+                ModulationDestination::Osc2_Morph => {
+                    if self.params.mod_source_4.value() == ModulationSource::Velocity {
+                        temp_mod_morph_2 += self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_morph_2 += mod_value_4;
+                    }
+                }
+                ModulationDestination::Osc3_Morph => {
+                    if self.params.mod_source_4.value() == ModulationSource::Velocity {
+                        temp_mod_morph_3 += self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_morph_3 += mod_value_4;
+                    }
+                }
+                ModulationDestination::Osc1_Shape => {
+                    if self.params.mod_source_4.value() == ModulationSource::Velocity {
+                        temp_mod_shape_1 += self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_shape_1 += mod_value_4;
+                    }
+                }
+                ModulationDestination::Osc2_Shape => {
+                    if self.params.mod_source_4.value() == ModulationSource::Velocity {
+                        temp_mod_shape_2 += self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_shape_2 += mod_value_4;
+                    }
+                }
+                ModulationDestination::Osc3_Shape => {
+                    if self.params.mod_source_4.value() == ModulationSource::Velocity {
+                        temp_mod_shape_3 += self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_shape_3 += mod_value_4;
+                    }
+                }
+                ModulationDestination::All_Gain => {
+                    if self.params.mod_source_4.value() == ModulationSource::Velocity {
+                        let vel = self.current_note_on_velocity.load(Ordering::SeqCst);
+                        temp_mod_gain_1 = vel;
+                        temp_mod_gain_2 = vel;
+                        temp_mod_gain_3 = vel;
+                    } else {
+                        temp_mod_lfo_gain_1 = mod_value_4;
+                        temp_mod_lfo_gain_2 = mod_value_4;
+                        temp_mod_lfo_gain_3 = mod_value_4;
+                    }
+                }
+                ModulationDestination::Osc1_Gain => {
+                    if self.params.mod_source_4.value() == ModulationSource::Velocity {
+                        temp_mod_gain_1 = self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_lfo_gain_1 = mod_value_4;
+                    }
+                }
+                ModulationDestination::Osc2_Gain => {
+                    if self.params.mod_source_4.value() == ModulationSource::Velocity {
+                        temp_mod_gain_2 = self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_lfo_gain_2 = mod_value_4;
+                    }
+                }
+                ModulationDestination::Osc3_Gain => {
+                    if self.params.mod_source_4.value() == ModulationSource::Velocity {
+                        temp_mod_gain_3 = self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_lfo_gain_3 = mod_value_4;
                     }
                 }
             }
+        }
+        if mod_value_5 != -2.0 {
+            match self.params.mod_destination_5.value() {
+                ModulationDestination::None | ModulationDestination::UnsetModulation => {}
+                ModulationDestination::Cutoff_1 => {
+                    if self.params.mod_source_5.value() == ModulationSource::Velocity {
+                        temp_mod_cutoff_1_source_5 +=
+                            20000.0 * self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_cutoff_1_source_5 += 20000.0 * mod_value_5;
+                    }
+                }
+                ModulationDestination::Cutoff_2 => {
+                    if self.params.mod_source_5.value() == ModulationSource::Velocity {
+                        temp_mod_cutoff_2_source_5 +=
+                            20000.0 * self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_cutoff_2_source_5 += 20000.0 * mod_value_5;
+                    }
+                }
+                ModulationDestination::Resonance_1 => {
+                    if self.params.mod_source_5.value() == ModulationSource::Velocity {
+                        temp_mod_resonance_1_source_5 -=
+                            self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_resonance_1_source_5 -= mod_value_5;
+                    }
+                }
+                ModulationDestination::Resonance_2 => {
+                    if self.params.mod_source_5.value() == ModulationSource::Velocity {
+                        temp_mod_resonance_2_source_5 -=
+                            self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_resonance_2_source_5 -= mod_value_5;
+                    }
+                }
+                ModulationDestination::All_Detune => {
+                    if self.params.mod_source_5.value() == ModulationSource::Velocity {
+                        temp_mod_vel_sum += mod_value_5;
+                    }
+                    temp_mod_detune_1 += mod_value_5;
+                    temp_mod_detune_2 += mod_value_5;
+                    temp_mod_detune_3 += mod_value_5;
+                }
+                ModulationDestination::Osc1Detune => {
+                    if self.params.mod_source_5.value() == ModulationSource::Velocity {
+                        temp_mod_vel_sum += mod_value_5;
+                    }
+                    temp_mod_detune_1 += mod_value_5;
+                }
+                ModulationDestination::Osc2Detune => {
+                    if self.params.mod_source_5.value() == ModulationSource::Velocity {
+                        temp_mod_vel_sum += mod_value_5;
+                    }
+                    temp_mod_detune_2 += mod_value_5;
+                }
+                ModulationDestination::Osc3Detune => {
+                    if self.params.mod_source_5.value() == ModulationSource::Velocity {
+                        temp_mod_vel_sum += mod_value_5;
+                    }
+                    temp_mod_detune_3 += mod_value_5;
+                }
+                ModulationDestination::All_UniDetune => {
+                    if self.params.mod_source_5.value() == ModulationSource::Velocity {
+                        temp_mod_vel_sum += mod_value_5;
+                    }
+                    temp_mod_uni_detune_1 += mod_value_5;
+                    temp_mod_uni_detune_2 += mod_value_5;
+                    temp_mod_uni_detune_3 += mod_value_5;
+                }
+                ModulationDestination::Osc1UniDetune => {
+                    if self.params.mod_source_5.value() == ModulationSource::Velocity {
+                        temp_mod_uni_vel_sum += mod_value_5;
+                    }
+                    temp_mod_uni_detune_1 += mod_value_5;
+                }
+                ModulationDestination::Osc2UniDetune => {
+                    if self.params.mod_source_5.value() == ModulationSource::Velocity {
+                        temp_mod_uni_vel_sum += mod_value_5;
+                    }
+                    temp_mod_uni_detune_2 += mod_value_5;
+                }
+                ModulationDestination::Osc3UniDetune => {
+                    if self.params.mod_source_5.value() == ModulationSource::Velocity {
+                        temp_mod_uni_vel_sum += mod_value_5;
+                    }
+                    temp_mod_uni_detune_3 += mod_value_5;
+                }
+                ModulationDestination::Drive_1 => {
+                    if self.params.mod_source_5.value() == ModulationSource::Velocity {
+                        temp_mod_drive_1 += self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_drive_1 += mod_value_5;
+                    }
+                }
+                ModulationDestination::Drive_2 => {
+                    if self.params.mod_source_5.value() == ModulationSource::Velocity {
+                        temp_mod_drive_2 += self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_drive_2 += mod_value_5;
+                    }
+                }
+                ModulationDestination::Osc1_Morph => {
+                    if self.params.mod_source_5.value() == ModulationSource::Velocity {
+                        temp_mod_morph_1 += self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_morph_1 += mod_value_5;
+                    }
+                }
+                ModulationDestination::Osc2_Morph => {
+                    if self.params.mod_source_5.value() == ModulationSource::Velocity {
+                        temp_mod_morph_2 += self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_morph_2 += mod_value_5;
+                    }
+                }
+                ModulationDestination::Osc3_Morph => {
+                    if self.params.mod_source_5.value() == ModulationSource::Velocity {
+                        temp_mod_morph_3 += self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_morph_3 += mod_value_5;
+                    }
+                }
+                ModulationDestination::Osc1_Shape => {
+                    if self.params.mod_source_5.value() == ModulationSource::Velocity {
+                        temp_mod_shape_1 += self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_shape_1 += mod_value_5;
+                    }
+                }
+                ModulationDestination::Osc2_Shape => {
+                    if self.params.mod_source_5.value() == ModulationSource::Velocity {
+                        temp_mod_shape_2 += self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_shape_2 += mod_value_5;
+                    }
+                }
+                ModulationDestination::Osc3_Shape => {
+                    if self.params.mod_source_5.value() == ModulationSource::Velocity {
+                        temp_mod_shape_3 += self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_shape_3 += mod_value_5;
+                    }
+                }
+                ModulationDestination::All_Gain => {
+                    if self.params.mod_source_5.value() == ModulationSource::Velocity {
+                        let vel = self.current_note_on_velocity.load(Ordering::SeqCst);
+                        temp_mod_gain_1 = vel;
+                        temp_mod_gain_2 = vel;
+                        temp_mod_gain_3 = vel;
+                    } else {
+                        temp_mod_lfo_gain_1 = mod_value_5;
+                        temp_mod_lfo_gain_2 = mod_value_5;
+                        temp_mod_lfo_gain_3 = mod_value_5;
+                    }
+                }
+                ModulationDestination::Osc1_Gain => {
+                    if self.params.mod_source_5.value() == ModulationSource::Velocity {
+                        temp_mod_gain_1 = self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_lfo_gain_1 = mod_value_5;
+                    }
+                }
+                ModulationDestination::Osc2_Gain => {
+                    if self.params.mod_source_5.value() == ModulationSource::Velocity {
+                        temp_mod_gain_2 = self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_lfo_gain_2 = mod_value_5;
+                    }
+                }
+                ModulationDestination::Osc3_Gain => {
+                    if self.params.mod_source_5.value() == ModulationSource::Velocity {
+                        temp_mod_gain_3 = self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_lfo_gain_3 = mod_value_5;
+                    }
+                }
+            }
+        }
+        if mod_value_6 != -2.0 {
+            match self.params.mod_destination_6.value() {
+                ModulationDestination::None | ModulationDestination::UnsetModulation => {}
+                ModulationDestination::Cutoff_1 => {
+                    if self.params.mod_source_6.value() == ModulationSource::Velocity {
+                        temp_mod_cutoff_1_source_6 +=
+                            20000.0 * self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_cutoff_1_source_6 += 20000.0 * mod_value_6;
+                    }
+                }
+                ModulationDestination::Cutoff_2 => {
+                    if self.params.mod_source_6.value() == ModulationSource::Velocity {
+                        temp_mod_cutoff_2_source_6 +=
+                            20000.0 * self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_cutoff_2_source_6 += 20000.0 * mod_value_6;
+                    }
+                }
+                ModulationDestination::Resonance_1 => {
+                    if self.params.mod_source_6.value() == ModulationSource::Velocity {
+                        temp_mod_resonance_1_source_6 -=
+                            self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_resonance_1_source_6 -= mod_value_6;
+                    }
+                }
+                ModulationDestination::Resonance_2 => {
+                    if self.params.mod_source_6.value() == ModulationSource::Velocity {
+                        temp_mod_resonance_2_source_6 -=
+                            self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_resonance_2_source_6 -= mod_value_6;
+                    }
+                }
+                ModulationDestination::All_Detune => {
+                    if self.params.mod_source_6.value() == ModulationSource::Velocity {
+                        temp_mod_vel_sum += mod_value_6;
+                    }
+                    temp_mod_detune_1 += mod_value_6;
+                    temp_mod_detune_2 += mod_value_6;
+                    temp_mod_detune_3 += mod_value_6;
+                }
+                ModulationDestination::Osc1Detune => {
+                    if self.params.mod_source_6.value() == ModulationSource::Velocity {
+                        temp_mod_vel_sum += mod_value_6;
+                    }
+                    temp_mod_detune_1 += mod_value_6;
+                }
+                ModulationDestination::Osc2Detune => {
+                    if self.params.mod_source_6.value() == ModulationSource::Velocity {
+                        temp_mod_vel_sum += mod_value_6;
+                    }
+                    temp_mod_detune_2 += mod_value_6;
+                }
+                ModulationDestination::Osc3Detune => {
+                    if self.params.mod_source_6.value() == ModulationSource::Velocity {
+                        temp_mod_vel_sum += mod_value_6;
+                    }
+                    temp_mod_detune_3 += mod_value_6;
+                }
+                ModulationDestination::All_UniDetune => {
+                    if self.params.mod_source_6.value() == ModulationSource::Velocity {
+                        temp_mod_vel_sum += mod_value_6;
+                    }
+                    temp_mod_uni_detune_1 += mod_value_6;
+                    temp_mod_uni_detune_2 += mod_value_6;
+                    temp_mod_uni_detune_3 += mod_value_6;
+                }
+                ModulationDestination::Osc1UniDetune => {
+                    if self.params.mod_source_6.value() == ModulationSource::Velocity {
+                        temp_mod_uni_vel_sum += mod_value_6;
+                    }
+                    temp_mod_uni_detune_1 += mod_value_6;
+                }
+                ModulationDestination::Osc2UniDetune => {
+                    if self.params.mod_source_6.value() == ModulationSource::Velocity {
+                        temp_mod_uni_vel_sum += mod_value_6;
+                    }
+                    temp_mod_uni_detune_2 += mod_value_6;
+                }
+                ModulationDestination::Osc3UniDetune => {
+                    if self.params.mod_source_6.value() == ModulationSource::Velocity {
+                        temp_mod_uni_vel_sum += mod_value_6;
+                    }
+                    temp_mod_uni_detune_3 += mod_value_6;
+                }
+                ModulationDestination::Drive_1 => {
+                    if self.params.mod_source_6.value() == ModulationSource::Velocity {
+                        temp_mod_drive_1 += self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_drive_1 += mod_value_6;
+                    }
+                }
+                ModulationDestination::Drive_2 => {
+                    if self.params.mod_source_6.value() == ModulationSource::Velocity {
+                        temp_mod_drive_2 += self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_drive_2 += mod_value_6;
+                    }
+                }
+                ModulationDestination::Osc1_Morph => {
+                    if self.params.mod_source_6.value() == ModulationSource::Velocity {
+                        temp_mod_morph_1 += self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_morph_1 += mod_value_6;
+                    }
+                }
+                ModulationDestination::Osc2_Morph => {
+                    if self.params.mod_source_6.value() == ModulationSource::Velocity {
+                        temp_mod_morph_2 += self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_morph_2 += mod_value_6;
+                    }
+                }
+                ModulationDestination::Osc3_Morph => {
+                    if self.params.mod_source_6.value() == ModulationSource::Velocity {
+                        temp_mod_morph_3 += self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_morph_3 += mod_value_6;
+                    }
+                }
+                ModulationDestination::Osc1_Shape => {
+                    if self.params.mod_source_6.value() == ModulationSource::Velocity {
+                        temp_mod_shape_1 += self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_shape_1 += mod_value_6;
+                    }
+                }
+                ModulationDestination::Osc2_Shape => {
+                    if self.params.mod_source_6.value() == ModulationSource::Velocity {
+                        temp_mod_shape_2 += self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_shape_2 += mod_value_6;
+                    }
+                }
+                ModulationDestination::Osc3_Shape => {
+                    if self.params.mod_source_6.value() == ModulationSource::Velocity {
+                        temp_mod_shape_3 += self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_shape_3 += mod_value_6;
+                    }
+                }
+                ModulationDestination::All_Gain => {
+                    if self.params.mod_source_6.value() == ModulationSource::Velocity {
+                        let vel = self.current_note_on_velocity.load(Ordering::SeqCst);
+                        temp_mod_gain_1 = vel;
+                        temp_mod_gain_2 = vel;
+                        temp_mod_gain_3 = vel;
+                    } else {
+                        temp_mod_lfo_gain_1 = mod_value_6;
+                        temp_mod_lfo_gain_2 = mod_value_6;
+                        temp_mod_lfo_gain_3 = mod_value_6;
+                    }
+                }
+                ModulationDestination::Osc1_Gain => {
+                    if self.params.mod_source_6.value() == ModulationSource::Velocity {
+                        temp_mod_gain_1 = self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_lfo_gain_1 = mod_value_6;
+                    }
+                }
+                ModulationDestination::Osc2_Gain => {
+                    if self.params.mod_source_6.value() == ModulationSource::Velocity {
+                        temp_mod_gain_2 = self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_lfo_gain_2 = mod_value_6;
+                    }
+                }
+                ModulationDestination::Osc3_Gain => {
+                    if self.params.mod_source_6.value() == ModulationSource::Velocity {
+                        temp_mod_gain_3 = self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_lfo_gain_3 = mod_value_6;
+                    }
+                }
+            }
+        }
+        if mod_value_7 != -2.0 {
+            match self.params.mod_destination_7.value() {
+                ModulationDestination::None | ModulationDestination::UnsetModulation => {}
+                ModulationDestination::Cutoff_1 => {
+                    if self.params.mod_source_7.value() == ModulationSource::Velocity {
+                        temp_mod_cutoff_1_source_7 +=
+                            20000.0 * self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_cutoff_1_source_7 += 20000.0 * mod_value_7;
+                    }
+                }
+                ModulationDestination::Cutoff_2 => {
+                    if self.params.mod_source_7.value() == ModulationSource::Velocity {
+                        temp_mod_cutoff_2_source_7 +=
+                            20000.0 * self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_cutoff_2_source_7 += 20000.0 * mod_value_7;
+                    }
+                }
+                ModulationDestination::Resonance_1 => {
+                    if self.params.mod_source_7.value() == ModulationSource::Velocity {
+                        temp_mod_resonance_1_source_7 -=
+                            self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_resonance_1_source_7 -= mod_value_7;
+                    }
+                }
+                ModulationDestination::Resonance_2 => {
+                    if self.params.mod_source_7.value() == ModulationSource::Velocity {
+                        temp_mod_resonance_2_source_7 -=
+                            self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_resonance_2_source_7 -= mod_value_7;
+                    }
+                }
+                ModulationDestination::All_Detune => {
+                    if self.params.mod_source_7.value() == ModulationSource::Velocity {
+                        temp_mod_vel_sum += mod_value_7;
+                    }
+                    temp_mod_detune_1 += mod_value_7;
+                    temp_mod_detune_2 += mod_value_7;
+                    temp_mod_detune_3 += mod_value_7;
+                }
+                ModulationDestination::Osc1Detune => {
+                    if self.params.mod_source_7.value() == ModulationSource::Velocity {
+                        temp_mod_vel_sum += mod_value_7;
+                    }
+                    temp_mod_detune_1 += mod_value_7;
+                }
+                ModulationDestination::Osc2Detune => {
+                    if self.params.mod_source_7.value() == ModulationSource::Velocity {
+                        temp_mod_vel_sum += mod_value_7;
+                    }
+                    temp_mod_detune_2 += mod_value_7;
+                }
+                ModulationDestination::Osc3Detune => {
+                    if self.params.mod_source_7.value() == ModulationSource::Velocity {
+                        temp_mod_vel_sum += mod_value_7;
+                    }
+                    temp_mod_detune_3 += mod_value_7;
+                }
+                ModulationDestination::All_UniDetune => {
+                    if self.params.mod_source_7.value() == ModulationSource::Velocity {
+                        temp_mod_vel_sum += mod_value_7;
+                    }
+                    temp_mod_uni_detune_1 += mod_value_7;
+                    temp_mod_uni_detune_2 += mod_value_7;
+                    temp_mod_uni_detune_3 += mod_value_7;
+                }
+                ModulationDestination::Osc1UniDetune => {
+                    if self.params.mod_source_7.value() == ModulationSource::Velocity {
+                        temp_mod_uni_vel_sum += mod_value_7;
+                    }
+                    temp_mod_uni_detune_1 += mod_value_7;
+                }
+                ModulationDestination::Osc2UniDetune => {
+                    if self.params.mod_source_7.value() == ModulationSource::Velocity {
+                        temp_mod_uni_vel_sum += mod_value_7;
+                    }
+                    temp_mod_uni_detune_2 += mod_value_7;
+                }
+                ModulationDestination::Osc3UniDetune => {
+                    if self.params.mod_source_7.value() == ModulationSource::Velocity {
+                        temp_mod_uni_vel_sum += mod_value_7;
+                    }
+                    temp_mod_uni_detune_3 += mod_value_7;
+                }
+                ModulationDestination::Drive_1 => {
+                    if self.params.mod_source_7.value() == ModulationSource::Velocity {
+                        temp_mod_drive_1 += self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_drive_1 += mod_value_7;
+                    }
+                }
+                ModulationDestination::Drive_2 => {
+                    if self.params.mod_source_7.value() == ModulationSource::Velocity {
+                        temp_mod_drive_2 += self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_drive_2 += mod_value_7;
+                    }
+                }
+                ModulationDestination::Osc1_Morph => {
+                    if self.params.mod_source_7.value() == ModulationSource::Velocity {
+                        temp_mod_morph_1 += self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_morph_1 += mod_value_7;
+                    }
+                }
+                ModulationDestination::Osc2_Morph => {
+                    if self.params.mod_source_7.value() == ModulationSource::Velocity {
+                        temp_mod_morph_2 += self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_morph_2 += mod_value_7;
+                    }
+                }
+                ModulationDestination::Osc3_Morph => {
+                    if self.params.mod_source_7.value() == ModulationSource::Velocity {
+                        temp_mod_morph_3 += self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_morph_3 += mod_value_7;
+                    }
+                }
+                ModulationDestination::Osc1_Shape => {
+                    if self.params.mod_source_7.value() == ModulationSource::Velocity {
+                        temp_mod_shape_1 += self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_shape_1 += mod_value_7;
+                    }
+                }
+                ModulationDestination::Osc2_Shape => {
+                    if self.params.mod_source_7.value() == ModulationSource::Velocity {
+                        temp_mod_shape_2 += self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_shape_2 += mod_value_7;
+                    }
+                }
+                ModulationDestination::Osc3_Shape => {
+                    if self.params.mod_source_7.value() == ModulationSource::Velocity {
+                        temp_mod_shape_3 += self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_shape_3 += mod_value_7;
+                    }
+                }
+                ModulationDestination::All_Gain => {
+                    if self.params.mod_source_7.value() == ModulationSource::Velocity {
+                        let vel = self.current_note_on_velocity.load(Ordering::SeqCst);
+                        temp_mod_gain_1 = vel;
+                        temp_mod_gain_2 = vel;
+                        temp_mod_gain_3 = vel;
+                    } else {
+                        temp_mod_lfo_gain_1 = mod_value_7;
+                        temp_mod_lfo_gain_2 = mod_value_7;
+                        temp_mod_lfo_gain_3 = mod_value_7;
+                    }
+                }
+                ModulationDestination::Osc1_Gain => {
+                    if self.params.mod_source_7.value() == ModulationSource::Velocity {
+                        temp_mod_gain_1 = self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_lfo_gain_1 = mod_value_7;
+                    }
+                }
+                ModulationDestination::Osc2_Gain => {
+                    if self.params.mod_source_7.value() == ModulationSource::Velocity {
+                        temp_mod_gain_2 = self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_lfo_gain_2 = mod_value_7;
+                    }
+                }
+                ModulationDestination::Osc3_Gain => {
+                    if self.params.mod_source_7.value() == ModulationSource::Velocity {
+                        temp_mod_gain_3 = self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_lfo_gain_3 = mod_value_7;
+                    }
+                }
+            }
+        }
+        if mod_value_8 != -2.0 {
+            match self.params.mod_destination_8.value() {
+                ModulationDestination::None | ModulationDestination::UnsetModulation => {}
+                ModulationDestination::Cutoff_1 => {
+                    if self.params.mod_source_8.value() == ModulationSource::Velocity {
+                        temp_mod_cutoff_1_source_8 +=
+                            20000.0 * self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_cutoff_1_source_8 += 20000.0 * mod_value_8;
+                    }
+                }
+                ModulationDestination::Cutoff_2 => {
+                    if self.params.mod_source_8.value() == ModulationSource::Velocity {
+                        temp_mod_cutoff_2_source_8 +=
+                            20000.0 * self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_cutoff_2_source_8 += 20000.0 * mod_value_8;
+                    }
+                }
+                ModulationDestination::Resonance_1 => {
+                    if self.params.mod_source_8.value() == ModulationSource::Velocity {
+                        temp_mod_resonance_1_source_8 -=
+                            self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_resonance_1_source_8 -= mod_value_8;
+                    }
+                }
+                ModulationDestination::Resonance_2 => {
+                    if self.params.mod_source_8.value() == ModulationSource::Velocity {
+                        temp_mod_resonance_2_source_8 -=
+                            self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_resonance_2_source_8 -= mod_value_8;
+                    }
+                }
+                ModulationDestination::All_Detune => {
+                    if self.params.mod_source_8.value() == ModulationSource::Velocity {
+                        temp_mod_vel_sum += mod_value_8;
+                    }
+                    temp_mod_detune_1 += mod_value_8;
+                    temp_mod_detune_2 += mod_value_8;
+                    temp_mod_detune_3 += mod_value_8;
+                }
+                ModulationDestination::Osc1Detune => {
+                    if self.params.mod_source_8.value() == ModulationSource::Velocity {
+                        temp_mod_vel_sum += mod_value_8;
+                    }
+                    temp_mod_detune_1 += mod_value_8;
+                }
+                ModulationDestination::Osc2Detune => {
+                    if self.params.mod_source_8.value() == ModulationSource::Velocity {
+                        temp_mod_vel_sum += mod_value_8;
+                    }
+                    temp_mod_detune_2 += mod_value_8;
+                }
+                ModulationDestination::Osc3Detune => {
+                    if self.params.mod_source_8.value() == ModulationSource::Velocity {
+                        temp_mod_vel_sum += mod_value_8;
+                    }
+                    temp_mod_detune_3 += mod_value_8;
+                }
+                ModulationDestination::All_UniDetune => {
+                    if self.params.mod_source_8.value() == ModulationSource::Velocity {
+                        temp_mod_vel_sum += mod_value_8;
+                    }
+                    temp_mod_uni_detune_1 += mod_value_8;
+                    temp_mod_uni_detune_2 += mod_value_8;
+                    temp_mod_uni_detune_3 += mod_value_8;
+                }
+                ModulationDestination::Osc1UniDetune => {
+                    if self.params.mod_source_8.value() == ModulationSource::Velocity {
+                        temp_mod_uni_vel_sum += mod_value_8;
+                    }
+                    temp_mod_uni_detune_1 += mod_value_8;
+                }
+                ModulationDestination::Osc2UniDetune => {
+                    if self.params.mod_source_8.value() == ModulationSource::Velocity {
+                        temp_mod_uni_vel_sum += mod_value_8;
+                    }
+                    temp_mod_uni_detune_2 += mod_value_8;
+                }
+                ModulationDestination::Osc3UniDetune => {
+                    if self.params.mod_source_8.value() == ModulationSource::Velocity {
+                        temp_mod_uni_vel_sum += mod_value_8;
+                    }
+                    temp_mod_uni_detune_3 += mod_value_8;
+                }
+                ModulationDestination::Drive_1 => {
+                    if self.params.mod_source_8.value() == ModulationSource::Velocity {
+                        temp_mod_drive_1 += self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_drive_1 += mod_value_8;
+                    }
+                }
+                ModulationDestination::Drive_2 => {
+                    if self.params.mod_source_8.value() == ModulationSource::Velocity {
+                        temp_mod_drive_2 += self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_drive_2 += mod_value_8;
+                    }
+                }
+                ModulationDestination::Osc1_Morph => {
+                    if self.params.mod_source_8.value() == ModulationSource::Velocity {
+                        temp_mod_morph_1 += self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_morph_1 += mod_value_8;
+                    }
+                }
+                ModulationDestination::Osc2_Morph => {
+                    if self.params.mod_source_8.value() == ModulationSource::Velocity {
+                        temp_mod_morph_2 += self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_morph_2 += mod_value_8;
+                    }
+                }
+                ModulationDestination::Osc3_Morph => {
+                    if self.params.mod_source_8.value() == ModulationSource::Velocity {
+                        temp_mod_morph_3 += self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_morph_3 += mod_value_8;
+                    }
+                }
+                ModulationDestination::Osc1_Shape => {
+                    if self.params.mod_source_8.value() == ModulationSource::Velocity {
+                        temp_mod_shape_1 += self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_shape_1 += mod_value_8;
+                    }
+                }
+                ModulationDestination::Osc2_Shape => {
+                    if self.params.mod_source_8.value() == ModulationSource::Velocity {
+                        temp_mod_shape_2 += self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_shape_2 += mod_value_8;
+                    }
+                }
+                ModulationDestination::Osc3_Shape => {
+                    if self.params.mod_source_8.value() == ModulationSource::Velocity {
+                        temp_mod_shape_3 += self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_shape_3 += mod_value_8;
+                    }
+                }
+                ModulationDestination::All_Gain => {
+                    if self.params.mod_source_8.value() == ModulationSource::Velocity {
+                        let vel = self.current_note_on_velocity.load(Ordering::SeqCst);
+                        temp_mod_gain_1 = vel;
+                        temp_mod_gain_2 = vel;
+                        temp_mod_gain_3 = vel;
+                    } else {
+                        temp_mod_lfo_gain_1 = mod_value_8;
+                        temp_mod_lfo_gain_2 = mod_value_8;
+                        temp_mod_lfo_gain_3 = mod_value_8;
+                    }
+                }
+                ModulationDestination::Osc1_Gain => {
+                    if self.params.mod_source_8.value() == ModulationSource::Velocity {
+                        temp_mod_gain_1 = self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_lfo_gain_1 = mod_value_8;
+                    }
+                }
+                ModulationDestination::Osc2_Gain => {
+                    if self.params.mod_source_8.value() == ModulationSource::Velocity {
+                        temp_mod_gain_2 = self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_lfo_gain_2 = mod_value_8;
+                    }
+                }
+                ModulationDestination::Osc3_Gain => {
+                    if self.params.mod_source_8.value() == ModulationSource::Velocity {
+                        temp_mod_gain_3 = self.current_note_on_velocity.load(Ordering::SeqCst);
+                    } else {
+                        temp_mod_lfo_gain_3 = mod_value_8;
+                    }
+                }
+            }
+        }
 
-            // I think this makes sense to split into structs so each modulation path has its own easily debuggable chain
-            modulations_1 = ModulationStruct {
-                temp_mod_cutoff_1: temp_mod_cutoff_1_source_1,
-                temp_mod_cutoff_2: temp_mod_cutoff_2_source_1,
-                temp_mod_resonance_1: temp_mod_resonance_1_source_1,
-                temp_mod_resonance_2: temp_mod_resonance_2_source_1,
-                temp_mod_detune_1: temp_mod_detune_1,
-                temp_mod_detune_2: temp_mod_detune_2,
-                temp_mod_detune_3: temp_mod_detune_3,
-                temp_mod_uni_detune_1: temp_mod_uni_detune_1,
-                temp_mod_uni_detune_2: temp_mod_uni_detune_2,
-                temp_mod_uni_detune_3: temp_mod_uni_detune_3,
-                temp_mod_vel_sum: temp_mod_vel_sum,
-            };
-            modulations_2 = ModulationStruct {
-                temp_mod_cutoff_1: temp_mod_cutoff_1_source_2,
-                temp_mod_cutoff_2: temp_mod_cutoff_2_source_2,
-                temp_mod_resonance_1: temp_mod_resonance_1_source_2,
-                temp_mod_resonance_2: temp_mod_resonance_2_source_2,
-                temp_mod_detune_1: temp_mod_detune_1,
-                temp_mod_detune_2: temp_mod_detune_2,
-                temp_mod_detune_3: temp_mod_detune_3,
-                temp_mod_uni_detune_1: temp_mod_uni_detune_1,
-                temp_mod_uni_detune_2: temp_mod_uni_detune_2,
-                temp_mod_uni_detune_3: temp_mod_uni_detune_3,
-                temp_mod_vel_sum: temp_mod_vel_sum,
-            };
-            modulations_3 = ModulationStruct {
-                temp_mod_cutoff_1: temp_mod_cutoff_1_source_3,
-                temp_mod_cutoff_2: temp_mod_cutoff_2_source_3,
-                temp_mod_resonance_1: temp_mod_resonance_1_source_3,
-                temp_mod_resonance_2: temp_mod_resonance_2_source_3,
-                temp_mod_detune_1: temp_mod_detune_1,
-                temp_mod_detune_2: temp_mod_detune_2,
-                temp_mod_detune_3: temp_mod_detune_3,
-                temp_mod_uni_detune_1: temp_mod_uni_detune_1,
-                temp_mod_uni_detune_2: temp_mod_uni_detune_2,
-                temp_mod_uni_detune_3: temp_mod_uni_detune_3,
-                temp_mod_vel_sum: temp_mod_vel_sum,
-            };
-            modulations_4 = ModulationStruct {
-                temp_mod_cutoff_1: temp_mod_cutoff_1_source_4,
-                temp_mod_cutoff_2: temp_mod_cutoff_2_source_4,
-                temp_mod_resonance_1: temp_mod_resonance_1_source_4,
-                temp_mod_resonance_2: temp_mod_resonance_2_source_4,
-                temp_mod_detune_1: temp_mod_detune_1,
-                temp_mod_detune_2: temp_mod_detune_2,
-                temp_mod_detune_3: temp_mod_detune_3,
-                temp_mod_uni_detune_1: temp_mod_uni_detune_1,
-                temp_mod_uni_detune_2: temp_mod_uni_detune_2,
-                temp_mod_uni_detune_3: temp_mod_uni_detune_3,
-                temp_mod_vel_sum: temp_mod_vel_sum,
-            };
-
-            // Audio Module Processing of Audio kicks off here
-            /////////////////////////////////////////////////////////////////////////////////////////////////
-
-            let mut fm_wave_1: f32 = 0.0;
-            let mut fm_wave_2: f32 = 0.0;
-            // Since File Dialog can be set by any of these we need to check each time
-            if !self.file_dialog.load(Ordering::SeqCst)
-                //&& self.params.audio_module_1_type.value() != AudioModuleType::Off
-                && am1_lock.audio_module_type != AudioModuleType::Off
-            {
-                // We send our sample_id position, params, current midi event, module index, current voice max, and whether any params have changed
-                (
-                    wave1_l,
-                    wave1_r,
-                    reset_filter_controller1,
-                    note_off_filter_controller1,
-                ) = am1_lock.process(
-                    sample_id,
-                    midi_event.clone(),
-                    sent_voice_max,
-                    modulations_1.temp_mod_detune_1
-                        + modulations_2.temp_mod_detune_1
-                        + modulations_3.temp_mod_detune_1
-                        + modulations_4.temp_mod_detune_1,
-                    modulations_1.temp_mod_uni_detune_1
-                        + modulations_2.temp_mod_uni_detune_1
-                        + modulations_3.temp_mod_uni_detune_1
-                        + modulations_4.temp_mod_uni_detune_1,
-                    temp_mod_vel_sum,
-                    temp_mod_uni_vel_sum,
-                    temp_mod_gain_1,
-                    temp_mod_lfo_gain_1,
-                    self.params.stereo_algorithm.value(),
-                    modulations_1.temp_mod_resonance_1
-                        + modulations_2.temp_mod_resonance_1
-                        + modulations_3.temp_mod_resonance_1
-                        + modulations_4.temp_mod_resonance_1,
-                    modulations_1.temp_mod_cutoff_1
-                        + modulations_2.temp_mod_cutoff_1
-                        + modulations_3.temp_mod_cutoff_1
-                        + modulations_4.temp_mod_cutoff_1,
-                    modulations_1.temp_mod_resonance_2
-                        + modulations_2.temp_mod_resonance_2
-                        + modulations_3.temp_mod_resonance_2
-                        + modulations_4.temp_mod_resonance_2,
-                    modulations_1.temp_mod_cutoff_2
-                        + modulations_2.temp_mod_cutoff_2
-                        + modulations_3.temp_mod_cutoff_2
-                        + modulations_4.temp_mod_cutoff_2,
-                );
-                // Sum to MONO
-                fm_wave_1 = (wave1_l + wave1_r)/2.0;
-                // I know this isn't a perfect 3rd, but 0.01 is acceptable headroom
-                let levelAmp1 = self.params.audio_module_1_level.value();
-                wave1_l *= levelAmp1 * 0.33;
-                wave1_r *= levelAmp1 * 0.33;
-            }
-
-            // Since File Dialog can be set by any of these we need to check each time
-            if !self.file_dialog.load(Ordering::SeqCst)
-                //&& self.params.audio_module_1_type.value() != AudioModuleType::Off
-                && am2_lock.audio_module_type != AudioModuleType::Off
-            {
-                // We send our sample_id position, params, current midi event, module index, current voice max, and whether any params have changed
-                (
-                    wave2_l,
-                    wave2_r,
-                    reset_filter_controller2,
-                    note_off_filter_controller2,
-                ) = am2_lock.process(
-                    sample_id,
-                    midi_event.clone(),
-                    sent_voice_max,
-                    modulations_1.temp_mod_detune_2
-                        + modulations_2.temp_mod_detune_2
-                        + modulations_3.temp_mod_detune_2
-                        + modulations_4.temp_mod_detune_2,
-                    modulations_1.temp_mod_uni_detune_2
-                        + modulations_2.temp_mod_uni_detune_2
-                        + modulations_3.temp_mod_uni_detune_2
-                        + modulations_4.temp_mod_uni_detune_2,
-                    temp_mod_vel_sum,
-                    temp_mod_uni_vel_sum,
-                    temp_mod_gain_2,
-                    temp_mod_lfo_gain_2,
-                    self.params.stereo_algorithm.value(),
-                    modulations_1.temp_mod_resonance_1
-                        + modulations_2.temp_mod_resonance_1
-                        + modulations_3.temp_mod_resonance_1
-                        + modulations_4.temp_mod_resonance_1,
-                    modulations_1.temp_mod_cutoff_1
-                        + modulations_2.temp_mod_cutoff_1
-                        + modulations_3.temp_mod_cutoff_1
-                        + modulations_4.temp_mod_cutoff_1,
-                    modulations_1.temp_mod_resonance_2
-                        + modulations_2.temp_mod_resonance_2
-                        + modulations_3.temp_mod_resonance_2
-                        + modulations_4.temp_mod_resonance_2,
-                    modulations_1.temp_mod_cutoff_2
-                        + modulations_2.temp_mod_cutoff_2
-                        + modulations_3.temp_mod_cutoff_2
-                        + modulations_4.temp_mod_cutoff_2,
-                );
-                // Sum to MONO
-                fm_wave_2 = (wave2_l + wave2_r)/2.0;
-                // I know this isn't a perfect 3rd, but 0.01 is acceptable headroom
-                let levelAmp2 = self.params.audio_module_2_level.value();
-                wave2_l *= levelAmp2 * 0.33;
-                wave2_r *= levelAmp2 * 0.33;
+        // I think this makes sense to split into structs so each modulation path has its own easily debuggable chain
+        modulations_1 = ModulationStruct {
+            temp_mod_cutoff_1: temp_mod_cutoff_1_source_1,
+            temp_mod_cutoff_2: temp_mod_cutoff_2_source_1,
+            temp_mod_resonance_1: temp_mod_resonance_1_source_1,
+            temp_mod_resonance_2: temp_mod_resonance_2_source_1,
+            temp_mod_detune_1: temp_mod_detune_1,
+            temp_mod_detune_2: temp_mod_detune_2,
+            temp_mod_detune_3: temp_mod_detune_3,
+            temp_mod_uni_detune_1: temp_mod_uni_detune_1,
+            temp_mod_uni_detune_2: temp_mod_uni_detune_2,
+            temp_mod_uni_detune_3: temp_mod_uni_detune_3,
+            temp_mod_vel_sum: temp_mod_vel_sum,
+        };
+        modulations_2 = ModulationStruct {
+            temp_mod_cutoff_1: temp_mod_cutoff_1_source_2,
+            temp_mod_cutoff_2: temp_mod_cutoff_2_source_2,
+            temp_mod_resonance_1: temp_mod_resonance_1_source_2,
+            temp_mod_resonance_2: temp_mod_resonance_2_source_2,
+            temp_mod_detune_1: temp_mod_detune_1,
+            temp_mod_detune_2: temp_mod_detune_2,
+            temp_mod_detune_3: temp_mod_detune_3,
+            temp_mod_uni_detune_1: temp_mod_uni_detune_1,
+            temp_mod_uni_detune_2: temp_mod_uni_detune_2,
+            temp_mod_uni_detune_3: temp_mod_uni_detune_3,
+            temp_mod_vel_sum: temp_mod_vel_sum,
+        };
+        modulations_3 = ModulationStruct {
+            temp_mod_cutoff_1: temp_mod_cutoff_1_source_3,
+            temp_mod_cutoff_2: temp_mod_cutoff_2_source_3,
+            temp_mod_resonance_1: temp_mod_resonance_1_source_3,
+            temp_mod_resonance_2: temp_mod_resonance_2_source_3,
+            temp_mod_detune_1: temp_mod_detune_1,
+            temp_mod_detune_2: temp_mod_detune_2,
+            temp_mod_detune_3: temp_mod_detune_3,
+            temp_mod_uni_detune_1: temp_mod_uni_detune_1,
+            temp_mod_uni_detune_2: temp_mod_uni_detune_2,
+            temp_mod_uni_detune_3: temp_mod_uni_detune_3,
+            temp_mod_vel_sum: temp_mod_vel_sum,
+        };
+        modulations_4 = ModulationStruct {
+            temp_mod_cutoff_1: temp_mod_cutoff_1_source_4,
+            temp_mod_cutoff_2: temp_mod_cutoff_2_source_4,
+            temp_mod_resonance_1: temp_mod_resonance_1_source_4,
+            temp_mod_resonance_2: temp_mod_resonance_2_source_4,
+            temp_mod_detune_1: temp_mod_detune_1,
+            temp_mod_detune_2: temp_mod_detune_2,
+            temp_mod_detune_3: temp_mod_detune_3,
+            temp_mod_uni_detune_1: temp_mod_uni_detune_1,
+            temp_mod_uni_detune_2: temp_mod_uni_detune_2,
+            temp_mod_uni_detune_3: temp_mod_uni_detune_3,
+            temp_mod_vel_sum: temp_mod_vel_sum,
+        };
+        modulations_5 = ModulationStruct {
+            temp_mod_cutoff_1: temp_mod_cutoff_1_source_5,
+            temp_mod_cutoff_2: temp_mod_cutoff_2_source_5,
+            temp_mod_resonance_1: temp_mod_resonance_1_source_5,
+            temp_mod_resonance_2: temp_mod_resonance_2_source_5,
+            temp_mod_detune_1: temp_mod_detune_1,
+            temp_mod_detune_2: temp_mod_detune_2,
+            temp_mod_detune_3: temp_mod_detune_3,
+            temp_mod_uni_detune_1: temp_mod_uni_detune_1,
+            temp_mod_uni_detune_2: temp_mod_uni_detune_2,
+            temp_mod_uni_detune_3: temp_mod_uni_detune_3,
+            temp_mod_vel_sum: temp_mod_vel_sum,
+        };
+        modulations_6 = ModulationStruct {
+            temp_mod_cutoff_1: temp_mod_cutoff_1_source_6,
+            temp_mod_cutoff_2: temp_mod_cutoff_2_source_6,
+            temp_mod_resonance_1: temp_mod_resonance_1_source_6,
+            temp_mod_resonance_2: temp_mod_resonance_2_source_6,
+            temp_mod_detune_1: temp_mod_detune_1,
+            temp_mod_detune_2: temp_mod_detune_2,
+            temp_mod_detune_3: temp_mod_detune_3,
+            temp_mod_uni_detune_1: temp_mod_uni_detune_1,
+            temp_mod_uni_detune_2: temp_mod_uni_detune_2,
+            temp_mod_uni_detune_3: temp_mod_uni_detune_3,
+            temp_mod_vel_sum: temp_mod_vel_sum,
+        };
+        modulations_7 = ModulationStruct {
+            temp_mod_cutoff_1: temp_mod_cutoff_1_source_7,
+            temp_mod_cutoff_2: temp_mod_cutoff_2_source_7,
+            temp_mod_resonance_1: temp_mod_resonance_1_source_7,
+            temp_mod_resonance_2: temp_mod_resonance_2_source_7,
+            temp_mod_detune_1: temp_mod_detune_1,
+            temp_mod_detune_2: temp_mod_detune_2,
+            temp_mod_detune_3: temp_mod_detune_3,
+            temp_mod_uni_detune_1: temp_mod_uni_detune_1,
+            temp_mod_uni_detune_2: temp_mod_uni_detune_2,
+            temp_mod_uni_detune_3: temp_mod_uni_detune_3,
+            temp_mod_vel_sum: temp_mod_vel_sum,
+        };
+        modulations_8 = ModulationStruct {
+            temp_mod_cutoff_1: temp_mod_cutoff_1_source_8,
+            temp_mod_cutoff_2: temp_mod_cutoff_2_source_8,
+            temp_mod_resonance_1: temp_mod_resonance_1_source_8,
+            temp_mod_resonance_2: temp_mod_resonance_2_source_8,
+            temp_mod_detune_1: temp_mod_detune_1,
+            temp_mod_detune_2: temp_mod_detune_2,
+            temp_mod_detune_3: temp_mod_detune_3,
+            temp_mod_uni_detune_1: temp_mod_uni_detune_1,
+            temp_mod_uni_detune_2: temp_mod_uni_detune_2,
+            temp_mod_uni_detune_3: temp_mod_uni_detune_3,
+            temp_mod_vel_sum: temp_mod_vel_sum,
+        };
+
+        // Looping filter envelopes restart on a tempo grid counted from the latest note
+        let mut filter_loop_retrigger = false;
+        if self.params.filter_env_loop.value() {
+            if let Some(NoteEvent::NoteOn { .. }) = midi_event {
+                self.filter_loop_phase = 0.0;
             }
+            let beats = self.params.filter_env_loop_rate.value().beats();
+            self.filter_loop_phase += (bpm / 60.0) / (beats * self.sample_rate);
+            if self.filter_loop_phase >= 1.0 {
+                self.filter_loop_phase -= 1.0;
+                filter_loop_retrigger = true;
+            }
+        }
 
-            // Since File Dialog can be set by any of these we need to check each time
-            if !self.file_dialog.load(Ordering::SeqCst)
-                //&& self.params.audio_module_1_type.value() != AudioModuleType::Off
-                && am3_lock.audio_module_type != AudioModuleType::Off
-            {
-                // We send our sample_id position, params, current midi event, module index, current voice max, and whether any params have changed
-                (
-                    wave3_l,
-                    wave3_r,
-                    reset_filter_controller3,
-                    note_off_filter_controller3,
-                ) = am3_lock.process(
-                    sample_id,
-                    midi_event.clone(),
-                    sent_voice_max,
-                    modulations_1.temp_mod_detune_3
-                        + modulations_2.temp_mod_detune_3
-                        + modulations_3.temp_mod_detune_3
-                        + modulations_4.temp_mod_detune_3,
-                    modulations_1.temp_mod_uni_detune_3
-                        + modulations_2.temp_mod_uni_detune_3
-                        + modulations_3.temp_mod_uni_detune_3
-                        + modulations_4.temp_mod_uni_detune_3,
-                    temp_mod_vel_sum,
-                    temp_mod_uni_vel_sum,
-                    temp_mod_gain_3,
-                    temp_mod_lfo_gain_3,
-                    self.params.stereo_algorithm.value(),
-                    modulations_1.temp_mod_resonance_1
-                        + modulations_2.temp_mod_resonance_1
-                        + modulations_3.temp_mod_resonance_1
-                        + modulations_4.temp_mod_resonance_1,
-                    modulations_1.temp_mod_cutoff_1
-                        + modulations_2.temp_mod_cutoff_1
-                        + modulations_3.temp_mod_cutoff_1
-                        + modulations_4.temp_mod_cutoff_1,
-                    modulations_1.temp_mod_resonance_2
-                        + modulations_2.temp_mod_resonance_2
-                        + modulations_3.temp_mod_resonance_2
-                        + modulations_4.temp_mod_resonance_2,
-                    modulations_1.temp_mod_cutoff_2
-                        + modulations_2.temp_mod_cutoff_2
-                        + modulations_3.temp_mod_cutoff_2
-                        + modulations_4.temp_mod_cutoff_2,
-                );
-                // I know this isn't a perfect 3rd, but 0.01 is acceptable headroom
-                let levelAmp3 = self.params.audio_module_3_level.value();
-                wave3_l *= levelAmp3 * 0.33;
-                wave3_r *= levelAmp3 * 0.33;
+        // Matrix detune can be snapped to steps so S&H and LFOs play notes instead of sliding
+        let mut pitch_mod_detune_1 = modulations_1.temp_mod_detune_1
+            + modulations_2.temp_mod_detune_1
+            + modulations_3.temp_mod_detune_1
+            + modulations_4.temp_mod_detune_1;
+        let mut pitch_mod_detune_2 = modulations_1.temp_mod_detune_2
+            + modulations_2.temp_mod_detune_2
+            + modulations_3.temp_mod_detune_2
+            + modulations_4.temp_mod_detune_2;
+        let mut pitch_mod_detune_3 = modulations_1.temp_mod_detune_3
+            + modulations_2.temp_mod_detune_3
+            + modulations_3.temp_mod_detune_3
+            + modulations_4.temp_mod_detune_3;
+        if self.params.pitch_mod_quantize.value() {
+            let scale = self.params.pitch_mod_scale.value();
+            pitch_mod_detune_1 = quantize_pitch_mod(pitch_mod_detune_1, scale);
+            pitch_mod_detune_2 = quantize_pitch_mod(pitch_mod_detune_2, scale);
+            pitch_mod_detune_3 = quantize_pitch_mod(pitch_mod_detune_3, scale);
+        }
+
+        // Audio Module Processing of Audio kicks off here
+        /////////////////////////////////////////////////////////////////////////////////////////////////
+
+        // Humanize and glide only land on the voices they belong to, see NotePitch
+        let note_pitch = NotePitch {
+            humanize: self.humanize_detune,
+            glide: self.glide_current,
+        };
+        let mut fm_wave_1: f32 = 0.0;
+        let mut fm_wave_2: f32 = 0.0;
+        // Mute and solo only silence a module's output, it still feeds FM
+        let module_mix = module_mix_gains(
+            [self.params.audio_module_1_mute.value(), self.params.audio_module_2_mute.value(), self.params.audio_module_3_mute.value()],
+            [self.params.audio_module_1_solo.value(), self.params.audio_module_2_solo.value(), self.params.audio_module_3_solo.value()],
+        );
+        // Since File Dialog can be set by any of these we need to check each time
+        if !self.file_dialog.load(Ordering::SeqCst)
+            //&& self.params.audio_module_1_type.value() != AudioModuleType::Off
+            && am1_lock.audio_module_type != AudioModuleType::Off
+        {
+            // We send our sample_id position, params, current midi event, module index, current voice max, and whether any params have changed
+            (
+                wave1_l,
+                wave1_r,
+                reset_filter_controller1,
+                note_off_filter_controller1,
+            ) = am1_lock.process(
+                sample_id,
+                midi_event.clone(),
+                sent_voice_max,
+                pitch_mod_detune_1,
+                note_pitch,
+                modulations_1.temp_mod_uni_detune_1
+                    + modulations_2.temp_mod_uni_detune_1
+                    + modulations_3.temp_mod_uni_detune_1
+                    + modulations_4.temp_mod_uni_detune_1,
+                temp_mod_vel_sum,
+                temp_mod_uni_vel_sum,
+                temp_mod_gain_1,
+                temp_mod_lfo_gain_1,
+                self.params.stereo_algorithm.value(),
+                modulations_1.temp_mod_resonance_1
+                    + modulations_2.temp_mod_resonance_1
+                    + modulations_3.temp_mod_resonance_1
+                    + modulations_4.temp_mod_resonance_1
+                    + modulations_5.temp_mod_resonance_1
+                    + modulations_6.temp_mod_resonance_1
+                    + modulations_7.temp_mod_resonance_1
+                    + modulations_8.temp_mod_resonance_1,
+                modulations_1.temp_mod_cutoff_1
+                    + modulations_2.temp_mod_cutoff_1
+                    + modulations_3.temp_mod_cutoff_1
+                    + modulations_4.temp_mod_cutoff_1
+                    + modulations_5.temp_mod_cutoff_1
+                    + modulations_6.temp_mod_cutoff_1
+                    + modulations_7.temp_mod_cutoff_1
+                    + modulations_8.temp_mod_cutoff_1,
+                modulations_1.temp_mod_resonance_2
+                    + modulations_2.temp_mod_resonance_2
+                    + modulations_3.temp_mod_resonance_2
+                    + modulations_4.temp_mod_resonance_2
+                    + modulations_5.temp_mod_resonance_2
+                    + modulations_6.temp_mod_resonance_2
+                    + modulations_7.temp_mod_resonance_2
+                    + modulations_8.temp_mod_resonance_2,
+                modulations_1.temp_mod_cutoff_2
+                    + modulations_2.temp_mod_cutoff_2
+                    + modulations_3.temp_mod_cutoff_2
+                    + modulations_4.temp_mod_cutoff_2
+                    + modulations_5.temp_mod_cutoff_2
+                    + modulations_6.temp_mod_cutoff_2
+                    + modulations_7.temp_mod_cutoff_2
+                    + modulations_8.temp_mod_cutoff_2,
+                temp_mod_drive_1,
+                temp_mod_drive_2,
+                temp_mod_morph_1,
+                temp_mod_shape_1,
+                filter_loop_retrigger,
+            );
+            // Sum to MONO
+            fm_wave_1 = (wave1_l + wave1_r)/2.0;
+            // I know this isn't a perfect 3rd, but 0.01 is acceptable headroom
+            let mut levelAmp1 = self.params.audio_module_1_level.value() * module_mix[0];
+            // Flipped after the FM tap so inverting a layer never changes what it modulates
+            if self.params.audio_module_1_phase_invert.value() {
+                levelAmp1 = -levelAmp1;
             }
+            wave1_l *= levelAmp1 * 0.33;
+            wave1_r *= levelAmp1 * 0.33;
+        }
 
-            // FM Calculations
-            let one_to_two = self.params.fm_one_to_two.value();
-            let one_to_three = self.params.fm_one_to_three.value();
-            let two_to_three = self.params.fm_two_to_three.value();
+        // Since File Dialog can be set by any of these we need to check each time
+        if !self.file_dialog.load(Ordering::SeqCst)
+            //&& self.params.audio_module_1_type.value() != AudioModuleType::Off
+            && am2_lock.audio_module_type != AudioModuleType::Off
+        {
+            // We send our sample_id position, params, current midi event, module index, current voice max, and whether any params have changed
+            (
+                wave2_l,
+                wave2_r,
+                reset_filter_controller2,
+                note_off_filter_controller2,
+            ) = am2_lock.process(
+                sample_id,
+                midi_event.clone(),
+                sent_voice_max,
+                pitch_mod_detune_2,
+                note_pitch,
+                modulations_1.temp_mod_uni_detune_2
+                    + modulations_2.temp_mod_uni_detune_2
+                    + modulations_3.temp_mod_uni_detune_2
+                    + modulations_4.temp_mod_uni_detune_2,
+                temp_mod_vel_sum,
+                temp_mod_uni_vel_sum,
+                temp_mod_gain_2,
+                temp_mod_lfo_gain_2,
+                self.params.stereo_algorithm.value(),
+                modulations_1.temp_mod_resonance_1
+                    + modulations_2.temp_mod_resonance_1
+                    + modulations_3.temp_mod_resonance_1
+                    + modulations_4.temp_mod_resonance_1
+                    + modulations_5.temp_mod_resonance_1
+                    + modulations_6.temp_mod_resonance_1
+                    + modulations_7.temp_mod_resonance_1
+                    + modulations_8.temp_mod_resonance_1,
+                modulations_1.temp_mod_cutoff_1
+                    + modulations_2.temp_mod_cutoff_1
+                    + modulations_3.temp_mod_cutoff_1
+                    + modulations_4.temp_mod_cutoff_1
+                    + modulations_5.temp_mod_cutoff_1
+                    + modulations_6.temp_mod_cutoff_1
+                    + modulations_7.temp_mod_cutoff_1
+                    + modulations_8.temp_mod_cutoff_1,
+                modulations_1.temp_mod_resonance_2
+                    + modulations_2.temp_mod_resonance_2
+                    + modulations_3.temp_mod_resonance_2
+                    + modulations_4.temp_mod_resonance_2
+                    + modulations_5.temp_mod_resonance_2
+                    + modulations_6.temp_mod_resonance_2
+                    + modulations_7.temp_mod_resonance_2
+                    + modulations_8.temp_mod_resonance_2,
+                modulations_1.temp_mod_cutoff_2
+                    + modulations_2.temp_mod_cutoff_2
+                    + modulations_3.temp_mod_cutoff_2
+                    + modulations_4.temp_mod_cutoff_2
+                    + modulations_5.temp_mod_cutoff_2
+                    + modulations_6.temp_mod_cutoff_2
+                    + modulations_7.temp_mod_cutoff_2
+                    + modulations_8.temp_mod_cutoff_2,
+                temp_mod_drive_1,
+                temp_mod_drive_2,
+                temp_mod_morph_2,
+                temp_mod_shape_2,
+                filter_loop_retrigger,
+            );
+            // Sum to MONO
+            fm_wave_2 = (wave2_l + wave2_r)/2.0;
+            // I know this isn't a perfect 3rd, but 0.01 is acceptable headroom
+            let mut levelAmp2 = self.params.audio_module_2_level.value() * module_mix[1];
+            // Flipped after the FM tap so inverting a layer never changes what it modulates
+            if self.params.audio_module_2_phase_invert.value() {
+                levelAmp2 = -levelAmp2;
+            }
+            wave2_l *= levelAmp2 * 0.33;
+            wave2_r *= levelAmp2 * 0.33;
+        }
 
-            // If a note is ending and we should enter releasing
-            if note_off_filter_controller1
-                || note_off_filter_controller2
-                || note_off_filter_controller3
-            {
-                self.fm_state = OscState::Releasing;
-                self.fm_rel_smoother_1 = match self.params.fm_release_curve.value() {
-                    SmoothStyle::Linear => Smoother::new(SmoothingStyle::Linear(
-                        self.params.fm_release.value(),
-                    )),
-                    SmoothStyle::Logarithmic => Smoother::new(SmoothingStyle::Logarithmic(
-                        self.params.fm_release.value(),
-                    )),
-                    SmoothStyle::Exponential => Smoother::new(SmoothingStyle::Exponential(
-                        self.params.fm_release.value(),
-                    )),
-                    SmoothStyle::LogSteep => Smoother::new(SmoothingStyle::LogSteep(
-                        self.params.fm_release.value(),
-                    )),
-                };
-                self.fm_rel_smoother_2 = self.fm_rel_smoother_1.clone();
-                self.fm_rel_smoother_3 = self.fm_rel_smoother_1.clone();
-                // Reset our filter release to be at sustain level to start
-                self.fm_rel_smoother_1.reset(
-                    self.params.fm_one_to_two.value() * (self.params.fm_sustain.value() / 1999.9),
+        // Since File Dialog can be set by any of these we need to check each time
+        if !self.file_dialog.load(Ordering::SeqCst)
+            //&& self.params.audio_module_1_type.value() != AudioModuleType::Off
+            && am3_lock.audio_module_type != AudioModuleType::Off
+        {
+            // We send our sample_id position, params, current midi event, module index, current voice max, and whether any params have changed
+            (
+                wave3_l,
+                wave3_r,
+                reset_filter_controller3,
+                note_off_filter_controller3,
+            ) = am3_lock.process(
+                sample_id,
+                midi_event.clone(),
+                sent_voice_max,
+                pitch_mod_detune_3,
+                note_pitch,
+                modulations_1.temp_mod_uni_detune_3
+                    + modulations_2.temp_mod_uni_detune_3
+                    + modulations_3.temp_mod_uni_detune_3
+                    + modulations_4.temp_mod_uni_detune_3,
+                temp_mod_vel_sum,
+                temp_mod_uni_vel_sum,
+                temp_mod_gain_3,
+                temp_mod_lfo_gain_3,
+                self.params.stereo_algorithm.value(),
+                modulations_1.temp_mod_resonance_1
+                    + modulations_2.temp_mod_resonance_1
+                    + modulations_3.temp_mod_resonance_1
+                    + modulations_4.temp_mod_resonance_1
+                    + modulations_5.temp_mod_resonance_1
+                    + modulations_6.temp_mod_resonance_1
+                    + modulations_7.temp_mod_resonance_1
+                    + modulations_8.temp_mod_resonance_1,
+                modulations_1.temp_mod_cutoff_1
+                    + modulations_2.temp_mod_cutoff_1
+                    + modulations_3.temp_mod_cutoff_1
+                    + modulations_4.temp_mod_cutoff_1
+                    + modulations_5.temp_mod_cutoff_1
+                    + modulations_6.temp_mod_cutoff_1
+                    + modulations_7.temp_mod_cutoff_1
+                    + modulations_8.temp_mod_cutoff_1,
+                modulations_1.temp_mod_resonance_2
+                    + modulations_2.temp_mod_resonance_2
+                    + modulations_3.temp_mod_resonance_2
+                    + modulations_4.temp_mod_resonance_2
+                    + modulations_5.temp_mod_resonance_2
+                    + modulations_6.temp_mod_resonance_2
+                    + modulations_7.temp_mod_resonance_2
+                    + modulations_8.temp_mod_resonance_2,
+                modulations_1.temp_mod_cutoff_2
+                    + modulations_2.temp_mod_cutoff_2
+                    + modulations_3.temp_mod_cutoff_2
+                    + modulations_4.temp_mod_cutoff_2
+                    + modulations_5.temp_mod_cutoff_2
+                    + modulations_6.temp_mod_cutoff_2
+                    + modulations_7.temp_mod_cutoff_2
+                    + modulations_8.temp_mod_cutoff_2,
+                temp_mod_drive_1,
+                temp_mod_drive_2,
+                temp_mod_morph_3,
+                temp_mod_shape_3,
+                filter_loop_retrigger,
+            );
+            // I know this isn't a perfect 3rd, but 0.01 is acceptable headroom
+            let mut levelAmp3 = self.params.audio_module_3_level.value() * module_mix[2];
+            // Flipped after the FM tap so inverting a layer never changes what it modulates
+            if self.params.audio_module_3_phase_invert.value() {
+                levelAmp3 = -levelAmp3;
+            }
+            wave3_l *= levelAmp3 * 0.33;
+            wave3_r *= levelAmp3 * 0.33;
+        }
+
+        // FM Calculations
+        let one_to_two = self.params.fm_one_to_two.value();
+        let one_to_three = self.params.fm_one_to_three.value();
+        let two_to_three = self.params.fm_two_to_three.value();
+
+        // If a note is ending and we should enter releasing
+        if note_off_filter_controller1
+            || note_off_filter_controller2
+            || note_off_filter_controller3
+        {
+            self.fm_state = OscState::Releasing;
+            self.fm_rel_smoother_1 = match self.params.fm_release_curve.value() {
+                SmoothStyle::Linear => Smoother::new(SmoothingStyle::Linear(
+                    self.params.fm_release.value(),
+                )),
+                SmoothStyle::Logarithmic => Smoother::new(SmoothingStyle::Logarithmic(
+                    self.params.fm_release.value(),
+                )),
+                SmoothStyle::Exponential => Smoother::new(SmoothingStyle::Exponential(
+                    self.params.fm_release.value(),
+                )),
+                SmoothStyle::LogSteep => Smoother::new(SmoothingStyle::LogSteep(
+                    self.params.fm_release.value(),
+                )),
+            };
+            self.fm_rel_smoother_2 = self.fm_rel_smoother_1.clone();
+            self.fm_rel_smoother_3 = self.fm_rel_smoother_1.clone();
+            // Reset our filter release to be at sustain level to start
+            self.fm_rel_smoother_1.reset(
+                self.params.fm_one_to_two.value() * (self.params.fm_sustain.value() / 1999.9),
+            );
+            self.fm_rel_smoother_2.reset(
+                self.params.fm_one_to_three.value() * (self.params.fm_sustain.value() / 1999.9),
+            );
+            self.fm_rel_smoother_3.reset(
+                self.params.fm_two_to_three.value() * (self.params.fm_sustain.value() / 1999.9),
+            );
+            // Move release to the cutoff to end
+            self.fm_rel_smoother_1
+                .set_target(self.sample_rate, self.params.fm_one_to_two.value());
+            self.fm_rel_smoother_2
+                .set_target(self.sample_rate, self.params.fm_one_to_three.value());
+            self.fm_rel_smoother_3
+                .set_target(self.sample_rate, self.params.fm_two_to_three.value());
+        }
+        // Try to trigger our filter mods on note on! This is sequential/single because we just need a trigger at a point in time
+        if reset_filter_controller1 || reset_filter_controller2 || reset_filter_controller3 {
+            // Set our filter in attack state
+            self.fm_state = OscState::Attacking;
+            // Consume our params for smoothing
+            self.fm_atk_smoother_1 = match self.params.fm_attack_curve.value() {
+                SmoothStyle::Linear => Smoother::new(SmoothingStyle::Linear(
+                    self.params.fm_attack.value(),
+                )),
+                SmoothStyle::Logarithmic => Smoother::new(SmoothingStyle::Logarithmic(
+                    self.params.fm_attack.value(),
+                )),
+                SmoothStyle::Exponential => Smoother::new(SmoothingStyle::Exponential(
+                    self.params.fm_attack.value(),
+                )),
+                SmoothStyle::LogSteep => Smoother::new(SmoothingStyle::LogSteep(
+                    self.params.fm_attack.value(),
+                )),
+            };
+            self.fm_atk_smoother_2 = self.fm_atk_smoother_1.clone();
+            self.fm_atk_smoother_3 = self.fm_atk_smoother_1.clone();
+            // Reset our attack to start from 0.0
+            if self.params.fm_attack_curve.value() == SmoothStyle::Linear {
+                self.fm_atk_smoother_1.reset(0.0);
+                self.fm_atk_smoother_2.reset(0.0);
+                self.fm_atk_smoother_3.reset(0.0);
+            } else {
+                self.fm_atk_smoother_1.reset(0.0001);
+                self.fm_atk_smoother_2.reset(0.0001);
+                self.fm_atk_smoother_3.reset(0.0001);
+            }
+            // Since we're in attack state at the start of our note we need to setup the attack going to the env peak
+            self.fm_atk_smoother_1.set_target(
+                self.sample_rate, self.params.fm_one_to_two.value()
+            );
+            self.fm_atk_smoother_2.set_target(
+                self.sample_rate, self.params.fm_one_to_three.value()
+            );
+            self.fm_atk_smoother_3.set_target(
+                self.sample_rate, self.params.fm_two_to_three.value()
+            );
+        }
+        // If our attack has finished
+        if self.fm_atk_smoother_1.steps_left() == 0
+            && self.fm_state == OscState::Attacking
+        {
+            self.fm_state = OscState::Decaying;
+            self.fm_dec_smoother_1 = match self.params.fm_decay_curve.value() {
+                SmoothStyle::Linear => Smoother::new(SmoothingStyle::Linear(
+                    self.params.fm_decay.value()
+                )),
+                SmoothStyle::Logarithmic => Smoother::new(SmoothingStyle::Logarithmic(
+                    self.params.fm_decay.value(),
+                )),
+                SmoothStyle::Exponential => Smoother::new(SmoothingStyle::Exponential(
+                    self.params.fm_decay.value(),
+                )),
+                SmoothStyle::LogSteep => Smoother::new(SmoothingStyle::LogSteep(
+                    self.params.fm_decay.value(),
+                )),
+            };
+            self.fm_dec_smoother_2 = self.fm_dec_smoother_1.clone();
+            self.fm_dec_smoother_3 = self.fm_dec_smoother_1.clone();
+            // This makes our fm decay start at env peak point
+            self.fm_dec_smoother_1.reset(self.params.fm_one_to_two.value());
+            self.fm_dec_smoother_2.reset(self.params.fm_one_to_three.value());
+            self.fm_dec_smoother_3.reset(self.params.fm_two_to_three.value());
+            // Set up the smoother for our filter movement to go from our decay point to our sustain point
+            self.fm_dec_smoother_1.set_target(
+                self.sample_rate,
+                self.params.fm_sustain.value() / 1999.9,
+            );
+            self.fm_dec_smoother_2.set_target(
+                self.sample_rate,
+                self.params.fm_sustain.value() / 1999.9,
+            );
+            self.fm_dec_smoother_3.set_target(
+                self.sample_rate,
+                self.params.fm_sustain.value() / 1999.9,
+            );
+        }
+        // If our decay has finished move to sustain state
+        if self.fm_dec_smoother_1.steps_left() == 0
+            && self.fm_state == OscState::Decaying
+        {
+            self.fm_state = OscState::Sustaining;
+        }
+        let next_fm_step_1 = match self.fm_state {
+            OscState::Attacking => {
+                self.fm_atk_smoother_1.next()
+            },
+            OscState::Decaying | OscState::Sustaining => {
+                self.fm_dec_smoother_1.next()
+            },
+            OscState::Releasing => {
+                self.fm_rel_smoother_1.next()
+            },
+            OscState::Off => {0.0},
+        };
+        let next_fm_step_2 = match self.fm_state {
+            OscState::Attacking => {
+                self.fm_atk_smoother_2.next()
+            },
+            OscState::Decaying | OscState::Sustaining => {
+                self.fm_dec_smoother_2.next()
+            },
+            OscState::Releasing => {
+                self.fm_rel_smoother_2.next()
+            },
+            OscState::Off => {0.0},
+        };
+        let next_fm_step_3 = match self.fm_state {
+            OscState::Attacking => {
+                self.fm_atk_smoother_3.next()
+            },
+            OscState::Decaying | OscState::Sustaining => {
+                self.fm_dec_smoother_3.next()
+            },
+            OscState::Releasing => {
+                self.fm_rel_smoother_3.next()
+            },
+            OscState::Off => {0.0},
+        };
+        let current_cycles = self.params.fm_cycles.value();
+        if one_to_two > 0.0 {
+            match current_cycles {
+                1 => {
+                    wave2_l = frequency_modulation::frequency_modulation(fm_wave_1, wave2_l, next_fm_step_1);
+                    wave2_r = frequency_modulation::frequency_modulation(fm_wave_1, wave2_r, next_fm_step_1);
+                },
+                2 => {
+                    wave2_l = frequency_modulation::double_modulation(fm_wave_1, wave2_l, next_fm_step_1);
+                    wave2_r = frequency_modulation::double_modulation(fm_wave_1, wave2_r, next_fm_step_1);
+                },
+                3 => {
+                    wave2_l = frequency_modulation::triple_modulation(fm_wave_1, wave2_l, next_fm_step_1);
+                    wave2_r = frequency_modulation::triple_modulation(fm_wave_1, wave2_r, next_fm_step_1);
+                },
+                _ => {}
+            }
+        }
+        if one_to_three > 0.0 {
+            match current_cycles {
+                1 => {
+                    wave3_l = frequency_modulation::frequency_modulation(fm_wave_1, wave3_l, next_fm_step_2);
+                    wave3_r = frequency_modulation::frequency_modulation(fm_wave_1, wave3_r, next_fm_step_2);
+                },
+                2 => {
+                    wave3_l = frequency_modulation::double_modulation(fm_wave_1, wave3_l, next_fm_step_2);
+                    wave3_r = frequency_modulation::double_modulation(fm_wave_1, wave3_r, next_fm_step_2);
+                },
+                3 => {
+                    wave3_l = frequency_modulation::triple_modulation(fm_wave_1, wave3_l, next_fm_step_2);
+                    wave3_r = frequency_modulation::triple_modulation(fm_wave_1, wave3_r, next_fm_step_2);
+                },
+                _ => {}
+            }
+        }
+        if two_to_three > 0.0 {
+            match current_cycles {
+                1 => {
+                    wave3_l = frequency_modulation::frequency_modulation(fm_wave_2, wave3_l, next_fm_step_3);
+                    wave3_r = frequency_modulation::frequency_modulation(fm_wave_2, wave3_r, next_fm_step_3);
+                },
+                2 => {
+                    wave3_l = frequency_modulation::double_modulation(fm_wave_2, wave3_l, next_fm_step_3);
+                    wave3_r = frequency_modulation::double_modulation(fm_wave_2, wave3_r, next_fm_step_3);
+                },
+                3 => {
+                    wave3_l = frequency_modulation::triple_modulation(fm_wave_2, wave3_l, next_fm_step_3);
+                    wave3_r = frequency_modulation::triple_modulation(fm_wave_2, wave3_r, next_fm_step_3);
+                },
+                _ => {}
+            }
+        }
+
+        // Ring Modulation - uses the same mono sums as FM but multiplies amplitudes instead
+        let ring_1x2 = self.params.ring_mod_1x2.value();
+        let ring_2x3 = self.params.ring_mod_2x3.value();
+        if ring_1x2 > 0.0 {
+            wave2_l = frequency_modulation::ring_modulation(fm_wave_1, wave2_l, ring_1x2);
+            wave2_r = frequency_modulation::ring_modulation(fm_wave_1, wave2_r, ring_1x2);
+        }
+        if ring_2x3 > 0.0 {
+            wave3_l = frequency_modulation::ring_modulation(fm_wave_2, wave3_l, ring_2x3);
+            wave3_r = frequency_modulation::ring_modulation(fm_wave_2, wave3_r, ring_2x3);
+        }
+
+        /////////////////////////////////////////////////////////////////////////////////////////////////
+        // Audio Module Processing over
+
+        // If a new note has happened we should reset the phase of our LFO if sync enabled
+        // One shot LFOs always restart on a note since they are acting as an envelope
+        if reset_filter_controller1 || reset_filter_controller2 || reset_filter_controller3 {
+            if self.params.lfo1_sync.value() || self.params.lfo1_retrigger.value() == LFOController::LFORetrigger::OneShot {
+                self.lfo_1.set_phase(self.params.lfo1_phase.value());
+            }
+            if self.params.lfo2_sync.value() || self.params.lfo2_retrigger.value() == LFOController::LFORetrigger::OneShot {
+                self.lfo_2.set_phase(self.params.lfo2_phase.value());
+            }
+            if self.params.lfo3_sync.value() || self.params.lfo3_retrigger.value() == LFOController::LFORetrigger::OneShot {
+                self.lfo_3.set_phase(self.params.lfo3_phase.value());
+            }
+        }
+
+        // Gated LFOs hold their value while nothing is sounding so modulation picks up where it left off
+        // Precedence: a new note applies Sync/Retrig phase resets first, then the gate decides if the phase moves
+        let voices_active = am1_lock.has_active_voices() || am2_lock.has_active_voices() || am3_lock.has_active_voices();
+        self.lfo_1.set_frozen(self.params.lfo1_gate.value() && !voices_active);
+        self.lfo_2.set_frozen(self.params.lfo2_gate.value() && !voices_active);
+        self.lfo_3.set_frozen(self.params.lfo3_gate.value() && !voices_active);
+
+        // Get our new LFO values
+        if self.params.lfo1_enable.value() {
+            lfo_1_current = self.lfo_1.next_sample(self.sample_rate);
+            self.lfo_values[0].store(lfo_1_current, Ordering::Relaxed);
+        }
+        if self.params.lfo2_enable.value() {
+            lfo_2_current = self.lfo_2.next_sample(self.sample_rate);
+            self.lfo_values[1].store(lfo_2_current, Ordering::Relaxed);
+        }
+        if self.params.lfo3_enable.value() {
+            lfo_3_current = self.lfo_3.next_sample(self.sample_rate);
+            self.lfo_values[2].store(lfo_3_current, Ordering::Relaxed);
+        }
+
+        // Define the outputs
+        let mut left_output: f32;
+        let mut right_output: f32;
+
+        // Generator meters take each module's share of the mix, instant attack and the output meter's release
+        for (hold, peak) in self.module_hold.iter_mut().zip([
+            wave1_l.abs().max(wave1_r.abs()),
+            wave2_l.abs().max(wave2_r.abs()),
+            wave3_l.abs().max(wave3_r.abs()),
+        ]) {
+            *hold = (peak * 0.33).max(*hold * meter_decay);
+        }
+
+        left_output = (wave1_l + wave2_l + wave3_l)*0.33 + input_l;
+        right_output = (wave1_r + wave2_r + wave3_r)*0.33 + input_r;
+
+        // FX
+        ////////////////////////////////////////////////////////////////////////////////////////
+        if self.params.use_fx.value() {
+            // Equalizer use
+            if self.fx_bypass.eq.advance(self.params.pre_use_eq.value(), self.sample_rate) {
+                let dry = (left_output, right_output);
+                let eq_ref = self.bands.clone();
+                let mut eq = eq_ref.lock().unwrap();
+                eq[0].set_type(FilterType::LowShelf);
+                eq[1].set_type(FilterType::Peak);
+                eq[2].set_type(FilterType::HighShelf);
+                let q_value: f32 = 0.93;
+                eq[0].update(
+                    self.sample_rate,
+                    self.params.pre_low_freq.value(),
+                    self.params.pre_low_gain.value(),
+                    q_value,
+                );
+                eq[1].update(
+                    self.sample_rate,
+                    self.params.pre_mid_freq.value(),
+                    self.params.pre_mid_gain.value(),
+                    q_value,
                 );
-                self.fm_rel_smoother_2.reset(
-                    self.params.fm_one_to_three.value() * (self.params.fm_sustain.value() / 1999.9),
+                eq[2].update(
+                    self.sample_rate,
+                    self.params.pre_high_freq.value(),
+                    self.params.pre_high_gain.value(),
+                    q_value,
                 );
-                self.fm_rel_smoother_3.reset(
-                    self.params.fm_two_to_three.value() * (self.params.fm_sustain.value() / 1999.9),
+
+                let mut temp_l: f32;
+                let mut temp_r: f32;
+                // This is the first time we run a filter at all
+                (temp_l, temp_r) = eq[0].process_sample(left_output, right_output);
+                (temp_l, temp_r) = eq[1].process_sample(temp_l, temp_r);
+                (temp_l, temp_r) = eq[2].process_sample(temp_l, temp_r);
+                // Reassign our new output
+                left_output = temp_l;
+                right_output = temp_r;
+                (left_output, right_output) = self.fx_bypass.eq.mix(dry, (left_output, right_output));
+            }
+            // Transient Shaper
+            if self.fx_bypass.transient.advance(self.params.use_transient.value(), self.sample_rate) {
+                let dry = (left_output, right_output);
+                self.transient_shaper.update(
+                    self.sample_rate,
+                    self.params.transient_attack.value(),
+                    self.params.transient_sustain.value(),
                 );
-                // Move release to the cutoff to end
-                self.fm_rel_smoother_1
-                    .set_target(self.sample_rate, self.params.fm_one_to_two.value());
-                self.fm_rel_smoother_2
-                    .set_target(self.sample_rate, self.params.fm_one_to_three.value());
-                self.fm_rel_smoother_3
-                    .set_target(self.sample_rate, self.params.fm_two_to_three.value());
+                (left_output, right_output) =
+                    self.transient_shaper.process(left_output, right_output);
+                (left_output, right_output) = self.fx_bypass.transient.mix(dry, (left_output, right_output));
             }
-            // Try to trigger our filter mods on note on! This is sequential/single because we just need a trigger at a point in time
-            if reset_filter_controller1 || reset_filter_controller2 || reset_filter_controller3 {
-                // Set our filter in attack state
-                self.fm_state = OscState::Attacking;
-                // Consume our params for smoothing
-                self.fm_atk_smoother_1 = match self.params.fm_attack_curve.value() {
-                    SmoothStyle::Linear => Smoother::new(SmoothingStyle::Linear(
-                        self.params.fm_attack.value(),
-                    )),
-                    SmoothStyle::Logarithmic => Smoother::new(SmoothingStyle::Logarithmic(
-                        self.params.fm_attack.value(),
-                    )),
-                    SmoothStyle::Exponential => Smoother::new(SmoothingStyle::Exponential(
-                        self.params.fm_attack.value(),
-                    )),
-                    SmoothStyle::LogSteep => Smoother::new(SmoothingStyle::LogSteep(
-                        self.params.fm_attack.value(),
-                    )),
-                };
-                self.fm_atk_smoother_2 = self.fm_atk_smoother_1.clone();
-                self.fm_atk_smoother_3 = self.fm_atk_smoother_1.clone();
-                // Reset our attack to start from 0.0
-                if self.params.fm_attack_curve.value() == SmoothStyle::Linear {
-                    self.fm_atk_smoother_1.reset(0.0);
-                    self.fm_atk_smoother_2.reset(0.0);
-                    self.fm_atk_smoother_3.reset(0.0);
-                } else {
-                    self.fm_atk_smoother_1.reset(0.0001);
-                    self.fm_atk_smoother_2.reset(0.0001);
-                    self.fm_atk_smoother_3.reset(0.0001);
+            // Compressor
+            if self.fx_bypass.compressor.advance(self.params.use_compressor.value(), self.sample_rate) {
+                let dry = (left_output, right_output);
+                self.compressor.update(
+                    self.sample_rate,
+                    self.params.comp_amt.value(),
+                    self.params.comp_atk.value(),
+                    self.params.comp_rel.value(),
+                    self.params.comp_drive.value(),
+                );
+                (left_output, right_output) =
+                    self.compressor.process(left_output, right_output);
+                (left_output, right_output) = self.fx_bypass.compressor.mix(dry, (left_output, right_output));
+            }
+            // ABass Algorithm
+            // Both saturation stages can work on the mid or side only to keep the stereo image clean
+            let sat_stereo_mode = self.params.sat_stereo_mode.value();
+            // The ramps advance at the base rate, the stages themselves run oversampled
+            let use_abass = self.fx_bypass.abass.advance(self.params.use_abass.value(), self.sample_rate);
+            let use_saturation = self.fx_bypass.saturation.advance(self.params.use_saturation.value(), self.sample_rate);
+            (left_output, right_output) = self.sat_oversampler.process(left_output, right_output, self.params.output_oversample.value(), |mut left_output, mut right_output| {
+                if use_abass {
+                    let dry = (left_output, right_output);
+                    let abass_amount = self.params.abass_amount.value();
+                    match sat_stereo_mode {
+                        SaturationStereoMode::LR => {
+                            left_output = a_bass_saturation(left_output, abass_amount);
+                            right_output = a_bass_saturation(right_output, abass_amount);
+                        },
+                        SaturationStereoMode::Mid => {
+                            let (mid, side) = saturation::to_mid_side(left_output, right_output);
+                            (left_output, right_output) = saturation::from_mid_side(a_bass_saturation(mid, abass_amount), side);
+                        },
+                        SaturationStereoMode::Side => {
+                            let (mid, side) = saturation::to_mid_side(left_output, right_output);
+                            (left_output, right_output) = saturation::from_mid_side(mid, a_bass_saturation(side, abass_amount));
+                        },
+                    }
+                    (left_output, right_output) = self.fx_bypass.abass.mix(dry, (left_output, right_output));
+                }
+                // Distortion
+                if use_saturation {
+                    let dry = (left_output, right_output);
+                    self.saturator.set_type(self.params.sat_type.value());
+                    match sat_stereo_mode {
+                        SaturationStereoMode::LR => {
+                            (left_output, right_output) = self.saturator.process(
+                                left_output,
+                                right_output,
+                                self.params.sat_amt.value(),
+                            );
+                        },
+                        SaturationStereoMode::Mid => {
+                            let (mid, side) = saturation::to_mid_side(left_output, right_output);
+                            let (sat_mid, _) = self.saturator.process(mid, mid, self.params.sat_amt.value());
+                            (left_output, right_output) = saturation::from_mid_side(sat_mid, side);
+                        },
+                        SaturationStereoMode::Side => {
+                            let (mid, side) = saturation::to_mid_side(left_output, right_output);
+                            let (sat_side, _) = self.saturator.process(side, side, self.params.sat_amt.value());
+                            (left_output, right_output) = saturation::from_mid_side(mid, sat_side);
+                        },
+                    }
+                    (left_output, right_output) = self.fx_bypass.saturation.mix(dry, (left_output, right_output));
                 }
-                // Since we're in attack state at the start of our note we need to setup the attack going to the env peak
-                self.fm_atk_smoother_1.set_target(
-                    self.sample_rate, self.params.fm_one_to_two.value()
+                (left_output, right_output)
+            });
+            // Buffer Modulator
+            if self.fx_bypass.buffermod.advance(self.params.use_buffermod.value(), self.sample_rate) {
+                let dry = (left_output, right_output);
+                self.buffermod.update(
+                    self.sample_rate,
+                    self.params.buffermod_depth.value(),
+                    self.params.buffermod_rate.value(),
+                    self.params.buffermod_spread.value(),
+                    self.params.buffermod_timing.value(),
                 );
-                self.fm_atk_smoother_2.set_target(
-                    self.sample_rate, self.params.fm_one_to_three.value()
+                self.buffermod.set_stereo(self.params.buffermod_stereo.value());
+                (left_output, right_output) = self.buffermod.process(
+                    left_output,
+                    right_output,
+                    self.params.buffermod_amount.value(),
                 );
-                self.fm_atk_smoother_3.set_target(
-                    self.sample_rate, self.params.fm_two_to_three.value()
+                (left_output, right_output) = self.fx_bypass.buffermod.mix(dry, (left_output, right_output));
+            }
+            // Chorus
+            if self.fx_bypass.chorus.advance(self.params.use_chorus.value(), self.sample_rate) {
+                let dry = (left_output, right_output);
+                self.chorus.update(
+                    self.sample_rate, 
+                    self.params.chorus_range.value(), 
+                    self.params.chorus_speed.value(), 
+                    self.params.chorus_amount.value()
                 );
+                (left_output, right_output) = self.chorus.process(left_output, right_output);
+                (left_output, right_output) = self.fx_bypass.chorus.mix(dry, (left_output, right_output));
             }
-            // If our attack has finished
-            if self.fm_atk_smoother_1.steps_left() == 0
-                && self.fm_state == OscState::Attacking
-            {
-                self.fm_state = OscState::Decaying;
-                self.fm_dec_smoother_1 = match self.params.fm_decay_curve.value() {
-                    SmoothStyle::Linear => Smoother::new(SmoothingStyle::Linear(
-                        self.params.fm_decay.value()
-                    )),
-                    SmoothStyle::Logarithmic => Smoother::new(SmoothingStyle::Logarithmic(
-                        self.params.fm_decay.value(),
-                    )),
-                    SmoothStyle::Exponential => Smoother::new(SmoothingStyle::Exponential(
-                        self.params.fm_decay.value(),
-                    )),
-                    SmoothStyle::LogSteep => Smoother::new(SmoothingStyle::LogSteep(
-                        self.params.fm_decay.value(),
-                    )),
-                };
-                self.fm_dec_smoother_2 = self.fm_dec_smoother_1.clone();
-                self.fm_dec_smoother_3 = self.fm_dec_smoother_1.clone();
-                // This makes our fm decay start at env peak point
-                self.fm_dec_smoother_1.reset(self.params.fm_one_to_two.value());
-                self.fm_dec_smoother_2.reset(self.params.fm_one_to_three.value());
-                self.fm_dec_smoother_3.reset(self.params.fm_two_to_three.value());
-                // Set up the smoother for our filter movement to go from our decay point to our sustain point
-                self.fm_dec_smoother_1.set_target(
-                    self.sample_rate,
-                    self.params.fm_sustain.value() / 1999.9,
+            // Phaser
+            if self.fx_bypass.phaser.advance(self.params.use_phaser.value(), self.sample_rate) {
+                let dry = (left_output, right_output);
+                self.phaser.set_sample_rate(self.sample_rate);
+                self.phaser.set_depth(self.params.phaser_depth.value());
+                self.phaser.set_stereo(self.params.phaser_stereo.value());
+                self.phaser.set_rate(self.params.phaser_rate.value());
+                self.phaser
+                    .set_feedback(self.params.phaser_feedback.value());
+                (left_output, right_output) = self.phaser.process(
+                    left_output,
+                    right_output,
+                    self.params.phaser_amount.value(),
                 );
-                self.fm_dec_smoother_2.set_target(
+                (left_output, right_output) = self.fx_bypass.phaser.mix(dry, (left_output, right_output));
+            }
+            // Flanger
+            if self.fx_bypass.flanger.advance(self.params.use_flanger.value(), self.sample_rate) {
+                let dry = (left_output, right_output);
+                self.flanger.update(
                     self.sample_rate,
-                    self.params.fm_sustain.value() / 1999.9,
+                    self.params.flanger_depth.value(),
+                    self.params.flanger_rate.value(),
+                    self.params.flanger_feedback.value(),
                 );
-                self.fm_dec_smoother_3.set_target(
-                    self.sample_rate,
-                    self.params.fm_sustain.value() / 1999.9,
+                self.flanger.set_stereo(self.params.flanger_stereo.value());
+                (left_output, right_output) = self.flanger.process(
+                    left_output,
+                    right_output,
+                    self.params.flanger_amount.value(),
                 );
+                (left_output, right_output) = self.fx_bypass.flanger.mix(dry, (left_output, right_output));
             }
-            // If our decay has finished move to sustain state
-            if self.fm_dec_smoother_1.steps_left() == 0
-                && self.fm_state == OscState::Decaying
-            {
-                self.fm_state = OscState::Sustaining;
+            // What reaches the delays and reverb, Dry Kill takes this back out afterwards
+            let (send_l, send_r) = (left_output, right_output);
+            let mut send_dry_gain = 1.0;
+            // Delay
+            let use_delay = self.fx_bypass.delay.advance(self.params.use_delay.value(), self.sample_rate);
+            let use_delay_2 = self.fx_bypass.delay_2.advance(self.params.use_delay_2.value(), self.sample_rate);
+            if use_delay {
+                self.delay.set_sample_rate(self.sample_rate, bpm);
+                self.delay.set_length(self.params.delay_time.value());
+                self.delay.set_feedback(self.params.delay_decay.value());
+                self.delay.set_type(self.params.delay_type.value());
+                self.delay.set_pitch(self.params.delay_pitch.value());
             }
-            let next_fm_step_1 = match self.fm_state {
-                OscState::Attacking => {
-                    self.fm_atk_smoother_1.next()
-                },
-                OscState::Decaying | OscState::Sustaining => {
-                    self.fm_dec_smoother_1.next()
-                },
-                OscState::Releasing => {
-                    self.fm_rel_smoother_1.next()
-                },
-                OscState::Off => {0.0},
-            };
-            let next_fm_step_2 = match self.fm_state {
-                OscState::Attacking => {
-                    self.fm_atk_smoother_2.next()
-                },
-                OscState::Decaying | OscState::Sustaining => {
-                    self.fm_dec_smoother_2.next()
-                },
-                OscState::Releasing => {
-                    self.fm_rel_smoother_2.next()
-                },
-                OscState::Off => {0.0},
-            };
-            let next_fm_step_3 = match self.fm_state {
-                OscState::Attacking => {
-                    self.fm_atk_smoother_3.next()
-                },
-                OscState::Decaying | OscState::Sustaining => {
-                    self.fm_dec_smoother_3.next()
-                },
-                OscState::Releasing => {
-                    self.fm_rel_smoother_3.next()
-                },
-                OscState::Off => {0.0},
-            };
-            let current_cycles = self.params.fm_cycles.value();
-            if one_to_two > 0.0 {
-                match current_cycles {
-                    1 => {
-                        wave2_l = frequency_modulation::frequency_modulation(fm_wave_1, wave2_l, next_fm_step_1);
-                        wave2_r = frequency_modulation::frequency_modulation(fm_wave_1, wave2_r, next_fm_step_1);
-                    },
-                    2 => {
-                        wave2_l = frequency_modulation::double_modulation(fm_wave_1, wave2_l, next_fm_step_1);
-                        wave2_r = frequency_modulation::double_modulation(fm_wave_1, wave2_r, next_fm_step_1);
-                    },
-                    3 => {
-                        wave2_l = frequency_modulation::triple_modulation(fm_wave_1, wave2_l, next_fm_step_1);
-                        wave2_r = frequency_modulation::triple_modulation(fm_wave_1, wave2_r, next_fm_step_1);
-                    },
-                    _ => {}
-                }
+            if use_delay_2 {
+                self.delay_2.set_sample_rate(self.sample_rate, bpm);
+                self.delay_2.set_length(self.params.delay_2_time.value());
+                self.delay_2.set_feedback(self.params.delay_2_decay.value());
+                self.delay_2.set_type(self.params.delay_2_type.value());
             }
-            if one_to_three > 0.0 {
-                match current_cycles {
-                    1 => {
-                        wave3_l = frequency_modulation::frequency_modulation(fm_wave_1, wave3_l, next_fm_step_2);
-                        wave3_r = frequency_modulation::frequency_modulation(fm_wave_1, wave3_r, next_fm_step_2);
-                    },
-                    2 => {
-                        wave3_l = frequency_modulation::double_modulation(fm_wave_1, wave3_l, next_fm_step_2);
-                        wave3_r = frequency_modulation::double_modulation(fm_wave_1, wave3_r, next_fm_step_2);
-                    },
-                    3 => {
-                        wave3_l = frequency_modulation::triple_modulation(fm_wave_1, wave3_l, next_fm_step_2);
-                        wave3_r = frequency_modulation::triple_modulation(fm_wave_1, wave3_r, next_fm_step_2);
-                    },
-                    _ => {}
+            if use_delay && use_delay_2 && self.params.delay_routing.value() == DelayRouting::Parallel {
+                // Both delays hear the same dry signal and their wet parts are added on top of it
+                let (dry_l, dry_r) = (left_output, right_output);
+                let (wet_1_l, wet_1_r) = self.delay.process(dry_l, dry_r, 1.0);
+                let (wet_2_l, wet_2_r) = self.delay_2.process(dry_l, dry_r, 1.0);
+                let amount_1 = self.params.delay_amount.value() * self.fx_bypass.delay.amount();
+                let amount_2 = self.params.delay_2_amount.value() * self.fx_bypass.delay_2.amount();
+                left_output = dry_l + amount_1 * (wet_1_l - dry_l) + amount_2 * (wet_2_l - dry_l);
+                right_output = dry_r + amount_1 * (wet_1_r - dry_r) + amount_2 * (wet_2_r - dry_r);
+            } else {
+                if use_delay {
+                    let dry = (left_output, right_output);
+                    (left_output, right_output) = self.delay.process(
+                        left_output,
+                        right_output,
+                        self.params.delay_amount.value(),
+                    );
+                    (left_output, right_output) = self.fx_bypass.delay.mix(dry, (left_output, right_output));
+                }
+                if use_delay_2 {
+                    let dry = (left_output, right_output);
+                    (left_output, right_output) = self.delay_2.process(
+                        left_output,
+                        right_output,
+                        self.params.delay_2_amount.value(),
+                    );
+                    (left_output, right_output) = self.fx_bypass.delay_2.mix(dry, (left_output, right_output));
                 }
             }
-            if two_to_three > 0.0 {
-                match current_cycles {
-                    1 => {
-                        wave3_l = frequency_modulation::frequency_modulation(fm_wave_2, wave3_l, next_fm_step_3);
-                        wave3_r = frequency_modulation::frequency_modulation(fm_wave_2, wave3_r, next_fm_step_3);
+            // Reverb
+            if self.fx_bypass.reverb.advance(self.params.use_reverb.value(), self.sample_rate) {
+                let dry = (left_output, right_output);
+                // Eco mode falls back to the stacked TDLs, the lightest model
+                let reverb_model = if self.params.eco_mode.value() {
+                    ReverbModel::Default
+                } else {
+                    self.params.reverb_model.value()
+                };
+                // Pre-delay: the reverbs get a delayed copy while the dry transient stays on time
+                let dry_l = left_output;
+                let dry_r = right_output;
+                let predelay_samples = (self.params.reverb_predelay.value() * 0.001 * self.sample_rate).round() as usize;
+                if predelay_samples > 0 {
+                    (left_output, right_output) = self.reverb_predelay.process(left_output, right_output, predelay_samples);
+                }
+                let delayed_l = left_output;
+                let delayed_r = right_output;
+                match reverb_model {
+                    // Stacked TDLs to make reverb
+                    ReverbModel::Default => {
+                        // Diffusion bends the tap sizes toward the full size (sparse, flutter) or further apart (smeared)
+                        // The exponent is 1 at the middle so the default keeps the original sizes
+                        let diffusion = self.params.reverb_diffusion.value() * 2.0;
+                        for (verb, tap_scale) in self.reverb.iter_mut().zip(TDL_TAP_SCALES) {
+                            verb.set_size(self.params.reverb_size.value() * tap_scale.powf(diffusion), self.sample_rate);
+                        }
+                        for verb in self.reverb.iter_mut() {
+                            verb.set_feedback(self.params.reverb_feedback.value());
+                            (left_output, right_output) = verb.process_tdl(
+                                left_output,
+                                right_output,
+                                self.params.reverb_amount.value(),
+                                self.params.reverb_density.value());
+                        }
                     },
-                    2 => {
-                        wave3_l = frequency_modulation::double_modulation(fm_wave_2, wave3_l, next_fm_step_3);
-                        wave3_r = frequency_modulation::double_modulation(fm_wave_2, wave3_r, next_fm_step_3);
+                    ReverbModel::Galactic => {
+                        // AW Galactic modified
+                        self.galactic_reverb.update(
+                            self.sample_rate,
+                            self.params.reverb_size.value() / 2.0,
+                            self.params.reverb_feedback.value(),
+                            self.params.reverb_amount.value());
+                        (left_output, right_output) = self.galactic_reverb.process(left_output, right_output);
                     },
-                    3 => {
-                        wave3_l = frequency_modulation::triple_modulation(fm_wave_2, wave3_l, next_fm_step_3);
-                        wave3_r = frequency_modulation::triple_modulation(fm_wave_2, wave3_r, next_fm_step_3);
+                    ReverbModel::ASpace => {
+                        // AW Galactic simplified and changed
+                        self.simple_space[0].update(
+                            self.sample_rate,
+                            self.params.reverb_size.value() / 2.0,
+                            self.params.reverb_feedback.value(),
+                            self.params.reverb_amount.value());
+                        (left_output, right_output) = self.simple_space[0].process(left_output, right_output);
+                        self.simple_space[1].update(
+                            self.sample_rate,
+                            self.params.reverb_size.value() / 2.5,
+                            self.params.reverb_feedback.value() + 0.2,
+                            self.params.reverb_amount.value());
+                        (left_output, right_output) = self.simple_space[1].process(left_output, right_output);
+                        self.simple_space[2].update(
+                            self.sample_rate,
+                            self.params.reverb_size.value() / 3.0,
+                            self.params.reverb_feedback.value() + 0.4,
+                            self.params.reverb_amount.value());
+                        (left_output, right_output) = self.simple_space[2].process(left_output, right_output);
+                        self.simple_space[3].update(
+                            self.sample_rate,
+                            self.params.reverb_size.value() / 4.0,
+                            self.params.reverb_feedback.value() + 0.6,
+                            self.params.reverb_amount.value());
+                        (left_output, right_output) = self.simple_space[3].process(left_output, right_output);
                     },
-                    _ => {}
                 }
-            }
-
-            /////////////////////////////////////////////////////////////////////////////////////////////////
-            // Audio Module Processing over
-
-            // If a new note has happened we should reset the phase of our LFO if sync enabled
-            if reset_filter_controller1 || reset_filter_controller2 || reset_filter_controller3 {
-                if self.params.lfo1_sync.value() {
-                    self.lfo_1.set_phase(self.params.lfo1_phase.value());
+                if predelay_samples > 0 {
+                    // Swap the delayed dry that each model passed through for the on time dry
+                    // The TDLs and Galactic pass the input at unity, A Space thins it a bit per stage
+                    let dry_gain = if reverb_model == ReverbModel::ASpace {
+                        (1.0 - self.params.reverb_amount.value() / 2.0).powi(4)
+                    } else {
+                        1.0
+                    };
+                    left_output += (dry_l - delayed_l) * dry_gain;
+                    right_output += (dry_r - delayed_r) * dry_gain;
                 }
-                if self.params.lfo2_sync.value() {
-                    self.lfo_2.set_phase(self.params.lfo2_phase.value());
-                }
-                if self.params.lfo3_sync.value() {
-                    self.lfo_3.set_phase(self.params.lfo3_phase.value());
+                if reverb_model == ReverbModel::ASpace {
+                    // Part way through a bypass fade only that share of the dry went through A Space
+                    let aspace_dry_gain = (1.0 - self.params.reverb_amount.value() / 2.0).powi(4);
+                    send_dry_gain = 1.0 + (aspace_dry_gain - 1.0) * self.fx_bypass.reverb.amount();
                 }
+                (left_output, right_output) = self.fx_bypass.reverb.mix(dry, (left_output, right_output));
             }
-
-            // Get our new LFO values
-            if self.params.lfo1_enable.value() {
-                lfo_1_current = self.lfo_1.next_sample(self.sample_rate);
+            // Resonator
+            // Rings the send like a reverb return, only the tuned tail gets added so Dry Kill still lines up
+            if self.fx_bypass.resonator.advance(self.params.use_resonator.value(), self.sample_rate) {
+                let dry = (left_output, right_output);
+                self.resonator.update(
+                    self.sample_rate,
+                    self.params.resonator_root.value(),
+                    self.params.resonator_chord.value(),
+                    self.params.resonator_decay.value(),
+                );
+                let (ring_l, ring_r) = self.resonator.process(send_l, send_r);
+                let resonator_mix = self.params.resonator_mix.value();
+                (left_output, right_output) = self.fx_bypass.resonator.mix(
+                    dry,
+                    (left_output + ring_l * resonator_mix, right_output + ring_r * resonator_mix),
+                );
             }
-            if self.params.lfo2_enable.value() {
-                lfo_2_current = self.lfo_2.next_sample(self.sample_rate);
+            // Dry Kill
+            // The delays and reverbs all pass their input through at a known gain so subtracting it leaves only the tails
+            // Everything before this point (EQ, saturation, modulation FX) just shapes what gets sent
+            if self.params.dry_kill.value() {
+                left_output -= send_l * send_dry_gain;
+                right_output -= send_r * send_dry_gain;
             }
-            if self.params.lfo3_enable.value() {
-                lfo_3_current = self.lfo_3.next_sample(self.sample_rate);
+            // Looper
+            // The output so far is recorded into a loop that plays back into itself, like tape on a loop
+            if self.fx_bypass.looper.advance(self.params.use_looper.value(), self.sample_rate) {
+                let dry = (left_output, right_output);
+                (left_output, right_output) = self.looper.process(
+                    left_output,
+                    right_output,
+                    (self.params.looper_length.value() * 0.001 * self.sample_rate).round() as usize,
+                    2.0_f32.powf(self.params.looper_pitch.value() / 12.0),
+                    self.params.looper_feedback.value(),
+                    self.params.looper_mix.value(),
+                );
+                (left_output, right_output) = self.fx_bypass.looper.mix(dry, (left_output, right_output));
             }
-
-            // Define the outputs
-            let mut left_output: f32;
-            let mut right_output: f32;
-
-            left_output = (wave1_l + wave2_l + wave3_l)*0.33;
-            right_output = (wave1_r + wave2_r + wave3_r)*0.33;
-
-            // FX
-            ////////////////////////////////////////////////////////////////////////////////////////
-            if self.params.use_fx.value() {
-                // Equalizer use
-                if self.params.pre_use_eq.value() {
-                    let eq_ref = self.bands.clone();
-                    let mut eq = eq_ref.lock().unwrap();
-                    eq[0].set_type(FilterType::LowShelf);
-                    eq[1].set_type(FilterType::Peak);
-                    eq[2].set_type(FilterType::HighShelf);
-                    let q_value: f32 = 0.93;
-                    eq[0].update(
-                        self.sample_rate,
-                        self.params.pre_low_freq.value(),
-                        self.params.pre_low_gain.value(),
-                        q_value,
-                    );
-                    eq[1].update(
-                        self.sample_rate,
-                        self.params.pre_mid_freq.value(),
-                        self.params.pre_mid_gain.value(),
-                        q_value,
-                    );
-                    eq[2].update(
-                        self.sample_rate,
-                        self.params.pre_high_freq.value(),
-                        self.params.pre_high_gain.value(),
-                        q_value,
-                    );
-
-                    let mut temp_l: f32;
-                    let mut temp_r: f32;
-                    // This is the first time we run a filter at all
-                    (temp_l, temp_r) = eq[0].process_sample(left_output, right_output);
-                    (temp_l, temp_r) = eq[1].process_sample(temp_l, temp_r);
-                    (temp_l, temp_r) = eq[2].process_sample(temp_l, temp_r);
-                    // Reassign our new output
-                    left_output = temp_l;
-                    right_output = temp_r;
-                }
-                // Compressor
-                if self.params.use_compressor.value() {
-                    self.compressor.update(
-                        self.sample_rate,
-                        self.params.comp_amt.value(),
-                        self.params.comp_atk.value(),
-                        self.params.comp_rel.value(),
-                        self.params.comp_drive.value(),
-                    );
-                    (left_output, right_output) =
-                        self.compressor.process(left_output, right_output);
-                }
-                // ABass Algorithm
-                if self.params.use_abass.value() {
-                    left_output = a_bass_saturation(left_output, self.params.abass_amount.value());
-                    right_output =
-                        a_bass_saturation(right_output, self.params.abass_amount.value());
-                }
-                // Distortion
-                if self.params.use_saturation.value() {
-                    self.saturator.set_type(self.params.sat_type.value());
-                    (left_output, right_output) = self.saturator.process(
-                        left_output,
-                        right_output,
-                        self.params.sat_amt.value(),
-                    );
-                }
-                // Buffer Modulator
-                if self.params.use_buffermod.value() {
-                    self.buffermod.update(
-                        self.sample_rate,
-                        self.params.buffermod_depth.value(),
-                        self.params.buffermod_rate.value(),
-                        self.params.buffermod_spread.value(),
-                        self.params.buffermod_timing.value(),
-                    );
-                    (left_output, right_output) = self.buffermod.process(
-                        left_output,
-                        right_output,
-                        self.params.buffermod_amount.value(),
-                    );
+            // Master Tilt
+            // Complementary shelves pivoting at 1kHz: lows go one way, highs the other
+            // Unsmoothed like the EQ so Biquad::update only recalculates when the param or sample rate changes
+            let tilt = self.params.master_tilt.value();
+            if tilt != 0.0 {
+                self.tilt_bands[0].update(self.sample_rate, 1000.0, -tilt, 0.5);
+                self.tilt_bands[1].update(self.sample_rate, 1000.0, tilt, 0.5);
+                (left_output, right_output) = self.tilt_bands[0].process_sample(left_output, right_output);
+                (left_output, right_output) = self.tilt_bands[1].process_sample(left_output, right_output);
+            }
+            // Trance Gate
+            if self.fx_bypass.gate.advance(self.params.use_gate.value(), self.sample_rate) {
+                let dry = (left_output, right_output);
+                self.trance_gate.update(
+                    self.sample_rate,
+                    bpm,
+                    self.params.gate_rate.value().beats(),
+                    self.params.gate_shape.value(),
+                    self.params.gate_smooth.value(),
+                );
+                (left_output, right_output) = self.trance_gate.process(left_output, right_output);
+                (left_output, right_output) = self.fx_bypass.gate.mix(dry, (left_output, right_output));
+            }
+            // Bass Mono
+            // Everything under the crossover is summed to mono so the sub stays centered, the highs keep their width
+            if self.fx_bypass.bass_mono.advance(self.params.use_bass_mono.value(), self.sample_rate) {
+                let dry = (left_output, right_output);
+                let crossover = self.params.elliptical_freq.value();
+                let (mut low_l, mut low_r) = (left_output, right_output);
+                for band in self.bass_mono_lows.iter_mut() {
+                    band.update(self.sample_rate, crossover, 0.0, 0.707);
+                    (low_l, low_r) = band.process_sample(low_l, low_r);
                 }
-                // Chorus
-                if self.params.use_chorus.value() {
-                    self.chorus.update(
-                        self.sample_rate, 
-                        self.params.chorus_range.value(), 
-                        self.params.chorus_speed.value(), 
-                        self.params.chorus_amount.value()
-                    );
-                    (left_output, right_output) = self.chorus.process(left_output, right_output);
-                }
-                // Phaser
-                if self.params.use_phaser.value() {
-                    self.phaser.set_sample_rate(self.sample_rate);
-                    self.phaser.set_depth(self.params.phaser_depth.value());
-                    self.phaser.set_rate(self.params.phaser_rate.value());
-                    self.phaser
-                        .set_feedback(self.params.phaser_feedback.value());
-                    (left_output, right_output) = self.phaser.process(
-                        left_output,
-                        right_output,
-                        self.params.phaser_amount.value(),
-                    );
+                let (mut high_l, mut high_r) = (left_output, right_output);
+                for band in self.bass_mono_highs.iter_mut() {
+                    band.update(self.sample_rate, crossover, 0.0, 0.707);
+                    (high_l, high_r) = band.process_sample(high_l, high_r);
                 }
-                // Flanger
-                if self.params.use_flanger.value() {
-                    self.flanger.update(
-                        self.sample_rate,
-                        self.params.flanger_depth.value(),
-                        self.params.flanger_rate.value(),
-                        self.params.flanger_feedback.value(),
-                    );
-                    (left_output, right_output) = self.flanger.process(
-                        left_output,
-                        right_output,
-                        self.params.flanger_amount.value(),
-                    );
+                let low_mono = (low_l + low_r) * 0.5;
+                left_output = low_mono + high_l;
+                right_output = low_mono + high_r;
+                (left_output, right_output) = self.fx_bypass.bass_mono.mix(dry, (left_output, right_output));
+            }
+            // Limiter
+            // Runs through the oversampler even when off so the reported latency never moves
+            let use_limiter = self.fx_bypass.limiter.advance(self.params.use_limiter.value(), self.sample_rate);
+            if use_limiter {
+                self.limiter.update(
+                    self.params.limiter_knee.value(),
+                    self.params.limiter_threshold.value(),
+                );
+                self.limiter.set_ceiling(self.params.limiter_ceiling.value());
+            }
+            (left_output, right_output) = self.limiter_oversampler.process(left_output, right_output, self.params.output_oversample.value(), |left_output, right_output| {
+                if use_limiter {
+                    let dry = (left_output, right_output);
+                    let wet = self.limiter.process(left_output, right_output);
+                    self.fx_bypass.limiter.mix(dry, wet)
+                } else {
+                    (left_output, right_output)
                 }
-                // Delay
-                if self.params.use_delay.value() {
-                    self.delay.set_sample_rate(
-                        self.sample_rate,
-                        context.transport().tempo.unwrap_or(1.0) as f32,
-                    );
-                    self.delay.set_length(self.params.delay_time.value());
-                    self.delay.set_feedback(self.params.delay_decay.value());
-                    self.delay.set_type(self.params.delay_type.value());
-                    (left_output, right_output) = self.delay.process(
-                        left_output,
-                        right_output,
-                        self.params.delay_amount.value(),
-                    );
+            });
+        }
+
+        // DC Offset Removal
+        ////////////////////////////////////////////////////////////////////////////////////////
+        // There were several filter settings that caused massive DC spikes so I added this here
+        if !self.file_dialog.load(Ordering::SeqCst) {
+            // Remove DC Offsets with our SVF
+            let eco_mode = self.params.eco_mode.value();
+            self.dc_filter_l.set_eco(eco_mode);
+            self.dc_filter_r.set_eco(eco_mode);
+            self.dc_filter_l
+                .update(20.0, 0.8, self.sample_rate, ResonanceType::Default);
+            self.dc_filter_r
+                .update(20.0, 0.8, self.sample_rate, ResonanceType::Default);
+            (_, _, left_output) = self.dc_filter_l.process(left_output);
+            (_, _, right_output) = self.dc_filter_r.process(right_output);
+        }
+
+        // Rumble Filter
+        ////////////////////////////////////////////////////////////////////////////////////////
+        // After the limiter and DC filter so it cleans up everything, FX on or off
+        let master_hpf = self.params.master_hpf.value();
+        if master_hpf > MASTER_HPF_OFF {
+            self.master_hpf.update(self.sample_rate, master_hpf, 0.0, 0.707);
+            (left_output, right_output) = self.master_hpf.process_sample(left_output, right_output);
+        }
+
+        // Space
+        ////////////////////////////////////////////////////////////////////////////////////////
+        // Only the side channel is scaled so the mono sum never changes. Side below the Mono Below crossover
+        // narrows as the knob moves either way, the rest follows the knob
+        // A move mid ramp is picked up once the current 5 ms ramp lands
+        let space_target = self.params.space.value();
+        if self.space_smoother.steps_left() == 0 && self.space_smoother.previous_value() != space_target {
+            self.space_smoother.set_target(self.sample_rate, space_target);
+        }
+        let space = self.space_smoother.next();
+        if space != 0.0 {
+            let mid = (left_output + right_output) * 0.5;
+            let side = (left_output - right_output) * 0.5;
+            self.space_side_low.update(self.sample_rate, self.params.elliptical_freq.value(), 0.0, 0.707);
+            let (side_low, _) = self.space_side_low.process_sample(side, 0.0);
+            let side = (side - side_low) * (1.0 + space) + side_low * (1.0 - space.abs());
+            left_output = mid + side;
+            right_output = mid - side;
+        }
+
+        // Tape Stop
+        ////////////////////////////////////////////////////////////////////////////////////////
+        // Slows the whole output down to a halt while held and spins it back up on release, FX on or off
+        (left_output, right_output) = self.tape_stop.process(
+            left_output,
+            right_output,
+            self.params.tape_stop.value(),
+            self.params.tape_stop_time.value(),
+            self.sample_rate,
+        );
+
+        // Soft start fade
+        ////////////////////////////////////////////////////////////////////////////////////////
+        // Only runs after a load/voice clear so regular notes are never faded
+        if self.soft_start_gain < 1.0 {
+            left_output *= self.soft_start_gain;
+            right_output *= self.soft_start_gain;
+            self.soft_start_gain = (self.soft_start_gain + 1.0 / (SOFT_START_TIME * self.sample_rate)).min(1.0);
+        }
+
+        // Output meter
+        ////////////////////////////////////////////////////////////////////////////////////////
+        // Measured after master level and before the safety clip so you can see what would clip
+        let meter_l = (left_output * self.params.master_level.value()).abs();
+        let meter_r = (right_output * self.params.master_level.value()).abs();
+        let meter_peak = meter_l.max(meter_r);
+        if meter_peak > 1.0 {
+            self.output_clipped.store(true, Ordering::Relaxed);
+        }
+        self.meter_hold = meter_peak.max(self.meter_hold * meter_decay);
+        // Correlation is averaged over the same window the meter falls back in
+        for (sum, product) in self.correlation_sums.iter_mut().zip([
+            left_output * right_output,
+            left_output * left_output,
+            right_output * right_output,
+        ]) {
+            *sum = product + meter_decay * (*sum - product);
+        }
+
+        // Final output to DAW
+        ////////////////////////////////////////////////////////////////////////////////////////
+
+        let mut final_l = left_output * self.params.master_level.value();
+        let mut final_r = right_output * self.params.master_level.value();
+        if *self.safety_clip_output.lock().unwrap() {
+            final_l = final_l.clamp(-1.0, 1.0);
+            final_r = final_r.clamp(-1.0, 1.0);
+        }
+        // Freeze grabs what you hear, before dither so resampling doesn't stack noise
+        // The GUI holds the lock while it copies a snapshot out, the audio thread never waits on that and drops the sample instead
+        if let Ok(mut capture) = self.output_capture.try_lock() {
+            capture.push(final_l, final_r);
+        }
+        // Dither goes dead last since anything after it would undo the quantization
+        if self.params.use_dither.value() {
+            (final_l, final_r) = self.dither.process(final_l, final_r, self.params.dither_shaping.value());
+        }
+        *lfo_current = [lfo_1_current, lfo_2_current, lfo_3_current];
+        (
+            (final_l, final_r),
+            [(wave1_l, wave1_r), (wave2_l, wave2_r), (wave3_l, wave3_r)],
+        )
+    }
+
+    // Mono and Legato voice handling
+    // Notes go to the audio modules as if only one key were held, glide rides along on the held voice via NotePitch
+    // Legato only glides while the previous key is still down so separate notes retrigger cleanly
+    fn handle_mono(&mut self, event: NoteEvent<()>) -> Option<NoteEvent<()>> {
+        let mode = self.params.mono_mode.value();
+        if mode == MonoMode::Poly {
+            if self.mono_voice_note.is_some() || !self.held_notes.is_empty() {
+                self.held_notes.clear();
+                self.mono_voice_note = None;
+                self.mono_last_pitch = None;
+                self.glide_current = 0.0;
+                self.glide_target = 0.0;
+            }
+            return Some(event);
+        }
+        match event {
+            NoteEvent::NoteOn { timing, voice_id, channel, note, velocity } => {
+                self.held_notes.retain(|held| *held != note);
+                self.held_notes.push(note);
+                self.mono_velocity = velocity;
+                match self.mono_voice_note {
+                    None => {
+                        self.mono_voice_note = Some(note);
+                        // Mono glides from wherever the last note ended up, Legato starts fresh
+                        match (mode, self.mono_last_pitch) {
+                            (MonoMode::Mono, Some(last_pitch)) => {
+                                self.glide_current = last_pitch - note as f32;
+                                self.set_glide_target(0.0);
+                            },
+                            _ => {
+                                self.glide_current = 0.0;
+                                self.glide_target = 0.0;
+                            },
+                        }
+                        Some(NoteEvent::NoteOn { timing, voice_id, channel, note, velocity })
+                    },
+                    Some(voice_note) => {
+                        if mode == MonoMode::Legato {
+                            self.set_glide_target(note as f32 - voice_note as f32);
+                            None
+                        } else {
+                            Some(self.mono_retrigger(voice_note, note, channel, timing))
+                        }
+                    },
                 }
-                // Reverb
-                if self.params.use_reverb.value() {
-                    match self.params.reverb_model.value() {
-                        // Stacked TDLs to make reverb
-                        ReverbModel::Default => {
-                            self.reverb[0]
-                                .set_size(self.params.reverb_size.value(), self.sample_rate);
-                            self.reverb[1]
-                                .set_size(self.params.reverb_size.value() * 0.546, self.sample_rate);
-                            self.reverb[2]
-                                .set_size(self.params.reverb_size.value() * 0.251, self.sample_rate);
-                            self.reverb[3]
-                                .set_size(self.params.reverb_size.value() * 0.735, self.sample_rate);
-                            self.reverb[4]
-                                .set_size(self.params.reverb_size.value() * 0.669, self.sample_rate);
-                            self.reverb[5]
-                                .set_size(self.params.reverb_size.value() * 0.374, self.sample_rate);
-                            self.reverb[6]
-                                .set_size(self.params.reverb_size.value() * 0.8, self.sample_rate);
-                            self.reverb[7]
-                                .set_size(self.params.reverb_size.value() * 0.4, self.sample_rate);
-                            for verb in self.reverb.iter_mut() {
-                                verb.set_feedback(self.params.reverb_feedback.value());
-                                (left_output, right_output) = verb.process_tdl(
-                                    left_output,
-                                    right_output,
-                                    self.params.reverb_amount.value());                    
-                            }
-                        },
-                        ReverbModel::Galactic => {
-                            // AW Galactic modified
-                            self.galactic_reverb.update(
-                                self.sample_rate,
-                                self.params.reverb_size.value() / 2.0,
-                                self.params.reverb_feedback.value(),
-                                self.params.reverb_amount.value());
-                            (left_output, right_output) = self.galactic_reverb.process(left_output, right_output);
-                        },
-                        ReverbModel::ASpace => {
-                            // AW Galactic simplified and changed
-                            self.simple_space[0].update(
-                                self.sample_rate,
-                                self.params.reverb_size.value() / 2.0,
-                                self.params.reverb_feedback.value(),
-                                self.params.reverb_amount.value());
-                            (left_output, right_output) = self.simple_space[0].process(left_output, right_output);
-                            self.simple_space[1].update(
-                                self.sample_rate,
-                                self.params.reverb_size.value() / 2.5,
-                                self.params.reverb_feedback.value() + 0.2,
-                                self.params.reverb_amount.value());
-                            (left_output, right_output) = self.simple_space[1].process(left_output, right_output);
-                            self.simple_space[2].update(
-                                self.sample_rate,
-                                self.params.reverb_size.value() / 3.0,
-                                self.params.reverb_feedback.value() + 0.4,
-                                self.params.reverb_amount.value());
-                            (left_output, right_output) = self.simple_space[2].process(left_output, right_output);
-                            self.simple_space[3].update(
-                                self.sample_rate,
-                                self.params.reverb_size.value() / 4.0,
-                                self.params.reverb_feedback.value() + 0.6,
-                                self.params.reverb_amount.value());
-                            (left_output, right_output) = self.simple_space[3].process(left_output, right_output);
-                        },
-                    }
+            },
+            NoteEvent::NoteOff { timing, voice_id, channel, note, velocity } => {
+                self.held_notes.retain(|held| *held != note);
+                let voice_note = match self.mono_voice_note {
+                    Some(voice_note) => voice_note,
+                    // Nothing of ours is sounding, this is likely left over from poly mode
+                    None => return Some(NoteEvent::NoteOff { timing, voice_id, channel, note, velocity }),
+                };
+                let sounding_note = (voice_note as f32 + self.glide_target).round() as u8;
+                if note != sounding_note {
+                    // A key that was held underneath the sounding one
+                    return None;
                 }
-                // Limiter
-                if self.params.use_limiter.value() {
-                    self.limiter.update(
-                        self.params.limiter_knee.value(),
-                        self.params.limiter_threshold.value(),
-                    );
-                    (left_output, right_output) = self.limiter.process(left_output, right_output);
+                match self.held_notes.last().copied() {
+                    Some(next_note) => {
+                        if mode == MonoMode::Legato {
+                            self.set_glide_target(next_note as f32 - voice_note as f32);
+                            None
+                        } else {
+                            Some(self.mono_retrigger(voice_note, next_note, channel, timing))
+                        }
+                    },
+                    None => {
+                        self.mono_voice_note = None;
+                        self.mono_last_pitch = Some(voice_note as f32 + self.glide_current);
+                        Some(NoteEvent::NoteOff { timing, voice_id, channel, note: voice_note, velocity })
+                    },
                 }
-            }
+            },
+            other => Some(other),
+        }
+    }
 
-            // DC Offset Removal
-            ////////////////////////////////////////////////////////////////////////////////////////
-            // There were several filter settings that caused massive DC spikes so I added this here
-            if !self.file_dialog.load(Ordering::SeqCst) {
-                // Remove DC Offsets with our SVF
-                self.dc_filter_l
-                    .update(20.0, 0.8, self.sample_rate, ResonanceType::Default);
-                self.dc_filter_r
-                    .update(20.0, 0.8, self.sample_rate, ResonanceType::Default);
-                (_, _, left_output) = self.dc_filter_l.process(left_output);
-                (_, _, right_output) = self.dc_filter_r.process(right_output);
-            }
+    // Release the sounding voice now and hold the new note for the next sample, gliding from the old pitch
+    // The glide only moves once the new note starts so the releasing voice keeps its pitch
+    fn mono_retrigger(&mut self, voice_note: u8, note: u8, channel: u8, timing: u32) -> NoteEvent<()> {
+        let sounding_pitch = voice_note as f32 + self.glide_current;
+        self.mono_voice_note = Some(note);
+        self.mono_pending = Some((
+            NoteEvent::NoteOn { timing, voice_id: None, channel, note, velocity: self.mono_velocity },
+            sounding_pitch - note as f32,
+        ));
+        NoteEvent::NoteOff { timing, voice_id: None, channel, note: voice_note, velocity: 0.0 }
+    }
 
-            // Final output to DAW
-            ////////////////////////////////////////////////////////////////////////////////////////
+    fn set_glide_target(&mut self, target: f32) {
+        self.glide_target = target;
+        let glide_samples = self.params.glide_time.value() * 0.001 * self.sample_rate;
+        if glide_samples < 1.0 {
+            self.glide_current = target;
+            self.glide_step = 0.0;
+        } else {
+            self.glide_step = (target - self.glide_current) / glide_samples;
+        }
+    }
 
-            if *self.safety_clip_output.lock().unwrap() {
-                // Reassign our output signal
-                *channel_samples.get_mut(0).unwrap() = (left_output * self.params.master_level.value()).clamp(-1.0, 1.0);
-                *channel_samples.get_mut(1).unwrap() = (right_output * self.params.master_level.value()).clamp(-1.0, 1.0);
+    // Offline render of a single note for preset previews
+    // A scratch engine shares the live params and runs render_sample on copies of the modules so the preview
+    // gets the same filters, mod matrix and FX as the patch without touching the live voices
+    // This is slow so it runs on its own thread, the module copies come from AudioModule::render_copy
+    fn render_preview(
+        mut location: PathBuf,
+        params: Arc<ActuateParams>,
+        modules: [AudioModule; 3],
+        bpm: f32,
+        note: u8,
+        hold_seconds: f32,
+        tail_seconds: f32,
+    ) {
+        location.set_extension("wav");
+        let [mut AM1, mut AM2, mut AM3] = modules;
+        // The per note sample libraries were left behind on the GUI thread so rebuild them here
+        AM1.regenerate_samples();
+        AM2.regenerate_samples();
+        AM3.regenerate_samples();
+        let sample_rate = AM1.get_sample_rate();
+
+        let mut engine = Actuate::default();
+        engine.sample_rate = sample_rate;
+        engine.looper.set_max_length(LOOPER_MAX_MS, sample_rate);
+        engine.tape_stop.set_max_time(TAPE_STOP_MAX_MS, sample_rate);
+        engine.reverb_predelay.set_max_length(REVERB_PREDELAY_MAX_MS, sample_rate);
+        engine.resonator.set_max_length(RESONATOR_LOWEST_ROOT, sample_rate);
+        // The libraries are fresh so don't let the first consume_params rebuild them again
+        engine.prev_restretch_1.store(params.restretch_1.value(), Ordering::SeqCst);
+        engine.prev_restretch_2.store(params.restretch_2.value(), Ordering::SeqCst);
+        engine.prev_restretch_3.store(params.restretch_3.value(), Ordering::SeqCst);
+        engine.params = params;
+        // Nothing was loaded mid-note here so skip the fade in
+        engine.soft_start_gain = 1.0;
+        // Without a host tempo the synced LFOs and delays fall back to 120
+        let bpm = if bpm > 1.0 { bpm } else { 120.0 };
+        engine.update_lfo_rates(bpm);
+        let meter_decay = (-1.0 / (METER_DECAY_TIME * sample_rate)).exp();
+
+        let spec = hound::WavSpec {
+            channels: 2,
+            sample_rate: sample_rate as u32,
+            bits_per_sample: 32,
+            sample_format: hound::SampleFormat::Float,
+        };
+        let writer = hound::WavWriter::create(&location, spec);
+        if let Err(err) = writer {
+            eprintln!("Error creating preview file: {}", err);
+            return;
+        }
+        let mut writer = writer.unwrap();
+
+        let hold_samples = (hold_seconds * sample_rate) as usize;
+        let total_samples = hold_samples + (tail_seconds * sample_rate) as usize;
+        let mut lfo_current: [f32; 3] = [-2.0; 3];
+        for sample_id in 0..total_samples {
+            let event: Option<NoteEvent<()>> = if sample_id == 0 {
+                Some(NoteEvent::NoteOn { timing: 0, voice_id: None, channel: 0, note: note, velocity: 0.8 })
+            } else if sample_id == hold_samples {
+                Some(NoteEvent::NoteOff { timing: 0, voice_id: None, channel: 0, note: note, velocity: 0.0 })
             } else {
-                // Reassign our output signal
-                *channel_samples.get_mut(0).unwrap() = left_output * self.params.master_level.value();
-                *channel_samples.get_mut(1).unwrap() = right_output * self.params.master_level.value();
-            }
+                None
+            };
+
+            // The whole render is treated as one block
+            let ((left_output, right_output), _) = engine.render_sample(
+                &mut AM1,
+                &mut AM2,
+                &mut AM3,
+                event,
+                sample_id,
+                total_samples,
+                (0.0, 0.0),
+                bpm,
+                meter_decay,
+                &mut lfo_current,
+            );
+
+            let _ = writer.write_sample(left_output.clamp(-1.0, 1.0));
+            let _ = writer.write_sample(right_output.clamp(-1.0, 1.0));
+        }
+
+        if let Err(err) = writer.finalize() {
+            eprintln!("Error writing preview file: {}", err);
         }
     }
 
-    
     fn export_preset(saving_preset: Option<PathBuf>, mut preset: ActuatePresetV131) {
+        if preset.preset_created.is_empty() {
+            preset.preset_created = preset_timestamp();
+        }
         if let Some(mut location) = saving_preset {
             if let Some(extension_check) = location.extension() {
                 let extension = extension_check.to_string_lossy().to_string();
@@ -5452,9 +9232,30 @@ impl Actuate {
 
                 // Serialize to json
                 let serialized_data = serde_json::to_string(&preset);
+                if let Err(err) = serialized_data {
+                    eprintln!("Error serializing data: {}", err);
+                    return;
+                }
+
+                // Sample data is huge and doesn't shrink much past the fast level, so only spend the time on plain patches
+                let has_samples = !preset.mod1_loaded_sample.is_empty()
+                    || !preset.mod2_loaded_sample.is_empty()
+                    || !preset.mod3_loaded_sample.is_empty();
+                let level = if has_samples {
+                    Compression::fast()
+                } else {
+                    Compression::best()
+                };
+                let compressed_data = match compress_bytes(serialized_data.unwrap().as_bytes(), level) {
+                    Ok(data) => data,
+                    Err(err) => {
+                        eprintln!("Error compressing preset: {}", err);
+                        return;
+                    }
+                };
 
                 // Now you can write the compressed data to the file
-                if let Err(err) = std::fs::write(&location, serialized_data.unwrap()) {
+                if let Err(err) = std::fs::write(&location, compressed_data) {
                     eprintln!("Error writing compressed data to file: {}", err);
                     return;
                 }
@@ -5464,6 +9265,18 @@ impl Actuate {
         }
     }
 
+    // The user's Init patch lives next to the banks so every new instance can find it
+    fn user_init_path() -> Option<PathBuf> {
+        dirs::document_dir().map(|dir| dir.join("ActuateDB").join("Init.actuate"))
+    }
+
+    fn user_init_preset() -> Option<ActuatePresetV131> {
+        Actuate::user_init_path()
+            .filter(|path| path.exists())
+            .and_then(|path| Actuate::import_preset(Some(path)).1)
+            .filter(|preset| !preset.preset_name.contains("Error"))
+    }
+
     // import_preset() uses message packing with serde
     fn import_preset(imported_preset: Option<PathBuf>) -> (String, Option<ActuatePresetV131>) {
         let return_name;
@@ -5475,16 +9288,24 @@ impl Actuate {
                 .to_string();
 
             // Read the compressed data from the file
-            let mut file_data = String::new();
+            let mut file_data: Vec<u8> = Vec::new();
             if let Err(err) = std::fs::File::open(&return_name)
-                .and_then(|mut file| file.read_to_string(&mut file_data))
+                .and_then(|mut file| file.read_to_end(&mut file_data))
             {
                 eprintln!("Error reading compressed data from file: {}", err);
                 return (err.to_string(), Option::None);
             }
+            // Presets saved before compression are plain json and pass through untouched
+            let file_data = match decompress_bytes(&file_data) {
+                Ok(data) => data,
+                Err(err) => {
+                    eprintln!("Error decompressing preset: {}", err);
+                    return (err.to_string(), Option::None);
+                }
+            };
 
             // Deserialize into preset struct - return default empty lib if error
-            let unserialized: ActuatePresetV131 = serde_json::from_slice(file_data.as_bytes()).unwrap_or(ERROR_PRESET.clone());
+            let unserialized: ActuatePresetV131 = serde_json::from_slice(&file_data).unwrap_or(ERROR_PRESET.clone());
 
             // This if cascade tries to load each predecessor format of presets
             if unserialized.preset_name.contains("Error") {
@@ -5496,6 +9317,41 @@ impl Actuate {
         return (String::from("Error"), Option::None);
     }
 
+    // Copies every readable .actuate file in a folder into a bank folder
+    // Returns the new files with their presets and how many were skipped as unreadable or already there
+    fn import_preset_folder(folder: &std::path::Path, bank_dir: &std::path::Path) -> (Vec<(PathBuf, ActuatePresetV131)>, usize) {
+        let mut imported = Vec::new();
+        let mut skipped = 0;
+        let Ok(entries) = std::fs::read_dir(folder) else {
+            return (imported, skipped);
+        };
+        let mut files: Vec<PathBuf> = entries
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| path.is_file() && path.extension().map_or(false, |ext| ext == "actuate"))
+            .collect();
+        files.sort();
+        for file in files {
+            let (_, preset) = Actuate::import_preset(Some(file.clone()));
+            // Unreadable files come back as the error preset rather than None
+            let Some(preset) = preset.filter(|preset| !preset.preset_name.contains("Error")) else {
+                skipped += 1;
+                continue;
+            };
+            let Some(file_name) = file.file_name() else {
+                skipped += 1;
+                continue;
+            };
+            let target = bank_dir.join(file_name);
+            if target.exists() || std::fs::copy(&file, &target).is_err() {
+                skipped += 1;
+                continue;
+            }
+            imported.push((target, preset));
+        }
+        (imported, skipped)
+    }
+
     /*
     // Load presets uses message packing with serde
     fn load_preset_bank(loading_bank: Option<PathBuf>) -> (String, Vec<ActuatePresetV131>) {
@@ -6016,6 +9872,161 @@ impl Actuate {
         setter.set_parameter(&params.additive_amp_3_13, loaded_preset.additive_amp_3_13);
         setter.set_parameter(&params.additive_amp_3_14, loaded_preset.additive_amp_3_14);
         setter.set_parameter(&params.additive_amp_3_15, loaded_preset.additive_amp_3_15);
+        setter.set_parameter(&params.master_tilt, loaded_preset.master_tilt);
+        setter.set_parameter(&params.lfo1_depth, loaded_preset.lfo1_depth);
+        setter.set_parameter(&params.lfo2_depth, loaded_preset.lfo2_depth);
+        setter.set_parameter(&params.lfo3_depth, loaded_preset.lfo3_depth);
+        setter.set_parameter(&params.ring_mod_1x2, loaded_preset.ring_mod_1x2);
+        setter.set_parameter(&params.ring_mod_2x3, loaded_preset.ring_mod_2x3);
+        setter.set_parameter(&params.sat_stereo_mode, loaded_preset.sat_stereo_mode);
+        setter.set_parameter(&params.humanize, loaded_preset.humanize);
+        setter.set_parameter(&params.osc_1_super, loaded_preset.mod1_osc_super);
+        setter.set_parameter(&params.osc_2_super, loaded_preset.mod2_osc_super);
+        setter.set_parameter(&params.osc_3_super, loaded_preset.mod3_osc_super);
+        setter.set_parameter(&params.filter_drive, loaded_preset.filter_drive);
+        setter.set_parameter(&params.filter_drive_2, loaded_preset.filter_drive_2);
+        setter.set_parameter(&params.osc_quality, loaded_preset.osc_quality);
+        setter.set_parameter(&params.release_vel_amount, loaded_preset.release_vel_amount);
+        setter.set_parameter(&params.mono_mode, loaded_preset.mono_mode);
+        setter.set_parameter(&params.glide_time, loaded_preset.glide_time);
+        setter.set_parameter(&params.osc_1_type_b, loaded_preset.mod1_osc_type_b);
+        setter.set_parameter(&params.osc_2_type_b, loaded_preset.mod2_osc_type_b);
+        setter.set_parameter(&params.osc_3_type_b, loaded_preset.mod3_osc_type_b);
+        setter.set_parameter(&params.osc_1_morph, loaded_preset.mod1_osc_morph);
+        setter.set_parameter(&params.osc_2_morph, loaded_preset.mod2_osc_morph);
+        setter.set_parameter(&params.osc_3_morph, loaded_preset.mod3_osc_morph);
+        setter.set_parameter(&params.use_transient, loaded_preset.use_transient);
+        setter.set_parameter(&params.transient_attack, loaded_preset.transient_attack);
+        setter.set_parameter(&params.transient_sustain, loaded_preset.transient_sustain);
+        setter.set_parameter(&params.filter_auto_gain, loaded_preset.filter_auto_gain);
+        setter.set_parameter(&params.global_phase_reset, loaded_preset.global_phase_reset);
+        setter.set_parameter(&params.delay_pitch, loaded_preset.delay_pitch);
+        setter.set_parameter(&params.pitch_mod_quantize, loaded_preset.pitch_mod_quantize);
+        setter.set_parameter(&params.pitch_mod_scale, loaded_preset.pitch_mod_scale);
+        setter.set_parameter(&params.grain_window_1, loaded_preset.mod1_grain_window);
+        setter.set_parameter(&params.grain_window_2, loaded_preset.mod2_grain_window);
+        setter.set_parameter(&params.grain_window_3, loaded_preset.mod3_grain_window);
+        setter.set_parameter(&params.use_delay_2, loaded_preset.use_delay_2);
+        setter.set_parameter(&params.delay_2_amount, loaded_preset.delay_2_amount);
+        setter.set_parameter(&params.delay_2_time, loaded_preset.delay_2_time.clone());
+        setter.set_parameter(&params.delay_2_decay, loaded_preset.delay_2_decay);
+        setter.set_parameter(&params.delay_2_type, loaded_preset.delay_2_type.clone());
+        setter.set_parameter(&params.delay_routing, loaded_preset.delay_routing.clone());
+        setter.set_parameter(&params.filter_env_loop, loaded_preset.filter_env_loop);
+        setter.set_parameter(&params.filter_env_loop_rate, loaded_preset.filter_env_loop_rate);
+        setter.set_parameter(&params.voice_spread, loaded_preset.voice_spread);
+        setter.set_parameter(&params.filter_env_invert_1, loaded_preset.filter_env_invert_1);
+        setter.set_parameter(&params.filter_env_invert_2, loaded_preset.filter_env_invert_2);
+        setter.set_parameter(&params.sample_root_1, loaded_preset.mod1_sample_root);
+        setter.set_parameter(&params.sample_root_2, loaded_preset.mod2_sample_root);
+        setter.set_parameter(&params.sample_root_3, loaded_preset.mod3_sample_root);
+        setter.set_parameter(&params.stretch_factor_1, loaded_preset.mod1_stretch_factor);
+        setter.set_parameter(&params.stretch_factor_2, loaded_preset.mod2_stretch_factor);
+        setter.set_parameter(&params.stretch_factor_3, loaded_preset.mod3_stretch_factor);
+        setter.set_parameter(&params.voice_steal_mode, loaded_preset.voice_steal_mode);
+        setter.set_parameter(&params.lfo1_gate, loaded_preset.lfo1_gate);
+        setter.set_parameter(&params.lfo2_gate, loaded_preset.lfo2_gate);
+        setter.set_parameter(&params.lfo3_gate, loaded_preset.lfo3_gate);
+        setter.set_parameter(&params.osc_1_shape, loaded_preset.mod1_osc_shape);
+        setter.set_parameter(&params.osc_1_shape_amount, loaded_preset.mod1_osc_shape_amount);
+        setter.set_parameter(&params.osc_2_shape, loaded_preset.mod2_osc_shape);
+        setter.set_parameter(&params.osc_2_shape_amount, loaded_preset.mod2_osc_shape_amount);
+        setter.set_parameter(&params.osc_3_shape, loaded_preset.mod3_osc_shape);
+        setter.set_parameter(&params.osc_3_shape_amount, loaded_preset.mod3_osc_shape_amount);
+        setter.set_parameter(&params.reverb_predelay, loaded_preset.reverb_predelay);
+        setter.set_parameter(&params.filter_res_link, loaded_preset.filter_res_link);
+        setter.set_parameter(&params.filter_env_link, loaded_preset.filter_env_link);
+        setter.set_parameter(&params.phaser_stereo, loaded_preset.phaser_stereo);
+        setter.set_parameter(&params.flanger_stereo, loaded_preset.flanger_stereo);
+        setter.set_parameter(&params.buffermod_stereo, loaded_preset.buffermod_stereo);
+        setter.set_parameter(&params.tuning_system, loaded_preset.tuning_system);
+        setter.set_parameter(&params.tuning_root, loaded_preset.tuning_root);
+        setter.set_parameter(&params.osc_1_warmth, loaded_preset.mod1_osc_warmth);
+        setter.set_parameter(&params.osc_2_warmth, loaded_preset.mod2_osc_warmth);
+        setter.set_parameter(&params.osc_3_warmth, loaded_preset.mod3_osc_warmth);
+        setter.set_parameter(&params.master_hpf, loaded_preset.master_hpf);
+        setter.set_parameter(&params.limiter_ceiling, loaded_preset.limiter_ceiling);
+        setter.set_parameter(&params.use_gate, loaded_preset.use_gate);
+        setter.set_parameter(&params.gate_rate, loaded_preset.gate_rate);
+        setter.set_parameter(&params.gate_shape, loaded_preset.gate_shape);
+        setter.set_parameter(&params.gate_smooth, loaded_preset.gate_smooth);
+        setter.set_parameter(&params.env_time_range, loaded_preset.env_time_range);
+        setter.set_parameter(&params.osc_1_drawbar_sub, loaded_preset.mod1_osc_drawbar_sub);
+        setter.set_parameter(&params.osc_1_drawbar_root, loaded_preset.mod1_osc_drawbar_root);
+        setter.set_parameter(&params.osc_1_drawbar_oct, loaded_preset.mod1_osc_drawbar_oct);
+        setter.set_parameter(&params.osc_1_drawbar_oct2, loaded_preset.mod1_osc_drawbar_oct2);
+        setter.set_parameter(&params.osc_2_drawbar_sub, loaded_preset.mod2_osc_drawbar_sub);
+        setter.set_parameter(&params.osc_2_drawbar_root, loaded_preset.mod2_osc_drawbar_root);
+        setter.set_parameter(&params.osc_2_drawbar_oct, loaded_preset.mod2_osc_drawbar_oct);
+        setter.set_parameter(&params.osc_2_drawbar_oct2, loaded_preset.mod2_osc_drawbar_oct2);
+        setter.set_parameter(&params.osc_3_drawbar_sub, loaded_preset.mod3_osc_drawbar_sub);
+        setter.set_parameter(&params.osc_3_drawbar_root, loaded_preset.mod3_osc_drawbar_root);
+        setter.set_parameter(&params.osc_3_drawbar_oct, loaded_preset.mod3_osc_drawbar_oct);
+        setter.set_parameter(&params.osc_3_drawbar_oct2, loaded_preset.mod3_osc_drawbar_oct2);
+        setter.set_parameter(&params.mod_source_6, loaded_preset.mod_source_6);
+        setter.set_parameter(&params.mod_source_7, loaded_preset.mod_source_7);
+        setter.set_parameter(&params.mod_source_8, loaded_preset.mod_source_8);
+        setter.set_parameter(&params.mod_destination_5, loaded_preset.mod_dest_5);
+        setter.set_parameter(&params.mod_destination_6, loaded_preset.mod_dest_6);
+        setter.set_parameter(&params.mod_destination_7, loaded_preset.mod_dest_7);
+        setter.set_parameter(&params.mod_destination_8, loaded_preset.mod_dest_8);
+        setter.set_parameter(&params.mod_amount_knob_5, loaded_preset.mod_amount_5);
+        setter.set_parameter(&params.mod_amount_knob_6, loaded_preset.mod_amount_6);
+        setter.set_parameter(&params.mod_amount_knob_7, loaded_preset.mod_amount_7);
+        setter.set_parameter(&params.mod_amount_knob_8, loaded_preset.mod_amount_8);
+        setter.set_parameter(&params.mod_source_5, loaded_preset.mod_source_5);
+        setter.set_parameter(&params.loop_xfade_1, loaded_preset.mod1_loop_xfade);
+        setter.set_parameter(&params.loop_xfade_2, loaded_preset.mod2_loop_xfade);
+        setter.set_parameter(&params.loop_xfade_3, loaded_preset.mod3_loop_xfade);
+        setter.set_parameter(&params.filter_cutoff_smooth, loaded_preset.filter_cutoff_smooth);
+        for (param, cents) in params.note_offset_cents().into_iter().zip(loaded_preset.note_offset_cents) {
+            setter.set_parameter(param, cents);
+        }
+        setter.set_parameter(&params.osc_1_env_keyscale, loaded_preset.mod1_osc_env_keyscale);
+        setter.set_parameter(&params.osc_2_env_keyscale, loaded_preset.mod2_osc_env_keyscale);
+        setter.set_parameter(&params.osc_3_env_keyscale, loaded_preset.mod3_osc_env_keyscale);
+        setter.set_parameter(&params.reference_pitch, loaded_preset.reference_pitch);
+        setter.set_parameter(&params.filter_stereo_offset_1, loaded_preset.filter_stereo_offset_1);
+        setter.set_parameter(&params.filter_stereo_offset_2, loaded_preset.filter_stereo_offset_2);
+        setter.set_parameter(&params.paraphonic, loaded_preset.paraphonic);
+        setter.set_parameter(&params.osc_1_detune_hz, loaded_preset.mod1_osc_detune_hz);
+        setter.set_parameter(&params.osc_2_detune_hz, loaded_preset.mod2_osc_detune_hz);
+        setter.set_parameter(&params.osc_3_detune_hz, loaded_preset.mod3_osc_detune_hz);
+        setter.set_parameter(&params.use_bass_mono, loaded_preset.use_bass_mono);
+        setter.set_parameter(&params.elliptical_freq, loaded_preset.elliptical_freq);
+        setter.set_parameter(&params.osc_1_unison_blend, loaded_preset.mod1_osc_unison_blend);
+        setter.set_parameter(&params.osc_2_unison_blend, loaded_preset.mod2_osc_unison_blend);
+        setter.set_parameter(&params.osc_3_unison_blend, loaded_preset.mod3_osc_unison_blend);
+        setter.set_parameter(&params.reverb_diffusion, loaded_preset.reverb_diffusion);
+        setter.set_parameter(&params.reverb_density, loaded_preset.reverb_density);
+        setter.set_parameter(&params.slice_count_1, loaded_preset.mod1_slice_count);
+        setter.set_parameter(&params.slice_count_2, loaded_preset.mod2_slice_count);
+        setter.set_parameter(&params.slice_count_3, loaded_preset.mod3_slice_count);
+        setter.set_parameter(&params.use_looper, loaded_preset.use_looper);
+        setter.set_parameter(&params.looper_length, loaded_preset.looper_length);
+        setter.set_parameter(&params.looper_feedback, loaded_preset.looper_feedback);
+        setter.set_parameter(&params.looper_mix, loaded_preset.looper_mix);
+        setter.set_parameter(&params.looper_pitch, loaded_preset.looper_pitch);
+        setter.set_parameter(&params.tape_stop_time, loaded_preset.tape_stop_time);
+        setter.set_parameter(&params.sample_normalize_1, loaded_preset.mod1_sample_normalize);
+        setter.set_parameter(&params.sample_normalize_2, loaded_preset.mod2_sample_normalize);
+        setter.set_parameter(&params.sample_normalize_3, loaded_preset.mod3_sample_normalize);
+        setter.set_parameter(&params.space, loaded_preset.space);
+        setter.set_parameter(&params.osc_1_punch, loaded_preset.mod1_osc_punch);
+        setter.set_parameter(&params.osc_2_punch, loaded_preset.mod2_osc_punch);
+        setter.set_parameter(&params.osc_3_punch, loaded_preset.mod3_osc_punch);
+        setter.set_parameter(&params.audio_module_1_phase_invert, loaded_preset.mod1_phase_invert);
+        setter.set_parameter(&params.audio_module_2_phase_invert, loaded_preset.mod2_phase_invert);
+        setter.set_parameter(&params.audio_module_3_phase_invert, loaded_preset.mod3_phase_invert);
+        setter.set_parameter(&params.live_waveform_switch, loaded_preset.live_waveform_switch);
+        setter.set_parameter(&params.use_resonator, loaded_preset.use_resonator);
+        setter.set_parameter(&params.resonator_chord, loaded_preset.resonator_chord);
+        setter.set_parameter(&params.resonator_root, loaded_preset.resonator_root);
+        setter.set_parameter(&params.resonator_decay, loaded_preset.resonator_decay);
+        setter.set_parameter(&params.resonator_mix, loaded_preset.resonator_mix);
+        setter.set_parameter(&params.grain_spray_1, loaded_preset.mod1_grain_spray);
+        setter.set_parameter(&params.grain_spray_2, loaded_preset.mod2_grain_spray);
+        setter.set_parameter(&params.grain_spray_3, loaded_preset.mod3_grain_spray);
 
         setter.set_parameter(&params.preset_category, loaded_preset.preset_category);
 
@@ -6112,350 +10123,521 @@ impl Actuate {
         let AM1 = AM1c.lock().unwrap();
         let AM2 = AM2c.lock().unwrap();
         let AM3 = AM3c.lock().unwrap();
-        *arc_lib.lock().unwrap() =
-            ActuatePresetV131 {
-                preset_name: self.params.preset_name_p.lock().unwrap().clone(),
-                preset_info: self.params.preset_info_p.lock().unwrap().clone(),
-                preset_category: self.params.preset_category.value(),
-                tag_acid: self.params.tag_acid.value(),
-                tag_analog: self.params.tag_analog.value(),
-                tag_bright: self.params.tag_bright.value(),
-                tag_chord: self.params.tag_chord.value(),
-                tag_crisp: self.params.tag_crisp.value(),
-                tag_deep: self.params.tag_deep.value(),
-                tag_delicate: self.params.tag_delicate.value(),
-                tag_hard: self.params.tag_hard.value(),
-                tag_harsh: self.params.tag_harsh.value(),
-                tag_lush: self.params.tag_lush.value(),
-                tag_mellow: self.params.tag_mellow.value(),
-                tag_resonant: self.params.tag_resonant.value(),
-                tag_rich: self.params.tag_rich.value(),
-                tag_sharp: self.params.tag_sharp.value(),
-                tag_silky: self.params.tag_silky.value(),
-                tag_smooth: self.params.tag_smooth.value(),
-                tag_soft: self.params.tag_soft.value(),
-                tag_stab: self.params.tag_stab.value(),
-                tag_warm: self.params.tag_warm.value(),
-                // Modules 1
-                ///////////////////////////////////////////////////////////
-                mod1_audio_module_type: self.params.audio_module_1_type.value(),
-                mod1_audio_module_level: self.params.audio_module_1_level.value(),
-                mod1_audio_module_routing: self.params.audio_module_1_routing.value(),
-                // Granulizer/Sampler
-                mod1_loaded_sample: AM1.loaded_sample.clone(),
-                mod1_sample_lib: AM1.sample_lib.clone(),
-                mod1_loop_wavetable: AM1.loop_wavetable,
-                mod1_single_cycle: AM1.single_cycle,
-                mod1_restretch: AM1.restretch,
-                mod1_prev_restretch: AM1.prev_restretch,
-                mod1_start_position: AM1.start_position,
-                mod1_end_position: AM1._end_position,
-                mod1_grain_crossfade: AM1.grain_crossfade,
-                mod1_grain_gap: AM1.grain_gap,
-                mod1_grain_hold: AM1.grain_hold,
-
-                // Osc module knob storage
-                mod1_osc_octave: AM1.osc_octave,
-                mod1_osc_semitones: AM1.osc_semitones,
-                mod1_osc_detune: AM1.osc_detune,
-                mod1_osc_attack: AM1.osc_attack,
-                mod1_osc_decay: AM1.osc_decay,
-                mod1_osc_sustain: AM1.osc_sustain,
-                mod1_osc_release: AM1.osc_release,
-                mod1_osc_retrigger: AM1.osc_retrigger,
-                mod1_osc_atk_curve: AM1.osc_atk_curve,
-                mod1_osc_dec_curve: AM1.osc_dec_curve,
-                mod1_osc_rel_curve: AM1.osc_rel_curve,
-                mod1_osc_unison: AM1.osc_unison,
-                mod1_osc_unison_detune: AM1.osc_unison_detune,
-                mod1_osc_stereo: AM1.osc_stereo,
-
-                // Modules 2
-                ///////////////////////////////////////////////////////////
-                mod2_audio_module_type: self.params.audio_module_2_type.value(),
-                mod2_audio_module_level: self.params.audio_module_2_level.value(),
-                mod2_audio_module_routing: self.params.audio_module_2_routing.value(),
-                // Granulizer/Sampler
-                mod2_loaded_sample: AM2.loaded_sample.clone(),
-                mod2_sample_lib: AM2.sample_lib.clone(),
-                mod2_loop_wavetable: AM2.loop_wavetable,
-                mod2_single_cycle: AM2.single_cycle,
-                mod2_restretch: AM2.restretch,
-                mod2_prev_restretch: AM2.prev_restretch,
-                mod2_start_position: AM2.start_position,
-                mod2_end_position: AM2._end_position,
-                mod2_grain_crossfade: AM2.grain_crossfade,
-                mod2_grain_gap: AM2.grain_gap,
-                mod2_grain_hold: AM2.grain_hold,
-
-                // Osc module knob storage
-                mod2_osc_octave: AM2.osc_octave,
-                mod2_osc_semitones: AM2.osc_semitones,
-                mod2_osc_detune: AM2.osc_detune,
-                mod2_osc_attack: AM2.osc_attack,
-                mod2_osc_decay: AM2.osc_decay,
-                mod2_osc_sustain: AM2.osc_sustain,
-                mod2_osc_release: AM2.osc_release,
-                mod2_osc_retrigger: AM2.osc_retrigger,
-                mod2_osc_atk_curve: AM2.osc_atk_curve,
-                mod2_osc_dec_curve: AM2.osc_dec_curve,
-                mod2_osc_rel_curve: AM2.osc_rel_curve,
-                mod2_osc_unison: AM2.osc_unison,
-                mod2_osc_unison_detune: AM2.osc_unison_detune,
-                mod2_osc_stereo: AM2.osc_stereo,
-
-                // Modules 3
-                ///////////////////////////////////////////////////////////
-                mod3_audio_module_type: self.params.audio_module_3_type.value(),
-                mod3_audio_module_level: self.params.audio_module_3_level.value(),
-                mod3_audio_module_routing: self.params.audio_module_3_routing.value(),
-                // Granulizer/Sampler
-                mod3_loaded_sample: AM3.loaded_sample.clone(),
-                mod3_sample_lib: AM3.sample_lib.clone(),
-                mod3_loop_wavetable: AM3.loop_wavetable,
-                mod3_single_cycle: AM3.single_cycle,
-                mod3_restretch: AM3.restretch,
-                mod3_prev_restretch: AM3.prev_restretch,
-                mod3_start_position: AM3.start_position,
-                mod3_end_position: AM3._end_position,
-                mod3_grain_crossfade: AM3.grain_crossfade,
-                mod3_grain_gap: AM3.grain_gap,
-                mod3_grain_hold: AM3.grain_hold,
-
-                // Osc module knob storage
-                mod3_osc_octave: AM3.osc_octave,
-                mod3_osc_semitones: AM3.osc_semitones,
-                mod3_osc_detune: AM3.osc_detune,
-                mod3_osc_attack: AM3.osc_attack,
-                mod3_osc_decay: AM3.osc_decay,
-                mod3_osc_sustain: AM3.osc_sustain,
-                mod3_osc_release: AM3.osc_release,
-                mod3_osc_retrigger: AM3.osc_retrigger,
-                mod3_osc_atk_curve: AM3.osc_atk_curve,
-                mod3_osc_dec_curve: AM3.osc_dec_curve,
-                mod3_osc_rel_curve: AM3.osc_rel_curve,
-                mod3_osc_unison: AM3.osc_unison,
-                mod3_osc_unison_detune: AM3.osc_unison_detune,
-                mod3_osc_stereo: AM3.osc_stereo,
-
-                // Filter storage - gotten from params
-                filter_wet: self.params.filter_wet.value(),
-                filter_cutoff: self.params.filter_cutoff.value(),
-                filter_resonance: self.params.filter_resonance.value(),
-                filter_res_type: self.params.filter_res_type.value(),
-                filter_lp_amount: self.params.filter_lp_amount.value(),
-                filter_hp_amount: self.params.filter_hp_amount.value(),
-                filter_bp_amount: self.params.filter_bp_amount.value(),
-                filter_env_peak: self.params.filter_env_peak.value(),
-                filter_env_attack: self.params.filter_env_attack.value(),
-                filter_env_decay: self.params.filter_env_decay.value(),
-                filter_env_sustain: self.params.filter_env_sustain.value(),
-                filter_env_release: self.params.filter_env_release.value(),
-                filter_env_atk_curve: self.params.filter_env_atk_curve.value(),
-                filter_env_dec_curve: self.params.filter_env_dec_curve.value(),
-                filter_env_rel_curve: self.params.filter_env_rel_curve.value(),
-                filter_alg_type: self.params.filter_alg_type.value(),
-                tilt_filter_type: self.params.tilt_filter_type.value(),
-
-                filter_wet_2: self.params.filter_wet_2.value(),
-                filter_cutoff_2: self.params.filter_cutoff_2.value(),
-                filter_resonance_2: self.params.filter_resonance_2.value(),
-                filter_res_type_2: self.params.filter_res_type_2.value(),
-                filter_lp_amount_2: self.params.filter_lp_amount_2.value(),
-                filter_hp_amount_2: self.params.filter_hp_amount_2.value(),
-                filter_bp_amount_2: self.params.filter_bp_amount_2.value(),
-                filter_env_peak_2: self.params.filter_env_peak_2.value(),
-                filter_env_attack_2: self.params.filter_env_attack_2.value(),
-                filter_env_decay_2: self.params.filter_env_decay_2.value(),
-                filter_env_sustain_2: self.params.filter_env_sustain_2.value(),
-                filter_env_release_2: self.params.filter_env_release_2.value(),
-                filter_env_atk_curve_2: self.params.filter_env_atk_curve_2.value(),
-                filter_env_dec_curve_2: self.params.filter_env_dec_curve_2.value(),
-                filter_env_rel_curve_2: self.params.filter_env_rel_curve_2.value(),
-                filter_alg_type_2: self.params.filter_alg_type_2.value(),
-                tilt_filter_type_2: self.params.tilt_filter_type_2.value(),
-
-                filter_routing: self.params.filter_routing.value(),
-                filter_cutoff_link: self.params.filter_cutoff_link.value(),
-
-                // Pitch
-                pitch_enable: self.params.pitch_enable.value(),
-                pitch_env_atk_curve: self.params.pitch_env_atk_curve.value(),
-                pitch_env_dec_curve: self.params.pitch_env_dec_curve.value(),
-                pitch_env_rel_curve: self.params.pitch_env_rel_curve.value(),
-                pitch_env_attack: self.params.pitch_env_attack.value(),
-                pitch_env_decay: self.params.pitch_env_decay.value(),
-                pitch_env_sustain: self.params.pitch_env_sustain.value(),
-                pitch_env_release: self.params.pitch_env_release.value(),
-                pitch_env_peak: self.params.pitch_env_peak.value(),
-                pitch_routing: self.params.pitch_routing.value(),
-
-                pitch_enable_2: self.params.pitch_enable_2.value(),
-                pitch_env_atk_curve_2: self.params.pitch_env_atk_curve_2.value(),
-                pitch_env_dec_curve_2: self.params.pitch_env_dec_curve_2.value(),
-                pitch_env_rel_curve_2: self.params.pitch_env_rel_curve_2.value(),
-                pitch_env_attack_2: self.params.pitch_env_attack_2.value(),
-                pitch_env_decay_2: self.params.pitch_env_decay_2.value(),
-                pitch_env_sustain_2: self.params.pitch_env_sustain_2.value(),
-                pitch_env_release_2: self.params.pitch_env_release_2.value(),
-                pitch_env_peak_2: self.params.pitch_env_peak_2.value(),
-                pitch_routing_2: self.params.pitch_routing_2.value(),
-
-                // LFOs
-                lfo1_enable: self.params.lfo1_enable.value(),
-                lfo2_enable: self.params.lfo2_enable.value(),
-                lfo3_enable: self.params.lfo3_enable.value(),
-
-                lfo1_freq: self.params.lfo1_freq.value(),
-                lfo1_retrigger: self.params.lfo1_retrigger.value(),
-                lfo1_sync: self.params.lfo1_sync.value(),
-                lfo1_snap: self.params.lfo1_snap.value(),
-                lfo1_waveform: self.params.lfo1_waveform.value(),
-                lfo1_phase: self.params.lfo1_phase.value(),
-
-                lfo2_freq: self.params.lfo2_freq.value(),
-                lfo2_retrigger: self.params.lfo2_retrigger.value(),
-                lfo2_sync: self.params.lfo2_sync.value(),
-                lfo2_snap: self.params.lfo2_snap.value(),
-                lfo2_waveform: self.params.lfo2_waveform.value(),
-                lfo2_phase: self.params.lfo2_phase.value(),
-
-                lfo3_freq: self.params.lfo3_freq.value(),
-                lfo3_retrigger: self.params.lfo3_retrigger.value(),
-                lfo3_sync: self.params.lfo3_sync.value(),
-                lfo3_snap: self.params.lfo3_snap.value(),
-                lfo3_waveform: self.params.lfo3_waveform.value(),
-                lfo3_phase: self.params.lfo3_phase.value(),
-
-                mod_source_1: self.params.mod_source_1.value(),
-                mod_source_2: self.params.mod_source_2.value(),
-                mod_source_3: self.params.mod_source_3.value(),
-                mod_source_4: self.params.mod_source_4.value(),
-                mod_dest_1: self.params.mod_destination_1.value(),
-                mod_dest_2: self.params.mod_destination_2.value(),
-                mod_dest_3: self.params.mod_destination_3.value(),
-                mod_dest_4: self.params.mod_destination_4.value(),
-                mod_amount_1: self.params.mod_amount_knob_1.value(),
-                mod_amount_2: self.params.mod_amount_knob_2.value(),
-                mod_amount_3: self.params.mod_amount_knob_3.value(),
-                mod_amount_4: self.params.mod_amount_knob_4.value(),
-
-                fm_one_to_two: self.params.fm_one_to_two.value(),
-                fm_one_to_three: self.params.fm_one_to_three.value(),
-                fm_two_to_three: self.params.fm_two_to_three.value(),
-                fm_cycles: self.params.fm_cycles.value(),
-                fm_attack: self.params.fm_attack.value(),
-                fm_decay: self.params.fm_decay.value(),
-                fm_sustain: self.params.fm_sustain.value(),
-                fm_release: self.params.fm_release.value(),
-                fm_attack_curve: self.params.fm_attack_curve.value(),
-                fm_decay_curve: self.params.fm_decay_curve.value(),
-                fm_release_curve: self.params.fm_release_curve.value(),
-
-                pre_use_eq: self.params.pre_use_eq.value(),
-                pre_low_freq: self.params.pre_low_freq.value(),
-                pre_mid_freq: self.params.pre_mid_freq.value(),
-                pre_high_freq: self.params.pre_high_freq.value(),
-                pre_low_gain: self.params.pre_low_gain.value(),
-                pre_mid_gain: self.params.pre_mid_gain.value(),
-                pre_high_gain: self.params.pre_high_gain.value(),
-
-                stereo_algorithm: self.params.stereo_algorithm.value().clone(),
-
-                use_fx: self.params.use_fx.value(),
-                use_compressor: self.params.use_compressor.value(),
-                comp_amt: self.params.comp_amt.value(),
-                comp_atk: self.params.comp_atk.value(),
-                comp_rel: self.params.comp_rel.value(),
-                comp_drive: self.params.comp_drive.value(),
-                use_abass: self.params.use_abass.value(),
-                abass_amount: self.params.abass_amount.value(),
-                use_saturation: self.params.use_saturation.value(),
-                sat_amount: self.params.sat_amt.value(),
-                sat_type: self.params.sat_type.value(),
-                use_delay: self.params.use_delay.value(),
-                delay_amount: self.params.delay_amount.value(),
-                delay_time: self.params.delay_time.value(),
-                delay_decay: self.params.delay_decay.value(),
-                delay_type: self.params.delay_type.value(),
-                use_reverb: self.params.use_reverb.value(),
-                reverb_model: self.params.reverb_model.value(),
-                reverb_amount: self.params.reverb_amount.value(),
-                reverb_size: self.params.reverb_size.value(),
-                reverb_feedback: self.params.reverb_feedback.value(),
-                use_chorus: self.params.use_chorus.value(),
-                chorus_amount: self.params.chorus_amount.value(),
-                chorus_range: self.params.chorus_range.value(),
-                chorus_speed: self.params.chorus_speed.value(),
-                use_phaser: self.params.use_phaser.value(),
-                phaser_amount: self.params.phaser_amount.value(),
-                phaser_depth: self.params.phaser_depth.value(),
-                phaser_rate: self.params.phaser_rate.value(),
-                phaser_feedback: self.params.phaser_feedback.value(),
-                use_buffermod: self.params.use_buffermod.value(),
-                buffermod_amount: self.params.buffermod_amount.value(),
-                buffermod_depth: self.params.buffermod_depth.value(),
-                buffermod_rate: self.params.buffermod_rate.value(),
-                buffermod_spread: self.params.buffermod_spread.value(),
-                buffermod_timing: self.params.buffermod_timing.value(),
-                use_flanger: self.params.use_flanger.value(),
-                flanger_amount: self.params.flanger_amount.value(),
-                flanger_depth: self.params.flanger_depth.value(),
-                flanger_rate: self.params.flanger_rate.value(),
-                flanger_feedback: self.params.flanger_feedback.value(),
-                use_limiter: self.params.use_limiter.value(),
-                limiter_threshold: self.params.limiter_threshold.value(),
-                limiter_knee: self.params.limiter_knee.value(),
-
-                additive_amp_1_0: self.params.additive_amp_1_0.value(),
-                additive_amp_1_1: self.params.additive_amp_1_1.value(),
-                additive_amp_1_2: self.params.additive_amp_1_2.value(),
-                additive_amp_1_3: self.params.additive_amp_1_3.value(),
-                additive_amp_1_4: self.params.additive_amp_1_4.value(),
-                additive_amp_1_5: self.params.additive_amp_1_5.value(),
-                additive_amp_1_6: self.params.additive_amp_1_6.value(),
-                additive_amp_1_7: self.params.additive_amp_1_7.value(),
-                additive_amp_1_8: self.params.additive_amp_1_8.value(),
-                additive_amp_1_9: self.params.additive_amp_1_9.value(),
-                additive_amp_1_10: self.params.additive_amp_1_10.value(),
-                additive_amp_1_11: self.params.additive_amp_1_11.value(),
-                additive_amp_1_12: self.params.additive_amp_1_12.value(),
-                additive_amp_1_13: self.params.additive_amp_1_13.value(),
-                additive_amp_1_14: self.params.additive_amp_1_14.value(),
-                additive_amp_1_15: self.params.additive_amp_1_15.value(),
-                additive_amp_2_0: self.params.additive_amp_2_0.value(),
-                additive_amp_2_1: self.params.additive_amp_2_1.value(),
-                additive_amp_2_2: self.params.additive_amp_2_2.value(),
-                additive_amp_2_3: self.params.additive_amp_2_3.value(),
-                additive_amp_2_4: self.params.additive_amp_2_4.value(),
-                additive_amp_2_5: self.params.additive_amp_2_5.value(),
-                additive_amp_2_6: self.params.additive_amp_2_6.value(),
-                additive_amp_2_7: self.params.additive_amp_2_7.value(),
-                additive_amp_2_8: self.params.additive_amp_2_8.value(),
-                additive_amp_2_9: self.params.additive_amp_2_9.value(),
-                additive_amp_2_10: self.params.additive_amp_2_10.value(),
-                additive_amp_2_11: self.params.additive_amp_2_11.value(),
-                additive_amp_2_12: self.params.additive_amp_2_12.value(),
-                additive_amp_2_13: self.params.additive_amp_2_13.value(),
-                additive_amp_2_14: self.params.additive_amp_2_14.value(),
-                additive_amp_2_15: self.params.additive_amp_2_15.value(),
-                additive_amp_3_0: self.params.additive_amp_3_0.value(),
-                additive_amp_3_1: self.params.additive_amp_3_1.value(),
-                additive_amp_3_2: self.params.additive_amp_3_2.value(),
-                additive_amp_3_3: self.params.additive_amp_3_3.value(),
-                additive_amp_3_4: self.params.additive_amp_3_4.value(),
-                additive_amp_3_5: self.params.additive_amp_3_5.value(),
-                additive_amp_3_6: self.params.additive_amp_3_6.value(),
-                additive_amp_3_7: self.params.additive_amp_3_7.value(),
-                additive_amp_3_8: self.params.additive_amp_3_8.value(),
-                additive_amp_3_9: self.params.additive_amp_3_9.value(),
-                additive_amp_3_10: self.params.additive_amp_3_10.value(),
-                additive_amp_3_11: self.params.additive_amp_3_11.value(),
-                additive_amp_3_12: self.params.additive_amp_3_12.value(),
-                additive_amp_3_13: self.params.additive_amp_3_13.value(),
-                additive_amp_3_14: self.params.additive_amp_3_14.value(),
-                additive_amp_3_15: self.params.additive_amp_3_15.value(),
-            };
+        // First save of a patch stamps when it was made, edits after that keep the original date
+        {
+            let mut preset_created = self.params.preset_created_p.lock().unwrap();
+            if preset_created.is_empty() {
+                *preset_created = preset_timestamp();
+            }
+        }
+        *arc_lib.lock().unwrap() = Actuate::snapshot_preset(&self.params, &AM1, &AM2, &AM3);
+    }
+
+    // Build a preset from the live params and audio modules - used by preset updates and A/B compare
+    fn snapshot_preset(
+        params: &ActuateParams,
+        AM1: &AudioModule,
+        AM2: &AudioModule,
+        AM3: &AudioModule,
+    ) -> ActuatePresetV131 {
+        ActuatePresetV131 {
+            preset_name: params.preset_name_p.lock().unwrap().clone(),
+            preset_info: params.preset_info_p.lock().unwrap().clone(),
+            preset_author: params.preset_author_p.lock().unwrap().clone(),
+            preset_created: params.preset_created_p.lock().unwrap().clone(),
+            preset_category: params.preset_category.value(),
+            tag_acid: params.tag_acid.value(),
+            tag_analog: params.tag_analog.value(),
+            tag_bright: params.tag_bright.value(),
+            tag_chord: params.tag_chord.value(),
+            tag_crisp: params.tag_crisp.value(),
+            tag_deep: params.tag_deep.value(),
+            tag_delicate: params.tag_delicate.value(),
+            tag_hard: params.tag_hard.value(),
+            tag_harsh: params.tag_harsh.value(),
+            tag_lush: params.tag_lush.value(),
+            tag_mellow: params.tag_mellow.value(),
+            tag_resonant: params.tag_resonant.value(),
+            tag_rich: params.tag_rich.value(),
+            tag_sharp: params.tag_sharp.value(),
+            tag_silky: params.tag_silky.value(),
+            tag_smooth: params.tag_smooth.value(),
+            tag_soft: params.tag_soft.value(),
+            tag_stab: params.tag_stab.value(),
+            tag_warm: params.tag_warm.value(),
+            // Modules 1
+            ///////////////////////////////////////////////////////////
+            mod1_audio_module_type: params.audio_module_1_type.value(),
+            mod1_audio_module_level: params.audio_module_1_level.value(),
+            mod1_audio_module_routing: params.audio_module_1_routing.value(),
+            // Granulizer/Sampler
+            mod1_loaded_sample: AM1.loaded_sample.clone(),
+            mod1_sample_lib: AM1.sample_lib.clone(),
+            mod1_loop_wavetable: AM1.loop_wavetable,
+            mod1_single_cycle: AM1.single_cycle,
+            mod1_restretch: AM1.restretch,
+            mod1_prev_restretch: AM1.prev_restretch,
+            mod1_start_position: AM1.start_position,
+            mod1_end_position: AM1._end_position,
+            mod1_grain_crossfade: AM1.grain_crossfade,
+            mod1_grain_gap: AM1.grain_gap,
+            mod1_grain_hold: AM1.grain_hold,
+
+            // Osc module knob storage
+            mod1_osc_octave: AM1.osc_octave,
+            mod1_osc_semitones: AM1.osc_semitones,
+            mod1_osc_detune: AM1.osc_detune,
+            mod1_osc_attack: AM1.osc_attack,
+            mod1_osc_decay: AM1.osc_decay,
+            mod1_osc_sustain: AM1.osc_sustain,
+            mod1_osc_release: AM1.osc_release,
+            mod1_osc_retrigger: AM1.osc_retrigger,
+            mod1_osc_atk_curve: AM1.osc_atk_curve,
+            mod1_osc_dec_curve: AM1.osc_dec_curve,
+            mod1_osc_rel_curve: AM1.osc_rel_curve,
+            mod1_osc_unison: AM1.osc_unison,
+            mod1_osc_unison_detune: AM1.osc_unison_detune,
+            mod1_osc_stereo: AM1.osc_stereo,
+
+            // Modules 2
+            ///////////////////////////////////////////////////////////
+            mod2_audio_module_type: params.audio_module_2_type.value(),
+            mod2_audio_module_level: params.audio_module_2_level.value(),
+            mod2_audio_module_routing: params.audio_module_2_routing.value(),
+            // Granulizer/Sampler
+            mod2_loaded_sample: AM2.loaded_sample.clone(),
+            mod2_sample_lib: AM2.sample_lib.clone(),
+            mod2_loop_wavetable: AM2.loop_wavetable,
+            mod2_single_cycle: AM2.single_cycle,
+            mod2_restretch: AM2.restretch,
+            mod2_prev_restretch: AM2.prev_restretch,
+            mod2_start_position: AM2.start_position,
+            mod2_end_position: AM2._end_position,
+            mod2_grain_crossfade: AM2.grain_crossfade,
+            mod2_grain_gap: AM2.grain_gap,
+            mod2_grain_hold: AM2.grain_hold,
+
+            // Osc module knob storage
+            mod2_osc_octave: AM2.osc_octave,
+            mod2_osc_semitones: AM2.osc_semitones,
+            mod2_osc_detune: AM2.osc_detune,
+            mod2_osc_attack: AM2.osc_attack,
+            mod2_osc_decay: AM2.osc_decay,
+            mod2_osc_sustain: AM2.osc_sustain,
+            mod2_osc_release: AM2.osc_release,
+            mod2_osc_retrigger: AM2.osc_retrigger,
+            mod2_osc_atk_curve: AM2.osc_atk_curve,
+            mod2_osc_dec_curve: AM2.osc_dec_curve,
+            mod2_osc_rel_curve: AM2.osc_rel_curve,
+            mod2_osc_unison: AM2.osc_unison,
+            mod2_osc_unison_detune: AM2.osc_unison_detune,
+            mod2_osc_stereo: AM2.osc_stereo,
+
+            // Modules 3
+            ///////////////////////////////////////////////////////////
+            mod3_audio_module_type: params.audio_module_3_type.value(),
+            mod3_audio_module_level: params.audio_module_3_level.value(),
+            mod3_audio_module_routing: params.audio_module_3_routing.value(),
+            // Granulizer/Sampler
+            mod3_loaded_sample: AM3.loaded_sample.clone(),
+            mod3_sample_lib: AM3.sample_lib.clone(),
+            mod3_loop_wavetable: AM3.loop_wavetable,
+            mod3_single_cycle: AM3.single_cycle,
+            mod3_restretch: AM3.restretch,
+            mod3_prev_restretch: AM3.prev_restretch,
+            mod3_start_position: AM3.start_position,
+            mod3_end_position: AM3._end_position,
+            mod3_grain_crossfade: AM3.grain_crossfade,
+            mod3_grain_gap: AM3.grain_gap,
+            mod3_grain_hold: AM3.grain_hold,
+
+            // Osc module knob storage
+            mod3_osc_octave: AM3.osc_octave,
+            mod3_osc_semitones: AM3.osc_semitones,
+            mod3_osc_detune: AM3.osc_detune,
+            mod3_osc_attack: AM3.osc_attack,
+            mod3_osc_decay: AM3.osc_decay,
+            mod3_osc_sustain: AM3.osc_sustain,
+            mod3_osc_release: AM3.osc_release,
+            mod3_osc_retrigger: AM3.osc_retrigger,
+            mod3_osc_atk_curve: AM3.osc_atk_curve,
+            mod3_osc_dec_curve: AM3.osc_dec_curve,
+            mod3_osc_rel_curve: AM3.osc_rel_curve,
+            mod3_osc_unison: AM3.osc_unison,
+            mod3_osc_unison_detune: AM3.osc_unison_detune,
+            mod3_osc_stereo: AM3.osc_stereo,
+
+            // Filter storage - gotten from params
+            filter_wet: params.filter_wet.value(),
+            filter_cutoff: params.filter_cutoff.value(),
+            filter_resonance: params.filter_resonance.value(),
+            filter_res_type: params.filter_res_type.value(),
+            filter_lp_amount: params.filter_lp_amount.value(),
+            filter_hp_amount: params.filter_hp_amount.value(),
+            filter_bp_amount: params.filter_bp_amount.value(),
+            filter_env_peak: params.filter_env_peak.value(),
+            filter_env_attack: params.filter_env_attack.value(),
+            filter_env_decay: params.filter_env_decay.value(),
+            filter_env_sustain: params.filter_env_sustain.value(),
+            filter_env_release: params.filter_env_release.value(),
+            filter_env_atk_curve: params.filter_env_atk_curve.value(),
+            filter_env_dec_curve: params.filter_env_dec_curve.value(),
+            filter_env_rel_curve: params.filter_env_rel_curve.value(),
+            filter_alg_type: params.filter_alg_type.value(),
+            tilt_filter_type: params.tilt_filter_type.value(),
+
+            filter_wet_2: params.filter_wet_2.value(),
+            filter_cutoff_2: params.filter_cutoff_2.value(),
+            filter_resonance_2: params.filter_resonance_2.value(),
+            filter_res_type_2: params.filter_res_type_2.value(),
+            filter_lp_amount_2: params.filter_lp_amount_2.value(),
+            filter_hp_amount_2: params.filter_hp_amount_2.value(),
+            filter_bp_amount_2: params.filter_bp_amount_2.value(),
+            filter_env_peak_2: params.filter_env_peak_2.value(),
+            filter_env_attack_2: params.filter_env_attack_2.value(),
+            filter_env_decay_2: params.filter_env_decay_2.value(),
+            filter_env_sustain_2: params.filter_env_sustain_2.value(),
+            filter_env_release_2: params.filter_env_release_2.value(),
+            filter_env_atk_curve_2: params.filter_env_atk_curve_2.value(),
+            filter_env_dec_curve_2: params.filter_env_dec_curve_2.value(),
+            filter_env_rel_curve_2: params.filter_env_rel_curve_2.value(),
+            filter_alg_type_2: params.filter_alg_type_2.value(),
+            tilt_filter_type_2: params.tilt_filter_type_2.value(),
+
+            filter_routing: params.filter_routing.value(),
+            filter_cutoff_link: params.filter_cutoff_link.value(),
+
+            // Pitch
+            pitch_enable: params.pitch_enable.value(),
+            pitch_env_atk_curve: params.pitch_env_atk_curve.value(),
+            pitch_env_dec_curve: params.pitch_env_dec_curve.value(),
+            pitch_env_rel_curve: params.pitch_env_rel_curve.value(),
+            pitch_env_attack: params.pitch_env_attack.value(),
+            pitch_env_decay: params.pitch_env_decay.value(),
+            pitch_env_sustain: params.pitch_env_sustain.value(),
+            pitch_env_release: params.pitch_env_release.value(),
+            pitch_env_peak: params.pitch_env_peak.value(),
+            pitch_routing: params.pitch_routing.value(),
+
+            pitch_enable_2: params.pitch_enable_2.value(),
+            pitch_env_atk_curve_2: params.pitch_env_atk_curve_2.value(),
+            pitch_env_dec_curve_2: params.pitch_env_dec_curve_2.value(),
+            pitch_env_rel_curve_2: params.pitch_env_rel_curve_2.value(),
+            pitch_env_attack_2: params.pitch_env_attack_2.value(),
+            pitch_env_decay_2: params.pitch_env_decay_2.value(),
+            pitch_env_sustain_2: params.pitch_env_sustain_2.value(),
+            pitch_env_release_2: params.pitch_env_release_2.value(),
+            pitch_env_peak_2: params.pitch_env_peak_2.value(),
+            pitch_routing_2: params.pitch_routing_2.value(),
+
+            // LFOs
+            lfo1_enable: params.lfo1_enable.value(),
+            lfo2_enable: params.lfo2_enable.value(),
+            lfo3_enable: params.lfo3_enable.value(),
+
+            lfo1_freq: params.lfo1_freq.value(),
+            lfo1_retrigger: params.lfo1_retrigger.value(),
+            lfo1_sync: params.lfo1_sync.value(),
+            lfo1_snap: params.lfo1_snap.value(),
+            lfo1_waveform: params.lfo1_waveform.value(),
+            lfo1_phase: params.lfo1_phase.value(),
+
+            lfo2_freq: params.lfo2_freq.value(),
+            lfo2_retrigger: params.lfo2_retrigger.value(),
+            lfo2_sync: params.lfo2_sync.value(),
+            lfo2_snap: params.lfo2_snap.value(),
+            lfo2_waveform: params.lfo2_waveform.value(),
+            lfo2_phase: params.lfo2_phase.value(),
+
+            lfo3_freq: params.lfo3_freq.value(),
+            lfo3_retrigger: params.lfo3_retrigger.value(),
+            lfo3_sync: params.lfo3_sync.value(),
+            lfo3_snap: params.lfo3_snap.value(),
+            lfo3_waveform: params.lfo3_waveform.value(),
+            lfo3_phase: params.lfo3_phase.value(),
+
+            mod_source_1: params.mod_source_1.value(),
+            mod_source_2: params.mod_source_2.value(),
+            mod_source_3: params.mod_source_3.value(),
+            mod_source_4: params.mod_source_4.value(),
+            mod_dest_1: params.mod_destination_1.value(),
+            mod_dest_2: params.mod_destination_2.value(),
+            mod_dest_3: params.mod_destination_3.value(),
+            mod_dest_4: params.mod_destination_4.value(),
+            mod_amount_1: params.mod_amount_knob_1.value(),
+            mod_amount_2: params.mod_amount_knob_2.value(),
+            mod_amount_3: params.mod_amount_knob_3.value(),
+            mod_amount_4: params.mod_amount_knob_4.value(),
+
+            fm_one_to_two: params.fm_one_to_two.value(),
+            fm_one_to_three: params.fm_one_to_three.value(),
+            fm_two_to_three: params.fm_two_to_three.value(),
+            fm_cycles: params.fm_cycles.value(),
+            fm_attack: params.fm_attack.value(),
+            fm_decay: params.fm_decay.value(),
+            fm_sustain: params.fm_sustain.value(),
+            fm_release: params.fm_release.value(),
+            fm_attack_curve: params.fm_attack_curve.value(),
+            fm_decay_curve: params.fm_decay_curve.value(),
+            fm_release_curve: params.fm_release_curve.value(),
+
+            pre_use_eq: params.pre_use_eq.value(),
+            pre_low_freq: params.pre_low_freq.value(),
+            pre_mid_freq: params.pre_mid_freq.value(),
+            pre_high_freq: params.pre_high_freq.value(),
+            pre_low_gain: params.pre_low_gain.value(),
+            pre_mid_gain: params.pre_mid_gain.value(),
+            pre_high_gain: params.pre_high_gain.value(),
+
+            stereo_algorithm: params.stereo_algorithm.value().clone(),
+
+            use_fx: params.use_fx.value(),
+            use_compressor: params.use_compressor.value(),
+            comp_amt: params.comp_amt.value(),
+            comp_atk: params.comp_atk.value(),
+            comp_rel: params.comp_rel.value(),
+            comp_drive: params.comp_drive.value(),
+            use_abass: params.use_abass.value(),
+            abass_amount: params.abass_amount.value(),
+            use_saturation: params.use_saturation.value(),
+            sat_amount: params.sat_amt.value(),
+            sat_type: params.sat_type.value(),
+            use_delay: params.use_delay.value(),
+            delay_amount: params.delay_amount.value(),
+            delay_time: params.delay_time.value(),
+            delay_decay: params.delay_decay.value(),
+            delay_type: params.delay_type.value(),
+            use_reverb: params.use_reverb.value(),
+            reverb_model: params.reverb_model.value(),
+            reverb_amount: params.reverb_amount.value(),
+            reverb_size: params.reverb_size.value(),
+            reverb_feedback: params.reverb_feedback.value(),
+            use_chorus: params.use_chorus.value(),
+            chorus_amount: params.chorus_amount.value(),
+            chorus_range: params.chorus_range.value(),
+            chorus_speed: params.chorus_speed.value(),
+            use_phaser: params.use_phaser.value(),
+            phaser_amount: params.phaser_amount.value(),
+            phaser_depth: params.phaser_depth.value(),
+            phaser_rate: params.phaser_rate.value(),
+            phaser_feedback: params.phaser_feedback.value(),
+            use_buffermod: params.use_buffermod.value(),
+            buffermod_amount: params.buffermod_amount.value(),
+            buffermod_depth: params.buffermod_depth.value(),
+            buffermod_rate: params.buffermod_rate.value(),
+            buffermod_spread: params.buffermod_spread.value(),
+            buffermod_timing: params.buffermod_timing.value(),
+            use_flanger: params.use_flanger.value(),
+            flanger_amount: params.flanger_amount.value(),
+            flanger_depth: params.flanger_depth.value(),
+            flanger_rate: params.flanger_rate.value(),
+            flanger_feedback: params.flanger_feedback.value(),
+            use_limiter: params.use_limiter.value(),
+            limiter_threshold: params.limiter_threshold.value(),
+            limiter_knee: params.limiter_knee.value(),
+
+            additive_amp_1_0: params.additive_amp_1_0.value(),
+            additive_amp_1_1: params.additive_amp_1_1.value(),
+            additive_amp_1_2: params.additive_amp_1_2.value(),
+            additive_amp_1_3: params.additive_amp_1_3.value(),
+            additive_amp_1_4: params.additive_amp_1_4.value(),
+            additive_amp_1_5: params.additive_amp_1_5.value(),
+            additive_amp_1_6: params.additive_amp_1_6.value(),
+            additive_amp_1_7: params.additive_amp_1_7.value(),
+            additive_amp_1_8: params.additive_amp_1_8.value(),
+            additive_amp_1_9: params.additive_amp_1_9.value(),
+            additive_amp_1_10: params.additive_amp_1_10.value(),
+            additive_amp_1_11: params.additive_amp_1_11.value(),
+            additive_amp_1_12: params.additive_amp_1_12.value(),
+            additive_amp_1_13: params.additive_amp_1_13.value(),
+            additive_amp_1_14: params.additive_amp_1_14.value(),
+            additive_amp_1_15: params.additive_amp_1_15.value(),
+            additive_amp_2_0: params.additive_amp_2_0.value(),
+            additive_amp_2_1: params.additive_amp_2_1.value(),
+            additive_amp_2_2: params.additive_amp_2_2.value(),
+            additive_amp_2_3: params.additive_amp_2_3.value(),
+            additive_amp_2_4: params.additive_amp_2_4.value(),
+            additive_amp_2_5: params.additive_amp_2_5.value(),
+            additive_amp_2_6: params.additive_amp_2_6.value(),
+            additive_amp_2_7: params.additive_amp_2_7.value(),
+            additive_amp_2_8: params.additive_amp_2_8.value(),
+            additive_amp_2_9: params.additive_amp_2_9.value(),
+            additive_amp_2_10: params.additive_amp_2_10.value(),
+            additive_amp_2_11: params.additive_amp_2_11.value(),
+            additive_amp_2_12: params.additive_amp_2_12.value(),
+            additive_amp_2_13: params.additive_amp_2_13.value(),
+            additive_amp_2_14: params.additive_amp_2_14.value(),
+            additive_amp_2_15: params.additive_amp_2_15.value(),
+            additive_amp_3_0: params.additive_amp_3_0.value(),
+            additive_amp_3_1: params.additive_amp_3_1.value(),
+            additive_amp_3_2: params.additive_amp_3_2.value(),
+            additive_amp_3_3: params.additive_amp_3_3.value(),
+            additive_amp_3_4: params.additive_amp_3_4.value(),
+            additive_amp_3_5: params.additive_amp_3_5.value(),
+            additive_amp_3_6: params.additive_amp_3_6.value(),
+            additive_amp_3_7: params.additive_amp_3_7.value(),
+            additive_amp_3_8: params.additive_amp_3_8.value(),
+            additive_amp_3_9: params.additive_amp_3_9.value(),
+            additive_amp_3_10: params.additive_amp_3_10.value(),
+            additive_amp_3_11: params.additive_amp_3_11.value(),
+            additive_amp_3_12: params.additive_amp_3_12.value(),
+            additive_amp_3_13: params.additive_amp_3_13.value(),
+            additive_amp_3_14: params.additive_amp_3_14.value(),
+            additive_amp_3_15: params.additive_amp_3_15.value(),
+            master_tilt: params.master_tilt.value(),
+            lfo1_depth: params.lfo1_depth.value(),
+            lfo2_depth: params.lfo2_depth.value(),
+            lfo3_depth: params.lfo3_depth.value(),
+            ring_mod_1x2: params.ring_mod_1x2.value(),
+            ring_mod_2x3: params.ring_mod_2x3.value(),
+            sat_stereo_mode: params.sat_stereo_mode.value(),
+            humanize: params.humanize.value(),
+            mod1_osc_super: params.osc_1_super.value(),
+            mod2_osc_super: params.osc_2_super.value(),
+            mod3_osc_super: params.osc_3_super.value(),
+            filter_drive: params.filter_drive.value(),
+            filter_drive_2: params.filter_drive_2.value(),
+            osc_quality: params.osc_quality.value(),
+            release_vel_amount: params.release_vel_amount.value(),
+            mono_mode: params.mono_mode.value(),
+            glide_time: params.glide_time.value(),
+            mod1_osc_type_b: params.osc_1_type_b.value(),
+            mod2_osc_type_b: params.osc_2_type_b.value(),
+            mod3_osc_type_b: params.osc_3_type_b.value(),
+            mod1_osc_morph: params.osc_1_morph.value(),
+            mod2_osc_morph: params.osc_2_morph.value(),
+            mod3_osc_morph: params.osc_3_morph.value(),
+            use_transient: params.use_transient.value(),
+            transient_attack: params.transient_attack.value(),
+            transient_sustain: params.transient_sustain.value(),
+            filter_auto_gain: params.filter_auto_gain.value(),
+            global_phase_reset: params.global_phase_reset.value(),
+            delay_pitch: params.delay_pitch.value(),
+            pitch_mod_quantize: params.pitch_mod_quantize.value(),
+            pitch_mod_scale: params.pitch_mod_scale.value(),
+            mod1_grain_window: params.grain_window_1.value(),
+            mod2_grain_window: params.grain_window_2.value(),
+            mod3_grain_window: params.grain_window_3.value(),
+            use_delay_2: params.use_delay_2.value(),
+            delay_2_amount: params.delay_2_amount.value(),
+            delay_2_time: params.delay_2_time.value(),
+            delay_2_decay: params.delay_2_decay.value(),
+            delay_2_type: params.delay_2_type.value(),
+            delay_routing: params.delay_routing.value(),
+            filter_env_loop: params.filter_env_loop.value(),
+            filter_env_loop_rate: params.filter_env_loop_rate.value(),
+            voice_spread: params.voice_spread.value(),
+            filter_env_invert_1: params.filter_env_invert_1.value(),
+            filter_env_invert_2: params.filter_env_invert_2.value(),
+            mod1_sample_root: params.sample_root_1.value(),
+            mod2_sample_root: params.sample_root_2.value(),
+            mod3_sample_root: params.sample_root_3.value(),
+            mod1_stretch_factor: params.stretch_factor_1.value(),
+            mod2_stretch_factor: params.stretch_factor_2.value(),
+            mod3_stretch_factor: params.stretch_factor_3.value(),
+            voice_steal_mode: params.voice_steal_mode.value(),
+            lfo1_gate: params.lfo1_gate.value(),
+            lfo2_gate: params.lfo2_gate.value(),
+            lfo3_gate: params.lfo3_gate.value(),
+            mod1_osc_shape: params.osc_1_shape.value(),
+            mod1_osc_shape_amount: params.osc_1_shape_amount.value(),
+            mod2_osc_shape: params.osc_2_shape.value(),
+            mod2_osc_shape_amount: params.osc_2_shape_amount.value(),
+            mod3_osc_shape: params.osc_3_shape.value(),
+            mod3_osc_shape_amount: params.osc_3_shape_amount.value(),
+            reverb_predelay: params.reverb_predelay.value(),
+            filter_res_link: params.filter_res_link.value(),
+            filter_env_link: params.filter_env_link.value(),
+            phaser_stereo: params.phaser_stereo.value(),
+            flanger_stereo: params.flanger_stereo.value(),
+            buffermod_stereo: params.buffermod_stereo.value(),
+            tuning_system: params.tuning_system.value(),
+            tuning_root: params.tuning_root.value(),
+            mod1_osc_warmth: params.osc_1_warmth.value(),
+            mod2_osc_warmth: params.osc_2_warmth.value(),
+            mod3_osc_warmth: params.osc_3_warmth.value(),
+            master_hpf: params.master_hpf.value(),
+            limiter_ceiling: params.limiter_ceiling.value(),
+            use_gate: params.use_gate.value(),
+            gate_rate: params.gate_rate.value(),
+            gate_shape: params.gate_shape.value(),
+            gate_smooth: params.gate_smooth.value(),
+            env_time_range: params.env_time_range.value(),
+            mod1_osc_drawbar_sub: params.osc_1_drawbar_sub.value(),
+            mod1_osc_drawbar_root: params.osc_1_drawbar_root.value(),
+            mod1_osc_drawbar_oct: params.osc_1_drawbar_oct.value(),
+            mod1_osc_drawbar_oct2: params.osc_1_drawbar_oct2.value(),
+            mod2_osc_drawbar_sub: params.osc_2_drawbar_sub.value(),
+            mod2_osc_drawbar_root: params.osc_2_drawbar_root.value(),
+            mod2_osc_drawbar_oct: params.osc_2_drawbar_oct.value(),
+            mod2_osc_drawbar_oct2: params.osc_2_drawbar_oct2.value(),
+            mod3_osc_drawbar_sub: params.osc_3_drawbar_sub.value(),
+            mod3_osc_drawbar_root: params.osc_3_drawbar_root.value(),
+            mod3_osc_drawbar_oct: params.osc_3_drawbar_oct.value(),
+            mod3_osc_drawbar_oct2: params.osc_3_drawbar_oct2.value(),
+            mod_source_6: params.mod_source_6.value(),
+            mod_source_7: params.mod_source_7.value(),
+            mod_source_8: params.mod_source_8.value(),
+            mod_dest_5: params.mod_destination_5.value(),
+            mod_dest_6: params.mod_destination_6.value(),
+            mod_dest_7: params.mod_destination_7.value(),
+            mod_dest_8: params.mod_destination_8.value(),
+            mod_amount_5: params.mod_amount_knob_5.value(),
+            mod_amount_6: params.mod_amount_knob_6.value(),
+            mod_amount_7: params.mod_amount_knob_7.value(),
+            mod_amount_8: params.mod_amount_knob_8.value(),
+            mod_source_5: params.mod_source_5.value(),
+            mod1_loop_xfade: params.loop_xfade_1.value(),
+            mod2_loop_xfade: params.loop_xfade_2.value(),
+            mod3_loop_xfade: params.loop_xfade_3.value(),
+            filter_cutoff_smooth: params.filter_cutoff_smooth.value(),
+            note_offset_cents: params.note_offset_cents().map(|param| param.value()),
+            mod1_osc_env_keyscale: params.osc_1_env_keyscale.value(),
+            mod2_osc_env_keyscale: params.osc_2_env_keyscale.value(),
+            mod3_osc_env_keyscale: params.osc_3_env_keyscale.value(),
+            reference_pitch: params.reference_pitch.value(),
+            filter_stereo_offset_1: params.filter_stereo_offset_1.value(),
+            filter_stereo_offset_2: params.filter_stereo_offset_2.value(),
+            paraphonic: params.paraphonic.value(),
+            mod1_osc_detune_hz: params.osc_1_detune_hz.value(),
+            mod2_osc_detune_hz: params.osc_2_detune_hz.value(),
+            mod3_osc_detune_hz: params.osc_3_detune_hz.value(),
+            use_bass_mono: params.use_bass_mono.value(),
+            elliptical_freq: params.elliptical_freq.value(),
+            mod1_osc_unison_blend: params.osc_1_unison_blend.value(),
+            mod2_osc_unison_blend: params.osc_2_unison_blend.value(),
+            mod3_osc_unison_blend: params.osc_3_unison_blend.value(),
+            reverb_diffusion: params.reverb_diffusion.value(),
+            reverb_density: params.reverb_density.value(),
+            mod1_slice_count: params.slice_count_1.value(),
+            mod2_slice_count: params.slice_count_2.value(),
+            mod3_slice_count: params.slice_count_3.value(),
+            use_looper: params.use_looper.value(),
+            looper_length: params.looper_length.value(),
+            looper_feedback: params.looper_feedback.value(),
+            looper_mix: params.looper_mix.value(),
+            looper_pitch: params.looper_pitch.value(),
+            tape_stop_time: params.tape_stop_time.value(),
+            mod1_sample_normalize: params.sample_normalize_1.value(),
+            mod2_sample_normalize: params.sample_normalize_2.value(),
+            mod3_sample_normalize: params.sample_normalize_3.value(),
+            space: params.space.value(),
+            mod1_osc_punch: params.osc_1_punch.value(),
+            mod2_osc_punch: params.osc_2_punch.value(),
+            mod3_osc_punch: params.osc_3_punch.value(),
+            mod1_phase_invert: params.audio_module_1_phase_invert.value(),
+            mod2_phase_invert: params.audio_module_2_phase_invert.value(),
+            mod3_phase_invert: params.audio_module_3_phase_invert.value(),
+            live_waveform_switch: params.live_waveform_switch.value(),
+            use_resonator: params.use_resonator.value(),
+            resonator_chord: params.resonator_chord.value(),
+            resonator_root: params.resonator_root.value(),
+            resonator_decay: params.resonator_decay.value(),
+            resonator_mix: params.resonator_mix.value(),
+            mod1_grain_spray: params.grain_spray_1.value(),
+            mod2_grain_spray: params.grain_spray_2.value(),
+            mod3_grain_spray: params.grain_spray_3.value(),
+        }
     }
 }
 
@@ -6481,6 +10663,87 @@ impl Vst3Plugin for Actuate {
 nih_export_clap!(Actuate);
 nih_export_vst3!(Actuate);
 
+// Gzip the serialized preset for export
+fn compress_bytes(data: &[u8], level: Compression) -> std::io::Result<Vec<u8>> {
+    let mut encoder = GzEncoder::new(Vec::new(), level);
+    encoder.write_all(data)?;
+    encoder.finish()
+}
+
+// Undo compress_bytes, anything without the gzip magic bytes is an older uncompressed preset
+fn decompress_bytes(data: &[u8]) -> std::io::Result<Vec<u8>> {
+    if data.len() < 2 || data[0] != 0x1f || data[1] != 0x8b {
+        return Ok(data.to_vec());
+    }
+    let mut decompressed = Vec::new();
+    GzDecoder::new(data).read_to_end(&mut decompressed)?;
+    Ok(decompressed)
+}
+
+// UTC date stamp for preset metadata, std has no calendar so the days are converted by hand
+fn preset_timestamp() -> String {
+    let seconds = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs() as i64)
+        .unwrap_or(0);
+    let days = seconds.div_euclid(86400);
+    let minutes_of_day = seconds.rem_euclid(86400) / 60;
+    // Days since 1970-01-01 to a civil date (Howard Hinnant's algorithm)
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let day_of_era = z - era * 146097;
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 { month_index + 3 } else { month_index - 9 };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    format!("{:04}-{:02}-{:02} {:02}:{:02} UTC", year, month, day, minutes_of_day / 60, minutes_of_day % 60)
+}
+
+// Output gain for each module from the mute and solo toggles
+// Soloed modules sum together, a muted module stays silent even when soloed
+fn module_mix_gains(mutes: [bool; 3], solos: [bool; 3]) -> [f32; 3] {
+    let any_solo = solos.iter().any(|solo| *solo);
+    let mut gains = [1.0; 3];
+    for ((gain, muted), soloed) in gains.iter_mut().zip(mutes).zip(solos) {
+        if muted || (any_solo && !soloed) {
+            *gain = 0.0;
+        }
+    }
+    gains
+}
+
+// Snap a pitch modulation amount in semitones to whole steps, optionally only ones in a scale above the played note
+// Correlation coefficient from the running L*R, L*L and R*R averages, silence reads as 0
+fn stereo_correlation(sums: [f32; 3]) -> f32 {
+    let energy = (sums[1] * sums[2]).sqrt();
+    if energy < 1e-10 {
+        0.0
+    } else {
+        (sums[0] / energy).clamp(-1.0, 1.0)
+    }
+}
+
+fn quantize_pitch_mod(semitones: f32, scale: PitchQuantizeScale) -> f32 {
+    let steps: &[i32] = match scale {
+        PitchQuantizeScale::Chromatic => return semitones.round(),
+        PitchQuantizeScale::Major => &[0, 2, 4, 5, 7, 9, 11],
+        PitchQuantizeScale::Minor => &[0, 2, 3, 5, 7, 8, 10],
+        PitchQuantizeScale::Pentatonic => &[0, 2, 4, 7, 9],
+    };
+    let octave = (semitones / 12.0).floor();
+    let within = semitones - octave * 12.0;
+    // Include the next octave's root so values near 12 can round up to it
+    let nearest = steps
+        .iter()
+        .map(|step| *step as f32)
+        .chain(std::iter::once(12.0))
+        .min_by(|a, b| (a - within).abs().total_cmp(&(b - within).abs()))
+        .unwrap_or(0.0);
+    octave * 12.0 + nearest
+}
+
 // I use this when I want to remove label and unit from a param in gui
 pub fn format_nothing() -> Arc<dyn Fn(f32) -> String + Send + Sync> {
     Arc::new(move |_| String::new())
@@ -6796,6 +11059,8 @@ lazy_static::lazy_static!(
     static ref ERROR_PRESET: ActuatePresetV131 = ActuatePresetV131 {
         preset_name: String::from("Error Loading"),
         preset_info: String::from("Corrupt or incompatible versions"),
+        preset_author: String::new(),
+        preset_created: String::new(),
         preset_category: PresetType::Select,
         tag_acid: false,
         tag_analog: false,
@@ -7135,11 +11400,166 @@ lazy_static::lazy_static!(
         additive_amp_3_13: 0.0,
         additive_amp_3_14: 0.0,
         additive_amp_3_15: 0.0,
+        master_tilt: 0.0,
+        lfo1_depth: 1.0,
+        lfo2_depth: 1.0,
+        lfo3_depth: 1.0,
+        ring_mod_1x2: 0.0,
+        ring_mod_2x3: 0.0,
+        sat_stereo_mode: SaturationStereoMode::LR,
+        humanize: 0.0,
+        mod1_osc_super: 0.0,
+        mod2_osc_super: 0.0,
+        mod3_osc_super: 0.0,
+        filter_drive: 0.0,
+        filter_drive_2: 0.0,
+        osc_quality: Oscillator::OscQuality::Normal,
+        release_vel_amount: 0.0,
+        mono_mode: MonoMode::Poly,
+        glide_time: 0.0,
+        mod1_osc_type_b: AudioModuleType::Saw,
+        mod2_osc_type_b: AudioModuleType::Saw,
+        mod3_osc_type_b: AudioModuleType::Saw,
+        mod1_osc_morph: 0.0,
+        mod2_osc_morph: 0.0,
+        mod3_osc_morph: 0.0,
+        use_transient: false,
+        transient_attack: 0.0,
+        transient_sustain: 0.0,
+        filter_auto_gain: false,
+        global_phase_reset: false,
+        delay_pitch: 0.0,
+        pitch_mod_quantize: false,
+        pitch_mod_scale: PitchQuantizeScale::Chromatic,
+        mod1_grain_window: GrainWindow::Linear,
+        mod2_grain_window: GrainWindow::Linear,
+        mod3_grain_window: GrainWindow::Linear,
+        use_delay_2: false,
+        delay_2_amount: 0.5,
+        delay_2_time: DelaySnapValues::EighthD,
+        delay_2_decay: 0.5,
+        delay_2_type: DelayType::Stereo,
+        delay_routing: DelayRouting::Series,
+        filter_env_loop: false,
+        filter_env_loop_rate: LFOController::LFOSnapValues::Eighth,
+        voice_spread: 0.0,
+        filter_env_invert_1: false,
+        filter_env_invert_2: false,
+        mod1_sample_root: 60,
+        mod2_sample_root: 60,
+        mod3_sample_root: 60,
+        mod1_stretch_factor: 1.0,
+        mod2_stretch_factor: 1.0,
+        mod3_stretch_factor: 1.0,
+        voice_steal_mode: VoiceStealMode::Oldest,
+        lfo1_gate: false,
+        lfo2_gate: false,
+        lfo3_gate: false,
+        mod1_osc_shape: Oscillator::OscShape::Off,
+        mod1_osc_shape_amount: 0.5,
+        mod2_osc_shape: Oscillator::OscShape::Off,
+        mod2_osc_shape_amount: 0.5,
+        mod3_osc_shape: Oscillator::OscShape::Off,
+        mod3_osc_shape_amount: 0.5,
+        reverb_predelay: 0.0,
+        filter_res_link: false,
+        filter_env_link: false,
+        phaser_stereo: 0.0,
+        flanger_stereo: 0.0,
+        buffermod_stereo: 0.0,
+        tuning_system: TuningSystem::TwelveTET,
+        tuning_root: 0,
+        mod1_osc_warmth: 0.0,
+        mod2_osc_warmth: 0.0,
+        mod3_osc_warmth: 0.0,
+        master_hpf: 10.0,
+        limiter_ceiling: 0.0,
+        use_gate: false,
+        gate_rate: LFOController::LFOSnapValues::Sixteen,
+        gate_shape: 0.5,
+        gate_smooth: 0.2,
+        env_time_range: EnvTimeRange::Normal,
+        mod1_osc_drawbar_sub: 0.0,
+        mod1_osc_drawbar_root: 1.0,
+        mod1_osc_drawbar_oct: 0.0,
+        mod1_osc_drawbar_oct2: 0.0,
+        mod2_osc_drawbar_sub: 0.0,
+        mod2_osc_drawbar_root: 1.0,
+        mod2_osc_drawbar_oct: 0.0,
+        mod2_osc_drawbar_oct2: 0.0,
+        mod3_osc_drawbar_sub: 0.0,
+        mod3_osc_drawbar_root: 1.0,
+        mod3_osc_drawbar_oct: 0.0,
+        mod3_osc_drawbar_oct2: 0.0,
+        mod_source_6: ModulationSource::None,
+        mod_source_7: ModulationSource::None,
+        mod_source_8: ModulationSource::None,
+        mod_dest_5: ModulationDestination::None,
+        mod_dest_6: ModulationDestination::None,
+        mod_dest_7: ModulationDestination::None,
+        mod_dest_8: ModulationDestination::None,
+        mod_amount_5: 0.0,
+        mod_amount_6: 0.0,
+        mod_amount_7: 0.0,
+        mod_amount_8: 0.0,
+        mod_source_5: ModulationSource::None,
+        mod1_loop_xfade: 5.0,
+        mod2_loop_xfade: 5.0,
+        mod3_loop_xfade: 5.0,
+        filter_cutoff_smooth: 0.0,
+        note_offset_cents: [0.0; 12],
+        mod1_osc_env_keyscale: 0.0,
+        mod2_osc_env_keyscale: 0.0,
+        mod3_osc_env_keyscale: 0.0,
+        reference_pitch: 440.0,
+        filter_stereo_offset_1: 0.0,
+        filter_stereo_offset_2: 0.0,
+        paraphonic: false,
+        mod1_osc_detune_hz: 0.0,
+        mod2_osc_detune_hz: 0.0,
+        mod3_osc_detune_hz: 0.0,
+        use_bass_mono: false,
+        elliptical_freq: 120.0,
+        mod1_osc_unison_blend: 0.5,
+        mod2_osc_unison_blend: 0.5,
+        mod3_osc_unison_blend: 0.5,
+        reverb_diffusion: 0.5,
+        reverb_density: 1.0,
+        mod1_slice_count: 1,
+        mod2_slice_count: 1,
+        mod3_slice_count: 1,
+        use_looper: false,
+        looper_length: 1000.0,
+        looper_feedback: 0.5,
+        looper_mix: 0.5,
+        looper_pitch: 0.0,
+        tape_stop_time: 1000.0,
+        mod1_sample_normalize: false,
+        mod2_sample_normalize: false,
+        mod3_sample_normalize: false,
+        space: 0.0,
+        mod1_osc_punch: 0.0,
+        mod2_osc_punch: 0.0,
+        mod3_osc_punch: 0.0,
+        mod1_phase_invert: false,
+        mod2_phase_invert: false,
+        mod3_phase_invert: false,
+        live_waveform_switch: true,
+        use_resonator: false,
+        resonator_chord: ResonatorChord::Major,
+        resonator_root: 48,
+        resonator_decay: 4.0,
+        resonator_mix: 0.5,
+        mod1_grain_spray: 0.0,
+        mod2_grain_spray: 0.0,
+        mod3_grain_spray: 0.0,
     };
 
     static ref DEFAULT_PRESET: ActuatePresetV131 = ActuatePresetV131 {
         preset_name: "Default".to_string(),
         preset_info: "Info".to_string(),
+        preset_author: String::new(),
+        preset_created: String::new(),
         preset_category: PresetType::Select,
         tag_acid: false,
         tag_analog: false,
@@ -7161,7 +11581,7 @@ lazy_static::lazy_static!(
         tag_stab: false,
         tag_warm: false,
         mod1_audio_module_type: AudioModuleType::Sine,
-        mod1_audio_module_level: 1.0,
+        mod1_audio_module_level: 0.1,
         mod1_audio_module_routing: AMFilterRouting::Filter1,
         mod1_loaded_sample: vec![vec![0.0, 0.0]],
         mod1_sample_lib: vec![vec![vec![0.0, 0.0]]],
@@ -7187,10 +11607,10 @@ lazy_static::lazy_static!(
         mod1_osc_rel_curve: SmoothStyle::Linear,
         mod1_osc_unison: 1,
         mod1_osc_unison_detune: 0.0,
-        mod1_osc_stereo: 0.0,
+        mod1_osc_stereo: 1.0,
 
-        mod2_audio_module_type: AudioModuleType::Off,
-        mod2_audio_module_level: 1.0,
+        mod2_audio_module_type: AudioModuleType::Sine,
+        mod2_audio_module_level: 0.1,
         mod2_audio_module_routing: AMFilterRouting::Filter1,
         mod2_loaded_sample: vec![vec![0.0, 0.0]],
         mod2_sample_lib: vec![vec![vec![0.0, 0.0]]],
@@ -7216,10 +11636,10 @@ lazy_static::lazy_static!(
         mod2_osc_rel_curve: SmoothStyle::Linear,
         mod2_osc_unison: 1,
         mod2_osc_unison_detune: 0.0,
-        mod2_osc_stereo: 0.0,
+        mod2_osc_stereo: 1.0,
 
-        mod3_audio_module_type: AudioModuleType::Off,
-        mod3_audio_module_level: 1.0,
+        mod3_audio_module_type: AudioModuleType::Sine,
+        mod3_audio_module_level: 0.1,
         mod3_audio_module_routing: AMFilterRouting::Filter1,
         mod3_loaded_sample: vec![vec![0.0, 0.0]],
         mod3_sample_lib: vec![vec![vec![0.0, 0.0]]],
@@ -7245,7 +11665,7 @@ lazy_static::lazy_static!(
         mod3_osc_rel_curve: SmoothStyle::Linear,
         mod3_osc_unison: 1,
         mod3_osc_unison_detune: 0.0,
-        mod3_osc_stereo: 0.0,
+        mod3_osc_stereo: 1.0,
 
         filter_wet: 1.0,
         filter_cutoff: 20000.0,
@@ -7258,7 +11678,7 @@ lazy_static::lazy_static!(
         filter_env_attack: 0.0001,
         filter_env_decay: 0.0001,
         filter_env_sustain: 1999.9,
-        filter_env_release: 5.0,
+        filter_env_release: 0.0001,
         filter_env_atk_curve: SmoothStyle::Linear,
         filter_env_dec_curve: SmoothStyle::Linear,
         filter_env_rel_curve: SmoothStyle::Linear,
@@ -7276,7 +11696,7 @@ lazy_static::lazy_static!(
         filter_env_attack_2: 0.0001,
         filter_env_decay_2: 0.0001,
         filter_env_sustain_2: 1999.9,
-        filter_env_release_2: 5.0,
+        filter_env_release_2: 0.0001,
         filter_env_atk_curve_2: SmoothStyle::Linear,
         filter_env_dec_curve_2: SmoothStyle::Linear,
         filter_env_rel_curve_2: SmoothStyle::Linear,
@@ -7292,7 +11712,7 @@ lazy_static::lazy_static!(
         pitch_env_peak: 0.0,
         pitch_env_attack: 0.0001,
         pitch_env_decay: 300.0,
-        pitch_env_sustain: 0.0,
+        pitch_env_sustain: 0.0001,
         pitch_env_release: 0.0001,
         pitch_env_atk_curve: SmoothStyle::Linear,
         pitch_env_dec_curve: SmoothStyle::Linear,
@@ -7303,7 +11723,7 @@ lazy_static::lazy_static!(
         pitch_env_peak_2: 0.0,
         pitch_env_attack_2: 0.0001,
         pitch_env_decay_2: 300.0,
-        pitch_env_sustain_2: 0.0,
+        pitch_env_sustain_2: 0.0001,
         pitch_env_release_2: 0.0001,
         pitch_env_atk_curve_2: SmoothStyle::Linear,
         pitch_env_dec_curve_2: SmoothStyle::Linear,
@@ -7314,21 +11734,21 @@ lazy_static::lazy_static!(
         lfo2_enable: false,
         lfo3_enable: false,
 
-        lfo1_freq: 2.0,
+        lfo1_freq: 4.62,
         lfo1_retrigger: LFOController::LFORetrigger::None,
         lfo1_sync: true,
         lfo1_snap: LFOController::LFOSnapValues::Half,
         lfo1_waveform: LFOController::Waveform::Sine,
         lfo1_phase: 0.0,
 
-        lfo2_freq: 2.0,
+        lfo2_freq: 4.62,
         lfo2_retrigger: LFOController::LFORetrigger::None,
         lfo2_sync: true,
         lfo2_snap: LFOController::LFOSnapValues::Half,
         lfo2_waveform: LFOController::Waveform::Sine,
         lfo2_phase: 0.0,
 
-        lfo3_freq: 2.0,
+        lfo3_freq: 4.62,
         lfo3_retrigger: LFOController::LFORetrigger::None,
         lfo3_sync: true,
         lfo3_snap: LFOController::LFOSnapValues::Half,
@@ -7383,7 +11803,7 @@ lazy_static::lazy_static!(
         comp_drive: 0.3,
 
         use_abass: false,
-        abass_amount: 0.00067,
+        abass_amount: 0.000668,
 
         use_saturation: false,
         sat_amount: 0.0,
@@ -7411,21 +11831,21 @@ lazy_static::lazy_static!(
 
         use_phaser: false,
         phaser_amount: 0.5,
-        phaser_depth: 0.5,
-        phaser_rate: 0.5,
+        phaser_depth: 1.0,
+        phaser_rate: 1.0,
         phaser_feedback: 0.5,
 
         use_buffermod: false,
         buffermod_amount: 0.5,
-        buffermod_depth: 0.5,
-        buffermod_rate: 0.5,
+        buffermod_depth: 1.0,
+        buffermod_rate: 0.01,
         buffermod_spread: 0.0,
         buffermod_timing: 620.0,
 
         use_flanger: false,
         flanger_amount: 0.5,
         flanger_depth: 0.5,
-        flanger_rate: 0.5,
+        flanger_rate: 5.0,
         flanger_feedback: 0.5,
 
         use_limiter: false,
@@ -7433,7 +11853,7 @@ lazy_static::lazy_static!(
         limiter_knee: 0.5,
 
         // v 1.3.1 Additive fields
-        additive_amp_1_0: 0.0,
+        additive_amp_1_0: 1.0,
         additive_amp_1_1: 0.0,
         additive_amp_1_2: 0.0,
         additive_amp_1_3: 0.0,
@@ -7449,7 +11869,7 @@ lazy_static::lazy_static!(
         additive_amp_1_13: 0.0,
         additive_amp_1_14: 0.0,
         additive_amp_1_15: 0.0,
-        additive_amp_2_0: 0.0,
+        additive_amp_2_0: 1.0,
         additive_amp_2_1: 0.0,
         additive_amp_2_2: 0.0,
         additive_amp_2_3: 0.0,
@@ -7465,7 +11885,7 @@ lazy_static::lazy_static!(
         additive_amp_2_13: 0.0,
         additive_amp_2_14: 0.0,
         additive_amp_2_15: 0.0,
-        additive_amp_3_0: 0.0,
+        additive_amp_3_0: 1.0,
         additive_amp_3_1: 0.0,
         additive_amp_3_2: 0.0,
         additive_amp_3_3: 0.0,
@@ -7481,6 +11901,275 @@ lazy_static::lazy_static!(
         additive_amp_3_13: 0.0,
         additive_amp_3_14: 0.0,
         additive_amp_3_15: 0.0,
+        master_tilt: 0.0,
+        lfo1_depth: 1.0,
+        lfo2_depth: 1.0,
+        lfo3_depth: 1.0,
+        ring_mod_1x2: 0.0,
+        ring_mod_2x3: 0.0,
+        sat_stereo_mode: SaturationStereoMode::LR,
+        humanize: 0.0,
+        mod1_osc_super: 0.0,
+        mod2_osc_super: 0.0,
+        mod3_osc_super: 0.0,
+        filter_drive: 0.0,
+        filter_drive_2: 0.0,
+        osc_quality: Oscillator::OscQuality::Normal,
+        release_vel_amount: 0.0,
+        mono_mode: MonoMode::Poly,
+        glide_time: 0.0,
+        mod1_osc_type_b: AudioModuleType::Saw,
+        mod2_osc_type_b: AudioModuleType::Saw,
+        mod3_osc_type_b: AudioModuleType::Saw,
+        mod1_osc_morph: 0.0,
+        mod2_osc_morph: 0.0,
+        mod3_osc_morph: 0.0,
+        use_transient: false,
+        transient_attack: 0.0,
+        transient_sustain: 0.0,
+        filter_auto_gain: false,
+        global_phase_reset: false,
+        delay_pitch: 0.0,
+        pitch_mod_quantize: false,
+        pitch_mod_scale: PitchQuantizeScale::Chromatic,
+        mod1_grain_window: GrainWindow::Linear,
+        mod2_grain_window: GrainWindow::Linear,
+        mod3_grain_window: GrainWindow::Linear,
+        use_delay_2: false,
+        delay_2_amount: 0.5,
+        delay_2_time: DelaySnapValues::EighthD,
+        delay_2_decay: 0.5,
+        delay_2_type: DelayType::Stereo,
+        delay_routing: DelayRouting::Series,
+        filter_env_loop: false,
+        filter_env_loop_rate: LFOController::LFOSnapValues::Eighth,
+        voice_spread: 0.0,
+        filter_env_invert_1: false,
+        filter_env_invert_2: false,
+        mod1_sample_root: 60,
+        mod2_sample_root: 60,
+        mod3_sample_root: 60,
+        mod1_stretch_factor: 1.0,
+        mod2_stretch_factor: 1.0,
+        mod3_stretch_factor: 1.0,
+        voice_steal_mode: VoiceStealMode::Oldest,
+        lfo1_gate: false,
+        lfo2_gate: false,
+        lfo3_gate: false,
+        mod1_osc_shape: Oscillator::OscShape::Off,
+        mod1_osc_shape_amount: 0.5,
+        mod2_osc_shape: Oscillator::OscShape::Off,
+        mod2_osc_shape_amount: 0.5,
+        mod3_osc_shape: Oscillator::OscShape::Off,
+        mod3_osc_shape_amount: 0.5,
+        reverb_predelay: 0.0,
+        filter_res_link: false,
+        filter_env_link: false,
+        phaser_stereo: 0.0,
+        flanger_stereo: 0.0,
+        buffermod_stereo: 0.0,
+        tuning_system: TuningSystem::TwelveTET,
+        tuning_root: 0,
+        mod1_osc_warmth: 0.0,
+        mod2_osc_warmth: 0.0,
+        mod3_osc_warmth: 0.0,
+        master_hpf: 10.0,
+        limiter_ceiling: 0.0,
+        use_gate: false,
+        gate_rate: LFOController::LFOSnapValues::Sixteen,
+        gate_shape: 0.5,
+        gate_smooth: 0.2,
+        env_time_range: EnvTimeRange::Normal,
+        mod1_osc_drawbar_sub: 0.0,
+        mod1_osc_drawbar_root: 1.0,
+        mod1_osc_drawbar_oct: 0.0,
+        mod1_osc_drawbar_oct2: 0.0,
+        mod2_osc_drawbar_sub: 0.0,
+        mod2_osc_drawbar_root: 1.0,
+        mod2_osc_drawbar_oct: 0.0,
+        mod2_osc_drawbar_oct2: 0.0,
+        mod3_osc_drawbar_sub: 0.0,
+        mod3_osc_drawbar_root: 1.0,
+        mod3_osc_drawbar_oct: 0.0,
+        mod3_osc_drawbar_oct2: 0.0,
+        mod_source_6: ModulationSource::None,
+        mod_source_7: ModulationSource::None,
+        mod_source_8: ModulationSource::None,
+        mod_dest_5: ModulationDestination::None,
+        mod_dest_6: ModulationDestination::None,
+        mod_dest_7: ModulationDestination::None,
+        mod_dest_8: ModulationDestination::None,
+        mod_amount_5: 0.0,
+        mod_amount_6: 0.0,
+        mod_amount_7: 0.0,
+        mod_amount_8: 0.0,
+        mod_source_5: ModulationSource::None,
+        mod1_loop_xfade: 5.0,
+        mod2_loop_xfade: 5.0,
+        mod3_loop_xfade: 5.0,
+        filter_cutoff_smooth: 0.0,
+        note_offset_cents: [0.0; 12],
+        mod1_osc_env_keyscale: 0.0,
+        mod2_osc_env_keyscale: 0.0,
+        mod3_osc_env_keyscale: 0.0,
+        reference_pitch: 440.0,
+        filter_stereo_offset_1: 0.0,
+        filter_stereo_offset_2: 0.0,
+        paraphonic: false,
+        mod1_osc_detune_hz: 0.0,
+        mod2_osc_detune_hz: 0.0,
+        mod3_osc_detune_hz: 0.0,
+        use_bass_mono: false,
+        elliptical_freq: 120.0,
+        mod1_osc_unison_blend: 0.5,
+        mod2_osc_unison_blend: 0.5,
+        mod3_osc_unison_blend: 0.5,
+        reverb_diffusion: 0.5,
+        reverb_density: 1.0,
+        mod1_slice_count: 1,
+        mod2_slice_count: 1,
+        mod3_slice_count: 1,
+        use_looper: false,
+        looper_length: 1000.0,
+        looper_feedback: 0.5,
+        looper_mix: 0.5,
+        looper_pitch: 0.0,
+        tape_stop_time: 1000.0,
+        mod1_sample_normalize: false,
+        mod2_sample_normalize: false,
+        mod3_sample_normalize: false,
+        space: 0.0,
+        mod1_osc_punch: 0.0,
+        mod2_osc_punch: 0.0,
+        mod3_osc_punch: 0.0,
+        mod1_phase_invert: false,
+        mod2_phase_invert: false,
+        mod3_phase_invert: false,
+        live_waveform_switch: true,
+        use_resonator: false,
+        resonator_chord: ResonatorChord::Major,
+        resonator_root: 48,
+        resonator_decay: 4.0,
+        resonator_mix: 0.5,
+        mod1_grain_spray: 0.0,
+        mod2_grain_spray: 0.0,
+        mod3_grain_spray: 0.0,
     };
 );
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // An engine with the voice mode and a 100 ms glide set through the params it is built from
+    fn mono_engine(mode: MonoMode) -> Actuate {
+        let mut preset = DEFAULT_PRESET.clone();
+        preset.mono_mode = mode;
+        preset.glide_time = 100.0;
+        let mut engine = Actuate::default();
+        engine.params = Arc::new(ActuateParams::new(
+            Arc::new(AtomicBool::new(false)),
+            Arc::new(AtomicBool::new(false)),
+            Arc::new(AtomicBool::new(false)),
+            &preset,
+        ));
+        engine
+    }
+
+    fn note_on(note: u8) -> NoteEvent<()> {
+        NoteEvent::NoteOn { timing: 0, voice_id: None, channel: 0, note, velocity: 0.8 }
+    }
+
+    fn note_off(note: u8) -> NoteEvent<()> {
+        NoteEvent::NoteOff { timing: 0, voice_id: None, channel: 0, note, velocity: 0.0 }
+    }
+
+    // Plays out a pending retrigger the way render_sample does on the next sample
+    fn take_pending(engine: &mut Actuate) -> Option<NoteEvent<()>> {
+        let (event, glide_from) = engine.mono_pending.take()?;
+        engine.glide_current = glide_from;
+        engine.set_glide_target(0.0);
+        Some(event)
+    }
+
+    #[test]
+    fn legato_overlapping_notes_glide() {
+        let mut engine = mono_engine(MonoMode::Legato);
+        assert!(matches!(engine.handle_mono(note_on(60)), Some(NoteEvent::NoteOn { note: 60, .. })));
+        // The second key rides on the held voice instead of starting a new one
+        assert!(engine.handle_mono(note_on(64)).is_none());
+        assert_eq!(engine.glide_target, 4.0);
+        assert!(engine.glide_step > 0.0);
+        // Letting go of the top key glides back down to the one still held
+        assert!(engine.handle_mono(note_off(64)).is_none());
+        assert_eq!(engine.glide_target, 0.0);
+        // The last key up releases the voice under the note it started on
+        assert!(matches!(engine.handle_mono(note_off(60)), Some(NoteEvent::NoteOff { note: 60, .. })));
+        assert!(engine.mono_voice_note.is_none());
+        assert!(engine.mono_pending.is_none());
+    }
+
+    #[test]
+    fn legato_separate_notes_do_not_glide() {
+        let mut engine = mono_engine(MonoMode::Legato);
+        assert!(engine.handle_mono(note_on(60)).is_some());
+        assert!(matches!(engine.handle_mono(note_off(60)), Some(NoteEvent::NoteOff { note: 60, .. })));
+        // A quick run of notes that never overlap starts each one fresh on its own pitch
+        for note in [67, 62, 72] {
+            assert!(matches!(engine.handle_mono(note_on(note)), Some(NoteEvent::NoteOn { note: n, .. }) if n == note));
+            assert_eq!(engine.glide_current, 0.0);
+            assert_eq!(engine.glide_target, 0.0);
+            assert!(matches!(engine.handle_mono(note_off(note)), Some(NoteEvent::NoteOff { note: n, .. }) if n == note));
+        }
+        assert!(engine.mono_pending.is_none());
+    }
+
+    #[test]
+    fn mono_overlapping_note_retriggers_from_the_sounding_pitch() {
+        let mut engine = mono_engine(MonoMode::Mono);
+        assert!(engine.handle_mono(note_on(60)).is_some());
+        // The old voice is released now and the new note waits for the next sample
+        assert!(matches!(engine.handle_mono(note_on(64)), Some(NoteEvent::NoteOff { note: 60, .. })));
+        assert_eq!(engine.mono_voice_note, Some(64));
+        assert!(matches!(take_pending(&mut engine), Some(NoteEvent::NoteOn { note: 64, .. })));
+        // It starts where 60 was sounding and glides up to its own pitch
+        assert_eq!(engine.glide_current, -4.0);
+        assert_eq!(engine.glide_target, 0.0);
+        assert!(engine.glide_step > 0.0);
+
+        // Released halfway up the glide, the held key comes back in from 62
+        engine.glide_current = -2.0;
+        assert!(matches!(engine.handle_mono(note_off(64)), Some(NoteEvent::NoteOff { note: 64, .. })));
+        assert!(matches!(take_pending(&mut engine), Some(NoteEvent::NoteOn { note: 60, .. })));
+        assert_eq!(engine.glide_current, 2.0);
+        assert_eq!(engine.mono_voice_note, Some(60));
+    }
+
+    #[test]
+    fn mono_separate_notes_glide_from_the_last_pitch() {
+        let mut engine = mono_engine(MonoMode::Mono);
+        assert!(engine.handle_mono(note_on(60)).is_some());
+        assert!(matches!(engine.handle_mono(note_off(60)), Some(NoteEvent::NoteOff { note: 60, .. })));
+        assert_eq!(engine.mono_last_pitch, Some(60.0));
+        // Mono keeps portamento between detached notes, no retrigger is needed since nothing is sounding
+        assert!(matches!(engine.handle_mono(note_on(64)), Some(NoteEvent::NoteOn { note: 64, .. })));
+        assert!(engine.mono_pending.is_none());
+        assert_eq!(engine.glide_current, -4.0);
+        assert_eq!(engine.glide_target, 0.0);
+    }
+
+    #[test]
+    fn held_key_under_the_sounding_one_releases_silently() {
+        for mode in [MonoMode::Mono, MonoMode::Legato] {
+            let mut engine = mono_engine(mode);
+            engine.handle_mono(note_on(60));
+            engine.handle_mono(note_on(64));
+            take_pending(&mut engine);
+            // 60 is only held underneath so letting it go changes nothing
+            assert!(engine.handle_mono(note_off(60)).is_none());
+            assert!(engine.mono_pending.is_none());
+            assert_eq!(engine.held_notes, vec![64]);
+        }
+    }
+}
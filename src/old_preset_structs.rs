@@ -1,9 +1,9 @@
 use crate::{
-    actuate_enums::StereoAlgorithm, audio_module::{
-        AudioModuleType,
+    actuate_enums::{EnvTimeRange, MonoMode, PitchQuantizeScale, StereoAlgorithm, TuningSystem, VoiceStealMode}, audio_module::{
+        AudioModuleType, GrainWindow,
         Oscillator::{self, RetriggerStyle, SmoothStyle},
     }, fx::{
-        delay::{DelaySnapValues, DelayType}, saturation::SaturationType, StateVariableFilter::ResonanceType, TiltFilter::{self}
+        delay::{DelayRouting, DelaySnapValues, DelayType}, resonator_bank::ResonatorChord, saturation::{SaturationStereoMode, SaturationType}, StateVariableFilter::ResonanceType, TiltFilter::{self}
     }, AMFilterRouting, ActuatePresetV131, FilterAlgorithms, FilterRouting, LFOController, ModulationDestination, ModulationSource, PitchRouting, PresetType, ReverbModel
 };
 use serde::{Deserialize, Serialize};
@@ -630,6 +630,8 @@ pub fn _convert_preset_v130(preset: ActuatePresetV130) -> ActuatePresetV131 {
     let new_format: ActuatePresetV131 = ActuatePresetV131 {
         preset_name: preset.preset_name,
         preset_info: preset.preset_info,
+        preset_author: String::new(),
+        preset_created: String::new(),
         preset_category: preset.preset_category,
         tag_acid: preset.tag_acid,
         tag_analog: preset.tag_analog,
@@ -948,6 +950,159 @@ pub fn _convert_preset_v130(preset: ActuatePresetV130) -> ActuatePresetV131 {
         additive_amp_3_13: 0.0,
         additive_amp_3_14: 0.0,
         additive_amp_3_15: 0.0,
+        master_tilt: 0.0,
+        lfo1_depth: 1.0,
+        lfo2_depth: 1.0,
+        lfo3_depth: 1.0,
+        ring_mod_1x2: 0.0,
+        ring_mod_2x3: 0.0,
+        sat_stereo_mode: SaturationStereoMode::LR,
+        humanize: 0.0,
+        mod1_osc_super: 0.0,
+        mod2_osc_super: 0.0,
+        mod3_osc_super: 0.0,
+        filter_drive: 0.0,
+        filter_drive_2: 0.0,
+        osc_quality: Oscillator::OscQuality::Normal,
+        release_vel_amount: 0.0,
+        mono_mode: MonoMode::Poly,
+        glide_time: 0.0,
+        mod1_osc_type_b: AudioModuleType::Saw,
+        mod2_osc_type_b: AudioModuleType::Saw,
+        mod3_osc_type_b: AudioModuleType::Saw,
+        mod1_osc_morph: 0.0,
+        mod2_osc_morph: 0.0,
+        mod3_osc_morph: 0.0,
+        use_transient: false,
+        transient_attack: 0.0,
+        transient_sustain: 0.0,
+        filter_auto_gain: false,
+        global_phase_reset: false,
+        delay_pitch: 0.0,
+        pitch_mod_quantize: false,
+        pitch_mod_scale: PitchQuantizeScale::Chromatic,
+        mod1_grain_window: GrainWindow::Linear,
+        mod2_grain_window: GrainWindow::Linear,
+        mod3_grain_window: GrainWindow::Linear,
+        use_delay_2: false,
+        delay_2_amount: 0.5,
+        delay_2_time: DelaySnapValues::EighthD,
+        delay_2_decay: 0.5,
+        delay_2_type: DelayType::Stereo,
+        delay_routing: DelayRouting::Series,
+        filter_env_loop: false,
+        filter_env_loop_rate: LFOController::LFOSnapValues::Eighth,
+        voice_spread: 0.0,
+        filter_env_invert_1: false,
+        filter_env_invert_2: false,
+        mod1_sample_root: 60,
+        mod2_sample_root: 60,
+        mod3_sample_root: 60,
+        mod1_stretch_factor: 1.0,
+        mod2_stretch_factor: 1.0,
+        mod3_stretch_factor: 1.0,
+        voice_steal_mode: VoiceStealMode::Oldest,
+        lfo1_gate: false,
+        lfo2_gate: false,
+        lfo3_gate: false,
+        mod1_osc_shape: Oscillator::OscShape::Off,
+        mod1_osc_shape_amount: 0.5,
+        mod2_osc_shape: Oscillator::OscShape::Off,
+        mod2_osc_shape_amount: 0.5,
+        mod3_osc_shape: Oscillator::OscShape::Off,
+        mod3_osc_shape_amount: 0.5,
+        reverb_predelay: 0.0,
+        filter_res_link: false,
+        filter_env_link: false,
+        phaser_stereo: 0.0,
+        flanger_stereo: 0.0,
+        buffermod_stereo: 0.0,
+        tuning_system: TuningSystem::TwelveTET,
+        tuning_root: 0,
+        mod1_osc_warmth: 0.0,
+        mod2_osc_warmth: 0.0,
+        mod3_osc_warmth: 0.0,
+        master_hpf: 10.0,
+        limiter_ceiling: 0.0,
+        use_gate: false,
+        gate_rate: LFOController::LFOSnapValues::Sixteen,
+        gate_shape: 0.5,
+        gate_smooth: 0.2,
+        env_time_range: EnvTimeRange::Normal,
+        mod1_osc_drawbar_sub: 0.0,
+        mod1_osc_drawbar_root: 1.0,
+        mod1_osc_drawbar_oct: 0.0,
+        mod1_osc_drawbar_oct2: 0.0,
+        mod2_osc_drawbar_sub: 0.0,
+        mod2_osc_drawbar_root: 1.0,
+        mod2_osc_drawbar_oct: 0.0,
+        mod2_osc_drawbar_oct2: 0.0,
+        mod3_osc_drawbar_sub: 0.0,
+        mod3_osc_drawbar_root: 1.0,
+        mod3_osc_drawbar_oct: 0.0,
+        mod3_osc_drawbar_oct2: 0.0,
+        mod_source_6: ModulationSource::None,
+        mod_source_7: ModulationSource::None,
+        mod_source_8: ModulationSource::None,
+        mod_dest_5: ModulationDestination::None,
+        mod_dest_6: ModulationDestination::None,
+        mod_dest_7: ModulationDestination::None,
+        mod_dest_8: ModulationDestination::None,
+        mod_amount_5: 0.0,
+        mod_amount_6: 0.0,
+        mod_amount_7: 0.0,
+        mod_amount_8: 0.0,
+        mod_source_5: ModulationSource::None,
+        mod1_loop_xfade: 5.0,
+        mod2_loop_xfade: 5.0,
+        mod3_loop_xfade: 5.0,
+        filter_cutoff_smooth: 0.0,
+        note_offset_cents: [0.0; 12],
+        mod1_osc_env_keyscale: 0.0,
+        mod2_osc_env_keyscale: 0.0,
+        mod3_osc_env_keyscale: 0.0,
+        reference_pitch: 440.0,
+        filter_stereo_offset_1: 0.0,
+        filter_stereo_offset_2: 0.0,
+        paraphonic: false,
+        mod1_osc_detune_hz: 0.0,
+        mod2_osc_detune_hz: 0.0,
+        mod3_osc_detune_hz: 0.0,
+        use_bass_mono: false,
+        elliptical_freq: 120.0,
+        mod1_osc_unison_blend: 0.5,
+        mod2_osc_unison_blend: 0.5,
+        mod3_osc_unison_blend: 0.5,
+        reverb_diffusion: 0.5,
+        reverb_density: 1.0,
+        mod1_slice_count: 1,
+        mod2_slice_count: 1,
+        mod3_slice_count: 1,
+        use_looper: false,
+        looper_length: 1000.0,
+        looper_feedback: 0.5,
+        looper_mix: 0.5,
+        looper_pitch: 0.0,
+        tape_stop_time: 1000.0,
+        mod1_sample_normalize: false,
+        mod2_sample_normalize: false,
+        mod3_sample_normalize: false,
+        space: 0.0,
+        mod1_osc_punch: 0.0,
+        mod2_osc_punch: 0.0,
+        mod3_osc_punch: 0.0,
+        mod1_phase_invert: false,
+        mod2_phase_invert: false,
+        mod3_phase_invert: false,
+        live_waveform_switch: true,
+        use_resonator: false,
+        resonator_chord: ResonatorChord::Major,
+        resonator_root: 48,
+        resonator_decay: 4.0,
+        resonator_mix: 0.5,
+        mod1_grain_spray: 0.0,
+        mod2_grain_spray: 0.0,
+        mod3_grain_spray: 0.0,
     };
     new_format
 }